@@ -14,7 +14,7 @@ object Main {
     override def lines(implicit indentation: Indentation): Iterator[String] = {
       "use crate::app::parse::traits::{FixedSize, FixedSizeVariation};".eol ++
       "use crate::app::control::{CommandStatus, ControlCode};".eol ++
-      "use crate::app::Timestamp;".eol ++
+      "use crate::app::{IntervalUnit, Timestamp};".eol ++
       "use crate::app::measurement::*;".eol ++
       space ++
       "use scursor::*;".eol
@@ -7,6 +7,7 @@ sealed abstract class FixedSizeFieldType(val numBytes: Int)
 case object UInt8Field extends FixedSizeFieldType(1)
 case object UInt16Field extends FixedSizeFieldType(2)
 case object UInt32Field extends FixedSizeFieldType(4)
+case object UInt64Field extends FixedSizeFieldType(8)
 case object S16Field extends FixedSizeFieldType(2)
 case object S32Field extends FixedSizeFieldType(4)
 case object Float32Field extends FixedSizeFieldType(4)
@@ -21,6 +22,7 @@ object FieldAttribute {
   object Timestamp48 extends FieldAttribute;
   object Timestamp16 extends FieldAttribute;
   object Value extends FieldAttribute;
+  object Bcd extends FieldAttribute;
 }
 
 object FixedSizeField {
@@ -42,6 +44,14 @@ object FixedSizeField {
   val float32 = FixedSizeField("value", Float32Field, Some(FieldAttribute.Value))
   val float64 = FixedSizeField("value", Float64Field, Some(FieldAttribute.Value))
 
+  // unsigned integer values
+  val uint8 = FixedSizeField("value", UInt8Field, Some(FieldAttribute.Value))
+
+  // binary-coded decimal values
+  val bcd16 = FixedSizeField("value", UInt16Field, Some(FieldAttribute.Bcd))
+  val bcd32 = FixedSizeField("value", UInt32Field, Some(FieldAttribute.Bcd))
+  val bcd64 = FixedSizeField("value", UInt64Field, Some(FieldAttribute.Bcd))
+
   //enums
   val commandStatus = FixedSizeField("status", EnumFieldType(CommandStatus))
 
@@ -93,6 +103,13 @@ sealed case class FixedSizeField(name: String, typ: FixedSizeFieldType, attr: Op
       case _ => false
     }
   }
+
+  def isBcd : Boolean = {
+    attr match {
+      case Some(FieldAttribute.Bcd) => true
+      case _ => false
+    }
+  }
 }
 
 sealed case class VariableField(name: String) extends Field
@@ -7,6 +7,7 @@ object ObjectGroup {
   def allVariations : List[Variation] = all.flatMap(g => g.variations)
 
   val all: List[ObjectGroup] = List(
+    Group0,
     Group1,
     Group2,
     Group3,
@@ -14,29 +15,40 @@ object ObjectGroup {
     Group10,
     Group11,
     Group12,
-    //Group13, TODO
+    Group13,
     Group20,
     Group21,
     Group22,
     Group23,
     Group30,
+    Group31,
     Group32,
+    Group33,
+    Group34,
     Group40,
     Group41,
     Group42,
-    //Group43, TODO
+    Group43,
     Group50,
     Group51,
     Group52,
     Group60,
-    //Group70,
+    Group70,
     Group80,
+    Group85,
+    Group86,
+    Group87,
+    Group88,
+    Group90,
+    Group91,
+    Group101,
+    Group102,
     Group110,
     Group111,
-    /*
     Group112,
     Group113,
     Group120,
+    /*
     Group121,
     Group122
     */
@@ -63,8 +75,13 @@ object GroupType {
   object StaticCounter extends Static
   object StaticFrozenCounter extends Static
   object StaticAnalog extends Static
+  object StaticFrozenAnalog extends Static
   object StaticAnalogOutputStatus extends Static
   object StaticOctetString extends Static
+  object StaticAnalogInputDeadband extends Static
+  object StaticDataSet extends Static
+  object StaticBcd extends Static
+  object StaticUnsignedInteger extends Static
 
   object BinaryEvent extends Event
   object BinaryOutputEvent extends Event
@@ -73,17 +90,24 @@ object GroupType {
   object CounterEvent extends Event
   object FrozenCounterEvent extends Event
   object AnalogEvent extends Event
+  object FrozenAnalogEvent extends Event
   object AnalogOutputEvent extends Event
   object AnalogOutputCommandEvent extends Event
   object OctetStringEvent extends Event
+  object DataSetEvent extends Event
   object VirtualTerminalEvent extends Event
 
+  object DeviceAttributes extends GroupType
+  object DataSetDefinition extends GroupType
   object Command extends GroupType
   object Time extends GroupType
   object ClassData extends GroupType
   object FileControl extends GroupType
+  object ApplicationIdentifier extends GroupType
+  object OperationStatus extends GroupType
   object InternalIndications extends GroupType
   object VirtualTerminalOutput extends GroupType
+  object Authentication extends GroupType
 }
 
 trait ObjectGroup {
@@ -15,7 +15,7 @@ trait Variation {
 
   final def id: Id = Id(group, variation)
 
-  final def name: String = s"${parent.name}Var${variation}"
+  final def name: String = s"${parent.name}Var${variation & 0xFF}"
 
   final def fullDesc: String = s"${parent.desc} - ${desc}"
 
@@ -39,6 +39,10 @@ class ClassData(g: ObjectGroup, v: Byte, desc: String) extends BasicGroupVariati
 
 class SizedByVariation(g: ObjectGroup, v: Byte) extends BasicGroupVariation(g, v, "Sized by variation")
 
+class AllAttributesRequest(g: ObjectGroup) extends BasicGroupVariation(g, 254.toByte, "Non-specific all attributes request")
+
+class SpecificAttribute(g: ObjectGroup) extends BasicGroupVariation(g, 0, "Specific attribute")
+
 abstract class DefaultVariableSize(g: ObjectGroup, v: Byte, description: String) extends BasicGroupVariation(g, v, description)
 
 class SingleBitField(g: ObjectGroup, v: Byte, description: String) extends BasicGroupVariation(g, v, description)
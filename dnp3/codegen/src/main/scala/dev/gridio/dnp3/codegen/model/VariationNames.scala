@@ -25,6 +25,8 @@ object VariationNames {
   val bit16WithoutFlag = "16-bit Without Flag"
   val bit32WithFlagTime = "32-bit With Flag and Time"
   val bit16WithFlagTime = "16-bit With Flag and Time"
+  val bit32WithFlagTimeOfFreeze = "32-bit With Flag and Time-of-Freeze"
+  val bit16WithFlagTimeOfFreeze = "16-bit With Flag and Time-of-Freeze"
   val singlePrecision = "Single-precision"
   val doublePrecision = "Double-precision"
   val singlePrecisionWithTime = "Single-precision With Time"
@@ -0,0 +1,36 @@
+package dev.gridio.dnp3.codegen.model.enums.protocol
+
+import dev.gridio.dnp3.codegen.model._
+
+object FileStatus extends EnumModel {
+
+  override def render: IntRender = IntRender.Base10
+
+  override def captureUnknownValues: Boolean = true
+
+  override def comments : List[String] = List(
+    "Status code used in file-control objects (g70v4 and g70v6)"
+  )
+
+  override def name: String = "FileStatus"
+
+  override def values: List[EnumValue] = List(
+    EnumValue("Success", 0, "requested operation was successful"),
+    EnumValue("PermissionDenied", 1, "permission was denied due to improper authentication key, user name, or password"),
+    EnumValue("InvalidMode", 2, "an unsupported or unknown operation mode was requested"),
+    EnumValue("FileNotFound", 3, "requested file does not exist"),
+    EnumValue("FileLocked", 4, "requested file is already in use by another user"),
+    EnumValue("TooManyOpen", 5, "file could not be opened because of limitations on the number of open files"),
+    EnumValue("InvalidHandle", 6, "there is no file opened with the handle in the request"),
+    EnumValue("WriteBlockSize", 7, "outstation is unable to negotiate a suitable write block size"),
+    EnumValue("CommLost", 8, "communications were lost or cannot be established with the end device where the file resides"),
+    EnumValue("CannotAbort", 9, "an abort request was unsuccessful because the outstation is unable or not programmed to abort"),
+    EnumValue("NotOpened", 16, "file handle does not reference an opened file"),
+    EnumValue("HandleExpired", 17, "file closed due to inactivity timeout"),
+    EnumValue("BufferOverrun", 18, "too much file data was received for outstation to process"),
+    EnumValue("Fatal", 19, "an error happened in the file processing that prevents any further activity with this file"),
+    EnumValue("BlockSeq", 20, "the block number did not have the expected sequence number"),
+    EnumValue("Undefined", 255, "some other error not listed above occurred")
+  )
+
+}
@@ -44,8 +44,12 @@ object FunctionCode extends EnumModel {
     EnumValue("GetFileInfo", 28, "Outstation shall retrieve information about a file"),
     EnumValue("AuthenticateFile", 29, "Outstation shall return a file authentication key"),
     EnumValue("AbortFile", 30, "Outstation shall abort a file transfer operation"),
+    EnumValue("ActivateConfig", 31, "Outstation shall activate the configurations specified by the objects in the request"),
+    EnumValue("AuthenticateRequest", 32, "Master sends this to an outstation to perform secure authentication, e.g. a challenge reply or session key change"),
+    EnumValue("AuthenticateRequestNoResponse", 33, "Same as AuthenticateRequest but outstation shall not send a response"),
     EnumValue("Response", 129, "Master shall interpret this fragment as an Application Layer response to an ApplicationLayer request"),
     EnumValue("UnsolicitedResponse", 130, "Master shall interpret this fragment as an unsolicited response that was not prompted by an explicit request"),
+    EnumValue("AuthenticateResponse", 131, "Master shall interpret this fragment as a secure authentication response, e.g. a challenge or session key status"),
   )
 
 }
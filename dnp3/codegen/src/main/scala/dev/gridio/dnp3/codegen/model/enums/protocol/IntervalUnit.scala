@@ -0,0 +1,31 @@
+package dev.gridio.dnp3.codegen.model.enums.protocol
+
+import dev.gridio.dnp3.codegen.model._
+
+object IntervalUnit extends EnumModel {
+
+  override def render: IntRender = IntRender.Base10
+
+  override def captureUnknownValues: Boolean = true
+
+  override def comments : List[String] = List(
+    "Units of the interval in an indexed absolute time and long interval object (g50v4)"
+  )
+
+  override def name: String = "IntervalUnit"
+
+  override def values: List[EnumValue] = List(
+    EnumValue("None", 0, "the outstation does not repeat the action regardless of the interval"),
+    EnumValue("Milliseconds", 1, "interval is a count of milliseconds"),
+    EnumValue("Seconds", 2, "interval is a count of seconds"),
+    EnumValue("Minutes", 3, "interval is a count of minutes"),
+    EnumValue("Hours", 4, "interval is a count of hours"),
+    EnumValue("Days", 5, "interval is a count of days"),
+    EnumValue("Weeks", 6, "interval is a count of weeks"),
+    EnumValue("Months", 7, "interval is a count of months on the same day of the month"),
+    EnumValue("MonthsSameDayOfWeekFromStart", 8, "interval is a count of months on the same day of the week counted from the start of the month"),
+    EnumValue("MonthsSameDayOfWeekFromEnd", 9, "interval is a count of months on the same day of the week counted from the end of the month"),
+    EnumValue("Seasons", 10, "interval is a count of seasons")
+  )
+
+}
@@ -0,0 +1,17 @@
+package dev.gridio.dnp3.codegen.model.groups
+
+import dev.gridio.dnp3.codegen.model._
+
+object Group0 extends ObjectGroup {
+  def variations: List[Variation] = List(Group0Var254, Group0AnyVar)
+
+  def group: Byte = 0
+
+  def desc: String = "Device Attributes"
+
+  override def groupType: GroupType = GroupType.DeviceAttributes
+}
+
+object Group0Var254 extends AllAttributesRequest(Group0)
+
+object Group0AnyVar extends SpecificAttribute(Group0)
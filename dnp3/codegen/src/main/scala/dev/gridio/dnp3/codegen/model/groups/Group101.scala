@@ -0,0 +1,23 @@
+package dev.gridio.dnp3.codegen.model.groups
+
+import dev.gridio.dnp3.codegen.model._
+import dev.gridio.dnp3.codegen.model.FixedSizeField._
+
+// binary-coded decimal integer
+object Group101 extends ObjectGroup {
+  def variations: List[Variation] = List(Group101Var0, Group101Var1, Group101Var2, Group101Var3)
+
+  def group: Byte = 101
+
+  def desc: String = "BCD Integer"
+
+  override def groupType: GroupType = GroupType.StaticBcd
+}
+
+object Group101Var0 extends AnyVariation(Group101, 0)
+
+object Group101Var1 extends FixedSize(Group101, 1, "Small (4 digits)")(bcd16)
+
+object Group101Var2 extends FixedSize(Group101, 2, "Medium (8 digits)")(bcd32)
+
+object Group101Var3 extends FixedSize(Group101, 3, "Large (16 digits)")(bcd64)
@@ -5,7 +5,7 @@ import dev.gridio.dnp3.codegen.model.FixedSizeField._
 import dev.gridio.dnp3.codegen.model.VariationNames._
 
 object Group12 extends ObjectGroup {
-  def variations: List[Variation] = List(Group12Var0, Group12Var1)
+  def variations: List[Variation] = List(Group12Var0, Group12Var1, Group12Var2, Group12Var3)
 
   def group: Byte = 12
 
@@ -23,3 +23,13 @@ object Group12Var1 extends FixedSize(Group12, 1, crob)(
   FixedSizeField("off_time", UInt32Field),
   commandStatus
 )
+
+object Group12Var2 extends FixedSize(Group12, 2, "Pattern Control Block")(
+  FixedSizeField("code", CustomFieldTypeU8("ControlCode")),
+  FixedSizeField("count", UInt8Field),
+  FixedSizeField("on_time", UInt32Field),
+  FixedSizeField("off_time", UInt32Field),
+  commandStatus
+)
+
+object Group12Var3 extends SingleBitField(Group12, 3, "Pattern Mask")
@@ -0,0 +1,45 @@
+package dev.gridio.dnp3.codegen.model.groups
+
+import dev.gridio.dnp3.codegen.model._
+
+// secure authentication
+object Group120 extends ObjectGroup {
+
+  def variations: List[Variation] = List(
+    Group120Var1,
+    Group120Var2,
+    Group120Var3,
+    Group120Var4,
+    Group120Var5,
+    Group120Var6,
+    Group120Var7,
+    Group120Var9
+  )
+
+  def group: Byte = 120
+
+  def desc: String = "Authentication"
+
+  override def groupType: GroupType = GroupType.Authentication
+}
+
+object Group120Var1 extends DefaultVariableSize(Group120, 1, "Challenge")
+
+object Group120Var2 extends DefaultVariableSize(Group120, 2, "Reply")
+
+object Group120Var3 extends FixedSize(Group120, 3, "Aggressive Mode Request")(
+  FixedSizeField("challenge_seq", UInt32Field),
+  FixedSizeField("user", UInt16Field)
+)
+
+object Group120Var4 extends FixedSize(Group120, 4, "Session Key Status Request")(
+  FixedSizeField("user", UInt16Field)
+)
+
+object Group120Var5 extends DefaultVariableSize(Group120, 5, "Session Key Status")
+
+object Group120Var6 extends DefaultVariableSize(Group120, 6, "Session Key Change")
+
+object Group120Var7 extends DefaultVariableSize(Group120, 7, "Error")
+
+object Group120Var9 extends DefaultVariableSize(Group120, 9, "HMAC")
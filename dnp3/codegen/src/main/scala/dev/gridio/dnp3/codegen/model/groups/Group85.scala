@@ -0,0 +1,17 @@
+package dev.gridio.dnp3.codegen.model.groups
+
+import dev.gridio.dnp3.codegen.model._
+
+// data set prototypes
+object Group85 extends ObjectGroup {
+
+  def variations: List[Variation] = List(Group85Var1)
+
+  def group: Byte = 85
+
+  def desc: String = "Data Set Prototype"
+
+  override def groupType: GroupType = GroupType.DataSetDefinition
+}
+
+object Group85Var1 extends DefaultVariableSize(Group85, 1, "With UUID")
@@ -0,0 +1,17 @@
+package dev.gridio.dnp3.codegen.model.groups
+
+import dev.gridio.dnp3.codegen.model._
+
+// data set descriptors
+object Group86 extends ObjectGroup {
+
+  def variations: List[Variation] = List(Group86Var1)
+
+  def group: Byte = 86
+
+  def desc: String = "Data Set Descriptor"
+
+  override def groupType: GroupType = GroupType.DataSetDefinition
+}
+
+object Group86Var1 extends DefaultVariableSize(Group86, 1, "Data Set Contents")
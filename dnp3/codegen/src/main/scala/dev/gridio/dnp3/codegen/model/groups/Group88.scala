@@ -0,0 +1,17 @@
+package dev.gridio.dnp3.codegen.model.groups
+
+import dev.gridio.dnp3.codegen.model._
+
+// data set snapshot events
+object Group88 extends ObjectGroup {
+
+  def variations: List[Variation] = List(Group88Var1)
+
+  def group: Byte = 88
+
+  def desc: String = "Data Set Event"
+
+  override def groupType: GroupType = GroupType.DataSetEvent
+}
+
+object Group88Var1 extends DefaultVariableSize(Group88, 1, "Snapshot")
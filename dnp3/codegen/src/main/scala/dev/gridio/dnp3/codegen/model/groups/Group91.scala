@@ -0,0 +1,17 @@
+package dev.gridio.dnp3.codegen.model.groups
+
+import dev.gridio.dnp3.codegen.model._
+
+// status returned in response to ACTIVATE_CONFIG
+object Group91 extends ObjectGroup {
+
+  def variations: List[Variation] = List(Group91Var1)
+
+  def group: Byte = 91
+
+  def desc: String = "Status of Requested Operation"
+
+  override def groupType: GroupType = GroupType.OperationStatus
+}
+
+object Group91Var1 extends DefaultVariableSize(Group91, 1, "Activate Configuration")
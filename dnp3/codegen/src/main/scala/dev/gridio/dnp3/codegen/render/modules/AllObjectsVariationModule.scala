@@ -1,7 +1,7 @@
 package dev.gridio.dnp3.codegen.render.modules
 
 import dev.gridio.dnp3.codegen.model._
-import dev.gridio.dnp3.codegen.model.groups.{Group110, Group111}
+import dev.gridio.dnp3.codegen.model.groups.{Group110, Group111, Group113, Group12Var3}
 import dev.gridio.dnp3.codegen.render._
 
 object AllObjectsVariationModule extends Module {
@@ -18,7 +18,10 @@ object AllObjectsVariationModule extends Module {
 
     "#[derive(Copy, Clone, Debug, PartialEq)]".eol ++
       bracket("pub(crate) enum AllObjectsVariation") {
-        variations.iterator.map(v => s"${v.name},")
+        variations.iterator.map {
+          case v : SpecificAttribute => s"${v.parent.name}(u8),"
+          case v => s"${v.name},"
+        }
       }
 
   }
@@ -27,6 +30,7 @@ object AllObjectsVariationModule extends Module {
 
     def getMatcher(v: Variation) : String = v match {
       case _ : SizedByVariation =>   s"Variation::${v.parent.name}(0) => Some(AllObjectsVariation::${v.name}),"
+      case _ : SpecificAttribute => s"Variation::${v.parent.name}(x) => Some(AllObjectsVariation::${v.parent.name}(x)),"
       case _ => s"Variation::${v.name} => Some(AllObjectsVariation::${v.name}),"
     }
 
@@ -42,12 +46,19 @@ object AllObjectsVariationModule extends Module {
 
   def variations : Iterator[Variation] = {
     ObjectGroup.allVariations.iterator.collect {
-      case v : SingleBitField => v
+      case v : AllAttributesRequest => v
+      case v : SpecificAttribute => v
+      // the pattern mask (g12v3) is only valid in a control request following a g12v2
+      case v : SingleBitField if v != Group12Var3 => v
       case v : DoubleBitField => v
       case v : ClassData => v
       case v : AnyVariation if v.parent.groupType != GroupType.Command => v
       case v : FixedSize if v.parent.groupType.isStatic || v.parent.groupType.isEvent => v
-      case v if v.parent == Group110 || v.parent == Group111 => v
+      case v if v.parent == Group110 || v.parent == Group111 || v.parent == Group113 => v
+      // data sets are free-format, but may be requested using the all objects qualifier
+      case v : DefaultVariableSize if v.parent.groupType == GroupType.DataSetDefinition || v.parent.groupType.isStatic || v.parent.groupType.isEvent => v
+      // application identifiers are free-format, but the all objects qualifier selects all applications
+      case v : DefaultVariableSize if v.parent.groupType == GroupType.ApplicationIdentifier => v
     }
   }
 
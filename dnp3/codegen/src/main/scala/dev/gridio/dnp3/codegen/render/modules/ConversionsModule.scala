@@ -217,6 +217,13 @@ object ConversionsModule extends Module {
       fixedSize(isType)
     }
 
+    def frozenAnalogVariations : List[FixedSize] = {
+      def isType(fs: FixedSize) : Boolean = {
+        fs.parent.groupType == GroupType.StaticFrozenAnalog || fs.parent.groupType == GroupType.FrozenAnalogEvent
+      }
+      fixedSize(isType)
+    }
+
     def analogOutputStatusVariations : List[FixedSize] = {
       def isType(fs: FixedSize) : Boolean = {
         fs.parent.groupType == GroupType.StaticAnalogOutputStatus || fs.parent.groupType == GroupType.AnalogOutputEvent
@@ -291,13 +298,9 @@ object ConversionsModule extends Module {
       }
 
       def toVariation: Iterator[String] = {
-        if(fs.parent.groupType == GroupType.AnalogOutputCommandEvent) {
-          Iterator.empty
-        } else {
-          space ++ bracket(s"impl ToVariation<${fs.name}> for ${name}") {
-            bracket(s"fn to_variation(&self) -> ${fs.name}") {
-              if(hasDoubleValue) measToVariation else measToVariationWithCast
-            }
+        space ++ bracket(s"impl ToVariation<${fs.name}> for ${name}") {
+          bracket(s"fn to_variation(&self) -> ${fs.name}") {
+            if(hasDoubleValue) measToVariation else measToVariationWithCast
           }
         }
       }
@@ -312,10 +315,150 @@ object ConversionsModule extends Module {
 
     spaced(analogVariations.map(single("AnalogInput")).iterator) ++
     space ++
+    spaced(frozenAnalogVariations.map(single("FrozenAnalogInput")).iterator) ++
+    space ++
     spaced(analogOutputStatusVariations.map(single("AnalogOutputStatus")).iterator)
   }
 
+  private def commandEventConversions(implicit indentation: Indentation) : Iterator[String] = {
+
+    def binaryVariations : List[FixedSize] = fixedSize(_.parent.groupType == GroupType.BinaryOutputCommandEvent)
+
+    def analogVariations : List[FixedSize] = fixedSize(_.parent.groupType == GroupType.AnalogOutputCommandEvent)
+
+    def binary(fs: FixedSize) : Iterator[String] = {
+      def measToVariation : Iterator[String] = {
+        def fieldGetter(field: FixedSizeField): String = {
+          field.typ match {
+            case TimestampField => "self.time.into()"
+            case UInt8Field if field.isFlags => "self.get_wire_flags()"
+          }
+        }
+
+        bracket(s"${fs.name}") {
+          fs.fields.map(f => s"${f.name}: ${fieldGetter(f)},").iterator
+        }
+      }
+
+      bracket(s"impl From<${fs.name}> for BinaryOutputCommandEvent") {
+        bracket(s"fn from(v: ${fs.name}) -> Self") {
+          bracket("BinaryOutputCommandEvent") {
+            "commanded_state : Flags::new(v.flags).state(),".eol ++
+            "status: CommandStatus::from(v.flags & 0x7F),".eol ++
+            s"time : ${timeConversion(fs)},".eol
+          }
+        }
+      } ++ space ++
+      bracket(s"impl ToVariation<${fs.name}> for BinaryOutputCommandEvent") {
+        bracket(s"fn to_variation(&self) -> ${fs.name}") {
+          measToVariation
+        }
+      }
+    }
+
+    def analog(fs: FixedSize) : Iterator[String] = {
+      val valueType = fs.fields.find(_.isValue).get.typ
+
+      def cast : String = valueType match {
+        case Float64Field => ""
+        case _ => " as f64"
+      }
+
+      def fieldGetter(field: FixedSizeField): String = {
+        field.typ match {
+          case TimestampField => "self.time.into()"
+          case EnumFieldType(_) => s"self.${field.name}"
+          case Float64Field if field.isValue => "self.commanded_value"
+          case _ if field.isValue => "_wire_value"
+        }
+      }
+
+      def measToVariation : Iterator[String] = {
+        def conversion : Iterator[String] = valueType match {
+          case Float64Field => Iterator.empty
+          case S16Field => "let (_wire_flags, _wire_value) = self.to_i16();".eol
+          case S32Field => "let (_wire_flags, _wire_value) = self.to_i32();".eol
+          case Float32Field => "let (_wire_flags, _wire_value) = self.to_f32();".eol
+        }
+
+        conversion ++ bracket(s"${fs.name}") {
+          fs.fields.map(f => s"${f.name}: ${fieldGetter(f)},").iterator
+        }
+      }
+
+      bracket(s"impl From<${fs.name}> for AnalogOutputCommandEvent") {
+        bracket(s"fn from(v: ${fs.name}) -> Self") {
+          bracket("AnalogOutputCommandEvent") {
+            s"commanded_value : v.value${cast},".eol ++
+            "status: v.status,".eol ++
+            s"time : ${timeConversion(fs)},".eol
+          }
+        }
+      } ++ space ++
+      bracket(s"impl ToVariation<${fs.name}> for AnalogOutputCommandEvent") {
+        bracket(s"fn to_variation(&self) -> ${fs.name}") {
+          measToVariation
+        }
+      }
+    }
+
+    spaced(binaryVariations.map(binary).iterator) ++
+    space ++
+    spaced(analogVariations.map(analog).iterator)
+  }
+
+  private def integerConversions(implicit indentation: Indentation) : Iterator[String] = {
+
+    def bcdVariations : List[FixedSize] = fixedSize(_.parent.groupType == GroupType.StaticBcd)
+
+    def unsignedVariations : List[FixedSize] = fixedSize(_.parent.groupType == GroupType.StaticUnsignedInteger)
+
+    def bcd(fs: FixedSize) : Iterator[String] = {
+      val field = fs.fields.find(f => f.isBcd).get
+      val (digits, toMeas, toVariation) = field.typ match {
+        case UInt64Field => ("16", "", "")
+        case UInt32Field => ("8", " as u64", " as u32")
+        case UInt16Field => ("4", " as u64", " as u16")
+      }
+
+      bracket(s"impl From<${fs.name}> for Bcd") {
+        bracket(s"fn from(v: ${fs.name}) -> Self") {
+          s"Bcd::decode(v.value${toMeas})".eol
+        }
+      } ++ space ++
+      bracket(s"impl ToVariation<${fs.name}> for Bcd") {
+        bracket(s"fn to_variation(&self) -> ${fs.name}") {
+          bracket(s"${fs.name}") {
+            s"value: self.encode(${digits})${toVariation},".eol
+          }
+        }
+      }
+    }
+
+    def unsigned(fs: FixedSize) : Iterator[String] = {
+      bracket(s"impl From<${fs.name}> for UnsignedInteger") {
+        bracket(s"fn from(v: ${fs.name}) -> Self") {
+          bracket("UnsignedInteger") {
+            "value: v.value,".eol
+          }
+        }
+      } ++ space ++
+      bracket(s"impl ToVariation<${fs.name}> for UnsignedInteger") {
+        bracket(s"fn to_variation(&self) -> ${fs.name}") {
+          bracket(s"${fs.name}") {
+            "value: self.value,".eol
+          }
+        }
+      }
+    }
+
+    spaced(bcdVariations.map(bcd).iterator) ++
+    space ++
+    spaced(unsignedVariations.map(unsigned).iterator)
+  }
+
   override def lines(implicit indentation: Indentation): Iterator[String] = {
+    "use crate::app::control::CommandStatus;".eol ++
     "use crate::app::measurement::*;".eol ++
     "use crate::app::variations::*;".eol ++
     space ++
@@ -325,7 +468,11 @@ object ConversionsModule extends Module {
     space ++
     counterConversions ++
     space ++
-    analogConversions
+    analogConversions ++
+    space ++
+    commandEventConversions ++
+    space ++
+    integerConversions
   }
 
 
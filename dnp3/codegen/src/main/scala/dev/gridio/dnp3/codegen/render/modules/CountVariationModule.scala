@@ -1,7 +1,7 @@
 package dev.gridio.dnp3.codegen.render.modules
 
 import dev.gridio.dnp3.codegen.model._
-import dev.gridio.dnp3.codegen.model.groups.{Group111, Group60Var1}
+import dev.gridio.dnp3.codegen.model.groups.{Group111, Group12Var2, Group20Var0, Group60Var1}
 import dev.gridio.dnp3.codegen.render._
 
 object CountVariationModule extends Module {
@@ -23,7 +23,7 @@ object CountVariationModule extends Module {
 
     def definition(v : Variation): Iterator[String] = {
       v match {
-        case v : FixedSize if v.parent.groupType == GroupType.Time => s"${v.name}(CountSequence<'a, ${v.name}>),".eol
+        case v : FixedSize if v.parent.groupType == GroupType.Time || v == Group12Var2 => s"${v.name}(CountSequence<'a, ${v.name}>),".eol
         case _ : SizedByVariation => {
             s"${v.parent.name}Var0,".eol ++
             s"${v.parent.name}VarX(u8),".eol
@@ -43,7 +43,7 @@ object CountVariationModule extends Module {
 
     def parseMatcher(v : Variation) : Iterator[String] = {
       v match {
-        case v : FixedSize if v.parent.groupType == GroupType.Time => s"Variation::${v.name} => Ok(CountVariation::${v.name}(CountSequence::parse(count, cursor)?)),".eol
+        case v : FixedSize if v.parent.groupType == GroupType.Time || v == Group12Var2 => s"Variation::${v.name} => Ok(CountVariation::${v.name}(CountSequence::parse(count, cursor)?)),".eol
         case _ : SizedByVariation => {
             s"Variation::${v.parent.name}(0) => Ok(CountVariation::${v.parent.name}Var0),".eol ++
             s"Variation::${v.parent.name}(x) => Ok(CountVariation::${v.parent.name}VarX(x)),".eol
@@ -53,7 +53,7 @@ object CountVariationModule extends Module {
     }
     def fmtMatcher(v : Variation) : Iterator[String] = {
       v match {
-        case v : FixedSize if v.parent.groupType == GroupType.Time => s"CountVariation::${v.name}(seq) => format_count_of_items(f, seq.iter()),".eol
+        case v : FixedSize if v.parent.groupType == GroupType.Time || v == Group12Var2 => s"CountVariation::${v.name}(seq) => format_count_of_items(f, seq.iter()),".eol
         case _ : SizedByVariation => {
             s"CountVariation::${v.parent.name}Var0 => Ok(()),".eol ++
             s"CountVariation::${v.parent.name}VarX(_) => Ok(()),".eol
@@ -83,7 +83,12 @@ object CountVariationModule extends Module {
         case v : AnyVariation if v.parent.groupType.isEvent => Some(v)
         case v : FixedSize if v.parent.groupType.isEvent => Some(v)
         case v : SizedByVariation if v.parent.groupType.isEvent => Some(v)
+        case v : DefaultVariableSize if v.parent.groupType.isEvent => Some(v)
         case v : ClassData if v != Group60Var1 => Some(v)
+        // limited count of counters in freeze requests
+        case Group20Var0 => Some(Group20Var0)
+        // pattern control block
+        case Group12Var2 => Some(Group12Var2)
         case v : FixedSize if v.parent.groupType == GroupType.Time => Some(v)
         case _ => None
       }
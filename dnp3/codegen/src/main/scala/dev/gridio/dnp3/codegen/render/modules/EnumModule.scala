@@ -6,7 +6,7 @@ import dev.gridio.dnp3.codegen.render._
 
 object EnumModule {
   def application : Module = {
-    new EnumModule(List(FunctionCode, QualifierCode))
+    new EnumModule(List(FunctionCode, QualifierCode, FileStatus, IntervalUnit))
   }
   def control : Module = {
     new EnumModule(List(CommandStatus, OpType, TripCloseCode))
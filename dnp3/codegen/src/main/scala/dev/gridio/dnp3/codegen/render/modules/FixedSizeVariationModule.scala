@@ -29,6 +29,7 @@ object FixedSizeVariationModule extends Module {
       case UInt8Field => "u8"
       case UInt16Field => "u16"
       case UInt32Field => "u32"
+      case UInt64Field => "u64"
       case S16Field => "i16"
       case S32Field => "i32"
       case Float32Field => "f32"
@@ -45,6 +46,7 @@ object FixedSizeVariationModule extends Module {
       case UInt8Field => "u8"
       case UInt16Field => "u16_le"
       case UInt32Field => "u32_le"
+      case UInt64Field => "u64_le"
       case S16Field => "i16_le"
       case S32Field => "i32_le"
       case Float32Field => "f32_le"
@@ -95,8 +97,13 @@ object FixedSizeVariationModule extends Module {
       }
     }
 
+    // BCD values are displayed in hex so that each nibble reads as a decimal digit
+    def fieldDisplay(f: FixedSizeField): String = {
+      if(f.isBcd) s"{:0${f.typ.numBytes * 2}X}" else fieldDisplayType(f.typ)
+    }
+
     def fieldNames : String = {
-      quoted(gv.fields.map( f=> s"${f.name}: ${fieldDisplayType(f.typ)}").mkString(" "))
+      quoted(gv.fields.map( f=> s"${f.name}: ${fieldDisplay(f)}").mkString(" "))
     }
 
     def fieldArgExpression(f: FixedSizeField) : String = {
@@ -107,6 +114,7 @@ object FixedSizeVariationModule extends Module {
         def counter = "CounterFlagFormatter"
         def binaryOutputStatus = "BinaryOutputStatusFlagFormatter"
         def doubleBitBinary = "DoubleBitBinaryFlagFormatter"
+        def binaryCommandEvent = "BinaryCommandEventFlagFormatter"
 
         gv.parent.groupType match {
           case GroupType.StaticBinary => binary
@@ -115,6 +123,8 @@ object FixedSizeVariationModule extends Module {
           case GroupType.StaticAnalogOutputStatus => analog
           case GroupType.AnalogEvent => analog
           case GroupType.StaticAnalog => analog
+          case GroupType.FrozenAnalogEvent => analog
+          case GroupType.StaticFrozenAnalog => analog
           case GroupType.StaticCounter => counter
           case GroupType.CounterEvent => counter
           case GroupType.StaticFrozenCounter => counter
@@ -124,7 +134,7 @@ object FixedSizeVariationModule extends Module {
           case GroupType.StaticDoubleBinary => doubleBitBinary
           case GroupType.DoubleBinaryEvent => doubleBitBinary
           case GroupType.AnalogOutputCommandEvent => analog
-          case GroupType.BinaryOutputCommandEvent => binary
+          case GroupType.BinaryOutputCommandEvent => binaryCommandEvent
           case _ => throw new Exception("unhandled group type")
         }
       }
@@ -190,7 +200,14 @@ object FixedSizeVariationModule extends Module {
     def implWrite : Iterator[String] = {
         bracket(s"fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError>") {
             gv.fields.iterator.flatMap { f =>
-              writeField(f).eol
+              f.typ match {
+                // the cursor has no 64-bit writer, so the value is written as two 32-bit words
+                case UInt64Field => {
+                  s"cursor.write_u32_le(self.${f.name} as u32)?;".eol ++
+                  s"cursor.write_u32_le((self.${f.name} >> 32) as u32)?;".eol
+                }
+                case _ => writeField(f).eol
+              }
             } ++ "Ok(())".eol
         }
     }
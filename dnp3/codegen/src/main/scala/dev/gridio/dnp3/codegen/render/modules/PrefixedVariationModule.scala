@@ -29,7 +29,7 @@ object PrefixedVariationModule extends Module {
     def definition(v : Variation) : Iterator[String] = {
       v match {
         case _ : SizedByVariation =>{
-          "Group111VarX(u8, PrefixedBytesSequence<'a, I>),".eol
+          s"${v.parent.name}VarX(u8, PrefixedBytesSequence<'a, I>),".eol
         }
         case _ => s"${v.name}(CountSequence<'a, Prefix<I, ${v.name}>>),".eol
       }
@@ -45,9 +45,9 @@ object PrefixedVariationModule extends Module {
   private def enumImpl(implicit indent: Indentation) : Iterator[String] = {
 
     def parseMatcher(v: Variation) : Iterator[String] = v match {
-      case Group111AnyVar => {
-        "Variation::Group111(0) => Err(ObjectParseError::ZeroLengthOctetData),".eol ++
-          "Variation::Group111(x) => Ok(PrefixedVariation::Group111VarX(x, PrefixedBytesSequence::parse(x, count, cursor)?)),".eol
+      case _ : SizedByVariation => {
+        s"Variation::${v.parent.name}(0) => Err(ObjectParseError::ZeroLengthOctetData),".eol ++
+          s"Variation::${v.parent.name}(x) => Ok(PrefixedVariation::${v.parent.name}VarX(x, PrefixedBytesSequence::parse(x, count, cursor)?)),".eol
       }
       case _ => {
         s"Variation::${v.name} => Ok(PrefixedVariation::${v.name}(CountSequence::parse(count, cursor)?)),".eol
@@ -83,18 +83,40 @@ object PrefixedVariationModule extends Module {
         case GroupType.CounterEvent => "counter"
         case GroupType.FrozenCounterEvent => "frozen_counter"
         case GroupType.AnalogEvent => "analog_input"
+        case GroupType.FrozenAnalogEvent => "frozen_analog_input"
         case GroupType.AnalogOutputEvent => "analog_output_status"
-        case GroupType.AnalogOutputCommandEvent => "analog_output_command"
-        case GroupType.BinaryOutputCommandEvent => "binary_output_command"
+        case GroupType.AnalogOutputCommandEvent => "analog_output_command_event"
+        case GroupType.BinaryOutputCommandEvent => "binary_output_command_event"
         case _ => throw new Exception("unhandled variation")
       }
 
       v match {
+        case _ if v.parent.groupType == GroupType.StaticAnalogInputDeadband => {
+          bracket(s"PrefixedVariation::${v.name}(seq) =>") {
+            parenSemi("handler.handle_analog_input_deadband") {
+              "self.get_header_info(),".eol ++
+              "&mut seq.iter().map(|x| (x.value.value.into(), x.index.widen_to_u16()))".eol
+            } ++ "true".eol
+          }
+        }
         case _ if v.parent.groupType == GroupType.Command => {
           bracket(s"PrefixedVariation::${v.name}(_) =>") {
             "false // command".eol
           }
         }
+        case Group50Var4 => {
+          bracket(s"PrefixedVariation::${v.name}(_) =>") {
+            "false // time and interval".eol
+          }
+        }
+        case Group110AnyVar => {
+          bracket(s"PrefixedVariation::Group110VarX(_, seq) =>") {
+            parenSemi("handler.handle_octet_string") {
+              "self.get_header_info(),".eol ++
+              "&mut seq.iter().map(|x| (x.0, x.1.widen_to_u16()))".eol
+            } ++ "true".eol
+          }
+        }
         case Group111AnyVar => {
           bracket(s"PrefixedVariation::Group111VarX(_, seq) =>") {
             parenSemi("handler.handle_octet_string") {
@@ -103,6 +125,13 @@ object PrefixedVariationModule extends Module {
             } ++ "true".eol
           }
         }
+        case Group112AnyVar => {
+          "// virtual terminal output is routed by the master to a per-port stream".eol ++
+          "PrefixedVariation::Group112VarX(_, _) => false,".eol
+        }
+        case Group113AnyVar => {
+          "PrefixedVariation::Group113VarX(_, _) => false,".eol
+        }
         case Group2Var3 => {
           bracket(s"PrefixedVariation::${v.name}(seq) =>") {
             parenSemi("handler.handle_binary_input") {
@@ -156,8 +185,12 @@ object PrefixedVariationModule extends Module {
 
   def variations : Iterator[Variation] = {
     ObjectGroup.allVariations.iterator.collect {
-      case v : SizedByVariation if v.parent == Group111 => v
-      case v : FixedSize if v.parent.groupType.isEvent || v.parent.groupType == GroupType.Command => v
+      case v : SizedByVariation if v.parent == Group110 || v.parent == Group111 || v.parent == Group112 || v.parent == Group113 => v
+      // pattern control blocks (g12v2) use a count qualifier instead of a prefix
+      case v : FixedSize if v != Group12Var2 && (v.parent.groupType.isEvent || v.parent.groupType == GroupType.Command) => v
+      case v : FixedSize if v.parent.groupType == GroupType.StaticAnalogInputDeadband => v
+      // indexed time and interval written by the master
+      case Group50Var4 => Group50Var4
     }
   }
 
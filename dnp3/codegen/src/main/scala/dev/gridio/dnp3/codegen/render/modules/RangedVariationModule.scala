@@ -1,7 +1,7 @@
 package dev.gridio.dnp3.codegen.render.modules
 
 import dev.gridio.dnp3.codegen.model._
-import dev.gridio.dnp3.codegen.model.groups.{Group10Var1, Group110AnyVar, Group1Var1, Group80Var1}
+import dev.gridio.dnp3.codegen.model.groups.{Group10Var1, Group12Var3, Group110AnyVar, Group1Var1, Group80Var1}
 import dev.gridio.dnp3.codegen.render._
 
 object RangedVariationModule extends Module {
@@ -15,6 +15,7 @@ object RangedVariationModule extends Module {
       "use crate::app::parse::bit::{BitSequence, DoubleBitSequence};".eol ++
       "use crate::master::{ReadHandler, HeaderInfo};".eol ++
       "use crate::app::ObjectParseError;".eol ++
+      "use crate::app::Attribute;".eol ++
       space ++
       "use scursor::ReadCursor;".eol ++
       space ++
@@ -26,6 +27,8 @@ object RangedVariationModule extends Module {
   private def rangedVariationEnumDefinition(implicit indent: Indentation) : Iterator[String] = {
 
     def getVarDefinition(v: Variation) : Iterator[String] = v match {
+      case _ : AllAttributesRequest => s"${v.name},".eol
+      case _ : SpecificAttribute => s"${v.parent.name}(u8, Option<Attribute<'a>>),".eol
       case _ : SingleBitField => s"${v.name}(BitSequence<'a>),".eol
       case _ : DoubleBitField => s"${v.name}(DoubleBitSequence<'a>),".eol
       case _ : AnyVariation => s"${v.name},".eol
@@ -56,6 +59,12 @@ object RangedVariationModule extends Module {
     }
 
     def getNonReadMatcher(v: Variation): Iterator[String] = v match {
+      case _ : AllAttributesRequest => Iterator.empty // only valid in READ requests
+
+      case _ : SpecificAttribute => {
+        s"Variation::${v.parent.name}(x) => Ok(RangedVariation::${v.parent.name}(x, Some(Attribute::parse_from_range(x, range, cursor)?))),".eol
+      }
+
       case _ : SingleBitField =>  {
         s"Variation::${v.name} => Ok(RangedVariation::${v.name}(BitSequence::parse(range, cursor)?)),".eol
       }
@@ -74,6 +83,10 @@ object RangedVariationModule extends Module {
     }
 
     def getReadMatcher(v: Variation): Iterator[String] = v match {
+      case _ : AllAttributesRequest => s"Variation::${v.name} => Ok(RangedVariation::${v.name}),".eol
+
+      case _ : SpecificAttribute => s"Variation::${v.parent.name}(x) => Ok(RangedVariation::${v.parent.name}(x, None)),".eol
+
       case _ : SingleBitField =>  {
         s"Variation::${v.name} => Ok(RangedVariation::${v.name}(BitSequence::empty())),".eol
       }
@@ -88,6 +101,11 @@ object RangedVariationModule extends Module {
     }
 
     def getFmtMatcher(v: Variation): Iterator[String] = v match {
+      case _ : AllAttributesRequest => s"RangedVariation::${v.name} => Ok(()),".eol
+      case _ : SpecificAttribute => {
+        s"RangedVariation::${v.parent.name}(_, None) => Ok(()),".eol ++
+          s"RangedVariation::${v.parent.name}(_, Some(attr)) => write!(f, \"\\n{}\", attr.value),".eol
+      }
       case _ : AnyVariation => s"RangedVariation::${v.name} => Ok(()),".eol
       case _ : SizedByVariation => {
         s"RangedVariation::${v.parent.name}Var0 => Ok(()),".eol ++
@@ -102,15 +120,24 @@ object RangedVariationModule extends Module {
         case GroupType.StaticDoubleBinary => "double_bit_binary_input"
         case GroupType.StaticBinaryOutputStatus => "binary_output_status"
         case GroupType.StaticAnalog => "analog_input"
+        case GroupType.StaticFrozenAnalog => "frozen_analog_input"
         case GroupType.StaticAnalogOutputStatus => "analog_output_status"
         case GroupType.StaticCounter => "counter"
         case GroupType.StaticFrozenCounter => "frozen_counter"
+        case GroupType.StaticBcd => "bcd"
+        case GroupType.StaticUnsignedInteger => "unsigned_integer"
         case _ => throw new Exception("unhandled variation")
       }
     }
 
     def getVariationMatcher(v: Variation): Iterator[String] = {
       v match {
+        case _ : AllAttributesRequest => {
+          s"RangedVariation::${v.name} => Variation::${v.name},".eol
+        }
+        case _ : SpecificAttribute => {
+          s"RangedVariation::${v.parent.name}(x, _) => Variation::${v.parent.name}(*x),".eol
+        }
         case _ : AnyVariation => {
           s"RangedVariation::${v.name} => Variation::${v.name},".eol
         }
@@ -129,6 +156,15 @@ object RangedVariationModule extends Module {
 
       val isEvent = v.parent.groupType.isEvent;
 
+      def deadbandExtract(v: Variation): Iterator[String] = {
+        bracket(s"RangedVariation::${v.name}(seq) =>") {
+          parenSemi("handler.handle_analog_input_deadband") {
+            s"HeaderInfo::new(self.variation(), qualifier, false, false),".eol ++
+            "&mut seq.iter().map(|(v,i)| (v.value.into(), i))".eol
+          } ++ "true".eol
+        }
+      }
+
       def simpleExtract(v: Variation): Iterator[String] = {
         bracket(s"RangedVariation::${v.name}(seq) =>") {
           parenSemi(s"handler.handle_${getMeasName(v)}") {
@@ -139,6 +175,22 @@ object RangedVariationModule extends Module {
       }
 
       v match {
+        case _ : AllAttributesRequest => {
+          bracket(s"RangedVariation::${v.name} =>") {
+            "false".eol
+          }
+        }
+        case _ : SpecificAttribute => {
+          bracket(s"RangedVariation::${v.parent.name}(_, None) =>") {
+            "false".eol
+          } ++
+          bracket(s"RangedVariation::${v.parent.name}(_, Some(attr)) =>") {
+            parenSemi("handler.handle_device_attribute") {
+              s"HeaderInfo::new(self.variation(), qualifier, false, false),".eol ++
+              "*attr".eol
+            } ++ "true".eol
+          }
+        }
         case _ : AnyVariation => {
           bracket(s"RangedVariation::${v.name} =>") {
               "false // qualifier 0x06".eol
@@ -149,9 +201,15 @@ object RangedVariationModule extends Module {
             "false // internal indications".eol
           }
         }
+        case Group12Var3 => {
+          bracket(s"RangedVariation::${v.name}(_) =>") {
+            "false // command".eol
+          }
+        }
         case Group1Var1 => simpleExtract(v)
         case Group10Var1 => simpleExtract(v)
         case _ : DoubleBitField => simpleExtract(v)
+        case _ : FixedSize if v.parent.groupType == GroupType.StaticAnalogInputDeadband => deadbandExtract(v)
         case _ : FixedSize => simpleExtract(v)
         case Group110AnyVar => {
           bracket(s"RangedVariation::${v.parent.name}Var0 =>") {
@@ -199,6 +257,8 @@ object RangedVariationModule extends Module {
   def variations : List[Variation] = {
     ObjectGroup.allVariations.flatMap { v =>
       v match {
+        case _ : AllAttributesRequest => Some(v)
+        case _ : SpecificAttribute => Some(v)
         case _ : DoubleBitField => Some(v)
         case _ : SingleBitField => Some(v)
         case v : AnyVariation if v.parent.groupType.isStatic => Some(v)
@@ -1,6 +1,6 @@
 package dev.gridio.dnp3.codegen.render.modules
 
-import dev.gridio.dnp3.codegen.model.{AnyVariation, FixedSize, ObjectGroup, SizedByVariation, Variation}
+import dev.gridio.dnp3.codegen.model.{AnyVariation, FixedSize, ObjectGroup, SizedByVariation, SpecificAttribute, Variation}
 import dev.gridio.dnp3.codegen.render._
 
 object VariationEnumModule extends Module {
@@ -13,6 +13,7 @@ object VariationEnumModule extends Module {
 
     def getVariationDefinition(v: Variation) : String = v match {
       case v : SizedByVariation => s"${v.parent.name}(u8)"
+      case v : SpecificAttribute => s"${v.parent.name}(u8)"
       case _ =>  s"${v.name}"
     }
 
@@ -42,10 +43,15 @@ object VariationEnumModule extends Module {
         if (isSizedByVariation(g)) {
           s"${g.group} => Some(Variation::${g.name}(var)),".eol
         } else {
+          // any variation not explicitly defined is a specific attribute
+          val default = g.variations.collectFirst {
+            case v : SpecificAttribute => s"_ => Some(Variation::${v.parent.name}(var)),"
+          }.getOrElse("_ => None,")
+
           bracketComma(s"${g.group} => match var") {
-            g.variations.iterator.flatMap { v =>
-              s"${v.variation} => Some(Variation::${v.name}),".eol
-            } ++ "_ => None,".eol
+            g.variations.iterator.filterNot(_.isInstanceOf[SpecificAttribute]).flatMap { v =>
+              s"${v.variation & 0xFF} => Some(Variation::${v.name}),".eol
+            } ++ default.eol
           }
         }
       }
@@ -63,8 +69,11 @@ object VariationEnumModule extends Module {
           case _ : SizedByVariation => {
             s"Variation::${v.parent.name}(x) => (${v.parent.group}, x),".eol
           }
+          case _ : SpecificAttribute => {
+            s"Variation::${v.parent.name}(x) => (${v.parent.group}, x),".eol
+          }
           case _ => {
-            s"Variation::${v.name} => (${v.group}, ${v.variation}),".eol
+            s"Variation::${v.name} => (${v.group}, ${v.variation & 0xFF}),".eol
           }
         }
       }
@@ -82,6 +91,9 @@ object VariationEnumModule extends Module {
           case _ : SizedByVariation => {
             s"Variation::${v.parent.name}(_) => ${quoted(v.fullDesc)},".eol
           }
+          case _ : SpecificAttribute => {
+            s"Variation::${v.parent.name}(_) => ${quoted(v.fullDesc)},".eol
+          }
           case _ => {
             s"Variation::${v.name} => ${quoted(v.fullDesc)},".eol
           }
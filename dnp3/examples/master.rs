@@ -142,6 +142,23 @@ impl ReadHandler for ExampleReadHandler {
         }
     }
 
+    fn handle_frozen_analog_input(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (FrozenAnalogInput, u16)>,
+    ) {
+        println!("Frozen Analog Inputs:");
+        println!("Qualifier: {}", info.qualifier);
+        println!("Variation: {}", info.variation);
+
+        for (x, idx) in iter {
+            println!(
+                "Frozen AI {}: Value={} Flags={:#04X} Time={:?}",
+                idx, x.value, x.flags.value, x.time
+            );
+        }
+    }
+
     fn handle_analog_output_status(
         &mut self,
         info: HeaderInfo,
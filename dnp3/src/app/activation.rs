@@ -0,0 +1,209 @@
+use crate::app::file::FreeFormat;
+use crate::app::measurement::OctetString;
+use crate::app::parse_error::ObjectParseError;
+use crate::app::variations::Variation;
+
+use scursor::{ReadCursor, WriteCursor, WriteError};
+
+/// Configuration referenced by an ACTIVATE_CONFIG request
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfigurationReference {
+    /// File specification string (g70v8), e.g. the path of a configuration file
+    File(String),
+    /// Octet string (g110) at the specified index, e.g. the name of a configuration
+    OctetString(u16, Box<OctetString>),
+}
+
+/// Status of a single configuration in a g91v1 object
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ActivationStatus {
+    /// status code where zero indicates success and other values are defined by the outstation
+    pub code: u8,
+    /// optional text describing the status
+    pub text: String,
+}
+
+impl ActivationStatus {
+    /// status code indicating the configuration was activated
+    pub const SUCCESS: u8 = 0;
+
+    /// construct a status from its fields
+    pub fn new(code: u8, text: String) -> Self {
+        Self { code, text }
+    }
+
+    /// successful activation without any descriptive text
+    pub fn success() -> Self {
+        Self::new(Self::SUCCESS, String::new())
+    }
+
+    /// true if the status code indicates success
+    pub fn is_success(&self) -> bool {
+        self.code == Self::SUCCESS
+    }
+}
+
+/// Status of requested operation (g91v1) returned in response to ACTIVATE_CONFIG
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ActivateConfigResponse {
+    /// time in milliseconds after which the new configuration is expected to be in effect
+    pub time_delay_ms: u32,
+    /// status of each configuration in the order they appeared in the request
+    pub statuses: Vec<ActivationStatus>,
+}
+
+impl ActivateConfigResponse {
+    /// construct a response from its fields
+    pub fn new(time_delay_ms: u32, statuses: Vec<ActivationStatus>) -> Self {
+        Self {
+            time_delay_ms,
+            statuses,
+        }
+    }
+}
+
+impl FreeFormat for ActivateConfigResponse {
+    const VARIATION: Variation = Variation::Group91Var1;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        let count = u8::try_from(self.statuses.len()).map_err(|_| WriteError::NumericOverflow)?;
+        cursor.write_u32_le(self.time_delay_ms)?;
+        cursor.write_u8(count)?;
+        for status in &self.statuses {
+            // the length includes the status code
+            let length =
+                u8::try_from(status.text.len() + 1).map_err(|_| WriteError::NumericOverflow)?;
+            cursor.write_u8(length)?;
+            cursor.write_u8(status.code)?;
+            cursor.write_bytes(status.text.as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+/// Status of Requested Operation - Activate Configuration
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group91Var1<'a> {
+    pub(crate) time_delay_ms: u32,
+    pub(crate) statuses: &'a [u8],
+}
+
+impl<'a> Group91Var1<'a> {
+    pub(crate) fn read(object: &'a [u8]) -> Result<Self, ObjectParseError> {
+        let mut cursor = ReadCursor::new(object);
+        let time_delay_ms = cursor.read_u32_le()?;
+        let count = cursor.read_u8()?;
+        let statuses = cursor.read_all();
+        let value = Self {
+            time_delay_ms,
+            statuses,
+        };
+        // validate the status elements so that they can be iterated without errors
+        let mut parsed = 0;
+        for status in value.iter() {
+            status?;
+            parsed += 1;
+        }
+        if parsed != count as usize {
+            return Err(ObjectParseError::InsufficientBytes);
+        }
+        Ok(value)
+    }
+
+    pub(crate) fn to_response(self) -> ActivateConfigResponse {
+        ActivateConfigResponse::new(
+            self.time_delay_ms,
+            self.iter()
+                .flatten()
+                .map(|(code, text)| ActivationStatus::new(code, text.to_string()))
+                .collect(),
+        )
+    }
+
+    fn iter(&self) -> StatusElements<'a> {
+        StatusElements {
+            cursor: ReadCursor::new(self.statuses),
+        }
+    }
+}
+
+/// iterator over the length-prefixed status elements of a g91v1 object
+struct StatusElements<'a> {
+    cursor: ReadCursor<'a>,
+}
+
+impl<'a> Iterator for StatusElements<'a> {
+    type Item = Result<(u8, &'a str), ObjectParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cursor.is_empty() {
+            return None;
+        }
+        Some(read_status(&mut self.cursor))
+    }
+}
+
+fn read_status<'a>(cursor: &mut ReadCursor<'a>) -> Result<(u8, &'a str), ObjectParseError> {
+    let length = cursor.read_u8()?;
+    let mut element = ReadCursor::new(cursor.read_bytes(length as usize)?);
+    let code = element.read_u8()?;
+    let text =
+        std::str::from_utf8(element.read_all()).map_err(|_| ObjectParseError::BadEncoding)?;
+    Ok((code, text))
+}
+
+impl std::fmt::Display for Group91Var1<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "time delay: {} ms", self.time_delay_ms)?;
+        for (code, text) in self.iter().flatten() {
+            write!(f, "\nstatus code: {} text: {}", code, text)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trips_status_elements() {
+        let response = ActivateConfigResponse::new(
+            5000,
+            vec![
+                ActivationStatus::success(),
+                ActivationStatus::new(4, "bad crc".to_string()),
+            ],
+        );
+
+        let mut buffer = [0; 64];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        response.write(&mut cursor).unwrap();
+        let written = cursor.written();
+
+        assert_eq!(
+            written,
+            &[
+                0x88, 0x13, 0x00, 0x00, 0x02, 0x01, 0x00, 0x08, 0x04, b'b', b'a', b'd', b' ', b'c',
+                b'r', b'c'
+            ]
+        );
+        assert_eq!(Group91Var1::read(written).unwrap().to_response(), response);
+    }
+
+    #[test]
+    fn rejects_count_that_does_not_match_status_elements() {
+        assert_eq!(
+            Group91Var1::read(&[0x00, 0x00, 0x00, 0x00, 0x02, 0x01, 0x00]),
+            Err(ObjectParseError::InsufficientBytes)
+        );
+    }
+
+    #[test]
+    fn rejects_status_element_without_status_code() {
+        assert_eq!(
+            Group91Var1::read(&[0x00, 0x00, 0x00, 0x00, 0x01, 0x00]),
+            Err(ObjectParseError::InsufficientBytes)
+        );
+    }
+}
@@ -134,10 +134,18 @@ pub enum FunctionCode {
     AuthenticateFile,
     ///  Outstation shall abort a file transfer operation (value == 30)
     AbortFile,
-    ///  Master shall interpret this fragment as an Application Layer response to an ApplicationLayer request (value == 129)
+    ///  Outstation shall activate the configurations specified by the objects in the request (value == 31)
+    ActivateConfig,
+    ///  Master sends this to an outstation to perform secure authentication, e.g. a challenge reply or session key change (value == 32)
+    AuthenticateRequest,
+    ///  Same as AuthenticateRequest but outstation shall not send a response (value == 33)
+    AuthenticateRequestNoResponse,
+    ///  Master shall interpret this fragment as an Application Layer response to an ApplicationLayer request to an ApplicationLayer request (value == 129)
     Response,
     ///  Master shall interpret this fragment as an unsolicited response that was not prompted by an explicit request (value == 130)
     UnsolicitedResponse,
+    ///  Master shall interpret this fragment as a secure authentication response, e.g. a challenge or session key status (value == 131)
+    AuthenticateResponse,
 }
 
 impl FunctionCode {
@@ -176,8 +184,12 @@ impl FunctionCode {
             28 => Some(FunctionCode::GetFileInfo),
             29 => Some(FunctionCode::AuthenticateFile),
             30 => Some(FunctionCode::AbortFile),
+            31 => Some(FunctionCode::ActivateConfig),
+            32 => Some(FunctionCode::AuthenticateRequest),
+            33 => Some(FunctionCode::AuthenticateRequestNoResponse),
             129 => Some(FunctionCode::Response),
             130 => Some(FunctionCode::UnsolicitedResponse),
+            131 => Some(FunctionCode::AuthenticateResponse),
             _ => None,
         }
     }
@@ -216,8 +228,12 @@ impl FunctionCode {
             FunctionCode::GetFileInfo => 28,
             FunctionCode::AuthenticateFile => 29,
             FunctionCode::AbortFile => 30,
+            FunctionCode::ActivateConfig => 31,
+            FunctionCode::AuthenticateRequest => 32,
+            FunctionCode::AuthenticateRequestNoResponse => 33,
             FunctionCode::Response => 129,
             FunctionCode::UnsolicitedResponse => 130,
+            FunctionCode::AuthenticateResponse => 131,
         }
     }
     
@@ -226,3 +242,164 @@ impl FunctionCode {
     }
 }
 
+/// Status code used in file-control objects (g70v4 and g70v6)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FileStatus {
+    ///  requested operation was successful (value == 0)
+    Success,
+    ///  permission was denied due to improper authentication key, user name, or password (value == 1)
+    PermissionDenied,
+    ///  an unsupported or unknown operation mode was requested (value == 2)
+    InvalidMode,
+    ///  requested file does not exist (value == 3)
+    FileNotFound,
+    ///  requested file is already in use by another user (value == 4)
+    FileLocked,
+    ///  file could not be opened because of limitations on the number of open files (value == 5)
+    TooManyOpen,
+    ///  there is no file opened with the handle in the request (value == 6)
+    InvalidHandle,
+    ///  outstation is unable to negotiate a suitable write block size (value == 7)
+    WriteBlockSize,
+    ///  communications were lost or cannot be established with the end device where the file resides (value == 8)
+    CommLost,
+    ///  an abort request was unsuccessful because the outstation is unable or not programmed to abort (value == 9)
+    CannotAbort,
+    ///  file handle does not reference an opened file (value == 16)
+    NotOpened,
+    ///  file closed due to inactivity timeout (value == 17)
+    HandleExpired,
+    ///  too much file data was received for outstation to process (value == 18)
+    BufferOverrun,
+    ///  an error happened in the file processing that prevents any further activity with this file (value == 19)
+    Fatal,
+    ///  the block number did not have the expected sequence number (value == 20)
+    BlockSeq,
+    ///  some other error not listed above occurred (value == 255)
+    Undefined,
+    /// captures any value not defined in the enumeration
+    Unknown(u8),
+}
+
+impl FileStatus {
+    /// create the enum from the underlying value
+    pub fn from(x: u8) -> Self {
+        match x {
+            0 => FileStatus::Success,
+            1 => FileStatus::PermissionDenied,
+            2 => FileStatus::InvalidMode,
+            3 => FileStatus::FileNotFound,
+            4 => FileStatus::FileLocked,
+            5 => FileStatus::TooManyOpen,
+            6 => FileStatus::InvalidHandle,
+            7 => FileStatus::WriteBlockSize,
+            8 => FileStatus::CommLost,
+            9 => FileStatus::CannotAbort,
+            16 => FileStatus::NotOpened,
+            17 => FileStatus::HandleExpired,
+            18 => FileStatus::BufferOverrun,
+            19 => FileStatus::Fatal,
+            20 => FileStatus::BlockSeq,
+            255 => FileStatus::Undefined,
+            _ => FileStatus::Unknown(x),
+        }
+    }
+    
+    /// convert the enum to its underlying value
+    pub fn as_u8(self) -> u8 {
+        match self {
+            FileStatus::Success => 0,
+            FileStatus::PermissionDenied => 1,
+            FileStatus::InvalidMode => 2,
+            FileStatus::FileNotFound => 3,
+            FileStatus::FileLocked => 4,
+            FileStatus::TooManyOpen => 5,
+            FileStatus::InvalidHandle => 6,
+            FileStatus::WriteBlockSize => 7,
+            FileStatus::CommLost => 8,
+            FileStatus::CannotAbort => 9,
+            FileStatus::NotOpened => 16,
+            FileStatus::HandleExpired => 17,
+            FileStatus::BufferOverrun => 18,
+            FileStatus::Fatal => 19,
+            FileStatus::BlockSeq => 20,
+            FileStatus::Undefined => 255,
+            FileStatus::Unknown(x) => x,
+        }
+    }
+    
+    pub(crate) fn write(self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.as_u8())
+    }
+}
+
+/// Units of the interval in an indexed absolute time and long interval object (g50v4)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IntervalUnit {
+    ///  the outstation does not repeat the action regardless of the interval (value == 0)
+    None,
+    ///  interval is a count of milliseconds (value == 1)
+    Milliseconds,
+    ///  interval is a count of seconds (value == 2)
+    Seconds,
+    ///  interval is a count of minutes (value == 3)
+    Minutes,
+    ///  interval is a count of hours (value == 4)
+    Hours,
+    ///  interval is a count of days (value == 5)
+    Days,
+    ///  interval is a count of weeks (value == 6)
+    Weeks,
+    ///  interval is a count of months on the same day of the month (value == 7)
+    Months,
+    ///  interval is a count of months on the same day of the week counted from the start of the month (value == 8)
+    MonthsSameDayOfWeekFromStart,
+    ///  interval is a count of months on the same day of the week counted from the end of the month (value == 9)
+    MonthsSameDayOfWeekFromEnd,
+    ///  interval is a count of seasons (value == 10)
+    Seasons,
+    /// captures any value not defined in the enumeration
+    Unknown(u8),
+}
+
+impl IntervalUnit {
+    /// create the enum from the underlying value
+    pub fn from(x: u8) -> Self {
+        match x {
+            0 => IntervalUnit::None,
+            1 => IntervalUnit::Milliseconds,
+            2 => IntervalUnit::Seconds,
+            3 => IntervalUnit::Minutes,
+            4 => IntervalUnit::Hours,
+            5 => IntervalUnit::Days,
+            6 => IntervalUnit::Weeks,
+            7 => IntervalUnit::Months,
+            8 => IntervalUnit::MonthsSameDayOfWeekFromStart,
+            9 => IntervalUnit::MonthsSameDayOfWeekFromEnd,
+            10 => IntervalUnit::Seasons,
+            _ => IntervalUnit::Unknown(x),
+        }
+    }
+    
+    /// convert the enum to its underlying value
+    pub fn as_u8(self) -> u8 {
+        match self {
+            IntervalUnit::None => 0,
+            IntervalUnit::Milliseconds => 1,
+            IntervalUnit::Seconds => 2,
+            IntervalUnit::Minutes => 3,
+            IntervalUnit::Hours => 4,
+            IntervalUnit::Days => 5,
+            IntervalUnit::Weeks => 6,
+            IntervalUnit::Months => 7,
+            IntervalUnit::MonthsSameDayOfWeekFromStart => 8,
+            IntervalUnit::MonthsSameDayOfWeekFromEnd => 9,
+            IntervalUnit::Seasons => 10,
+            IntervalUnit::Unknown(x) => x,
+        }
+    }
+    
+    pub(crate) fn write(self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.as_u8())
+    }
+}
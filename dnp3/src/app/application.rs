@@ -0,0 +1,50 @@
+use crate::app::file::FreeFormat;
+use crate::app::parse_error::ObjectParseError;
+use crate::app::variations::Variation;
+
+use scursor::{WriteCursor, WriteError};
+
+/// Application targeted by an INITIALIZE_APPLICATION, START_APPLICATION, or STOP_APPLICATION request
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ApplicationIdentifier {
+    /// All applications on the outstation, encoded as g90v1 with the all objects qualifier (0x06)
+    All,
+    /// A single application identified by name, encoded as a g90v1 object with the free-format qualifier (0x5B)
+    Name(String),
+}
+
+impl std::fmt::Display for ApplicationIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::All => f.write_str("all applications"),
+            Self::Name(name) => f.write_str(name),
+        }
+    }
+}
+
+/// Application identifier object
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group90Var1<'a> {
+    pub(crate) name: &'a str,
+}
+
+impl<'a> Group90Var1<'a> {
+    pub(crate) fn read(object: &'a [u8]) -> Result<Self, ObjectParseError> {
+        let name = std::str::from_utf8(object).map_err(|_| ObjectParseError::BadEncoding)?;
+        Ok(Self { name })
+    }
+}
+
+impl FreeFormat for Group90Var1<'_> {
+    const VARIATION: Variation = Variation::Group90Var1;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_bytes(self.name.as_bytes())
+    }
+}
+
+impl std::fmt::Display for Group90Var1<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "name: {}", self.name)
+    }
+}
@@ -0,0 +1,533 @@
+use crate::app::parse::range::Range;
+use crate::app::parse_error::ObjectParseError;
+use crate::app::Timestamp;
+
+use scursor::{ReadCursor, WriteCursor, WriteError};
+
+/// Data type code used to encode the value of a device attribute (g0)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AttrDataType {
+    /// Visible character string
+    VisibleString,
+    /// Unsigned integer
+    UnsignedInt,
+    /// Signed integer
+    SignedInt,
+    /// Single or double precision floating point
+    FloatingPoint,
+    /// Octet string
+    OctetString,
+    /// Bit string
+    BitString,
+    /// DNP3 absolute time
+    Time,
+    /// List of attribute variations and their properties
+    AttrList,
+}
+
+impl AttrDataType {
+    pub(crate) fn get(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(Self::VisibleString),
+            2 => Some(Self::UnsignedInt),
+            3 => Some(Self::SignedInt),
+            4 => Some(Self::FloatingPoint),
+            5 => Some(Self::OctetString),
+            6 => Some(Self::BitString),
+            7 => Some(Self::Time),
+            254 => Some(Self::AttrList),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn to_u8(self) -> u8 {
+        match self {
+            Self::VisibleString => 1,
+            Self::UnsignedInt => 2,
+            Self::SignedInt => 3,
+            Self::FloatingPoint => 4,
+            Self::OctetString => 5,
+            Self::BitString => 6,
+            Self::Time => 7,
+            Self::AttrList => 254,
+        }
+    }
+}
+
+/// Errors that occur when parsing a device attribute (g0)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AttrParseError {
+    /// data type code not defined by the standard
+    UnknownDataType(u8),
+    /// length is not valid for the data type
+    BadLength(AttrDataType, u8),
+    /// set index that does not fit in a single byte
+    SetOutOfRange(u16),
+    /// header that does not contain exactly one attribute
+    CountNotOne(usize),
+}
+
+impl std::fmt::Display for AttrParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::UnknownDataType(x) => write!(f, "unknown attribute data type: {}", x),
+            Self::BadLength(t, len) => write!(f, "length of {} is invalid for {:?}", len, t),
+            Self::SetOutOfRange(x) => write!(f, "attribute set {} is out of range", x),
+            Self::CountNotOne(x) => {
+                write!(
+                    f,
+                    "attribute headers must contain one object, received {}",
+                    x
+                )
+            }
+        }
+    }
+}
+
+/// Floating point value of a device attribute
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FloatType {
+    /// single precision value
+    F32(f32),
+    /// double precision value
+    F64(f64),
+}
+
+impl FloatType {
+    /// value of the attribute as a double precision value
+    pub fn value(self) -> f64 {
+        match self {
+            Self::F32(x) => x as f64,
+            Self::F64(x) => x,
+        }
+    }
+}
+
+/// Properties of an attribute as reported in the attribute list (variation 255)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AttrProp {
+    /// true if the master may WRITE the attribute
+    pub is_writable: bool,
+}
+
+impl AttrProp {
+    const WRITABLE: u8 = 0x01;
+
+    /// properties of an attribute that may be written by the master
+    pub fn writable() -> Self {
+        Self { is_writable: true }
+    }
+
+    /// properties of an attribute that may only be read by the master
+    pub fn read_only() -> Self {
+        Self { is_writable: false }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        Self {
+            is_writable: value & Self::WRITABLE != 0,
+        }
+    }
+
+    fn to_u8(self) -> u8 {
+        if self.is_writable {
+            Self::WRITABLE
+        } else {
+            0
+        }
+    }
+}
+
+/// Entry in the list of attributes returned by an outstation (variation 255)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AttrItem {
+    /// variation of the attribute
+    pub variation: u8,
+    /// properties of the attribute
+    pub properties: AttrProp,
+}
+
+/// Iterator over the entries of an attribute list
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AttrItemIter<'a> {
+    data: &'a [u8],
+}
+
+impl Iterator for AttrItemIter<'_> {
+    type Item = AttrItem;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.data {
+            [variation, properties, rest @ ..] => {
+                self.data = rest;
+                Some(AttrItem {
+                    variation: *variation,
+                    properties: AttrProp::from_u8(*properties),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Value of a device attribute borrowed from a parsed ASDU
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum AttrValue<'a> {
+    /// visible character string
+    VisibleString(&'a str),
+    /// unsigned integer
+    UnsignedInt(u32),
+    /// signed integer
+    SignedInt(i32),
+    /// floating point value
+    FloatingPoint(FloatType),
+    /// octet string
+    OctetString(&'a [u8]),
+    /// bit string
+    BitString(&'a [u8]),
+    /// absolute time
+    Time(Timestamp),
+    /// list of attribute variations defined in the set
+    AttrList(AttrItemIter<'a>),
+}
+
+/// Device attribute (g0) borrowed from a parsed ASDU
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Attribute<'a> {
+    /// set to which the attribute belongs, 0 being the standard set
+    pub set: u8,
+    /// variation of the attribute which identifies it within the set
+    pub variation: u8,
+    /// value of the attribute
+    pub value: AttrValue<'a>,
+}
+
+/// Owned value of a device attribute
+#[derive(Clone, Debug, PartialEq)]
+pub enum OwnedAttrValue {
+    /// visible character string
+    VisibleString(String),
+    /// unsigned integer
+    UnsignedInt(u32),
+    /// signed integer
+    SignedInt(i32),
+    /// floating point value
+    FloatingPoint(FloatType),
+    /// octet string
+    OctetString(Vec<u8>),
+    /// bit string
+    BitString(Vec<u8>),
+    /// absolute time
+    Time(Timestamp),
+    /// list of attribute variations defined in the set
+    AttrList(Vec<AttrItem>),
+}
+
+/// Owned device attribute (g0)
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnedAttribute {
+    /// set to which the attribute belongs, 0 being the standard set
+    pub set: u8,
+    /// variation of the attribute which identifies it within the set
+    pub variation: u8,
+    /// value of the attribute
+    pub value: OwnedAttrValue,
+}
+
+impl<'a> AttrValue<'a> {
+    /// data type used to encode the value
+    pub fn data_type(&self) -> AttrDataType {
+        match self {
+            Self::VisibleString(_) => AttrDataType::VisibleString,
+            Self::UnsignedInt(_) => AttrDataType::UnsignedInt,
+            Self::SignedInt(_) => AttrDataType::SignedInt,
+            Self::FloatingPoint(_) => AttrDataType::FloatingPoint,
+            Self::OctetString(_) => AttrDataType::OctetString,
+            Self::BitString(_) => AttrDataType::BitString,
+            Self::Time(_) => AttrDataType::Time,
+            Self::AttrList(_) => AttrDataType::AttrList,
+        }
+    }
+
+    fn parse(cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        let code = cursor.read_u8()?;
+        let data_type = AttrDataType::get(code).ok_or(AttrParseError::UnknownDataType(code))?;
+        let len = cursor.read_u8()?;
+        let data = cursor.read_bytes(len as usize)?;
+        let bad_length = || AttrParseError::BadLength(data_type, len);
+
+        let value = match data_type {
+            AttrDataType::VisibleString => Self::VisibleString(
+                std::str::from_utf8(data).map_err(|_| ObjectParseError::BadEncoding)?,
+            ),
+            AttrDataType::UnsignedInt => {
+                Self::UnsignedInt(read_unsigned(data).ok_or_else(bad_length)?)
+            }
+            AttrDataType::SignedInt => Self::SignedInt(read_signed(data).ok_or_else(bad_length)?),
+            AttrDataType::FloatingPoint => {
+                let mut cursor = ReadCursor::new(data);
+                let value = match len {
+                    4 => FloatType::F32(cursor.read_f32_le()?),
+                    8 => FloatType::F64(cursor.read_f64_le()?),
+                    _ => return Err(bad_length().into()),
+                };
+                Self::FloatingPoint(value)
+            }
+            AttrDataType::OctetString => Self::OctetString(data),
+            AttrDataType::BitString => Self::BitString(data),
+            AttrDataType::Time => {
+                if len != 6 {
+                    return Err(bad_length().into());
+                }
+                Self::Time(Timestamp::new(ReadCursor::new(data).read_u48_le()?))
+            }
+            AttrDataType::AttrList => {
+                if len % 2 != 0 {
+                    return Err(bad_length().into());
+                }
+                Self::AttrList(AttrItemIter { data })
+            }
+        };
+
+        Ok(value)
+    }
+
+    /// copy the value into an owned representation
+    pub fn to_owned_value(&self) -> OwnedAttrValue {
+        match self {
+            Self::VisibleString(x) => OwnedAttrValue::VisibleString(x.to_string()),
+            Self::UnsignedInt(x) => OwnedAttrValue::UnsignedInt(*x),
+            Self::SignedInt(x) => OwnedAttrValue::SignedInt(*x),
+            Self::FloatingPoint(x) => OwnedAttrValue::FloatingPoint(*x),
+            Self::OctetString(x) => OwnedAttrValue::OctetString(x.to_vec()),
+            Self::BitString(x) => OwnedAttrValue::BitString(x.to_vec()),
+            Self::Time(x) => OwnedAttrValue::Time(*x),
+            Self::AttrList(x) => OwnedAttrValue::AttrList(x.collect()),
+        }
+    }
+}
+
+impl<'a> Attribute<'a> {
+    /// parse the single attribute contained in a range header where start == stop == set
+    pub(crate) fn parse_from_range(
+        variation: u8,
+        range: Range,
+        cursor: &mut ReadCursor<'a>,
+    ) -> Result<Self, ObjectParseError> {
+        if range.get_count() != 1 {
+            return Err(AttrParseError::CountNotOne(range.get_count()).into());
+        }
+        let set = u8::try_from(range.get_start())
+            .map_err(|_| AttrParseError::SetOutOfRange(range.get_start()))?;
+        Ok(Self {
+            set,
+            variation,
+            value: AttrValue::parse(cursor)?,
+        })
+    }
+
+    /// copy the attribute into an owned representation
+    pub fn to_owned_attr(&self) -> OwnedAttribute {
+        OwnedAttribute {
+            set: self.set,
+            variation: self.variation,
+            value: self.value.to_owned_value(),
+        }
+    }
+}
+
+impl OwnedAttrValue {
+    /// data type used to encode the value
+    pub fn data_type(&self) -> AttrDataType {
+        match self {
+            Self::VisibleString(_) => AttrDataType::VisibleString,
+            Self::UnsignedInt(_) => AttrDataType::UnsignedInt,
+            Self::SignedInt(_) => AttrDataType::SignedInt,
+            Self::FloatingPoint(_) => AttrDataType::FloatingPoint,
+            Self::OctetString(_) => AttrDataType::OctetString,
+            Self::BitString(_) => AttrDataType::BitString,
+            Self::Time(_) => AttrDataType::Time,
+            Self::AttrList(_) => AttrDataType::AttrList,
+        }
+    }
+
+    /// number of bytes used to encode the value, excluding the type and length fields
+    pub(crate) fn encoded_len(&self) -> usize {
+        match self {
+            Self::VisibleString(x) => x.len(),
+            Self::UnsignedInt(_) => 4,
+            Self::SignedInt(_) => 4,
+            Self::FloatingPoint(FloatType::F32(_)) => 4,
+            Self::FloatingPoint(FloatType::F64(_)) => 8,
+            Self::OctetString(x) => x.len(),
+            Self::BitString(x) => x.len(),
+            Self::Time(_) => 6,
+            Self::AttrList(x) => 2 * x.len(),
+        }
+    }
+
+    pub(crate) fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        let len = u8::try_from(self.encoded_len()).map_err(|_| WriteError::NumericOverflow)?;
+        cursor.write_u8(self.data_type().to_u8())?;
+        cursor.write_u8(len)?;
+        match self {
+            Self::VisibleString(x) => cursor.write_bytes(x.as_bytes()),
+            Self::UnsignedInt(x) => cursor.write_u32_le(*x),
+            Self::SignedInt(x) => cursor.write_i32_le(*x),
+            Self::FloatingPoint(FloatType::F32(x)) => cursor.write_f32_le(*x),
+            Self::FloatingPoint(FloatType::F64(x)) => cursor.write_f64_le(*x),
+            Self::OctetString(x) => cursor.write_bytes(x),
+            Self::BitString(x) => cursor.write_bytes(x),
+            Self::Time(x) => x.write(cursor),
+            Self::AttrList(items) => {
+                for item in items {
+                    cursor.write_u8(item.variation)?;
+                    cursor.write_u8(item.properties.to_u8())?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl OwnedAttribute {
+    /// construct an owned attribute from its fields
+    pub fn new(set: u8, variation: u8, value: OwnedAttrValue) -> Self {
+        Self {
+            set,
+            variation,
+            value,
+        }
+    }
+}
+
+pub(crate) fn read_unsigned(data: &[u8]) -> Option<u32> {
+    if data.is_empty() || data.len() > 4 {
+        return None;
+    }
+    Some(
+        data.iter()
+            .rev()
+            .fold(0u32, |acc, x| (acc << 8) | (*x as u32)),
+    )
+}
+
+pub(crate) fn read_signed(data: &[u8]) -> Option<i32> {
+    let value = read_unsigned(data)?;
+    // sign extend values shorter than 4 bytes
+    let shift = 32 - 8 * data.len() as u32;
+    Some(((value << shift) as i32) >> shift)
+}
+
+impl std::fmt::Display for AttrValue<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::VisibleString(x) => write!(f, "visible string: {}", x),
+            Self::UnsignedInt(x) => write!(f, "unsigned int: {}", x),
+            Self::SignedInt(x) => write!(f, "signed int: {}", x),
+            Self::FloatingPoint(x) => write!(f, "float: {}", x.value()),
+            Self::OctetString(x) => write!(f, "octet string: {:02X?}", x),
+            Self::BitString(x) => write!(f, "bit string: {:02X?}", x),
+            Self::Time(x) => write!(f, "time: {}", x),
+            Self::AttrList(items) => {
+                f.write_str("attribute list:")?;
+                for item in *items {
+                    write!(
+                        f,
+                        " (variation: {} writable: {})",
+                        item.variation, item.properties.is_writable
+                    )?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Attribute<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "set: {} variation: {} {}",
+            self.set, self.variation, self.value
+        )
+    }
+}
+
+impl From<AttrParseError> for ObjectParseError {
+    fn from(err: AttrParseError) -> Self {
+        ObjectParseError::BadAttribute(err)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn round_trip(value: OwnedAttrValue) {
+        let mut buffer = [0u8; 300];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        value.write(&mut cursor).unwrap();
+        let mut input = ReadCursor::new(cursor.written());
+        let parsed = AttrValue::parse(&mut input).unwrap();
+        assert!(input.is_empty());
+        assert_eq!(parsed.to_owned_value(), value);
+    }
+
+    #[test]
+    fn values_round_trip() {
+        round_trip(OwnedAttrValue::VisibleString(
+            "Step Function I/O".to_string(),
+        ));
+        round_trip(OwnedAttrValue::UnsignedInt(0xCAFE_BABE));
+        round_trip(OwnedAttrValue::SignedInt(-42));
+        round_trip(OwnedAttrValue::FloatingPoint(FloatType::F32(1.5)));
+        round_trip(OwnedAttrValue::FloatingPoint(FloatType::F64(-3.25)));
+        round_trip(OwnedAttrValue::OctetString(vec![0x01, 0x02, 0x03]));
+        round_trip(OwnedAttrValue::BitString(vec![0xAA]));
+        round_trip(OwnedAttrValue::Time(Timestamp::new(0x0102_0304_0506)));
+        round_trip(OwnedAttrValue::AttrList(vec![
+            AttrItem {
+                variation: 252,
+                properties: AttrProp::read_only(),
+            },
+            AttrItem {
+                variation: 246,
+                properties: AttrProp::writable(),
+            },
+        ]));
+    }
+
+    #[test]
+    fn parses_short_integers() {
+        let mut cursor = ReadCursor::new(&[0x02, 0x02, 0xFE, 0xFF]);
+        assert_eq!(
+            AttrValue::parse(&mut cursor).unwrap(),
+            AttrValue::UnsignedInt(0xFFFE)
+        );
+        let mut cursor = ReadCursor::new(&[0x03, 0x02, 0xFE, 0xFF]);
+        assert_eq!(
+            AttrValue::parse(&mut cursor).unwrap(),
+            AttrValue::SignedInt(-2)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_lengths() {
+        let mut cursor = ReadCursor::new(&[0x02, 0x05, 0x01, 0x02, 0x03, 0x04, 0x05]);
+        assert_eq!(
+            AttrValue::parse(&mut cursor),
+            Err(AttrParseError::BadLength(AttrDataType::UnsignedInt, 5).into())
+        );
+        let mut cursor = ReadCursor::new(&[0x04, 0x02, 0x01, 0x02]);
+        assert_eq!(
+            AttrValue::parse(&mut cursor),
+            Err(AttrParseError::BadLength(AttrDataType::FloatingPoint, 2).into())
+        );
+        let mut cursor = ReadCursor::new(&[0x08, 0x00]);
+        assert_eq!(
+            AttrValue::parse(&mut cursor),
+            Err(AttrParseError::UnknownDataType(8).into())
+        );
+    }
+}
@@ -0,0 +1,338 @@
+use crate::app::file::FreeFormat;
+use crate::app::parse_error::ObjectParseError;
+use crate::app::variations::Variation;
+use crate::app::Timestamp;
+
+use scursor::{ReadCursor, WriteCursor, WriteError};
+
+/// Authentication - Challenge
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var1<'a> {
+    pub(crate) challenge_seq: u32,
+    pub(crate) user: u16,
+    pub(crate) mac_algorithm: u8,
+    pub(crate) reason: u8,
+    pub(crate) challenge_data: &'a [u8],
+}
+
+/// Authentication - Reply
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var2<'a> {
+    pub(crate) challenge_seq: u32,
+    pub(crate) user: u16,
+    pub(crate) mac: &'a [u8],
+}
+
+/// Authentication - Session Key Status
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var5<'a> {
+    pub(crate) key_change_seq: u32,
+    pub(crate) user: u16,
+    pub(crate) key_wrap_algorithm: u8,
+    pub(crate) key_status: u8,
+    pub(crate) mac_algorithm: u8,
+    pub(crate) challenge_data: &'a [u8],
+    pub(crate) mac: &'a [u8],
+}
+
+/// Authentication - Session Key Change
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var6<'a> {
+    pub(crate) key_change_seq: u32,
+    pub(crate) user: u16,
+    pub(crate) wrapped_key_data: &'a [u8],
+}
+
+/// Authentication - Error
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var7<'a> {
+    pub(crate) challenge_seq: u32,
+    pub(crate) user: u16,
+    pub(crate) association_id: u16,
+    pub(crate) error_code: u8,
+    pub(crate) time_of_error: Timestamp,
+    pub(crate) error_text: &'a str,
+}
+
+/// Authentication - HMAC
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var9<'a> {
+    pub(crate) mac: &'a [u8],
+}
+
+/// the reason for a challenge, CRITICAL is the only value defined by the standard
+pub(crate) const REASON_CRITICAL: u8 = 1;
+
+impl<'a> Group120Var1<'a> {
+    pub(crate) fn read(object: &'a [u8]) -> Result<Self, ObjectParseError> {
+        let mut cursor = ReadCursor::new(object);
+        Ok(Self {
+            challenge_seq: cursor.read_u32_le()?,
+            user: cursor.read_u16_le()?,
+            mac_algorithm: cursor.read_u8()?,
+            reason: cursor.read_u8()?,
+            challenge_data: cursor.read_all(),
+        })
+    }
+}
+
+impl FreeFormat for Group120Var1<'_> {
+    const VARIATION: Variation = Variation::Group120Var1;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u32_le(self.challenge_seq)?;
+        cursor.write_u16_le(self.user)?;
+        cursor.write_u8(self.mac_algorithm)?;
+        cursor.write_u8(self.reason)?;
+        cursor.write_bytes(self.challenge_data)?;
+        Ok(())
+    }
+}
+
+impl<'a> Group120Var2<'a> {
+    pub(crate) fn read(object: &'a [u8]) -> Result<Self, ObjectParseError> {
+        let mut cursor = ReadCursor::new(object);
+        Ok(Self {
+            challenge_seq: cursor.read_u32_le()?,
+            user: cursor.read_u16_le()?,
+            mac: cursor.read_all(),
+        })
+    }
+}
+
+impl FreeFormat for Group120Var2<'_> {
+    const VARIATION: Variation = Variation::Group120Var2;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u32_le(self.challenge_seq)?;
+        cursor.write_u16_le(self.user)?;
+        cursor.write_bytes(self.mac)?;
+        Ok(())
+    }
+}
+
+impl<'a> Group120Var5<'a> {
+    pub(crate) fn read(object: &'a [u8]) -> Result<Self, ObjectParseError> {
+        let mut cursor = ReadCursor::new(object);
+        let key_change_seq = cursor.read_u32_le()?;
+        let user = cursor.read_u16_le()?;
+        let key_wrap_algorithm = cursor.read_u8()?;
+        let key_status = cursor.read_u8()?;
+        let mac_algorithm = cursor.read_u8()?;
+        let challenge_length = cursor.read_u16_le()?;
+        Ok(Self {
+            key_change_seq,
+            user,
+            key_wrap_algorithm,
+            key_status,
+            mac_algorithm,
+            challenge_data: cursor.read_bytes(challenge_length as usize)?,
+            mac: cursor.read_all(),
+        })
+    }
+
+    /// the key status message as included in the key wrap data, i.e. the object without the MAC
+    pub(crate) fn to_status_message(self) -> Result<Vec<u8>, WriteError> {
+        let mut buffer = vec![0; 11 + self.challenge_data.len()];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        Self { mac: &[], ..self }.write(&mut cursor)?;
+        Ok(buffer)
+    }
+}
+
+impl FreeFormat for Group120Var5<'_> {
+    const VARIATION: Variation = Variation::Group120Var5;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        let challenge_length =
+            u16::try_from(self.challenge_data.len()).map_err(|_| WriteError::NumericOverflow)?;
+        cursor.write_u32_le(self.key_change_seq)?;
+        cursor.write_u16_le(self.user)?;
+        cursor.write_u8(self.key_wrap_algorithm)?;
+        cursor.write_u8(self.key_status)?;
+        cursor.write_u8(self.mac_algorithm)?;
+        cursor.write_u16_le(challenge_length)?;
+        cursor.write_bytes(self.challenge_data)?;
+        cursor.write_bytes(self.mac)?;
+        Ok(())
+    }
+}
+
+impl<'a> Group120Var6<'a> {
+    pub(crate) fn read(object: &'a [u8]) -> Result<Self, ObjectParseError> {
+        let mut cursor = ReadCursor::new(object);
+        Ok(Self {
+            key_change_seq: cursor.read_u32_le()?,
+            user: cursor.read_u16_le()?,
+            wrapped_key_data: cursor.read_all(),
+        })
+    }
+}
+
+impl FreeFormat for Group120Var6<'_> {
+    const VARIATION: Variation = Variation::Group120Var6;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u32_le(self.key_change_seq)?;
+        cursor.write_u16_le(self.user)?;
+        cursor.write_bytes(self.wrapped_key_data)?;
+        Ok(())
+    }
+}
+
+impl<'a> Group120Var7<'a> {
+    pub(crate) fn read(object: &'a [u8]) -> Result<Self, ObjectParseError> {
+        let mut cursor = ReadCursor::new(object);
+        Ok(Self {
+            challenge_seq: cursor.read_u32_le()?,
+            user: cursor.read_u16_le()?,
+            association_id: cursor.read_u16_le()?,
+            error_code: cursor.read_u8()?,
+            time_of_error: Timestamp::new(cursor.read_u48_le()?),
+            error_text: std::str::from_utf8(cursor.read_all())
+                .map_err(|_| ObjectParseError::BadEncoding)?,
+        })
+    }
+}
+
+impl FreeFormat for Group120Var7<'_> {
+    const VARIATION: Variation = Variation::Group120Var7;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u32_le(self.challenge_seq)?;
+        cursor.write_u16_le(self.user)?;
+        cursor.write_u16_le(self.association_id)?;
+        cursor.write_u8(self.error_code)?;
+        self.time_of_error.write(cursor)?;
+        cursor.write_bytes(self.error_text.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl<'a> Group120Var9<'a> {
+    pub(crate) fn read(object: &'a [u8]) -> Result<Self, ObjectParseError> {
+        Ok(Self { mac: object })
+    }
+}
+
+impl FreeFormat for Group120Var9<'_> {
+    const VARIATION: Variation = Variation::Group120Var9;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_bytes(self.mac)
+    }
+}
+
+impl std::fmt::Display for Group120Var1<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "csq: {} user: {} mac algorithm: {} reason: {} challenge length: {}",
+            self.challenge_seq,
+            self.user,
+            self.mac_algorithm,
+            self.reason,
+            self.challenge_data.len()
+        )
+    }
+}
+
+impl std::fmt::Display for Group120Var2<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "csq: {} user: {} mac length: {}",
+            self.challenge_seq,
+            self.user,
+            self.mac.len()
+        )
+    }
+}
+
+impl std::fmt::Display for Group120Var5<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "ksq: {} user: {} key wrap algorithm: {} key status: {} mac algorithm: {} challenge length: {} mac length: {}",
+            self.key_change_seq,
+            self.user,
+            self.key_wrap_algorithm,
+            self.key_status,
+            self.mac_algorithm,
+            self.challenge_data.len(),
+            self.mac.len()
+        )
+    }
+}
+
+impl std::fmt::Display for Group120Var6<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "ksq: {} user: {} wrapped key length: {}",
+            self.key_change_seq,
+            self.user,
+            self.wrapped_key_data.len()
+        )
+    }
+}
+
+impl std::fmt::Display for Group120Var7<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "csq: {} user: {} association id: {} error code: {} time of error: {} text: {}",
+            self.challenge_seq,
+            self.user,
+            self.association_id,
+            self.error_code,
+            self.time_of_error,
+            self.error_text
+        )
+    }
+}
+
+impl std::fmt::Display for Group120Var9<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "mac length: {}", self.mac.len())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn key_status_round_trip() {
+        let status = Group120Var5 {
+            key_change_seq: 7,
+            user: 1,
+            key_wrap_algorithm: 2,
+            key_status: 1,
+            mac_algorithm: 4,
+            challenge_data: &[0xAA, 0xBB, 0xCC, 0xDD],
+            mac: &[1, 2, 3, 4, 5, 6, 7, 8],
+        };
+
+        let mut buffer = [0; 64];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        status.write(&mut cursor).unwrap();
+        let written = cursor.written().to_vec();
+
+        assert_eq!(Group120Var5::read(&written), Ok(status));
+        // the status message omits the trailing MAC
+        assert_eq!(
+            status.to_status_message().unwrap(),
+            written[..written.len() - 8].to_vec()
+        );
+    }
+
+    #[test]
+    fn key_status_rejects_challenge_beyond_object() {
+        let object = [0, 0, 0, 0, 1, 0, 2, 1, 4, 0x10, 0x00, 0xAA];
+        assert_eq!(
+            Group120Var5::read(&object),
+            Err(ObjectParseError::InsufficientBytes)
+        );
+    }
+}
@@ -0,0 +1,823 @@
+use crate::app::attr::{read_signed, read_unsigned};
+use crate::app::file::FreeFormat;
+use crate::app::parse_error::ObjectParseError;
+use crate::app::variations::Variation;
+use crate::app::{FloatType, Timestamp};
+
+use scursor::{ReadCursor, ReadError, WriteCursor, WriteError};
+
+/// Data type code of a data set element
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DataSetType {
+    /// Element without a value, e.g. a name or a prototype reference
+    None,
+    /// Visible character string
+    VisibleString,
+    /// Unsigned integer
+    UnsignedInt,
+    /// Signed integer
+    SignedInt,
+    /// Single or double precision floating point
+    FloatingPoint,
+    /// Octet string
+    OctetString,
+    /// Bit string
+    BitString,
+    /// DNP3 absolute time
+    Time,
+}
+
+impl DataSetType {
+    pub(crate) fn get(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::None),
+            1 => Some(Self::VisibleString),
+            2 => Some(Self::UnsignedInt),
+            3 => Some(Self::SignedInt),
+            4 => Some(Self::FloatingPoint),
+            5 => Some(Self::OctetString),
+            6 => Some(Self::BitString),
+            7 => Some(Self::Time),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn to_u8(self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::VisibleString => 1,
+            Self::UnsignedInt => 2,
+            Self::SignedInt => 3,
+            Self::FloatingPoint => 4,
+            Self::OctetString => 5,
+            Self::BitString => 6,
+            Self::Time => 7,
+        }
+    }
+}
+
+/// Descriptor code that identifies the purpose of a data set element
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DescriptorCode {
+    /// Namespace in which the name of the data set is defined
+    Namespace,
+    /// Name of the data set
+    Name,
+    /// Data element whose value is reported by the outstation
+    DataElement,
+    /// Reference to a prototype whose elements are included at this position
+    Prototype,
+    /// Control value that may be set by the master
+    ControlValue,
+    /// Status of a control value reported by the outstation
+    ControlStatus,
+}
+
+impl DescriptorCode {
+    pub(crate) fn get(value: u8) -> Option<Self> {
+        match value {
+            3 => Some(Self::Namespace),
+            4 => Some(Self::Name),
+            5 => Some(Self::DataElement),
+            6 => Some(Self::Prototype),
+            7 => Some(Self::ControlValue),
+            8 => Some(Self::ControlStatus),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn to_u8(self) -> u8 {
+        match self {
+            Self::Namespace => 3,
+            Self::Name => 4,
+            Self::DataElement => 5,
+            Self::Prototype => 6,
+            Self::ControlValue => 7,
+            Self::ControlStatus => 8,
+        }
+    }
+
+    /// true if elements with this code have a value in present values and snapshot events
+    pub fn has_value(self) -> bool {
+        matches!(
+            self,
+            Self::DataElement | Self::ControlValue | Self::ControlStatus
+        )
+    }
+}
+
+/// Errors that occur when parsing a data set object (g85 - g88)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DataSetParseError {
+    /// element whose length extends beyond the end of the object
+    Truncated,
+    /// identifier that is not an unsigned integer in the range [0, 65535]
+    BadId,
+    /// UUID element that is not 16 bytes long
+    BadUuid(usize),
+    /// timestamp element that is not 6 bytes long
+    BadTime(usize),
+    /// descriptor element that is too short to contain its code, type, and maximum length
+    BadDescriptor(usize),
+    /// descriptor code not defined by the standard
+    UnknownDescriptorCode(u8),
+    /// data type code not defined by the standard
+    UnknownDataType(u8),
+}
+
+impl std::fmt::Display for DataSetParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Truncated => f.write_str("data set element extends beyond the object"),
+            Self::BadId => f.write_str("data set identifier is not a 16-bit unsigned integer"),
+            Self::BadUuid(x) => write!(f, "data set UUID has length {}", x),
+            Self::BadTime(x) => write!(f, "data set timestamp has length {}", x),
+            Self::BadDescriptor(x) => write!(f, "data set descriptor element has length {}", x),
+            Self::UnknownDescriptorCode(x) => write!(f, "unknown descriptor code: {}", x),
+            Self::UnknownDataType(x) => write!(f, "unknown data set data type: {}", x),
+        }
+    }
+}
+
+impl From<ReadError> for DataSetParseError {
+    fn from(_: ReadError) -> Self {
+        DataSetParseError::Truncated
+    }
+}
+
+impl From<DataSetParseError> for ObjectParseError {
+    fn from(err: DataSetParseError) -> Self {
+        ObjectParseError::BadDataSet(err)
+    }
+}
+
+/// Element of a data set prototype (g85) or descriptor (g86)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DataSetElement {
+    /// purpose of the element
+    pub code: DescriptorCode,
+    /// data type of the element's value
+    pub data_type: DataSetType,
+    /// maximum length of the element's value, enforced for strings
+    pub max_length: u8,
+    /// name of the data set or element, or the UUID of a referenced prototype
+    pub ancillary: Vec<u8>,
+}
+
+impl DataSetElement {
+    /// construct an element from its fields
+    pub fn new(
+        code: DescriptorCode,
+        data_type: DataSetType,
+        max_length: u8,
+        ancillary: Vec<u8>,
+    ) -> Self {
+        Self {
+            code,
+            data_type,
+            max_length,
+            ancillary,
+        }
+    }
+
+    /// namespace in which the name of the data set is defined
+    pub fn namespace(namespace: &str) -> Self {
+        Self::new(
+            DescriptorCode::Namespace,
+            DataSetType::None,
+            0,
+            namespace.as_bytes().to_vec(),
+        )
+    }
+
+    /// name of the data set
+    pub fn name(name: &str) -> Self {
+        Self::new(
+            DescriptorCode::Name,
+            DataSetType::None,
+            0,
+            name.as_bytes().to_vec(),
+        )
+    }
+
+    /// named data element reported by the outstation
+    pub fn data_element(name: &str, data_type: DataSetType, max_length: u8) -> Self {
+        Self::new(
+            DescriptorCode::DataElement,
+            data_type,
+            max_length,
+            name.as_bytes().to_vec(),
+        )
+    }
+
+    /// named control value that may be set by the master
+    pub fn control_value(name: &str, data_type: DataSetType, max_length: u8) -> Self {
+        Self::new(
+            DescriptorCode::ControlValue,
+            data_type,
+            max_length,
+            name.as_bytes().to_vec(),
+        )
+    }
+
+    /// named status of a control value reported by the outstation
+    pub fn control_status(name: &str, data_type: DataSetType, max_length: u8) -> Self {
+        Self::new(
+            DescriptorCode::ControlStatus,
+            data_type,
+            max_length,
+            name.as_bytes().to_vec(),
+        )
+    }
+
+    /// reference to the prototype with the specified UUID
+    pub fn prototype(uuid: [u8; 16]) -> Self {
+        Self::new(
+            DescriptorCode::Prototype,
+            DataSetType::None,
+            0,
+            uuid.to_vec(),
+        )
+    }
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        write_element(cursor, |cur| {
+            cur.write_u8(self.code.to_u8())?;
+            cur.write_u8(self.data_type.to_u8())?;
+            cur.write_u8(self.max_length)?;
+            cur.write_bytes(&self.ancillary)
+        })
+    }
+}
+
+/// Data set prototype (g85v1) whose elements may be included in any number of data sets
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DataSetPrototype {
+    /// identifier of the prototype
+    pub id: u16,
+    /// universally unique identifier used to reference the prototype from descriptors
+    pub uuid: [u8; 16],
+    /// elements of the prototype
+    pub elements: Vec<DataSetElement>,
+}
+
+impl DataSetPrototype {
+    /// construct a prototype from its fields
+    pub fn new(id: u16, uuid: [u8; 16], elements: Vec<DataSetElement>) -> Self {
+        Self { id, uuid, elements }
+    }
+}
+
+impl FreeFormat for DataSetPrototype {
+    const VARIATION: Variation = Variation::Group85Var1;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        write_element(cursor, |cur| cur.write_u16_le(self.id))?;
+        write_element(cursor, |cur| cur.write_bytes(&self.uuid))?;
+        for element in &self.elements {
+            element.write(cursor)?;
+        }
+        Ok(())
+    }
+}
+
+/// Data set descriptor (g86v1) that defines the structure of a data set
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DataSetDescriptor {
+    /// identifier of the data set
+    pub id: u16,
+    /// elements of the data set
+    pub elements: Vec<DataSetElement>,
+}
+
+impl DataSetDescriptor {
+    /// construct a descriptor from its fields
+    pub fn new(id: u16, elements: Vec<DataSetElement>) -> Self {
+        Self { id, elements }
+    }
+
+    /// elements that have a value in present values and snapshot events, in the order they
+    /// are reported, with the elements of referenced prototypes expanded in place
+    ///
+    /// returns None if a referenced prototype cannot be found
+    pub(crate) fn value_elements<'a, F>(&'a self, find: F) -> Option<Vec<&'a DataSetElement>>
+    where
+        F: Fn(&[u8]) -> Option<&'a DataSetPrototype>,
+    {
+        let mut elements = Vec::new();
+        for element in self.elements.iter() {
+            if element.code == DescriptorCode::Prototype {
+                let prototype = find(&element.ancillary)?;
+                elements.extend(prototype.elements.iter().filter(|x| x.code.has_value()));
+            } else if element.code.has_value() {
+                elements.push(element);
+            }
+        }
+        Some(elements)
+    }
+}
+
+impl FreeFormat for DataSetDescriptor {
+    const VARIATION: Variation = Variation::Group86Var1;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        write_element(cursor, |cur| cur.write_u16_le(self.id))?;
+        for element in &self.elements {
+            element.write(cursor)?;
+        }
+        Ok(())
+    }
+}
+
+/// Value of a data set element
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataSetValue {
+    /// visible character string
+    VisibleString(String),
+    /// unsigned integer
+    UnsignedInt(u32),
+    /// signed integer
+    SignedInt(i32),
+    /// floating point value
+    FloatingPoint(FloatType),
+    /// octet string
+    OctetString(Vec<u8>),
+    /// bit string
+    BitString(Vec<u8>),
+    /// absolute time
+    Time(Timestamp),
+    /// value whose type is unknown because the descriptor of the data set has not been read,
+    /// or because the value could not be decoded as the type in the descriptor
+    Unknown(Vec<u8>),
+}
+
+impl DataSetValue {
+    /// data type used to encode the value, or None if the type is unknown
+    pub fn data_type(&self) -> Option<DataSetType> {
+        match self {
+            Self::VisibleString(_) => Some(DataSetType::VisibleString),
+            Self::UnsignedInt(_) => Some(DataSetType::UnsignedInt),
+            Self::SignedInt(_) => Some(DataSetType::SignedInt),
+            Self::FloatingPoint(_) => Some(DataSetType::FloatingPoint),
+            Self::OctetString(_) => Some(DataSetType::OctetString),
+            Self::BitString(_) => Some(DataSetType::BitString),
+            Self::Time(_) => Some(DataSetType::Time),
+            Self::Unknown(_) => None,
+        }
+    }
+
+    /// decode the value using the data type of its element, falling back to `Unknown`
+    fn decode(data_type: Option<DataSetType>, data: &[u8]) -> Self {
+        let value = match data_type {
+            Some(DataSetType::VisibleString) => std::str::from_utf8(data)
+                .ok()
+                .map(|x| Self::VisibleString(x.to_string())),
+            Some(DataSetType::UnsignedInt) => read_unsigned(data).map(Self::UnsignedInt),
+            Some(DataSetType::SignedInt) => read_signed(data).map(Self::SignedInt),
+            Some(DataSetType::FloatingPoint) => {
+                let mut cursor = ReadCursor::new(data);
+                match data.len() {
+                    4 => cursor.read_f32_le().ok().map(FloatType::F32),
+                    8 => cursor.read_f64_le().ok().map(FloatType::F64),
+                    _ => None,
+                }
+                .map(Self::FloatingPoint)
+            }
+            Some(DataSetType::OctetString) => Some(Self::OctetString(data.to_vec())),
+            Some(DataSetType::BitString) => Some(Self::BitString(data.to_vec())),
+            Some(DataSetType::Time) if data.len() == 6 => ReadCursor::new(data)
+                .read_u48_le()
+                .ok()
+                .map(|x| Self::Time(Timestamp::new(x))),
+            _ => None,
+        };
+
+        value.unwrap_or_else(|| {
+            if data_type.is_some() {
+                tracing::warn!(
+                    "data set value {:02X?} is not a valid {:?}",
+                    data,
+                    data_type
+                );
+            }
+            Self::Unknown(data.to_vec())
+        })
+    }
+
+    /// true if the value may be reported for the element, i.e. it has the same type and
+    /// strings don't exceed the maximum length
+    pub(crate) fn matches(&self, element: &DataSetElement) -> bool {
+        if self.data_type() != Some(element.data_type) {
+            return false;
+        }
+        match self {
+            Self::VisibleString(x) => x.len() <= element.max_length as usize,
+            Self::OctetString(x) | Self::BitString(x) => x.len() <= element.max_length as usize,
+            _ => true,
+        }
+    }
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        write_element(cursor, |cur| match self {
+            Self::VisibleString(x) => cur.write_bytes(x.as_bytes()),
+            Self::UnsignedInt(x) => cur.write_u32_le(*x),
+            Self::SignedInt(x) => cur.write_i32_le(*x),
+            Self::FloatingPoint(FloatType::F32(x)) => cur.write_f32_le(*x),
+            Self::FloatingPoint(FloatType::F64(x)) => cur.write_f64_le(*x),
+            Self::OctetString(x) | Self::BitString(x) | Self::Unknown(x) => cur.write_bytes(x),
+            Self::Time(x) => x.write(cur),
+        })
+    }
+}
+
+/// Present value (g87v1) or snapshot event (g88v1) of a data set
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataSet {
+    /// identifier of the data set
+    pub id: u16,
+    /// time at which the values were captured
+    pub time: Timestamp,
+    /// values of the elements in the order defined by the descriptor
+    pub values: Vec<DataSetValue>,
+}
+
+impl DataSet {
+    /// construct a data set from its fields
+    pub fn new(id: u16, time: Timestamp, values: Vec<DataSetValue>) -> Self {
+        Self { id, time, values }
+    }
+
+    pub(crate) fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        write_element(cursor, |cur| cur.write_u16_le(self.id))?;
+        write_element(cursor, |cur| self.time.write(cur))?;
+        for value in &self.values {
+            value.write(cursor)?;
+        }
+        Ok(())
+    }
+}
+
+/// Wrapper used to write the present value of a data set
+pub(crate) struct PresentValue<'a>(pub(crate) &'a DataSet);
+
+impl FreeFormat for PresentValue<'_> {
+    const VARIATION: Variation = Variation::Group87Var1;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.0.write(cursor)
+    }
+}
+
+/// Data Set Prototype - With UUID
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group85Var1<'a> {
+    pub(crate) id: u16,
+    pub(crate) uuid: [u8; 16],
+    pub(crate) elements: &'a [u8],
+}
+
+/// Data Set Descriptor - Data Set Contents
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group86Var1<'a> {
+    pub(crate) id: u16,
+    pub(crate) elements: &'a [u8],
+}
+
+/// Data Set - Present Value
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group87Var1<'a> {
+    pub(crate) id: u16,
+    pub(crate) time: Timestamp,
+    pub(crate) values: &'a [u8],
+}
+
+/// Data Set Event - Snapshot
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group88Var1<'a> {
+    pub(crate) id: u16,
+    pub(crate) time: Timestamp,
+    pub(crate) values: &'a [u8],
+}
+
+impl<'a> Group85Var1<'a> {
+    pub(crate) fn read(object: &'a [u8]) -> Result<Self, ObjectParseError> {
+        let mut cursor = ReadCursor::new(object);
+        let id = read_id(&mut cursor)?;
+        let uuid = read_uuid(&mut cursor)?;
+        let elements = cursor.read_all();
+        check_descriptors(elements)?;
+        Ok(Self { id, uuid, elements })
+    }
+
+    pub(crate) fn to_prototype(self) -> DataSetPrototype {
+        DataSetPrototype::new(self.id, self.uuid, descriptors(self.elements))
+    }
+}
+
+impl<'a> Group86Var1<'a> {
+    pub(crate) fn read(object: &'a [u8]) -> Result<Self, ObjectParseError> {
+        let mut cursor = ReadCursor::new(object);
+        let id = read_id(&mut cursor)?;
+        let elements = cursor.read_all();
+        check_descriptors(elements)?;
+        Ok(Self { id, elements })
+    }
+
+    pub(crate) fn to_descriptor(self) -> DataSetDescriptor {
+        DataSetDescriptor::new(self.id, descriptors(self.elements))
+    }
+}
+
+impl<'a> Group87Var1<'a> {
+    pub(crate) fn read(object: &'a [u8]) -> Result<Self, ObjectParseError> {
+        let (id, time, values) = read_data_set(object)?;
+        Ok(Self { id, time, values })
+    }
+
+    pub(crate) fn to_data_set(self, elements: Option<&[&DataSetElement]>) -> DataSet {
+        decode_data_set(self.id, self.time, self.values, elements)
+    }
+}
+
+impl<'a> Group88Var1<'a> {
+    pub(crate) fn read(object: &'a [u8]) -> Result<Self, ObjectParseError> {
+        let (id, time, values) = read_data_set(object)?;
+        Ok(Self { id, time, values })
+    }
+
+    pub(crate) fn to_data_set(self, elements: Option<&[&DataSetElement]>) -> DataSet {
+        decode_data_set(self.id, self.time, self.values, elements)
+    }
+}
+
+/// write an element preceded by its single byte length
+fn write_element<F>(cursor: &mut WriteCursor, write: F) -> Result<(), WriteError>
+where
+    F: FnOnce(&mut WriteCursor) -> Result<(), WriteError>,
+{
+    let pos_of_length = cursor.position();
+    cursor.skip(1)?;
+    let start = cursor.position();
+    write(cursor)?;
+    let length =
+        u8::try_from(cursor.position() - start).map_err(|_| WriteError::NumericOverflow)?;
+    cursor.at_pos(pos_of_length, |cur| cur.write_u8(length))
+}
+
+/// iterator over the length-prefixed elements of a data set object
+struct Elements<'a> {
+    cursor: ReadCursor<'a>,
+}
+
+impl<'a> Elements<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self {
+            cursor: ReadCursor::new(data),
+        }
+    }
+}
+
+impl<'a> Iterator for Elements<'a> {
+    type Item = Result<&'a [u8], DataSetParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cursor.is_empty() {
+            return None;
+        }
+        Some(read_element(&mut self.cursor))
+    }
+}
+
+fn read_element<'a>(cursor: &mut ReadCursor<'a>) -> Result<&'a [u8], DataSetParseError> {
+    let length = cursor.read_u8()?;
+    Ok(cursor.read_bytes(length as usize)?)
+}
+
+fn read_id(cursor: &mut ReadCursor) -> Result<u16, DataSetParseError> {
+    read_unsigned(read_element(cursor)?)
+        .and_then(|x| u16::try_from(x).ok())
+        .ok_or(DataSetParseError::BadId)
+}
+
+fn read_uuid(cursor: &mut ReadCursor) -> Result<[u8; 16], DataSetParseError> {
+    let data = read_element(cursor)?;
+    data.try_into()
+        .map_err(|_| DataSetParseError::BadUuid(data.len()))
+}
+
+fn read_time(cursor: &mut ReadCursor) -> Result<Timestamp, DataSetParseError> {
+    let data = read_element(cursor)?;
+    if data.len() != 6 {
+        return Err(DataSetParseError::BadTime(data.len()));
+    }
+    Ok(Timestamp::new(ReadCursor::new(data).read_u48_le()?))
+}
+
+fn parse_descriptor(data: &[u8]) -> Result<DataSetElement, DataSetParseError> {
+    match data {
+        [code, data_type, max_length, ancillary @ ..] => Ok(DataSetElement::new(
+            DescriptorCode::get(*code).ok_or(DataSetParseError::UnknownDescriptorCode(*code))?,
+            DataSetType::get(*data_type).ok_or(DataSetParseError::UnknownDataType(*data_type))?,
+            *max_length,
+            ancillary.to_vec(),
+        )),
+        _ => Err(DataSetParseError::BadDescriptor(data.len())),
+    }
+}
+
+fn check_descriptors(data: &[u8]) -> Result<(), DataSetParseError> {
+    for element in Elements::new(data) {
+        let element = element?;
+        match element {
+            [code, data_type, _, ..] => {
+                DescriptorCode::get(*code)
+                    .ok_or(DataSetParseError::UnknownDescriptorCode(*code))?;
+                DataSetType::get(*data_type)
+                    .ok_or(DataSetParseError::UnknownDataType(*data_type))?;
+            }
+            _ => return Err(DataSetParseError::BadDescriptor(element.len())),
+        }
+    }
+    Ok(())
+}
+
+/// descriptor elements that have already been validated by `check_descriptors`
+fn descriptors(data: &[u8]) -> Vec<DataSetElement> {
+    Elements::new(data)
+        .filter_map(|x| x.and_then(parse_descriptor).ok())
+        .collect()
+}
+
+fn read_data_set(object: &[u8]) -> Result<(u16, Timestamp, &[u8]), DataSetParseError> {
+    let mut cursor = ReadCursor::new(object);
+    let id = read_id(&mut cursor)?;
+    let time = read_time(&mut cursor)?;
+    let values = cursor.read_all();
+    // check the framing of the values, their types are only known from the descriptor
+    for value in Elements::new(values) {
+        value?;
+    }
+    Ok((id, time, values))
+}
+
+fn decode_data_set(
+    id: u16,
+    time: Timestamp,
+    values: &[u8],
+    elements: Option<&[&DataSetElement]>,
+) -> DataSet {
+    let count = Elements::new(values).count();
+    let elements = match elements {
+        Some(elements) if elements.len() != count => {
+            tracing::warn!(
+                "data set {} contains {} values, but its descriptor defines {}",
+                id,
+                count,
+                elements.len()
+            );
+            None
+        }
+        x => x,
+    };
+
+    let values = Elements::new(values)
+        .filter_map(Result::ok)
+        .enumerate()
+        .map(|(pos, data)| DataSetValue::decode(elements.map(|x| x[pos].data_type), data))
+        .collect();
+
+    DataSet::new(id, time, values)
+}
+
+fn format_elements(f: &mut std::fmt::Formatter, data: &[u8]) -> std::fmt::Result {
+    for element in Elements::new(data).filter_map(Result::ok) {
+        write!(f, "\nelement: {:02X?}", element)?;
+    }
+    Ok(())
+}
+
+impl std::fmt::Display for Group85Var1<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "id: {} uuid: {:02X?}", self.id, self.uuid)?;
+        format_elements(f, self.elements)
+    }
+}
+
+impl std::fmt::Display for Group86Var1<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "id: {}", self.id)?;
+        format_elements(f, self.elements)
+    }
+}
+
+impl std::fmt::Display for Group87Var1<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "id: {} time: {}", self.id, self.time)?;
+        format_elements(f, self.values)
+    }
+}
+
+impl std::fmt::Display for Group88Var1<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "id: {} time: {}", self.id, self.time)?;
+        format_elements(f, self.values)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn encode<T: FreeFormat>(object: &T) -> Vec<u8> {
+        let mut buffer = [0; 256];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        object.write(&mut cursor).unwrap();
+        cursor.written().to_vec()
+    }
+
+    #[test]
+    fn prototype_round_trip() {
+        let prototype = DataSetPrototype::new(
+            3,
+            [0xAB; 16],
+            vec![
+                DataSetElement::name("meter"),
+                DataSetElement::data_element("voltage", DataSetType::FloatingPoint, 4),
+            ],
+        );
+
+        let bytes = encode(&prototype);
+        assert_eq!(&bytes[..3], &[0x02, 0x03, 0x00]);
+        let parsed = Group85Var1::read(&bytes).unwrap();
+        assert_eq!(parsed.id, 3);
+        assert_eq!(parsed.to_prototype(), prototype);
+    }
+
+    #[test]
+    fn rejects_unknown_descriptor_code() {
+        let object = [0x01, 0x07, 0x03, 0x09, 0x01, 0x00];
+        assert_eq!(
+            Group86Var1::read(&object),
+            Err(ObjectParseError::BadDataSet(
+                DataSetParseError::UnknownDescriptorCode(9)
+            ))
+        );
+    }
+
+    #[test]
+    fn decodes_values_using_expanded_prototype() {
+        let prototype = DataSetPrototype::new(
+            1,
+            [0x01; 16],
+            vec![
+                DataSetElement::name("ignored"),
+                DataSetElement::data_element("count", DataSetType::UnsignedInt, 4),
+            ],
+        );
+        let descriptor = DataSetDescriptor::new(
+            7,
+            vec![
+                DataSetElement::data_element("label", DataSetType::VisibleString, 8),
+                DataSetElement::prototype([0x01; 16]),
+            ],
+        );
+        let elements = descriptor
+            .value_elements(|uuid| (uuid == prototype.uuid).then_some(&prototype))
+            .unwrap();
+
+        let data_set = DataSet::new(
+            7,
+            Timestamp::new(42),
+            vec![
+                DataSetValue::VisibleString("abc".to_string()),
+                DataSetValue::UnsignedInt(300),
+            ],
+        );
+        assert!(data_set
+            .values
+            .iter()
+            .zip(elements.iter())
+            .all(|(v, e)| v.matches(e)));
+
+        let bytes = encode(&PresentValue(&data_set));
+        let parsed = Group87Var1::read(&bytes).unwrap();
+        assert_eq!(parsed.to_data_set(Some(&elements)), data_set);
+        // without the descriptor the values can't be typed
+        assert_eq!(
+            parsed.to_data_set(None).values,
+            vec![
+                DataSetValue::Unknown(b"abc".to_vec()),
+                DataSetValue::Unknown(vec![0x2C, 0x01, 0x00, 0x00]),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_values_that_extend_beyond_object() {
+        let object = [0x01, 0x07, 0x06, 0, 0, 0, 0, 0, 0, 0x04, 0x01];
+        assert_eq!(
+            Group88Var1::read(&object),
+            Err(ObjectParseError::BadDataSet(DataSetParseError::Truncated))
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use crate::app::control::*;
 use crate::app::measurement::Flags;
 use crate::app::measurement::*;
+use crate::app::variations::Group12Var2;
 use crate::app::{FunctionCode, QualifierCode};
 use crate::util::bit::bits::{BIT_6, BIT_7};
 
@@ -47,6 +48,30 @@ impl Group12Var1 {
     }
 }
 
+impl From<Group12Var1> for Group12Var2 {
+    fn from(value: Group12Var1) -> Self {
+        Self {
+            code: value.code,
+            count: value.count,
+            on_time: value.on_time,
+            off_time: value.off_time,
+            status: value.status,
+        }
+    }
+}
+
+impl From<Group12Var2> for Group12Var1 {
+    fn from(value: Group12Var2) -> Self {
+        Self {
+            code: value.code,
+            count: value.count,
+            on_time: value.on_time,
+            off_time: value.off_time,
+            status: value.status,
+        }
+    }
+}
+
 impl Group41Var1 {
     /// construct a `Group41Var1` instance. The status field is automatically set to `CommandStatus::Success`
     pub const fn new(value: i32) -> Self {
@@ -127,12 +152,29 @@ impl WireFlags for AnalogInput {
     }
 }
 
+impl WireFlags for FrozenAnalogInput {
+    fn get_wire_flags(&self) -> u8 {
+        self.flags.value
+    }
+}
+
 impl WireFlags for AnalogOutputStatus {
     fn get_wire_flags(&self) -> u8 {
         self.flags.value
     }
 }
 
+impl WireFlags for BinaryOutputCommandEvent {
+    fn get_wire_flags(&self) -> u8 {
+        let status = self.status.as_u8() & 0x7F;
+        if self.commanded_state {
+            status | BIT_7.value
+        } else {
+            status
+        }
+    }
+}
+
 impl AnalogConversions for AnalogInput {
     fn get_value(&self) -> f64 {
         self.value
@@ -143,6 +185,16 @@ impl AnalogConversions for AnalogInput {
     }
 }
 
+impl AnalogConversions for FrozenAnalogInput {
+    fn get_value(&self) -> f64 {
+        self.value
+    }
+
+    fn get_flags(&self) -> Flags {
+        self.flags
+    }
+}
+
 impl AnalogConversions for AnalogOutputStatus {
     fn get_value(&self) -> f64 {
         self.value
@@ -153,6 +205,17 @@ impl AnalogConversions for AnalogOutputStatus {
     }
 }
 
+impl AnalogConversions for AnalogOutputCommandEvent {
+    fn get_value(&self) -> f64 {
+        self.commanded_value
+    }
+
+    // command events have no flags, so the over-range bit is discarded
+    fn get_flags(&self) -> Flags {
+        Flags::new(0)
+    }
+}
+
 impl CommandStatus {
     pub(crate) fn is_success(self) -> bool {
         self == CommandStatus::Success
@@ -216,6 +279,29 @@ impl FunctionInfo {
 }
 
 impl FunctionCode {
+    /// true if the outstation never responds to requests with this function code
+    pub(crate) fn is_no_response(&self) -> bool {
+        matches!(
+            self,
+            FunctionCode::DirectOperateNoResponse
+                | FunctionCode::ImmediateFreezeNoResponse
+                | FunctionCode::FreezeClearNoResponse
+                | FunctionCode::FreezeAtTimeNoResponse
+                | FunctionCode::AuthenticateRequestNoResponse
+        )
+    }
+
+    /// true if the function code may be sent by a master in a request
+    pub(crate) fn is_request(&self) -> bool {
+        !matches!(
+            self,
+            FunctionCode::Confirm
+                | FunctionCode::Response
+                | FunctionCode::UnsolicitedResponse
+                | FunctionCode::AuthenticateResponse
+        )
+    }
+
     pub(crate) fn get_function_info(&self) -> FunctionInfo {
         match self {
             // confirm
@@ -245,6 +331,9 @@ impl FunctionCode {
             FunctionCode::GetFileInfo => FunctionInfo::request_with_objects(),
             FunctionCode::AuthenticateFile => FunctionInfo::request_with_objects(),
             FunctionCode::AbortFile => FunctionInfo::request_with_objects(),
+            FunctionCode::ActivateConfig => FunctionInfo::request_with_objects(),
+            FunctionCode::AuthenticateRequest => FunctionInfo::request_with_objects(),
+            FunctionCode::AuthenticateRequestNoResponse => FunctionInfo::request_with_objects(),
             // requests that never have object headers
             FunctionCode::ColdRestart => FunctionInfo::request_by_function_only(),
             FunctionCode::WarmRestart => FunctionInfo::request_by_function_only(),
@@ -255,6 +344,7 @@ impl FunctionCode {
             // responses
             FunctionCode::Response => FunctionInfo::response(),
             FunctionCode::UnsolicitedResponse => FunctionInfo::response(),
+            FunctionCode::AuthenticateResponse => FunctionInfo::response(),
         }
     }
 }
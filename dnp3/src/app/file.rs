@@ -0,0 +1,686 @@
+use crate::app::parse_error::ObjectParseError;
+use crate::app::variations::Variation;
+use crate::app::{FileStatus, Timestamp};
+
+use scursor::{ReadCursor, WriteCursor, WriteError};
+
+/// Mode used when opening a file (g70v3)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FileMode {
+    /// Null mode, used when deleting a file
+    Null,
+    /// Open the file for reading
+    Read,
+    /// Open the file for writing, truncating any existing content
+    Write,
+    /// Open the file for writing, appending to any existing content
+    Append,
+    /// Mode not defined by the standard
+    Unknown(u16),
+}
+
+impl FileMode {
+    pub(crate) fn new(value: u16) -> Self {
+        match value {
+            0 => Self::Null,
+            1 => Self::Read,
+            2 => Self::Write,
+            3 => Self::Append,
+            _ => Self::Unknown(value),
+        }
+    }
+
+    pub(crate) fn to_u16(self) -> u16 {
+        match self {
+            Self::Null => 0,
+            Self::Read => 1,
+            Self::Write => 2,
+            Self::Append => 3,
+            Self::Unknown(x) => x,
+        }
+    }
+}
+
+/// Type of a file reported in a file descriptor (g70v7)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FileType {
+    /// The file is a directory
+    Directory,
+    /// The file is a simple file
+    Simple,
+    /// Type not defined by the standard
+    Unknown(u16),
+}
+
+impl FileType {
+    pub(crate) fn new(value: u16) -> Self {
+        match value {
+            0 => Self::Directory,
+            1 => Self::Simple,
+            _ => Self::Unknown(value),
+        }
+    }
+
+    pub(crate) fn to_u16(self) -> u16 {
+        match self {
+            Self::Directory => 0,
+            Self::Simple => 1,
+            Self::Unknown(x) => x,
+        }
+    }
+}
+
+/// Read, write, and execute permissions for a class of users
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PermissionSet {
+    /// permission to execute
+    pub execute: bool,
+    /// permission to write
+    pub write: bool,
+    /// permission to read
+    pub read: bool,
+}
+
+impl PermissionSet {
+    /// permission set with every permission granted
+    pub fn all() -> Self {
+        Self {
+            execute: true,
+            write: true,
+            read: true,
+        }
+    }
+
+    fn new(bits: u16) -> Self {
+        Self {
+            execute: bits & 0x01 != 0,
+            write: bits & 0x02 != 0,
+            read: bits & 0x04 != 0,
+        }
+    }
+
+    fn bits(self) -> u16 {
+        let mut value = 0;
+        if self.execute {
+            value |= 0x01;
+        }
+        if self.write {
+            value |= 0x02;
+        }
+        if self.read {
+            value |= 0x04;
+        }
+        value
+    }
+}
+
+/// File permissions as encoded in g70v3 and g70v7
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Permissions {
+    /// permissions granted to everyone
+    pub world: PermissionSet,
+    /// permissions granted to the group of the owner
+    pub group: PermissionSet,
+    /// permissions granted to the owner
+    pub owner: PermissionSet,
+}
+
+impl Permissions {
+    pub(crate) fn new(value: u16) -> Self {
+        Self {
+            world: PermissionSet::new(value),
+            group: PermissionSet::new(value >> 3),
+            owner: PermissionSet::new(value >> 6),
+        }
+    }
+
+    pub(crate) fn to_u16(self) -> u16 {
+        self.world.bits() | (self.group.bits() << 3) | (self.owner.bits() << 6)
+    }
+}
+
+/// Information about a file or directory, as described by a file descriptor (g70v7)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileInfo {
+    /// name of the file
+    pub file_name: String,
+    /// type of the file
+    pub file_type: FileType,
+    /// size of the file in bytes, or number of entries if the file is a directory
+    pub size: u32,
+    /// time at which the file was created
+    pub time_created: Timestamp,
+    /// permissions of the file
+    pub permissions: Permissions,
+}
+
+impl FileInfo {
+    /// Append this information to `dest` as a file descriptor (g70v7)
+    ///
+    /// The content of a directory read by the master is a sequence of these descriptors.
+    pub fn encode_directory_entry(&self, dest: &mut Vec<u8>) {
+        let mut buffer = vec![0; Group70Var7::FIXED_SIZE + self.file_name.len()];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        if self.to_descriptor(0).write(&mut cursor).is_ok() {
+            dest.extend_from_slice(cursor.written());
+        } else {
+            tracing::warn!("file name too long: {}", self.file_name);
+        }
+    }
+
+    pub(crate) fn to_descriptor(&self, request_id: u16) -> Group70Var7<'_> {
+        Group70Var7 {
+            file_type: self.file_type,
+            file_size: self.size,
+            time_of_creation: self.time_created,
+            permissions: self.permissions,
+            request_id,
+            file_name: &self.file_name,
+        }
+    }
+}
+
+impl From<Group70Var7<'_>> for FileInfo {
+    fn from(x: Group70Var7) -> Self {
+        Self {
+            file_name: x.file_name.to_string(),
+            file_type: x.file_type,
+            size: x.file_size,
+            time_created: x.time_of_creation,
+            permissions: x.permissions,
+        }
+    }
+}
+
+/// variable-length objects that are encoded with the free-format qualifier (0x5B)
+pub(crate) trait FreeFormat {
+    const VARIATION: Variation;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError>;
+}
+
+/// Block number of a file transport object, where the MSB indicates the last block
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct BlockNumber {
+    pub(crate) value: u32,
+    pub(crate) last: bool,
+}
+
+impl BlockNumber {
+    const LAST_BIT: u32 = 0x8000_0000;
+
+    pub(crate) fn new(value: u32, last: bool) -> Self {
+        Self {
+            value: value & !Self::LAST_BIT,
+            last,
+        }
+    }
+
+    fn from_raw(raw: u32) -> Self {
+        Self::new(raw, raw & Self::LAST_BIT != 0)
+    }
+
+    fn raw(self) -> u32 {
+        if self.last {
+            self.value | Self::LAST_BIT
+        } else {
+            self.value
+        }
+    }
+}
+
+impl std::fmt::Display for BlockNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} (last: {})", self.value, self.last)
+    }
+}
+
+/// File-control - Authentication
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group70Var2<'a> {
+    pub(crate) auth_key: u32,
+    pub(crate) user_name: &'a str,
+    pub(crate) password: &'a str,
+}
+
+/// File-control - File command
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group70Var3<'a> {
+    pub(crate) time_of_creation: Timestamp,
+    pub(crate) permissions: Permissions,
+    pub(crate) auth_key: u32,
+    pub(crate) file_size: u32,
+    pub(crate) mode: FileMode,
+    pub(crate) max_block_size: u16,
+    pub(crate) request_id: u16,
+    pub(crate) file_name: &'a str,
+}
+
+/// File-control - File command status
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group70Var4<'a> {
+    pub(crate) file_handle: u32,
+    pub(crate) file_size: u32,
+    pub(crate) max_block_size: u16,
+    pub(crate) request_id: u16,
+    pub(crate) status: FileStatus,
+    pub(crate) text: &'a str,
+}
+
+/// File-control - File transport
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group70Var5<'a> {
+    pub(crate) file_handle: u32,
+    pub(crate) block_number: BlockNumber,
+    pub(crate) data: &'a [u8],
+}
+
+/// File-control - File transport status
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group70Var6<'a> {
+    pub(crate) file_handle: u32,
+    pub(crate) block_number: BlockNumber,
+    pub(crate) status: FileStatus,
+    pub(crate) text: &'a str,
+}
+
+/// File-control - File descriptor
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group70Var7<'a> {
+    pub(crate) file_type: FileType,
+    pub(crate) file_size: u32,
+    pub(crate) time_of_creation: Timestamp,
+    pub(crate) permissions: Permissions,
+    pub(crate) request_id: u16,
+    pub(crate) file_name: &'a str,
+}
+
+/// File-control - File specification string
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group70Var8<'a> {
+    pub(crate) file_specification: &'a str,
+}
+
+fn to_str(bytes: &[u8]) -> Result<&str, ObjectParseError> {
+    std::str::from_utf8(bytes).map_err(|_| ObjectParseError::BadEncoding)
+}
+
+/// extract a string referenced by an offset and size from the start of the object
+fn get_str(object: &[u8], offset: u16, size: u16) -> Result<&str, ObjectParseError> {
+    let start = offset as usize;
+    let end = start + size as usize;
+    match object.get(start..end) {
+        Some(x) => to_str(x),
+        None => Err(ObjectParseError::InsufficientBytes),
+    }
+}
+
+fn str_length(value: &str) -> Result<u16, WriteError> {
+    u16::try_from(value.len()).map_err(|_| WriteError::NumericOverflow)
+}
+
+impl<'a> Group70Var2<'a> {
+    const FIXED_SIZE: u16 = 12;
+
+    pub(crate) fn read(object: &'a [u8]) -> Result<Self, ObjectParseError> {
+        let mut cursor = ReadCursor::new(object);
+        let user_name_offset = cursor.read_u16_le()?;
+        let user_name_size = cursor.read_u16_le()?;
+        let password_offset = cursor.read_u16_le()?;
+        let password_size = cursor.read_u16_le()?;
+        let auth_key = cursor.read_u32_le()?;
+        Ok(Self {
+            auth_key,
+            user_name: get_str(object, user_name_offset, user_name_size)?,
+            password: get_str(object, password_offset, password_size)?,
+        })
+    }
+}
+
+impl FreeFormat for Group70Var2<'_> {
+    const VARIATION: Variation = Variation::Group70Var2;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        let user_name_size = str_length(self.user_name)?;
+        cursor.write_u16_le(Self::FIXED_SIZE)?;
+        cursor.write_u16_le(user_name_size)?;
+        cursor.write_u16_le(Self::FIXED_SIZE + user_name_size)?;
+        cursor.write_u16_le(str_length(self.password)?)?;
+        cursor.write_u32_le(self.auth_key)?;
+        cursor.write_bytes(self.user_name.as_bytes())?;
+        cursor.write_bytes(self.password.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl<'a> Group70Var3<'a> {
+    const FIXED_SIZE: u16 = 26;
+
+    pub(crate) fn read(object: &'a [u8]) -> Result<Self, ObjectParseError> {
+        let mut cursor = ReadCursor::new(object);
+        let file_name_offset = cursor.read_u16_le()?;
+        let file_name_size = cursor.read_u16_le()?;
+        Ok(Self {
+            time_of_creation: Timestamp::new(cursor.read_u48_le()?),
+            permissions: Permissions::new(cursor.read_u16_le()?),
+            auth_key: cursor.read_u32_le()?,
+            file_size: cursor.read_u32_le()?,
+            mode: FileMode::new(cursor.read_u16_le()?),
+            max_block_size: cursor.read_u16_le()?,
+            request_id: cursor.read_u16_le()?,
+            file_name: get_str(object, file_name_offset, file_name_size)?,
+        })
+    }
+}
+
+impl FreeFormat for Group70Var3<'_> {
+    const VARIATION: Variation = Variation::Group70Var3;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u16_le(Self::FIXED_SIZE)?;
+        cursor.write_u16_le(str_length(self.file_name)?)?;
+        self.time_of_creation.write(cursor)?;
+        cursor.write_u16_le(self.permissions.to_u16())?;
+        cursor.write_u32_le(self.auth_key)?;
+        cursor.write_u32_le(self.file_size)?;
+        cursor.write_u16_le(self.mode.to_u16())?;
+        cursor.write_u16_le(self.max_block_size)?;
+        cursor.write_u16_le(self.request_id)?;
+        cursor.write_bytes(self.file_name.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl<'a> Group70Var4<'a> {
+    pub(crate) fn read(object: &'a [u8]) -> Result<Self, ObjectParseError> {
+        let mut cursor = ReadCursor::new(object);
+        Ok(Self {
+            file_handle: cursor.read_u32_le()?,
+            file_size: cursor.read_u32_le()?,
+            max_block_size: cursor.read_u16_le()?,
+            request_id: cursor.read_u16_le()?,
+            status: FileStatus::from(cursor.read_u8()?),
+            text: to_str(cursor.read_all())?,
+        })
+    }
+}
+
+impl FreeFormat for Group70Var4<'_> {
+    const VARIATION: Variation = Variation::Group70Var4;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u32_le(self.file_handle)?;
+        cursor.write_u32_le(self.file_size)?;
+        cursor.write_u16_le(self.max_block_size)?;
+        cursor.write_u16_le(self.request_id)?;
+        self.status.write(cursor)?;
+        cursor.write_bytes(self.text.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl<'a> Group70Var5<'a> {
+    pub(crate) fn read(object: &'a [u8]) -> Result<Self, ObjectParseError> {
+        let mut cursor = ReadCursor::new(object);
+        Ok(Self {
+            file_handle: cursor.read_u32_le()?,
+            block_number: BlockNumber::from_raw(cursor.read_u32_le()?),
+            data: cursor.read_all(),
+        })
+    }
+}
+
+impl FreeFormat for Group70Var5<'_> {
+    const VARIATION: Variation = Variation::Group70Var5;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u32_le(self.file_handle)?;
+        cursor.write_u32_le(self.block_number.raw())?;
+        cursor.write_bytes(self.data)?;
+        Ok(())
+    }
+}
+
+impl<'a> Group70Var6<'a> {
+    pub(crate) fn read(object: &'a [u8]) -> Result<Self, ObjectParseError> {
+        let mut cursor = ReadCursor::new(object);
+        Ok(Self {
+            file_handle: cursor.read_u32_le()?,
+            block_number: BlockNumber::from_raw(cursor.read_u32_le()?),
+            status: FileStatus::from(cursor.read_u8()?),
+            text: to_str(cursor.read_all())?,
+        })
+    }
+}
+
+impl FreeFormat for Group70Var6<'_> {
+    const VARIATION: Variation = Variation::Group70Var6;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u32_le(self.file_handle)?;
+        cursor.write_u32_le(self.block_number.raw())?;
+        self.status.write(cursor)?;
+        cursor.write_bytes(self.text.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl<'a> Group70Var7<'a> {
+    const FIXED_SIZE: usize = 20;
+
+    pub(crate) fn read(object: &'a [u8]) -> Result<Self, ObjectParseError> {
+        let mut cursor = ReadCursor::new(object);
+        let file_name_offset = cursor.read_u16_le()?;
+        let file_name_size = cursor.read_u16_le()?;
+        Ok(Self {
+            file_type: FileType::new(cursor.read_u16_le()?),
+            file_size: cursor.read_u32_le()?,
+            time_of_creation: Timestamp::new(cursor.read_u48_le()?),
+            permissions: Permissions::new(cursor.read_u16_le()?),
+            request_id: cursor.read_u16_le()?,
+            file_name: get_str(object, file_name_offset, file_name_size)?,
+        })
+    }
+
+    /// read a sequence of descriptors without object headers, e.g. the content of a directory
+    pub(crate) fn read_sequence(data: &'a [u8]) -> Result<Vec<Self>, ObjectParseError> {
+        let mut items = Vec::new();
+        let mut cursor = ReadCursor::new(data);
+        while !cursor.is_empty() {
+            // the length of each descriptor is defined by the location of its name
+            let mut peek = cursor;
+            let offset = peek.read_u16_le()? as usize;
+            let size = peek.read_u16_le()? as usize;
+            let object = cursor.read_bytes(offset + size)?;
+            items.push(Self::read(object)?);
+        }
+        Ok(items)
+    }
+}
+
+impl FreeFormat for Group70Var7<'_> {
+    const VARIATION: Variation = Variation::Group70Var7;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u16_le(Self::FIXED_SIZE as u16)?;
+        cursor.write_u16_le(str_length(self.file_name)?)?;
+        cursor.write_u16_le(self.file_type.to_u16())?;
+        cursor.write_u32_le(self.file_size)?;
+        self.time_of_creation.write(cursor)?;
+        cursor.write_u16_le(self.permissions.to_u16())?;
+        cursor.write_u16_le(self.request_id)?;
+        cursor.write_bytes(self.file_name.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl<'a> Group70Var8<'a> {
+    pub(crate) fn read(object: &'a [u8]) -> Result<Self, ObjectParseError> {
+        Ok(Self {
+            file_specification: to_str(object)?,
+        })
+    }
+}
+
+impl FreeFormat for Group70Var8<'_> {
+    const VARIATION: Variation = Variation::Group70Var8;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_bytes(self.file_specification.as_bytes())
+    }
+}
+
+impl std::fmt::Display for Group70Var2<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "auth key: {} user name: {}",
+            self.auth_key, self.user_name
+        )
+    }
+}
+
+impl std::fmt::Display for Group70Var3<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "file name: {} mode: {:?} max block size: {} request id: {} file size: {} auth key: {} time of creation: {} permissions: 0x{:04X}",
+            self.file_name,
+            self.mode,
+            self.max_block_size,
+            self.request_id,
+            self.file_size,
+            self.auth_key,
+            self.time_of_creation,
+            self.permissions.to_u16()
+        )
+    }
+}
+
+impl std::fmt::Display for Group70Var4<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "handle: {} status: {:?} file size: {} max block size: {} request id: {} text: {}",
+            self.file_handle,
+            self.status,
+            self.file_size,
+            self.max_block_size,
+            self.request_id,
+            self.text
+        )
+    }
+}
+
+impl std::fmt::Display for Group70Var5<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "handle: {} block: {} length: {}",
+            self.file_handle,
+            self.block_number,
+            self.data.len()
+        )
+    }
+}
+
+impl std::fmt::Display for Group70Var6<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "handle: {} block: {} status: {:?} text: {}",
+            self.file_handle, self.block_number, self.status, self.text
+        )
+    }
+}
+
+impl std::fmt::Display for Group70Var7<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "file name: {} type: {:?} size: {} time of creation: {} permissions: 0x{:04X} request id: {}",
+            self.file_name,
+            self.file_type,
+            self.file_size,
+            self.time_of_creation,
+            self.permissions.to_u16(),
+            self.request_id
+        )
+    }
+}
+
+impl std::fmt::Display for Group70Var8<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "file specification: {}", self.file_specification)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn permissions_round_trip() {
+        let permissions = Permissions {
+            world: PermissionSet {
+                execute: false,
+                write: false,
+                read: true,
+            },
+            group: PermissionSet::default(),
+            owner: PermissionSet::all(),
+        };
+
+        assert_eq!(permissions.to_u16(), 0x01C4);
+        assert_eq!(Permissions::new(0x01C4), permissions);
+    }
+
+    #[test]
+    fn block_number_uses_msb_as_last_flag() {
+        let block = BlockNumber::from_raw(0x8000_0002);
+        assert_eq!(block, BlockNumber::new(2, true));
+        assert_eq!(block.raw(), 0x8000_0002);
+    }
+
+    #[test]
+    fn reads_sequence_of_descriptors() {
+        let first = FileInfo {
+            file_name: "a.txt".to_string(),
+            file_type: FileType::Simple,
+            size: 42,
+            time_created: Timestamp::new(1000),
+            permissions: Permissions::default(),
+        };
+        let second = FileInfo {
+            file_name: "logs".to_string(),
+            file_type: FileType::Directory,
+            size: 3,
+            time_created: Timestamp::new(2000),
+            permissions: Permissions::new(0x01FF),
+        };
+
+        let mut data = Vec::new();
+        first.encode_directory_entry(&mut data);
+        second.encode_directory_entry(&mut data);
+
+        let items: Vec<FileInfo> = Group70Var7::read_sequence(&data)
+            .unwrap()
+            .into_iter()
+            .map(FileInfo::from)
+            .collect();
+
+        assert_eq!(items, vec![first, second]);
+    }
+
+    #[test]
+    fn rejects_string_beyond_object() {
+        // file name offset 20, size 10, but no file name bytes
+        let mut object = [0u8; 20];
+        object[0] = 20;
+        object[2] = 10;
+        assert_eq!(
+            Group70Var7::read(&object),
+            Err(ObjectParseError::InsufficientBytes)
+        );
+    }
+}
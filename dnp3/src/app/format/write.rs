@@ -1,3 +1,4 @@
+use crate::app::file::FreeFormat;
 use crate::app::header::{ControlField, RequestHeader};
 #[cfg(test)]
 use crate::app::header::{Iin, ResponseFunction, ResponseHeader};
@@ -95,11 +96,76 @@ impl<'a, 'b> HeaderWriter<'a, 'b> {
     }
 
     pub(crate) fn write_clear_restart(&mut self) -> Result<(), WriteError> {
-        self.write_range_only(Variation::Group80Var1, 7u8, 7u8)?;
-        self.cursor.write_u8(0)?;
+        self.write_iin_bit(7, false)
+    }
+
+    pub(crate) fn write_iin_bit(&mut self, index: u8, value: bool) -> Result<(), WriteError> {
+        self.write_range_only(Variation::Group80Var1, index, index)?;
+        self.cursor.write_u8(value as u8)?;
+        Ok(())
+    }
+
+    pub(crate) fn write_octet_string(
+        &mut self,
+        index: u16,
+        value: &[u8],
+    ) -> Result<(), WriteError> {
+        let length = u8::try_from(value.len()).map_err(|_| WriteError::NumericOverflow)?;
+        self.write_range_only(Variation::Group110(length), index, index)?;
+        self.cursor.write_bytes(value)?;
+        Ok(())
+    }
+
+    /// write a single block of virtual terminal output (g112) using 2-byte count and prefix
+    pub(crate) fn write_virtual_terminal_output(
+        &mut self,
+        port: u16,
+        block: &[u8],
+    ) -> Result<(), WriteError> {
+        let length = u8::try_from(block.len()).map_err(|_| WriteError::NumericOverflow)?;
+        Variation::Group112(length).write(self.cursor)?;
+        QualifierCode::CountAndPrefix16.write(self.cursor)?;
+        self.cursor.write_u16_le(1)?;
+        self.cursor.write_u16_le(port)?;
+        self.cursor.write_bytes(block)?;
+        Ok(())
+    }
+
+    /// write a pattern mask (g12v3) over the range [start, stop] as packed bits
+    pub(crate) fn write_pattern_mask<T>(
+        &mut self,
+        start: T,
+        stop: T,
+        mask: impl Iterator<Item = bool>,
+    ) -> Result<(), WriteError>
+    where
+        T: Index,
+    {
+        self.write_range_only(Variation::Group12Var3, start, stop)?;
+        let mut acc: u8 = 0;
+        let mut num_bits: u8 = 0;
+        for bit in mask {
+            if bit {
+                acc |= 1 << num_bits;
+            }
+            num_bits += 1;
+            if num_bits == 8 {
+                self.cursor.write_u8(acc)?;
+                acc = 0;
+                num_bits = 0;
+            }
+        }
+        if num_bits > 0 {
+            self.cursor.write_u8(acc)?;
+        }
         Ok(())
     }
 
+    /// write pre-formatted object header(s) verbatim
+    pub(crate) fn write_raw(&mut self, bytes: &[u8]) -> Result<(), WriteError> {
+        self.cursor.write_bytes(bytes)
+    }
+
     pub(crate) fn write_prefixed_items<'c, V, I>(
         &mut self,
         iter: impl Iterator<Item = &'c (V, I)>,
@@ -134,6 +200,23 @@ impl<'a, 'b> HeaderWriter<'a, 'b> {
         Ok(())
     }
 
+    pub(crate) fn write_free_format<V>(&mut self, item: &V) -> Result<(), WriteError>
+    where
+        V: FreeFormat,
+    {
+        V::VARIATION.write(self.cursor)?;
+        QualifierCode::FreeFormat16.write(self.cursor)?;
+        self.cursor.write_u8(1)?;
+        let pos_of_size = self.cursor.position();
+        self.cursor.skip(2)?;
+        let start = self.cursor.position();
+        item.write(self.cursor)?;
+        let size = u16::try_from(self.cursor.position() - start)
+            .map_err(|_| WriteError::NumericOverflow)?;
+        self.cursor
+            .at_pos(pos_of_size, |cur| cur.write_u16_le(size))
+    }
+
     #[cfg(test)]
     pub(crate) fn to_parsed(&'a self) -> ParsedFragment<'a> {
         ParsedFragment::parse(self.cursor.written()).unwrap()
@@ -14,6 +14,8 @@ use crate::app::variations::Variation;
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub(crate) enum AllObjectsVariation {
+    Group0Var254,
+    Group0(u8),
     Group1Var0,
     Group1Var1,
     Group1Var2,
@@ -34,6 +36,9 @@ pub(crate) enum AllObjectsVariation {
     Group11Var0,
     Group11Var1,
     Group11Var2,
+    Group13Var0,
+    Group13Var1,
+    Group13Var2,
     Group20Var0,
     Group20Var1,
     Group20Var2,
@@ -63,6 +68,15 @@ pub(crate) enum AllObjectsVariation {
     Group30Var4,
     Group30Var5,
     Group30Var6,
+    Group31Var0,
+    Group31Var1,
+    Group31Var2,
+    Group31Var3,
+    Group31Var4,
+    Group31Var5,
+    Group31Var6,
+    Group31Var7,
+    Group31Var8,
     Group32Var0,
     Group32Var1,
     Group32Var2,
@@ -72,6 +86,19 @@ pub(crate) enum AllObjectsVariation {
     Group32Var6,
     Group32Var7,
     Group32Var8,
+    Group33Var0,
+    Group33Var1,
+    Group33Var2,
+    Group33Var3,
+    Group33Var4,
+    Group33Var5,
+    Group33Var6,
+    Group33Var7,
+    Group33Var8,
+    Group34Var0,
+    Group34Var1,
+    Group34Var2,
+    Group34Var3,
     Group40Var0,
     Group40Var1,
     Group40Var2,
@@ -86,18 +113,41 @@ pub(crate) enum AllObjectsVariation {
     Group42Var6,
     Group42Var7,
     Group42Var8,
+    Group43Var0,
+    Group43Var1,
+    Group43Var2,
+    Group43Var3,
+    Group43Var4,
+    Group43Var5,
+    Group43Var6,
+    Group43Var7,
+    Group43Var8,
     Group60Var1,
     Group60Var2,
     Group60Var3,
     Group60Var4,
     Group80Var1,
+    Group85Var1,
+    Group86Var1,
+    Group87Var1,
+    Group88Var1,
+    Group90Var1,
+    Group101Var0,
+    Group101Var1,
+    Group101Var2,
+    Group101Var3,
+    Group102Var0,
+    Group102Var1,
     Group110Var0,
     Group111Var0,
+    Group113Var0,
 }
 
 impl AllObjectsVariation {
     pub(crate) fn get(v: Variation) -> Option<AllObjectsVariation> {
         match v {
+            Variation::Group0Var254 => Some(AllObjectsVariation::Group0Var254),
+            Variation::Group0(x) => Some(AllObjectsVariation::Group0(x)),
             Variation::Group1Var0 => Some(AllObjectsVariation::Group1Var0),
             Variation::Group1Var1 => Some(AllObjectsVariation::Group1Var1),
             Variation::Group1Var2 => Some(AllObjectsVariation::Group1Var2),
@@ -118,6 +168,9 @@ impl AllObjectsVariation {
             Variation::Group11Var0 => Some(AllObjectsVariation::Group11Var0),
             Variation::Group11Var1 => Some(AllObjectsVariation::Group11Var1),
             Variation::Group11Var2 => Some(AllObjectsVariation::Group11Var2),
+            Variation::Group13Var0 => Some(AllObjectsVariation::Group13Var0),
+            Variation::Group13Var1 => Some(AllObjectsVariation::Group13Var1),
+            Variation::Group13Var2 => Some(AllObjectsVariation::Group13Var2),
             Variation::Group20Var0 => Some(AllObjectsVariation::Group20Var0),
             Variation::Group20Var1 => Some(AllObjectsVariation::Group20Var1),
             Variation::Group20Var2 => Some(AllObjectsVariation::Group20Var2),
@@ -147,6 +200,15 @@ impl AllObjectsVariation {
             Variation::Group30Var4 => Some(AllObjectsVariation::Group30Var4),
             Variation::Group30Var5 => Some(AllObjectsVariation::Group30Var5),
             Variation::Group30Var6 => Some(AllObjectsVariation::Group30Var6),
+            Variation::Group31Var0 => Some(AllObjectsVariation::Group31Var0),
+            Variation::Group31Var1 => Some(AllObjectsVariation::Group31Var1),
+            Variation::Group31Var2 => Some(AllObjectsVariation::Group31Var2),
+            Variation::Group31Var3 => Some(AllObjectsVariation::Group31Var3),
+            Variation::Group31Var4 => Some(AllObjectsVariation::Group31Var4),
+            Variation::Group31Var5 => Some(AllObjectsVariation::Group31Var5),
+            Variation::Group31Var6 => Some(AllObjectsVariation::Group31Var6),
+            Variation::Group31Var7 => Some(AllObjectsVariation::Group31Var7),
+            Variation::Group31Var8 => Some(AllObjectsVariation::Group31Var8),
             Variation::Group32Var0 => Some(AllObjectsVariation::Group32Var0),
             Variation::Group32Var1 => Some(AllObjectsVariation::Group32Var1),
             Variation::Group32Var2 => Some(AllObjectsVariation::Group32Var2),
@@ -156,6 +218,19 @@ impl AllObjectsVariation {
             Variation::Group32Var6 => Some(AllObjectsVariation::Group32Var6),
             Variation::Group32Var7 => Some(AllObjectsVariation::Group32Var7),
             Variation::Group32Var8 => Some(AllObjectsVariation::Group32Var8),
+            Variation::Group33Var0 => Some(AllObjectsVariation::Group33Var0),
+            Variation::Group33Var1 => Some(AllObjectsVariation::Group33Var1),
+            Variation::Group33Var2 => Some(AllObjectsVariation::Group33Var2),
+            Variation::Group33Var3 => Some(AllObjectsVariation::Group33Var3),
+            Variation::Group33Var4 => Some(AllObjectsVariation::Group33Var4),
+            Variation::Group33Var5 => Some(AllObjectsVariation::Group33Var5),
+            Variation::Group33Var6 => Some(AllObjectsVariation::Group33Var6),
+            Variation::Group33Var7 => Some(AllObjectsVariation::Group33Var7),
+            Variation::Group33Var8 => Some(AllObjectsVariation::Group33Var8),
+            Variation::Group34Var0 => Some(AllObjectsVariation::Group34Var0),
+            Variation::Group34Var1 => Some(AllObjectsVariation::Group34Var1),
+            Variation::Group34Var2 => Some(AllObjectsVariation::Group34Var2),
+            Variation::Group34Var3 => Some(AllObjectsVariation::Group34Var3),
             Variation::Group40Var0 => Some(AllObjectsVariation::Group40Var0),
             Variation::Group40Var1 => Some(AllObjectsVariation::Group40Var1),
             Variation::Group40Var2 => Some(AllObjectsVariation::Group40Var2),
@@ -170,13 +245,34 @@ impl AllObjectsVariation {
             Variation::Group42Var6 => Some(AllObjectsVariation::Group42Var6),
             Variation::Group42Var7 => Some(AllObjectsVariation::Group42Var7),
             Variation::Group42Var8 => Some(AllObjectsVariation::Group42Var8),
+            Variation::Group43Var0 => Some(AllObjectsVariation::Group43Var0),
+            Variation::Group43Var1 => Some(AllObjectsVariation::Group43Var1),
+            Variation::Group43Var2 => Some(AllObjectsVariation::Group43Var2),
+            Variation::Group43Var3 => Some(AllObjectsVariation::Group43Var3),
+            Variation::Group43Var4 => Some(AllObjectsVariation::Group43Var4),
+            Variation::Group43Var5 => Some(AllObjectsVariation::Group43Var5),
+            Variation::Group43Var6 => Some(AllObjectsVariation::Group43Var6),
+            Variation::Group43Var7 => Some(AllObjectsVariation::Group43Var7),
+            Variation::Group43Var8 => Some(AllObjectsVariation::Group43Var8),
             Variation::Group60Var1 => Some(AllObjectsVariation::Group60Var1),
             Variation::Group60Var2 => Some(AllObjectsVariation::Group60Var2),
             Variation::Group60Var3 => Some(AllObjectsVariation::Group60Var3),
             Variation::Group60Var4 => Some(AllObjectsVariation::Group60Var4),
             Variation::Group80Var1 => Some(AllObjectsVariation::Group80Var1),
+            Variation::Group85Var1 => Some(AllObjectsVariation::Group85Var1),
+            Variation::Group86Var1 => Some(AllObjectsVariation::Group86Var1),
+            Variation::Group87Var1 => Some(AllObjectsVariation::Group87Var1),
+            Variation::Group88Var1 => Some(AllObjectsVariation::Group88Var1),
+            Variation::Group90Var1 => Some(AllObjectsVariation::Group90Var1),
+            Variation::Group101Var0 => Some(AllObjectsVariation::Group101Var0),
+            Variation::Group101Var1 => Some(AllObjectsVariation::Group101Var1),
+            Variation::Group101Var2 => Some(AllObjectsVariation::Group101Var2),
+            Variation::Group101Var3 => Some(AllObjectsVariation::Group101Var3),
+            Variation::Group102Var0 => Some(AllObjectsVariation::Group102Var0),
+            Variation::Group102Var1 => Some(AllObjectsVariation::Group102Var1),
             Variation::Group110(0) => Some(AllObjectsVariation::Group110Var0),
             Variation::Group111(0) => Some(AllObjectsVariation::Group111Var0),
+            Variation::Group113(0) => Some(AllObjectsVariation::Group113Var0),
             _ => None,
         }
     }
@@ -10,6 +10,7 @@
 // This file is auto-generated. Do not edit manually
 //
 
+use crate::app::control::CommandStatus;
 use crate::app::measurement::*;
 use crate::app::variations::*;
 
@@ -816,6 +817,328 @@ impl ToVariation<Group30Var1> for AnalogInput {
 }
 
 
+impl From<Group33Var8> for FrozenAnalogInput {
+    fn from(v: Group33Var8) -> Self {
+        FrozenAnalogInput {
+            value : v.value,
+            flags: Flags::new(v.flags),
+            time : Some(Time::Synchronized(v.time)),
+        }
+    }
+}
+
+impl ToVariation<Group33Var8> for FrozenAnalogInput {
+    fn to_variation(&self) -> Group33Var8 {
+        Group33Var8 {
+            flags: self.flags.value,
+            value: self.value,
+            time: self.time.into(),
+        }
+    }
+}
+
+impl From<Group33Var7> for FrozenAnalogInput {
+    fn from(v: Group33Var7) -> Self {
+        FrozenAnalogInput {
+            value : v.value as f64,
+            flags: Flags::new(v.flags),
+            time : Some(Time::Synchronized(v.time)),
+        }
+    }
+}
+
+impl ToVariation<Group33Var7> for FrozenAnalogInput {
+    fn to_variation(&self) -> Group33Var7 {
+        let (_wire_flags, _wire_value) = self.to_f32();
+        Group33Var7 {
+            flags: _wire_flags.value,
+            value: _wire_value,
+            time: self.time.into(),
+        }
+    }
+}
+
+impl From<Group33Var6> for FrozenAnalogInput {
+    fn from(v: Group33Var6) -> Self {
+        FrozenAnalogInput {
+            value : v.value,
+            flags: Flags::new(v.flags),
+            time : None,
+        }
+    }
+}
+
+impl ToVariation<Group33Var6> for FrozenAnalogInput {
+    fn to_variation(&self) -> Group33Var6 {
+        Group33Var6 {
+            flags: self.flags.value,
+            value: self.value,
+        }
+    }
+}
+
+impl From<Group33Var5> for FrozenAnalogInput {
+    fn from(v: Group33Var5) -> Self {
+        FrozenAnalogInput {
+            value : v.value as f64,
+            flags: Flags::new(v.flags),
+            time : None,
+        }
+    }
+}
+
+impl ToVariation<Group33Var5> for FrozenAnalogInput {
+    fn to_variation(&self) -> Group33Var5 {
+        let (_wire_flags, _wire_value) = self.to_f32();
+        Group33Var5 {
+            flags: _wire_flags.value,
+            value: _wire_value,
+        }
+    }
+}
+
+impl From<Group33Var4> for FrozenAnalogInput {
+    fn from(v: Group33Var4) -> Self {
+        FrozenAnalogInput {
+            value : v.value as f64,
+            flags: Flags::new(v.flags),
+            time : Some(Time::Synchronized(v.time)),
+        }
+    }
+}
+
+impl ToVariation<Group33Var4> for FrozenAnalogInput {
+    fn to_variation(&self) -> Group33Var4 {
+        let (_wire_flags, _wire_value) = self.to_i16();
+        Group33Var4 {
+            flags: _wire_flags.value,
+            value: _wire_value,
+            time: self.time.into(),
+        }
+    }
+}
+
+impl From<Group33Var3> for FrozenAnalogInput {
+    fn from(v: Group33Var3) -> Self {
+        FrozenAnalogInput {
+            value : v.value as f64,
+            flags: Flags::new(v.flags),
+            time : Some(Time::Synchronized(v.time)),
+        }
+    }
+}
+
+impl ToVariation<Group33Var3> for FrozenAnalogInput {
+    fn to_variation(&self) -> Group33Var3 {
+        let (_wire_flags, _wire_value) = self.to_i32();
+        Group33Var3 {
+            flags: _wire_flags.value,
+            value: _wire_value,
+            time: self.time.into(),
+        }
+    }
+}
+
+impl From<Group33Var2> for FrozenAnalogInput {
+    fn from(v: Group33Var2) -> Self {
+        FrozenAnalogInput {
+            value : v.value as f64,
+            flags: Flags::new(v.flags),
+            time : None,
+        }
+    }
+}
+
+impl ToVariation<Group33Var2> for FrozenAnalogInput {
+    fn to_variation(&self) -> Group33Var2 {
+        let (_wire_flags, _wire_value) = self.to_i16();
+        Group33Var2 {
+            flags: _wire_flags.value,
+            value: _wire_value,
+        }
+    }
+}
+
+impl From<Group33Var1> for FrozenAnalogInput {
+    fn from(v: Group33Var1) -> Self {
+        FrozenAnalogInput {
+            value : v.value as f64,
+            flags: Flags::new(v.flags),
+            time : None,
+        }
+    }
+}
+
+impl ToVariation<Group33Var1> for FrozenAnalogInput {
+    fn to_variation(&self) -> Group33Var1 {
+        let (_wire_flags, _wire_value) = self.to_i32();
+        Group33Var1 {
+            flags: _wire_flags.value,
+            value: _wire_value,
+        }
+    }
+}
+
+impl From<Group31Var8> for FrozenAnalogInput {
+    fn from(v: Group31Var8) -> Self {
+        FrozenAnalogInput {
+            value : v.value,
+            flags: Flags::new(v.flags),
+            time : None,
+        }
+    }
+}
+
+impl ToVariation<Group31Var8> for FrozenAnalogInput {
+    fn to_variation(&self) -> Group31Var8 {
+        Group31Var8 {
+            flags: self.flags.value,
+            value: self.value,
+        }
+    }
+}
+
+impl From<Group31Var7> for FrozenAnalogInput {
+    fn from(v: Group31Var7) -> Self {
+        FrozenAnalogInput {
+            value : v.value as f64,
+            flags: Flags::new(v.flags),
+            time : None,
+        }
+    }
+}
+
+impl ToVariation<Group31Var7> for FrozenAnalogInput {
+    fn to_variation(&self) -> Group31Var7 {
+        let (_wire_flags, _wire_value) = self.to_f32();
+        Group31Var7 {
+            flags: _wire_flags.value,
+            value: _wire_value,
+        }
+    }
+}
+
+impl From<Group31Var6> for FrozenAnalogInput {
+    fn from(v: Group31Var6) -> Self {
+        FrozenAnalogInput {
+            value : v.value as f64,
+            flags: Flags::ONLINE,
+            time : None,
+        }
+    }
+}
+
+impl ToVariation<Group31Var6> for FrozenAnalogInput {
+    fn to_variation(&self) -> Group31Var6 {
+        let (_wire_flags, _wire_value) = self.to_i16();
+        Group31Var6 {
+            value: _wire_value,
+        }
+    }
+}
+
+impl From<Group31Var5> for FrozenAnalogInput {
+    fn from(v: Group31Var5) -> Self {
+        FrozenAnalogInput {
+            value : v.value as f64,
+            flags: Flags::ONLINE,
+            time : None,
+        }
+    }
+}
+
+impl ToVariation<Group31Var5> for FrozenAnalogInput {
+    fn to_variation(&self) -> Group31Var5 {
+        let (_wire_flags, _wire_value) = self.to_i32();
+        Group31Var5 {
+            value: _wire_value,
+        }
+    }
+}
+
+impl From<Group31Var4> for FrozenAnalogInput {
+    fn from(v: Group31Var4) -> Self {
+        FrozenAnalogInput {
+            value : v.value as f64,
+            flags: Flags::new(v.flags),
+            time : Some(Time::Synchronized(v.time)),
+        }
+    }
+}
+
+impl ToVariation<Group31Var4> for FrozenAnalogInput {
+    fn to_variation(&self) -> Group31Var4 {
+        let (_wire_flags, _wire_value) = self.to_i16();
+        Group31Var4 {
+            flags: _wire_flags.value,
+            value: _wire_value,
+            time: self.time.into(),
+        }
+    }
+}
+
+impl From<Group31Var3> for FrozenAnalogInput {
+    fn from(v: Group31Var3) -> Self {
+        FrozenAnalogInput {
+            value : v.value as f64,
+            flags: Flags::new(v.flags),
+            time : Some(Time::Synchronized(v.time)),
+        }
+    }
+}
+
+impl ToVariation<Group31Var3> for FrozenAnalogInput {
+    fn to_variation(&self) -> Group31Var3 {
+        let (_wire_flags, _wire_value) = self.to_i32();
+        Group31Var3 {
+            flags: _wire_flags.value,
+            value: _wire_value,
+            time: self.time.into(),
+        }
+    }
+}
+
+impl From<Group31Var2> for FrozenAnalogInput {
+    fn from(v: Group31Var2) -> Self {
+        FrozenAnalogInput {
+            value : v.value as f64,
+            flags: Flags::new(v.flags),
+            time : None,
+        }
+    }
+}
+
+impl ToVariation<Group31Var2> for FrozenAnalogInput {
+    fn to_variation(&self) -> Group31Var2 {
+        let (_wire_flags, _wire_value) = self.to_i16();
+        Group31Var2 {
+            flags: _wire_flags.value,
+            value: _wire_value,
+        }
+    }
+}
+
+impl From<Group31Var1> for FrozenAnalogInput {
+    fn from(v: Group31Var1) -> Self {
+        FrozenAnalogInput {
+            value : v.value as f64,
+            flags: Flags::new(v.flags),
+            time : None,
+        }
+    }
+}
+
+impl ToVariation<Group31Var1> for FrozenAnalogInput {
+    fn to_variation(&self) -> Group31Var1 {
+        let (_wire_flags, _wire_value) = self.to_i32();
+        Group31Var1 {
+            flags: _wire_flags.value,
+            value: _wire_value,
+        }
+    }
+}
+
+
 impl From<Group42Var8> for AnalogOutputStatus {
     fn from(v: Group42Var8) -> Self {
         AnalogOutputStatus {
@@ -1057,3 +1380,260 @@ impl ToVariation<Group40Var1> for AnalogOutputStatus {
     }
 }
 
+impl From<Group13Var2> for BinaryOutputCommandEvent {
+    fn from(v: Group13Var2) -> Self {
+        BinaryOutputCommandEvent {
+            commanded_state : Flags::new(v.flags).state(),
+            status: CommandStatus::from(v.flags & 0x7F),
+            time : Some(Time::Synchronized(v.time)),
+        }
+    }
+}
+
+impl ToVariation<Group13Var2> for BinaryOutputCommandEvent {
+    fn to_variation(&self) -> Group13Var2 {
+        Group13Var2 {
+            flags: self.get_wire_flags(),
+            time: self.time.into(),
+        }
+    }
+}
+
+impl From<Group13Var1> for BinaryOutputCommandEvent {
+    fn from(v: Group13Var1) -> Self {
+        BinaryOutputCommandEvent {
+            commanded_state : Flags::new(v.flags).state(),
+            status: CommandStatus::from(v.flags & 0x7F),
+            time : None,
+        }
+    }
+}
+
+impl ToVariation<Group13Var1> for BinaryOutputCommandEvent {
+    fn to_variation(&self) -> Group13Var1 {
+        Group13Var1 {
+            flags: self.get_wire_flags(),
+        }
+    }
+}
+
+impl From<Group43Var8> for AnalogOutputCommandEvent {
+    fn from(v: Group43Var8) -> Self {
+        AnalogOutputCommandEvent {
+            commanded_value : v.value,
+            status: v.status,
+            time : Some(Time::Synchronized(v.time)),
+        }
+    }
+}
+
+impl ToVariation<Group43Var8> for AnalogOutputCommandEvent {
+    fn to_variation(&self) -> Group43Var8 {
+        Group43Var8 {
+            status: self.status,
+            value: self.commanded_value,
+            time: self.time.into(),
+        }
+    }
+}
+
+impl From<Group43Var7> for AnalogOutputCommandEvent {
+    fn from(v: Group43Var7) -> Self {
+        AnalogOutputCommandEvent {
+            commanded_value : v.value as f64,
+            status: v.status,
+            time : Some(Time::Synchronized(v.time)),
+        }
+    }
+}
+
+impl ToVariation<Group43Var7> for AnalogOutputCommandEvent {
+    fn to_variation(&self) -> Group43Var7 {
+        let (_wire_flags, _wire_value) = self.to_f32();
+        Group43Var7 {
+            status: self.status,
+            value: _wire_value,
+            time: self.time.into(),
+        }
+    }
+}
+
+impl From<Group43Var6> for AnalogOutputCommandEvent {
+    fn from(v: Group43Var6) -> Self {
+        AnalogOutputCommandEvent {
+            commanded_value : v.value,
+            status: v.status,
+            time : None,
+        }
+    }
+}
+
+impl ToVariation<Group43Var6> for AnalogOutputCommandEvent {
+    fn to_variation(&self) -> Group43Var6 {
+        Group43Var6 {
+            status: self.status,
+            value: self.commanded_value,
+        }
+    }
+}
+
+impl From<Group43Var5> for AnalogOutputCommandEvent {
+    fn from(v: Group43Var5) -> Self {
+        AnalogOutputCommandEvent {
+            commanded_value : v.value as f64,
+            status: v.status,
+            time : None,
+        }
+    }
+}
+
+impl ToVariation<Group43Var5> for AnalogOutputCommandEvent {
+    fn to_variation(&self) -> Group43Var5 {
+        let (_wire_flags, _wire_value) = self.to_f32();
+        Group43Var5 {
+            status: self.status,
+            value: _wire_value,
+        }
+    }
+}
+
+impl From<Group43Var4> for AnalogOutputCommandEvent {
+    fn from(v: Group43Var4) -> Self {
+        AnalogOutputCommandEvent {
+            commanded_value : v.value as f64,
+            status: v.status,
+            time : Some(Time::Synchronized(v.time)),
+        }
+    }
+}
+
+impl ToVariation<Group43Var4> for AnalogOutputCommandEvent {
+    fn to_variation(&self) -> Group43Var4 {
+        let (_wire_flags, _wire_value) = self.to_i16();
+        Group43Var4 {
+            status: self.status,
+            value: _wire_value,
+            time: self.time.into(),
+        }
+    }
+}
+
+impl From<Group43Var3> for AnalogOutputCommandEvent {
+    fn from(v: Group43Var3) -> Self {
+        AnalogOutputCommandEvent {
+            commanded_value : v.value as f64,
+            status: v.status,
+            time : Some(Time::Synchronized(v.time)),
+        }
+    }
+}
+
+impl ToVariation<Group43Var3> for AnalogOutputCommandEvent {
+    fn to_variation(&self) -> Group43Var3 {
+        let (_wire_flags, _wire_value) = self.to_i32();
+        Group43Var3 {
+            status: self.status,
+            value: _wire_value,
+            time: self.time.into(),
+        }
+    }
+}
+
+impl From<Group43Var2> for AnalogOutputCommandEvent {
+    fn from(v: Group43Var2) -> Self {
+        AnalogOutputCommandEvent {
+            commanded_value : v.value as f64,
+            status: v.status,
+            time : None,
+        }
+    }
+}
+
+impl ToVariation<Group43Var2> for AnalogOutputCommandEvent {
+    fn to_variation(&self) -> Group43Var2 {
+        let (_wire_flags, _wire_value) = self.to_i16();
+        Group43Var2 {
+            status: self.status,
+            value: _wire_value,
+        }
+    }
+}
+
+impl From<Group43Var1> for AnalogOutputCommandEvent {
+    fn from(v: Group43Var1) -> Self {
+        AnalogOutputCommandEvent {
+            commanded_value : v.value as f64,
+            status: v.status,
+            time : None,
+        }
+    }
+}
+
+impl ToVariation<Group43Var1> for AnalogOutputCommandEvent {
+    fn to_variation(&self) -> Group43Var1 {
+        let (_wire_flags, _wire_value) = self.to_i32();
+        Group43Var1 {
+            status: self.status,
+            value: _wire_value,
+        }
+    }
+}
+
+
+impl From<Group101Var3> for Bcd {
+    fn from(v: Group101Var3) -> Self {
+        Bcd::decode(v.value)
+    }
+}
+
+impl ToVariation<Group101Var3> for Bcd {
+    fn to_variation(&self) -> Group101Var3 {
+        Group101Var3 {
+            value: self.encode(16),
+        }
+    }
+}
+
+impl From<Group101Var2> for Bcd {
+    fn from(v: Group101Var2) -> Self {
+        Bcd::decode(v.value as u64)
+    }
+}
+
+impl ToVariation<Group101Var2> for Bcd {
+    fn to_variation(&self) -> Group101Var2 {
+        Group101Var2 {
+            value: self.encode(8) as u32,
+        }
+    }
+}
+
+impl From<Group101Var1> for Bcd {
+    fn from(v: Group101Var1) -> Self {
+        Bcd::decode(v.value as u64)
+    }
+}
+
+impl ToVariation<Group101Var1> for Bcd {
+    fn to_variation(&self) -> Group101Var1 {
+        Group101Var1 {
+            value: self.encode(4) as u16,
+        }
+    }
+}
+
+impl From<Group102Var1> for UnsignedInteger {
+    fn from(v: Group102Var1) -> Self {
+        UnsignedInteger {
+            value: v.value,
+        }
+    }
+}
+
+impl ToVariation<Group102Var1> for UnsignedInteger {
+    fn to_variation(&self) -> Group102Var1 {
+        Group102Var1 {
+            value: self.value,
+        }
+    }
+}
@@ -42,6 +42,16 @@ pub(crate) enum CountVariation<'a> {
     Group11Var1,
     /// Binary Output Event - Output Status With Time
     Group11Var2,
+    /// Binary Command - Pattern Control Block
+    Group12Var2(CountSequence<'a, Group12Var2>),
+    /// Binary Output Command Event - Any Variation
+    Group13Var0,
+    /// Binary Output Command Event - Without Time
+    Group13Var1,
+    /// Binary Output Command Event - With Time
+    Group13Var2,
+    /// Counter - Any Variation
+    Group20Var0,
     /// Counter Event - Any Variation
     Group22Var0,
     /// Counter Event - 32-bit With Flag
@@ -80,6 +90,24 @@ pub(crate) enum CountVariation<'a> {
     Group32Var7,
     /// Analog Input Event - Double-precision With Flag and Time
     Group32Var8,
+    /// Frozen Analog Input Event - Any Variation
+    Group33Var0,
+    /// Frozen Analog Input Event - 32-bit With Flag
+    Group33Var1,
+    /// Frozen Analog Input Event - 16-bit With Flag
+    Group33Var2,
+    /// Frozen Analog Input Event - 32-bit With Flag and Time
+    Group33Var3,
+    /// Frozen Analog Input Event - 16-bit With Flag and Time
+    Group33Var4,
+    /// Frozen Analog Input Event - Single-precision With Flag
+    Group33Var5,
+    /// Frozen Analog Input Event - Double-precision With Flag
+    Group33Var6,
+    /// Frozen Analog Input Event - Single-precision With Flag and Time
+    Group33Var7,
+    /// Frozen Analog Input Event - Double-precision With Flag and Time
+    Group33Var8,
     /// Analog Output Event - Any Variation
     Group42Var0,
     /// Analog Output Event - 32-bit With Flag
@@ -98,8 +126,28 @@ pub(crate) enum CountVariation<'a> {
     Group42Var7,
     /// Analog Output Event - Double-precision With Flag and Time
     Group42Var8,
+    /// Analog Output Command Event - Any Variation
+    Group43Var0,
+    /// Analog Output Command Event - 32-bit
+    Group43Var1,
+    /// Analog Output Command Event - 16-bit
+    Group43Var2,
+    /// Analog Output Command Event - 32-bit With Time
+    Group43Var3,
+    /// Analog Output Command Event - 16-bit With Time
+    Group43Var4,
+    /// Analog Output Command Event - Single-precision
+    Group43Var5,
+    /// Analog Output Command Event - Double-precision
+    Group43Var6,
+    /// Analog Output Command Event - Single-precision With Time
+    Group43Var7,
+    /// Analog Output Command Event - Double-precision With Time
+    Group43Var8,
     /// Time and Date - Absolute Time
     Group50Var1(CountSequence<'a, Group50Var1>),
+    /// Time and Date - Absolute time and interval
+    Group50Var2(CountSequence<'a, Group50Var2>),
     /// Time and Date - Absolute Time at last recorded time
     Group50Var3(CountSequence<'a, Group50Var3>),
     /// Time and Date - Indexed absolute time and long interval
@@ -118,9 +166,18 @@ pub(crate) enum CountVariation<'a> {
     Group60Var3,
     /// Class Data - Class 3
     Group60Var4,
+    /// Data Set Event - Snapshot
+    Group88Var1,
     /// Octet String Event - Sized by variation
     Group111Var0,
     Group111VarX(u8),
+    /// Virtual Terminal Event Data - Sized by variation
+    Group113Var0,
+    Group113VarX(u8),
+    /// Authentication - Aggressive Mode Request
+    Group120Var3(CountSequence<'a, Group120Var3>),
+    /// Authentication - Session Key Status Request
+    Group120Var4(CountSequence<'a, Group120Var4>),
 }
 
 impl<'a> CountVariation<'a> {
@@ -137,6 +194,11 @@ impl<'a> CountVariation<'a> {
             Variation::Group11Var0 => Ok(CountVariation::Group11Var0),
             Variation::Group11Var1 => Ok(CountVariation::Group11Var1),
             Variation::Group11Var2 => Ok(CountVariation::Group11Var2),
+            Variation::Group12Var2 => Ok(CountVariation::Group12Var2(CountSequence::parse(count, cursor)?)),
+            Variation::Group13Var0 => Ok(CountVariation::Group13Var0),
+            Variation::Group13Var1 => Ok(CountVariation::Group13Var1),
+            Variation::Group13Var2 => Ok(CountVariation::Group13Var2),
+            Variation::Group20Var0 => Ok(CountVariation::Group20Var0),
             Variation::Group22Var0 => Ok(CountVariation::Group22Var0),
             Variation::Group22Var1 => Ok(CountVariation::Group22Var1),
             Variation::Group22Var2 => Ok(CountVariation::Group22Var2),
@@ -156,6 +218,15 @@ impl<'a> CountVariation<'a> {
             Variation::Group32Var6 => Ok(CountVariation::Group32Var6),
             Variation::Group32Var7 => Ok(CountVariation::Group32Var7),
             Variation::Group32Var8 => Ok(CountVariation::Group32Var8),
+            Variation::Group33Var0 => Ok(CountVariation::Group33Var0),
+            Variation::Group33Var1 => Ok(CountVariation::Group33Var1),
+            Variation::Group33Var2 => Ok(CountVariation::Group33Var2),
+            Variation::Group33Var3 => Ok(CountVariation::Group33Var3),
+            Variation::Group33Var4 => Ok(CountVariation::Group33Var4),
+            Variation::Group33Var5 => Ok(CountVariation::Group33Var5),
+            Variation::Group33Var6 => Ok(CountVariation::Group33Var6),
+            Variation::Group33Var7 => Ok(CountVariation::Group33Var7),
+            Variation::Group33Var8 => Ok(CountVariation::Group33Var8),
             Variation::Group42Var0 => Ok(CountVariation::Group42Var0),
             Variation::Group42Var1 => Ok(CountVariation::Group42Var1),
             Variation::Group42Var2 => Ok(CountVariation::Group42Var2),
@@ -165,7 +236,17 @@ impl<'a> CountVariation<'a> {
             Variation::Group42Var6 => Ok(CountVariation::Group42Var6),
             Variation::Group42Var7 => Ok(CountVariation::Group42Var7),
             Variation::Group42Var8 => Ok(CountVariation::Group42Var8),
+            Variation::Group43Var0 => Ok(CountVariation::Group43Var0),
+            Variation::Group43Var1 => Ok(CountVariation::Group43Var1),
+            Variation::Group43Var2 => Ok(CountVariation::Group43Var2),
+            Variation::Group43Var3 => Ok(CountVariation::Group43Var3),
+            Variation::Group43Var4 => Ok(CountVariation::Group43Var4),
+            Variation::Group43Var5 => Ok(CountVariation::Group43Var5),
+            Variation::Group43Var6 => Ok(CountVariation::Group43Var6),
+            Variation::Group43Var7 => Ok(CountVariation::Group43Var7),
+            Variation::Group43Var8 => Ok(CountVariation::Group43Var8),
             Variation::Group50Var1 => Ok(CountVariation::Group50Var1(CountSequence::parse(count, cursor)?)),
+            Variation::Group50Var2 => Ok(CountVariation::Group50Var2(CountSequence::parse(count, cursor)?)),
             Variation::Group50Var3 => Ok(CountVariation::Group50Var3(CountSequence::parse(count, cursor)?)),
             Variation::Group50Var4 => Ok(CountVariation::Group50Var4(CountSequence::parse(count, cursor)?)),
             Variation::Group51Var1 => Ok(CountVariation::Group51Var1(CountSequence::parse(count, cursor)?)),
@@ -175,8 +256,13 @@ impl<'a> CountVariation<'a> {
             Variation::Group60Var2 => Ok(CountVariation::Group60Var2),
             Variation::Group60Var3 => Ok(CountVariation::Group60Var3),
             Variation::Group60Var4 => Ok(CountVariation::Group60Var4),
+            Variation::Group88Var1 => Ok(CountVariation::Group88Var1),
             Variation::Group111(0) => Ok(CountVariation::Group111Var0),
             Variation::Group111(x) => Ok(CountVariation::Group111VarX(x)),
+            Variation::Group113(0) => Ok(CountVariation::Group113Var0),
+            Variation::Group113(x) => Ok(CountVariation::Group113VarX(x)),
+            Variation::Group120Var3 => Ok(CountVariation::Group120Var3(CountSequence::parse(count, cursor)?)),
+            Variation::Group120Var4 => Ok(CountVariation::Group120Var4(CountSequence::parse(count, cursor)?)),
             _ => Err(ObjectParseError::InvalidQualifierForVariation(v, qualifier)),
         }
     }
@@ -194,6 +280,11 @@ impl<'a> CountVariation<'a> {
             CountVariation::Group11Var0 => Ok(()),
             CountVariation::Group11Var1 => Ok(()),
             CountVariation::Group11Var2 => Ok(()),
+            CountVariation::Group12Var2(seq) => format_count_of_items(f, seq.iter()),
+            CountVariation::Group13Var0 => Ok(()),
+            CountVariation::Group13Var1 => Ok(()),
+            CountVariation::Group13Var2 => Ok(()),
+            CountVariation::Group20Var0 => Ok(()),
             CountVariation::Group22Var0 => Ok(()),
             CountVariation::Group22Var1 => Ok(()),
             CountVariation::Group22Var2 => Ok(()),
@@ -213,6 +304,15 @@ impl<'a> CountVariation<'a> {
             CountVariation::Group32Var6 => Ok(()),
             CountVariation::Group32Var7 => Ok(()),
             CountVariation::Group32Var8 => Ok(()),
+            CountVariation::Group33Var0 => Ok(()),
+            CountVariation::Group33Var1 => Ok(()),
+            CountVariation::Group33Var2 => Ok(()),
+            CountVariation::Group33Var3 => Ok(()),
+            CountVariation::Group33Var4 => Ok(()),
+            CountVariation::Group33Var5 => Ok(()),
+            CountVariation::Group33Var6 => Ok(()),
+            CountVariation::Group33Var7 => Ok(()),
+            CountVariation::Group33Var8 => Ok(()),
             CountVariation::Group42Var0 => Ok(()),
             CountVariation::Group42Var1 => Ok(()),
             CountVariation::Group42Var2 => Ok(()),
@@ -222,7 +322,17 @@ impl<'a> CountVariation<'a> {
             CountVariation::Group42Var6 => Ok(()),
             CountVariation::Group42Var7 => Ok(()),
             CountVariation::Group42Var8 => Ok(()),
+            CountVariation::Group43Var0 => Ok(()),
+            CountVariation::Group43Var1 => Ok(()),
+            CountVariation::Group43Var2 => Ok(()),
+            CountVariation::Group43Var3 => Ok(()),
+            CountVariation::Group43Var4 => Ok(()),
+            CountVariation::Group43Var5 => Ok(()),
+            CountVariation::Group43Var6 => Ok(()),
+            CountVariation::Group43Var7 => Ok(()),
+            CountVariation::Group43Var8 => Ok(()),
             CountVariation::Group50Var1(seq) => format_count_of_items(f, seq.iter()),
+            CountVariation::Group50Var2(seq) => format_count_of_items(f, seq.iter()),
             CountVariation::Group50Var3(seq) => format_count_of_items(f, seq.iter()),
             CountVariation::Group50Var4(seq) => format_count_of_items(f, seq.iter()),
             CountVariation::Group51Var1(seq) => format_count_of_items(f, seq.iter()),
@@ -232,8 +342,13 @@ impl<'a> CountVariation<'a> {
             CountVariation::Group60Var2 => Ok(()),
             CountVariation::Group60Var3 => Ok(()),
             CountVariation::Group60Var4 => Ok(()),
+            CountVariation::Group88Var1 => Ok(()),
             CountVariation::Group111Var0 => Ok(()),
             CountVariation::Group111VarX(_) => Ok(()),
+            CountVariation::Group113Var0 => Ok(()),
+            CountVariation::Group113VarX(_) => Ok(()),
+            CountVariation::Group120Var3(seq) => format_count_of_items(f, seq.iter()),
+            CountVariation::Group120Var4(seq) => format_count_of_items(f, seq.iter()),
         }
     }
 }
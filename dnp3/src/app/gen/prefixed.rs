@@ -42,6 +42,10 @@ pub(crate) enum PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt:
     Group11Var2(CountSequence<'a, Prefix<I, Group11Var2>>),
     /// Binary Command - Control Relay Output Block
     Group12Var1(CountSequence<'a, Prefix<I, Group12Var1>>),
+    /// Binary Output Command Event - Without Time
+    Group13Var1(CountSequence<'a, Prefix<I, Group13Var1>>),
+    /// Binary Output Command Event - With Time
+    Group13Var2(CountSequence<'a, Prefix<I, Group13Var2>>),
     /// Counter Event - 32-bit With Flag
     Group22Var1(CountSequence<'a, Prefix<I, Group22Var1>>),
     /// Counter Event - 16-bit With Flag
@@ -74,6 +78,28 @@ pub(crate) enum PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt:
     Group32Var7(CountSequence<'a, Prefix<I, Group32Var7>>),
     /// Analog Input Event - Double-precision With Flag and Time
     Group32Var8(CountSequence<'a, Prefix<I, Group32Var8>>),
+    /// Frozen Analog Input Event - 32-bit With Flag
+    Group33Var1(CountSequence<'a, Prefix<I, Group33Var1>>),
+    /// Frozen Analog Input Event - 16-bit With Flag
+    Group33Var2(CountSequence<'a, Prefix<I, Group33Var2>>),
+    /// Frozen Analog Input Event - 32-bit With Flag and Time
+    Group33Var3(CountSequence<'a, Prefix<I, Group33Var3>>),
+    /// Frozen Analog Input Event - 16-bit With Flag and Time
+    Group33Var4(CountSequence<'a, Prefix<I, Group33Var4>>),
+    /// Frozen Analog Input Event - Single-precision With Flag
+    Group33Var5(CountSequence<'a, Prefix<I, Group33Var5>>),
+    /// Frozen Analog Input Event - Double-precision With Flag
+    Group33Var6(CountSequence<'a, Prefix<I, Group33Var6>>),
+    /// Frozen Analog Input Event - Single-precision With Flag and Time
+    Group33Var7(CountSequence<'a, Prefix<I, Group33Var7>>),
+    /// Frozen Analog Input Event - Double-precision With Flag and Time
+    Group33Var8(CountSequence<'a, Prefix<I, Group33Var8>>),
+    /// Analog Input Deadband - 16-bit
+    Group34Var1(CountSequence<'a, Prefix<I, Group34Var1>>),
+    /// Analog Input Deadband - 32-bit
+    Group34Var2(CountSequence<'a, Prefix<I, Group34Var2>>),
+    /// Analog Input Deadband - Single-precision
+    Group34Var3(CountSequence<'a, Prefix<I, Group34Var3>>),
     /// Analog Output - 32-bit With Flag
     Group41Var1(CountSequence<'a, Prefix<I, Group41Var1>>),
     /// Analog Output - 16-bit With Flag
@@ -98,8 +124,32 @@ pub(crate) enum PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt:
     Group42Var7(CountSequence<'a, Prefix<I, Group42Var7>>),
     /// Analog Output Event - Double-precision With Flag and Time
     Group42Var8(CountSequence<'a, Prefix<I, Group42Var8>>),
+    /// Analog Output Command Event - 32-bit
+    Group43Var1(CountSequence<'a, Prefix<I, Group43Var1>>),
+    /// Analog Output Command Event - 16-bit
+    Group43Var2(CountSequence<'a, Prefix<I, Group43Var2>>),
+    /// Analog Output Command Event - 32-bit With Time
+    Group43Var3(CountSequence<'a, Prefix<I, Group43Var3>>),
+    /// Analog Output Command Event - 16-bit With Time
+    Group43Var4(CountSequence<'a, Prefix<I, Group43Var4>>),
+    /// Analog Output Command Event - Single-precision
+    Group43Var5(CountSequence<'a, Prefix<I, Group43Var5>>),
+    /// Analog Output Command Event - Double-precision
+    Group43Var6(CountSequence<'a, Prefix<I, Group43Var6>>),
+    /// Analog Output Command Event - Single-precision With Time
+    Group43Var7(CountSequence<'a, Prefix<I, Group43Var7>>),
+    /// Analog Output Command Event - Double-precision With Time
+    Group43Var8(CountSequence<'a, Prefix<I, Group43Var8>>),
+    /// Time and Date - Indexed absolute time and long interval
+    Group50Var4(CountSequence<'a, Prefix<I, Group50Var4>>),
+    /// Octet String - Sized by variation
+    Group110VarX(u8, PrefixedBytesSequence<'a, I>),
     /// Octet String Event - Sized by variation
     Group111VarX(u8, PrefixedBytesSequence<'a, I>),
+    /// Virtual Terminal Output Block - Sized by variation
+    Group112VarX(u8, PrefixedBytesSequence<'a, I>),
+    /// Virtual Terminal Event Data - Sized by variation
+    Group113VarX(u8, PrefixedBytesSequence<'a, I>),
 }
 
 impl<'a, I> PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt::Display {
@@ -114,6 +164,8 @@ impl<'a, I> PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt::Dis
             Variation::Group11Var1 => Ok(PrefixedVariation::Group11Var1(CountSequence::parse(count, cursor)?)),
             Variation::Group11Var2 => Ok(PrefixedVariation::Group11Var2(CountSequence::parse(count, cursor)?)),
             Variation::Group12Var1 => Ok(PrefixedVariation::Group12Var1(CountSequence::parse(count, cursor)?)),
+            Variation::Group13Var1 => Ok(PrefixedVariation::Group13Var1(CountSequence::parse(count, cursor)?)),
+            Variation::Group13Var2 => Ok(PrefixedVariation::Group13Var2(CountSequence::parse(count, cursor)?)),
             Variation::Group22Var1 => Ok(PrefixedVariation::Group22Var1(CountSequence::parse(count, cursor)?)),
             Variation::Group22Var2 => Ok(PrefixedVariation::Group22Var2(CountSequence::parse(count, cursor)?)),
             Variation::Group22Var5 => Ok(PrefixedVariation::Group22Var5(CountSequence::parse(count, cursor)?)),
@@ -130,6 +182,17 @@ impl<'a, I> PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt::Dis
             Variation::Group32Var6 => Ok(PrefixedVariation::Group32Var6(CountSequence::parse(count, cursor)?)),
             Variation::Group32Var7 => Ok(PrefixedVariation::Group32Var7(CountSequence::parse(count, cursor)?)),
             Variation::Group32Var8 => Ok(PrefixedVariation::Group32Var8(CountSequence::parse(count, cursor)?)),
+            Variation::Group33Var1 => Ok(PrefixedVariation::Group33Var1(CountSequence::parse(count, cursor)?)),
+            Variation::Group33Var2 => Ok(PrefixedVariation::Group33Var2(CountSequence::parse(count, cursor)?)),
+            Variation::Group33Var3 => Ok(PrefixedVariation::Group33Var3(CountSequence::parse(count, cursor)?)),
+            Variation::Group33Var4 => Ok(PrefixedVariation::Group33Var4(CountSequence::parse(count, cursor)?)),
+            Variation::Group33Var5 => Ok(PrefixedVariation::Group33Var5(CountSequence::parse(count, cursor)?)),
+            Variation::Group33Var6 => Ok(PrefixedVariation::Group33Var6(CountSequence::parse(count, cursor)?)),
+            Variation::Group33Var7 => Ok(PrefixedVariation::Group33Var7(CountSequence::parse(count, cursor)?)),
+            Variation::Group33Var8 => Ok(PrefixedVariation::Group33Var8(CountSequence::parse(count, cursor)?)),
+            Variation::Group34Var1 => Ok(PrefixedVariation::Group34Var1(CountSequence::parse(count, cursor)?)),
+            Variation::Group34Var2 => Ok(PrefixedVariation::Group34Var2(CountSequence::parse(count, cursor)?)),
+            Variation::Group34Var3 => Ok(PrefixedVariation::Group34Var3(CountSequence::parse(count, cursor)?)),
             Variation::Group41Var1 => Ok(PrefixedVariation::Group41Var1(CountSequence::parse(count, cursor)?)),
             Variation::Group41Var2 => Ok(PrefixedVariation::Group41Var2(CountSequence::parse(count, cursor)?)),
             Variation::Group41Var3 => Ok(PrefixedVariation::Group41Var3(CountSequence::parse(count, cursor)?)),
@@ -142,8 +205,23 @@ impl<'a, I> PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt::Dis
             Variation::Group42Var6 => Ok(PrefixedVariation::Group42Var6(CountSequence::parse(count, cursor)?)),
             Variation::Group42Var7 => Ok(PrefixedVariation::Group42Var7(CountSequence::parse(count, cursor)?)),
             Variation::Group42Var8 => Ok(PrefixedVariation::Group42Var8(CountSequence::parse(count, cursor)?)),
+            Variation::Group43Var1 => Ok(PrefixedVariation::Group43Var1(CountSequence::parse(count, cursor)?)),
+            Variation::Group43Var2 => Ok(PrefixedVariation::Group43Var2(CountSequence::parse(count, cursor)?)),
+            Variation::Group43Var3 => Ok(PrefixedVariation::Group43Var3(CountSequence::parse(count, cursor)?)),
+            Variation::Group43Var4 => Ok(PrefixedVariation::Group43Var4(CountSequence::parse(count, cursor)?)),
+            Variation::Group43Var5 => Ok(PrefixedVariation::Group43Var5(CountSequence::parse(count, cursor)?)),
+            Variation::Group43Var6 => Ok(PrefixedVariation::Group43Var6(CountSequence::parse(count, cursor)?)),
+            Variation::Group43Var7 => Ok(PrefixedVariation::Group43Var7(CountSequence::parse(count, cursor)?)),
+            Variation::Group43Var8 => Ok(PrefixedVariation::Group43Var8(CountSequence::parse(count, cursor)?)),
+            Variation::Group50Var4 => Ok(PrefixedVariation::Group50Var4(CountSequence::parse(count, cursor)?)),
+            Variation::Group110(0) => Err(ObjectParseError::ZeroLengthOctetData),
+            Variation::Group110(x) => Ok(PrefixedVariation::Group110VarX(x, PrefixedBytesSequence::parse(x, count, cursor)?)),
             Variation::Group111(0) => Err(ObjectParseError::ZeroLengthOctetData),
             Variation::Group111(x) => Ok(PrefixedVariation::Group111VarX(x, PrefixedBytesSequence::parse(x, count, cursor)?)),
+            Variation::Group112(0) => Err(ObjectParseError::ZeroLengthOctetData),
+            Variation::Group112(x) => Ok(PrefixedVariation::Group112VarX(x, PrefixedBytesSequence::parse(x, count, cursor)?)),
+            Variation::Group113(0) => Err(ObjectParseError::ZeroLengthOctetData),
+            Variation::Group113(x) => Ok(PrefixedVariation::Group113VarX(x, PrefixedBytesSequence::parse(x, count, cursor)?)),
             _ => Err(ObjectParseError::InvalidQualifierForVariation(v, I::COUNT_AND_PREFIX_QUALIFIER)),
         }
     }
@@ -159,6 +237,8 @@ impl<'a, I> PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt::Dis
             PrefixedVariation::Group11Var1(seq) => format_prefixed_items(f, seq.iter()),
             PrefixedVariation::Group11Var2(seq) => format_prefixed_items(f, seq.iter()),
             PrefixedVariation::Group12Var1(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group13Var1(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group13Var2(seq) => format_prefixed_items(f, seq.iter()),
             PrefixedVariation::Group22Var1(seq) => format_prefixed_items(f, seq.iter()),
             PrefixedVariation::Group22Var2(seq) => format_prefixed_items(f, seq.iter()),
             PrefixedVariation::Group22Var5(seq) => format_prefixed_items(f, seq.iter()),
@@ -175,6 +255,17 @@ impl<'a, I> PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt::Dis
             PrefixedVariation::Group32Var6(seq) => format_prefixed_items(f, seq.iter()),
             PrefixedVariation::Group32Var7(seq) => format_prefixed_items(f, seq.iter()),
             PrefixedVariation::Group32Var8(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group33Var1(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group33Var2(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group33Var3(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group33Var4(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group33Var5(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group33Var6(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group33Var7(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group33Var8(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group34Var1(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group34Var2(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group34Var3(seq) => format_prefixed_items(f, seq.iter()),
             PrefixedVariation::Group41Var1(seq) => format_prefixed_items(f, seq.iter()),
             PrefixedVariation::Group41Var2(seq) => format_prefixed_items(f, seq.iter()),
             PrefixedVariation::Group41Var3(seq) => format_prefixed_items(f, seq.iter()),
@@ -187,7 +278,19 @@ impl<'a, I> PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt::Dis
             PrefixedVariation::Group42Var6(seq) => format_prefixed_items(f, seq.iter()),
             PrefixedVariation::Group42Var7(seq) => format_prefixed_items(f, seq.iter()),
             PrefixedVariation::Group42Var8(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group43Var1(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group43Var2(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group43Var3(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group43Var4(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group43Var5(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group43Var6(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group43Var7(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group43Var8(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group50Var4(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group110VarX(_, seq) => format_indexed_items(f, seq.iter().map(|(x, i)| (Bytes::new(x), i))),
             PrefixedVariation::Group111VarX(_, seq) => format_indexed_items(f, seq.iter().map(|(x, i)| (Bytes::new(x), i))),
+            PrefixedVariation::Group112VarX(_, seq) => format_indexed_items(f, seq.iter().map(|(x, i)| (Bytes::new(x), i))),
+            PrefixedVariation::Group113VarX(_, seq) => format_indexed_items(f, seq.iter().map(|(x, i)| (Bytes::new(x), i))),
         }
     }
     
@@ -252,6 +355,20 @@ impl<'a, I> PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt::Dis
             PrefixedVariation::Group12Var1(_) => {
                 false // command
             }
+            PrefixedVariation::Group13Var1(seq) => {
+                handler.handle_binary_output_command_event(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group13Var2(seq) => {
+                handler.handle_binary_output_command_event(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
             PrefixedVariation::Group22Var1(seq) => {
                 handler.handle_counter(
                     self.get_header_info(),
@@ -364,6 +481,83 @@ impl<'a, I> PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt::Dis
                 );
                 true
             }
+            PrefixedVariation::Group33Var1(seq) => {
+                handler.handle_frozen_analog_input(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group33Var2(seq) => {
+                handler.handle_frozen_analog_input(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group33Var3(seq) => {
+                handler.handle_frozen_analog_input(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group33Var4(seq) => {
+                handler.handle_frozen_analog_input(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group33Var5(seq) => {
+                handler.handle_frozen_analog_input(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group33Var6(seq) => {
+                handler.handle_frozen_analog_input(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group33Var7(seq) => {
+                handler.handle_frozen_analog_input(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group33Var8(seq) => {
+                handler.handle_frozen_analog_input(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group34Var1(seq) => {
+                handler.handle_analog_input_deadband(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group34Var2(seq) => {
+                handler.handle_analog_input_deadband(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group34Var3(seq) => {
+                handler.handle_analog_input_deadband(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
             PrefixedVariation::Group41Var1(_) => {
                 false // command
             }
@@ -432,6 +626,72 @@ impl<'a, I> PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt::Dis
                 );
                 true
             }
+            PrefixedVariation::Group43Var1(seq) => {
+                handler.handle_analog_output_command_event(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group43Var2(seq) => {
+                handler.handle_analog_output_command_event(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group43Var3(seq) => {
+                handler.handle_analog_output_command_event(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group43Var4(seq) => {
+                handler.handle_analog_output_command_event(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group43Var5(seq) => {
+                handler.handle_analog_output_command_event(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group43Var6(seq) => {
+                handler.handle_analog_output_command_event(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group43Var7(seq) => {
+                handler.handle_analog_output_command_event(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group43Var8(seq) => {
+                handler.handle_analog_output_command_event(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group50Var4(_) => {
+                false // time and interval
+            }
+            PrefixedVariation::Group110VarX(_, seq) => {
+                handler.handle_octet_string(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.0, x.1.widen_to_u16()))
+                );
+                true
+            }
             PrefixedVariation::Group111VarX(_, seq) => {
                 handler.handle_octet_string(
                     self.get_header_info(),
@@ -439,6 +699,9 @@ impl<'a, I> PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt::Dis
                 );
                 true
             }
+            // virtual terminal output is routed by the master to a per-port stream
+            PrefixedVariation::Group112VarX(_, _) => false,
+            PrefixedVariation::Group113VarX(_, _) => false,
         }
     }
     
@@ -453,6 +716,8 @@ impl<'a, I> PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt::Dis
             PrefixedVariation::Group11Var1(_) => HeaderInfo::new(Variation::Group11Var1, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
             PrefixedVariation::Group11Var2(_) => HeaderInfo::new(Variation::Group11Var2, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
             PrefixedVariation::Group12Var1(_) => HeaderInfo::new(Variation::Group12Var1, I::COUNT_AND_PREFIX_QUALIFIER, false, false),
+            PrefixedVariation::Group13Var1(_) => HeaderInfo::new(Variation::Group13Var1, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
+            PrefixedVariation::Group13Var2(_) => HeaderInfo::new(Variation::Group13Var2, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
             PrefixedVariation::Group22Var1(_) => HeaderInfo::new(Variation::Group22Var1, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
             PrefixedVariation::Group22Var2(_) => HeaderInfo::new(Variation::Group22Var2, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
             PrefixedVariation::Group22Var5(_) => HeaderInfo::new(Variation::Group22Var5, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
@@ -469,6 +734,17 @@ impl<'a, I> PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt::Dis
             PrefixedVariation::Group32Var6(_) => HeaderInfo::new(Variation::Group32Var6, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
             PrefixedVariation::Group32Var7(_) => HeaderInfo::new(Variation::Group32Var7, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
             PrefixedVariation::Group32Var8(_) => HeaderInfo::new(Variation::Group32Var8, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
+            PrefixedVariation::Group33Var1(_) => HeaderInfo::new(Variation::Group33Var1, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
+            PrefixedVariation::Group33Var2(_) => HeaderInfo::new(Variation::Group33Var2, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
+            PrefixedVariation::Group33Var3(_) => HeaderInfo::new(Variation::Group33Var3, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
+            PrefixedVariation::Group33Var4(_) => HeaderInfo::new(Variation::Group33Var4, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
+            PrefixedVariation::Group33Var5(_) => HeaderInfo::new(Variation::Group33Var5, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
+            PrefixedVariation::Group33Var6(_) => HeaderInfo::new(Variation::Group33Var6, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
+            PrefixedVariation::Group33Var7(_) => HeaderInfo::new(Variation::Group33Var7, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
+            PrefixedVariation::Group33Var8(_) => HeaderInfo::new(Variation::Group33Var8, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
+            PrefixedVariation::Group34Var1(_) => HeaderInfo::new(Variation::Group34Var1, I::COUNT_AND_PREFIX_QUALIFIER, false, false),
+            PrefixedVariation::Group34Var2(_) => HeaderInfo::new(Variation::Group34Var2, I::COUNT_AND_PREFIX_QUALIFIER, false, false),
+            PrefixedVariation::Group34Var3(_) => HeaderInfo::new(Variation::Group34Var3, I::COUNT_AND_PREFIX_QUALIFIER, false, false),
             PrefixedVariation::Group41Var1(_) => HeaderInfo::new(Variation::Group41Var1, I::COUNT_AND_PREFIX_QUALIFIER, false, false),
             PrefixedVariation::Group41Var2(_) => HeaderInfo::new(Variation::Group41Var2, I::COUNT_AND_PREFIX_QUALIFIER, false, false),
             PrefixedVariation::Group41Var3(_) => HeaderInfo::new(Variation::Group41Var3, I::COUNT_AND_PREFIX_QUALIFIER, false, false),
@@ -481,7 +757,19 @@ impl<'a, I> PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt::Dis
             PrefixedVariation::Group42Var6(_) => HeaderInfo::new(Variation::Group42Var6, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
             PrefixedVariation::Group42Var7(_) => HeaderInfo::new(Variation::Group42Var7, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
             PrefixedVariation::Group42Var8(_) => HeaderInfo::new(Variation::Group42Var8, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
+            PrefixedVariation::Group43Var1(_) => HeaderInfo::new(Variation::Group43Var1, I::COUNT_AND_PREFIX_QUALIFIER, true, false),
+            PrefixedVariation::Group43Var2(_) => HeaderInfo::new(Variation::Group43Var2, I::COUNT_AND_PREFIX_QUALIFIER, true, false),
+            PrefixedVariation::Group43Var3(_) => HeaderInfo::new(Variation::Group43Var3, I::COUNT_AND_PREFIX_QUALIFIER, true, false),
+            PrefixedVariation::Group43Var4(_) => HeaderInfo::new(Variation::Group43Var4, I::COUNT_AND_PREFIX_QUALIFIER, true, false),
+            PrefixedVariation::Group43Var5(_) => HeaderInfo::new(Variation::Group43Var5, I::COUNT_AND_PREFIX_QUALIFIER, true, false),
+            PrefixedVariation::Group43Var6(_) => HeaderInfo::new(Variation::Group43Var6, I::COUNT_AND_PREFIX_QUALIFIER, true, false),
+            PrefixedVariation::Group43Var7(_) => HeaderInfo::new(Variation::Group43Var7, I::COUNT_AND_PREFIX_QUALIFIER, true, false),
+            PrefixedVariation::Group43Var8(_) => HeaderInfo::new(Variation::Group43Var8, I::COUNT_AND_PREFIX_QUALIFIER, true, false),
+            PrefixedVariation::Group50Var4(_) => HeaderInfo::new(Variation::Group50Var4, I::COUNT_AND_PREFIX_QUALIFIER, false, false),
+            PrefixedVariation::Group110VarX(x, _) =>  HeaderInfo::new(Variation::Group110(*x), I::COUNT_AND_PREFIX_QUALIFIER, false, false),
             PrefixedVariation::Group111VarX(x, _) =>  HeaderInfo::new(Variation::Group111(*x), I::COUNT_AND_PREFIX_QUALIFIER, true, false),
+            PrefixedVariation::Group112VarX(x, _) =>  HeaderInfo::new(Variation::Group112(*x), I::COUNT_AND_PREFIX_QUALIFIER, false, false),
+            PrefixedVariation::Group113VarX(x, _) =>  HeaderInfo::new(Variation::Group113(*x), I::COUNT_AND_PREFIX_QUALIFIER, true, false),
         }
     }
 }
@@ -18,11 +18,16 @@ use crate::app::parse::bytes::*;
 use crate::app::parse::bit::{BitSequence, DoubleBitSequence};
 use crate::master::{ReadHandler, HeaderInfo};
 use crate::app::ObjectParseError;
+use crate::app::Attribute;
 
 use scursor::ReadCursor;
 
 #[derive(Debug, PartialEq)]
 pub(crate) enum RangedVariation<'a> {
+    /// Device Attributes - Non-specific all attributes request
+    Group0Var254,
+    /// Device Attributes - Specific attribute
+    Group0(u8, Option<Attribute<'a>>),
     /// Binary Input - Any Variation
     Group1Var0,
     /// Binary Input - Packed Format
@@ -41,6 +46,8 @@ pub(crate) enum RangedVariation<'a> {
     Group10Var1(BitSequence<'a>),
     /// Binary Output - Output Status With Flags
     Group10Var2(RangedSequence<'a, Group10Var2>),
+    /// Binary Command - Pattern Mask
+    Group12Var3(BitSequence<'a>),
     /// Counter - Any Variation
     Group20Var0,
     /// Counter - 32-bit With Flag
@@ -79,6 +86,32 @@ pub(crate) enum RangedVariation<'a> {
     Group30Var5(RangedSequence<'a, Group30Var5>),
     /// Analog Input - Double-precision With Flag
     Group30Var6(RangedSequence<'a, Group30Var6>),
+    /// Frozen Analog Input - Any Variation
+    Group31Var0,
+    /// Frozen Analog Input - 32-bit With Flag
+    Group31Var1(RangedSequence<'a, Group31Var1>),
+    /// Frozen Analog Input - 16-bit With Flag
+    Group31Var2(RangedSequence<'a, Group31Var2>),
+    /// Frozen Analog Input - 32-bit With Flag and Time-of-Freeze
+    Group31Var3(RangedSequence<'a, Group31Var3>),
+    /// Frozen Analog Input - 16-bit With Flag and Time-of-Freeze
+    Group31Var4(RangedSequence<'a, Group31Var4>),
+    /// Frozen Analog Input - 32-bit Without Flag
+    Group31Var5(RangedSequence<'a, Group31Var5>),
+    /// Frozen Analog Input - 16-bit Without Flag
+    Group31Var6(RangedSequence<'a, Group31Var6>),
+    /// Frozen Analog Input - Single-precision With Flag
+    Group31Var7(RangedSequence<'a, Group31Var7>),
+    /// Frozen Analog Input - Double-precision With Flag
+    Group31Var8(RangedSequence<'a, Group31Var8>),
+    /// Analog Input Deadband - Any Variation
+    Group34Var0,
+    /// Analog Input Deadband - 16-bit
+    Group34Var1(RangedSequence<'a, Group34Var1>),
+    /// Analog Input Deadband - 32-bit
+    Group34Var2(RangedSequence<'a, Group34Var2>),
+    /// Analog Input Deadband - Single-precision
+    Group34Var3(RangedSequence<'a, Group34Var3>),
     /// Analog Output Status - Any Variation
     Group40Var0,
     /// Analog Output Status - 32-bit With Flag
@@ -91,6 +124,18 @@ pub(crate) enum RangedVariation<'a> {
     Group40Var4(RangedSequence<'a, Group40Var4>),
     /// Internal Indications - Packed Format
     Group80Var1(BitSequence<'a>),
+    /// BCD Integer - Any Variation
+    Group101Var0,
+    /// BCD Integer - Small (4 digits)
+    Group101Var1(RangedSequence<'a, Group101Var1>),
+    /// BCD Integer - Medium (8 digits)
+    Group101Var2(RangedSequence<'a, Group101Var2>),
+    /// BCD Integer - Large (16 digits)
+    Group101Var3(RangedSequence<'a, Group101Var3>),
+    /// Unsigned Integer - Any Variation
+    Group102Var0,
+    /// Unsigned Integer - 8-bit
+    Group102Var1(RangedSequence<'a, Group102Var1>),
     /// Octet String - Sized by variation
     Group110Var0,
     Group110VarX(u8, RangedBytesSequence<'a>),
@@ -99,6 +144,7 @@ pub(crate) enum RangedVariation<'a> {
 impl<'a> RangedVariation<'a> {
     pub(crate) fn parse_non_read(v: Variation, qualifier: QualifierCode, range: Range, cursor: &mut ReadCursor<'a>) -> Result<RangedVariation<'a>, ObjectParseError> {
         match v {
+            Variation::Group0(x) => Ok(RangedVariation::Group0(x, Some(Attribute::parse_from_range(x, range, cursor)?))),
             Variation::Group1Var0 => Ok(RangedVariation::Group1Var0),
             Variation::Group1Var1 => Ok(RangedVariation::Group1Var1(BitSequence::parse(range, cursor)?)),
             Variation::Group1Var2 => Ok(RangedVariation::Group1Var2(RangedSequence::parse(range, cursor)?)),
@@ -108,6 +154,7 @@ impl<'a> RangedVariation<'a> {
             Variation::Group10Var0 => Ok(RangedVariation::Group10Var0),
             Variation::Group10Var1 => Ok(RangedVariation::Group10Var1(BitSequence::parse(range, cursor)?)),
             Variation::Group10Var2 => Ok(RangedVariation::Group10Var2(RangedSequence::parse(range, cursor)?)),
+            Variation::Group12Var3 => Ok(RangedVariation::Group12Var3(BitSequence::parse(range, cursor)?)),
             Variation::Group20Var0 => Ok(RangedVariation::Group20Var0),
             Variation::Group20Var1 => Ok(RangedVariation::Group20Var1(RangedSequence::parse(range, cursor)?)),
             Variation::Group20Var2 => Ok(RangedVariation::Group20Var2(RangedSequence::parse(range, cursor)?)),
@@ -127,12 +174,31 @@ impl<'a> RangedVariation<'a> {
             Variation::Group30Var4 => Ok(RangedVariation::Group30Var4(RangedSequence::parse(range, cursor)?)),
             Variation::Group30Var5 => Ok(RangedVariation::Group30Var5(RangedSequence::parse(range, cursor)?)),
             Variation::Group30Var6 => Ok(RangedVariation::Group30Var6(RangedSequence::parse(range, cursor)?)),
+            Variation::Group31Var0 => Ok(RangedVariation::Group31Var0),
+            Variation::Group31Var1 => Ok(RangedVariation::Group31Var1(RangedSequence::parse(range, cursor)?)),
+            Variation::Group31Var2 => Ok(RangedVariation::Group31Var2(RangedSequence::parse(range, cursor)?)),
+            Variation::Group31Var3 => Ok(RangedVariation::Group31Var3(RangedSequence::parse(range, cursor)?)),
+            Variation::Group31Var4 => Ok(RangedVariation::Group31Var4(RangedSequence::parse(range, cursor)?)),
+            Variation::Group31Var5 => Ok(RangedVariation::Group31Var5(RangedSequence::parse(range, cursor)?)),
+            Variation::Group31Var6 => Ok(RangedVariation::Group31Var6(RangedSequence::parse(range, cursor)?)),
+            Variation::Group31Var7 => Ok(RangedVariation::Group31Var7(RangedSequence::parse(range, cursor)?)),
+            Variation::Group31Var8 => Ok(RangedVariation::Group31Var8(RangedSequence::parse(range, cursor)?)),
+            Variation::Group34Var0 => Ok(RangedVariation::Group34Var0),
+            Variation::Group34Var1 => Ok(RangedVariation::Group34Var1(RangedSequence::parse(range, cursor)?)),
+            Variation::Group34Var2 => Ok(RangedVariation::Group34Var2(RangedSequence::parse(range, cursor)?)),
+            Variation::Group34Var3 => Ok(RangedVariation::Group34Var3(RangedSequence::parse(range, cursor)?)),
             Variation::Group40Var0 => Ok(RangedVariation::Group40Var0),
             Variation::Group40Var1 => Ok(RangedVariation::Group40Var1(RangedSequence::parse(range, cursor)?)),
             Variation::Group40Var2 => Ok(RangedVariation::Group40Var2(RangedSequence::parse(range, cursor)?)),
             Variation::Group40Var3 => Ok(RangedVariation::Group40Var3(RangedSequence::parse(range, cursor)?)),
             Variation::Group40Var4 => Ok(RangedVariation::Group40Var4(RangedSequence::parse(range, cursor)?)),
             Variation::Group80Var1 => Ok(RangedVariation::Group80Var1(BitSequence::parse(range, cursor)?)),
+            Variation::Group101Var0 => Ok(RangedVariation::Group101Var0),
+            Variation::Group101Var1 => Ok(RangedVariation::Group101Var1(RangedSequence::parse(range, cursor)?)),
+            Variation::Group101Var2 => Ok(RangedVariation::Group101Var2(RangedSequence::parse(range, cursor)?)),
+            Variation::Group101Var3 => Ok(RangedVariation::Group101Var3(RangedSequence::parse(range, cursor)?)),
+            Variation::Group102Var0 => Ok(RangedVariation::Group102Var0),
+            Variation::Group102Var1 => Ok(RangedVariation::Group102Var1(RangedSequence::parse(range, cursor)?)),
             Variation::Group110(0) => Err(ObjectParseError::ZeroLengthOctetData),
             Variation::Group110(x) => {
                 Ok(RangedVariation::Group110VarX(x, RangedBytesSequence::parse(x, range.get_start(), range.get_count(), cursor)?))
@@ -143,6 +209,8 @@ impl<'a> RangedVariation<'a> {
     
     pub(crate) fn parse_read(v: Variation, qualifier: QualifierCode) -> Result<RangedVariation<'a>, ObjectParseError> {
         match v {
+            Variation::Group0Var254 => Ok(RangedVariation::Group0Var254),
+            Variation::Group0(x) => Ok(RangedVariation::Group0(x, None)),
             Variation::Group1Var0 => Ok(RangedVariation::Group1Var0),
             Variation::Group1Var1 => Ok(RangedVariation::Group1Var1(BitSequence::empty())),
             Variation::Group1Var2 => Ok(RangedVariation::Group1Var2(RangedSequence::empty())),
@@ -152,6 +220,7 @@ impl<'a> RangedVariation<'a> {
             Variation::Group10Var0 => Ok(RangedVariation::Group10Var0),
             Variation::Group10Var1 => Ok(RangedVariation::Group10Var1(BitSequence::empty())),
             Variation::Group10Var2 => Ok(RangedVariation::Group10Var2(RangedSequence::empty())),
+            Variation::Group12Var3 => Ok(RangedVariation::Group12Var3(BitSequence::empty())),
             Variation::Group20Var0 => Ok(RangedVariation::Group20Var0),
             Variation::Group20Var1 => Ok(RangedVariation::Group20Var1(RangedSequence::empty())),
             Variation::Group20Var2 => Ok(RangedVariation::Group20Var2(RangedSequence::empty())),
@@ -171,12 +240,31 @@ impl<'a> RangedVariation<'a> {
             Variation::Group30Var4 => Ok(RangedVariation::Group30Var4(RangedSequence::empty())),
             Variation::Group30Var5 => Ok(RangedVariation::Group30Var5(RangedSequence::empty())),
             Variation::Group30Var6 => Ok(RangedVariation::Group30Var6(RangedSequence::empty())),
+            Variation::Group31Var0 => Ok(RangedVariation::Group31Var0),
+            Variation::Group31Var1 => Ok(RangedVariation::Group31Var1(RangedSequence::empty())),
+            Variation::Group31Var2 => Ok(RangedVariation::Group31Var2(RangedSequence::empty())),
+            Variation::Group31Var3 => Ok(RangedVariation::Group31Var3(RangedSequence::empty())),
+            Variation::Group31Var4 => Ok(RangedVariation::Group31Var4(RangedSequence::empty())),
+            Variation::Group31Var5 => Ok(RangedVariation::Group31Var5(RangedSequence::empty())),
+            Variation::Group31Var6 => Ok(RangedVariation::Group31Var6(RangedSequence::empty())),
+            Variation::Group31Var7 => Ok(RangedVariation::Group31Var7(RangedSequence::empty())),
+            Variation::Group31Var8 => Ok(RangedVariation::Group31Var8(RangedSequence::empty())),
+            Variation::Group34Var0 => Ok(RangedVariation::Group34Var0),
+            Variation::Group34Var1 => Ok(RangedVariation::Group34Var1(RangedSequence::empty())),
+            Variation::Group34Var2 => Ok(RangedVariation::Group34Var2(RangedSequence::empty())),
+            Variation::Group34Var3 => Ok(RangedVariation::Group34Var3(RangedSequence::empty())),
             Variation::Group40Var0 => Ok(RangedVariation::Group40Var0),
             Variation::Group40Var1 => Ok(RangedVariation::Group40Var1(RangedSequence::empty())),
             Variation::Group40Var2 => Ok(RangedVariation::Group40Var2(RangedSequence::empty())),
             Variation::Group40Var3 => Ok(RangedVariation::Group40Var3(RangedSequence::empty())),
             Variation::Group40Var4 => Ok(RangedVariation::Group40Var4(RangedSequence::empty())),
             Variation::Group80Var1 => Ok(RangedVariation::Group80Var1(BitSequence::empty())),
+            Variation::Group101Var0 => Ok(RangedVariation::Group101Var0),
+            Variation::Group101Var1 => Ok(RangedVariation::Group101Var1(RangedSequence::empty())),
+            Variation::Group101Var2 => Ok(RangedVariation::Group101Var2(RangedSequence::empty())),
+            Variation::Group101Var3 => Ok(RangedVariation::Group101Var3(RangedSequence::empty())),
+            Variation::Group102Var0 => Ok(RangedVariation::Group102Var0),
+            Variation::Group102Var1 => Ok(RangedVariation::Group102Var1(RangedSequence::empty())),
             Variation::Group110(0) => Ok(RangedVariation::Group110Var0),
             _ => Err(ObjectParseError::InvalidQualifierForVariation(v, qualifier)),
         }
@@ -184,6 +272,9 @@ impl<'a> RangedVariation<'a> {
     
     pub(crate) fn format_objects(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
+            RangedVariation::Group0Var254 => Ok(()),
+            RangedVariation::Group0(_, None) => Ok(()),
+            RangedVariation::Group0(_, Some(attr)) => write!(f, "\n{}", attr.value),
             RangedVariation::Group1Var0 => Ok(()),
             RangedVariation::Group1Var1(seq) => format_indexed_items(f, seq.iter()),
             RangedVariation::Group1Var2(seq) => format_indexed_items(f, seq.iter()),
@@ -193,6 +284,7 @@ impl<'a> RangedVariation<'a> {
             RangedVariation::Group10Var0 => Ok(()),
             RangedVariation::Group10Var1(seq) => format_indexed_items(f, seq.iter()),
             RangedVariation::Group10Var2(seq) => format_indexed_items(f, seq.iter()),
+            RangedVariation::Group12Var3(seq) => format_indexed_items(f, seq.iter()),
             RangedVariation::Group20Var0 => Ok(()),
             RangedVariation::Group20Var1(seq) => format_indexed_items(f, seq.iter()),
             RangedVariation::Group20Var2(seq) => format_indexed_items(f, seq.iter()),
@@ -212,12 +304,31 @@ impl<'a> RangedVariation<'a> {
             RangedVariation::Group30Var4(seq) => format_indexed_items(f, seq.iter()),
             RangedVariation::Group30Var5(seq) => format_indexed_items(f, seq.iter()),
             RangedVariation::Group30Var6(seq) => format_indexed_items(f, seq.iter()),
+            RangedVariation::Group31Var0 => Ok(()),
+            RangedVariation::Group31Var1(seq) => format_indexed_items(f, seq.iter()),
+            RangedVariation::Group31Var2(seq) => format_indexed_items(f, seq.iter()),
+            RangedVariation::Group31Var3(seq) => format_indexed_items(f, seq.iter()),
+            RangedVariation::Group31Var4(seq) => format_indexed_items(f, seq.iter()),
+            RangedVariation::Group31Var5(seq) => format_indexed_items(f, seq.iter()),
+            RangedVariation::Group31Var6(seq) => format_indexed_items(f, seq.iter()),
+            RangedVariation::Group31Var7(seq) => format_indexed_items(f, seq.iter()),
+            RangedVariation::Group31Var8(seq) => format_indexed_items(f, seq.iter()),
+            RangedVariation::Group34Var0 => Ok(()),
+            RangedVariation::Group34Var1(seq) => format_indexed_items(f, seq.iter()),
+            RangedVariation::Group34Var2(seq) => format_indexed_items(f, seq.iter()),
+            RangedVariation::Group34Var3(seq) => format_indexed_items(f, seq.iter()),
             RangedVariation::Group40Var0 => Ok(()),
             RangedVariation::Group40Var1(seq) => format_indexed_items(f, seq.iter()),
             RangedVariation::Group40Var2(seq) => format_indexed_items(f, seq.iter()),
             RangedVariation::Group40Var3(seq) => format_indexed_items(f, seq.iter()),
             RangedVariation::Group40Var4(seq) => format_indexed_items(f, seq.iter()),
             RangedVariation::Group80Var1(seq) => format_indexed_items(f, seq.iter()),
+            RangedVariation::Group101Var0 => Ok(()),
+            RangedVariation::Group101Var1(seq) => format_indexed_items(f, seq.iter()),
+            RangedVariation::Group101Var2(seq) => format_indexed_items(f, seq.iter()),
+            RangedVariation::Group101Var3(seq) => format_indexed_items(f, seq.iter()),
+            RangedVariation::Group102Var0 => Ok(()),
+            RangedVariation::Group102Var1(seq) => format_indexed_items(f, seq.iter()),
             RangedVariation::Group110Var0 => Ok(()),
             RangedVariation::Group110VarX(_, seq) => format_indexed_items(f, seq.iter().map(|(x, i)| (Bytes::new(x), i))),
         }
@@ -225,6 +336,19 @@ impl<'a> RangedVariation<'a> {
     
     pub(crate) fn extract_measurements_to(&self, qualifier: QualifierCode, handler: &mut dyn ReadHandler) -> bool {
         match self {
+            RangedVariation::Group0Var254 => {
+                false
+            }
+            RangedVariation::Group0(_, None) => {
+                false
+            }
+            RangedVariation::Group0(_, Some(attr)) => {
+                handler.handle_device_attribute(
+                    HeaderInfo::new(self.variation(), qualifier, false, false),
+                    *attr
+                );
+                true
+            }
             RangedVariation::Group1Var0 => {
                 false // qualifier 0x06
             }
@@ -276,6 +400,9 @@ impl<'a> RangedVariation<'a> {
                 );
                 true
             }
+            RangedVariation::Group12Var3(_) => {
+                false // command
+            }
             RangedVariation::Group20Var0 => {
                 false // qualifier 0x06
             }
@@ -397,6 +524,89 @@ impl<'a> RangedVariation<'a> {
                 );
                 true
             }
+            RangedVariation::Group31Var0 => {
+                false // qualifier 0x06
+            }
+            RangedVariation::Group31Var1(seq) => {
+                handler.handle_frozen_analog_input(
+                    HeaderInfo::new(self.variation(), qualifier, false, true),
+                    &mut seq.iter().map(|(v,i)| (v.into(), i))
+                );
+                true
+            }
+            RangedVariation::Group31Var2(seq) => {
+                handler.handle_frozen_analog_input(
+                    HeaderInfo::new(self.variation(), qualifier, false, true),
+                    &mut seq.iter().map(|(v,i)| (v.into(), i))
+                );
+                true
+            }
+            RangedVariation::Group31Var3(seq) => {
+                handler.handle_frozen_analog_input(
+                    HeaderInfo::new(self.variation(), qualifier, false, true),
+                    &mut seq.iter().map(|(v,i)| (v.into(), i))
+                );
+                true
+            }
+            RangedVariation::Group31Var4(seq) => {
+                handler.handle_frozen_analog_input(
+                    HeaderInfo::new(self.variation(), qualifier, false, true),
+                    &mut seq.iter().map(|(v,i)| (v.into(), i))
+                );
+                true
+            }
+            RangedVariation::Group31Var5(seq) => {
+                handler.handle_frozen_analog_input(
+                    HeaderInfo::new(self.variation(), qualifier, false, false),
+                    &mut seq.iter().map(|(v,i)| (v.into(), i))
+                );
+                true
+            }
+            RangedVariation::Group31Var6(seq) => {
+                handler.handle_frozen_analog_input(
+                    HeaderInfo::new(self.variation(), qualifier, false, false),
+                    &mut seq.iter().map(|(v,i)| (v.into(), i))
+                );
+                true
+            }
+            RangedVariation::Group31Var7(seq) => {
+                handler.handle_frozen_analog_input(
+                    HeaderInfo::new(self.variation(), qualifier, false, true),
+                    &mut seq.iter().map(|(v,i)| (v.into(), i))
+                );
+                true
+            }
+            RangedVariation::Group31Var8(seq) => {
+                handler.handle_frozen_analog_input(
+                    HeaderInfo::new(self.variation(), qualifier, false, true),
+                    &mut seq.iter().map(|(v,i)| (v.into(), i))
+                );
+                true
+            }
+            RangedVariation::Group34Var0 => {
+                false // qualifier 0x06
+            }
+            RangedVariation::Group34Var1(seq) => {
+                handler.handle_analog_input_deadband(
+                    HeaderInfo::new(self.variation(), qualifier, false, false),
+                    &mut seq.iter().map(|(v,i)| (v.value.into(), i))
+                );
+                true
+            }
+            RangedVariation::Group34Var2(seq) => {
+                handler.handle_analog_input_deadband(
+                    HeaderInfo::new(self.variation(), qualifier, false, false),
+                    &mut seq.iter().map(|(v,i)| (v.value.into(), i))
+                );
+                true
+            }
+            RangedVariation::Group34Var3(seq) => {
+                handler.handle_analog_input_deadband(
+                    HeaderInfo::new(self.variation(), qualifier, false, false),
+                    &mut seq.iter().map(|(v,i)| (v.value.into(), i))
+                );
+                true
+            }
             RangedVariation::Group40Var0 => {
                 false // qualifier 0x06
             }
@@ -431,6 +641,40 @@ impl<'a> RangedVariation<'a> {
             RangedVariation::Group80Var1(_) => {
                 false // internal indications
             }
+            RangedVariation::Group101Var0 => {
+                false // qualifier 0x06
+            }
+            RangedVariation::Group101Var1(seq) => {
+                handler.handle_bcd(
+                    HeaderInfo::new(self.variation(), qualifier, false, false),
+                    &mut seq.iter().map(|(v,i)| (v.into(), i))
+                );
+                true
+            }
+            RangedVariation::Group101Var2(seq) => {
+                handler.handle_bcd(
+                    HeaderInfo::new(self.variation(), qualifier, false, false),
+                    &mut seq.iter().map(|(v,i)| (v.into(), i))
+                );
+                true
+            }
+            RangedVariation::Group101Var3(seq) => {
+                handler.handle_bcd(
+                    HeaderInfo::new(self.variation(), qualifier, false, false),
+                    &mut seq.iter().map(|(v,i)| (v.into(), i))
+                );
+                true
+            }
+            RangedVariation::Group102Var0 => {
+                false // qualifier 0x06
+            }
+            RangedVariation::Group102Var1(seq) => {
+                handler.handle_unsigned_integer(
+                    HeaderInfo::new(self.variation(), qualifier, false, false),
+                    &mut seq.iter().map(|(v,i)| (v.into(), i))
+                );
+                true
+            }
             RangedVariation::Group110Var0 => {
                 false
             }
@@ -446,6 +690,8 @@ impl<'a> RangedVariation<'a> {
     
     pub(crate) fn variation(&self) -> Variation {
         match self {
+            RangedVariation::Group0Var254 => Variation::Group0Var254,
+            RangedVariation::Group0(x, _) => Variation::Group0(*x),
             RangedVariation::Group1Var0 => Variation::Group1Var0,
             RangedVariation::Group1Var1(_) => Variation::Group1Var1,
             RangedVariation::Group1Var2(_) => Variation::Group1Var2,
@@ -455,6 +701,7 @@ impl<'a> RangedVariation<'a> {
             RangedVariation::Group10Var0 => Variation::Group10Var0,
             RangedVariation::Group10Var1(_) => Variation::Group10Var1,
             RangedVariation::Group10Var2(_) => Variation::Group10Var2,
+            RangedVariation::Group12Var3(_) => Variation::Group12Var3,
             RangedVariation::Group20Var0 => Variation::Group20Var0,
             RangedVariation::Group20Var1(_) => Variation::Group20Var1,
             RangedVariation::Group20Var2(_) => Variation::Group20Var2,
@@ -474,12 +721,31 @@ impl<'a> RangedVariation<'a> {
             RangedVariation::Group30Var4(_) => Variation::Group30Var4,
             RangedVariation::Group30Var5(_) => Variation::Group30Var5,
             RangedVariation::Group30Var6(_) => Variation::Group30Var6,
+            RangedVariation::Group31Var0 => Variation::Group31Var0,
+            RangedVariation::Group31Var1(_) => Variation::Group31Var1,
+            RangedVariation::Group31Var2(_) => Variation::Group31Var2,
+            RangedVariation::Group31Var3(_) => Variation::Group31Var3,
+            RangedVariation::Group31Var4(_) => Variation::Group31Var4,
+            RangedVariation::Group31Var5(_) => Variation::Group31Var5,
+            RangedVariation::Group31Var6(_) => Variation::Group31Var6,
+            RangedVariation::Group31Var7(_) => Variation::Group31Var7,
+            RangedVariation::Group31Var8(_) => Variation::Group31Var8,
+            RangedVariation::Group34Var0 => Variation::Group34Var0,
+            RangedVariation::Group34Var1(_) => Variation::Group34Var1,
+            RangedVariation::Group34Var2(_) => Variation::Group34Var2,
+            RangedVariation::Group34Var3(_) => Variation::Group34Var3,
             RangedVariation::Group40Var0 => Variation::Group40Var0,
             RangedVariation::Group40Var1(_) => Variation::Group40Var1,
             RangedVariation::Group40Var2(_) => Variation::Group40Var2,
             RangedVariation::Group40Var3(_) => Variation::Group40Var3,
             RangedVariation::Group40Var4(_) => Variation::Group40Var4,
             RangedVariation::Group80Var1(_) => Variation::Group80Var1,
+            RangedVariation::Group101Var0 => Variation::Group101Var0,
+            RangedVariation::Group101Var1(_) => Variation::Group101Var1,
+            RangedVariation::Group101Var2(_) => Variation::Group101Var2,
+            RangedVariation::Group101Var3(_) => Variation::Group101Var3,
+            RangedVariation::Group102Var0 => Variation::Group102Var0,
+            RangedVariation::Group102Var1(_) => Variation::Group102Var1,
             RangedVariation::Group110Var0 => Variation::Group110(0),
             RangedVariation::Group110VarX(x, _) => Variation::Group110(*x),
         }
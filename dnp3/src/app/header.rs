@@ -501,13 +501,15 @@ pub struct RequestHeader {
     pub function: FunctionCode,
 }
 
-/// Only 2 function codes allowed in responses
+/// Only 3 function codes allowed in responses
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ResponseFunction {
     /// (solicited) response (0x81)
     Response,
     /// unsolicited response (0x82)
     UnsolicitedResponse,
+    /// secure authentication response (0x83)
+    AuthenticateResponse,
 }
 
 /// application-layer header for responses
@@ -515,7 +517,7 @@ pub enum ResponseFunction {
 pub struct ResponseHeader {
     /// control field
     pub control: ControlField,
-    /// Function code limited to Response or UnsolicitedResponse
+    /// Function code limited to Response, UnsolicitedResponse, or AuthenticateResponse
     pub function: ResponseFunction,
     /// internal indications field
     pub iin: Iin,
@@ -527,6 +529,7 @@ impl ResponseFunction {
         match self {
             ResponseFunction::Response => false,
             ResponseFunction::UnsolicitedResponse => true,
+            ResponseFunction::AuthenticateResponse => false,
         }
     }
 
@@ -535,6 +538,7 @@ impl ResponseFunction {
         match self {
             ResponseFunction::Response => FunctionCode::Response,
             ResponseFunction::UnsolicitedResponse => FunctionCode::UnsolicitedResponse,
+            ResponseFunction::AuthenticateResponse => FunctionCode::AuthenticateResponse,
         }
     }
 }
@@ -544,11 +548,14 @@ impl From<ResponseFunction> for FunctionCode {
         match from {
             ResponseFunction::Response => FunctionCode::Response,
             ResponseFunction::UnsolicitedResponse => FunctionCode::UnsolicitedResponse,
+            ResponseFunction::AuthenticateResponse => FunctionCode::AuthenticateResponse,
         }
     }
 }
 
 impl RequestHeader {
+    pub(crate) const LENGTH: usize = 2;
+
     pub(crate) fn new(control: ControlField, function: FunctionCode) -> Self {
         Self { control, function }
     }
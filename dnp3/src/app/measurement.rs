@@ -1,5 +1,6 @@
 use std::time::Duration;
 
+use crate::app::control::CommandStatus;
 use crate::app::types::Timestamp;
 use crate::util::bit::bits;
 use crate::util::bit::BitMask;
@@ -261,6 +262,28 @@ impl AnalogInput {
     }
 }
 
+/// Measurement type corresponding to groups 31 and 33
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FrozenAnalogInput {
+    /// value of the type
+    pub value: f64,
+    /// associated flags
+    pub flags: Flags,
+    /// associated time
+    pub time: Option<Time>,
+}
+
+impl FrozenAnalogInput {
+    /// construct a `FrozenAnalogInput` from its fields
+    pub fn new(value: f64, flags: Flags, time: Time) -> Self {
+        Self {
+            value,
+            flags,
+            time: Some(time),
+        }
+    }
+}
+
 impl std::ops::BitOr<Flags> for Flags {
     type Output = Flags;
 
@@ -450,6 +473,28 @@ impl std::fmt::Display for BinaryOutputStatusFlagFormatter {
     }
 }
 
+pub(crate) struct BinaryCommandEventFlagFormatter {
+    flags: Flags,
+}
+
+impl BinaryCommandEventFlagFormatter {
+    pub(crate) fn new(value: u8) -> Self {
+        Self {
+            flags: Flags::new(value),
+        }
+    }
+}
+
+impl std::fmt::Display for BinaryCommandEventFlagFormatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut formatter = FlagFormatter::new();
+        FlagFormatter::begin(self.flags, f)?;
+        formatter.push_debug_item("state", self.flags.state(), f)?;
+        formatter.push_debug_item("status", CommandStatus::from(self.flags.value & 0x7F), f)?;
+        FlagFormatter::end(f)
+    }
+}
+
 pub(crate) struct CounterFlagFormatter {
     flags: Flags,
 }
@@ -563,6 +608,116 @@ impl AnalogOutputStatus {
     }
 }
 
+/// Measurement type corresponding to group 13
+///
+/// Records the state commanded by a binary output control and the status
+/// with which the outstation responded to it
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BinaryOutputCommandEvent {
+    /// state that was commanded
+    pub commanded_state: bool,
+    /// status of the command
+    pub status: CommandStatus,
+    /// associated time
+    pub time: Option<Time>,
+}
+
+impl BinaryOutputCommandEvent {
+    /// construct a `BinaryOutputCommandEvent` from its fields
+    pub fn new(commanded_state: bool, status: CommandStatus, time: Time) -> Self {
+        Self {
+            commanded_state,
+            status,
+            time: Some(time),
+        }
+    }
+}
+
+/// Measurement type corresponding to group 43
+///
+/// Records the value commanded by an analog output control and the status
+/// with which the outstation responded to it
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AnalogOutputCommandEvent {
+    /// value that was commanded
+    pub commanded_value: f64,
+    /// status of the command
+    pub status: CommandStatus,
+    /// associated time
+    pub time: Option<Time>,
+}
+
+impl AnalogOutputCommandEvent {
+    /// construct an `AnalogOutputCommandEvent` from its fields
+    pub fn new(commanded_value: f64, status: CommandStatus, time: Time) -> Self {
+        Self {
+            commanded_value,
+            status,
+            time: Some(time),
+        }
+    }
+}
+
+/// Unsigned integer point type corresponding to group 102
+///
+/// Unsigned integers are static values without flags or timestamps and never produce events.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct UnsignedInteger {
+    /// value of the type
+    pub value: u8,
+}
+
+impl UnsignedInteger {
+    /// construct an `UnsignedInteger` from its value
+    pub fn new(value: u8) -> Self {
+        Self { value }
+    }
+}
+
+/// Binary-coded decimal (BCD) point type corresponding to group 101
+///
+/// The value is the decoded decimal number, e.g. the 4-digit BCD encoding `0x1234` has
+/// a value of `1234`. BCD integers are static values without flags or timestamps and never
+/// produce events.
+///
+/// When encoded as a small (4 digit) or medium (8 digit) variation, values that do not fit
+/// saturate at the largest representable value (9999 or 99999999). When decoding, nibbles
+/// that are not valid decimal digits (0xA to 0xF) are weighted using their binary value.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Bcd {
+    /// decoded decimal value of the type
+    pub value: u64,
+}
+
+impl Bcd {
+    /// maximum value of a large (16 digit) BCD integer
+    pub const MAX: u64 = 9_999_999_999_999_999;
+
+    /// construct a `Bcd` from its decimal value
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    pub(crate) fn decode(encoded: u64) -> Self {
+        let mut value: u64 = 0;
+        for shift in (0..16).rev() {
+            let digit = (encoded >> (shift * 4)) & 0x0F;
+            value = value.wrapping_mul(10).wrapping_add(digit);
+        }
+        Self { value }
+    }
+
+    pub(crate) fn encode(&self, digits: u32) -> u64 {
+        let mut value = self.value.min(10u64.pow(digits) - 1);
+        let mut encoded: u64 = 0;
+        for position in 0..digits {
+            encoded |= (value % 10) << (position * 4);
+            value /= 10;
+        }
+        encoded
+    }
+}
+
 /// Octet string point type corresponding to groups 110 and 111
 ///
 /// Octet strings can only hold from 1 to 255 octets. Zero-length
@@ -658,6 +813,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn bcd_round_trips_decimal_values() {
+        assert_eq!(Bcd::decode(0x1234).value, 1234);
+        assert_eq!(Bcd::new(1234).encode(4), 0x1234);
+        assert_eq!(Bcd::new(Bcd::MAX).encode(16), 0x9999_9999_9999_9999);
+        assert_eq!(Bcd::decode(0x9999_9999_9999_9999).value, Bcd::MAX);
+    }
+
+    #[test]
+    fn bcd_saturates_values_that_do_not_fit() {
+        assert_eq!(Bcd::new(12345).encode(4), 0x9999);
+        assert_eq!(Bcd::new(u64::MAX).encode(8), 0x9999_9999);
+        assert_eq!(Bcd::new(u64::MAX).encode(16), 0x9999_9999_9999_9999);
+    }
+
+    #[test]
+    fn bcd_weights_invalid_nibbles_by_binary_value() {
+        assert_eq!(Bcd::decode(0x1A).value, 20);
+    }
+
     #[test]
     fn octet_string_default_value() {
         assert_eq!(&[0x00], OctetString::default().value());
@@ -696,4 +871,16 @@ mod tests {
             "0xC1 [ONLINE, state = Indeterminate]"
         );
     }
+
+    #[test]
+    fn formats_binary_command_event_flags() {
+        assert_eq!(
+            format!("{}", BinaryCommandEventFlagFormatter::new(0)),
+            "0x00 [state = false, status = Success]"
+        );
+        assert_eq!(
+            format!("{}", BinaryCommandEventFlagFormatter::new(0x86)),
+            "0x86 [state = true, status = HardwareError]"
+        );
+    }
 }
@@ -1,5 +1,10 @@
+pub use activation::*;
 pub use app_enums::*;
+pub use application::*;
+pub use attr::*;
 pub use buffer_size::*;
+pub use data_set::*;
+pub use file::{FileInfo, FileMode, FileType, PermissionSet, Permissions};
 pub use header::*;
 pub use listener::*;
 pub use maybe_async::MaybeAsync;
@@ -34,9 +39,21 @@ impl EndpointType {
 mod control_types;
 #[rustfmt::skip]
 mod app_enums;
+/// configuration activation status (group 91)
+pub(crate) mod activation;
+/// application identifiers (group 90)
+pub(crate) mod application;
+/// device attributes (group 0)
+mod attr;
+/// secure authentication objects (group 120)
+pub(crate) mod auth;
 mod buffer_size;
 mod control_enums;
+/// data sets (groups 85 - 88)
+mod data_set;
 mod extensions;
+/// file-control objects (group 70)
+pub(crate) mod file;
 mod header;
 mod listener;
 mod maybe_async;
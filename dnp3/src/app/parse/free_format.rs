@@ -0,0 +1,86 @@
+use crate::app::activation::Group91Var1;
+use crate::app::application::Group90Var1;
+use crate::app::auth::*;
+use crate::app::data_set::*;
+use crate::app::file::*;
+use crate::app::parse_error::ObjectParseError;
+use crate::app::variations::Variation;
+use crate::app::QualifierCode;
+
+/// Variations that may be encoded using the free-format qualifier (0x5B)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum FreeFormatVariation<'a> {
+    Group70Var2(Group70Var2<'a>),
+    Group70Var3(Group70Var3<'a>),
+    Group70Var4(Group70Var4<'a>),
+    Group70Var5(Group70Var5<'a>),
+    Group70Var6(Group70Var6<'a>),
+    Group70Var7(Group70Var7<'a>),
+    Group70Var8(Group70Var8<'a>),
+    Group85Var1(Group85Var1<'a>),
+    Group86Var1(Group86Var1<'a>),
+    Group87Var1(Group87Var1<'a>),
+    Group88Var1(Group88Var1<'a>),
+    Group90Var1(Group90Var1<'a>),
+    Group91Var1(Group91Var1<'a>),
+    Group120Var1(Group120Var1<'a>),
+    Group120Var2(Group120Var2<'a>),
+    Group120Var5(Group120Var5<'a>),
+    Group120Var6(Group120Var6<'a>),
+    Group120Var7(Group120Var7<'a>),
+    Group120Var9(Group120Var9<'a>),
+}
+
+impl<'a> FreeFormatVariation<'a> {
+    pub(crate) fn parse(v: Variation, object: &'a [u8]) -> Result<Self, ObjectParseError> {
+        match v {
+            Variation::Group70Var2 => Ok(Self::Group70Var2(Group70Var2::read(object)?)),
+            Variation::Group70Var3 => Ok(Self::Group70Var3(Group70Var3::read(object)?)),
+            Variation::Group70Var4 => Ok(Self::Group70Var4(Group70Var4::read(object)?)),
+            Variation::Group70Var5 => Ok(Self::Group70Var5(Group70Var5::read(object)?)),
+            Variation::Group70Var6 => Ok(Self::Group70Var6(Group70Var6::read(object)?)),
+            Variation::Group70Var7 => Ok(Self::Group70Var7(Group70Var7::read(object)?)),
+            Variation::Group70Var8 => Ok(Self::Group70Var8(Group70Var8::read(object)?)),
+            Variation::Group85Var1 => Ok(Self::Group85Var1(Group85Var1::read(object)?)),
+            Variation::Group86Var1 => Ok(Self::Group86Var1(Group86Var1::read(object)?)),
+            Variation::Group87Var1 => Ok(Self::Group87Var1(Group87Var1::read(object)?)),
+            Variation::Group88Var1 => Ok(Self::Group88Var1(Group88Var1::read(object)?)),
+            Variation::Group90Var1 => Ok(Self::Group90Var1(Group90Var1::read(object)?)),
+            Variation::Group91Var1 => Ok(Self::Group91Var1(Group91Var1::read(object)?)),
+            Variation::Group120Var1 => Ok(Self::Group120Var1(Group120Var1::read(object)?)),
+            Variation::Group120Var2 => Ok(Self::Group120Var2(Group120Var2::read(object)?)),
+            Variation::Group120Var5 => Ok(Self::Group120Var5(Group120Var5::read(object)?)),
+            Variation::Group120Var6 => Ok(Self::Group120Var6(Group120Var6::read(object)?)),
+            Variation::Group120Var7 => Ok(Self::Group120Var7(Group120Var7::read(object)?)),
+            Variation::Group120Var9 => Ok(Self::Group120Var9(Group120Var9::read(object)?)),
+            _ => Err(ObjectParseError::InvalidQualifierForVariation(
+                v,
+                QualifierCode::FreeFormat16,
+            )),
+        }
+    }
+
+    pub(crate) fn format_objects(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Group70Var2(x) => write!(f, "\n{}", x),
+            Self::Group70Var3(x) => write!(f, "\n{}", x),
+            Self::Group70Var4(x) => write!(f, "\n{}", x),
+            Self::Group70Var5(x) => write!(f, "\n{}", x),
+            Self::Group70Var6(x) => write!(f, "\n{}", x),
+            Self::Group70Var7(x) => write!(f, "\n{}", x),
+            Self::Group70Var8(x) => write!(f, "\n{}", x),
+            Self::Group85Var1(x) => write!(f, "\n{}", x),
+            Self::Group86Var1(x) => write!(f, "\n{}", x),
+            Self::Group87Var1(x) => write!(f, "\n{}", x),
+            Self::Group88Var1(x) => write!(f, "\n{}", x),
+            Self::Group90Var1(x) => write!(f, "\n{}", x),
+            Self::Group91Var1(x) => write!(f, "\n{}", x),
+            Self::Group120Var1(x) => write!(f, "\n{}", x),
+            Self::Group120Var2(x) => write!(f, "\n{}", x),
+            Self::Group120Var5(x) => write!(f, "\n{}", x),
+            Self::Group120Var6(x) => write!(f, "\n{}", x),
+            Self::Group120Var7(x) => write!(f, "\n{}", x),
+            Self::Group120Var9(x) => write!(f, "\n{}", x),
+        }
+    }
+}
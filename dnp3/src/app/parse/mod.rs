@@ -1,6 +1,7 @@
 pub(crate) mod bit;
 pub(crate) mod bytes;
 pub(crate) mod count;
+pub(crate) mod free_format;
 pub(crate) mod parser;
 pub(crate) mod prefix;
 pub(crate) mod range;
@@ -7,6 +7,7 @@ use crate::app::gen::count::CountVariation;
 use crate::app::gen::prefixed::PrefixedVariation;
 use crate::app::gen::ranged::RangedVariation;
 use crate::app::header::{ControlField, Iin, RequestHeader, ResponseFunction, ResponseHeader};
+use crate::app::parse::free_format::FreeFormatVariation;
 use crate::app::parse::prefix::Prefix;
 use crate::app::parse::range::Range;
 use crate::app::parse::traits::{FixedSizeVariation, Index};
@@ -118,6 +119,9 @@ impl<'a> ParsedFragment<'a> {
             (FunctionCode::UnsolicitedResponse, Some(x)) => {
                 (ResponseFunction::UnsolicitedResponse, x)
             }
+            (FunctionCode::AuthenticateResponse, Some(x)) => {
+                (ResponseFunction::AuthenticateResponse, x)
+            }
             _ => return Err(ResponseValidationError::UnexpectedFunction(self.function)),
         };
 
@@ -152,6 +156,7 @@ impl<'a> ParsedFragment<'a> {
         let iin = match function {
             FunctionCode::Response => Some(Iin::parse(&mut cursor)?),
             FunctionCode::UnsolicitedResponse => Some(Iin::parse(&mut cursor)?),
+            FunctionCode::AuthenticateResponse => Some(Iin::parse(&mut cursor)?),
             _ => None,
         };
 
@@ -279,6 +284,20 @@ impl<'a> ObjectHeader<'a> {
                 }
                 Ok(())
             }
+            HeaderDetails::TwoByteFreeFormat(c, obj) => {
+                write!(
+                    f,
+                    "{} : {} - {} - [{}]",
+                    self.variation,
+                    self.variation.description(),
+                    self.details.qualifier().description(),
+                    c
+                )?;
+                if format_values {
+                    obj.format_objects(f)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -329,9 +348,10 @@ pub(crate) enum HeaderDetails<'a> {
     TwoByteCount(u16, CountVariation<'a>),
     OneByteCountAndPrefix(u8, PrefixedVariation<'a, u8>),
     TwoByteCountAndPrefix(u16, PrefixedVariation<'a, u16>),
+    TwoByteFreeFormat(u8, FreeFormatVariation<'a>),
 }
 
-impl HeaderDetails<'_> {
+impl<'a> HeaderDetails<'a> {
     pub(crate) fn qualifier(&self) -> QualifierCode {
         match self {
             HeaderDetails::AllObjects(_) => QualifierCode::AllObjects,
@@ -341,6 +361,7 @@ impl HeaderDetails<'_> {
             HeaderDetails::TwoByteCount(_, _) => QualifierCode::Count16,
             HeaderDetails::OneByteCountAndPrefix(_, _) => QualifierCode::CountAndPrefix8,
             HeaderDetails::TwoByteCountAndPrefix(_, _) => QualifierCode::CountAndPrefix16,
+            HeaderDetails::TwoByteFreeFormat(_, _) => QualifierCode::FreeFormat16,
         }
     }
 
@@ -351,6 +372,13 @@ impl HeaderDetails<'_> {
             _ => None,
         }
     }
+
+    pub(crate) fn free_format(&self) -> Option<FreeFormatVariation<'a>> {
+        match self {
+            HeaderDetails::TwoByteFreeFormat(_, object) => Some(*object),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -400,6 +428,14 @@ impl<'a> HeaderCollection<'a> {
         }
     }
 
+    /// return an iterator of the headers paired with the raw bytes from which each was parsed
+    pub(crate) fn iter_with_bytes(&self) -> HeaderBytesIterator<'a> {
+        HeaderBytesIterator {
+            data: self.data,
+            parser: ObjectParser::one_pass(self.function, self.data),
+        }
+    }
+
     pub(crate) fn get_only_header(&self) -> Option<ObjectHeader<'a>> {
         let mut iter = self.iter();
         match iter.next() {
@@ -434,6 +470,28 @@ impl<'a> Iterator for HeaderIterator<'a> {
     }
 }
 
+#[derive(Copy, Clone)]
+pub(crate) struct HeaderBytesIterator<'a> {
+    data: &'a [u8],
+    parser: ObjectParser<'a>,
+}
+
+impl<'a> Iterator for HeaderBytesIterator<'a> {
+    type Item = (ObjectHeader<'a>, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.data.len() - self.parser.cursor.remaining();
+        match self.parser.next() {
+            Some(Ok(x)) => {
+                let end = self.data.len() - self.parser.cursor.remaining();
+                Some((x, &self.data[start..end]))
+            }
+            // see HeaderIterator
+            _ => None,
+        }
+    }
+}
+
 impl<'a> ObjectParser<'a> {
     pub(crate) fn parse(
         function: FunctionCode,
@@ -482,7 +540,7 @@ impl<'a> ObjectParser<'a> {
             QualifierCode::Count16 => self.parse_count_u16(gv),
             QualifierCode::CountAndPrefix8 => self.parse_count_and_prefix_u8(gv),
             QualifierCode::CountAndPrefix16 => self.parse_count_and_prefix_u16(gv),
-            _ => Err(ObjectParseError::UnsupportedQualifierCode(qualifier)),
+            QualifierCode::FreeFormat16 => self.parse_free_format_u16(gv),
         }
     }
 
@@ -571,6 +629,24 @@ impl<'a> ObjectParser<'a> {
             HeaderDetails::TwoByteCountAndPrefix(count, data),
         ))
     }
+
+    fn parse_free_format_u16(
+        &mut self,
+        v: Variation,
+    ) -> Result<ObjectHeader<'a>, ObjectParseError> {
+        let count = self.cursor.read_u8()?;
+        // every variation supported with this qualifier is sent one object at a time
+        if count != 1 {
+            return Err(ObjectParseError::UnsupportedFreeFormatCount(count));
+        }
+        let size = self.cursor.read_u16_le()?;
+        let object = self.cursor.read_bytes(size as usize)?;
+        let data = FreeFormatVariation::parse(v, object)?;
+        Ok(ObjectHeader::new(
+            v,
+            HeaderDetails::TwoByteFreeFormat(count, data),
+        ))
+    }
 }
 
 impl<'a> Iterator for ObjectParser<'a> {
@@ -627,6 +703,7 @@ mod test {
     use crate::app::sequence::Sequence;
     use crate::app::types::Timestamp;
     use crate::app::variations::*;
+    use crate::app::{AttrParseError, AttrValue};
 
     use super::*;
 
@@ -1018,4 +1095,111 @@ mod test {
             ObjectParseError::ZeroLengthOctetData,
         );
     }
+
+    #[test]
+    fn parses_group70var5_free_format() {
+        let input = [
+            0x46, 0x05, 0x5B, 0x01, 0x0A, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x80,
+            0xAA, 0xBB,
+        ];
+        let mut headers = ObjectParser::parse(FunctionCode::Write, &input)
+            .unwrap()
+            .iter();
+
+        let block = assert_matches!(
+            headers.next().unwrap().details,
+            HeaderDetails::TwoByteFreeFormat(0x01, FreeFormatVariation::Group70Var5(x)) => x
+        );
+
+        assert_eq!(block.file_handle, 1);
+        assert_eq!(block.block_number.value, 2);
+        assert!(block.block_number.last);
+        assert_eq!(block.data, [0xAA, 0xBB]);
+        assert_matches!(headers.next(), None);
+    }
+
+    #[test]
+    fn free_format_requires_a_count_of_one() {
+        test_parse_error(
+            &[0x46, 0x05, 0x5B, 0x02, 0x00, 0x00],
+            FunctionCode::Write,
+            ObjectParseError::UnsupportedFreeFormatCount(2),
+        );
+    }
+
+    #[test]
+    fn parses_group0_device_attribute() {
+        let input = [0x00, 0xFC, 0x00, 0x00, 0x00, 0x01, 0x02, b'h', b'i'];
+        let mut headers = ObjectParser::parse(FunctionCode::Response, &input)
+            .unwrap()
+            .iter();
+
+        let attr = assert_matches!(
+            headers.next().unwrap().details,
+            HeaderDetails::OneByteStartStop(0x00, 0x00, RangedVariation::Group0(0xFC, Some(x))) => x
+        );
+
+        assert_eq!(attr.set, 0);
+        assert_eq!(attr.variation, 0xFC);
+        assert_eq!(attr.value, AttrValue::VisibleString("hi"));
+        assert_matches!(headers.next(), None);
+    }
+
+    #[test]
+    fn parses_group0_read_requests() {
+        let input = [0x00, 0xFE, 0x06, 0x00, 0xFC, 0x00, 0x01, 0x01];
+        let mut headers = ObjectParser::parse(FunctionCode::Read, &input)
+            .unwrap()
+            .iter();
+
+        assert_matches!(
+            headers.next().unwrap().details,
+            HeaderDetails::AllObjects(AllObjectsVariation::Group0Var254)
+        );
+        assert_matches!(
+            headers.next().unwrap().details,
+            HeaderDetails::OneByteStartStop(0x01, 0x01, RangedVariation::Group0(0xFC, None))
+        );
+        assert_matches!(headers.next(), None);
+    }
+
+    #[test]
+    fn group0_requires_a_single_set() {
+        test_parse_error(
+            &[0x00, 0xFC, 0x00, 0x00, 0x01, 0x02, 0x00, 0x02, 0x00],
+            FunctionCode::Response,
+            ObjectParseError::BadAttribute(AttrParseError::CountNotOne(2)),
+        );
+    }
+
+    #[test]
+    fn free_format_rejects_fixed_size_variations() {
+        test_parse_error(
+            &[0x01, 0x02, 0x5B, 0x01, 0x01, 0x00, 0x01],
+            FunctionCode::Write,
+            ObjectParseError::InvalidQualifierForVariation(
+                Variation::Group1Var2,
+                QualifierCode::FreeFormat16,
+            ),
+        );
+    }
+
+    #[test]
+    fn header_bytes_iterator_returns_the_raw_bytes_of_each_header() {
+        // g60v2 all objects, g30v1 [1..1] with one value
+        let input = [
+            60, 2, 0x06, 30, 1, 0x00, 0x01, 0x01, 0x01, 0x2A, 0x00, 0x00, 0x00,
+        ];
+        let mut headers = ObjectParser::parse(FunctionCode::Response, &input)
+            .unwrap()
+            .iter_with_bytes();
+
+        let (header, bytes) = headers.next().unwrap();
+        assert_eq!(header.variation, Variation::Group60Var2);
+        assert_eq!(bytes, &input[0..3]);
+        let (header, bytes) = headers.next().unwrap();
+        assert_eq!(header.variation, Variation::Group30Var1);
+        assert_eq!(bytes, &input[3..]);
+        assert!(headers.next().is_none());
+    }
 }
@@ -1,5 +1,7 @@
 use std::fmt::Formatter;
 
+use crate::app::attr::AttrParseError;
+use crate::app::data_set::DataSetParseError;
 use crate::app::parse::range::InvalidRange;
 use crate::app::sequence::Sequence;
 use crate::app::variations::Variation;
@@ -33,6 +35,14 @@ pub enum ObjectParseError {
     UnsupportedQualifierCode(QualifierCode),
     /// response containing zero-length octet data disallowed by the specification
     ZeroLengthOctetData,
+    /// free-format header with a count of objects other than one
+    UnsupportedFreeFormatCount(u8),
+    /// string that is not valid UTF-8
+    BadEncoding,
+    /// device attribute (g0) that could not be parsed
+    BadAttribute(AttrParseError),
+    /// data set object (g85 - g88) that could not be parsed
+    BadDataSet(DataSetParseError),
 }
 
 /// errors that occur when interpreting a header as a request header
@@ -99,6 +109,12 @@ impl std::fmt::Display for ObjectParseError {
             ObjectParseError::ZeroLengthOctetData => {
                 f.write_str("octet-data may not be zero length")
             }
+            ObjectParseError::UnsupportedFreeFormatCount(count) => {
+                write!(f, "unsupported count of free-format objects: {}", count)
+            }
+            ObjectParseError::BadEncoding => f.write_str("string is not valid UTF-8"),
+            ObjectParseError::BadAttribute(err) => write!(f, "bad device attribute: {}", err),
+            ObjectParseError::BadDataSet(err) => write!(f, "bad data set: {}", err),
         }
     }
 }
@@ -2,8 +2,8 @@ use std::convert::TryFrom;
 use std::time::{Duration, SystemTime};
 
 use crate::app::measurement::DoubleBit;
-use crate::app::variations::Variation;
-use crate::app::QualifierCode;
+use crate::app::variations::{Group50Var4, Variation};
+use crate::app::{IntervalUnit, QualifierCode};
 
 use chrono::{DateTime, SecondsFormat, TimeZone, Utc};
 use scursor::{WriteCursor, WriteError};
@@ -83,6 +83,45 @@ impl std::fmt::Display for Timestamp {
     }
 }
 
+/// Absolute time and long interval (g50v4) used to schedule a repeating action on a point,
+/// e.g. the freeze of a counter
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TimeAndInterval {
+    /// time at which the action is first performed
+    pub time: Timestamp,
+    /// count of `units` between each repetition of the action
+    pub interval: u32,
+    /// units of the interval
+    pub units: IntervalUnit,
+}
+
+impl TimeAndInterval {
+    /// construct a `TimeAndInterval` from its fields
+    pub fn new(time: Timestamp, interval: u32, units: IntervalUnit) -> Self {
+        Self {
+            time,
+            interval,
+            units,
+        }
+    }
+}
+
+impl From<Group50Var4> for TimeAndInterval {
+    fn from(v: Group50Var4) -> Self {
+        Self::new(v.time, v.interval, v.units)
+    }
+}
+
+impl From<TimeAndInterval> for Group50Var4 {
+    fn from(v: TimeAndInterval) -> Self {
+        Self {
+            time: v.time,
+            interval: v.interval,
+            units: v.units,
+        }
+    }
+}
+
 pub(crate) struct BitPair {
     pub(crate) high: bool,
     pub(crate) low: bool,
@@ -12,7 +12,7 @@
 
 use crate::app::parse::traits::{FixedSize, FixedSizeVariation};
 use crate::app::control::{CommandStatus, ControlCode};
-use crate::app::Timestamp;
+use crate::app::{IntervalUnit, Timestamp};
 use crate::app::measurement::*;
 
 use scursor::*;
@@ -20,6 +20,10 @@ use scursor::*;
 /// All variations supported by the library
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Variation {
+    /// Device Attributes - Non-specific all attributes request
+    Group0Var254,
+    /// Device Attributes - Specific attribute
+    Group0(u8),
     /// Binary Input - Any Variation
     Group1Var0,
     /// Binary Input - Packed Format
@@ -64,6 +68,16 @@ pub enum Variation {
     Group12Var0,
     /// Binary Command - Control Relay Output Block
     Group12Var1,
+    /// Binary Command - Pattern Control Block
+    Group12Var2,
+    /// Binary Command - Pattern Mask
+    Group12Var3,
+    /// Binary Output Command Event - Any Variation
+    Group13Var0,
+    /// Binary Output Command Event - Without Time
+    Group13Var1,
+    /// Binary Output Command Event - With Time
+    Group13Var2,
     /// Counter - Any Variation
     Group20Var0,
     /// Counter - 32-bit With Flag
@@ -122,6 +136,24 @@ pub enum Variation {
     Group30Var5,
     /// Analog Input - Double-precision With Flag
     Group30Var6,
+    /// Frozen Analog Input - Any Variation
+    Group31Var0,
+    /// Frozen Analog Input - 32-bit With Flag
+    Group31Var1,
+    /// Frozen Analog Input - 16-bit With Flag
+    Group31Var2,
+    /// Frozen Analog Input - 32-bit With Flag and Time-of-Freeze
+    Group31Var3,
+    /// Frozen Analog Input - 16-bit With Flag and Time-of-Freeze
+    Group31Var4,
+    /// Frozen Analog Input - 32-bit Without Flag
+    Group31Var5,
+    /// Frozen Analog Input - 16-bit Without Flag
+    Group31Var6,
+    /// Frozen Analog Input - Single-precision With Flag
+    Group31Var7,
+    /// Frozen Analog Input - Double-precision With Flag
+    Group31Var8,
     /// Analog Input Event - Any Variation
     Group32Var0,
     /// Analog Input Event - 32-bit With Flag
@@ -140,6 +172,32 @@ pub enum Variation {
     Group32Var7,
     /// Analog Input Event - Double-precision With Flag and Time
     Group32Var8,
+    /// Frozen Analog Input Event - Any Variation
+    Group33Var0,
+    /// Frozen Analog Input Event - 32-bit With Flag
+    Group33Var1,
+    /// Frozen Analog Input Event - 16-bit With Flag
+    Group33Var2,
+    /// Frozen Analog Input Event - 32-bit With Flag and Time
+    Group33Var3,
+    /// Frozen Analog Input Event - 16-bit With Flag and Time
+    Group33Var4,
+    /// Frozen Analog Input Event - Single-precision With Flag
+    Group33Var5,
+    /// Frozen Analog Input Event - Double-precision With Flag
+    Group33Var6,
+    /// Frozen Analog Input Event - Single-precision With Flag and Time
+    Group33Var7,
+    /// Frozen Analog Input Event - Double-precision With Flag and Time
+    Group33Var8,
+    /// Analog Input Deadband - Any Variation
+    Group34Var0,
+    /// Analog Input Deadband - 16-bit
+    Group34Var1,
+    /// Analog Input Deadband - 32-bit
+    Group34Var2,
+    /// Analog Input Deadband - Single-precision
+    Group34Var3,
     /// Analog Output Status - Any Variation
     Group40Var0,
     /// Analog Output Status - 32-bit With Flag
@@ -178,8 +236,28 @@ pub enum Variation {
     Group42Var7,
     /// Analog Output Event - Double-precision With Flag and Time
     Group42Var8,
+    /// Analog Output Command Event - Any Variation
+    Group43Var0,
+    /// Analog Output Command Event - 32-bit
+    Group43Var1,
+    /// Analog Output Command Event - 16-bit
+    Group43Var2,
+    /// Analog Output Command Event - 32-bit With Time
+    Group43Var3,
+    /// Analog Output Command Event - 16-bit With Time
+    Group43Var4,
+    /// Analog Output Command Event - Single-precision
+    Group43Var5,
+    /// Analog Output Command Event - Double-precision
+    Group43Var6,
+    /// Analog Output Command Event - Single-precision With Time
+    Group43Var7,
+    /// Analog Output Command Event - Double-precision With Time
+    Group43Var8,
     /// Time and Date - Absolute Time
     Group50Var1,
+    /// Time and Date - Absolute time and interval
+    Group50Var2,
     /// Time and Date - Absolute Time at last recorded time
     Group50Var3,
     /// Time and Date - Indexed absolute time and long interval
@@ -200,17 +278,81 @@ pub enum Variation {
     Group60Var3,
     /// Class Data - Class 3
     Group60Var4,
+    /// File-control - File identifier
+    Group70Var1,
+    /// File-control - Authentication
+    Group70Var2,
+    /// File-control - File command
+    Group70Var3,
+    /// File-control - File command status
+    Group70Var4,
+    /// File-control - File transport
+    Group70Var5,
+    /// File-control - File transport status
+    Group70Var6,
+    /// File-control - File descriptor
+    Group70Var7,
+    /// File-control - File specification string
+    Group70Var8,
     /// Internal Indications - Packed Format
     Group80Var1,
+    /// Data Set Prototype - With UUID
+    Group85Var1,
+    /// Data Set Descriptor - Data Set Contents
+    Group86Var1,
+    /// Data Set - Present Value
+    Group87Var1,
+    /// Data Set Event - Snapshot
+    Group88Var1,
+    /// Application Identifier - Identifier
+    Group90Var1,
+    /// Status of Requested Operation - Activate Configuration
+    Group91Var1,
+    /// BCD Integer - Any Variation
+    Group101Var0,
+    /// BCD Integer - Small (4 digits)
+    Group101Var1,
+    /// BCD Integer - Medium (8 digits)
+    Group101Var2,
+    /// BCD Integer - Large (16 digits)
+    Group101Var3,
+    /// Unsigned Integer - Any Variation
+    Group102Var0,
+    /// Unsigned Integer - 8-bit
+    Group102Var1,
     /// Octet String - Sized by variation
     Group110(u8),
     /// Octet String Event - Sized by variation
     Group111(u8),
+    /// Virtual Terminal Output Block - Sized by variation
+    Group112(u8),
+    /// Virtual Terminal Event Data - Sized by variation
+    Group113(u8),
+    /// Authentication - Challenge
+    Group120Var1,
+    /// Authentication - Reply
+    Group120Var2,
+    /// Authentication - Aggressive Mode Request
+    Group120Var3,
+    /// Authentication - Session Key Status Request
+    Group120Var4,
+    /// Authentication - Session Key Status
+    Group120Var5,
+    /// Authentication - Session Key Change
+    Group120Var6,
+    /// Authentication - Error
+    Group120Var7,
+    /// Authentication - HMAC
+    Group120Var9,
 }
 
 impl Variation {
     pub(crate) fn lookup(group: u8, var: u8) -> Option<Variation> {
         match group {
+            0 => match var {
+                254 => Some(Variation::Group0Var254),
+                _ => Some(Variation::Group0(var)),
+            },
             1 => match var {
                 0 => Some(Variation::Group1Var0),
                 1 => Some(Variation::Group1Var1),
@@ -252,6 +394,14 @@ impl Variation {
             12 => match var {
                 0 => Some(Variation::Group12Var0),
                 1 => Some(Variation::Group12Var1),
+                2 => Some(Variation::Group12Var2),
+                3 => Some(Variation::Group12Var3),
+                _ => None,
+            },
+            13 => match var {
+                0 => Some(Variation::Group13Var0),
+                1 => Some(Variation::Group13Var1),
+                2 => Some(Variation::Group13Var2),
                 _ => None,
             },
             20 => match var {
@@ -298,6 +448,18 @@ impl Variation {
                 6 => Some(Variation::Group30Var6),
                 _ => None,
             },
+            31 => match var {
+                0 => Some(Variation::Group31Var0),
+                1 => Some(Variation::Group31Var1),
+                2 => Some(Variation::Group31Var2),
+                3 => Some(Variation::Group31Var3),
+                4 => Some(Variation::Group31Var4),
+                5 => Some(Variation::Group31Var5),
+                6 => Some(Variation::Group31Var6),
+                7 => Some(Variation::Group31Var7),
+                8 => Some(Variation::Group31Var8),
+                _ => None,
+            },
             32 => match var {
                 0 => Some(Variation::Group32Var0),
                 1 => Some(Variation::Group32Var1),
@@ -310,6 +472,25 @@ impl Variation {
                 8 => Some(Variation::Group32Var8),
                 _ => None,
             },
+            33 => match var {
+                0 => Some(Variation::Group33Var0),
+                1 => Some(Variation::Group33Var1),
+                2 => Some(Variation::Group33Var2),
+                3 => Some(Variation::Group33Var3),
+                4 => Some(Variation::Group33Var4),
+                5 => Some(Variation::Group33Var5),
+                6 => Some(Variation::Group33Var6),
+                7 => Some(Variation::Group33Var7),
+                8 => Some(Variation::Group33Var8),
+                _ => None,
+            },
+            34 => match var {
+                0 => Some(Variation::Group34Var0),
+                1 => Some(Variation::Group34Var1),
+                2 => Some(Variation::Group34Var2),
+                3 => Some(Variation::Group34Var3),
+                _ => None,
+            },
             40 => match var {
                 0 => Some(Variation::Group40Var0),
                 1 => Some(Variation::Group40Var1),
@@ -338,8 +519,21 @@ impl Variation {
                 8 => Some(Variation::Group42Var8),
                 _ => None,
             },
+            43 => match var {
+                0 => Some(Variation::Group43Var0),
+                1 => Some(Variation::Group43Var1),
+                2 => Some(Variation::Group43Var2),
+                3 => Some(Variation::Group43Var3),
+                4 => Some(Variation::Group43Var4),
+                5 => Some(Variation::Group43Var5),
+                6 => Some(Variation::Group43Var6),
+                7 => Some(Variation::Group43Var7),
+                8 => Some(Variation::Group43Var8),
+                _ => None,
+            },
             50 => match var {
                 1 => Some(Variation::Group50Var1),
+                2 => Some(Variation::Group50Var2),
                 3 => Some(Variation::Group50Var3),
                 4 => Some(Variation::Group50Var4),
                 _ => None,
@@ -361,18 +555,80 @@ impl Variation {
                 4 => Some(Variation::Group60Var4),
                 _ => None,
             },
+            70 => match var {
+                1 => Some(Variation::Group70Var1),
+                2 => Some(Variation::Group70Var2),
+                3 => Some(Variation::Group70Var3),
+                4 => Some(Variation::Group70Var4),
+                5 => Some(Variation::Group70Var5),
+                6 => Some(Variation::Group70Var6),
+                7 => Some(Variation::Group70Var7),
+                8 => Some(Variation::Group70Var8),
+                _ => None,
+            },
             80 => match var {
                 1 => Some(Variation::Group80Var1),
                 _ => None,
             },
+            85 => match var {
+                1 => Some(Variation::Group85Var1),
+                _ => None,
+            },
+            86 => match var {
+                1 => Some(Variation::Group86Var1),
+                _ => None,
+            },
+            87 => match var {
+                1 => Some(Variation::Group87Var1),
+                _ => None,
+            },
+            88 => match var {
+                1 => Some(Variation::Group88Var1),
+                _ => None,
+            },
+            90 => match var {
+                1 => Some(Variation::Group90Var1),
+                _ => None,
+            },
+            91 => match var {
+                1 => Some(Variation::Group91Var1),
+                _ => None,
+            },
+            101 => match var {
+                0 => Some(Variation::Group101Var0),
+                1 => Some(Variation::Group101Var1),
+                2 => Some(Variation::Group101Var2),
+                3 => Some(Variation::Group101Var3),
+                _ => None,
+            },
+            102 => match var {
+                0 => Some(Variation::Group102Var0),
+                1 => Some(Variation::Group102Var1),
+                _ => None,
+            },
             110 => Some(Variation::Group110(var)),
             111 => Some(Variation::Group111(var)),
+            112 => Some(Variation::Group112(var)),
+            113 => Some(Variation::Group113(var)),
+            120 => match var {
+                1 => Some(Variation::Group120Var1),
+                2 => Some(Variation::Group120Var2),
+                3 => Some(Variation::Group120Var3),
+                4 => Some(Variation::Group120Var4),
+                5 => Some(Variation::Group120Var5),
+                6 => Some(Variation::Group120Var6),
+                7 => Some(Variation::Group120Var7),
+                9 => Some(Variation::Group120Var9),
+                _ => None,
+            },
             _ => None,
         }
     }
     
     pub(crate) fn to_group_and_var(self) -> (u8, u8) {
         match self {
+            Variation::Group0Var254 => (0, 254),
+            Variation::Group0(x) => (0, x),
             Variation::Group1Var0 => (1, 0),
             Variation::Group1Var1 => (1, 1),
             Variation::Group1Var2 => (1, 2),
@@ -395,6 +651,11 @@ impl Variation {
             Variation::Group11Var2 => (11, 2),
             Variation::Group12Var0 => (12, 0),
             Variation::Group12Var1 => (12, 1),
+            Variation::Group12Var2 => (12, 2),
+            Variation::Group12Var3 => (12, 3),
+            Variation::Group13Var0 => (13, 0),
+            Variation::Group13Var1 => (13, 1),
+            Variation::Group13Var2 => (13, 2),
             Variation::Group20Var0 => (20, 0),
             Variation::Group20Var1 => (20, 1),
             Variation::Group20Var2 => (20, 2),
@@ -424,6 +685,15 @@ impl Variation {
             Variation::Group30Var4 => (30, 4),
             Variation::Group30Var5 => (30, 5),
             Variation::Group30Var6 => (30, 6),
+            Variation::Group31Var0 => (31, 0),
+            Variation::Group31Var1 => (31, 1),
+            Variation::Group31Var2 => (31, 2),
+            Variation::Group31Var3 => (31, 3),
+            Variation::Group31Var4 => (31, 4),
+            Variation::Group31Var5 => (31, 5),
+            Variation::Group31Var6 => (31, 6),
+            Variation::Group31Var7 => (31, 7),
+            Variation::Group31Var8 => (31, 8),
             Variation::Group32Var0 => (32, 0),
             Variation::Group32Var1 => (32, 1),
             Variation::Group32Var2 => (32, 2),
@@ -433,6 +703,19 @@ impl Variation {
             Variation::Group32Var6 => (32, 6),
             Variation::Group32Var7 => (32, 7),
             Variation::Group32Var8 => (32, 8),
+            Variation::Group33Var0 => (33, 0),
+            Variation::Group33Var1 => (33, 1),
+            Variation::Group33Var2 => (33, 2),
+            Variation::Group33Var3 => (33, 3),
+            Variation::Group33Var4 => (33, 4),
+            Variation::Group33Var5 => (33, 5),
+            Variation::Group33Var6 => (33, 6),
+            Variation::Group33Var7 => (33, 7),
+            Variation::Group33Var8 => (33, 8),
+            Variation::Group34Var0 => (34, 0),
+            Variation::Group34Var1 => (34, 1),
+            Variation::Group34Var2 => (34, 2),
+            Variation::Group34Var3 => (34, 3),
             Variation::Group40Var0 => (40, 0),
             Variation::Group40Var1 => (40, 1),
             Variation::Group40Var2 => (40, 2),
@@ -452,7 +735,17 @@ impl Variation {
             Variation::Group42Var6 => (42, 6),
             Variation::Group42Var7 => (42, 7),
             Variation::Group42Var8 => (42, 8),
+            Variation::Group43Var0 => (43, 0),
+            Variation::Group43Var1 => (43, 1),
+            Variation::Group43Var2 => (43, 2),
+            Variation::Group43Var3 => (43, 3),
+            Variation::Group43Var4 => (43, 4),
+            Variation::Group43Var5 => (43, 5),
+            Variation::Group43Var6 => (43, 6),
+            Variation::Group43Var7 => (43, 7),
+            Variation::Group43Var8 => (43, 8),
             Variation::Group50Var1 => (50, 1),
+            Variation::Group50Var2 => (50, 2),
             Variation::Group50Var3 => (50, 3),
             Variation::Group50Var4 => (50, 4),
             Variation::Group51Var1 => (51, 1),
@@ -463,14 +756,46 @@ impl Variation {
             Variation::Group60Var2 => (60, 2),
             Variation::Group60Var3 => (60, 3),
             Variation::Group60Var4 => (60, 4),
+            Variation::Group70Var1 => (70, 1),
+            Variation::Group70Var2 => (70, 2),
+            Variation::Group70Var3 => (70, 3),
+            Variation::Group70Var4 => (70, 4),
+            Variation::Group70Var5 => (70, 5),
+            Variation::Group70Var6 => (70, 6),
+            Variation::Group70Var7 => (70, 7),
+            Variation::Group70Var8 => (70, 8),
             Variation::Group80Var1 => (80, 1),
+            Variation::Group85Var1 => (85, 1),
+            Variation::Group86Var1 => (86, 1),
+            Variation::Group87Var1 => (87, 1),
+            Variation::Group88Var1 => (88, 1),
+            Variation::Group90Var1 => (90, 1),
+            Variation::Group91Var1 => (91, 1),
+            Variation::Group101Var0 => (101, 0),
+            Variation::Group101Var1 => (101, 1),
+            Variation::Group101Var2 => (101, 2),
+            Variation::Group101Var3 => (101, 3),
+            Variation::Group102Var0 => (102, 0),
+            Variation::Group102Var1 => (102, 1),
             Variation::Group110(x) => (110, x),
             Variation::Group111(x) => (111, x),
+            Variation::Group112(x) => (112, x),
+            Variation::Group113(x) => (113, x),
+            Variation::Group120Var1 => (120, 1),
+            Variation::Group120Var2 => (120, 2),
+            Variation::Group120Var3 => (120, 3),
+            Variation::Group120Var4 => (120, 4),
+            Variation::Group120Var5 => (120, 5),
+            Variation::Group120Var6 => (120, 6),
+            Variation::Group120Var7 => (120, 7),
+            Variation::Group120Var9 => (120, 9),
         }
     }
     
     pub(crate) fn description(self) -> &'static str {
         match self {
+            Variation::Group0Var254 => "Device Attributes - Non-specific all attributes request",
+            Variation::Group0(_) => "Device Attributes - Specific attribute",
             Variation::Group1Var0 => "Binary Input - Any Variation",
             Variation::Group1Var1 => "Binary Input - Packed Format",
             Variation::Group1Var2 => "Binary Input - With Flags",
@@ -493,6 +818,11 @@ impl Variation {
             Variation::Group11Var2 => "Binary Output Event - Output Status With Time",
             Variation::Group12Var0 => "Binary Command - Any Variation",
             Variation::Group12Var1 => "Binary Command - Control Relay Output Block",
+            Variation::Group12Var2 => "Binary Command - Pattern Control Block",
+            Variation::Group12Var3 => "Binary Command - Pattern Mask",
+            Variation::Group13Var0 => "Binary Output Command Event - Any Variation",
+            Variation::Group13Var1 => "Binary Output Command Event - Without Time",
+            Variation::Group13Var2 => "Binary Output Command Event - With Time",
             Variation::Group20Var0 => "Counter - Any Variation",
             Variation::Group20Var1 => "Counter - 32-bit With Flag",
             Variation::Group20Var2 => "Counter - 16-bit With Flag",
@@ -522,6 +852,15 @@ impl Variation {
             Variation::Group30Var4 => "Analog Input - 16-bit Without Flag",
             Variation::Group30Var5 => "Analog Input - Single-precision With Flag",
             Variation::Group30Var6 => "Analog Input - Double-precision With Flag",
+            Variation::Group31Var0 => "Frozen Analog Input - Any Variation",
+            Variation::Group31Var1 => "Frozen Analog Input - 32-bit With Flag",
+            Variation::Group31Var2 => "Frozen Analog Input - 16-bit With Flag",
+            Variation::Group31Var3 => "Frozen Analog Input - 32-bit With Flag and Time-of-Freeze",
+            Variation::Group31Var4 => "Frozen Analog Input - 16-bit With Flag and Time-of-Freeze",
+            Variation::Group31Var5 => "Frozen Analog Input - 32-bit Without Flag",
+            Variation::Group31Var6 => "Frozen Analog Input - 16-bit Without Flag",
+            Variation::Group31Var7 => "Frozen Analog Input - Single-precision With Flag",
+            Variation::Group31Var8 => "Frozen Analog Input - Double-precision With Flag",
             Variation::Group32Var0 => "Analog Input Event - Any Variation",
             Variation::Group32Var1 => "Analog Input Event - 32-bit With Flag",
             Variation::Group32Var2 => "Analog Input Event - 16-bit With Flag",
@@ -531,6 +870,19 @@ impl Variation {
             Variation::Group32Var6 => "Analog Input Event - Double-precision With Flag",
             Variation::Group32Var7 => "Analog Input Event - Single-precision With Flag and Time",
             Variation::Group32Var8 => "Analog Input Event - Double-precision With Flag and Time",
+            Variation::Group33Var0 => "Frozen Analog Input Event - Any Variation",
+            Variation::Group33Var1 => "Frozen Analog Input Event - 32-bit With Flag",
+            Variation::Group33Var2 => "Frozen Analog Input Event - 16-bit With Flag",
+            Variation::Group33Var3 => "Frozen Analog Input Event - 32-bit With Flag and Time",
+            Variation::Group33Var4 => "Frozen Analog Input Event - 16-bit With Flag and Time",
+            Variation::Group33Var5 => "Frozen Analog Input Event - Single-precision With Flag",
+            Variation::Group33Var6 => "Frozen Analog Input Event - Double-precision With Flag",
+            Variation::Group33Var7 => "Frozen Analog Input Event - Single-precision With Flag and Time",
+            Variation::Group33Var8 => "Frozen Analog Input Event - Double-precision With Flag and Time",
+            Variation::Group34Var0 => "Analog Input Deadband - Any Variation",
+            Variation::Group34Var1 => "Analog Input Deadband - 16-bit",
+            Variation::Group34Var2 => "Analog Input Deadband - 32-bit",
+            Variation::Group34Var3 => "Analog Input Deadband - Single-precision",
             Variation::Group40Var0 => "Analog Output Status - Any Variation",
             Variation::Group40Var1 => "Analog Output Status - 32-bit With Flag",
             Variation::Group40Var2 => "Analog Output Status - 16-bit With Flag",
@@ -550,7 +902,17 @@ impl Variation {
             Variation::Group42Var6 => "Analog Output Event - Double-precision With Flag",
             Variation::Group42Var7 => "Analog Output Event - Single-precision With Flag and Time",
             Variation::Group42Var8 => "Analog Output Event - Double-precision With Flag and Time",
+            Variation::Group43Var0 => "Analog Output Command Event - Any Variation",
+            Variation::Group43Var1 => "Analog Output Command Event - 32-bit",
+            Variation::Group43Var2 => "Analog Output Command Event - 16-bit",
+            Variation::Group43Var3 => "Analog Output Command Event - 32-bit With Time",
+            Variation::Group43Var4 => "Analog Output Command Event - 16-bit With Time",
+            Variation::Group43Var5 => "Analog Output Command Event - Single-precision",
+            Variation::Group43Var6 => "Analog Output Command Event - Double-precision",
+            Variation::Group43Var7 => "Analog Output Command Event - Single-precision With Time",
+            Variation::Group43Var8 => "Analog Output Command Event - Double-precision With Time",
             Variation::Group50Var1 => "Time and Date - Absolute Time",
+            Variation::Group50Var2 => "Time and Date - Absolute time and interval",
             Variation::Group50Var3 => "Time and Date - Absolute Time at last recorded time",
             Variation::Group50Var4 => "Time and Date - Indexed absolute time and long interval",
             Variation::Group51Var1 => "Time and Date CTO - Absolute time, synchronized",
@@ -561,13 +923,87 @@ impl Variation {
             Variation::Group60Var2 => "Class Data - Class 1",
             Variation::Group60Var3 => "Class Data - Class 2",
             Variation::Group60Var4 => "Class Data - Class 3",
+            Variation::Group70Var1 => "File-control - File identifier",
+            Variation::Group70Var2 => "File-control - Authentication",
+            Variation::Group70Var3 => "File-control - File command",
+            Variation::Group70Var4 => "File-control - File command status",
+            Variation::Group70Var5 => "File-control - File transport",
+            Variation::Group70Var6 => "File-control - File transport status",
+            Variation::Group70Var7 => "File-control - File descriptor",
+            Variation::Group70Var8 => "File-control - File specification string",
             Variation::Group80Var1 => "Internal Indications - Packed Format",
+            Variation::Group85Var1 => "Data Set Prototype - With UUID",
+            Variation::Group86Var1 => "Data Set Descriptor - Data Set Contents",
+            Variation::Group87Var1 => "Data Set - Present Value",
+            Variation::Group88Var1 => "Data Set Event - Snapshot",
+            Variation::Group90Var1 => "Application Identifier - Identifier",
+            Variation::Group91Var1 => "Status of Requested Operation - Activate Configuration",
+            Variation::Group101Var0 => "BCD Integer - Any Variation",
+            Variation::Group101Var1 => "BCD Integer - Small (4 digits)",
+            Variation::Group101Var2 => "BCD Integer - Medium (8 digits)",
+            Variation::Group101Var3 => "BCD Integer - Large (16 digits)",
+            Variation::Group102Var0 => "Unsigned Integer - Any Variation",
+            Variation::Group102Var1 => "Unsigned Integer - 8-bit",
             Variation::Group110(_) => "Octet String - Sized by variation",
             Variation::Group111(_) => "Octet String Event - Sized by variation",
+            Variation::Group112(_) => "Virtual Terminal Output Block - Sized by variation",
+            Variation::Group113(_) => "Virtual Terminal Event Data - Sized by variation",
+            Variation::Group120Var1 => "Authentication - Challenge",
+            Variation::Group120Var2 => "Authentication - Reply",
+            Variation::Group120Var3 => "Authentication - Aggressive Mode Request",
+            Variation::Group120Var4 => "Authentication - Session Key Status Request",
+            Variation::Group120Var5 => "Authentication - Session Key Status",
+            Variation::Group120Var6 => "Authentication - Session Key Change",
+            Variation::Group120Var7 => "Authentication - Error",
+            Variation::Group120Var9 => "Authentication - HMAC",
         }
     }
 }
 
+/// Authentication - Session Key Status Request
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var4 {
+    /// user field of the variation
+    pub(crate) user: u16,
+}
+
+/// Authentication - Aggressive Mode Request
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var3 {
+    /// challenge_seq field of the variation
+    pub(crate) challenge_seq: u32,
+    /// user field of the variation
+    pub(crate) user: u16,
+}
+
+/// Unsigned Integer - 8-bit
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group102Var1 {
+    /// value field of the variation
+    pub(crate) value: u8,
+}
+
+/// BCD Integer - Large (16 digits)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group101Var3 {
+    /// value field of the variation
+    pub(crate) value: u64,
+}
+
+/// BCD Integer - Medium (8 digits)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group101Var2 {
+    /// value field of the variation
+    pub(crate) value: u32,
+}
+
+/// BCD Integer - Small (4 digits)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group101Var1 {
+    /// value field of the variation
+    pub(crate) value: u16,
+}
+
 /// Time Delay - Fine
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub(crate) struct Group52Var2 {
@@ -604,7 +1040,7 @@ pub(crate) struct Group50Var4 {
     /// interval field of the variation
     pub(crate) interval: u32,
     /// units field of the variation
-    pub(crate) units: u8,
+    pub(crate) units: IntervalUnit,
 }
 
 /// Time and Date - Absolute Time at last recorded time
@@ -614,6 +1050,15 @@ pub(crate) struct Group50Var3 {
     pub(crate) time: Timestamp,
 }
 
+/// Time and Date - Absolute time and interval
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group50Var2 {
+    /// time field of the variation
+    pub(crate) time: Timestamp,
+    /// interval field of the variation
+    pub(crate) interval: u32,
+}
+
 /// Time and Date - Absolute Time
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub(crate) struct Group50Var1 {
@@ -621,6 +1066,86 @@ pub(crate) struct Group50Var1 {
     pub(crate) time: Timestamp,
 }
 
+/// Analog Output Command Event - Double-precision With Time
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct Group43Var8 {
+    /// status field of the variation
+    pub(crate) status: CommandStatus,
+    /// value field of the variation
+    pub(crate) value: f64,
+    /// time field of the variation
+    pub(crate) time: Timestamp,
+}
+
+/// Analog Output Command Event - Single-precision With Time
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct Group43Var7 {
+    /// status field of the variation
+    pub(crate) status: CommandStatus,
+    /// value field of the variation
+    pub(crate) value: f32,
+    /// time field of the variation
+    pub(crate) time: Timestamp,
+}
+
+/// Analog Output Command Event - Double-precision
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct Group43Var6 {
+    /// status field of the variation
+    pub(crate) status: CommandStatus,
+    /// value field of the variation
+    pub(crate) value: f64,
+}
+
+/// Analog Output Command Event - Single-precision
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct Group43Var5 {
+    /// status field of the variation
+    pub(crate) status: CommandStatus,
+    /// value field of the variation
+    pub(crate) value: f32,
+}
+
+/// Analog Output Command Event - 16-bit With Time
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group43Var4 {
+    /// status field of the variation
+    pub(crate) status: CommandStatus,
+    /// value field of the variation
+    pub(crate) value: i16,
+    /// time field of the variation
+    pub(crate) time: Timestamp,
+}
+
+/// Analog Output Command Event - 32-bit With Time
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group43Var3 {
+    /// status field of the variation
+    pub(crate) status: CommandStatus,
+    /// value field of the variation
+    pub(crate) value: i32,
+    /// time field of the variation
+    pub(crate) time: Timestamp,
+}
+
+/// Analog Output Command Event - 16-bit
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group43Var2 {
+    /// status field of the variation
+    pub(crate) status: CommandStatus,
+    /// value field of the variation
+    pub(crate) value: i16,
+}
+
+/// Analog Output Command Event - 32-bit
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group43Var1 {
+    /// status field of the variation
+    pub(crate) status: CommandStatus,
+    /// value field of the variation
+    pub(crate) value: i32,
+}
+
 /// Analog Output Event - Double-precision With Flag and Time
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub(crate) struct Group42Var8 {
@@ -773,6 +1298,107 @@ pub(crate) struct Group40Var1 {
     pub(crate) value: i32,
 }
 
+/// Analog Input Deadband - Single-precision
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct Group34Var3 {
+    /// value field of the variation
+    pub(crate) value: f32,
+}
+
+/// Analog Input Deadband - 32-bit
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group34Var2 {
+    /// value field of the variation
+    pub(crate) value: u32,
+}
+
+/// Analog Input Deadband - 16-bit
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group34Var1 {
+    /// value field of the variation
+    pub(crate) value: u16,
+}
+
+/// Frozen Analog Input Event - Double-precision With Flag and Time
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct Group33Var8 {
+    /// flags field of the variation
+    pub(crate) flags: u8,
+    /// value field of the variation
+    pub(crate) value: f64,
+    /// time field of the variation
+    pub(crate) time: Timestamp,
+}
+
+/// Frozen Analog Input Event - Single-precision With Flag and Time
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct Group33Var7 {
+    /// flags field of the variation
+    pub(crate) flags: u8,
+    /// value field of the variation
+    pub(crate) value: f32,
+    /// time field of the variation
+    pub(crate) time: Timestamp,
+}
+
+/// Frozen Analog Input Event - Double-precision With Flag
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct Group33Var6 {
+    /// flags field of the variation
+    pub(crate) flags: u8,
+    /// value field of the variation
+    pub(crate) value: f64,
+}
+
+/// Frozen Analog Input Event - Single-precision With Flag
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct Group33Var5 {
+    /// flags field of the variation
+    pub(crate) flags: u8,
+    /// value field of the variation
+    pub(crate) value: f32,
+}
+
+/// Frozen Analog Input Event - 16-bit With Flag and Time
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group33Var4 {
+    /// flags field of the variation
+    pub(crate) flags: u8,
+    /// value field of the variation
+    pub(crate) value: i16,
+    /// time field of the variation
+    pub(crate) time: Timestamp,
+}
+
+/// Frozen Analog Input Event - 32-bit With Flag and Time
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group33Var3 {
+    /// flags field of the variation
+    pub(crate) flags: u8,
+    /// value field of the variation
+    pub(crate) value: i32,
+    /// time field of the variation
+    pub(crate) time: Timestamp,
+}
+
+/// Frozen Analog Input Event - 16-bit With Flag
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group33Var2 {
+    /// flags field of the variation
+    pub(crate) flags: u8,
+    /// value field of the variation
+    pub(crate) value: i16,
+}
+
+/// Frozen Analog Input Event - 32-bit With Flag
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group33Var1 {
+    /// flags field of the variation
+    pub(crate) flags: u8,
+    /// value field of the variation
+    pub(crate) value: i32,
+}
+
 /// Analog Input Event - Double-precision With Flag and Time
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub(crate) struct Group32Var8 {
@@ -853,6 +1479,78 @@ pub(crate) struct Group32Var1 {
     pub(crate) value: i32,
 }
 
+/// Frozen Analog Input - Double-precision With Flag
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct Group31Var8 {
+    /// flags field of the variation
+    pub(crate) flags: u8,
+    /// value field of the variation
+    pub(crate) value: f64,
+}
+
+/// Frozen Analog Input - Single-precision With Flag
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct Group31Var7 {
+    /// flags field of the variation
+    pub(crate) flags: u8,
+    /// value field of the variation
+    pub(crate) value: f32,
+}
+
+/// Frozen Analog Input - 16-bit Without Flag
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group31Var6 {
+    /// value field of the variation
+    pub(crate) value: i16,
+}
+
+/// Frozen Analog Input - 32-bit Without Flag
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group31Var5 {
+    /// value field of the variation
+    pub(crate) value: i32,
+}
+
+/// Frozen Analog Input - 16-bit With Flag and Time-of-Freeze
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group31Var4 {
+    /// flags field of the variation
+    pub(crate) flags: u8,
+    /// value field of the variation
+    pub(crate) value: i16,
+    /// time field of the variation
+    pub(crate) time: Timestamp,
+}
+
+/// Frozen Analog Input - 32-bit With Flag and Time-of-Freeze
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group31Var3 {
+    /// flags field of the variation
+    pub(crate) flags: u8,
+    /// value field of the variation
+    pub(crate) value: i32,
+    /// time field of the variation
+    pub(crate) time: Timestamp,
+}
+
+/// Frozen Analog Input - 16-bit With Flag
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group31Var2 {
+    /// flags field of the variation
+    pub(crate) flags: u8,
+    /// value field of the variation
+    pub(crate) value: i16,
+}
+
+/// Frozen Analog Input - 32-bit With Flag
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group31Var1 {
+    /// flags field of the variation
+    pub(crate) flags: u8,
+    /// value field of the variation
+    pub(crate) value: i32,
+}
+
 /// Analog Input - Double-precision With Flag
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub(crate) struct Group30Var6 {
@@ -1069,10 +1767,26 @@ pub(crate) struct Group20Var1 {
     pub(crate) value: u32,
 }
 
-/// Binary Command - Control Relay Output Block
+/// Binary Output Command Event - With Time
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub struct Group12Var1 {
-    /// code field of the variation
+pub(crate) struct Group13Var2 {
+    /// flags field of the variation
+    pub(crate) flags: u8,
+    /// time field of the variation
+    pub(crate) time: Timestamp,
+}
+
+/// Binary Output Command Event - Without Time
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group13Var1 {
+    /// flags field of the variation
+    pub(crate) flags: u8,
+}
+
+/// Binary Command - Control Relay Output Block
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Group12Var1 {
+    /// code field of the variation
     pub code: ControlCode,
     /// count field of the variation
     pub count: u8,
@@ -1084,6 +1798,21 @@ pub struct Group12Var1 {
     pub status: CommandStatus,
 }
 
+/// Binary Command - Pattern Control Block
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group12Var2 {
+    /// code field of the variation
+    pub(crate) code: ControlCode,
+    /// count field of the variation
+    pub(crate) count: u8,
+    /// on_time field of the variation
+    pub(crate) on_time: u32,
+    /// off_time field of the variation
+    pub(crate) off_time: u32,
+    /// status field of the variation
+    pub(crate) status: CommandStatus,
+}
+
 /// Binary Output Event - Output Status With Time
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub(crate) struct Group11Var2 {
@@ -1171,121 +1900,700 @@ pub(crate) struct Group1Var2 {
     pub(crate) flags: u8,
 }
 
-
-impl FixedSize for Group52Var2 {
-    const SIZE: u8 = 2;
+
+impl FixedSize for Group120Var4 {
+    const SIZE: u8 = 2;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group120Var4 {
+                user: cursor.read_u16_le()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u16_le(self.user)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group120Var3 {
+    const SIZE: u8 = 6;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group120Var3 {
+                challenge_seq: cursor.read_u32_le()?,
+                user: cursor.read_u16_le()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u32_le(self.challenge_seq)?;
+        cursor.write_u16_le(self.user)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group102Var1 {
+    const SIZE: u8 = 1;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group102Var1 {
+                value: cursor.read_u8()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.value)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group101Var3 {
+    const SIZE: u8 = 8;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group101Var3 {
+                value: cursor.read_u64_le()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u32_le(self.value as u32)?;
+        cursor.write_u32_le((self.value >> 32) as u32)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group101Var2 {
+    const SIZE: u8 = 4;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group101Var2 {
+                value: cursor.read_u32_le()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u32_le(self.value)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group101Var1 {
+    const SIZE: u8 = 2;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group101Var1 {
+                value: cursor.read_u16_le()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u16_le(self.value)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group52Var2 {
+    const SIZE: u8 = 2;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group52Var2 {
+                time: cursor.read_u16_le()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u16_le(self.time)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group52Var1 {
+    const SIZE: u8 = 2;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group52Var1 {
+                time: cursor.read_u16_le()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u16_le(self.time)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group51Var2 {
+    const SIZE: u8 = 6;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group51Var2 {
+                time: Timestamp::new(cursor.read_u48_le()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.time.write(cursor)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group51Var1 {
+    const SIZE: u8 = 6;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group51Var1 {
+                time: Timestamp::new(cursor.read_u48_le()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.time.write(cursor)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group50Var4 {
+    const SIZE: u8 = 11;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group50Var4 {
+                time: Timestamp::new(cursor.read_u48_le()?),
+                interval: cursor.read_u32_le()?,
+                units: IntervalUnit::from(cursor.read_u8()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.time.write(cursor)?;
+        cursor.write_u32_le(self.interval)?;
+        self.units.write(cursor)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group50Var2 {
+    const SIZE: u8 = 10;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group50Var2 {
+                time: Timestamp::new(cursor.read_u48_le()?),
+                interval: cursor.read_u32_le()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.time.write(cursor)?;
+        cursor.write_u32_le(self.interval)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group50Var3 {
+    const SIZE: u8 = 6;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group50Var3 {
+                time: Timestamp::new(cursor.read_u48_le()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.time.write(cursor)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group50Var1 {
+    const SIZE: u8 = 6;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group50Var1 {
+                time: Timestamp::new(cursor.read_u48_le()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.time.write(cursor)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group43Var8 {
+    const SIZE: u8 = 15;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group43Var8 {
+                status: CommandStatus::from(cursor.read_u8()?),
+                value: cursor.read_f64_le()?,
+                time: Timestamp::new(cursor.read_u48_le()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.status.write(cursor)?;
+        cursor.write_f64_le(self.value)?;
+        self.time.write(cursor)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group43Var7 {
+    const SIZE: u8 = 11;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group43Var7 {
+                status: CommandStatus::from(cursor.read_u8()?),
+                value: cursor.read_f32_le()?,
+                time: Timestamp::new(cursor.read_u48_le()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.status.write(cursor)?;
+        cursor.write_f32_le(self.value)?;
+        self.time.write(cursor)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group43Var6 {
+    const SIZE: u8 = 9;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group43Var6 {
+                status: CommandStatus::from(cursor.read_u8()?),
+                value: cursor.read_f64_le()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.status.write(cursor)?;
+        cursor.write_f64_le(self.value)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group43Var5 {
+    const SIZE: u8 = 5;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group43Var5 {
+                status: CommandStatus::from(cursor.read_u8()?),
+                value: cursor.read_f32_le()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.status.write(cursor)?;
+        cursor.write_f32_le(self.value)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group43Var4 {
+    const SIZE: u8 = 9;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group43Var4 {
+                status: CommandStatus::from(cursor.read_u8()?),
+                value: cursor.read_i16_le()?,
+                time: Timestamp::new(cursor.read_u48_le()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.status.write(cursor)?;
+        cursor.write_i16_le(self.value)?;
+        self.time.write(cursor)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group43Var3 {
+    const SIZE: u8 = 11;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group43Var3 {
+                status: CommandStatus::from(cursor.read_u8()?),
+                value: cursor.read_i32_le()?,
+                time: Timestamp::new(cursor.read_u48_le()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.status.write(cursor)?;
+        cursor.write_i32_le(self.value)?;
+        self.time.write(cursor)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group43Var2 {
+    const SIZE: u8 = 3;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group43Var2 {
+                status: CommandStatus::from(cursor.read_u8()?),
+                value: cursor.read_i16_le()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.status.write(cursor)?;
+        cursor.write_i16_le(self.value)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group43Var1 {
+    const SIZE: u8 = 5;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group43Var1 {
+                status: CommandStatus::from(cursor.read_u8()?),
+                value: cursor.read_i32_le()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.status.write(cursor)?;
+        cursor.write_i32_le(self.value)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group42Var8 {
+    const SIZE: u8 = 15;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group42Var8 {
+                flags: cursor.read_u8()?,
+                value: cursor.read_f64_le()?,
+                time: Timestamp::new(cursor.read_u48_le()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.flags)?;
+        cursor.write_f64_le(self.value)?;
+        self.time.write(cursor)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group42Var7 {
+    const SIZE: u8 = 11;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group42Var7 {
+                flags: cursor.read_u8()?,
+                value: cursor.read_f32_le()?,
+                time: Timestamp::new(cursor.read_u48_le()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.flags)?;
+        cursor.write_f32_le(self.value)?;
+        self.time.write(cursor)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group42Var6 {
+    const SIZE: u8 = 9;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group42Var6 {
+                flags: cursor.read_u8()?,
+                value: cursor.read_f64_le()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.flags)?;
+        cursor.write_f64_le(self.value)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group42Var5 {
+    const SIZE: u8 = 5;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group42Var5 {
+                flags: cursor.read_u8()?,
+                value: cursor.read_f32_le()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.flags)?;
+        cursor.write_f32_le(self.value)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group42Var4 {
+    const SIZE: u8 = 9;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group42Var4 {
+                flags: cursor.read_u8()?,
+                value: cursor.read_i16_le()?,
+                time: Timestamp::new(cursor.read_u48_le()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.flags)?;
+        cursor.write_i16_le(self.value)?;
+        self.time.write(cursor)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group42Var3 {
+    const SIZE: u8 = 11;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group42Var3 {
+                flags: cursor.read_u8()?,
+                value: cursor.read_i32_le()?,
+                time: Timestamp::new(cursor.read_u48_le()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.flags)?;
+        cursor.write_i32_le(self.value)?;
+        self.time.write(cursor)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group42Var2 {
+    const SIZE: u8 = 3;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group42Var2 {
+                flags: cursor.read_u8()?,
+                value: cursor.read_i16_le()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.flags)?;
+        cursor.write_i16_le(self.value)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group42Var1 {
+    const SIZE: u8 = 5;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group42Var1 {
+                flags: cursor.read_u8()?,
+                value: cursor.read_i32_le()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.flags)?;
+        cursor.write_i32_le(self.value)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group41Var4 {
+    const SIZE: u8 = 9;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group41Var4 {
+                value: cursor.read_f64_le()?,
+                status: CommandStatus::from(cursor.read_u8()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_f64_le(self.value)?;
+        self.status.write(cursor)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group41Var3 {
+    const SIZE: u8 = 5;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group41Var3 {
+                value: cursor.read_f32_le()?,
+                status: CommandStatus::from(cursor.read_u8()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_f32_le(self.value)?;
+        self.status.write(cursor)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group41Var2 {
+    const SIZE: u8 = 3;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group41Var2 {
+                value: cursor.read_i16_le()?,
+                status: CommandStatus::from(cursor.read_u8()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_i16_le(self.value)?;
+        self.status.write(cursor)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group41Var1 {
+    const SIZE: u8 = 5;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group41Var1 {
+                value: cursor.read_i32_le()?,
+                status: CommandStatus::from(cursor.read_u8()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_i32_le(self.value)?;
+        self.status.write(cursor)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group40Var4 {
+    const SIZE: u8 = 9;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group52Var2 {
-                time: cursor.read_u16_le()?,
+            Group40Var4 {
+                flags: cursor.read_u8()?,
+                value: cursor.read_f64_le()?,
             }
         )
     }
     fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
-        cursor.write_u16_le(self.time)?;
+        cursor.write_u8(self.flags)?;
+        cursor.write_f64_le(self.value)?;
         Ok(())
     }
 }
 
-impl FixedSize for Group52Var1 {
-    const SIZE: u8 = 2;
+impl FixedSize for Group40Var3 {
+    const SIZE: u8 = 5;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group52Var1 {
-                time: cursor.read_u16_le()?,
+            Group40Var3 {
+                flags: cursor.read_u8()?,
+                value: cursor.read_f32_le()?,
             }
         )
     }
     fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
-        cursor.write_u16_le(self.time)?;
+        cursor.write_u8(self.flags)?;
+        cursor.write_f32_le(self.value)?;
         Ok(())
     }
 }
 
-impl FixedSize for Group51Var2 {
-    const SIZE: u8 = 6;
+impl FixedSize for Group40Var2 {
+    const SIZE: u8 = 3;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group51Var2 {
-                time: Timestamp::new(cursor.read_u48_le()?),
+            Group40Var2 {
+                flags: cursor.read_u8()?,
+                value: cursor.read_i16_le()?,
             }
         )
     }
     fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
-        self.time.write(cursor)?;
+        cursor.write_u8(self.flags)?;
+        cursor.write_i16_le(self.value)?;
         Ok(())
     }
 }
 
-impl FixedSize for Group51Var1 {
-    const SIZE: u8 = 6;
+impl FixedSize for Group40Var1 {
+    const SIZE: u8 = 5;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group51Var1 {
-                time: Timestamp::new(cursor.read_u48_le()?),
+            Group40Var1 {
+                flags: cursor.read_u8()?,
+                value: cursor.read_i32_le()?,
             }
         )
     }
     fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
-        self.time.write(cursor)?;
+        cursor.write_u8(self.flags)?;
+        cursor.write_i32_le(self.value)?;
         Ok(())
     }
 }
 
-impl FixedSize for Group50Var4 {
-    const SIZE: u8 = 11;
+impl FixedSize for Group34Var3 {
+    const SIZE: u8 = 4;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group50Var4 {
-                time: Timestamp::new(cursor.read_u48_le()?),
-                interval: cursor.read_u32_le()?,
-                units: cursor.read_u8()?,
+            Group34Var3 {
+                value: cursor.read_f32_le()?,
             }
         )
     }
     fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
-        self.time.write(cursor)?;
-        cursor.write_u32_le(self.interval)?;
-        cursor.write_u8(self.units)?;
+        cursor.write_f32_le(self.value)?;
         Ok(())
     }
 }
 
-impl FixedSize for Group50Var3 {
-    const SIZE: u8 = 6;
+impl FixedSize for Group34Var2 {
+    const SIZE: u8 = 4;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group50Var3 {
-                time: Timestamp::new(cursor.read_u48_le()?),
+            Group34Var2 {
+                value: cursor.read_u32_le()?,
             }
         )
     }
     fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
-        self.time.write(cursor)?;
+        cursor.write_u32_le(self.value)?;
         Ok(())
     }
 }
 
-impl FixedSize for Group50Var1 {
-    const SIZE: u8 = 6;
+impl FixedSize for Group34Var1 {
+    const SIZE: u8 = 2;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group50Var1 {
-                time: Timestamp::new(cursor.read_u48_le()?),
+            Group34Var1 {
+                value: cursor.read_u16_le()?,
             }
         )
     }
     fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
-        self.time.write(cursor)?;
+        cursor.write_u16_le(self.value)?;
         Ok(())
     }
 }
 
-impl FixedSize for Group42Var8 {
+impl FixedSize for Group33Var8 {
     const SIZE: u8 = 15;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group42Var8 {
+            Group33Var8 {
                 flags: cursor.read_u8()?,
                 value: cursor.read_f64_le()?,
                 time: Timestamp::new(cursor.read_u48_le()?),
@@ -1300,11 +2608,11 @@ impl FixedSize for Group42Var8 {
     }
 }
 
-impl FixedSize for Group42Var7 {
+impl FixedSize for Group33Var7 {
     const SIZE: u8 = 11;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group42Var7 {
+            Group33Var7 {
                 flags: cursor.read_u8()?,
                 value: cursor.read_f32_le()?,
                 time: Timestamp::new(cursor.read_u48_le()?),
@@ -1319,11 +2627,11 @@ impl FixedSize for Group42Var7 {
     }
 }
 
-impl FixedSize for Group42Var6 {
+impl FixedSize for Group33Var6 {
     const SIZE: u8 = 9;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group42Var6 {
+            Group33Var6 {
                 flags: cursor.read_u8()?,
                 value: cursor.read_f64_le()?,
             }
@@ -1336,11 +2644,11 @@ impl FixedSize for Group42Var6 {
     }
 }
 
-impl FixedSize for Group42Var5 {
+impl FixedSize for Group33Var5 {
     const SIZE: u8 = 5;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group42Var5 {
+            Group33Var5 {
                 flags: cursor.read_u8()?,
                 value: cursor.read_f32_le()?,
             }
@@ -1353,11 +2661,11 @@ impl FixedSize for Group42Var5 {
     }
 }
 
-impl FixedSize for Group42Var4 {
+impl FixedSize for Group33Var4 {
     const SIZE: u8 = 9;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group42Var4 {
+            Group33Var4 {
                 flags: cursor.read_u8()?,
                 value: cursor.read_i16_le()?,
                 time: Timestamp::new(cursor.read_u48_le()?),
@@ -1372,11 +2680,11 @@ impl FixedSize for Group42Var4 {
     }
 }
 
-impl FixedSize for Group42Var3 {
+impl FixedSize for Group33Var3 {
     const SIZE: u8 = 11;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group42Var3 {
+            Group33Var3 {
                 flags: cursor.read_u8()?,
                 value: cursor.read_i32_le()?,
                 time: Timestamp::new(cursor.read_u48_le()?),
@@ -1391,11 +2699,11 @@ impl FixedSize for Group42Var3 {
     }
 }
 
-impl FixedSize for Group42Var2 {
+impl FixedSize for Group33Var2 {
     const SIZE: u8 = 3;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group42Var2 {
+            Group33Var2 {
                 flags: cursor.read_u8()?,
                 value: cursor.read_i16_le()?,
             }
@@ -1408,11 +2716,11 @@ impl FixedSize for Group42Var2 {
     }
 }
 
-impl FixedSize for Group42Var1 {
+impl FixedSize for Group33Var1 {
     const SIZE: u8 = 5;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group42Var1 {
+            Group33Var1 {
                 flags: cursor.read_u8()?,
                 value: cursor.read_i32_le()?,
             }
@@ -1425,113 +2733,121 @@ impl FixedSize for Group42Var1 {
     }
 }
 
-impl FixedSize for Group41Var4 {
-    const SIZE: u8 = 9;
+impl FixedSize for Group32Var8 {
+    const SIZE: u8 = 15;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group41Var4 {
+            Group32Var8 {
+                flags: cursor.read_u8()?,
                 value: cursor.read_f64_le()?,
-                status: CommandStatus::from(cursor.read_u8()?),
+                time: Timestamp::new(cursor.read_u48_le()?),
             }
         )
     }
     fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.flags)?;
         cursor.write_f64_le(self.value)?;
-        self.status.write(cursor)?;
+        self.time.write(cursor)?;
         Ok(())
     }
 }
 
-impl FixedSize for Group41Var3 {
-    const SIZE: u8 = 5;
+impl FixedSize for Group32Var7 {
+    const SIZE: u8 = 11;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group41Var3 {
+            Group32Var7 {
+                flags: cursor.read_u8()?,
                 value: cursor.read_f32_le()?,
-                status: CommandStatus::from(cursor.read_u8()?),
+                time: Timestamp::new(cursor.read_u48_le()?),
             }
         )
     }
     fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.flags)?;
         cursor.write_f32_le(self.value)?;
-        self.status.write(cursor)?;
+        self.time.write(cursor)?;
         Ok(())
     }
 }
 
-impl FixedSize for Group41Var2 {
-    const SIZE: u8 = 3;
+impl FixedSize for Group32Var6 {
+    const SIZE: u8 = 9;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group41Var2 {
-                value: cursor.read_i16_le()?,
-                status: CommandStatus::from(cursor.read_u8()?),
+            Group32Var6 {
+                flags: cursor.read_u8()?,
+                value: cursor.read_f64_le()?,
             }
         )
     }
     fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
-        cursor.write_i16_le(self.value)?;
-        self.status.write(cursor)?;
+        cursor.write_u8(self.flags)?;
+        cursor.write_f64_le(self.value)?;
         Ok(())
     }
 }
 
-impl FixedSize for Group41Var1 {
+impl FixedSize for Group32Var5 {
     const SIZE: u8 = 5;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group41Var1 {
-                value: cursor.read_i32_le()?,
-                status: CommandStatus::from(cursor.read_u8()?),
+            Group32Var5 {
+                flags: cursor.read_u8()?,
+                value: cursor.read_f32_le()?,
             }
         )
     }
     fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
-        cursor.write_i32_le(self.value)?;
-        self.status.write(cursor)?;
+        cursor.write_u8(self.flags)?;
+        cursor.write_f32_le(self.value)?;
         Ok(())
     }
 }
 
-impl FixedSize for Group40Var4 {
+impl FixedSize for Group32Var4 {
     const SIZE: u8 = 9;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group40Var4 {
+            Group32Var4 {
                 flags: cursor.read_u8()?,
-                value: cursor.read_f64_le()?,
+                value: cursor.read_i16_le()?,
+                time: Timestamp::new(cursor.read_u48_le()?),
             }
         )
     }
     fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
         cursor.write_u8(self.flags)?;
-        cursor.write_f64_le(self.value)?;
+        cursor.write_i16_le(self.value)?;
+        self.time.write(cursor)?;
         Ok(())
     }
 }
 
-impl FixedSize for Group40Var3 {
-    const SIZE: u8 = 5;
+impl FixedSize for Group32Var3 {
+    const SIZE: u8 = 11;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group40Var3 {
+            Group32Var3 {
                 flags: cursor.read_u8()?,
-                value: cursor.read_f32_le()?,
+                value: cursor.read_i32_le()?,
+                time: Timestamp::new(cursor.read_u48_le()?),
             }
         )
     }
     fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
         cursor.write_u8(self.flags)?;
-        cursor.write_f32_le(self.value)?;
+        cursor.write_i32_le(self.value)?;
+        self.time.write(cursor)?;
         Ok(())
     }
 }
 
-impl FixedSize for Group40Var2 {
+impl FixedSize for Group32Var2 {
     const SIZE: u8 = 3;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group40Var2 {
+            Group32Var2 {
                 flags: cursor.read_u8()?,
                 value: cursor.read_i16_le()?,
             }
@@ -1544,11 +2860,11 @@ impl FixedSize for Group40Var2 {
     }
 }
 
-impl FixedSize for Group40Var1 {
+impl FixedSize for Group32Var1 {
     const SIZE: u8 = 5;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group40Var1 {
+            Group32Var1 {
                 flags: cursor.read_u8()?,
                 value: cursor.read_i32_le()?,
             }
@@ -1561,83 +2877,75 @@ impl FixedSize for Group40Var1 {
     }
 }
 
-impl FixedSize for Group32Var8 {
-    const SIZE: u8 = 15;
+impl FixedSize for Group31Var8 {
+    const SIZE: u8 = 9;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group32Var8 {
+            Group31Var8 {
                 flags: cursor.read_u8()?,
                 value: cursor.read_f64_le()?,
-                time: Timestamp::new(cursor.read_u48_le()?),
             }
         )
     }
     fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
         cursor.write_u8(self.flags)?;
         cursor.write_f64_le(self.value)?;
-        self.time.write(cursor)?;
         Ok(())
     }
 }
 
-impl FixedSize for Group32Var7 {
-    const SIZE: u8 = 11;
+impl FixedSize for Group31Var7 {
+    const SIZE: u8 = 5;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group32Var7 {
+            Group31Var7 {
                 flags: cursor.read_u8()?,
                 value: cursor.read_f32_le()?,
-                time: Timestamp::new(cursor.read_u48_le()?),
             }
         )
     }
     fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
         cursor.write_u8(self.flags)?;
         cursor.write_f32_le(self.value)?;
-        self.time.write(cursor)?;
         Ok(())
     }
 }
 
-impl FixedSize for Group32Var6 {
-    const SIZE: u8 = 9;
+impl FixedSize for Group31Var6 {
+    const SIZE: u8 = 2;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group32Var6 {
-                flags: cursor.read_u8()?,
-                value: cursor.read_f64_le()?,
+            Group31Var6 {
+                value: cursor.read_i16_le()?,
             }
         )
     }
     fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
-        cursor.write_u8(self.flags)?;
-        cursor.write_f64_le(self.value)?;
+        cursor.write_i16_le(self.value)?;
         Ok(())
     }
 }
 
-impl FixedSize for Group32Var5 {
-    const SIZE: u8 = 5;
+impl FixedSize for Group31Var5 {
+    const SIZE: u8 = 4;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group32Var5 {
-                flags: cursor.read_u8()?,
-                value: cursor.read_f32_le()?,
+            Group31Var5 {
+                value: cursor.read_i32_le()?,
             }
         )
     }
     fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
-        cursor.write_u8(self.flags)?;
-        cursor.write_f32_le(self.value)?;
+        cursor.write_i32_le(self.value)?;
         Ok(())
     }
 }
 
-impl FixedSize for Group32Var4 {
+impl FixedSize for Group31Var4 {
     const SIZE: u8 = 9;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group32Var4 {
+            Group31Var4 {
                 flags: cursor.read_u8()?,
                 value: cursor.read_i16_le()?,
                 time: Timestamp::new(cursor.read_u48_le()?),
@@ -1652,11 +2960,11 @@ impl FixedSize for Group32Var4 {
     }
 }
 
-impl FixedSize for Group32Var3 {
+impl FixedSize for Group31Var3 {
     const SIZE: u8 = 11;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group32Var3 {
+            Group31Var3 {
                 flags: cursor.read_u8()?,
                 value: cursor.read_i32_le()?,
                 time: Timestamp::new(cursor.read_u48_le()?),
@@ -1671,11 +2979,11 @@ impl FixedSize for Group32Var3 {
     }
 }
 
-impl FixedSize for Group32Var2 {
+impl FixedSize for Group31Var2 {
     const SIZE: u8 = 3;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group32Var2 {
+            Group31Var2 {
                 flags: cursor.read_u8()?,
                 value: cursor.read_i16_le()?,
             }
@@ -1688,11 +2996,11 @@ impl FixedSize for Group32Var2 {
     }
 }
 
-impl FixedSize for Group32Var1 {
+impl FixedSize for Group31Var1 {
     const SIZE: u8 = 5;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
         Ok(
-            Group32Var1 {
+            Group31Var1 {
                 flags: cursor.read_u8()?,
                 value: cursor.read_i32_le()?,
             }
@@ -2107,8 +3415,63 @@ impl FixedSize for Group20Var1 {
         )
     }
     fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
-        cursor.write_u8(self.flags)?;
-        cursor.write_u32_le(self.value)?;
+        cursor.write_u8(self.flags)?;
+        cursor.write_u32_le(self.value)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group13Var2 {
+    const SIZE: u8 = 7;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group13Var2 {
+                flags: cursor.read_u8()?,
+                time: Timestamp::new(cursor.read_u48_le()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.flags)?;
+        self.time.write(cursor)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group13Var1 {
+    const SIZE: u8 = 1;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group13Var1 {
+                flags: cursor.read_u8()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.flags)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group12Var2 {
+    const SIZE: u8 = 11;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group12Var2 {
+                code: ControlCode::from(cursor.read_u8()?),
+                count: cursor.read_u8()?,
+                on_time: cursor.read_u32_le()?,
+                off_time: cursor.read_u32_le()?,
+                status: CommandStatus::from(cursor.read_u8()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.code.as_u8())?;
+        cursor.write_u8(self.count)?;
+        cursor.write_u32_le(self.on_time)?;
+        cursor.write_u32_le(self.off_time)?;
+        self.status.write(cursor)?;
         Ok(())
     }
 }
@@ -2312,6 +3675,42 @@ impl FixedSize for Group1Var2 {
 }
 
 
+impl std::fmt::Display for Group120Var4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "user: {}", self.user)
+    }
+}
+
+impl std::fmt::Display for Group120Var3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "challenge_seq: {} user: {}", self.challenge_seq, self.user)
+    }
+}
+
+impl std::fmt::Display for Group102Var1 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "value: {}", self.value)
+    }
+}
+
+impl std::fmt::Display for Group101Var3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "value: {:016X}", self.value)
+    }
+}
+
+impl std::fmt::Display for Group101Var2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "value: {:08X}", self.value)
+    }
+}
+
+impl std::fmt::Display for Group101Var1 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "value: {:04X}", self.value)
+    }
+}
+
 impl std::fmt::Display for Group52Var2 {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "time: {}", self.time)
@@ -2338,7 +3737,13 @@ impl std::fmt::Display for Group51Var1 {
 
 impl std::fmt::Display for Group50Var4 {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "time: {} interval: {} units: {}", self.time, self.interval, self.units)
+        write!(f, "time: {} interval: {} units: {:?}", self.time, self.interval, self.units)
+    }
+}
+
+impl std::fmt::Display for Group50Var2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "time: {} interval: {}", self.time, self.interval)
     }
 }
 
@@ -2354,6 +3759,54 @@ impl std::fmt::Display for Group50Var1 {
     }
 }
 
+impl std::fmt::Display for Group43Var8 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "status: {:?} value: {} time: {}", self.status, self.value, self.time)
+    }
+}
+
+impl std::fmt::Display for Group43Var7 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "status: {:?} value: {} time: {}", self.status, self.value, self.time)
+    }
+}
+
+impl std::fmt::Display for Group43Var6 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "status: {:?} value: {}", self.status, self.value)
+    }
+}
+
+impl std::fmt::Display for Group43Var5 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "status: {:?} value: {}", self.status, self.value)
+    }
+}
+
+impl std::fmt::Display for Group43Var4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "status: {:?} value: {} time: {}", self.status, self.value, self.time)
+    }
+}
+
+impl std::fmt::Display for Group43Var3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "status: {:?} value: {} time: {}", self.status, self.value, self.time)
+    }
+}
+
+impl std::fmt::Display for Group43Var2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "status: {:?} value: {}", self.status, self.value)
+    }
+}
+
+impl std::fmt::Display for Group43Var1 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "status: {:?} value: {}", self.status, self.value)
+    }
+}
+
 impl std::fmt::Display for Group42Var8 {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "flags: {} value: {} time: {}", AnalogFlagFormatter::new(self.flags), self.value, self.time)
@@ -2450,6 +3903,72 @@ impl std::fmt::Display for Group40Var1 {
     }
 }
 
+impl std::fmt::Display for Group34Var3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "value: {}", self.value)
+    }
+}
+
+impl std::fmt::Display for Group34Var2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "value: {}", self.value)
+    }
+}
+
+impl std::fmt::Display for Group34Var1 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "value: {}", self.value)
+    }
+}
+
+impl std::fmt::Display for Group33Var8 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "flags: {} value: {} time: {}", AnalogFlagFormatter::new(self.flags), self.value, self.time)
+    }
+}
+
+impl std::fmt::Display for Group33Var7 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "flags: {} value: {} time: {}", AnalogFlagFormatter::new(self.flags), self.value, self.time)
+    }
+}
+
+impl std::fmt::Display for Group33Var6 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "flags: {} value: {}", AnalogFlagFormatter::new(self.flags), self.value)
+    }
+}
+
+impl std::fmt::Display for Group33Var5 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "flags: {} value: {}", AnalogFlagFormatter::new(self.flags), self.value)
+    }
+}
+
+impl std::fmt::Display for Group33Var4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "flags: {} value: {} time: {}", AnalogFlagFormatter::new(self.flags), self.value, self.time)
+    }
+}
+
+impl std::fmt::Display for Group33Var3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "flags: {} value: {} time: {}", AnalogFlagFormatter::new(self.flags), self.value, self.time)
+    }
+}
+
+impl std::fmt::Display for Group33Var2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "flags: {} value: {}", AnalogFlagFormatter::new(self.flags), self.value)
+    }
+}
+
+impl std::fmt::Display for Group33Var1 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "flags: {} value: {}", AnalogFlagFormatter::new(self.flags), self.value)
+    }
+}
+
 impl std::fmt::Display for Group32Var8 {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "flags: {} value: {} time: {}", AnalogFlagFormatter::new(self.flags), self.value, self.time)
@@ -2498,6 +4017,54 @@ impl std::fmt::Display for Group32Var1 {
     }
 }
 
+impl std::fmt::Display for Group31Var8 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "flags: {} value: {}", AnalogFlagFormatter::new(self.flags), self.value)
+    }
+}
+
+impl std::fmt::Display for Group31Var7 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "flags: {} value: {}", AnalogFlagFormatter::new(self.flags), self.value)
+    }
+}
+
+impl std::fmt::Display for Group31Var6 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "value: {}", self.value)
+    }
+}
+
+impl std::fmt::Display for Group31Var5 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "value: {}", self.value)
+    }
+}
+
+impl std::fmt::Display for Group31Var4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "flags: {} value: {} time: {}", AnalogFlagFormatter::new(self.flags), self.value, self.time)
+    }
+}
+
+impl std::fmt::Display for Group31Var3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "flags: {} value: {} time: {}", AnalogFlagFormatter::new(self.flags), self.value, self.time)
+    }
+}
+
+impl std::fmt::Display for Group31Var2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "flags: {} value: {}", AnalogFlagFormatter::new(self.flags), self.value)
+    }
+}
+
+impl std::fmt::Display for Group31Var1 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "flags: {} value: {}", AnalogFlagFormatter::new(self.flags), self.value)
+    }
+}
+
 impl std::fmt::Display for Group30Var6 {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "flags: {} value: {}", AnalogFlagFormatter::new(self.flags), self.value)
@@ -2642,6 +4209,24 @@ impl std::fmt::Display for Group20Var1 {
     }
 }
 
+impl std::fmt::Display for Group13Var2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "flags: {} time: {}", BinaryCommandEventFlagFormatter::new(self.flags), self.time)
+    }
+}
+
+impl std::fmt::Display for Group13Var1 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "flags: {}", BinaryCommandEventFlagFormatter::new(self.flags))
+    }
+}
+
+impl std::fmt::Display for Group12Var2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "code: {} count: {} on_time: {} off_time: {} status: {:?}", self.code, self.count, self.on_time, self.off_time, self.status)
+    }
+}
+
 impl std::fmt::Display for Group12Var1 {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "code: {} count: {} on_time: {} off_time: {} status: {:?}", self.code, self.count, self.on_time, self.off_time, self.status)
@@ -2715,6 +4300,30 @@ impl std::fmt::Display for Group1Var2 {
 }
 
 
+impl FixedSizeVariation for Group120Var4 {
+    const VARIATION : Variation = Variation::Group120Var4;
+}
+
+impl FixedSizeVariation for Group120Var3 {
+    const VARIATION : Variation = Variation::Group120Var3;
+}
+
+impl FixedSizeVariation for Group102Var1 {
+    const VARIATION : Variation = Variation::Group102Var1;
+}
+
+impl FixedSizeVariation for Group101Var3 {
+    const VARIATION : Variation = Variation::Group101Var3;
+}
+
+impl FixedSizeVariation for Group101Var2 {
+    const VARIATION : Variation = Variation::Group101Var2;
+}
+
+impl FixedSizeVariation for Group101Var1 {
+    const VARIATION : Variation = Variation::Group101Var1;
+}
+
 impl FixedSizeVariation for Group52Var2 {
     const VARIATION : Variation = Variation::Group52Var2;
 }
@@ -2735,6 +4344,10 @@ impl FixedSizeVariation for Group50Var4 {
     const VARIATION : Variation = Variation::Group50Var4;
 }
 
+impl FixedSizeVariation for Group50Var2 {
+    const VARIATION : Variation = Variation::Group50Var2;
+}
+
 impl FixedSizeVariation for Group50Var3 {
     const VARIATION : Variation = Variation::Group50Var3;
 }
@@ -2743,6 +4356,38 @@ impl FixedSizeVariation for Group50Var1 {
     const VARIATION : Variation = Variation::Group50Var1;
 }
 
+impl FixedSizeVariation for Group43Var8 {
+    const VARIATION : Variation = Variation::Group43Var8;
+}
+
+impl FixedSizeVariation for Group43Var7 {
+    const VARIATION : Variation = Variation::Group43Var7;
+}
+
+impl FixedSizeVariation for Group43Var6 {
+    const VARIATION : Variation = Variation::Group43Var6;
+}
+
+impl FixedSizeVariation for Group43Var5 {
+    const VARIATION : Variation = Variation::Group43Var5;
+}
+
+impl FixedSizeVariation for Group43Var4 {
+    const VARIATION : Variation = Variation::Group43Var4;
+}
+
+impl FixedSizeVariation for Group43Var3 {
+    const VARIATION : Variation = Variation::Group43Var3;
+}
+
+impl FixedSizeVariation for Group43Var2 {
+    const VARIATION : Variation = Variation::Group43Var2;
+}
+
+impl FixedSizeVariation for Group43Var1 {
+    const VARIATION : Variation = Variation::Group43Var1;
+}
+
 impl FixedSizeVariation for Group42Var8 {
     const VARIATION : Variation = Variation::Group42Var8;
 }
@@ -2807,6 +4452,50 @@ impl FixedSizeVariation for Group40Var1 {
     const VARIATION : Variation = Variation::Group40Var1;
 }
 
+impl FixedSizeVariation for Group34Var3 {
+    const VARIATION : Variation = Variation::Group34Var3;
+}
+
+impl FixedSizeVariation for Group34Var2 {
+    const VARIATION : Variation = Variation::Group34Var2;
+}
+
+impl FixedSizeVariation for Group34Var1 {
+    const VARIATION : Variation = Variation::Group34Var1;
+}
+
+impl FixedSizeVariation for Group33Var8 {
+    const VARIATION : Variation = Variation::Group33Var8;
+}
+
+impl FixedSizeVariation for Group33Var7 {
+    const VARIATION : Variation = Variation::Group33Var7;
+}
+
+impl FixedSizeVariation for Group33Var6 {
+    const VARIATION : Variation = Variation::Group33Var6;
+}
+
+impl FixedSizeVariation for Group33Var5 {
+    const VARIATION : Variation = Variation::Group33Var5;
+}
+
+impl FixedSizeVariation for Group33Var4 {
+    const VARIATION : Variation = Variation::Group33Var4;
+}
+
+impl FixedSizeVariation for Group33Var3 {
+    const VARIATION : Variation = Variation::Group33Var3;
+}
+
+impl FixedSizeVariation for Group33Var2 {
+    const VARIATION : Variation = Variation::Group33Var2;
+}
+
+impl FixedSizeVariation for Group33Var1 {
+    const VARIATION : Variation = Variation::Group33Var1;
+}
+
 impl FixedSizeVariation for Group32Var8 {
     const VARIATION : Variation = Variation::Group32Var8;
 }
@@ -2839,6 +4528,38 @@ impl FixedSizeVariation for Group32Var1 {
     const VARIATION : Variation = Variation::Group32Var1;
 }
 
+impl FixedSizeVariation for Group31Var8 {
+    const VARIATION : Variation = Variation::Group31Var8;
+}
+
+impl FixedSizeVariation for Group31Var7 {
+    const VARIATION : Variation = Variation::Group31Var7;
+}
+
+impl FixedSizeVariation for Group31Var6 {
+    const VARIATION : Variation = Variation::Group31Var6;
+}
+
+impl FixedSizeVariation for Group31Var5 {
+    const VARIATION : Variation = Variation::Group31Var5;
+}
+
+impl FixedSizeVariation for Group31Var4 {
+    const VARIATION : Variation = Variation::Group31Var4;
+}
+
+impl FixedSizeVariation for Group31Var3 {
+    const VARIATION : Variation = Variation::Group31Var3;
+}
+
+impl FixedSizeVariation for Group31Var2 {
+    const VARIATION : Variation = Variation::Group31Var2;
+}
+
+impl FixedSizeVariation for Group31Var1 {
+    const VARIATION : Variation = Variation::Group31Var1;
+}
+
 impl FixedSizeVariation for Group30Var6 {
     const VARIATION : Variation = Variation::Group30Var6;
 }
@@ -2935,6 +4656,18 @@ impl FixedSizeVariation for Group20Var1 {
     const VARIATION : Variation = Variation::Group20Var1;
 }
 
+impl FixedSizeVariation for Group13Var2 {
+    const VARIATION : Variation = Variation::Group13Var2;
+}
+
+impl FixedSizeVariation for Group13Var1 {
+    const VARIATION : Variation = Variation::Group13Var1;
+}
+
+impl FixedSizeVariation for Group12Var2 {
+    const VARIATION : Variation = Variation::Group12Var2;
+}
+
 impl FixedSizeVariation for Group12Var1 {
     const VARIATION : Variation = Variation::Group12Var1;
 }
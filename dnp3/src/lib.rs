@@ -64,6 +64,11 @@ pub mod link;
 pub mod master;
 /// Types and traits specific to outstations
 pub mod outstation;
+/// Secure Authentication version 5 (IEEE 1815-2012 clause 7) for masters and outstations
+///
+/// Critical requests are authenticated with HMAC-SHA-256 using session keys that the master
+/// changes periodically. Session keys are wrapped with a pre-shared update key (AES key wrap).
+pub mod sa;
 /// Entry points and types for serial
 #[cfg(feature = "serial")]
 pub mod serial;
@@ -9,18 +9,23 @@ use crate::app::{FunctionCode, Timestamp};
 use crate::app::{Iin, ResponseHeader};
 use crate::app::{Sequence, Timeout};
 use crate::link::EndpointAddress;
-use crate::master::error::{AssociationError, TaskError, TimeSyncError};
-use crate::master::extract::extract_measurements;
+use crate::master::auth::{AggressiveMode, ChallengeReply, MasterAuth};
+use crate::master::cache::SharedCache;
+use crate::master::error::{AssociationError, AuthenticationError, TaskError, TimeSyncError};
+use crate::master::extract::{extract_measurements, ExtractState};
 use crate::master::handler::{AssociationHandler, Promise};
 use crate::master::messages::AssociationMsgType;
 use crate::master::poll::{PollHandle, PollMap, PollMsg};
 use crate::master::request::{Classes, EventClasses, TimeSyncProcedure};
+use crate::master::scheduler::{TaskPriority, TaskScheduler};
 use crate::master::session::RunError;
+use crate::master::tasks::auth::SessionKeyTask;
 use crate::master::tasks::auto::AutoTask;
 use crate::master::tasks::time::TimeSyncTask;
 use crate::master::tasks::NonReadTask::TimeSync;
 use crate::master::tasks::{AssociationTask, ReadTask, Task};
 use crate::master::{AssociationInformation, ReadHandler, ReadType, TaskType};
+use crate::sa::MasterAuthConfig;
 use crate::util::Smallest;
 
 use tokio::time::Instant;
@@ -33,6 +38,10 @@ pub struct AssociationConfig {
     /// The event classes to disable on startup
     pub disable_unsol_classes: EventClasses,
     /// The event classes to enable on startup
+    ///
+    /// Classes can be enabled or disabled later using [AssociationHandle::enable_unsolicited](crate::master::AssociationHandle::enable_unsolicited)
+    /// and [AssociationHandle::disable_unsolicited](crate::master::AssociationHandle::disable_unsolicited). The resulting
+    /// set of classes is what the master re-applies when it detects that the outstation restarted.
     pub enable_unsol_classes: EventClasses,
     /// Startup integrity classes to ask on master startup and when an outstation restart is detected.
     ///
@@ -54,6 +63,15 @@ pub struct AssociationConfig {
     /// The maximum number of user requests (e.g. commands, adhoc reads, etc) that will be queued
     /// before back-pressure is applied by failing requests with TaskError::TooManyRequests
     pub max_queued_user_requests: usize,
+    /// Secure authentication (SAv5) of critical requests
+    ///
+    /// When set, the master establishes session keys before any other task and answers
+    /// challenges from the outstation
+    pub authentication: Option<MasterAuthConfig>,
+    /// Keep the last known value of each measurement received from the outstation
+    ///
+    /// When enabled, the cache can be read and subscribed to using the [AssociationHandle](crate::master::AssociationHandle)
+    pub measurement_cache: bool,
 }
 
 impl AssociationConfig {
@@ -79,6 +97,8 @@ impl AssociationConfig {
             auto_integrity_scan_on_buffer_overflow: false,
             event_scan_on_events_available,
             max_queued_user_requests: Self::DEFAULT_MAX_QUEUED_USER_REQUESTS,
+            authentication: None,
+            measurement_cache: false,
         }
     }
 
@@ -96,6 +116,8 @@ impl AssociationConfig {
             auto_integrity_scan_on_buffer_overflow: false,
             event_scan_on_events_available: EventClasses::none(),
             max_queued_user_requests: Self::DEFAULT_MAX_QUEUED_USER_REQUESTS,
+            authentication: None,
+            measurement_cache: false,
         }
     }
 }
@@ -113,6 +135,8 @@ impl Default for AssociationConfig {
             auto_integrity_scan_on_buffer_overflow: true,
             event_scan_on_events_available: EventClasses::none(),
             max_queued_user_requests: Self::DEFAULT_MAX_QUEUED_USER_REQUESTS,
+            authentication: None,
+            measurement_cache: false,
         }
     }
 }
@@ -186,6 +210,7 @@ impl AutoTaskState {
 
 #[derive(Debug)]
 pub(crate) struct TaskStates {
+    session_keys: AutoTaskState,
     disable_unsolicited: AutoTaskState,
     integrity_scan: AutoTaskState,
     enabled_unsolicited: AutoTaskState,
@@ -197,6 +222,7 @@ pub(crate) struct TaskStates {
 impl TaskStates {
     pub(crate) fn new() -> Self {
         Self {
+            session_keys: AutoTaskState::Pending,
             disable_unsolicited: AutoTaskState::Pending,
             integrity_scan: AutoTaskState::Pending,
             enabled_unsolicited: AutoTaskState::Pending,
@@ -210,8 +236,11 @@ impl TaskStates {
         *self = Self::new();
     }
 
-    fn on_restart_iin(&mut self) {
+    fn on_restart_iin(&mut self, disable_unsolicited: bool) {
         self.clear_restart_iin.demand();
+        if disable_unsolicited {
+            self.disable_unsolicited.demand();
+        }
         self.integrity_scan.demand();
         self.enabled_unsolicited.demand();
     }
@@ -223,10 +252,12 @@ impl TaskStates {
                 .create_next_task(|| AutoTask::ClearRestartBit.wrap());
         }
 
-        if config.disable_unsol_classes.any() && self.disable_unsolicited.is_pending() {
-            return self.disable_unsolicited.create_next_task(|| {
-                AutoTask::DisableUnsolicited(config.disable_unsol_classes).wrap()
-            });
+        let disable_unsol_classes =
+            config.disable_unsol_classes | association.disabled_unsol_classes;
+        if disable_unsol_classes.any() && self.disable_unsolicited.is_pending() {
+            return self
+                .disable_unsolicited
+                .create_next_task(|| AutoTask::DisableUnsolicited(disable_unsol_classes).wrap());
         }
 
         if config.startup_integrity_classes.any() && self.integrity_scan.is_pending() {
@@ -243,10 +274,11 @@ impl TaskStates {
             }
         }
 
-        if config.enable_unsol_classes.any() && self.enabled_unsolicited.is_pending() {
-            return self.enabled_unsolicited.create_next_task(|| {
-                AutoTask::EnableUnsolicited(config.enable_unsol_classes).wrap()
-            });
+        let enable_unsol_classes = association.enabled_unsol_classes;
+        if enable_unsol_classes.any() && self.enabled_unsolicited.is_pending() {
+            return self
+                .enabled_unsolicited
+                .create_next_task(|| AutoTask::EnableUnsolicited(enable_unsol_classes).wrap());
         }
 
         let events_to_scan = association.events_available & config.event_scan_on_events_available;
@@ -282,8 +314,10 @@ pub(crate) struct Association {
     address: EndpointAddress,
     response_timeout: Timeout,
     seq: Sequence,
+    // sequence reported when the current task started, b/c multi-fragment responses advance `seq`
+    task_seq: Sequence,
     last_unsol_frag: Option<LastUnsolFragment>,
-    request_queue: VecDeque<Task>,
+    request_queue: VecDeque<(Task, Option<TaskPriority>)>,
     max_request_queue_size: usize,
     auto_tasks: TaskStates,
     read_handler: Box<dyn ReadHandler>,
@@ -291,9 +325,14 @@ pub(crate) struct Association {
     assoc_info: Box<dyn AssociationInformation>,
     config: AssociationConfig,
     polls: PollMap,
+    auth: Option<MasterAuth>,
     next_link_status: Option<Instant>,
     startup_integrity_done: bool,
     events_available: EventClasses,
+    // unsolicited classes as last enabled or disabled, re-applied after the outstation restarts
+    enabled_unsol_classes: EventClasses,
+    disabled_unsol_classes: EventClasses,
+    extract_state: ExtractState,
 }
 
 impl Association {
@@ -308,6 +347,7 @@ impl Association {
             response_timeout: config.response_timeout,
             address,
             seq: Sequence::default(),
+            task_seq: Sequence::default(),
             last_unsol_frag: None,
             request_queue: VecDeque::new(),
             max_request_queue_size: config.max_queued_user_requests,
@@ -317,20 +357,27 @@ impl Association {
             assoc_info,
             config,
             polls: PollMap::new(),
+            auth: config.authentication.map(MasterAuth::new),
             next_link_status: config
                 .keep_alive_timeout
                 .map(|delay| Instant::now() + delay),
             startup_integrity_done: false,
             events_available: EventClasses::none(),
+            enabled_unsol_classes: config.enable_unsol_classes,
+            disabled_unsol_classes: EventClasses::none(),
+            extract_state: ExtractState {
+                cache: config.measurement_cache.then(SharedCache::new),
+                ..ExtractState::default()
+            },
         }
     }
 
     pub(crate) fn process_message(&mut self, msg: AssociationMsgType, is_connected: bool) {
         match msg {
-            AssociationMsgType::QueueTask(task) => {
+            AssociationMsgType::QueueTask(task, priority) => {
                 if is_connected {
                     if self.request_queue.len() < self.max_request_queue_size {
-                        self.request_queue.push_back(task);
+                        self.request_queue.push_back((task, priority));
                     } else {
                         task.on_task_error(Some(self), TaskError::TooManyRequests);
                     }
@@ -341,6 +388,9 @@ impl Association {
             AssociationMsgType::Poll(msg) => {
                 self.process_poll_message(msg);
             }
+            AssociationMsgType::OpenVirtualTerminal(port, sender) => {
+                self.extract_state.virtual_terminals.open(port, sender);
+            }
         }
     }
 
@@ -362,12 +412,15 @@ impl Association {
 
     fn reset(&mut self, err: RunError) {
         // Fail any pending requests
-        while let Some(task) = self.request_queue.pop_front() {
+        while let Some((task, _)) = self.request_queue.pop_front() {
             task.on_task_error(Some(self), err.into());
         }
 
         // Reset the auto tasks
         self.auto_tasks.reset();
+        if let Some(auth) = &mut self.auth {
+            auth.reset();
+        }
         self.startup_integrity_done = false;
 
         // Clear last unsolicited fragment
@@ -414,7 +467,8 @@ impl Association {
     pub(crate) fn on_restart_iin_observed(&mut self) {
         if self.auto_tasks.clear_restart_iin.is_idle() {
             tracing::warn!("device restart detected (address == {})", self.address);
-            self.auto_tasks.on_restart_iin();
+            self.auto_tasks
+                .on_restart_iin(self.disabled_unsol_classes.any());
             self.startup_integrity_done = false;
         }
     }
@@ -486,11 +540,111 @@ impl Association {
         self.auto_tasks.disable_unsolicited.done();
     }
 
+    pub(crate) fn on_unsolicited_enabled(&mut self, classes: EventClasses) {
+        // the automatic enable stays pending when there is nothing to enable, but it no longer
+        // needs to run unless unsolicited reporting has yet to be disabled during startup
+        let disable_pending = self.auto_tasks.disable_unsolicited.is_pending()
+            && (self.config.disable_unsol_classes | self.disabled_unsol_classes).any();
+        if !self.enabled_unsol_classes.any() && !disable_pending {
+            self.auto_tasks.enabled_unsolicited.done();
+        }
+
+        self.enabled_unsol_classes = self.enabled_unsol_classes | classes;
+        self.disabled_unsol_classes = self.disabled_unsol_classes.without(classes);
+    }
+
+    pub(crate) fn on_unsolicited_disabled(&mut self, classes: EventClasses) {
+        self.disabled_unsol_classes = self.disabled_unsol_classes | classes;
+        self.enabled_unsol_classes = self.enabled_unsol_classes.without(classes);
+    }
+
     pub(crate) fn on_disable_unsolicited_failure(&mut self) {
         tracing::warn!("failed to disable unsolicited responses");
         self.auto_tasks.disable_unsolicited.failure(&self.config);
     }
 
+    pub(crate) fn auth(&self) -> Option<&MasterAuth> {
+        self.auth.as_ref()
+    }
+
+    pub(crate) fn auth_mut(&mut self) -> Option<&mut MasterAuth> {
+        self.auth.as_mut()
+    }
+
+    pub(crate) fn measurement_cache(&self) -> Option<SharedCache> {
+        self.extract_state.cache.clone()
+    }
+
+    pub(crate) fn extract_state_mut(&mut self) -> &mut ExtractState {
+        &mut self.extract_state
+    }
+
+    pub(crate) fn on_request_sent(&mut self, fragment: &[u8], aggressive_mode: Option<u32>) {
+        if let Some(auth) = &mut self.auth {
+            auth.on_request_sent(fragment, aggressive_mode);
+        }
+    }
+
+    pub(crate) fn aggressive_mode(&self, function: FunctionCode) -> Option<AggressiveMode> {
+        self.auth.as_ref()?.aggressive_mode(function)
+    }
+
+    pub(crate) fn reply_to_challenge(
+        &mut self,
+        response: &Response,
+    ) -> Result<ChallengeReply, AuthenticationError> {
+        let auth = self
+            .auth
+            .as_mut()
+            .ok_or(AuthenticationError::NotConfigured)?;
+
+        let result = auth.reply_to_challenge(response);
+        if let Err(AuthenticationError::NoSessionKeys | AuthenticationError::ErrorResponse(_)) =
+            result
+        {
+            self.auto_tasks.session_keys.demand();
+        }
+        result
+    }
+
+    pub(crate) fn on_session_key_change_success(&mut self) {
+        tracing::info!("session keys changed");
+        self.auto_tasks.session_keys.done();
+    }
+
+    pub(crate) fn on_session_key_change_failure(&mut self, err: TaskError) {
+        tracing::warn!("session key change failed: {}", err);
+        if let Some(auth) = &mut self.auth {
+            auth.invalidate_keys();
+        }
+        self.auto_tasks.session_keys.failure(&self.config);
+    }
+
+    /// session key changes take priority over every other task
+    fn session_key_task(&mut self) -> Next<Task> {
+        let auth = match self.auth.as_ref() {
+            Some(x) => x,
+            None => return Next::None,
+        };
+
+        if auth.keys_expired() && self.auto_tasks.session_keys.demand() {
+            tracing::info!("session keys expired");
+        }
+
+        match self
+            .auto_tasks
+            .session_keys
+            .create_next_task(|| SessionKeyTask::Status(auth.config().user).wrap().wrap())
+        {
+            // wake up to change the keys when they expire
+            Next::None => match auth.key_expiration() {
+                Some(x) => Next::NotBefore(x),
+                None => Next::None,
+            },
+            next => next,
+        }
+    }
+
     pub(crate) fn on_link_activity(&mut self) {
         self.next_link_status = self
             .config
@@ -526,6 +680,7 @@ impl Association {
                     ReadType::Unsolicited,
                     response.header,
                     objects,
+                    &mut self.extract_state,
                     self.read_handler.as_mut(),
                 )
                 .await;
@@ -552,6 +707,7 @@ impl Association {
             ReadType::StartupIntegrity,
             header,
             objects,
+            &mut self.extract_state,
             self.read_handler.as_mut(),
         )
         .await;
@@ -566,6 +722,7 @@ impl Association {
             ReadType::PeriodicPoll,
             header,
             objects,
+            &mut self.extract_state,
             self.read_handler.as_mut(),
         )
         .await;
@@ -580,6 +737,7 @@ impl Association {
             ReadType::PeriodicPoll,
             header,
             objects,
+            &mut self.extract_state,
             self.read_handler.as_mut(),
         )
         .await;
@@ -594,18 +752,19 @@ impl Association {
             ReadType::SinglePoll,
             header,
             objects,
+            &mut self.extract_state,
             self.read_handler.as_mut(),
         )
         .await;
     }
 
     pub(crate) fn notify_task_start(&mut self, task_type: TaskType, fc: FunctionCode) {
-        self.assoc_info
-            .task_start(task_type, fc, Sequence::new(self.seq.next()))
+        self.task_seq = Sequence::new(self.seq.next());
+        self.assoc_info.task_start(task_type, fc, self.task_seq)
     }
 
     pub(crate) fn notify_task_success(&mut self, task_type: TaskType, fc: FunctionCode) {
-        self.assoc_info.task_success(task_type, fc, self.seq);
+        self.assoc_info.task_success(task_type, fc, self.task_seq);
     }
 
     pub(crate) fn notify_task_fail(&mut self, task_type: TaskType, err: TaskError) {
@@ -616,60 +775,112 @@ impl Association {
         self.assoc_info.unsolicited_response(is_duplicate, seq);
     }
 
-    pub(crate) fn priority_task(&mut self) -> Option<Task> {
-        while let Some(task) = self.request_queue.pop_front() {
-            if let Some(task) = task.start(self) {
-                return Some(task);
+    /// Highest priority task that is ready to run, if any
+    ///
+    /// The earliest time at which a task that isn't ready will become ready is recorded in `earliest`
+    fn next_candidate(
+        &self,
+        now: Instant,
+        scheduler: &dyn TaskScheduler,
+        earliest: &mut Smallest<Instant>,
+    ) -> Option<Candidate> {
+        let mut best = None;
+
+        for (index, (task, priority)) in self.request_queue.iter().enumerate() {
+            let priority = priority.unwrap_or_else(|| task.priority(scheduler));
+            Candidate::new(priority, TaskSource::User(index)).keep_best(&mut best);
+        }
+
+        match self.auto_tasks.next(&self.config, self) {
+            Next::Now(task) => {
+                Candidate::new(task.priority(scheduler), TaskSource::Auto).keep_best(&mut best)
             }
-        }
-
-        None
-    }
-
-    fn next_task(&mut self, now: Instant) -> Next<Task> {
-        loop {
-            let next_task = self.get_next_task(now);
-
-            if let Next::Now(task) = next_task {
-                // Check if execution can still happen
-                if let Some(task) = task.start(self) {
-                    return Next::Now(task);
-                }
-            } else {
-                return next_task;
+            Next::NotBefore(x) => {
+                // polls don't run while an automatic task is waiting to be retried
+                earliest.observe(x);
+                return best;
             }
-        }
-    }
-
-    fn get_next_task(&self, now: Instant) -> Next<Task> {
-        // Check for automatic tasks
-        let next = self.auto_tasks.next(&self.config, self);
-
-        // Startup task have greater priority
-        if !matches!(next, Next::None) {
-            return next;
+            Next::None => {}
         }
 
-        // If no automatic tasks to complete, check for lower priority polls or link status request
         match self.polls.next(now) {
-            Next::Now(poll) => Next::Now(Task::Read(ReadTask::PeriodicPoll(poll))),
-            Next::NotBefore(next_poll) => match self.next_link_status {
-                Some(next_link_status) => {
-                    Next::NotBefore(Instant::min(next_link_status, next_poll))
+            Next::Now(_) => {
+                Candidate::new(scheduler.priority(TaskType::PeriodicPoll), TaskSource::Poll)
+                    .keep_best(&mut best);
+            }
+            Next::NotBefore(next_poll) => {
+                earliest.observe(next_poll);
+                if let Some(next_link_status) = self.next_link_status {
+                    earliest.observe(next_link_status);
                 }
-                None => Next::NotBefore(next_poll),
-            },
-            Next::None => match self.next_link_status {
-                Some(next) => {
-                    let now = Instant::now();
+            }
+            Next::None => {
+                if let Some(next) = self.next_link_status {
                     if now < next {
-                        Next::NotBefore(next)
+                        earliest.observe(next);
                     } else {
-                        Next::Now(Task::LinkStatus(Promise::None))
+                        Candidate::new(TaskPriority::Low, TaskSource::LinkStatus)
+                            .keep_best(&mut best);
                     }
                 }
-                None => Next::None,
+            }
+        }
+
+        best
+    }
+
+    /// Remove the task chosen using `next_candidate` and check if it can still execute
+    fn start_task(&mut self, source: TaskSource, now: Instant) -> Option<Task> {
+        let task = match source {
+            TaskSource::User(index) => self.request_queue.remove(index).map(|(task, _)| task),
+            TaskSource::Auto => match self.auto_tasks.next(&self.config, self) {
+                Next::Now(task) => Some(task),
+                _ => None,
+            },
+            TaskSource::Poll => match self.polls.next(now) {
+                Next::Now(poll) => Some(Task::Read(ReadTask::PeriodicPoll(poll))),
+                _ => None,
             },
+            TaskSource::LinkStatus => Some(Task::LinkStatus(Promise::None)),
+        };
+
+        task?.start(self)
+    }
+}
+
+/// Where a task that is ready to run comes from, in the order used to break ties in priority
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum TaskSource {
+    /// position in the queue of user requests
+    User(usize),
+    Auto,
+    Poll,
+    LinkStatus,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Candidate {
+    priority: TaskPriority,
+    source: TaskSource,
+}
+
+impl Candidate {
+    fn new(priority: TaskPriority, source: TaskSource) -> Self {
+        Self { priority, source }
+    }
+
+    fn is_better_than(&self, other: &Candidate) -> bool {
+        match self.priority.cmp(&other.priority) {
+            std::cmp::Ordering::Greater => true,
+            std::cmp::Ordering::Less => false,
+            std::cmp::Ordering::Equal => self.source < other.source,
+        }
+    }
+
+    fn keep_best(self, best: &mut Option<Candidate>) {
+        match best {
+            Some(x) if !self.is_better_than(x) => {}
+            _ => *best = Some(self),
         }
     }
 }
@@ -742,51 +953,70 @@ impl AssociationMap {
         }
     }
 
-    pub(crate) fn next_task(&mut self) -> Next<AssociationTask> {
-        // Check for priority task
-        for (index, address) in self.priority.iter().enumerate() {
-            if let Some(association) = self.map.get_mut(address) {
-                // Check for priority task
-                if let Some(task) = association.priority_task() {
-                    // just before returning, move this session to last priority
-                    if let Some(x) = self.priority.remove(index) {
-                        self.priority.push_back(x);
+    pub(crate) fn next_task(&mut self, scheduler: &dyn TaskScheduler) -> Next<AssociationTask> {
+        // Session key changes always run first
+        let mut next_key_change = Smallest::<Instant>::new();
+        for index in 0..self.priority.len() {
+            let address = self.priority[index];
+            if let Some(association) = self.map.get_mut(&address) {
+                match association.session_key_task() {
+                    Next::Now(task) => {
+                        self.move_to_back(index);
+                        return Next::Now(AssociationTask::new(address, task));
                     }
-
-                    let task = AssociationTask::new(association.address, task);
-                    return Next::Now(task);
+                    Next::NotBefore(x) => next_key_change.observe(x),
+                    Next::None => {}
                 }
             }
         }
 
-        // Check for non-priority tasks
-        let now = Instant::now();
-        let mut earliest = Smallest::<Instant>::new();
-
-        for (index, address) in self.priority.iter().enumerate() {
-            if let Some(association) = self.map.get_mut(address) {
-                match association.next_task(now) {
-                    Next::Now(task) => {
-                        // just before returning, move this session to last priority
-                        if let Some(x) = self.priority.remove(index) {
-                            self.priority.push_back(x);
+        loop {
+            let now = Instant::now();
+            let mut earliest = Smallest::<Instant>::new();
+            if let Some(x) = next_key_change.value() {
+                earliest.observe(x);
+            }
+            let mut best: Option<(usize, Candidate)> = None;
+
+            // associations are checked in turn so that ties go to the one that waited longest
+            for (index, address) in self.priority.iter().enumerate() {
+                if let Some(association) = self.map.get(address) {
+                    if let Some(candidate) =
+                        association.next_candidate(now, scheduler, &mut earliest)
+                    {
+                        match best {
+                            Some((_, x)) if !candidate.is_better_than(&x) => {}
+                            _ => best = Some((index, candidate)),
                         }
+                    }
+                }
+            }
 
-                        let task = AssociationTask::new(association.address, task);
-                        return Next::Now(task);
+            let (index, candidate) = match best {
+                Some(x) => x,
+                None => {
+                    return match earliest.value() {
+                        Some(x) => Next::NotBefore(x),
+                        None => Next::None,
                     }
-                    Next::NotBefore(x) => earliest.observe(x),
-                    Next::None => {}
+                }
+            };
+
+            let address = self.priority[index];
+            if let Some(association) = self.map.get_mut(&address) {
+                // the task might have been cancelled, in which case another one is chosen
+                if let Some(task) = association.start_task(candidate.source, now) {
+                    self.move_to_back(index);
+                    return Next::Now(AssociationTask::new(address, task));
                 }
             }
         }
+    }
 
-        // Return earliest task
-        if let Some(x) = earliest.value() {
-            return Next::NotBefore(x);
+    /// move an association to last priority just before returning one of its tasks
+    fn move_to_back(&mut self, index: usize) {
+        if let Some(x) = self.priority.remove(index) {
+            self.priority.push_back(x);
         }
-
-        // No task found
-        Next::None
     }
 }
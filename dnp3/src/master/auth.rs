@@ -0,0 +1,225 @@
+use crate::app::auth::Group120Var2;
+use crate::app::format::write::HeaderWriter;
+use crate::app::parse::free_format::FreeFormatVariation;
+use crate::app::parse::parser::Response;
+use crate::app::variations::Group120Var3;
+use crate::app::{FunctionCode, ResponseHeader};
+use crate::master::error::AuthenticationError;
+use crate::master::tasks::RequestWriter;
+use crate::sa::crypto::{calc_mac, SessionKeys};
+use crate::sa::*;
+
+use scursor::{WriteCursor, WriteError};
+use tokio::time::Instant;
+
+/// Secure authentication state of a master association
+pub(crate) struct MasterAuth {
+    config: MasterAuthConfig,
+    keys: Option<SessionKeys>,
+    expiration: Instant,
+    /// last request sent, authenticated by challenge replies and key status MACs
+    last_request: Vec<u8>,
+    /// last challenge or key status received, authenticated by aggressive mode requests
+    last_challenge: Option<LastChallenge>,
+}
+
+struct LastChallenge {
+    /// sequence number of the challenge, or of the last aggressive mode request sent with it
+    challenge_seq: u32,
+    mac_algorithm: MacAlgorithm,
+    fragment: Vec<u8>,
+}
+
+/// objects needed to sign a request in aggressive mode
+pub(crate) struct AggressiveMode {
+    pub(crate) header: Group120Var3,
+    mac_algorithm: MacAlgorithm,
+    key: Vec<u8>,
+    challenge: Vec<u8>,
+}
+
+impl AggressiveMode {
+    /// MAC over the last challenge and the request without the g120v9 object
+    pub(crate) fn mac(&self, request: &[u8]) -> Vec<u8> {
+        calc_mac(self.mac_algorithm, &self.key, &[&self.challenge, request])
+    }
+}
+
+/// reply (g120v2) to a challenge from the outstation
+pub(crate) struct ChallengeReply {
+    challenge_seq: u32,
+    user: UserNumber,
+    mac: Vec<u8>,
+}
+
+impl RequestWriter for ChallengeReply {
+    fn function(&self) -> FunctionCode {
+        FunctionCode::AuthenticateRequest
+    }
+
+    fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        writer.write_free_format(&Group120Var2 {
+            challenge_seq: self.challenge_seq,
+            user: self.user.value(),
+            mac: &self.mac,
+        })
+    }
+}
+
+impl MasterAuth {
+    pub(crate) fn new(config: MasterAuthConfig) -> Self {
+        Self {
+            config,
+            keys: None,
+            expiration: Instant::now(),
+            last_request: Vec::new(),
+            last_challenge: None,
+        }
+    }
+
+    pub(crate) fn config(&self) -> &MasterAuthConfig {
+        &self.config
+    }
+
+    /// session keys don't survive the loss of communications
+    pub(crate) fn reset(&mut self) {
+        self.keys = None;
+        self.last_challenge = None;
+    }
+
+    pub(crate) fn last_request(&self) -> &[u8] {
+        &self.last_request
+    }
+
+    pub(crate) fn on_request_sent(&mut self, fragment: &[u8], aggressive_mode: Option<u32>) {
+        self.last_request.clear();
+        self.last_request.extend_from_slice(fragment);
+        // the sequence number is only used up once the request is actually sent
+        if let (Some(seq), Some(challenge)) = (aggressive_mode, self.last_challenge.as_mut()) {
+            challenge.challenge_seq = seq;
+        }
+    }
+
+    /// when the current session keys expire, if there are any
+    pub(crate) fn key_expiration(&self) -> Option<Instant> {
+        self.keys.as_ref().map(|_| self.expiration)
+    }
+
+    pub(crate) fn keys_expired(&self) -> bool {
+        self.keys.is_some() && Instant::now() >= self.expiration
+    }
+
+    /// the key status confirming new session keys is also challenge data for aggressive mode
+    pub(crate) fn set_keys(
+        &mut self,
+        keys: SessionKeys,
+        key_change_seq: u32,
+        mac_algorithm: MacAlgorithm,
+        response: &Response,
+    ) -> Result<(), AuthenticationError> {
+        let fragment = challenge_fragment(response.header, response.raw_objects)
+            .map_err(|_| AuthenticationError::UnexpectedResponse)?;
+        self.keys = Some(keys);
+        self.expiration = Instant::now() + self.config.session_key_change_interval;
+        self.last_challenge = Some(LastChallenge {
+            challenge_seq: key_change_seq,
+            mac_algorithm,
+            fragment,
+        });
+        Ok(())
+    }
+
+    pub(crate) fn invalidate_keys(&mut self) {
+        self.keys = None;
+        self.last_challenge = None;
+    }
+
+    fn valid_keys(&self) -> Option<&SessionKeys> {
+        match &self.keys {
+            Some(keys) if Instant::now() < self.expiration => Some(keys),
+            _ => None,
+        }
+    }
+
+    /// objects used to sign a critical request without waiting for a challenge
+    pub(crate) fn aggressive_mode(&self, function: FunctionCode) -> Option<AggressiveMode> {
+        if !self.config.aggressive_mode || !is_critical(function) {
+            return None;
+        }
+
+        let key = self.valid_keys()?.control.clone();
+        let challenge = self.last_challenge.as_ref()?;
+
+        Some(AggressiveMode {
+            header: Group120Var3 {
+                challenge_seq: challenge.challenge_seq.wrapping_add(1),
+                user: self.config.user.value(),
+            },
+            mac_algorithm: challenge.mac_algorithm,
+            key,
+            challenge: challenge.fragment.clone(),
+        })
+    }
+
+    /// calculate the reply to a challenge of the last request
+    pub(crate) fn reply_to_challenge(
+        &mut self,
+        response: &Response,
+    ) -> Result<ChallengeReply, AuthenticationError> {
+        let header = response
+            .objects
+            .ok()
+            .and_then(|x| x.get_only_header())
+            .ok_or(AuthenticationError::UnexpectedResponse)?;
+
+        let challenge = match header.details.free_format() {
+            Some(FreeFormatVariation::Group120Var1(x)) => x,
+            Some(FreeFormatVariation::Group120Var7(x)) => {
+                let code = AuthErrorCode::new(x.error_code);
+                tracing::warn!("outstation returned authentication error: {:?}", code);
+                self.invalidate_keys();
+                return Err(AuthenticationError::ErrorResponse(code));
+            }
+            _ => return Err(AuthenticationError::UnexpectedResponse),
+        };
+
+        let mac_algorithm = MacAlgorithm::from_u8(challenge.mac_algorithm)
+            .ok_or(AuthenticationError::UnsupportedAlgorithm)?;
+
+        let keys = self
+            .valid_keys()
+            .ok_or(AuthenticationError::NoSessionKeys)?;
+
+        let fragment = challenge_fragment(response.header, response.raw_objects)
+            .map_err(|_| AuthenticationError::UnexpectedResponse)?;
+
+        let mac = calc_mac(
+            mac_algorithm,
+            &keys.control,
+            &[&fragment, &self.last_request],
+        );
+
+        tracing::info!("replying to challenge: {}", challenge);
+
+        self.last_challenge = Some(LastChallenge {
+            challenge_seq: challenge.challenge_seq,
+            mac_algorithm,
+            fragment,
+        });
+
+        Ok(ChallengeReply {
+            challenge_seq: challenge.challenge_seq,
+            user: self.config.user,
+            mac,
+        })
+    }
+}
+
+/// the response doesn't retain the raw fragment, so the header is formatted again
+fn challenge_fragment(header: ResponseHeader, objects: &[u8]) -> Result<Vec<u8>, WriteError> {
+    let mut buffer = vec![0; ResponseHeader::LENGTH + objects.len()];
+    let mut cursor = WriteCursor::new(&mut buffer);
+    header.write(&mut cursor)?;
+    cursor.write_bytes(objects)?;
+    Ok(buffer)
+}
@@ -0,0 +1,384 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use crate::app::measurement::*;
+use crate::app::{
+    Attribute, DataSet, DataSetDescriptor, DataSetPrototype, MaybeAsync, ResponseHeader,
+};
+use crate::master::{HeaderInfo, ReadHandler, ReadType};
+
+/// Type of measurement stored in a [MeasurementCache]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MeasurementType {
+    /// Binary input (g1/g2)
+    BinaryInput,
+    /// Double-bit binary input (g3/g4)
+    DoubleBitBinaryInput,
+    /// Binary output status (g10/g11)
+    BinaryOutputStatus,
+    /// Counter (g20/g22)
+    Counter,
+    /// Frozen counter (g21/g23)
+    FrozenCounter,
+    /// Analog input (g30/g32)
+    AnalogInput,
+    /// Frozen analog input (g31/g33)
+    FrozenAnalogInput,
+    /// Analog output status (g40/g42)
+    AnalogOutputStatus,
+    /// Octet string (g110/g111)
+    OctetString,
+    /// Unsigned integer (g102)
+    UnsignedInteger,
+    /// BCD integer (g101)
+    Bcd,
+}
+
+/// Last known value of each measurement received from an outstation, keyed by point index
+///
+/// Static values and events are both applied in the order they are received, so the
+/// cached value of a point is whatever the outstation reported most recently. Values include
+/// the flags and time of the measurement when they were present in the response.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MeasurementCache {
+    /// binary inputs
+    pub binary_input: BTreeMap<u16, BinaryInput>,
+    /// double-bit binary inputs
+    pub double_bit_binary_input: BTreeMap<u16, DoubleBitBinaryInput>,
+    /// binary output status
+    pub binary_output_status: BTreeMap<u16, BinaryOutputStatus>,
+    /// counters
+    pub counter: BTreeMap<u16, Counter>,
+    /// frozen counters
+    pub frozen_counter: BTreeMap<u16, FrozenCounter>,
+    /// analog inputs
+    pub analog_input: BTreeMap<u16, AnalogInput>,
+    /// frozen analog inputs
+    pub frozen_analog_input: BTreeMap<u16, FrozenAnalogInput>,
+    /// analog output status
+    pub analog_output_status: BTreeMap<u16, AnalogOutputStatus>,
+    /// octet strings
+    pub octet_string: BTreeMap<u16, OctetString>,
+    /// unsigned integers
+    pub unsigned_integer: BTreeMap<u16, UnsignedInteger>,
+    /// BCD integers
+    pub bcd: BTreeMap<u16, Bcd>,
+}
+
+/// Notification published when a response fragment changes the [MeasurementCache]
+///
+/// Subscribers that fall behind miss notifications, but can always recover by reading
+/// the cache.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CacheUpdate {
+    /// what triggered the read, e.g. a poll or an unsolicited response
+    pub read_type: ReadType,
+    /// type and index of each point whose cached value changed, in order of type and index
+    pub changes: Vec<(MeasurementType, u16)>,
+}
+
+impl MeasurementCache {
+    /// apply the values in `other` and return the points whose value changed
+    fn merge(&mut self, other: MeasurementCache) -> Vec<(MeasurementType, u16)> {
+        fn merge_map<T: PartialEq>(
+            kind: MeasurementType,
+            target: &mut BTreeMap<u16, T>,
+            source: BTreeMap<u16, T>,
+            changes: &mut Vec<(MeasurementType, u16)>,
+        ) {
+            for (index, value) in source {
+                if target.get(&index) != Some(&value) {
+                    target.insert(index, value);
+                    changes.push((kind, index));
+                }
+            }
+        }
+
+        let mut changes = Vec::new();
+        merge_map(
+            MeasurementType::BinaryInput,
+            &mut self.binary_input,
+            other.binary_input,
+            &mut changes,
+        );
+        merge_map(
+            MeasurementType::DoubleBitBinaryInput,
+            &mut self.double_bit_binary_input,
+            other.double_bit_binary_input,
+            &mut changes,
+        );
+        merge_map(
+            MeasurementType::BinaryOutputStatus,
+            &mut self.binary_output_status,
+            other.binary_output_status,
+            &mut changes,
+        );
+        merge_map(
+            MeasurementType::Counter,
+            &mut self.counter,
+            other.counter,
+            &mut changes,
+        );
+        merge_map(
+            MeasurementType::FrozenCounter,
+            &mut self.frozen_counter,
+            other.frozen_counter,
+            &mut changes,
+        );
+        merge_map(
+            MeasurementType::AnalogInput,
+            &mut self.analog_input,
+            other.analog_input,
+            &mut changes,
+        );
+        merge_map(
+            MeasurementType::FrozenAnalogInput,
+            &mut self.frozen_analog_input,
+            other.frozen_analog_input,
+            &mut changes,
+        );
+        merge_map(
+            MeasurementType::AnalogOutputStatus,
+            &mut self.analog_output_status,
+            other.analog_output_status,
+            &mut changes,
+        );
+        merge_map(
+            MeasurementType::OctetString,
+            &mut self.octet_string,
+            other.octet_string,
+            &mut changes,
+        );
+        merge_map(
+            MeasurementType::UnsignedInteger,
+            &mut self.unsigned_integer,
+            other.unsigned_integer,
+            &mut changes,
+        );
+        merge_map(MeasurementType::Bcd, &mut self.bcd, other.bcd, &mut changes);
+        changes
+    }
+}
+
+/// Cache shared between an association and its handles
+#[derive(Clone, Debug)]
+pub(crate) struct SharedCache {
+    cache: Arc<Mutex<MeasurementCache>>,
+    updates: tokio::sync::broadcast::Sender<CacheUpdate>,
+}
+
+impl SharedCache {
+    /// maximum number of notifications buffered for a subscriber before it starts missing them
+    const MAX_QUEUED_UPDATES: usize = 64;
+
+    pub(crate) fn new() -> Self {
+        let (updates, _) = tokio::sync::broadcast::channel(Self::MAX_QUEUED_UPDATES);
+        Self {
+            cache: Arc::new(Mutex::new(MeasurementCache::default())),
+            updates,
+        }
+    }
+
+    pub(crate) fn read<R>(&self, func: impl FnOnce(&MeasurementCache) -> R) -> R {
+        func(&self.cache.lock().unwrap())
+    }
+
+    pub(crate) fn subscribe(&self) -> tokio::sync::broadcast::Receiver<CacheUpdate> {
+        self.updates.subscribe()
+    }
+
+    /// apply the values received in a fragment and notify subscribers of any changes
+    pub(crate) fn apply(&self, read_type: ReadType, received: MeasurementCache) {
+        let changes = self.cache.lock().unwrap().merge(received);
+        if !changes.is_empty() {
+            // an error only indicates that there are no subscribers
+            let _ = self.updates.send(CacheUpdate { read_type, changes });
+        }
+    }
+}
+
+/// `ReadHandler` that records the measurements passed to another handler
+///
+/// Values are recorded separately from the shared cache so that the lock is never held while
+/// user callbacks are running.
+pub(crate) struct CachingHandler<'a> {
+    received: MeasurementCache,
+    inner: &'a mut dyn ReadHandler,
+}
+
+impl<'a> CachingHandler<'a> {
+    pub(crate) fn new(inner: &'a mut dyn ReadHandler) -> Self {
+        Self {
+            received: MeasurementCache::default(),
+            inner,
+        }
+    }
+
+    pub(crate) fn into_received(self) -> MeasurementCache {
+        self.received
+    }
+}
+
+fn record<T: Clone>(
+    map: &mut BTreeMap<u16, T>,
+    iter: &mut dyn Iterator<Item = (T, u16)>,
+) -> Vec<(T, u16)> {
+    let items: Vec<(T, u16)> = iter.collect();
+    for (value, index) in items.iter() {
+        map.insert(*index, value.clone());
+    }
+    items
+}
+
+impl ReadHandler for CachingHandler<'_> {
+    fn begin_fragment(&mut self, read_type: ReadType, header: ResponseHeader) -> MaybeAsync<()> {
+        self.inner.begin_fragment(read_type, header)
+    }
+
+    fn end_fragment(&mut self, read_type: ReadType, header: ResponseHeader) -> MaybeAsync<()> {
+        self.inner.end_fragment(read_type, header)
+    }
+
+    fn handle_binary_input(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (BinaryInput, u16)>,
+    ) {
+        let items = record(&mut self.received.binary_input, iter);
+        self.inner.handle_binary_input(info, &mut items.into_iter());
+    }
+
+    fn handle_double_bit_binary_input(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (DoubleBitBinaryInput, u16)>,
+    ) {
+        let items = record(&mut self.received.double_bit_binary_input, iter);
+        self.inner
+            .handle_double_bit_binary_input(info, &mut items.into_iter());
+    }
+
+    fn handle_binary_output_status(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (BinaryOutputStatus, u16)>,
+    ) {
+        let items = record(&mut self.received.binary_output_status, iter);
+        self.inner
+            .handle_binary_output_status(info, &mut items.into_iter());
+    }
+
+    fn handle_counter(&mut self, info: HeaderInfo, iter: &mut dyn Iterator<Item = (Counter, u16)>) {
+        let items = record(&mut self.received.counter, iter);
+        self.inner.handle_counter(info, &mut items.into_iter());
+    }
+
+    fn handle_frozen_counter(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (FrozenCounter, u16)>,
+    ) {
+        let items = record(&mut self.received.frozen_counter, iter);
+        self.inner
+            .handle_frozen_counter(info, &mut items.into_iter());
+    }
+
+    fn handle_analog_input(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (AnalogInput, u16)>,
+    ) {
+        let items = record(&mut self.received.analog_input, iter);
+        self.inner.handle_analog_input(info, &mut items.into_iter());
+    }
+
+    fn handle_frozen_analog_input(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (FrozenAnalogInput, u16)>,
+    ) {
+        let items = record(&mut self.received.frozen_analog_input, iter);
+        self.inner
+            .handle_frozen_analog_input(info, &mut items.into_iter());
+    }
+
+    fn handle_analog_output_status(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (AnalogOutputStatus, u16)>,
+    ) {
+        let items = record(&mut self.received.analog_output_status, iter);
+        self.inner
+            .handle_analog_output_status(info, &mut items.into_iter());
+    }
+
+    fn handle_octet_string<'b>(
+        &mut self,
+        info: HeaderInfo,
+        iter: &'b mut dyn Iterator<Item = (&'b [u8], u16)>,
+    ) {
+        let items: Vec<(&[u8], u16)> = iter.collect();
+        for (value, index) in items.iter() {
+            if let Ok(value) = OctetString::new(value) {
+                self.received.octet_string.insert(*index, value);
+            }
+        }
+        self.inner
+            .handle_octet_string(info, &mut items.iter().map(|(x, i)| (*x, *i)));
+    }
+
+    fn handle_device_attribute(&mut self, info: HeaderInfo, attr: Attribute) {
+        self.inner.handle_device_attribute(info, attr)
+    }
+
+    fn handle_data_set_prototype(&mut self, info: HeaderInfo, prototype: &DataSetPrototype) {
+        self.inner.handle_data_set_prototype(info, prototype)
+    }
+
+    fn handle_data_set_descriptor(&mut self, info: HeaderInfo, descriptor: &DataSetDescriptor) {
+        self.inner.handle_data_set_descriptor(info, descriptor)
+    }
+
+    fn handle_data_set(&mut self, info: HeaderInfo, data_set: &DataSet) {
+        self.inner.handle_data_set(info, data_set)
+    }
+
+    fn handle_analog_input_deadband(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (f64, u16)>,
+    ) {
+        self.inner.handle_analog_input_deadband(info, iter)
+    }
+
+    fn handle_binary_output_command_event(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (BinaryOutputCommandEvent, u16)>,
+    ) {
+        self.inner.handle_binary_output_command_event(info, iter)
+    }
+
+    fn handle_analog_output_command_event(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (AnalogOutputCommandEvent, u16)>,
+    ) {
+        self.inner.handle_analog_output_command_event(info, iter)
+    }
+
+    fn handle_unsigned_integer(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (UnsignedInteger, u16)>,
+    ) {
+        let items = record(&mut self.received.unsigned_integer, iter);
+        self.inner
+            .handle_unsigned_integer(info, &mut items.into_iter());
+    }
+
+    fn handle_bcd(&mut self, info: HeaderInfo, iter: &mut dyn Iterator<Item = (Bcd, u16)>) {
+        let items = record(&mut self.received.bcd, iter);
+        self.inner.handle_bcd(info, &mut items.into_iter());
+    }
+}
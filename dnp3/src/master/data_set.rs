@@ -0,0 +1,77 @@
+use std::collections::BTreeMap;
+
+use crate::app::parse::free_format::FreeFormatVariation;
+use crate::app::*;
+use crate::master::handler::{HeaderInfo, ReadHandler};
+
+/// Data set prototypes and descriptors read from an outstation
+///
+/// Present values (g87) and snapshot events (g88) only contain the encoded values, so the
+/// descriptor (g86) and any prototypes (g85) it references are needed to determine their types.
+/// They are retained for the lifetime of the association.
+#[derive(Default)]
+pub(crate) struct DataSetRegistry {
+    prototypes: BTreeMap<[u8; 16], DataSetPrototype>,
+    descriptors: BTreeMap<u16, DataSetDescriptor>,
+}
+
+impl DataSetRegistry {
+    /// process a free-format header, returning false if it is not a data set
+    pub(crate) fn extract(
+        &mut self,
+        variation: &FreeFormatVariation,
+        qualifier: QualifierCode,
+        handler: &mut dyn ReadHandler,
+    ) -> bool {
+        match variation {
+            FreeFormatVariation::Group85Var1(x) => {
+                let prototype = x.to_prototype();
+                handler.handle_data_set_prototype(
+                    HeaderInfo::new(Variation::Group85Var1, qualifier, false, false),
+                    &prototype,
+                );
+                self.prototypes.insert(prototype.uuid, prototype);
+            }
+            FreeFormatVariation::Group86Var1(x) => {
+                let descriptor = x.to_descriptor();
+                handler.handle_data_set_descriptor(
+                    HeaderInfo::new(Variation::Group86Var1, qualifier, false, false),
+                    &descriptor,
+                );
+                self.descriptors.insert(descriptor.id, descriptor);
+            }
+            FreeFormatVariation::Group87Var1(x) => {
+                let data_set = x.to_data_set(self.value_elements(x.id).as_deref());
+                handler.handle_data_set(
+                    HeaderInfo::new(Variation::Group87Var1, qualifier, false, false),
+                    &data_set,
+                );
+            }
+            FreeFormatVariation::Group88Var1(x) => {
+                let data_set = x.to_data_set(self.value_elements(x.id).as_deref());
+                handler.handle_data_set(
+                    HeaderInfo::new(Variation::Group88Var1, qualifier, true, false),
+                    &data_set,
+                );
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn value_elements(&self, id: u16) -> Option<Vec<&DataSetElement>> {
+        let descriptor = self.descriptors.get(&id)?;
+        let elements = descriptor.value_elements(|uuid| {
+            <[u8; 16]>::try_from(uuid)
+                .ok()
+                .and_then(|uuid| self.prototypes.get(&uuid))
+        });
+        if elements.is_none() {
+            tracing::warn!(
+                "data set {} references a prototype that has not been read",
+                id
+            );
+        }
+        elements
+    }
+}
@@ -1,12 +1,13 @@
 use std::error::Error;
 
 use crate::app::control::CommandStatus;
-use crate::app::{Iin, Iin2};
+use crate::app::{FileStatus, FunctionCode, Iin, Iin2};
 use crate::app::{ObjectParseError, Shutdown};
 use crate::link::error::LinkError;
 use crate::link::EndpointAddress;
 use crate::master::association::NoAssociation;
 use crate::master::session::{RunError, StateChange};
+use crate::sa::{AuthErrorCode, KeyStatus};
 use crate::transport::TransportResponseError;
 
 use scursor::WriteError;
@@ -56,6 +57,27 @@ pub enum TaskError {
     Shutdown,
     /// The master was disabled
     Disabled,
+    /// Secure authentication of the request failed
+    Authentication(AuthenticationError),
+}
+
+/// Errors that can occur during secure authentication
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AuthenticationError {
+    /// The outstation challenged a request, but authentication isn't configured on the association
+    NotConfigured,
+    /// The outstation challenged a request before session keys were established
+    NoSessionKeys,
+    /// The outstation rejected the request with an error object (g120v7)
+    ErrorResponse(AuthErrorCode),
+    /// The outstation answered with an unexpected authentication object
+    UnexpectedResponse,
+    /// The MAC of the key status returned by the outstation was invalid
+    InvalidMac,
+    /// The outstation reported a key status other than OK after a key change
+    BadKeyStatus(KeyStatus),
+    /// The outstation requested an algorithm that isn't supported
+    UnsupportedAlgorithm,
 }
 
 /// Errors that can occur when adding/modifying polls
@@ -126,6 +148,43 @@ pub enum CommandError {
     Response(CommandResponseError),
 }
 
+/// Errors that can occur during a WRITE, ASSIGN_CLASS, or freeze request
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WriteRequestError {
+    /// Failed b/c of a generic task execution error
+    Task(TaskError),
+    /// Outstation returned an IIN.2 error
+    IinError(Iin2),
+}
+
+/// Errors that can occur during a custom request
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CustomRequestError {
+    /// Failed b/c of a generic task execution error
+    Task(TaskError),
+    /// The function code may not be used in a request, e.g. CONFIRM or RESPONSE
+    InvalidFunction(FunctionCode),
+}
+
+/// Errors that can occur during a file transfer operation
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FileError {
+    /// Failed b/c of a generic task execution error
+    Task(TaskError),
+    /// Outstation returned an IIN.2 error instead of a file-control object
+    IinError(Iin2),
+    /// Outstation indicated that the operation was not successful for the specified reason
+    BadStatus(FileStatus),
+    /// Outstation did not grant an authentication key for the supplied credentials
+    NoPermission,
+    /// Outstation returned a file handle different from the one used in the request
+    WrongHandle,
+    /// Outstation returned a block number other than the expected one
+    BadBlockNum,
+    /// The file exceeds the configured maximum length
+    MaxLengthExceeded,
+}
+
 impl std::fmt::Display for AssociationError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
@@ -218,6 +277,31 @@ impl std::fmt::Display for TaskError {
             TaskError::Disabled => f.write_str("the master was disabled while executing the task"),
             TaskError::NoConnection => f.write_str("no connection"),
             TaskError::NoSuchAssociation(x) => write!(f, "no association with address: {}", x),
+            TaskError::Authentication(err) => write!(f, "authentication failed: {}", err),
+        }
+    }
+}
+
+impl std::fmt::Display for AuthenticationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AuthenticationError::NotConfigured => {
+                f.write_str("secure authentication is not configured on the association")
+            }
+            AuthenticationError::NoSessionKeys => f.write_str("no valid session keys"),
+            AuthenticationError::ErrorResponse(code) => {
+                write!(f, "outstation returned an authentication error: {:?}", code)
+            }
+            AuthenticationError::UnexpectedResponse => {
+                f.write_str("unexpected authentication object in the response")
+            }
+            AuthenticationError::InvalidMac => f.write_str("invalid MAC in the key status"),
+            AuthenticationError::BadKeyStatus(status) => {
+                write!(f, "outstation reported key status: {:?}", status)
+            }
+            AuthenticationError::UnsupportedAlgorithm => {
+                f.write_str("outstation requested an unsupported algorithm")
+            }
         }
     }
 }
@@ -243,12 +327,68 @@ impl std::fmt::Display for TimeSyncError {
     }
 }
 
+impl std::fmt::Display for WriteRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WriteRequestError::Task(err) => write!(f, "{}", err),
+            WriteRequestError::IinError(iin2) => {
+                write!(f, "outstation indicated an error: {}", iin2)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for CustomRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CustomRequestError::Task(err) => write!(f, "{}", err),
+            CustomRequestError::InvalidFunction(function) => {
+                write!(
+                    f,
+                    "function code may not be used in a request: {:?}",
+                    function
+                )
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for FileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            FileError::Task(err) => write!(f, "{}", err),
+            FileError::IinError(iin2) => write!(f, "outstation indicated an error: {}", iin2),
+            FileError::BadStatus(status) => write!(
+                f,
+                "file status value other than Success was returned: {:?}",
+                status
+            ),
+            FileError::NoPermission => {
+                f.write_str("outstation did not grant permission for the supplied credentials")
+            }
+            FileError::WrongHandle => {
+                f.write_str("outstation returned a file handle that doesn't match the request")
+            }
+            FileError::BadBlockNum => f.write_str("outstation returned an unexpected block number"),
+            FileError::MaxLengthExceeded => {
+                f.write_str("file exceeds the configured maximum length")
+            }
+        }
+    }
+}
+
 impl From<WriteError> for TaskError {
     fn from(_: WriteError) -> Self {
         TaskError::WriteError
     }
 }
 
+impl From<AuthenticationError> for TaskError {
+    fn from(err: AuthenticationError) -> Self {
+        TaskError::Authentication(err)
+    }
+}
+
 impl From<LinkError> for TaskError {
     fn from(err: LinkError) -> Self {
         TaskError::Link(err)
@@ -313,6 +453,24 @@ impl From<TaskError> for TimeSyncError {
     }
 }
 
+impl From<TaskError> for WriteRequestError {
+    fn from(err: TaskError) -> Self {
+        WriteRequestError::Task(err)
+    }
+}
+
+impl From<TaskError> for CustomRequestError {
+    fn from(err: TaskError) -> Self {
+        CustomRequestError::Task(err)
+    }
+}
+
+impl From<TaskError> for FileError {
+    fn from(err: TaskError) -> Self {
+        FileError::Task(err)
+    }
+}
+
 impl From<RecvError> for AssociationError {
     fn from(_: RecvError) -> Self {
         AssociationError::Shutdown
@@ -346,6 +504,24 @@ impl From<RecvError> for TimeSyncError {
     }
 }
 
+impl From<RecvError> for WriteRequestError {
+    fn from(_: RecvError) -> Self {
+        WriteRequestError::Task(TaskError::Shutdown)
+    }
+}
+
+impl From<RecvError> for CustomRequestError {
+    fn from(_: RecvError) -> Self {
+        CustomRequestError::Task(TaskError::Shutdown)
+    }
+}
+
+impl From<RecvError> for FileError {
+    fn from(_: RecvError) -> Self {
+        FileError::Task(TaskError::Shutdown)
+    }
+}
+
 impl<T> From<SendError<T>> for Shutdown {
     fn from(_: SendError<T>) -> Self {
         Shutdown
@@ -376,6 +552,24 @@ impl From<Shutdown> for TimeSyncError {
     }
 }
 
+impl From<Shutdown> for WriteRequestError {
+    fn from(_: Shutdown) -> Self {
+        WriteRequestError::Task(TaskError::Shutdown)
+    }
+}
+
+impl From<Shutdown> for CustomRequestError {
+    fn from(_: Shutdown) -> Self {
+        CustomRequestError::Task(TaskError::Shutdown)
+    }
+}
+
+impl From<Shutdown> for FileError {
+    fn from(_: Shutdown) -> Self {
+        FileError::Task(TaskError::Shutdown)
+    }
+}
+
 impl From<Shutdown> for PollError {
     fn from(_: Shutdown) -> Self {
         PollError::Shutdown
@@ -384,7 +578,10 @@ impl From<Shutdown> for PollError {
 
 impl Error for AssociationError {}
 impl Error for TaskError {}
+impl Error for AuthenticationError {}
 impl Error for PollError {}
 impl Error for CommandError {}
 impl Error for CommandResponseError {}
 impl Error for TimeSyncError {}
+impl Error for CustomRequestError {}
+impl Error for FileError {}
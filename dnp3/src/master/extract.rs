@@ -1,10 +1,29 @@
 use crate::app::gen::count::CountVariation;
+use crate::app::gen::prefixed::PrefixedVariation;
 use crate::app::measurement::*;
 use crate::app::parse::parser::{HeaderCollection, HeaderDetails, ObjectHeader};
 use crate::app::variations::*;
 use crate::app::ResponseHeader;
+use crate::master::cache::{CachingHandler, SharedCache};
+use crate::master::data_set::DataSetRegistry;
 use crate::master::handler::ReadHandler;
 use crate::master::ReadType;
+use crate::util::virtual_terminal::VirtualTerminalPorts;
+
+/// Per-association state used to extract objects that aren't simply passed to the `ReadHandler`
+#[derive(Default)]
+pub(crate) struct ExtractState {
+    pub(crate) data_sets: DataSetRegistry,
+    pub(crate) virtual_terminals: VirtualTerminalPorts,
+    pub(crate) cache: Option<SharedCache>,
+}
+
+impl ExtractState {
+    fn deliver_virtual_terminal_output(&mut self, port: u16, data: &[u8]) {
+        // blocks that can't be delivered are logged and discarded
+        self.virtual_terminals.deliver(port, data);
+    }
+}
 
 /// Extract measurements from a HeaderCollection, sinking them into
 /// something that implements `MeasurementHandler`
@@ -12,10 +31,18 @@ pub(crate) async fn extract_measurements(
     read_type: ReadType,
     header: ResponseHeader,
     objects: HeaderCollection<'_>,
+    state: &mut ExtractState,
     handler: &mut dyn ReadHandler,
 ) {
     handler.begin_fragment(read_type, header).get().await;
-    extract_measurements_inner(objects, handler);
+    match state.cache.clone() {
+        Some(cache) => {
+            let mut caching = CachingHandler::new(handler);
+            extract_measurements_inner(objects, state, &mut caching);
+            cache.apply(read_type, caching.into_received());
+        }
+        None => extract_measurements_inner(objects, state, handler),
+    }
     handler.end_fragment(read_type, header).get().await;
 }
 
@@ -23,6 +50,7 @@ pub(crate) async fn extract_measurements(
 /// something that implements `MeasurementHandler`
 pub(crate) fn extract_measurements_inner(
     objects: HeaderCollection<'_>,
+    state: &mut ExtractState,
     handler: &mut dyn ReadHandler,
 ) {
     fn extract_cto_g51v1(prev: Option<Time>, item: Option<Group51Var1>) -> Option<Time> {
@@ -36,6 +64,7 @@ pub(crate) fn extract_measurements_inner(
     fn handle(
         cto: Option<Time>,
         header: ObjectHeader,
+        state: &mut ExtractState,
         handler: &mut dyn ReadHandler,
     ) -> Option<Time> {
         let handled = match &header.details {
@@ -52,6 +81,19 @@ pub(crate) fn extract_measurements_inner(
             HeaderDetails::TwoByteCount(1, CountVariation::Group51Var2(seq)) => {
                 return extract_cto_g51v2(cto, seq.single())
             }
+            // virtual terminal output is routed to the stream opened for the port
+            HeaderDetails::OneByteCountAndPrefix(_, PrefixedVariation::Group113VarX(_, seq)) => {
+                for (data, port) in seq.iter() {
+                    state.deliver_virtual_terminal_output(port.into(), data);
+                }
+                true
+            }
+            HeaderDetails::TwoByteCountAndPrefix(_, PrefixedVariation::Group113VarX(_, seq)) => {
+                for (data, port) in seq.iter() {
+                    state.deliver_virtual_terminal_output(port, data);
+                }
+                true
+            }
             // everything else
             HeaderDetails::OneByteStartStop(_, _, var) => {
                 var.extract_measurements_to(header.details.qualifier(), handler)
@@ -65,6 +107,11 @@ pub(crate) fn extract_measurements_inner(
             HeaderDetails::TwoByteCountAndPrefix(_, var) => {
                 var.extract_measurements_to(cto, handler)
             }
+            HeaderDetails::TwoByteFreeFormat(_, var) => {
+                state
+                    .data_sets
+                    .extract(var, header.details.qualifier(), handler)
+            }
             _ => false,
         };
 
@@ -82,7 +129,7 @@ pub(crate) fn extract_measurements_inner(
 
     objects
         .iter()
-        .fold(None, |cto, header| handle(cto, header, handler));
+        .fold(None, |cto, header| handle(cto, header, state, handler));
 }
 
 #[cfg(test)]
@@ -90,6 +137,8 @@ mod test {
     use crate::app::parse::parser::HeaderCollection;
     use crate::app::*;
     use crate::master::handler::{HeaderInfo, ReadHandler};
+    use crate::master::{CacheUpdate, MeasurementType};
+    use crate::util::virtual_terminal::virtual_terminal_channel;
 
     use super::*;
 
@@ -104,6 +153,9 @@ mod test {
     #[derive(Debug)]
     enum Header {
         Binary(Vec<(BinaryInput, u16)>),
+        DataSet(bool, DataSet),
+        UnsignedInteger(Vec<(UnsignedInteger, u16)>),
+        Bcd(Vec<(Bcd, u16)>),
     }
 
     struct MockHandler {
@@ -156,7 +208,42 @@ mod test {
                 Header::Binary(expected) => {
                     let received: Vec<_> = x.collect();
                     assert_eq!(received, expected);
-                } //x => panic!("Unexpected header: {:?}", x)
+                }
+                x => panic!("Unexpected header: {:?}", x),
+            }
+        }
+
+        fn handle_data_set(&mut self, info: HeaderInfo, data_set: &DataSet) {
+            match self.expected.pop() {
+                Some(Header::DataSet(is_event, expected)) => {
+                    assert_eq!(info.is_event, is_event);
+                    assert_eq!(data_set, &expected);
+                }
+                x => panic!("Unexpected header: {:?}", x),
+            }
+        }
+
+        fn handle_unsigned_integer(
+            &mut self,
+            _info: HeaderInfo,
+            x: &mut dyn Iterator<Item = (UnsignedInteger, u16)>,
+        ) {
+            match self.expected.pop() {
+                Some(Header::UnsignedInteger(expected)) => {
+                    let received: Vec<_> = x.collect();
+                    assert_eq!(received, expected);
+                }
+                x => panic!("Unexpected header: {:?}", x),
+            }
+        }
+
+        fn handle_bcd(&mut self, _info: HeaderInfo, x: &mut dyn Iterator<Item = (Bcd, u16)>) {
+            match self.expected.pop() {
+                Some(Header::Bcd(expected)) => {
+                    let received: Vec<_> = x.collect();
+                    assert_eq!(received, expected);
+                }
+                x => panic!("Unexpected header: {:?}", x),
             }
         }
 
@@ -236,7 +323,7 @@ mod test {
         );
 
         handler.expect(Header::Binary(vec![expected]));
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut ExtractState::default(), &mut handler);
         assert!(handler.is_empty());
     }
 
@@ -263,7 +350,7 @@ mod test {
         );
 
         handler.expect(Header::Binary(vec![expected]));
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut ExtractState::default(), &mut handler);
         assert!(handler.is_empty());
     }
 
@@ -290,7 +377,46 @@ mod test {
         );
 
         handler.expect(Header::Binary(vec![expected]));
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut ExtractState::default(), &mut handler);
+        assert!(handler.is_empty());
+    }
+
+    #[test]
+    fn g102v1_yields_unsigned_integers() {
+        let mut handler = MockHandler::new();
+        let objects = HeaderCollection::parse(
+            FunctionCode::Response,
+            // g102v1  start: 3  stop: 4  values: 7, 255
+            &[0x66, 0x01, 0x00, 0x03, 0x04, 0x07, 0xFF],
+        )
+        .unwrap();
+
+        handler.expect(Header::UnsignedInteger(vec![
+            (UnsignedInteger::new(7), 3),
+            (UnsignedInteger::new(255), 4),
+        ]));
+        extract_measurements_inner(objects, &mut ExtractState::default(), &mut handler);
+        assert!(handler.is_empty());
+    }
+
+    #[test]
+    fn g101_variations_yield_decoded_bcd_values() {
+        let mut handler = MockHandler::new();
+        let objects = HeaderCollection::parse(
+            FunctionCode::Response,
+            &[
+                // g101v1  start: 0  stop: 0  value: 0x1234
+                0x65, 0x01, 0x00, 0x00, 0x00, 0x34, 0x12,
+                // g101v2  start: 1  stop: 1  value: 0x87654321
+                0x65, 0x02, 0x00, 0x01, 0x01, 0x21, 0x43, 0x65, 0x87,
+            ],
+        )
+        .unwrap();
+
+        // headers are popped from the back
+        handler.expect(Header::Bcd(vec![(Bcd::new(87654321), 1)]));
+        handler.expect(Header::Bcd(vec![(Bcd::new(1234), 0)]));
+        extract_measurements_inner(objects, &mut ExtractState::default(), &mut handler);
         assert!(handler.is_empty());
     }
 
@@ -317,7 +443,7 @@ mod test {
         );
 
         handler.expect(Header::Binary(vec![expected]));
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut ExtractState::default(), &mut handler);
         assert!(handler.is_empty());
     }
 
@@ -344,7 +470,106 @@ mod test {
         );
 
         handler.expect(Header::Binary(vec![expected]));
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut ExtractState::default(), &mut handler);
+        assert!(handler.is_empty());
+    }
+
+    #[test]
+    fn data_set_values_are_typed_using_previous_descriptor() {
+        let mut handler = MockHandler::new();
+        let mut state = ExtractState::default();
+
+        let descriptor = HeaderCollection::parse(
+            FunctionCode::Response,
+            // g86v1 free-format, id: 1, data element "v" of type signed integer
+            &[
+                86, 0x01, 0x5B, 0x01, 0x08, 0x00, 0x02, 0x01, 0x00, 0x04, 0x05, 0x03, 0x04, b'v',
+            ],
+        )
+        .unwrap();
+        extract_measurements_inner(descriptor, &mut state, &mut handler);
+
+        let event = HeaderCollection::parse(
+            FunctionCode::Response,
+            // g88v1 free-format, id: 1, time: 5, value: -2
+            &[
+                88, 0x01, 0x5B, 0x01, 0x0F, 0x00, 0x02, 0x01, 0x00, 0x06, 0x05, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x04, 0xFE, 0xFF, 0xFF, 0xFF,
+            ],
+        )
+        .unwrap();
+        handler.expect(Header::DataSet(
+            true,
+            DataSet::new(1, Timestamp::new(5), vec![DataSetValue::SignedInt(-2)]),
+        ));
+        extract_measurements_inner(event, &mut state, &mut handler);
         assert!(handler.is_empty());
     }
+
+    #[test]
+    fn virtual_terminal_output_is_routed_to_open_port() {
+        let mut handler = MockHandler::new();
+        let mut state = ExtractState::default();
+        let (tx, mut rx) = virtual_terminal_channel();
+        state.virtual_terminals.open(3, tx);
+
+        let objects = HeaderCollection::parse(
+            FunctionCode::Response,
+            // g113v2 with 2-byte count and prefix, ports 3 and 4
+            &[
+                113, 0x02, 0x28, 0x02, 0x00, 0x03, 0x00, b'o', b'k', 0x04, 0x00, b'n', b'o',
+            ],
+        )
+        .unwrap();
+        extract_measurements_inner(objects, &mut state, &mut handler);
+
+        assert_eq!(rx.try_recv().unwrap(), b"ok".to_vec());
+        assert!(rx.try_recv().is_err());
+        assert!(handler.is_empty());
+    }
+
+    #[tokio::test]
+    async fn cache_records_values_and_notifies_subscribers_of_changes() {
+        let mut handler = MockHandler::new();
+        let cache = SharedCache::new();
+        let mut updates = cache.subscribe();
+        let mut state = ExtractState {
+            cache: Some(cache.clone()),
+            ..ExtractState::default()
+        };
+        // g102v1  start: 3  stop: 4  values: 7, 255
+        let bytes = &[0x66, 0x01, 0x00, 0x03, 0x04, 0x07, 0xFF];
+        let values = vec![(UnsignedInteger::new(7), 3), (UnsignedInteger::new(255), 4)];
+
+        for _ in 0..2 {
+            handler.expect(Header::UnsignedInteger(values.clone()));
+            let objects = HeaderCollection::parse(FunctionCode::Response, bytes).unwrap();
+            extract_measurements(
+                ReadType::PeriodicPoll,
+                header(),
+                objects,
+                &mut state,
+                &mut handler,
+            )
+            .await;
+            assert!(handler.is_empty());
+        }
+
+        assert_eq!(
+            cache.read(|x| x.unsigned_integer.get(&4).copied()),
+            Some(UnsignedInteger::new(255))
+        );
+        // the second fragment didn't change any values
+        assert_eq!(
+            updates.try_recv().unwrap(),
+            CacheUpdate {
+                read_type: ReadType::PeriodicPoll,
+                changes: vec![
+                    (MeasurementType::UnsignedInteger, 3),
+                    (MeasurementType::UnsignedInteger, 4)
+                ],
+            }
+        );
+        assert!(updates.try_recv().is_err());
+    }
 }
@@ -0,0 +1,168 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::app::Permissions;
+use crate::master::error::{FileError, TaskError};
+
+/// User name and password used to obtain an authentication key before opening a file
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileCredentials {
+    /// user name
+    pub user_name: String,
+    /// password
+    pub password: String,
+}
+
+impl FileCredentials {
+    /// construct credentials from a user name and password
+    pub fn new<S: ToString>(user_name: S, password: S) -> Self {
+        Self {
+            user_name: user_name.to_string(),
+            password: password.to_string(),
+        }
+    }
+}
+
+/// Configuration used when reading a file or directory from the outstation
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FileReadConfig {
+    /// maximum block size requested from the outstation
+    pub max_block_size: u16,
+    /// maximum number of bytes that may be read before the transfer is failed
+    pub max_file_size: usize,
+    /// maximum number of blocks queued on a [FileReadStream] before the master stops reading
+    /// the file, with a minimum of 1 (not used when reading directories)
+    pub max_queued_blocks: usize,
+}
+
+impl FileReadConfig {
+    /// default maximum block size
+    pub const DEFAULT_MAX_BLOCK_SIZE: u16 = 1024;
+    /// default maximum file size (16 MiB)
+    pub const DEFAULT_MAX_FILE_SIZE: usize = 16 * 1024 * 1024;
+    /// default maximum number of queued blocks
+    pub const DEFAULT_MAX_QUEUED_BLOCKS: usize = 16;
+}
+
+impl Default for FileReadConfig {
+    fn default() -> Self {
+        Self {
+            max_block_size: Self::DEFAULT_MAX_BLOCK_SIZE,
+            max_file_size: Self::DEFAULT_MAX_FILE_SIZE,
+            max_queued_blocks: Self::DEFAULT_MAX_QUEUED_BLOCKS,
+        }
+    }
+}
+
+/// Configuration used when writing a file to the outstation
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FileWriteConfig {
+    /// maximum block size used to transfer the file
+    ///
+    /// The actual block size is the smaller of this value and the value returned by the outstation
+    pub max_block_size: u16,
+    /// permissions assigned to the file if it is created
+    pub permissions: Permissions,
+    /// if true, the data is appended to the existing file instead of replacing it
+    pub append: bool,
+}
+
+impl Default for FileWriteConfig {
+    fn default() -> Self {
+        Self {
+            max_block_size: FileReadConfig::DEFAULT_MAX_BLOCK_SIZE,
+            permissions: Permissions::default(),
+            append: false,
+        }
+    }
+}
+
+/// Sending half of a [FileReadStream] used by the file task
+pub(crate) struct FileReadSender {
+    blocks: tokio::sync::mpsc::Sender<Vec<u8>>,
+    result: tokio::sync::oneshot::Sender<Result<(), FileError>>,
+}
+
+impl FileReadSender {
+    /// wait until the stream has room for the block, returning false if the stream was dropped
+    pub(crate) async fn send(&self, block: Vec<u8>) -> bool {
+        self.blocks.send(block).await.is_ok()
+    }
+
+    /// the result is received once the blocks already queued have been read
+    pub(crate) fn complete(self, result: Result<(), FileError>) {
+        self.result.send(result).ok();
+    }
+}
+
+/// Stream of blocks received while reading a file from the outstation
+///
+/// Blocks are queued on a bounded channel whose capacity is set by [FileReadConfig::max_queued_blocks].
+/// When the queue is full, the master doesn't request the next block until the stream is read,
+/// which also pauses the other tasks of the channel. The stream yields an error if the transfer
+/// fails and ends once the entire file has been read and the file has been closed.
+///
+/// Dropping the stream before the transfer completes causes the master to abort the transfer.
+pub struct FileReadStream {
+    blocks: tokio::sync::mpsc::Receiver<Vec<u8>>,
+    result: Option<tokio::sync::oneshot::Receiver<Result<(), FileError>>>,
+}
+
+impl FileReadStream {
+    pub(crate) fn new(capacity: usize) -> (Self, FileReadSender) {
+        let (blocks_tx, blocks_rx) = tokio::sync::mpsc::channel(capacity.max(1));
+        let (result_tx, result_rx) = tokio::sync::oneshot::channel();
+        (
+            Self {
+                blocks: blocks_rx,
+                result: Some(result_rx),
+            },
+            FileReadSender {
+                blocks: blocks_tx,
+                result: result_tx,
+            },
+        )
+    }
+
+    /// Retrieve the next block of file data
+    ///
+    /// Returns `Ok(None)` once the entire file has been read and the file has been closed
+    pub async fn next(&mut self) -> Result<Option<Vec<u8>>, FileError> {
+        futures::StreamExt::next(self).await.transpose()
+    }
+
+    /// Read the remainder of the file into a single buffer
+    pub async fn read_to_end(mut self) -> Result<Vec<u8>, FileError> {
+        let mut data = Vec::new();
+        while let Some(block) = self.next().await? {
+            data.extend(block);
+        }
+        Ok(data)
+    }
+}
+
+impl futures::Stream for FileReadStream {
+    type Item = Result<Vec<u8>, FileError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        let result = match this.result.as_mut() {
+            Some(x) => x,
+            None => return Poll::Ready(None),
+        };
+
+        if let Some(block) = futures::ready!(this.blocks.poll_recv(cx)) {
+            return Poll::Ready(Some(Ok(block)));
+        }
+
+        // every block has been read, so report the outcome of the transfer
+        let outcome = futures::ready!(Pin::new(result).poll(cx));
+        this.result = None;
+        match outcome {
+            Ok(Ok(())) => Poll::Ready(None),
+            Ok(Err(err)) => Poll::Ready(Some(Err(err))),
+            Err(_) => Poll::Ready(Some(Err(FileError::Task(TaskError::Shutdown)))),
+        }
+    }
+}
@@ -6,16 +6,35 @@ use crate::app::*;
 use crate::decode::DecodeLevel;
 use crate::link::EndpointAddress;
 use crate::master::association::AssociationConfig;
-use crate::master::error::{AssociationError, CommandError, PollError, TaskError, TimeSyncError};
+use crate::master::cache::{CacheUpdate, MeasurementCache, SharedCache};
+use crate::master::error::{
+    AssociationError, CommandError, CustomRequestError, FileError, PollError, TaskError,
+    TimeSyncError, WriteRequestError,
+};
+use crate::master::file::{FileCredentials, FileReadConfig, FileReadStream, FileWriteConfig};
 use crate::master::messages::{AssociationMsg, AssociationMsgType, MasterMsg, Message};
 use crate::master::poll::{PollHandle, PollMsg};
-use crate::master::request::{CommandHeaders, CommandMode, ReadRequest, TimeSyncProcedure};
+use crate::master::request::{
+    AnalogInputDeadbands, AssignClassRequest, CommandHeaders, CommandMode, CustomRequest,
+    EventClasses, FreezeRequest, ReadRequest, TimeSyncProcedure, WriteRequest,
+};
+use crate::master::scheduler::{TaskPriority, TaskScheduler};
+use crate::master::tasks::activate_config::ActivateConfigTask;
+use crate::master::tasks::application::{ApplicationOperation, ApplicationTask};
+use crate::master::tasks::assign_class::AssignClassTask;
 use crate::master::tasks::command::CommandTask;
+use crate::master::tasks::custom::CustomTask;
+use crate::master::tasks::file::{FileOperation, FileTask, ReadSink};
+use crate::master::tasks::freeze::FreezeTask;
 use crate::master::tasks::read::SingleReadTask;
 use crate::master::tasks::restart::{RestartTask, RestartType};
 use crate::master::tasks::time::TimeSyncTask;
+use crate::master::tasks::unsolicited::UnsolicitedTask;
+use crate::master::tasks::write::WriteTask;
 use crate::master::tasks::Task;
+use crate::master::virtual_terminal::VirtualTerminal;
 use crate::util::channel::Sender;
+use crate::util::virtual_terminal::virtual_terminal_channel;
 
 /// Handle to a master communication channel. This handle controls
 /// a task running on the Tokio Runtime.
@@ -32,6 +51,7 @@ pub struct MasterChannel {
 pub struct AssociationHandle {
     address: EndpointAddress,
     master: MasterChannel,
+    cache: Option<SharedCache>,
 }
 
 /// Configuration for a MasterChannel
@@ -89,6 +109,19 @@ impl MasterChannel {
         Ok(())
     }
 
+    /// Replace the policy used to prioritize the tasks of every association on this channel
+    ///
+    /// The [DefaultScheduler](crate::master::DefaultScheduler) is used until this is called. See
+    /// [TaskScheduler] for the order in which tasks run.
+    pub async fn set_scheduler(
+        &mut self,
+        scheduler: Box<dyn TaskScheduler>,
+    ) -> Result<(), Shutdown> {
+        self.send_master_message(MasterMsg::SetScheduler(scheduler))
+            .await?;
+        Ok(())
+    }
+
     /// Get the current decoding level used by this master
     pub async fn get_decode_level(&mut self) -> Result<DecodeLevel, Shutdown> {
         let (tx, rx) = tokio::sync::oneshot::channel::<Result<DecodeLevel, Shutdown>>();
@@ -109,7 +142,8 @@ impl MasterChannel {
         assoc_handler: Box<dyn AssociationHandler>,
         assoc_information: Box<dyn AssociationInformation>,
     ) -> Result<AssociationHandle, AssociationError> {
-        let (tx, rx) = tokio::sync::oneshot::channel::<Result<(), AssociationError>>();
+        let (tx, rx) =
+            tokio::sync::oneshot::channel::<Result<Option<SharedCache>, AssociationError>>();
         self.send_master_message(MasterMsg::AddAssociation(
             address,
             config,
@@ -120,7 +154,7 @@ impl MasterChannel {
         ))
         .await?;
         rx.await?
-            .map(|_| (AssociationHandle::new(address, self.clone())))
+            .map(|cache| AssociationHandle::new(address, self.clone(), cache))
     }
 
     /// Remove an association
@@ -155,11 +189,19 @@ impl AssociationHandle {
     #[doc(hidden)]
     #[cfg(feature = "ffi")]
     pub fn create(address: EndpointAddress, master: MasterChannel) -> Self {
-        Self::new(address, master)
+        Self::new(address, master, None)
     }
 
-    pub(crate) fn new(address: EndpointAddress, master: MasterChannel) -> Self {
-        Self { address, master }
+    pub(crate) fn new(
+        address: EndpointAddress,
+        master: MasterChannel,
+        cache: Option<SharedCache>,
+    ) -> Self {
+        Self {
+            address,
+            master,
+            cache,
+        }
     }
 
     /// retrieve the outstation address of the association
@@ -167,6 +209,29 @@ impl AssociationHandle {
         self.address
     }
 
+    /// Query the last known value of measurements received from the outstation
+    ///
+    /// The closure is invoked while the cache is locked, so it should return quickly. Returns
+    /// `None` if [AssociationConfig::measurement_cache] was not enabled.
+    pub fn read_cache<R>(&self, func: impl FnOnce(&MeasurementCache) -> R) -> Option<R> {
+        self.cache.as_ref().map(|cache| cache.read(func))
+    }
+
+    /// Retrieve a copy of the last known value of every measurement received from the outstation
+    ///
+    /// Returns `None` if [AssociationConfig::measurement_cache] was not enabled.
+    pub fn get_cache_snapshot(&self) -> Option<MeasurementCache> {
+        self.read_cache(|cache| cache.clone())
+    }
+
+    /// Subscribe to notifications of changes to the measurement cache
+    ///
+    /// A [CacheUpdate] is published after each response fragment that changes at least one cached
+    /// value. Returns `None` if [AssociationConfig::measurement_cache] was not enabled.
+    pub fn subscribe_to_cache(&self) -> Option<tokio::sync::broadcast::Receiver<CacheUpdate>> {
+        self.cache.as_ref().map(|cache| cache.subscribe())
+    }
+
     /// Add a poll to the association
     /// * `request` defines what data is being requested
     /// * `period` defines how often the READ operation is performed
@@ -204,6 +269,21 @@ impl AssociationHandle {
         rx.await?
     }
 
+    /// Perform an asynchronous READ request with the specified priority
+    ///
+    /// The priority overrides the one assigned by the channel's [TaskScheduler]
+    pub async fn read_with_priority(
+        &mut self,
+        request: ReadRequest,
+        priority: TaskPriority,
+    ) -> Result<(), TaskError> {
+        let (tx, rx) = tokio::sync::oneshot::channel::<Result<(), TaskError>>();
+        let task = SingleReadTask::new(request, Promise::OneShot(tx));
+        self.send_task_with_priority(task.wrap().wrap(), Some(priority))
+            .await?;
+        rx.await?
+    }
+
     /// Perform an asynchronous READ request with a custom read handler
     ///
     /// If successful, the custom [ReadHandler](crate::master::ReadHandler) will process the received measurement data
@@ -232,6 +312,22 @@ impl AssociationHandle {
         rx.await?
     }
 
+    /// Perform an asynchronous operate request with the specified priority
+    ///
+    /// The priority overrides the one assigned by the channel's [TaskScheduler]
+    pub async fn operate_with_priority(
+        &mut self,
+        mode: CommandMode,
+        headers: CommandHeaders,
+        priority: TaskPriority,
+    ) -> Result<(), CommandError> {
+        let (tx, rx) = tokio::sync::oneshot::channel::<Result<(), CommandError>>();
+        let task = CommandTask::from_mode(mode, headers, Promise::OneShot(tx));
+        self.send_task_with_priority(task.wrap().wrap(), Some(priority))
+            .await?;
+        rx.await?
+    }
+
     /// Perform a WARM_RESTART operation
     ///
     /// Returns the delay from the outstation's response as a [Duration](std::time::Duration)
@@ -263,6 +359,276 @@ impl AssociationHandle {
         rx.await?
     }
 
+    /// Write one or more object headers to the outstation using the WRITE function code
+    ///
+    /// Fails with [WriteRequestError::IinError] if the outstation rejects the request
+    pub async fn write(&mut self, request: WriteRequest) -> Result<(), WriteRequestError> {
+        let (tx, rx) = tokio::sync::oneshot::channel::<Result<(), WriteRequestError>>();
+        let task = WriteTask::new(request, Promise::OneShot(tx));
+        self.send_task(task.wrap().wrap()).await?;
+        rx.await?
+    }
+
+    /// Write the deadbands of one or more analog inputs (group 34)
+    ///
+    /// Fails with [WriteRequestError::IinError] if the outstation rejects the request
+    pub async fn write_analog_input_deadbands(
+        &mut self,
+        deadbands: AnalogInputDeadbands,
+    ) -> Result<(), WriteRequestError> {
+        self.write(deadbands.into()).await
+    }
+
+    /// Write indexed absolute times and long intervals (g50v4) as a list of (value, index) pairs
+    ///
+    /// These are typically used to configure the freeze schedule of individual counters.
+    ///
+    /// Fails with [WriteRequestError::IinError] if the outstation rejects the request
+    pub async fn write_time_and_interval(
+        &mut self,
+        values: &[(TimeAndInterval, u16)],
+    ) -> Result<(), WriteRequestError> {
+        let mut request = WriteRequest::new();
+        request.add_time_and_interval(values);
+        self.write(request).await
+    }
+
+    /// Assign the event class of points on the outstation using the ASSIGN_CLASS function code
+    ///
+    /// Fails with [WriteRequestError::IinError] if the outstation rejects the request
+    pub async fn assign_class(
+        &mut self,
+        request: AssignClassRequest,
+    ) -> Result<(), WriteRequestError> {
+        let (tx, rx) = tokio::sync::oneshot::channel::<Result<(), WriteRequestError>>();
+        let task = AssignClassTask::new(request, Promise::OneShot(tx));
+        self.send_task(task.wrap().wrap()).await?;
+        rx.await?
+    }
+
+    /// Enable unsolicited reporting of the specified event classes using ENABLE_UNSOLICITED
+    ///
+    /// Fails with [WriteRequestError::IinError] if the outstation rejects the request. On success,
+    /// the classes are remembered by the association and enabled again whenever the outstation
+    /// restarts, in addition to [AssociationConfig::enable_unsol_classes].
+    pub async fn enable_unsolicited(
+        &mut self,
+        classes: EventClasses,
+    ) -> Result<(), WriteRequestError> {
+        let (tx, rx) = tokio::sync::oneshot::channel::<Result<(), WriteRequestError>>();
+        let task = UnsolicitedTask::enable(classes, Promise::OneShot(tx));
+        self.send_task(task.wrap().wrap()).await?;
+        rx.await?
+    }
+
+    /// Disable unsolicited reporting of the specified event classes using DISABLE_UNSOLICITED
+    ///
+    /// Fails with [WriteRequestError::IinError] if the outstation rejects the request. On success,
+    /// the classes are remembered by the association and disabled again whenever the outstation
+    /// restarts, until they are re-enabled using [AssociationHandle::enable_unsolicited].
+    pub async fn disable_unsolicited(
+        &mut self,
+        classes: EventClasses,
+    ) -> Result<(), WriteRequestError> {
+        let (tx, rx) = tokio::sync::oneshot::channel::<Result<(), WriteRequestError>>();
+        let task = UnsolicitedTask::disable(classes, Promise::OneShot(tx));
+        self.send_task(task.wrap().wrap()).await?;
+        rx.await?
+    }
+
+    /// Freeze counters using IMMEDIATE_FREEZE, FREEZE_CLEAR, FREEZE_AT_TIME or
+    /// their NO_ACK variants
+    ///
+    /// Fails with [WriteRequestError::IinError] if the outstation rejects the request. Requests
+    /// using a NO_ACK function code complete successfully as soon as they are sent.
+    pub async fn freeze(&mut self, request: FreezeRequest) -> Result<(), WriteRequestError> {
+        let (tx, rx) = tokio::sync::oneshot::channel::<Result<(), WriteRequestError>>();
+        let task = FreezeTask::new(request, Promise::OneShot(tx));
+        self.send_task(task.wrap().wrap()).await?;
+        rx.await?
+    }
+
+    /// Prepare one or all applications on the outstation to be started using INITIALIZE_APPLICATION
+    ///
+    /// Fails with [WriteRequestError::IinError] if the outstation rejects the request
+    pub async fn initialize_application(
+        &mut self,
+        application: ApplicationIdentifier,
+    ) -> Result<(), WriteRequestError> {
+        self.control_application(ApplicationOperation::Initialize, application)
+            .await
+    }
+
+    /// Start one or all applications on the outstation using START_APPLICATION
+    ///
+    /// Fails with [WriteRequestError::IinError] if the outstation rejects the request
+    pub async fn start_application(
+        &mut self,
+        application: ApplicationIdentifier,
+    ) -> Result<(), WriteRequestError> {
+        self.control_application(ApplicationOperation::Start, application)
+            .await
+    }
+
+    /// Stop one or all applications on the outstation using STOP_APPLICATION
+    ///
+    /// Fails with [WriteRequestError::IinError] if the outstation rejects the request
+    pub async fn stop_application(
+        &mut self,
+        application: ApplicationIdentifier,
+    ) -> Result<(), WriteRequestError> {
+        self.control_application(ApplicationOperation::Stop, application)
+            .await
+    }
+
+    /// Activate configurations on the outstation using ACTIVATE_CONFIG
+    ///
+    /// Each configuration is referenced by a file specification string (g70v8) or an
+    /// octet string (g110). On success, returns the g91v1 status reported by the outstation.
+    ///
+    /// Fails with [WriteRequestError::IinError] if the outstation rejects the request
+    pub async fn activate_config(
+        &mut self,
+        configurations: Vec<ConfigurationReference>,
+    ) -> Result<ActivateConfigResponse, WriteRequestError> {
+        let (tx, rx) =
+            tokio::sync::oneshot::channel::<Result<ActivateConfigResponse, WriteRequestError>>();
+        let task = ActivateConfigTask::new(configurations, Promise::OneShot(tx));
+        self.send_task(task.wrap().wrap()).await?;
+        rx.await?
+    }
+
+    async fn control_application(
+        &mut self,
+        operation: ApplicationOperation,
+        application: ApplicationIdentifier,
+    ) -> Result<(), WriteRequestError> {
+        let (tx, rx) = tokio::sync::oneshot::channel::<Result<(), WriteRequestError>>();
+        let task = ApplicationTask::new(operation, application, Promise::OneShot(tx));
+        self.send_task(task.wrap().wrap()).await?;
+        rx.await?
+    }
+
+    /// Send a request with an arbitrary function code and object headers
+    ///
+    /// This is an escape hatch for non-standard function code or object combinations. The
+    /// request uses the same sequence number, confirmation, and multi-fragment handling as a READ.
+    /// Each response fragment, including its parsed object headers, is delivered to the `handler`.
+    /// Requests using a NO_ACK function code complete successfully as soon as they are sent.
+    ///
+    /// Fails with [CustomRequestError::InvalidFunction] if the function code is only valid in
+    /// a response or confirm.
+    pub async fn send_custom_request(
+        &mut self,
+        request: CustomRequest,
+        handler: Box<dyn CustomResponseHandler>,
+    ) -> Result<(), CustomRequestError> {
+        if !request.function().is_request() {
+            return Err(CustomRequestError::InvalidFunction(request.function()));
+        }
+
+        let (tx, rx) = tokio::sync::oneshot::channel::<Result<(), TaskError>>();
+        let task = CustomTask::new(request, handler, Promise::OneShot(tx));
+        self.send_task(task.wrap().wrap()).await?;
+        rx.await??;
+        Ok(())
+    }
+
+    /// Read a file from the outstation
+    ///
+    /// The file is opened (after optionally obtaining an authentication key using the supplied
+    /// credentials) and its blocks are delivered through the returned [FileReadStream] as they are
+    /// received. If the stream isn't read fast enough, the master waits for room in the stream before
+    /// requesting the next block. Dropping the stream before the transfer completes aborts the transfer.
+    pub async fn read_file<T: ToString>(
+        &mut self,
+        remote_file_path: T,
+        config: FileReadConfig,
+        credentials: Option<FileCredentials>,
+    ) -> Result<FileReadStream, FileError> {
+        let (stream, tx) = FileReadStream::new(config.max_queued_blocks);
+        let task = FileTask::new(
+            remote_file_path.to_string(),
+            credentials,
+            FileOperation::Read(ReadSink::Stream(tx), config),
+        );
+        self.send_task(task.wrap().wrap()).await?;
+        Ok(stream)
+    }
+
+    /// Write a file to the outstation
+    ///
+    /// The data is transferred in blocks no larger than the smaller of the configured
+    /// maximum block size and the block size returned by the outstation.
+    pub async fn write_file<T: ToString>(
+        &mut self,
+        remote_file_path: T,
+        data: Vec<u8>,
+        config: FileWriteConfig,
+        credentials: Option<FileCredentials>,
+    ) -> Result<(), FileError> {
+        let (tx, rx) = tokio::sync::oneshot::channel::<Result<(), FileError>>();
+        let task = FileTask::new(
+            remote_file_path.to_string(),
+            credentials,
+            FileOperation::Write(data, config, Promise::OneShot(tx)),
+        );
+        self.send_task(task.wrap().wrap()).await?;
+        rx.await?
+    }
+
+    /// Read the content of a directory on the outstation
+    ///
+    /// The directory is read like a file and its content is parsed as a sequence of file descriptors (g70v7)
+    pub async fn read_directory<T: ToString>(
+        &mut self,
+        remote_dir_path: T,
+        config: FileReadConfig,
+        credentials: Option<FileCredentials>,
+    ) -> Result<Vec<FileInfo>, FileError> {
+        let (tx, rx) = tokio::sync::oneshot::channel::<Result<Vec<FileInfo>, FileError>>();
+        let task = FileTask::new(
+            remote_dir_path.to_string(),
+            credentials,
+            FileOperation::Read(
+                ReadSink::Directory(Vec::new(), Promise::OneShot(tx)),
+                config,
+            ),
+        );
+        self.send_task(task.wrap().wrap()).await?;
+        rx.await?
+    }
+
+    /// Retrieve information about a file or directory on the outstation using GET_FILE_INFO
+    pub async fn get_file_info<T: ToString>(
+        &mut self,
+        remote_file_path: T,
+    ) -> Result<FileInfo, FileError> {
+        let (tx, rx) = tokio::sync::oneshot::channel::<Result<FileInfo, FileError>>();
+        let task = FileTask::new(
+            remote_file_path.to_string(),
+            None,
+            FileOperation::GetInfo(Promise::OneShot(tx)),
+        );
+        self.send_task(task.wrap().wrap()).await?;
+        rx.await?
+    }
+
+    /// Open a byte stream for the virtual terminal port (groups 112/113) with the specified index
+    ///
+    /// Opening a port that is already open ends the previous stream. Up to 32 blocks of output
+    /// are buffered, after which output from the outstation is discarded until the stream is read.
+    pub async fn open_virtual_terminal(&mut self, port: u16) -> Result<VirtualTerminal, Shutdown> {
+        let (tx, rx) = virtual_terminal_channel();
+        self.master
+            .send_association_message(
+                self.address,
+                AssociationMsgType::OpenVirtualTerminal(port, tx),
+            )
+            .await?;
+        Ok(VirtualTerminal::new(port, self.clone(), rx))
+    }
+
     /// Trigger the master to issue a REQUEST_LINK_STATUS function in advance of the link status timeout
     ///
     /// This function is provided for testing purposes. Using the configured link status timeout
@@ -278,8 +644,16 @@ impl AssociationHandle {
     }
 
     async fn send_task(&mut self, task: Task) -> Result<(), Shutdown> {
+        self.send_task_with_priority(task, None).await
+    }
+
+    async fn send_task_with_priority(
+        &mut self,
+        task: Task,
+        priority: Option<TaskPriority>,
+    ) -> Result<(), Shutdown> {
         self.master
-            .send_association_message(self.address, AssociationMsgType::QueueTask(task))
+            .send_association_message(self.address, AssociationMsgType::QueueTask(task, priority))
             .await
     }
 
@@ -327,14 +701,30 @@ pub enum TaskType {
     Command,
     /// Clear `RESTART` IIN bit
     ClearRestartBit,
-    /// Enable unsolicited startup request
+    /// Enable unsolicited request, during startup or initiated by the user
     EnableUnsolicited,
-    /// Disable unsolicited startup request
+    /// Disable unsolicited request, during startup or initiated by the user
     DisableUnsolicited,
     /// Time synchronisation task
     TimeSync,
     /// Cold or warm restart task
     Restart,
+    /// File transfer task
+    FileTransfer,
+    /// User-defined write request
+    Write,
+    /// Assign class request
+    AssignClass,
+    /// Freeze request
+    Freeze,
+    /// Initialize, start, or stop application request
+    ApplicationControl,
+    /// Activate configuration request
+    ActivateConfig,
+    /// User-defined request with an arbitrary function code
+    Custom,
+    /// Secure authentication session key change
+    SessionKeyChange,
 }
 
 /// callbacks associated with a single master to outstation association
@@ -392,6 +782,33 @@ impl HeaderInfo {
     }
 }
 
+/// Object header received in response to a [CustomRequest](crate::master::CustomRequest)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CustomObjectHeader<'a> {
+    /// variation of the header
+    pub variation: Variation,
+    /// qualifier code of the header
+    pub qualifier: QualifierCode,
+    /// start and stop indices if the qualifier is a range
+    pub range: Option<(u16, u16)>,
+    /// number of objects if the qualifier is a count, count and prefix, or free-format
+    pub count: Option<u16>,
+    /// complete bytes of the header, i.e. variation, qualifier, range or count, and all objects
+    pub raw: &'a [u8],
+}
+
+/// Callbacks used to process the responses to a [CustomRequest](crate::master::CustomRequest)
+pub trait CustomResponseHandler: Send + Sync {
+    /// Called when a response fragment is received, before any of its object headers
+    fn begin_fragment(&mut self, _header: ResponseHeader) {}
+
+    /// Called for each object header in the response fragment
+    fn handle_header(&mut self, header: CustomObjectHeader);
+
+    /// Called after all of the object headers in the response fragment
+    fn end_fragment(&mut self, _header: ResponseHeader) {}
+}
+
 /// Describes the source of a read event
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ReadType {
@@ -462,6 +879,16 @@ pub trait ReadHandler: Send + Sync {
         iter: &mut dyn Iterator<Item = (AnalogInput, u16)>,
     );
 
+    /// Process an object header of `FrozenAnalogInput` values
+    ///
+    /// The default implementation ignores them.
+    fn handle_frozen_analog_input(
+        &mut self,
+        _info: HeaderInfo,
+        _iter: &mut dyn Iterator<Item = (FrozenAnalogInput, u16)>,
+    ) {
+    }
+
     /// Process an object header of `AnalogOutputStatus` values
     fn handle_analog_output_status(
         &mut self,
@@ -475,6 +902,77 @@ pub trait ReadHandler: Send + Sync {
         info: HeaderInfo,
         iter: &'a mut dyn Iterator<Item = (&'a [u8], u16)>,
     );
+
+    /// Process a device attribute (g0)
+    ///
+    /// Each object header contains a single attribute. The default implementation ignores it.
+    fn handle_device_attribute(&mut self, _info: HeaderInfo, _attr: Attribute) {}
+
+    /// Process a data set prototype (g85)
+    ///
+    /// The association remembers prototypes so that data sets that reference them can be decoded.
+    /// The default implementation ignores it.
+    fn handle_data_set_prototype(&mut self, _info: HeaderInfo, _prototype: &DataSetPrototype) {}
+
+    /// Process a data set descriptor (g86)
+    ///
+    /// The association remembers descriptors so that the values of subsequent data sets with the
+    /// same identifier can be decoded. The default implementation ignores it.
+    fn handle_data_set_descriptor(&mut self, _info: HeaderInfo, _descriptor: &DataSetDescriptor) {}
+
+    /// Process the present value (g87) or a snapshot event (g88) of a data set
+    ///
+    /// `info.is_event` distinguishes snapshot events from present values. Values are typed using
+    /// the descriptor previously read for the data set, or reported as [DataSetValue::Unknown] if
+    /// no descriptor has been read. The default implementation ignores it.
+    fn handle_data_set(&mut self, _info: HeaderInfo, _data_set: &DataSet) {}
+
+    /// Process an object header of analog input deadband (g34) values
+    ///
+    /// 16-bit, 32-bit, and single-precision deadbands are all widened to `f64`. The default
+    /// implementation ignores them.
+    fn handle_analog_input_deadband(
+        &mut self,
+        _info: HeaderInfo,
+        _iter: &mut dyn Iterator<Item = (f64, u16)>,
+    ) {
+    }
+
+    /// Process an object header of `BinaryOutputCommandEvent` (g13) values
+    ///
+    /// The default implementation ignores them.
+    fn handle_binary_output_command_event(
+        &mut self,
+        _info: HeaderInfo,
+        _iter: &mut dyn Iterator<Item = (BinaryOutputCommandEvent, u16)>,
+    ) {
+    }
+
+    /// Process an object header of `AnalogOutputCommandEvent` (g43) values
+    ///
+    /// The default implementation ignores them.
+    fn handle_analog_output_command_event(
+        &mut self,
+        _info: HeaderInfo,
+        _iter: &mut dyn Iterator<Item = (AnalogOutputCommandEvent, u16)>,
+    ) {
+    }
+
+    /// Process an object header of `UnsignedInteger` (g102) values
+    ///
+    /// The default implementation ignores them.
+    fn handle_unsigned_integer(
+        &mut self,
+        _info: HeaderInfo,
+        _iter: &mut dyn Iterator<Item = (UnsignedInteger, u16)>,
+    ) {
+    }
+
+    /// Process an object header of `Bcd` (g101) values
+    ///
+    /// Small, medium, and large BCD integers are all decoded to `u64`. The default
+    /// implementation ignores them.
+    fn handle_bcd(&mut self, _info: HeaderInfo, _iter: &mut dyn Iterator<Item = (Bcd, u16)>) {}
 }
 
 /// read handler that does nothing
@@ -1,12 +1,15 @@
 use crate::app::Shutdown;
 use crate::decode::DecodeLevel;
 use crate::link::EndpointAddress;
+use crate::master::cache::SharedCache;
 use crate::master::error::PollError;
 use crate::master::error::{AssociationError, TaskError};
 use crate::master::handler::Promise;
 use crate::master::poll::PollMsg;
+use crate::master::scheduler::{TaskPriority, TaskScheduler};
 use crate::master::tasks::Task;
 use crate::master::{AssociationConfig, AssociationHandler, AssociationInformation, ReadHandler};
+use crate::util::virtual_terminal::VirtualTerminalSender;
 
 /// Messages sent from the handles to the master task via an mpsc.
 pub(crate) enum Message {
@@ -26,7 +29,7 @@ pub(crate) enum MasterMsg {
         Box<dyn ReadHandler>,
         Box<dyn AssociationHandler>,
         Box<dyn AssociationInformation>,
-        Promise<Result<(), AssociationError>>,
+        Promise<Result<Option<SharedCache>, AssociationError>>,
     ),
     /// Remove an association from the master
     RemoveAssociation(EndpointAddress),
@@ -34,6 +37,8 @@ pub(crate) enum MasterMsg {
     SetDecodeLevel(DecodeLevel),
     /// Get the decoding level
     GetDecodeLevel(Promise<Result<DecodeLevel, Shutdown>>),
+    /// Replace the policy used to prioritize tasks
+    SetScheduler(Box<dyn TaskScheduler>),
 }
 
 pub(crate) struct AssociationMsg {
@@ -42,10 +47,12 @@ pub(crate) struct AssociationMsg {
 }
 
 pub(crate) enum AssociationMsgType {
-    /// Queue an I/O task for execution later
-    QueueTask(Task),
+    /// Queue an I/O task for execution later, optionally overriding its priority
+    QueueTask(Task, Option<TaskPriority>),
     /// Modify polls
     Poll(PollMsg),
+    /// Route virtual terminal output from a port to a stream
+    OpenVirtualTerminal(u16, VirtualTerminalSender),
 }
 
 impl AssociationMsg {
@@ -57,12 +64,14 @@ impl AssociationMsg {
 impl AssociationMsgType {
     pub(crate) fn on_association_failure(self, address: EndpointAddress) {
         match self {
-            AssociationMsgType::QueueTask(task) => {
+            AssociationMsgType::QueueTask(task, _) => {
                 task.on_task_error(None, TaskError::NoSuchAssociation(address));
             }
             AssociationMsgType::Poll(msg) => {
                 msg.on_error(PollError::NoSuchAssociation(address));
             }
+            // dropping the sender ends the stream
+            AssociationMsgType::OpenVirtualTerminal(_, _) => {}
         }
     }
 }
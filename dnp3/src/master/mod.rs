@@ -1,15 +1,34 @@
 pub use association::*;
+pub use cache::{CacheUpdate, MeasurementCache, MeasurementType};
 pub use error::*;
+pub use file::*;
 pub use handler::*;
 pub use poll::PollHandle;
 pub use request::*;
+pub use scheduler::*;
+pub use soe::*;
+pub use stream::*;
+pub use virtual_terminal::*;
 
 mod association;
+/// last known value of received measurements
+mod cache;
 mod error;
+mod file;
 mod handler;
 mod request;
+/// priorities used to choose the next task
+mod scheduler;
+/// sequence of events (SOE) log sorted by timestamp
+mod soe;
+/// owned measurement batches published on a stream
+mod stream;
+/// virtual terminal (groups 112/113) byte streams
+mod virtual_terminal;
 
+pub(crate) mod auth;
 pub(crate) mod convert;
+pub(crate) mod data_set;
 pub(crate) mod extract;
 pub(crate) mod messages;
 pub(crate) mod poll;
@@ -1,14 +1,21 @@
 use std::ops::BitAnd;
+use std::sync::Arc;
 
 use crate::app::control::CommandStatus;
 use crate::app::format::write::HeaderWriter;
+use crate::app::gen::count::CountVariation;
 use crate::app::gen::prefixed::PrefixedVariation;
+use crate::app::gen::ranged::RangedVariation;
+use crate::app::measurement::OctetString;
+use crate::app::parse::bit::BitSequence;
 use crate::app::parse::count::CountSequence;
-use crate::app::parse::parser::{HeaderCollection, HeaderDetails};
+use crate::app::parse::parser::{HeaderCollection, HeaderDetails, HeaderIterator};
 use crate::app::parse::prefix::Prefix;
 use crate::app::parse::traits::{FixedSizeVariation, Index};
 use crate::app::variations::*;
+use crate::app::{FunctionCode, TimeAndInterval, Timestamp};
 use crate::master::error::CommandResponseError;
+use crate::util::virtual_terminal::MAX_BLOCK_SIZE;
 
 use scursor::WriteError;
 
@@ -130,6 +137,15 @@ impl EventClasses {
         }
     }
 
+    /// classes in `self` that are not in `other`
+    pub(crate) fn without(self, other: Self) -> Self {
+        Self::new(
+            self.class1 && !other.class1,
+            self.class2 && !other.class2,
+            self.class3 && !other.class3,
+        )
+    }
+
     pub(crate) fn write(self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
         if self.class1 {
             writer.write_all_objects_header(Variation::Group60Var2)?;
@@ -374,6 +390,89 @@ pub(crate) enum CommandHeader {
     G41V2U16(Vec<(Group41Var2, u16)>),
     G41V3U16(Vec<(Group41Var3, u16)>),
     G41V4U16(Vec<(Group41Var4, u16)>),
+    PatternU8(PatternHeader<u8>),
+    PatternU16(PatternHeader<u16>),
+}
+
+/// pattern control block (g12v2) and the pattern mask (g12v3) that selects the outputs it controls
+#[derive(Clone)]
+pub(crate) struct PatternHeader<I>
+where
+    I: Index,
+{
+    control: Group12Var2,
+    start: I,
+    stop: I,
+    mask: Vec<bool>,
+}
+
+impl<I> PatternHeader<I>
+where
+    I: Index,
+{
+    fn new(command: Group12Var1, start: I, stop: I, mask: &[bool]) -> Self {
+        let count =
+            (stop.widen_to_u16() as usize + 1).saturating_sub(start.widen_to_u16() as usize);
+        Self {
+            control: command.into(),
+            start,
+            stop,
+            mask: mask
+                .iter()
+                .copied()
+                .chain(std::iter::repeat(false))
+                .take(count)
+                .collect(),
+        }
+    }
+
+    fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        writer.write_count_of_one(self.control)?;
+        writer.write_pattern_mask(self.start, self.stop, self.mask.iter().copied())
+    }
+
+    fn compare_control(&self, response: HeaderDetails) -> Result<(), CommandResponseError> {
+        let seq = match response {
+            HeaderDetails::OneByteCount(_, CountVariation::Group12Var2(seq)) => seq,
+            HeaderDetails::TwoByteCount(_, CountVariation::Group12Var2(seq)) => seq,
+            _ => return Err(CommandResponseError::HeaderTypeMismatch),
+        };
+
+        let received = seq
+            .single()
+            .ok_or(CommandResponseError::ObjectCountMismatch)?;
+
+        if received.status != CommandStatus::Success {
+            return Err(CommandResponseError::BadStatus(received.status));
+        }
+
+        if received != self.control {
+            return Err(CommandResponseError::ObjectValueMismatch);
+        }
+
+        Ok(())
+    }
+
+    fn compare_mask(
+        &self,
+        start: I,
+        stop: I,
+        bits: BitSequence,
+    ) -> Result<(), CommandResponseError> {
+        if start != self.start || stop != self.stop {
+            return Err(CommandResponseError::ObjectCountMismatch);
+        }
+
+        if !bits
+            .iter()
+            .map(|(bit, _)| bit)
+            .eq(self.mask.iter().copied())
+        {
+            return Err(CommandResponseError::ObjectValueMismatch);
+        }
+
+        Ok(())
+    }
 }
 
 pub(crate) trait Command {
@@ -471,10 +570,7 @@ impl CommandHeaders {
         let mut iter = headers.iter();
 
         for sent in &self.headers {
-            match iter.next() {
-                None => return Err(CommandResponseError::HeaderCountMismatch),
-                Some(received) => sent.compare(received.details)?,
-            }
+            sent.compare(&mut iter)?;
         }
 
         if iter.next().is_some() {
@@ -663,6 +759,30 @@ impl CommandBuilder {
         }
     }
 
+    /// Add a pattern control block (g12v2) and pattern mask (g12v3) using one byte addressing
+    ///
+    /// The command is applied to every index in the range [`start`, `stop`] whose bit is set in `mask`.
+    /// The first element of `mask` corresponds to `start`. Indices beyond the end of `mask` are not controlled.
+    pub fn add_pattern_u8(&mut self, command: Group12Var1, start: u8, stop: u8, mask: &[bool]) {
+        self.finish_header();
+        self.headers
+            .push(CommandHeader::PatternU8(PatternHeader::new(
+                command, start, stop, mask,
+            )));
+    }
+
+    /// Add a pattern control block (g12v2) and pattern mask (g12v3) using two byte addressing
+    ///
+    /// The command is applied to every index in the range [`start`, `stop`] whose bit is set in `mask`.
+    /// The first element of `mask` corresponds to `start`. Indices beyond the end of `mask` are not controlled.
+    pub fn add_pattern_u16(&mut self, command: Group12Var1, start: u16, stop: u16, mask: &[bool]) {
+        self.finish_header();
+        self.headers
+            .push(CommandHeader::PatternU16(PatternHeader::new(
+                command, start, stop, mask,
+            )));
+    }
+
     /// Consume the instance and return a fully built `CommandHeaders`
     pub fn build(mut self) -> CommandHeaders {
         self.finish_header();
@@ -781,6 +901,8 @@ impl CommandHeader {
             CommandHeader::G41V2U16(items) => writer.write_prefixed_items(items.iter()),
             CommandHeader::G41V3U16(items) => writer.write_prefixed_items(items.iter()),
             CommandHeader::G41V4U16(items) => writer.write_prefixed_items(items.iter()),
+            CommandHeader::PatternU8(pattern) => pattern.write(writer),
+            CommandHeader::PatternU16(pattern) => pattern.write(writer),
         }
     }
 
@@ -815,7 +937,15 @@ impl CommandHeader {
         Ok(())
     }
 
-    pub(crate) fn compare(&self, response: HeaderDetails) -> Result<(), CommandResponseError> {
+    pub(crate) fn compare(
+        &self,
+        received: &mut HeaderIterator,
+    ) -> Result<(), CommandResponseError> {
+        let response = match received.next() {
+            None => return Err(CommandResponseError::HeaderCountMismatch),
+            Some(header) => header.details,
+        };
+
         match self {
             CommandHeader::G12V1U8(items) => match response {
                 HeaderDetails::OneByteCountAndPrefix(_, PrefixedVariation::Group12Var1(seq)) => {
@@ -877,6 +1007,457 @@ impl CommandHeader {
                 }
                 _ => Err(CommandResponseError::HeaderTypeMismatch),
             },
+            // the pattern control block is followed by a separate header for the mask
+            CommandHeader::PatternU8(pattern) => {
+                pattern.compare_control(response)?;
+                match received.next().map(|x| x.details) {
+                    None => Err(CommandResponseError::HeaderCountMismatch),
+                    Some(HeaderDetails::OneByteStartStop(
+                        start,
+                        stop,
+                        RangedVariation::Group12Var3(bits),
+                    )) => pattern.compare_mask(start, stop, bits),
+                    Some(_) => Err(CommandResponseError::HeaderTypeMismatch),
+                }
+            }
+            CommandHeader::PatternU16(pattern) => {
+                pattern.compare_control(response)?;
+                match received.next().map(|x| x.details) {
+                    None => Err(CommandResponseError::HeaderCountMismatch),
+                    Some(HeaderDetails::TwoByteStartStop(
+                        start,
+                        stop,
+                        RangedVariation::Group12Var3(bits),
+                    )) => pattern.compare_mask(start, stop, bits),
+                    Some(_) => Err(CommandResponseError::HeaderTypeMismatch),
+                }
+            }
+        }
+    }
+}
+
+/// Event class assigned to points using the ASSIGN_CLASS function code
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AssignedClass {
+    /// Class 0 (g60v1), the points no longer produce events
+    Class0,
+    /// Class 1 (g60v2)
+    Class1,
+    /// Class 2 (g60v3)
+    Class2,
+    /// Class 3 (g60v4)
+    Class3,
+}
+
+impl AssignedClass {
+    fn variation(self) -> Variation {
+        match self {
+            AssignedClass::Class0 => Variation::Group60Var1,
+            AssignedClass::Class1 => Variation::Group60Var2,
+            AssignedClass::Class2 => Variation::Group60Var3,
+            AssignedClass::Class3 => Variation::Group60Var4,
+        }
+    }
+}
+
+/// Builder object used to create an ASSIGN_CLASS request
+#[derive(Clone, Debug, Default)]
+pub struct AssignClassRequest {
+    headers: Vec<(AssignedClass, ReadHeader)>,
+}
+
+impl AssignClassRequest {
+    /// construct a new empty `AssignClassRequest`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Assign the points selected by a header to a class
+    ///
+    /// Points are selected using variation 0 of a static type, e.g. `Variation::Group30Var0`,
+    /// in an all objects or range header. Consecutive headers assigned to the same class
+    /// are preceded by a single class header in the request.
+    pub fn add(&mut self, class: AssignedClass, points: ReadHeader) {
+        self.headers.push((class, points));
+    }
+
+    pub(crate) fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        let mut current = None;
+        for (class, points) in self.headers.iter() {
+            if current != Some(*class) {
+                writer.write_all_objects_header(class.variation())?;
+                current = Some(*class);
+            }
+            points.format(writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Freeze operation performed on counters
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FreezeOperation {
+    /// Copy the current value of the counters to the frozen counters (IMMEDIATE_FREEZE)
+    Immediate,
+    /// Copy the current value of the counters to the frozen counters and then
+    /// clear the counters (FREEZE_CLEAR)
+    Clear,
+    /// Freeze the counters at a time and then periodically at an interval (FREEZE_AT_TIME)
+    ///
+    /// The schedule is sent to the outstation as a g50v2 object. A time of zero means
+    /// relative to when the outstation receives the request, and an interval of zero
+    /// means that the counters are frozen only once.
+    AtTime {
+        /// time of the first freeze
+        time: Timestamp,
+        /// interval between freezes in milliseconds
+        interval_ms: u32,
+    },
+}
+
+/// Builder object used to create a freeze request
+#[derive(Clone, Debug)]
+pub struct FreezeRequest {
+    operation: FreezeOperation,
+    no_ack: bool,
+    headers: Vec<ReadHeader>,
+}
+
+impl FreezeRequest {
+    /// construct a new empty `FreezeRequest` to which the outstation responds
+    pub fn new(operation: FreezeOperation) -> Self {
+        Self {
+            operation,
+            no_ack: false,
+            headers: Vec::new(),
+        }
+    }
+
+    /// construct a new empty `FreezeRequest` that uses the NO_ACK variant of the function code
+    ///
+    /// The outstation does not respond to the request, so the task completes as soon as
+    /// the request is sent.
+    pub fn no_ack(operation: FreezeOperation) -> Self {
+        Self {
+            no_ack: true,
+            ..Self::new(operation)
+        }
+    }
+
+    /// Freeze the counters selected by a header
+    ///
+    /// Counters are typically selected using `Variation::Group20Var0` in an all objects,
+    /// range, or limited count header. A limited count selects the first `count` counters.
+    pub fn add(&mut self, counters: ReadHeader) {
+        self.headers.push(counters);
+    }
+
+    pub(crate) fn function(&self) -> FunctionCode {
+        match (self.operation, self.no_ack) {
+            (FreezeOperation::Immediate, false) => FunctionCode::ImmediateFreeze,
+            (FreezeOperation::Immediate, true) => FunctionCode::ImmediateFreezeNoResponse,
+            (FreezeOperation::Clear, false) => FunctionCode::FreezeClear,
+            (FreezeOperation::Clear, true) => FunctionCode::FreezeClearNoResponse,
+            (FreezeOperation::AtTime { .. }, false) => FunctionCode::FreezeAtTime,
+            (FreezeOperation::AtTime { .. }, true) => FunctionCode::FreezeAtTimeNoResponse,
+        }
+    }
+
+    pub(crate) fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        if let FreezeOperation::AtTime { time, interval_ms } = self.operation {
+            writer.write_count_of_one(Group50Var2 {
+                time,
+                interval: interval_ms,
+            })?;
+        }
+        for header in self.headers.iter() {
+            header.format(writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+enum DeadbandHeader {
+    G34V1(Vec<(Group34Var1, u16)>),
+    G34V2(Vec<(Group34Var2, u16)>),
+    G34V3(Vec<(Group34Var3, u16)>),
+}
+
+/// Analog input deadbands (group 34) written to the outstation using two byte index prefixes
+#[derive(Clone, Debug)]
+pub struct AnalogInputDeadbands {
+    header: DeadbandHeader,
+}
+
+impl AnalogInputDeadbands {
+    /// write 16-bit deadbands (g34v1) as a list of (deadband, index) pairs
+    pub fn u16(values: &[(u16, u16)]) -> Self {
+        Self {
+            header: DeadbandHeader::G34V1(
+                values
+                    .iter()
+                    .map(|(value, index)| (Group34Var1 { value: *value }, *index))
+                    .collect(),
+            ),
         }
     }
+
+    /// write 32-bit deadbands (g34v2) as a list of (deadband, index) pairs
+    pub fn u32(values: &[(u32, u16)]) -> Self {
+        Self {
+            header: DeadbandHeader::G34V2(
+                values
+                    .iter()
+                    .map(|(value, index)| (Group34Var2 { value: *value }, *index))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// write single-precision floating point deadbands (g34v3) as a list of (deadband, index) pairs
+    pub fn f32(values: &[(f32, u16)]) -> Self {
+        Self {
+            header: DeadbandHeader::G34V3(
+                values
+                    .iter()
+                    .map(|(value, index)| (Group34Var3 { value: *value }, *index))
+                    .collect(),
+            ),
+        }
+    }
+
+    pub(crate) fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        match &self.header {
+            DeadbandHeader::G34V1(items) => writer.write_prefixed_items(items.iter()),
+            DeadbandHeader::G34V2(items) => writer.write_prefixed_items(items.iter()),
+            DeadbandHeader::G34V3(items) => writer.write_prefixed_items(items.iter()),
+        }
+    }
+}
+
+/// Object header that can be added to a [WriteRequest]
+pub(crate) trait WriteHeader: std::fmt::Debug + Send + Sync {
+    fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError>;
+}
+
+impl WriteHeader for AnalogInputDeadbands {
+    fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        AnalogInputDeadbands::write(self, writer)
+    }
+}
+
+#[derive(Debug)]
+struct OctetStringHeader {
+    index: u16,
+    value: OctetString,
+}
+
+impl WriteHeader for OctetStringHeader {
+    fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        writer.write_octet_string(self.index, self.value.value())
+    }
+}
+
+#[derive(Debug)]
+struct AbsoluteTimeHeader(Timestamp);
+
+impl WriteHeader for AbsoluteTimeHeader {
+    fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        writer.write_count_of_one(Group50Var1 { time: self.0 })
+    }
+}
+
+#[derive(Debug)]
+struct TimeAndIntervalHeader(Vec<(Group50Var4, u16)>);
+
+impl WriteHeader for TimeAndIntervalHeader {
+    fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        writer.write_prefixed_items(self.0.iter())
+    }
+}
+
+#[derive(Debug)]
+struct IinBitHeader {
+    index: u8,
+    value: bool,
+}
+
+impl WriteHeader for IinBitHeader {
+    fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        writer.write_iin_bit(self.index, self.value)
+    }
+}
+
+#[derive(Debug)]
+struct VirtualTerminalHeader {
+    port: u16,
+    block: Vec<u8>,
+}
+
+impl WriteHeader for VirtualTerminalHeader {
+    fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        writer.write_virtual_terminal_output(self.port, &self.block)
+    }
+}
+
+#[derive(Debug)]
+struct RawHeader(Vec<u8>);
+
+impl WriteHeader for RawHeader {
+    fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        writer.write_raw(&self.0)
+    }
+}
+
+/// Builder object used to create a WRITE request
+///
+/// Object headers are written in the order in which they are added
+#[derive(Clone, Debug, Default)]
+pub struct WriteRequest {
+    headers: Vec<Arc<dyn WriteHeader>>,
+}
+
+impl WriteRequest {
+    /// construct a new empty `WriteRequest`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// write the deadbands of one or more analog inputs (g34)
+    pub fn add_analog_input_deadbands(&mut self, deadbands: AnalogInputDeadbands) {
+        self.add_header(deadbands);
+    }
+
+    /// write an octet string (g110) at the specified index
+    ///
+    /// The variation of the header is the length of the octet string
+    pub fn add_octet_string(&mut self, index: u16, value: OctetString) {
+        self.add_header(OctetStringHeader { index, value });
+    }
+
+    /// write the absolute time of the outstation (g50v1)
+    pub fn add_absolute_time(&mut self, time: Timestamp) {
+        self.add_header(AbsoluteTimeHeader(time));
+    }
+
+    /// write indexed absolute times and long intervals (g50v4) as a list of (value, index) pairs
+    ///
+    /// These are typically used to configure the freeze schedule of individual counters
+    pub fn add_time_and_interval(&mut self, values: &[(TimeAndInterval, u16)]) {
+        self.add_header(TimeAndIntervalHeader(
+            values
+                .iter()
+                .map(|(value, index)| ((*value).into(), *index))
+                .collect(),
+        ));
+    }
+
+    /// write the value of the IIN bit (g80v1) at the specified index
+    ///
+    /// Outstations typically only allow clearing IIN1.7 DEVICE_RESTART (index 7)
+    pub fn add_iin_bit(&mut self, index: u8, value: bool) {
+        self.add_header(IinBitHeader { index, value });
+    }
+
+    /// write output to the virtual terminal port (g112) at the specified index
+    ///
+    /// The output is split into blocks of at most 255 bytes, each in its own header
+    pub fn add_virtual_terminal_output(&mut self, port: u16, data: &[u8]) {
+        for block in data.chunks(MAX_BLOCK_SIZE) {
+            self.add_header(VirtualTerminalHeader {
+                port,
+                block: block.to_vec(),
+            });
+        }
+    }
+
+    /// add one or more complete object headers formatted by the user
+    ///
+    /// The bytes must begin with the group and variation and include the qualifier,
+    /// range or count, and any object values. They are copied into the request verbatim
+    /// and are NOT validated before they are sent.
+    pub fn add_raw_header(&mut self, bytes: &[u8]) {
+        self.add_header(RawHeader(bytes.to_vec()));
+    }
+
+    /// add any object header that knows how to format itself
+    pub(crate) fn add_header<T>(&mut self, header: T)
+    where
+        T: WriteHeader + 'static,
+    {
+        self.headers.push(Arc::new(header));
+    }
+
+    pub(crate) fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        for header in self.headers.iter() {
+            header.write(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<AnalogInputDeadbands> for WriteRequest {
+    fn from(deadbands: AnalogInputDeadbands) -> Self {
+        let mut request = Self::new();
+        request.add_analog_input_deadbands(deadbands);
+        request
+    }
+}
+
+#[derive(Clone, Debug)]
+enum CustomHeader {
+    Read(ReadHeader),
+    Raw(Vec<u8>),
+}
+
+/// Builder object used to create a request with an arbitrary function code
+///
+/// This is an escape hatch for devices that require a function code or object
+/// combination that isn't otherwise supported by the master. Raw object headers are
+/// copied into the request verbatim and are NOT validated before they are sent.
+///
+/// Object headers are written in the order in which they are added
+#[derive(Clone, Debug)]
+pub struct CustomRequest {
+    function: FunctionCode,
+    headers: Vec<CustomHeader>,
+}
+
+impl CustomRequest {
+    /// construct a new `CustomRequest` with the specified function code and no object headers
+    pub fn new(function: FunctionCode) -> Self {
+        Self {
+            function,
+            headers: Vec::new(),
+        }
+    }
+
+    /// add a header consisting of a variation, qualifier and range/count without any object values
+    pub fn add_header(&mut self, header: ReadHeader) {
+        self.headers.push(CustomHeader::Read(header));
+    }
+
+    /// add one or more complete object headers formatted by the user
+    ///
+    /// The bytes must begin with the group and variation and include the qualifier,
+    /// range or count, and any object values.
+    pub fn add_raw_header(&mut self, bytes: &[u8]) {
+        self.headers.push(CustomHeader::Raw(bytes.to_vec()));
+    }
+
+    /// function code used in the request
+    pub fn function(&self) -> FunctionCode {
+        self.function
+    }
+
+    pub(crate) fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        for header in self.headers.iter() {
+            match header {
+                CustomHeader::Read(header) => header.format(writer)?,
+                CustomHeader::Raw(bytes) => writer.write_raw(bytes)?,
+            }
+        }
+        Ok(())
+    }
 }
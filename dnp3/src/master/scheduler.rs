@@ -0,0 +1,55 @@
+use crate::master::TaskType;
+
+/// Priority of a task used to decide which task the master runs next
+///
+/// Priorities only affect which task is started next. A task that has already started, e.g. a
+/// long integrity poll on a slow serial line, is never interrupted by a task of higher priority.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TaskPriority {
+    /// runs after tasks of every other priority
+    Low,
+    /// priority of most tasks when using the [DefaultScheduler]
+    Normal,
+    /// runs before tasks of every other priority
+    High,
+}
+
+/// Policy used by a [MasterChannel](crate::master::MasterChannel) to prioritize tasks
+///
+/// Each time the channel is ready to start a task, it considers the requests queued by the user
+/// and the automatic tasks and periodic polls that are due across all associations. Tasks are
+/// then ordered as follows:
+///
+/// 1. Session key changes required by secure authentication always run first.
+/// 2. Tasks with a higher [TaskPriority] run before tasks with a lower priority. The priority is
+///    the one passed explicitly to methods such as
+///    [AssociationHandle::operate_with_priority](crate::master::AssociationHandle::operate_with_priority),
+///    or otherwise the one returned by [TaskScheduler::priority].
+/// 3. Tasks with the same priority run in the following order: user requests in the order they
+///    were queued, automatic tasks (e.g. the startup integrity poll), periodic polls, and finally
+///    link status requests. Associations with tasks of the same priority and kind take turns.
+///
+/// Link status requests sent automatically when the channel is idle always have
+/// [TaskPriority::Low]. Automatic tasks that are waiting to be retried after a failure prevent the
+/// periodic polls of their association from running, as they did before priorities were introduced.
+pub trait TaskScheduler: Send {
+    /// Priority of a task that was not given an explicit priority
+    fn priority(&self, task_type: TaskType) -> TaskPriority;
+}
+
+/// Default [TaskScheduler] that always runs commands before polls
+///
+/// Commands have [TaskPriority::High], periodic polls have [TaskPriority::Low] and every other
+/// task has [TaskPriority::Normal].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DefaultScheduler;
+
+impl TaskScheduler for DefaultScheduler {
+    fn priority(&self, task_type: TaskType) -> TaskPriority {
+        match task_type {
+            TaskType::Command => TaskPriority::High,
+            TaskType::PeriodicPoll => TaskPriority::Low,
+            _ => TaskPriority::Normal,
+        }
+    }
+}
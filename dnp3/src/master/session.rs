@@ -3,15 +3,18 @@ use std::time::Duration;
 
 use tracing::Instrument;
 
+use crate::app::auth::Group120Var9;
 use crate::app::format::write;
+use crate::app::format::write::HeaderWriter;
 use crate::app::parse::parser::Response;
-use crate::app::{BufferSize, ControlField, FunctionCode, Sequence, Shutdown};
+use crate::app::{BufferSize, ControlField, FunctionCode, ResponseFunction, Sequence, Shutdown};
 use crate::decode::DecodeLevel;
 use crate::link::error::LinkError;
 use crate::link::EndpointAddress;
 use crate::master::association::{AssociationMap, Next};
 use crate::master::error::TaskError;
 use crate::master::messages::{MasterMsg, Message};
+use crate::master::scheduler::{DefaultScheduler, TaskScheduler};
 use crate::master::tasks::{AssociationTask, NonReadTask, ReadTask, RequestWriter, Task};
 use crate::master::Association;
 use crate::transport::{TransportReader, TransportResponse, TransportWriter};
@@ -25,6 +28,7 @@ pub(crate) struct MasterSession {
     enabled: bool,
     decode_level: DecodeLevel,
     associations: AssociationMap,
+    scheduler: Box<dyn TaskScheduler>,
     messages: Receiver<Message>,
     tx_buffer: Buffer,
 }
@@ -33,6 +37,8 @@ enum ReadResponseAction {
     Ignore,
     ReadNext,
     Complete,
+    /// the request was challenged and the reply was sent with this sequence number
+    Challenged(Sequence),
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -82,6 +88,7 @@ impl MasterSession {
             enabled,
             decode_level,
             associations: AssociationMap::new(),
+            scheduler: Box::new(DefaultScheduler),
             messages,
             tx_buffer: tx_buffer_size.create_buffer(),
         }
@@ -261,13 +268,10 @@ impl MasterSession {
                 assoc_info,
                 callback,
             ) => {
-                callback.complete(self.associations.register(Association::new(
-                    address,
-                    config,
-                    read_handler,
-                    assoc_handler,
-                    assoc_info,
-                )));
+                let association =
+                    Association::new(address, config, read_handler, assoc_handler, assoc_info);
+                let cache = association.measurement_cache();
+                callback.complete(self.associations.register(association).map(|_| cache));
             }
             MasterMsg::RemoveAssociation(address) => {
                 self.associations.remove(address);
@@ -278,6 +282,9 @@ impl MasterSession {
             MasterMsg::GetDecodeLevel(promise) => {
                 promise.complete(Ok(self.decode_level));
             }
+            MasterMsg::SetScheduler(scheduler) => {
+                self.scheduler = scheduler;
+            }
         }
     }
 
@@ -386,7 +393,7 @@ impl MasterSession {
         writer: &mut TransportWriter,
         reader: &mut TransportReader,
     ) -> Result<Option<NonReadTask>, TaskError> {
-        let seq = match self.send_request(io, destination, &task, writer).await {
+        let mut seq = match self.send_request(io, destination, &task, writer).await {
             Ok(seq) => seq,
             Err(err) => {
                 task.on_task_error(self.associations.get_mut(destination).ok(), err);
@@ -394,8 +401,13 @@ impl MasterSession {
             }
         };
 
+        if task.function().is_no_response() {
+            // the outstation never responds, so the task completes once the request is sent
+            return Ok(task.on_request_sent());
+        }
+
         let timeout = self.associations.get_timeout(destination)?;
-        let deadline = timeout.deadline_from_now();
+        let mut deadline = timeout.deadline_from_now();
 
         loop {
             tokio::select! {
@@ -421,6 +433,18 @@ impl MasterSession {
                             match result {
                                 // continue reading responses until timeout
                                 Ok(None) => continue,
+                                Ok(Some(response)) if Self::is_challenge(task.function(), &response) => {
+                                    match self.reply_to_challenge(io, destination, &response, writer).await {
+                                        Ok(x) => {
+                                            seq = x;
+                                            deadline = timeout.deadline_from_now();
+                                        }
+                                        Err(err) => {
+                                            task.on_task_error(self.associations.get_mut(destination).ok(), err);
+                                            return Err(err);
+                                        }
+                                    }
+                                }
                                 Ok(Some(response)) => {
                                     match self.associations.get_mut(destination) {
                                         Err(x) => {
@@ -429,7 +453,7 @@ impl MasterSession {
                                         }
                                         Ok(association) => {
                                             association.process_iin(response.header.iin);
-                                            return Ok(task.handle(association, response));
+                                            return Ok(task.handle(association, response).await);
                                         }
                                     }
                                 }
@@ -508,7 +532,7 @@ impl MasterSession {
         reader: &mut TransportReader,
     ) -> Result<(), TaskError> {
         if let Ok(association) = self.associations.get_mut(destination) {
-            association.notify_task_start(task.as_task_type(), FunctionCode::Read);
+            association.notify_task_start(task.as_task_type(), task.function());
         }
 
         let result = self
@@ -546,6 +570,10 @@ impl MasterSession {
         reader: &mut TransportReader,
     ) -> Result<(), TaskError> {
         let mut seq = self.send_request(io, destination, task, writer).await?;
+        if task.function().is_no_response() {
+            return Ok(());
+        }
+
         let mut is_first = true;
 
         // read responses until we get a FIN or an error occurs
@@ -570,6 +598,11 @@ impl MasterSession {
                                     ReadResponseAction::Ignore => continue,
                                     // read task complete
                                     ReadResponseAction::Complete => return Ok(()),
+                                    // break to the outer loop and wait for the response to the reply
+                                    ReadResponseAction::Challenged(x) => {
+                                        seq = x;
+                                        break;
+                                    }
                                     // break to the outer loop and read another response
                                     ReadResponseAction::ReadNext => {
                                         is_first = false;
@@ -627,6 +660,13 @@ impl MasterSession {
             return Ok(ReadResponseAction::Ignore);
         }
 
+        if Self::is_challenge(task.function(), &response) {
+            let seq = self
+                .reply_to_challenge(io, destination, &response, writer)
+                .await?;
+            return Ok(ReadResponseAction::Challenged(seq));
+        }
+
         // now do validations
 
         if response.header.control.fir && !is_first {
@@ -658,7 +698,29 @@ impl MasterSession {
     }
 
     fn get_next_task(&mut self) -> Next<AssociationTask> {
-        self.associations.next_task()
+        self.associations.next_task(self.scheduler.as_ref())
+    }
+}
+
+// Secure authentication
+impl MasterSession {
+    /// authentication responses to anything but an authentication request are challenges or errors
+    fn is_challenge(function: FunctionCode, response: &Response) -> bool {
+        response.header.function == ResponseFunction::AuthenticateResponse
+            && function != FunctionCode::AuthenticateRequest
+    }
+
+    async fn reply_to_challenge(
+        &mut self,
+        io: &mut PhysLayer,
+        destination: EndpointAddress,
+        response: &Response<'_>,
+        writer: &mut TransportWriter,
+    ) -> Result<Sequence, TaskError> {
+        let association = self.associations.get_mut(destination)?;
+        association.process_iin(response.header.iin);
+        let reply = association.reply_to_challenge(response)?;
+        self.send_request(io, destination, &reply, writer).await
     }
 }
 
@@ -762,13 +824,25 @@ impl MasterSession {
         // format the request
         let association = self.associations.get_mut(address)?;
         let seq = association.increment_seq();
+        let aggressive_mode = association.aggressive_mode(request.function());
         let mut cursor = self.tx_buffer.write_cursor();
         let mut hw =
             write::start_request(ControlField::request(seq), request.function(), &mut cursor)?;
+        if let Some(mode) = &aggressive_mode {
+            hw.write_count_of_one(mode.header)?;
+        }
         request.write(&mut hw)?;
+        if let Some(mode) = &aggressive_mode {
+            let mac = mode.mac(cursor.written());
+            HeaderWriter::new(&mut cursor).write_free_format(&Group120Var9 { mac: &mac })?;
+        }
         writer
             .write(io, self.decode_level, address.wrap(), cursor.written())
             .await?;
+        self.associations.get_mut(address)?.on_request_sent(
+            cursor.written(),
+            aggressive_mode.map(|x| x.header.challenge_seq),
+        );
         Ok(seq)
     }
 }
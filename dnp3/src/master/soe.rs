@@ -0,0 +1,538 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use crate::app::measurement::*;
+use crate::app::{MaybeAsync, ResponseHeader};
+use crate::master::{HeaderInfo, ReadHandler, ReadType};
+
+/// Controls when the events held by a [SoeStream] are published as a [SoeLog]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SoeFlush {
+    /// Publish the events of each response fragment as soon as the fragment has been processed
+    EndFragment,
+    /// Hold events across fragments and publish them once the specified time has elapsed since
+    /// the first event was held
+    ///
+    /// This allows events reported in separate fragments, e.g. several unsolicited responses,
+    /// to be ordered relative to each other.
+    Window(Duration),
+}
+
+/// Controls how events with [Time::Unsynchronized] timestamps are ordered in a [SoeLog]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UnsynchronizedTime {
+    /// Place the events in [SoeLog::unordered] in the order they were received
+    Separate,
+    /// Sort the events together with synchronized events using the reported timestamp
+    ///
+    /// This is useful when the outstation's clock is never synchronized, but is known to be
+    /// consistent across all of its events.
+    Merge,
+}
+
+/// Configuration of a sequence of events (SOE) collector
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SoeConfig {
+    /// when held events are published
+    pub flush: SoeFlush,
+    /// how events with unsynchronized timestamps are ordered
+    pub unsynchronized_time: UnsynchronizedTime,
+    /// maximum number of fragments queued between the handler and the stream before the handler
+    /// applies back-pressure, with a minimum of 1
+    pub capacity: usize,
+}
+
+impl SoeConfig {
+    /// construct a configuration from its fields
+    pub fn new(flush: SoeFlush, unsynchronized_time: UnsynchronizedTime, capacity: usize) -> Self {
+        Self {
+            flush,
+            unsynchronized_time,
+            capacity,
+        }
+    }
+}
+
+impl Default for SoeConfig {
+    fn default() -> Self {
+        Self::new(SoeFlush::EndFragment, UnsynchronizedTime::Separate, 16)
+    }
+}
+
+/// Value of a single event in a [SoeLog]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum EventValue {
+    /// binary input event (g2)
+    BinaryInput(BinaryInput),
+    /// double-bit binary input event (g4)
+    DoubleBitBinaryInput(DoubleBitBinaryInput),
+    /// binary output status event (g11)
+    BinaryOutputStatus(BinaryOutputStatus),
+    /// counter event (g22)
+    Counter(Counter),
+    /// frozen counter event (g23)
+    FrozenCounter(FrozenCounter),
+    /// analog input event (g32)
+    AnalogInput(AnalogInput),
+    /// frozen analog input event (g33)
+    FrozenAnalogInput(FrozenAnalogInput),
+    /// analog output status event (g42)
+    AnalogOutputStatus(AnalogOutputStatus),
+    /// binary output command event (g13)
+    BinaryOutputCommand(BinaryOutputCommandEvent),
+    /// analog output command event (g43)
+    AnalogOutputCommand(AnalogOutputCommandEvent),
+}
+
+impl EventValue {
+    /// time of the event, if present in the response
+    pub fn time(&self) -> Option<Time> {
+        match self {
+            Self::BinaryInput(x) => x.time,
+            Self::DoubleBitBinaryInput(x) => x.time,
+            Self::BinaryOutputStatus(x) => x.time,
+            Self::Counter(x) => x.time,
+            Self::FrozenCounter(x) => x.time,
+            Self::AnalogInput(x) => x.time,
+            Self::FrozenAnalogInput(x) => x.time,
+            Self::AnalogOutputStatus(x) => x.time,
+            Self::BinaryOutputCommand(x) => x.time,
+            Self::AnalogOutputCommand(x) => x.time,
+        }
+    }
+}
+
+/// Single event in a [SoeLog]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SoeEvent {
+    /// what triggered the read in which the event was received
+    pub read_type: ReadType,
+    /// variation and qualifier of the header containing the event
+    pub info: HeaderInfo,
+    /// index of the point
+    pub index: u16,
+    /// value, flags and time of the event
+    pub value: EventValue,
+}
+
+/// Events received from an outstation in chronological order
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SoeLog {
+    /// events sorted by timestamp
+    ///
+    /// Events with the same timestamp remain in the order they were received.
+    pub events: Vec<SoeEvent>,
+    /// events that cannot be placed in chronological order, kept in the order they were received
+    ///
+    /// This contains events without a timestamp and, when configured with
+    /// [UnsynchronizedTime::Separate], events with an unsynchronized timestamp.
+    pub unordered: Vec<SoeEvent>,
+}
+
+impl SoeLog {
+    fn new(events: Vec<SoeEvent>, unsynchronized_time: UnsynchronizedTime) -> Self {
+        let (mut sorted, unordered): (Vec<SoeEvent>, Vec<SoeEvent>) =
+            events
+                .into_iter()
+                .partition(|event| match event.value.time() {
+                    Some(Time::Synchronized(_)) => true,
+                    Some(Time::Unsynchronized(_)) => {
+                        unsynchronized_time == UnsynchronizedTime::Merge
+                    }
+                    None => false,
+                });
+
+        // the sort is stable so events with the same time remain in the order they were received
+        sorted.sort_by_key(|event| match event.value.time() {
+            Some(Time::Synchronized(x)) | Some(Time::Unsynchronized(x)) => x.raw_value(),
+            None => 0,
+        });
+
+        Self {
+            events: sorted,
+            unordered,
+        }
+    }
+
+    /// true if the log doesn't contain any events
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty() && self.unordered.is_empty()
+    }
+}
+
+/// [ReadHandler] that collects events of all types into a sequence of events (SOE) log
+///
+/// Events are read from the [SoeStream] returned by [SoeReadHandler::create]. When the queue
+/// between the handler and the stream is full, the future returned by `end_fragment` doesn't
+/// complete until the stream has room. Static values and other objects are ignored.
+#[derive(Debug)]
+pub struct SoeReadHandler {
+    events: Vec<SoeEvent>,
+    read_type: ReadType,
+    tx: tokio::sync::mpsc::Sender<Vec<SoeEvent>>,
+}
+
+/// Stream of [SoeLog] produced by a [SoeReadHandler]
+///
+/// The timer of [SoeFlush::Window] only runs while the stream is being polled. Any held events
+/// are published when the handler is dropped, after which the stream ends.
+#[derive(Debug)]
+pub struct SoeStream {
+    config: SoeConfig,
+    held: Vec<SoeEvent>,
+    deadline: Option<Pin<Box<tokio::time::Sleep>>>,
+    rx: tokio::sync::mpsc::Receiver<Vec<SoeEvent>>,
+}
+
+impl SoeReadHandler {
+    /// Create a handler and the stream on which it publishes logs
+    pub fn create(config: SoeConfig) -> (Self, SoeStream) {
+        let (tx, rx) = tokio::sync::mpsc::channel(config.capacity.max(1));
+        let handler = Self {
+            events: Vec::new(),
+            read_type: ReadType::StartupIntegrity,
+            tx,
+        };
+        let stream = SoeStream {
+            config,
+            held: Vec::new(),
+            deadline: None,
+            rx,
+        };
+        (handler, stream)
+    }
+
+    fn push<T>(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (T, u16)>,
+        convert: fn(T) -> EventValue,
+    ) {
+        if !info.is_event {
+            return;
+        }
+        let read_type = self.read_type;
+        self.events.extend(iter.map(|(value, index)| SoeEvent {
+            read_type,
+            info,
+            index,
+            value: convert(value),
+        }));
+    }
+}
+
+impl SoeStream {
+    fn flush(&mut self) -> SoeLog {
+        self.deadline = None;
+        SoeLog::new(
+            std::mem::take(&mut self.held),
+            self.config.unsynchronized_time,
+        )
+    }
+}
+
+impl futures::Stream for SoeStream {
+    type Item = SoeLog;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match self.rx.poll_recv(cx) {
+                Poll::Ready(Some(events)) => match self.config.flush {
+                    SoeFlush::EndFragment => {
+                        return Poll::Ready(Some(SoeLog::new(
+                            events,
+                            self.config.unsynchronized_time,
+                        )))
+                    }
+                    SoeFlush::Window(window) => {
+                        if self.deadline.is_none() {
+                            self.deadline = Some(Box::pin(tokio::time::sleep(window)));
+                        }
+                        self.held.extend(events);
+                    }
+                },
+                Poll::Ready(None) => {
+                    if self.held.is_empty() {
+                        return Poll::Ready(None);
+                    }
+                    return Poll::Ready(Some(self.flush()));
+                }
+                Poll::Pending => break,
+            }
+        }
+
+        if let Some(deadline) = self.deadline.as_mut() {
+            if deadline.as_mut().poll(cx).is_ready() {
+                return Poll::Ready(Some(self.flush()));
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+impl ReadHandler for SoeReadHandler {
+    fn begin_fragment(&mut self, read_type: ReadType, _header: ResponseHeader) -> MaybeAsync<()> {
+        self.read_type = read_type;
+        self.events.clear();
+        MaybeAsync::ready(())
+    }
+
+    fn end_fragment(&mut self, _read_type: ReadType, _header: ResponseHeader) -> MaybeAsync<()> {
+        if self.events.is_empty() {
+            return MaybeAsync::ready(());
+        }
+        let events = std::mem::take(&mut self.events);
+        let tx = self.tx.clone();
+        MaybeAsync::asynchronous(async move {
+            // an error only indicates that the stream was dropped
+            let _ = tx.send(events).await;
+        })
+    }
+
+    fn handle_binary_input(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (BinaryInput, u16)>,
+    ) {
+        self.push(info, iter, EventValue::BinaryInput);
+    }
+
+    fn handle_double_bit_binary_input(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (DoubleBitBinaryInput, u16)>,
+    ) {
+        self.push(info, iter, EventValue::DoubleBitBinaryInput);
+    }
+
+    fn handle_binary_output_status(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (BinaryOutputStatus, u16)>,
+    ) {
+        self.push(info, iter, EventValue::BinaryOutputStatus);
+    }
+
+    fn handle_counter(&mut self, info: HeaderInfo, iter: &mut dyn Iterator<Item = (Counter, u16)>) {
+        self.push(info, iter, EventValue::Counter);
+    }
+
+    fn handle_frozen_counter(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (FrozenCounter, u16)>,
+    ) {
+        self.push(info, iter, EventValue::FrozenCounter);
+    }
+
+    fn handle_analog_input(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (AnalogInput, u16)>,
+    ) {
+        self.push(info, iter, EventValue::AnalogInput);
+    }
+
+    fn handle_frozen_analog_input(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (FrozenAnalogInput, u16)>,
+    ) {
+        self.push(info, iter, EventValue::FrozenAnalogInput);
+    }
+
+    fn handle_analog_output_status(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (AnalogOutputStatus, u16)>,
+    ) {
+        self.push(info, iter, EventValue::AnalogOutputStatus);
+    }
+
+    fn handle_octet_string<'a>(
+        &mut self,
+        _info: HeaderInfo,
+        _iter: &'a mut dyn Iterator<Item = (&'a [u8], u16)>,
+    ) {
+        // octet string events don't have a timestamp
+    }
+
+    fn handle_binary_output_command_event(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (BinaryOutputCommandEvent, u16)>,
+    ) {
+        self.push(info, iter, EventValue::BinaryOutputCommand);
+    }
+
+    fn handle_analog_output_command_event(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (AnalogOutputCommandEvent, u16)>,
+    ) {
+        self.push(info, iter, EventValue::AnalogOutputCommand);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures::StreamExt;
+
+    use crate::app::variations::Variation;
+    use crate::app::{ControlField, Iin, QualifierCode, ResponseFunction};
+
+    use super::*;
+
+    fn header() -> ResponseHeader {
+        ResponseHeader::new(
+            ControlField::from(0xC0),
+            ResponseFunction::UnsolicitedResponse,
+            Iin::default(),
+        )
+    }
+
+    fn event_info(variation: Variation) -> HeaderInfo {
+        HeaderInfo::new(variation, QualifierCode::CountAndPrefix16, true, true)
+    }
+
+    fn binary(time: Option<Time>) -> BinaryInput {
+        BinaryInput {
+            value: true,
+            flags: Flags::ONLINE,
+            time,
+        }
+    }
+
+    fn analog(time: Option<Time>) -> AnalogInput {
+        AnalogInput {
+            value: 1.0,
+            flags: Flags::ONLINE,
+            time,
+        }
+    }
+
+    async fn fragment(
+        handler: &mut SoeReadHandler,
+        binaries: Vec<(BinaryInput, u16)>,
+        analogs: Vec<(AnalogInput, u16)>,
+    ) {
+        handler
+            .begin_fragment(ReadType::Unsolicited, header())
+            .get()
+            .await;
+        handler.handle_binary_input(event_info(Variation::Group2Var2), &mut binaries.into_iter());
+        handler.handle_analog_input(event_info(Variation::Group32Var3), &mut analogs.into_iter());
+        handler
+            .end_fragment(ReadType::Unsolicited, header())
+            .get()
+            .await;
+    }
+
+    fn indices(events: &[SoeEvent]) -> Vec<u16> {
+        events.iter().map(|x| x.index).collect()
+    }
+
+    #[tokio::test]
+    async fn sorts_events_of_all_types_within_a_fragment() {
+        let (mut handler, mut stream) = SoeReadHandler::create(SoeConfig::default());
+
+        fragment(
+            &mut handler,
+            vec![
+                (binary(Some(Time::synchronized(30))), 0),
+                (binary(Some(Time::unsynchronized(5))), 1),
+                (binary(None), 2),
+            ],
+            vec![
+                (analog(Some(Time::synchronized(10))), 3),
+                (analog(Some(Time::synchronized(30))), 4),
+            ],
+        )
+        .await;
+
+        let log = stream.next().await.unwrap();
+        assert_eq!(indices(&log.events), vec![3, 0, 4]);
+        assert_eq!(indices(&log.unordered), vec![1, 2]);
+        assert_eq!(
+            log.events[0].value,
+            EventValue::AnalogInput(analog(Some(Time::synchronized(10))))
+        );
+        assert_eq!(log.events[0].read_type, ReadType::Unsolicited);
+    }
+
+    #[tokio::test]
+    async fn can_merge_unsynchronized_time_and_ignores_static_values() {
+        let (mut handler, mut stream) = SoeReadHandler::create(SoeConfig::new(
+            SoeFlush::EndFragment,
+            UnsynchronizedTime::Merge,
+            1,
+        ));
+
+        handler
+            .begin_fragment(ReadType::PeriodicPoll, header())
+            .get()
+            .await;
+        handler.handle_binary_input(
+            HeaderInfo::new(Variation::Group1Var2, QualifierCode::Range8, false, true),
+            &mut std::iter::once((binary(None), 9)),
+        );
+        handler.handle_binary_input(
+            event_info(Variation::Group2Var2),
+            &mut vec![
+                (binary(Some(Time::synchronized(20))), 0),
+                (binary(Some(Time::unsynchronized(5))), 1),
+            ]
+            .into_iter(),
+        );
+        handler
+            .end_fragment(ReadType::PeriodicPoll, header())
+            .get()
+            .await;
+
+        let log = stream.next().await.unwrap();
+        assert_eq!(indices(&log.events), vec![1, 0]);
+        assert!(log.unordered.is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn window_orders_events_across_fragments() {
+        let (mut handler, mut stream) = SoeReadHandler::create(SoeConfig::new(
+            SoeFlush::Window(Duration::from_secs(1)),
+            UnsynchronizedTime::Separate,
+            4,
+        ));
+
+        fragment(
+            &mut handler,
+            vec![(binary(Some(Time::synchronized(20))), 0)],
+            vec![],
+        )
+        .await;
+        fragment(
+            &mut handler,
+            vec![],
+            vec![(analog(Some(Time::synchronized(10))), 1)],
+        )
+        .await;
+
+        let start = tokio::time::Instant::now();
+        let log = stream.next().await.unwrap();
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+        assert_eq!(indices(&log.events), vec![1, 0]);
+
+        // held events are published when the handler is dropped
+        fragment(
+            &mut handler,
+            vec![(binary(Some(Time::synchronized(30))), 2)],
+            vec![],
+        )
+        .await;
+        drop(handler);
+        let log = stream.next().await.unwrap();
+        assert_eq!(indices(&log.events), vec![2]);
+        assert!(stream.next().await.is_none());
+    }
+}
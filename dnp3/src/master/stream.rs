@@ -0,0 +1,366 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::app::measurement::*;
+use crate::app::{MaybeAsync, ResponseHeader};
+use crate::master::{HeaderInfo, ReadHandler, ReadType};
+
+/// Owned values of a single object header
+#[derive(Clone, Debug, PartialEq)]
+pub enum Measurements {
+    /// binary inputs (g1/g2)
+    BinaryInput(Vec<(BinaryInput, u16)>),
+    /// double-bit binary inputs (g3/g4)
+    DoubleBitBinaryInput(Vec<(DoubleBitBinaryInput, u16)>),
+    /// binary output status (g10/g11)
+    BinaryOutputStatus(Vec<(BinaryOutputStatus, u16)>),
+    /// counters (g20/g22)
+    Counter(Vec<(Counter, u16)>),
+    /// frozen counters (g21/g23)
+    FrozenCounter(Vec<(FrozenCounter, u16)>),
+    /// analog inputs (g30/g32)
+    AnalogInput(Vec<(AnalogInput, u16)>),
+    /// frozen analog inputs (g31/g33)
+    FrozenAnalogInput(Vec<(FrozenAnalogInput, u16)>),
+    /// analog output status (g40/g42)
+    AnalogOutputStatus(Vec<(AnalogOutputStatus, u16)>),
+    /// binary output command events (g13)
+    BinaryOutputCommandEvent(Vec<(BinaryOutputCommandEvent, u16)>),
+    /// analog output command events (g43)
+    AnalogOutputCommandEvent(Vec<(AnalogOutputCommandEvent, u16)>),
+    /// octet strings (g110/g111)
+    OctetString(Vec<(OctetString, u16)>),
+    /// unsigned integers (g102)
+    UnsignedInteger(Vec<(UnsignedInteger, u16)>),
+    /// BCD integers (g101)
+    Bcd(Vec<(Bcd, u16)>),
+}
+
+/// Measurements of a single object header and information about the header
+#[derive(Clone, Debug, PartialEq)]
+pub struct MeasurementHeader {
+    /// variation and qualifier of the header
+    pub info: HeaderInfo,
+    /// values contained in the header
+    pub measurements: Measurements,
+}
+
+/// Measurements received in a single response fragment
+#[derive(Clone, Debug, PartialEq)]
+pub struct MeasurementBatch {
+    /// what triggered the read, e.g. a poll or an unsolicited response
+    pub read_type: ReadType,
+    /// application-layer header of the fragment
+    pub header: ResponseHeader,
+    /// measurement headers in the order they appeared in the fragment
+    pub headers: Vec<MeasurementHeader>,
+}
+
+/// [ReadHandler] that publishes each response fragment as a [MeasurementBatch]
+///
+/// Batches are sent on a bounded channel read using the [MeasurementStream] returned by
+/// [StreamReadHandler::create]. When the channel is full, the future returned by `end_fragment`
+/// doesn't complete until the stream has room, which pauses the association until the consumer
+/// catches up. Device attributes (g0), analog input deadbands (g34), and data set prototypes,
+/// descriptors, and values (g85-g87) are not published. If the stream is dropped, batches are
+/// discarded.
+#[derive(Debug)]
+pub struct StreamReadHandler {
+    current: Option<MeasurementBatch>,
+    tx: tokio::sync::mpsc::Sender<MeasurementBatch>,
+}
+
+/// Stream of [MeasurementBatch] produced by a [StreamReadHandler]
+///
+/// The stream ends when the handler is dropped, e.g. when the association is removed.
+#[derive(Debug)]
+pub struct MeasurementStream {
+    rx: tokio::sync::mpsc::Receiver<MeasurementBatch>,
+}
+
+impl StreamReadHandler {
+    /// Create a handler and the stream on which it publishes batches
+    ///
+    /// `capacity` is the maximum number of batches queued before the handler applies
+    /// back-pressure, with a minimum of 1.
+    pub fn create(capacity: usize) -> (Self, MeasurementStream) {
+        let (tx, rx) = tokio::sync::mpsc::channel(capacity.max(1));
+        (Self { current: None, tx }, MeasurementStream { rx })
+    }
+
+    fn push(&mut self, info: HeaderInfo, measurements: Measurements) {
+        if let Some(batch) = self.current.as_mut() {
+            batch.headers.push(MeasurementHeader { info, measurements });
+        }
+    }
+}
+
+impl futures::Stream for MeasurementStream {
+    type Item = MeasurementBatch;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+impl ReadHandler for StreamReadHandler {
+    fn begin_fragment(&mut self, read_type: ReadType, header: ResponseHeader) -> MaybeAsync<()> {
+        self.current = Some(MeasurementBatch {
+            read_type,
+            header,
+            headers: Vec::new(),
+        });
+        MaybeAsync::ready(())
+    }
+
+    fn end_fragment(&mut self, read_type: ReadType, header: ResponseHeader) -> MaybeAsync<()> {
+        let batch = self.current.take().unwrap_or(MeasurementBatch {
+            read_type,
+            header,
+            headers: Vec::new(),
+        });
+        let tx = self.tx.clone();
+        MaybeAsync::asynchronous(async move {
+            // an error only indicates that the stream was dropped
+            let _ = tx.send(batch).await;
+        })
+    }
+
+    fn handle_binary_input(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (BinaryInput, u16)>,
+    ) {
+        self.push(info, Measurements::BinaryInput(iter.collect()));
+    }
+
+    fn handle_double_bit_binary_input(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (DoubleBitBinaryInput, u16)>,
+    ) {
+        self.push(info, Measurements::DoubleBitBinaryInput(iter.collect()));
+    }
+
+    fn handle_binary_output_status(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (BinaryOutputStatus, u16)>,
+    ) {
+        self.push(info, Measurements::BinaryOutputStatus(iter.collect()));
+    }
+
+    fn handle_counter(&mut self, info: HeaderInfo, iter: &mut dyn Iterator<Item = (Counter, u16)>) {
+        self.push(info, Measurements::Counter(iter.collect()));
+    }
+
+    fn handle_frozen_counter(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (FrozenCounter, u16)>,
+    ) {
+        self.push(info, Measurements::FrozenCounter(iter.collect()));
+    }
+
+    fn handle_analog_input(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (AnalogInput, u16)>,
+    ) {
+        self.push(info, Measurements::AnalogInput(iter.collect()));
+    }
+
+    fn handle_frozen_analog_input(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (FrozenAnalogInput, u16)>,
+    ) {
+        self.push(info, Measurements::FrozenAnalogInput(iter.collect()));
+    }
+
+    fn handle_analog_output_status(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (AnalogOutputStatus, u16)>,
+    ) {
+        self.push(info, Measurements::AnalogOutputStatus(iter.collect()));
+    }
+
+    fn handle_binary_output_command_event(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (BinaryOutputCommandEvent, u16)>,
+    ) {
+        self.push(info, Measurements::BinaryOutputCommandEvent(iter.collect()));
+    }
+
+    fn handle_analog_output_command_event(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (AnalogOutputCommandEvent, u16)>,
+    ) {
+        self.push(info, Measurements::AnalogOutputCommandEvent(iter.collect()));
+    }
+
+    fn handle_octet_string<'a>(
+        &mut self,
+        info: HeaderInfo,
+        iter: &'a mut dyn Iterator<Item = (&'a [u8], u16)>,
+    ) {
+        // parsed octet strings are always 1 to 255 bytes long
+        let values = iter
+            .filter_map(|(x, index)| OctetString::new(x).ok().map(|x| (x, index)))
+            .collect();
+        self.push(info, Measurements::OctetString(values));
+    }
+
+    fn handle_unsigned_integer(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (UnsignedInteger, u16)>,
+    ) {
+        self.push(info, Measurements::UnsignedInteger(iter.collect()));
+    }
+
+    fn handle_bcd(&mut self, info: HeaderInfo, iter: &mut dyn Iterator<Item = (Bcd, u16)>) {
+        self.push(info, Measurements::Bcd(iter.collect()));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures::StreamExt;
+
+    use crate::app::control::CommandStatus;
+    use crate::app::variations::Variation;
+    use crate::app::{ControlField, Iin, QualifierCode, ResponseFunction};
+
+    use super::*;
+
+    fn header() -> ResponseHeader {
+        ResponseHeader::new(
+            ControlField::from(0xC0),
+            ResponseFunction::Response,
+            Iin::default(),
+        )
+    }
+
+    fn info(variation: Variation) -> HeaderInfo {
+        HeaderInfo::new(variation, QualifierCode::Range8, false, true)
+    }
+
+    #[tokio::test]
+    async fn publishes_owned_values_of_each_fragment() {
+        let (mut handler, mut stream) = StreamReadHandler::create(4);
+        let binary = BinaryInput::new(true, Flags::ONLINE, Time::unsynchronized(0));
+
+        handler
+            .begin_fragment(ReadType::PeriodicPoll, header())
+            .get()
+            .await;
+        handler.handle_binary_input(
+            info(Variation::Group1Var2),
+            &mut std::iter::once((binary, 3)),
+        );
+        handler.handle_octet_string(
+            info(Variation::Group110(2)),
+            &mut std::iter::once((&b"ok"[..], 7)),
+        );
+        handler
+            .end_fragment(ReadType::PeriodicPoll, header())
+            .get()
+            .await;
+
+        assert_eq!(
+            stream.next().await.unwrap(),
+            MeasurementBatch {
+                read_type: ReadType::PeriodicPoll,
+                header: header(),
+                headers: vec![
+                    MeasurementHeader {
+                        info: info(Variation::Group1Var2),
+                        measurements: Measurements::BinaryInput(vec![(binary, 3)]),
+                    },
+                    MeasurementHeader {
+                        info: info(Variation::Group110(2)),
+                        measurements: Measurements::OctetString(vec![(
+                            OctetString::new(b"ok").unwrap(),
+                            7
+                        )]),
+                    },
+                ],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn publishes_command_events() {
+        let (mut handler, mut stream) = StreamReadHandler::create(1);
+        let binary =
+            BinaryOutputCommandEvent::new(true, CommandStatus::Success, Time::synchronized(1));
+        let analog =
+            AnalogOutputCommandEvent::new(4.5, CommandStatus::Timeout, Time::synchronized(2));
+
+        handler
+            .begin_fragment(ReadType::Unsolicited, header())
+            .get()
+            .await;
+        handler.handle_binary_output_command_event(
+            info(Variation::Group13Var2),
+            &mut std::iter::once((binary, 1)),
+        );
+        handler.handle_analog_output_command_event(
+            info(Variation::Group43Var7),
+            &mut std::iter::once((analog, 2)),
+        );
+        handler
+            .end_fragment(ReadType::Unsolicited, header())
+            .get()
+            .await;
+
+        let measurements: Vec<Measurements> = stream
+            .next()
+            .await
+            .unwrap()
+            .headers
+            .into_iter()
+            .map(|x| x.measurements)
+            .collect();
+        assert_eq!(
+            measurements,
+            vec![
+                Measurements::BinaryOutputCommandEvent(vec![(binary, 1)]),
+                Measurements::AnalogOutputCommandEvent(vec![(analog, 2)]),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn end_fragment_waits_for_room_in_the_stream() {
+        let (mut handler, mut stream) = StreamReadHandler::create(1);
+
+        handler
+            .begin_fragment(ReadType::SinglePoll, header())
+            .get()
+            .await;
+        let first = handler.end_fragment(ReadType::SinglePoll, header()).get();
+        first.await;
+
+        handler
+            .begin_fragment(ReadType::Unsolicited, header())
+            .get()
+            .await;
+        let mut second = Box::pin(handler.end_fragment(ReadType::Unsolicited, header()).get());
+        assert!(futures::poll!(&mut second).is_pending());
+
+        assert_eq!(stream.next().await.unwrap().read_type, ReadType::SinglePoll);
+        second.await;
+        assert_eq!(
+            stream.next().await.unwrap().read_type,
+            ReadType::Unsolicited
+        );
+
+        drop(handler);
+        assert!(stream.next().await.is_none());
+    }
+}
@@ -0,0 +1,205 @@
+use crate::app::file::Group70Var8;
+use crate::app::format::write::HeaderWriter;
+use crate::app::parse::free_format::FreeFormatVariation;
+use crate::app::parse::parser::Response;
+use crate::app::{ActivateConfigResponse, ConfigurationReference, FunctionCode};
+use crate::master::error::{TaskError, WriteRequestError};
+use crate::master::handler::Promise;
+use crate::master::tasks::NonReadTask;
+
+use scursor::WriteError;
+
+pub(crate) struct ActivateConfigTask {
+    configurations: Vec<ConfigurationReference>,
+    promise: Promise<Result<ActivateConfigResponse, WriteRequestError>>,
+}
+
+impl ActivateConfigTask {
+    pub(crate) fn new(
+        configurations: Vec<ConfigurationReference>,
+        promise: Promise<Result<ActivateConfigResponse, WriteRequestError>>,
+    ) -> Self {
+        Self {
+            configurations,
+            promise,
+        }
+    }
+
+    pub(crate) fn wrap(self) -> NonReadTask {
+        NonReadTask::ActivateConfig(self)
+    }
+
+    pub(crate) fn function(&self) -> FunctionCode {
+        FunctionCode::ActivateConfig
+    }
+
+    pub(crate) fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        for configuration in &self.configurations {
+            match configuration {
+                ConfigurationReference::File(spec) => writer.write_free_format(&Group70Var8 {
+                    file_specification: spec.as_str(),
+                })?,
+                ConfigurationReference::OctetString(index, value) => {
+                    writer.write_octet_string(*index, value.value())?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn on_task_error(self, err: TaskError) {
+        self.promise.complete(Err(err.into()))
+    }
+
+    pub(crate) fn handle(self, response: Response) -> Option<NonReadTask> {
+        if response.header.iin.has_request_error() {
+            self.promise
+                .complete(Err(WriteRequestError::IinError(response.header.iin.iin2)));
+            return None;
+        }
+
+        let headers = match response.objects {
+            Ok(x) => x,
+            Err(err) => {
+                self.promise
+                    .complete(Err(TaskError::MalformedResponse(err).into()));
+                return None;
+            }
+        };
+
+        match headers
+            .get_only_header()
+            .and_then(|header| header.details.free_format())
+        {
+            Some(FreeFormatVariation::Group91Var1(status)) => {
+                self.promise.complete(Ok(status.to_response()))
+            }
+            _ => self
+                .promise
+                .complete(Err(TaskError::UnexpectedResponseHeaders.into())),
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::format::write::{start_request, start_response};
+    use crate::app::gen::ranged::RangedVariation;
+    use crate::app::measurement::OctetString;
+    use crate::app::parse::parser::HeaderDetails;
+    use crate::app::{ActivationStatus, ControlField, Iin, Iin1, Iin2, ResponseFunction, Sequence};
+    use scursor::WriteCursor;
+
+    use super::*;
+
+    fn respond(task: ActivateConfigTask, iin: Iin, status: Option<&ActivateConfigResponse>) {
+        let mut buffer = [0; 64];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        let mut writer = start_response(
+            ControlField::response(Sequence::default(), true, true, false),
+            ResponseFunction::Response,
+            iin,
+            &mut cursor,
+        )
+        .unwrap();
+        if let Some(status) = status {
+            writer.write_free_format(status).unwrap();
+        }
+        let response = writer.to_parsed().to_response().unwrap();
+        assert!(task.handle(response).is_none());
+    }
+
+    #[test]
+    fn writes_file_specifications_and_octet_strings_in_order() {
+        let task = ActivateConfigTask::new(
+            vec![
+                ConfigurationReference::File("/cfg/a.xml".to_string()),
+                ConfigurationReference::OctetString(3, Box::new(OctetString::new(b"cfg").unwrap())),
+            ],
+            Promise::None,
+        );
+
+        let mut buffer = [0; 64];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        let mut writer = start_request(
+            ControlField::request(Sequence::default()),
+            task.function(),
+            &mut cursor,
+        )
+        .unwrap();
+        task.write(&mut writer).unwrap();
+        let request = writer.to_parsed().to_request().unwrap();
+
+        assert_eq!(request.header.function, FunctionCode::ActivateConfig);
+        let headers: Vec<HeaderDetails> =
+            request.objects.unwrap().iter().map(|x| x.details).collect();
+        assert_eq!(headers.len(), 2);
+        match &headers[0] {
+            HeaderDetails::TwoByteFreeFormat(1, FreeFormatVariation::Group70Var8(x)) => {
+                assert_eq!(x.file_specification, "/cfg/a.xml");
+            }
+            _ => unreachable!(),
+        }
+        match &headers[1] {
+            HeaderDetails::TwoByteStartStop(3, 3, RangedVariation::Group110VarX(3, seq)) => {
+                let items: Vec<(&[u8], u16)> = seq.iter().collect();
+                assert_eq!(items, vec![(&b"cfg"[..], 3)]);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn completes_with_parsed_status() {
+        let (tx, mut rx) = tokio::sync::oneshot::channel();
+        let task = ActivateConfigTask::new(
+            vec![ConfigurationReference::File("a".to_string())],
+            Promise::OneShot(tx),
+        );
+
+        let status = ActivateConfigResponse::new(
+            2000,
+            vec![ActivationStatus::new(1, "rejected".to_string())],
+        );
+        respond(task, Iin::default(), Some(&status));
+        assert_eq!(rx.try_recv().unwrap(), Ok(status));
+    }
+
+    #[test]
+    fn fails_when_status_is_missing() {
+        let (tx, mut rx) = tokio::sync::oneshot::channel();
+        let task = ActivateConfigTask::new(
+            vec![ConfigurationReference::File("a".to_string())],
+            Promise::OneShot(tx),
+        );
+
+        respond(task, Iin::default(), None);
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            Err(WriteRequestError::Task(
+                TaskError::UnexpectedResponseHeaders
+            ))
+        );
+    }
+
+    #[test]
+    fn reports_iin2_errors() {
+        let (tx, mut rx) = tokio::sync::oneshot::channel();
+        let task = ActivateConfigTask::new(
+            vec![ConfigurationReference::File("a".to_string())],
+            Promise::OneShot(tx),
+        );
+
+        respond(
+            task,
+            Iin::new(Iin1::default(), Iin2::NO_FUNC_CODE_SUPPORT),
+            None,
+        );
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            Err(WriteRequestError::IinError(Iin2::NO_FUNC_CODE_SUPPORT))
+        );
+    }
+}
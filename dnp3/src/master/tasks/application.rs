@@ -0,0 +1,155 @@
+use crate::app::application::Group90Var1;
+use crate::app::format::write::HeaderWriter;
+use crate::app::parse::parser::Response;
+use crate::app::variations::Variation;
+use crate::app::{ApplicationIdentifier, FunctionCode};
+use crate::master::error::{TaskError, WriteRequestError};
+use crate::master::handler::Promise;
+use crate::master::tasks::NonReadTask;
+
+use scursor::WriteError;
+
+/// Operation to perform on an application
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum ApplicationOperation {
+    /// Prepare the application to be started
+    Initialize,
+    /// Start running the application
+    Start,
+    /// Stop running the application
+    Stop,
+}
+
+impl ApplicationOperation {
+    fn function(self) -> FunctionCode {
+        match self {
+            Self::Initialize => FunctionCode::InitializeApplication,
+            Self::Start => FunctionCode::StartApplication,
+            Self::Stop => FunctionCode::StopApplication,
+        }
+    }
+}
+
+pub(crate) struct ApplicationTask {
+    operation: ApplicationOperation,
+    application: ApplicationIdentifier,
+    promise: Promise<Result<(), WriteRequestError>>,
+}
+
+impl ApplicationTask {
+    pub(crate) fn new(
+        operation: ApplicationOperation,
+        application: ApplicationIdentifier,
+        promise: Promise<Result<(), WriteRequestError>>,
+    ) -> Self {
+        Self {
+            operation,
+            application,
+            promise,
+        }
+    }
+
+    pub(crate) fn wrap(self) -> NonReadTask {
+        NonReadTask::Application(self)
+    }
+
+    pub(crate) fn function(&self) -> FunctionCode {
+        self.operation.function()
+    }
+
+    pub(crate) fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        match &self.application {
+            ApplicationIdentifier::All => writer.write_all_objects_header(Variation::Group90Var1),
+            ApplicationIdentifier::Name(name) => writer.write_free_format(&Group90Var1 {
+                name: name.as_str(),
+            }),
+        }
+    }
+
+    pub(crate) fn on_task_error(self, err: TaskError) {
+        self.promise.complete(Err(err.into()))
+    }
+
+    pub(crate) fn handle(self, response: Response) -> Option<NonReadTask> {
+        if !response.raw_objects.is_empty() {
+            self.promise
+                .complete(Err(TaskError::UnexpectedResponseHeaders.into()));
+            return None;
+        }
+
+        if response.header.iin.has_request_error() {
+            self.promise
+                .complete(Err(WriteRequestError::IinError(response.header.iin.iin2)));
+            return None;
+        }
+
+        self.promise.complete(Ok(()));
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::format::write::start_request;
+    use crate::app::gen::all::AllObjectsVariation;
+    use crate::app::parse::free_format::FreeFormatVariation;
+    use crate::app::parse::parser::HeaderDetails;
+    use crate::app::{ControlField, Sequence};
+    use scursor::WriteCursor;
+
+    use super::*;
+
+    #[test]
+    fn formats_all_applications_with_all_objects_qualifier() {
+        let task = ApplicationTask::new(
+            ApplicationOperation::Initialize,
+            ApplicationIdentifier::All,
+            Promise::None,
+        );
+
+        let mut buffer = [0; 32];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        let mut writer = start_request(
+            ControlField::request(Sequence::default()),
+            task.function(),
+            &mut cursor,
+        )
+        .unwrap();
+        task.write(&mut writer).unwrap();
+        let request = writer.to_parsed().to_request().unwrap();
+
+        assert_eq!(request.header.function, FunctionCode::InitializeApplication);
+        assert!(matches!(
+            request.objects.unwrap().get_only_header().unwrap().details,
+            HeaderDetails::AllObjects(AllObjectsVariation::Group90Var1)
+        ));
+    }
+
+    #[test]
+    fn formats_named_application_as_free_format() {
+        let task = ApplicationTask::new(
+            ApplicationOperation::Stop,
+            ApplicationIdentifier::Name("logic".to_string()),
+            Promise::None,
+        );
+
+        let mut buffer = [0; 32];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        let mut writer = start_request(
+            ControlField::request(Sequence::default()),
+            task.function(),
+            &mut cursor,
+        )
+        .unwrap();
+        task.write(&mut writer).unwrap();
+        let request = writer.to_parsed().to_request().unwrap();
+
+        assert_eq!(request.header.function, FunctionCode::StopApplication);
+        match request.objects.unwrap().get_only_header().unwrap().details {
+            HeaderDetails::TwoByteFreeFormat(1, FreeFormatVariation::Group90Var1(x)) => {
+                assert_eq!(x.name, "logic");
+            }
+            _ => unreachable!(),
+        }
+    }
+}
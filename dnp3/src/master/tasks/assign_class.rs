@@ -0,0 +1,123 @@
+use crate::app::format::write::HeaderWriter;
+use crate::app::parse::parser::Response;
+use crate::app::FunctionCode;
+use crate::master::error::{TaskError, WriteRequestError};
+use crate::master::handler::Promise;
+use crate::master::request::AssignClassRequest;
+use crate::master::tasks::NonReadTask;
+
+use scursor::WriteError;
+
+pub(crate) struct AssignClassTask {
+    request: AssignClassRequest,
+    promise: Promise<Result<(), WriteRequestError>>,
+}
+
+impl AssignClassTask {
+    pub(crate) fn new(
+        request: AssignClassRequest,
+        promise: Promise<Result<(), WriteRequestError>>,
+    ) -> Self {
+        Self { request, promise }
+    }
+
+    pub(crate) fn wrap(self) -> NonReadTask {
+        NonReadTask::AssignClass(self)
+    }
+
+    pub(crate) fn function(&self) -> FunctionCode {
+        FunctionCode::AssignClass
+    }
+
+    pub(crate) fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        self.request.write(writer)
+    }
+
+    pub(crate) fn on_task_error(self, err: TaskError) {
+        self.promise.complete(Err(err.into()))
+    }
+
+    pub(crate) fn handle(self, response: Response) -> Option<NonReadTask> {
+        if !response.raw_objects.is_empty() {
+            self.promise
+                .complete(Err(TaskError::UnexpectedResponseHeaders.into()));
+            return None;
+        }
+
+        if response.header.iin.has_request_error() {
+            self.promise
+                .complete(Err(WriteRequestError::IinError(response.header.iin.iin2)));
+            return None;
+        }
+
+        self.promise.complete(Ok(()));
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::format::write::start_request;
+    use crate::app::gen::all::AllObjectsVariation;
+    use crate::app::gen::ranged::RangedVariation;
+    use crate::app::parse::parser::HeaderDetails;
+    use crate::app::{ControlField, Sequence, Variation};
+    use crate::master::request::{AssignedClass, ReadHeader};
+    use scursor::WriteCursor;
+
+    use super::*;
+
+    #[test]
+    fn class_headers_precede_point_headers() {
+        let mut request = AssignClassRequest::new();
+        request.add(
+            AssignedClass::Class2,
+            ReadHeader::all_objects(Variation::Group1Var0),
+        );
+        request.add(
+            AssignedClass::Class2,
+            ReadHeader::one_byte_range(Variation::Group30Var0, 3, 5),
+        );
+        request.add(
+            AssignedClass::Class0,
+            ReadHeader::all_objects(Variation::Group20Var0),
+        );
+        let task = AssignClassTask::new(request, Promise::None);
+
+        let mut buffer = [0; 64];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        let mut writer = start_request(
+            ControlField::request(Sequence::default()),
+            task.function(),
+            &mut cursor,
+        )
+        .unwrap();
+        task.write(&mut writer).unwrap();
+        let request = writer.to_parsed().to_request().unwrap();
+
+        assert_eq!(request.header.function, FunctionCode::AssignClass);
+        let headers: Vec<HeaderDetails> =
+            request.objects.unwrap().iter().map(|x| x.details).collect();
+        assert_eq!(headers.len(), 5);
+        assert!(matches!(
+            headers[0],
+            HeaderDetails::AllObjects(AllObjectsVariation::Group60Var3)
+        ));
+        assert!(matches!(
+            headers[1],
+            HeaderDetails::AllObjects(AllObjectsVariation::Group1Var0)
+        ));
+        assert!(matches!(
+            headers[2],
+            HeaderDetails::OneByteStartStop(3, 5, RangedVariation::Group30Var0)
+        ));
+        assert!(matches!(
+            headers[3],
+            HeaderDetails::AllObjects(AllObjectsVariation::Group60Var1)
+        ));
+        assert!(matches!(
+            headers[4],
+            HeaderDetails::AllObjects(AllObjectsVariation::Group20Var0)
+        ));
+    }
+}
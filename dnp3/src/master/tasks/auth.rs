@@ -0,0 +1,166 @@
+use crate::app::auth::{Group120Var5, Group120Var6};
+use crate::app::format::write::HeaderWriter;
+use crate::app::parse::free_format::FreeFormatVariation;
+use crate::app::parse::parser::Response;
+use crate::app::variations::Group120Var4;
+use crate::app::FunctionCode;
+use crate::master::association::Association;
+use crate::master::error::{AuthenticationError, TaskError};
+use crate::master::tasks::NonReadTask;
+use crate::sa::crypto::{verify_mac, SessionKeys};
+use crate::sa::*;
+
+use scursor::WriteError;
+
+/// Session key change, started automatically when the association has no valid session keys
+pub(crate) enum SessionKeyTask {
+    /// request the key status of the user (g120v4)
+    Status(UserNumber),
+    /// send new session keys wrapped with the update key (g120v6)
+    Change {
+        user: UserNumber,
+        key_change_seq: u32,
+        keys: SessionKeys,
+        wrapped_key_data: Vec<u8>,
+    },
+}
+
+impl SessionKeyTask {
+    pub(crate) fn wrap(self) -> NonReadTask {
+        NonReadTask::SessionKey(self)
+    }
+
+    pub(crate) fn function(&self) -> FunctionCode {
+        FunctionCode::AuthenticateRequest
+    }
+
+    pub(crate) fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        match self {
+            SessionKeyTask::Status(user) => {
+                writer.write_count_of_one(Group120Var4 { user: user.value() })
+            }
+            SessionKeyTask::Change {
+                user,
+                key_change_seq,
+                wrapped_key_data,
+                ..
+            } => writer.write_free_format(&Group120Var6 {
+                key_change_seq: *key_change_seq,
+                user: user.value(),
+                wrapped_key_data,
+            }),
+        }
+    }
+
+    pub(crate) fn on_task_error(self, association: Option<&mut Association>, err: TaskError) {
+        if let Some(association) = association {
+            association.on_session_key_change_failure(err);
+        }
+    }
+
+    pub(crate) fn handle(
+        self,
+        association: &mut Association,
+        response: Response,
+    ) -> Option<NonReadTask> {
+        match self.process_response(association, response) {
+            Ok(next) => next,
+            Err(err) => {
+                association.on_session_key_change_failure(err.into());
+                None
+            }
+        }
+    }
+
+    fn process_response(
+        self,
+        association: &mut Association,
+        response: Response,
+    ) -> Result<Option<NonReadTask>, AuthenticationError> {
+        let config = *association
+            .auth()
+            .ok_or(AuthenticationError::NotConfigured)?
+            .config();
+
+        let status = match response
+            .objects
+            .ok()
+            .and_then(|x| x.get_only_header())
+            .and_then(|x| x.details.free_format())
+        {
+            Some(FreeFormatVariation::Group120Var5(x)) if x.user == config.user.value() => x,
+            Some(FreeFormatVariation::Group120Var7(x)) => {
+                return Err(AuthenticationError::ErrorResponse(AuthErrorCode::new(
+                    x.error_code,
+                )))
+            }
+            _ => return Err(AuthenticationError::UnexpectedResponse),
+        };
+
+        match self {
+            SessionKeyTask::Status(_) => {
+                let next = Self::change_keys(&config, &status)?;
+                Ok(Some(next.wrap()))
+            }
+            SessionKeyTask::Change {
+                keys,
+                key_change_seq,
+                ..
+            } => {
+                let key_status = KeyStatus::new(status.key_status);
+                if key_status != KeyStatus::Ok {
+                    return Err(AuthenticationError::BadKeyStatus(key_status));
+                }
+
+                let mac_algorithm = MacAlgorithm::from_u8(status.mac_algorithm)
+                    .ok_or(AuthenticationError::UnsupportedAlgorithm)?;
+
+                // the MAC proves that the outstation received the key change request
+                let auth = association
+                    .auth_mut()
+                    .ok_or(AuthenticationError::NotConfigured)?;
+                if !verify_mac(
+                    mac_algorithm,
+                    &keys.monitor,
+                    &[auth.last_request()],
+                    status.mac,
+                ) {
+                    return Err(AuthenticationError::InvalidMac);
+                }
+
+                auth.set_keys(keys, key_change_seq, mac_algorithm, &response)?;
+                association.on_session_key_change_success();
+                Ok(None)
+            }
+        }
+    }
+
+    fn change_keys(
+        config: &MasterAuthConfig,
+        status: &Group120Var5,
+    ) -> Result<SessionKeyTask, AuthenticationError> {
+        if KeyWrapAlgorithm::from_u8(status.key_wrap_algorithm)
+            != Some(config.update_key.algorithm())
+        {
+            return Err(AuthenticationError::UnsupportedAlgorithm);
+        }
+
+        let message = status
+            .to_status_message()
+            .map_err(|_| AuthenticationError::UnexpectedResponse)?;
+
+        let keys = SessionKeys::generate(config.update_key.algorithm())
+            .ok_or(AuthenticationError::NoSessionKeys)?;
+
+        let wrapped_key_data = keys
+            .wrap(&config.update_key, &message)
+            .ok_or(AuthenticationError::NoSessionKeys)?;
+
+        Ok(SessionKeyTask::Change {
+            user: config.user,
+            key_change_seq: status.key_change_seq,
+            keys,
+            wrapped_key_data,
+        })
+    }
+}
@@ -107,3 +107,68 @@ impl CommandTask {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::app::control::{CommandStatus, Group12Var1, OpType};
+    use crate::app::parse::parser::HeaderCollection;
+    use scursor::WriteCursor;
+
+    use super::*;
+
+    const PATTERN_HEADERS: &[u8] = &[
+        12, 2, 0x07, 0x01, 0x03, 0x01, 0xE8, 0x03, 0x00, 0x00, 0xE8, 0x03, 0x00, 0x00, 0x00, 12, 3,
+        0x00, 0x02, 0x05, 0b1101,
+    ];
+
+    fn pattern() -> CommandHeaders {
+        let mut builder = CommandBuilder::new();
+        builder.add_pattern_u8(
+            Group12Var1::from_op_type(OpType::LatchOn),
+            2,
+            5,
+            &[true, false, true, true],
+        );
+        builder.build()
+    }
+
+    #[test]
+    fn formats_pattern_control_block_and_mask() {
+        let mut buffer = [0; 64];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        pattern()
+            .write(&mut HeaderWriter::new(&mut cursor))
+            .unwrap();
+        assert_eq!(cursor.written(), PATTERN_HEADERS);
+    }
+
+    #[test]
+    fn compares_echoed_pattern_control_block_and_mask() {
+        let headers = pattern();
+
+        let echo = HeaderCollection::parse(FunctionCode::Response, PATTERN_HEADERS).unwrap();
+        assert_eq!(headers.compare(echo), Ok(()));
+
+        let mut bad_status = PATTERN_HEADERS.to_vec();
+        bad_status[14] = CommandStatus::NotSupported.as_u8();
+        let echo = HeaderCollection::parse(FunctionCode::Response, &bad_status).unwrap();
+        assert_eq!(
+            headers.compare(echo),
+            Err(CommandResponseError::BadStatus(CommandStatus::NotSupported))
+        );
+
+        let mut bad_mask = PATTERN_HEADERS.to_vec();
+        bad_mask[20] = 0b1111;
+        let echo = HeaderCollection::parse(FunctionCode::Response, &bad_mask).unwrap();
+        assert_eq!(
+            headers.compare(echo),
+            Err(CommandResponseError::ObjectValueMismatch)
+        );
+
+        let echo = HeaderCollection::parse(FunctionCode::Response, &PATTERN_HEADERS[..15]).unwrap();
+        assert_eq!(
+            headers.compare(echo),
+            Err(CommandResponseError::HeaderCountMismatch)
+        );
+    }
+}
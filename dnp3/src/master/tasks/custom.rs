@@ -0,0 +1,80 @@
+use crate::app::format::write::HeaderWriter;
+use crate::app::parse::parser::{HeaderCollection, HeaderDetails, ObjectHeader};
+use crate::app::{FunctionCode, ResponseHeader};
+use crate::master::error::TaskError;
+use crate::master::handler::{CustomObjectHeader, CustomResponseHandler, Promise};
+use crate::master::request::CustomRequest;
+use crate::master::tasks::ReadTask;
+
+use scursor::WriteError;
+
+pub(crate) struct CustomTask {
+    request: CustomRequest,
+    handler: Box<dyn CustomResponseHandler>,
+    promise: Promise<Result<(), TaskError>>,
+}
+
+impl CustomTask {
+    pub(crate) fn new(
+        request: CustomRequest,
+        handler: Box<dyn CustomResponseHandler>,
+        promise: Promise<Result<(), TaskError>>,
+    ) -> Self {
+        Self {
+            request,
+            handler,
+            promise,
+        }
+    }
+
+    pub(crate) fn wrap(self) -> ReadTask {
+        ReadTask::Custom(self)
+    }
+
+    pub(crate) fn function(&self) -> FunctionCode {
+        self.request.function()
+    }
+
+    pub(crate) fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        self.request.write(writer)
+    }
+
+    pub(crate) fn process_response(&mut self, header: ResponseHeader, objects: HeaderCollection) {
+        self.handler.begin_fragment(header);
+        for (object, raw) in objects.iter_with_bytes() {
+            self.handler.handle_header(get_custom_header(&object, raw));
+        }
+        self.handler.end_fragment(header);
+    }
+
+    pub(crate) fn on_task_error(self, err: TaskError) {
+        self.promise.complete(Err(err))
+    }
+
+    pub(crate) fn on_complete(self) {
+        self.promise.complete(Ok(()))
+    }
+}
+
+fn get_custom_header<'a>(header: &ObjectHeader, raw: &'a [u8]) -> CustomObjectHeader<'a> {
+    let (range, count) = match &header.details {
+        HeaderDetails::AllObjects(_) => (None, None),
+        HeaderDetails::OneByteStartStop(start, stop, _) => {
+            (Some((*start as u16, *stop as u16)), None)
+        }
+        HeaderDetails::TwoByteStartStop(start, stop, _) => (Some((*start, *stop)), None),
+        HeaderDetails::OneByteCount(count, _) => (None, Some(*count as u16)),
+        HeaderDetails::TwoByteCount(count, _) => (None, Some(*count)),
+        HeaderDetails::OneByteCountAndPrefix(count, _) => (None, Some(*count as u16)),
+        HeaderDetails::TwoByteCountAndPrefix(count, _) => (None, Some(*count)),
+        HeaderDetails::TwoByteFreeFormat(count, _) => (None, Some(*count as u16)),
+    };
+
+    CustomObjectHeader {
+        variation: header.variation,
+        qualifier: header.details.qualifier(),
+        range,
+        count,
+        raw,
+    }
+}
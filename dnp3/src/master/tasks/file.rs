@@ -0,0 +1,918 @@
+use std::sync::atomic::{AtomicU16, Ordering};
+
+use crate::app::file::*;
+use crate::app::format::write::HeaderWriter;
+use crate::app::parse::free_format::FreeFormatVariation;
+use crate::app::parse::parser::Response;
+use crate::app::{FileStatus, FunctionCode, Timestamp};
+use crate::master::error::{FileError, TaskError};
+use crate::master::file::{FileCredentials, FileReadConfig, FileReadSender, FileWriteConfig};
+use crate::master::handler::Promise;
+use crate::master::tasks::NonReadTask;
+
+use scursor::WriteError;
+
+/// Where the data read from a file is sent
+pub(crate) enum ReadSink {
+    /// blocks are forwarded to a user stream as they arrive
+    Stream(FileReadSender),
+    /// blocks are accumulated and parsed as a sequence of file descriptors
+    Directory(Vec<u8>, Promise<Result<Vec<FileInfo>, FileError>>),
+}
+
+pub(crate) enum FileOperation {
+    Read(ReadSink, FileReadConfig),
+    Write(Vec<u8>, FileWriteConfig, Promise<Result<(), FileError>>),
+    GetInfo(Promise<Result<FileInfo, FileError>>),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum State {
+    Authenticate,
+    Open,
+    Read {
+        handle: u32,
+        block: u32,
+        total: usize,
+    },
+    Write {
+        handle: u32,
+        block: u32,
+        offset: usize,
+        block_size: usize,
+    },
+    Close {
+        handle: u32,
+    },
+    /// abort the transfer, then report the error (if any) once the outstation responds
+    Abort {
+        handle: u32,
+        error: Option<FileError>,
+    },
+    GetInfo,
+}
+
+impl State {
+    /// handle of a file that must be aborted if the transfer fails
+    fn open_handle(&self) -> Option<u32> {
+        match self {
+            State::Read { handle, .. } | State::Write { handle, .. } => Some(*handle),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) struct FileTask {
+    file_name: String,
+    credentials: Option<FileCredentials>,
+    auth_key: u32,
+    request_id: u16,
+    state: State,
+    operation: FileOperation,
+}
+
+fn next_request_id() -> u16 {
+    static REQUEST_ID: AtomicU16 = AtomicU16::new(0);
+    REQUEST_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+impl ReadSink {
+    /// returns false if the user dropped the stream and the transfer should be aborted
+    async fn on_data(&mut self, data: &[u8]) -> bool {
+        match self {
+            // waits for the user to read queued blocks before the next block is requested
+            ReadSink::Stream(tx) => tx.send(data.to_vec()).await,
+            ReadSink::Directory(buffer, _) => {
+                buffer.extend_from_slice(data);
+                true
+            }
+        }
+    }
+
+    fn complete(self, result: Result<(), FileError>) {
+        match self {
+            ReadSink::Stream(tx) => tx.complete(result),
+            ReadSink::Directory(buffer, promise) => {
+                let result = result.and_then(|_| {
+                    Group70Var7::read_sequence(&buffer)
+                        .map(|items| items.into_iter().map(FileInfo::from).collect())
+                        .map_err(|err| FileError::Task(TaskError::MalformedResponse(err)))
+                });
+                promise.complete(result)
+            }
+        }
+    }
+}
+
+impl FileOperation {
+    fn complete(self, result: Result<(), FileError>) {
+        match self {
+            FileOperation::Read(sink, _) => sink.complete(result),
+            FileOperation::Write(_, _, promise) => promise.complete(result),
+            FileOperation::GetInfo(promise) => match result {
+                Ok(()) => {
+                    tracing::error!("get file info completed without a descriptor");
+                    promise.complete(Err(FileError::Task(TaskError::UnexpectedResponseHeaders)))
+                }
+                Err(err) => promise.complete(Err(err)),
+            },
+        }
+    }
+
+    fn mode(&self) -> FileMode {
+        match self {
+            FileOperation::Read(_, _) => FileMode::Read,
+            FileOperation::Write(_, config, _) => {
+                if config.append {
+                    FileMode::Append
+                } else {
+                    FileMode::Write
+                }
+            }
+            FileOperation::GetInfo(_) => FileMode::Null,
+        }
+    }
+}
+
+impl FileTask {
+    pub(crate) fn new(
+        file_name: String,
+        credentials: Option<FileCredentials>,
+        operation: FileOperation,
+    ) -> Self {
+        let state = match (&operation, &credentials) {
+            (FileOperation::GetInfo(_), _) => State::GetInfo,
+            (_, Some(_)) => State::Authenticate,
+            (_, None) => State::Open,
+        };
+
+        Self {
+            file_name,
+            credentials,
+            auth_key: 0,
+            request_id: next_request_id(),
+            state,
+            operation,
+        }
+    }
+
+    pub(crate) fn wrap(self) -> NonReadTask {
+        NonReadTask::File(self)
+    }
+
+    pub(crate) fn function(&self) -> FunctionCode {
+        match self.state {
+            State::Authenticate => FunctionCode::AuthenticateFile,
+            State::Open => FunctionCode::OpenFile,
+            State::Read { .. } => FunctionCode::Read,
+            State::Write { .. } => FunctionCode::Write,
+            State::Close { .. } => FunctionCode::CloseFile,
+            State::Abort { .. } => FunctionCode::AbortFile,
+            State::GetInfo => FunctionCode::GetFileInfo,
+        }
+    }
+
+    pub(crate) fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        match self.state {
+            State::Authenticate => {
+                let (user_name, password) = match &self.credentials {
+                    Some(x) => (x.user_name.as_str(), x.password.as_str()),
+                    None => ("", ""),
+                };
+                writer.write_free_format(&Group70Var2 {
+                    auth_key: 0,
+                    user_name,
+                    password,
+                })
+            }
+            State::Open => {
+                let (file_size, max_block_size, permissions) = match &self.operation {
+                    FileOperation::Read(_, config) => {
+                        (0, config.max_block_size, Permissions::default())
+                    }
+                    FileOperation::Write(data, config, _) => (
+                        u32::try_from(data.len()).map_err(|_| WriteError::NumericOverflow)?,
+                        config.max_block_size,
+                        config.permissions,
+                    ),
+                    FileOperation::GetInfo(_) => (0, 0, Permissions::default()),
+                };
+                writer.write_free_format(&Group70Var3 {
+                    time_of_creation: Timestamp::new(0),
+                    permissions,
+                    auth_key: self.auth_key,
+                    file_size,
+                    mode: self.operation.mode(),
+                    max_block_size,
+                    request_id: self.request_id,
+                    file_name: &self.file_name,
+                })
+            }
+            State::Read { handle, block, .. } => writer.write_free_format(&Group70Var5 {
+                file_handle: handle,
+                block_number: BlockNumber::new(block, false),
+                data: &[],
+            }),
+            State::Write {
+                handle,
+                block,
+                offset,
+                block_size,
+            } => {
+                let (data, last) = self.next_write_block(offset, block_size);
+                writer.write_free_format(&Group70Var5 {
+                    file_handle: handle,
+                    block_number: BlockNumber::new(block, last),
+                    data,
+                })
+            }
+            State::Close { handle } | State::Abort { handle, .. } => {
+                writer.write_free_format(&Group70Var4 {
+                    file_handle: handle,
+                    file_size: 0,
+                    max_block_size: 0,
+                    request_id: self.request_id,
+                    status: FileStatus::Success,
+                    text: "",
+                })
+            }
+            State::GetInfo => writer.write_free_format(&Group70Var7 {
+                file_type: FileType::Simple,
+                file_size: 0,
+                time_of_creation: Timestamp::new(0),
+                permissions: Permissions::default(),
+                request_id: self.request_id,
+                file_name: &self.file_name,
+            }),
+        }
+    }
+
+    pub(crate) fn on_task_error(self, err: TaskError) {
+        match self.state {
+            // the error that caused the abort is more useful than the failure of the abort
+            State::Abort {
+                error: Some(error), ..
+            } => self.operation.complete(Err(error)),
+            _ => self.operation.complete(Err(err.into())),
+        }
+    }
+
+    pub(crate) async fn handle(self, response: Response<'_>) -> Option<NonReadTask> {
+        if let State::Abort { error, .. } = self.state {
+            // the response to an abort doesn't change the outcome of the transfer
+            if let Some(err) = error {
+                self.operation.complete(Err(err));
+            }
+            return None;
+        }
+
+        let object = match Self::get_object(&response) {
+            Ok(x) => x,
+            Err(err) => return self.fail(err).map(|task| task.wrap()),
+        };
+
+        let result = match self.state {
+            State::Authenticate => self.handle_authenticate(object),
+            State::Open => self.handle_open(object),
+            State::Read {
+                handle,
+                block,
+                total,
+            } => self.handle_read(handle, block, total, object).await,
+            State::Write {
+                handle,
+                block,
+                offset,
+                block_size,
+            } => self.handle_write(handle, block, offset, block_size, object),
+            State::Close { handle } => self.handle_close(handle, object),
+            State::Abort { .. } => None,
+            State::GetInfo => self.handle_get_info(object),
+        };
+
+        result.map(|task| task.wrap())
+    }
+
+    fn get_object<'a>(response: &Response<'a>) -> Result<FreeFormatVariation<'a>, FileError> {
+        let headers = response
+            .objects
+            .map_err(|err| FileError::Task(TaskError::MalformedResponse(err)))?;
+
+        match headers.get_only_header() {
+            Some(header) => match header.details.free_format() {
+                Some(x) => Ok(x),
+                None => Err(FileError::Task(TaskError::UnexpectedResponseHeaders)),
+            },
+            None => {
+                if response.header.iin.has_request_error() {
+                    Err(FileError::IinError(response.header.iin.iin2))
+                } else {
+                    Err(FileError::Task(TaskError::UnexpectedResponseHeaders))
+                }
+            }
+        }
+    }
+
+    fn next_write_block(&self, offset: usize, block_size: usize) -> (&[u8], bool) {
+        let data = match &self.operation {
+            FileOperation::Write(data, _, _) => data.as_slice(),
+            _ => &[],
+        };
+        let end = data.len().min(offset + block_size);
+        (&data[offset..end], end == data.len())
+    }
+
+    fn with_state(self, state: State) -> Self {
+        Self { state, ..self }
+    }
+
+    /// complete the operation with an error, aborting the transfer first if a file is open
+    fn fail(self, err: FileError) -> Option<FileTask> {
+        if let Some(handle) = self.state.open_handle() {
+            tracing::warn!("aborting file transfer: {:?}", err);
+            return Some(self.with_state(State::Abort {
+                handle,
+                error: Some(err),
+            }));
+        }
+
+        self.operation.complete(Err(err));
+        None
+    }
+
+    fn unexpected(self) -> Option<FileTask> {
+        self.fail(FileError::Task(TaskError::UnexpectedResponseHeaders))
+    }
+
+    fn handle_authenticate(self, object: FreeFormatVariation) -> Option<FileTask> {
+        let auth_key = match object {
+            FreeFormatVariation::Group70Var2(x) => x.auth_key,
+            _ => return self.unexpected(),
+        };
+
+        if auth_key == 0 {
+            return self.fail(FileError::NoPermission);
+        }
+
+        Some(Self {
+            auth_key,
+            ..self.with_state(State::Open)
+        })
+    }
+
+    fn handle_open(self, object: FreeFormatVariation) -> Option<FileTask> {
+        let status = match object {
+            FreeFormatVariation::Group70Var4(x) => x,
+            _ => return self.unexpected(),
+        };
+
+        if status.status != FileStatus::Success {
+            return self.fail(FileError::BadStatus(status.status));
+        }
+
+        let handle = status.file_handle;
+        let next = match &self.operation {
+            FileOperation::Read(_, _) => State::Read {
+                handle,
+                block: 0,
+                total: 0,
+            },
+            FileOperation::Write(_, config, _) => {
+                let block_size = config.max_block_size.min(status.max_block_size).max(1);
+                State::Write {
+                    handle,
+                    block: 0,
+                    offset: 0,
+                    block_size: block_size as usize,
+                }
+            }
+            FileOperation::GetInfo(_) => return self.unexpected(),
+        };
+
+        Some(self.with_state(next))
+    }
+
+    async fn handle_read(
+        mut self,
+        handle: u32,
+        block: u32,
+        total: usize,
+        object: FreeFormatVariation<'_>,
+    ) -> Option<FileTask> {
+        let transport = match object {
+            FreeFormatVariation::Group70Var5(x) => x,
+            FreeFormatVariation::Group70Var6(x) => {
+                let status = if x.status == FileStatus::Success {
+                    FileStatus::Undefined
+                } else {
+                    x.status
+                };
+                return self.fail(FileError::BadStatus(status));
+            }
+            _ => return self.unexpected(),
+        };
+
+        if transport.file_handle != handle {
+            return self.fail(FileError::WrongHandle);
+        }
+
+        if transport.block_number.value != block {
+            return self.fail(FileError::BadBlockNum);
+        }
+
+        let total = total + transport.data.len();
+        let max_file_size = match &self.operation {
+            FileOperation::Read(_, config) => config.max_file_size,
+            _ => return self.unexpected(),
+        };
+        if total > max_file_size {
+            return self.fail(FileError::MaxLengthExceeded);
+        }
+
+        if let FileOperation::Read(sink, _) = &mut self.operation {
+            if !sink.on_data(transport.data).await {
+                tracing::info!("file read stream dropped, aborting transfer");
+                return Some(self.with_state(State::Abort {
+                    handle,
+                    error: None,
+                }));
+            }
+        }
+
+        if transport.block_number.last {
+            return Some(self.with_state(State::Close { handle }));
+        }
+
+        Some(self.with_state(State::Read {
+            handle,
+            block: block.wrapping_add(1),
+            total,
+        }))
+    }
+
+    fn handle_write(
+        self,
+        handle: u32,
+        block: u32,
+        offset: usize,
+        block_size: usize,
+        object: FreeFormatVariation,
+    ) -> Option<FileTask> {
+        let status = match object {
+            FreeFormatVariation::Group70Var6(x) => x,
+            _ => return self.unexpected(),
+        };
+
+        if status.file_handle != handle {
+            return self.fail(FileError::WrongHandle);
+        }
+
+        if status.status != FileStatus::Success {
+            return self.fail(FileError::BadStatus(status.status));
+        }
+
+        if status.block_number.value != block {
+            return self.fail(FileError::BadBlockNum);
+        }
+
+        let (data, last) = self.next_write_block(offset, block_size);
+        if last {
+            return Some(self.with_state(State::Close { handle }));
+        }
+
+        let offset = offset + data.len();
+        Some(self.with_state(State::Write {
+            handle,
+            block: block.wrapping_add(1),
+            offset,
+            block_size,
+        }))
+    }
+
+    fn handle_close(self, handle: u32, object: FreeFormatVariation) -> Option<FileTask> {
+        let status = match object {
+            FreeFormatVariation::Group70Var4(x) => x,
+            _ => return self.unexpected(),
+        };
+
+        if status.file_handle != handle {
+            return self.fail(FileError::WrongHandle);
+        }
+
+        if status.status != FileStatus::Success {
+            return self.fail(FileError::BadStatus(status.status));
+        }
+
+        self.operation.complete(Ok(()));
+        None
+    }
+
+    fn handle_get_info(self, object: FreeFormatVariation) -> Option<FileTask> {
+        match object {
+            FreeFormatVariation::Group70Var7(x) => {
+                if let FileOperation::GetInfo(promise) = self.operation {
+                    promise.complete(Ok(x.into()));
+                }
+                None
+            }
+            FreeFormatVariation::Group70Var4(x) if x.status != FileStatus::Success => {
+                self.fail(FileError::BadStatus(x.status))
+            }
+            _ => self.unexpected(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::format::write::{start_request, start_response};
+    use crate::app::parse::parser::Request;
+    use crate::app::Sequence;
+    use crate::app::{ControlField, Iin, ResponseFunction};
+    use crate::link::EndpointAddress;
+    use crate::master::association::{Association, AssociationConfig};
+    use crate::master::file::FileReadStream;
+    use crate::master::tasks::RequestWriter;
+    use crate::master::{AssociationHandler, NullAssociationInformation, NullReadHandler};
+    use scursor::WriteCursor;
+
+    use super::*;
+
+    struct DefaultAssociationHandler;
+    impl AssociationHandler for DefaultAssociationHandler {}
+
+    fn association() -> Association {
+        Association::new(
+            EndpointAddress::try_new(1).unwrap(),
+            AssociationConfig::default(),
+            Box::new(NullReadHandler),
+            Box::new(DefaultAssociationHandler),
+            Box::new(NullAssociationInformation),
+        )
+    }
+
+    fn check_request<F>(task: &NonReadTask, function: FunctionCode, check: F)
+    where
+        F: FnOnce(FreeFormatVariation),
+    {
+        let mut buffer = [0; 256];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        let mut writer = start_request(
+            ControlField::request(Sequence::default()),
+            task.function(),
+            &mut cursor,
+        )
+        .unwrap();
+        task.write(&mut writer).unwrap();
+        let request: Request = writer.to_parsed().to_request().unwrap();
+        assert_eq!(request.header.function, function);
+        let headers = request.objects.unwrap();
+        let header = headers.get_only_header().unwrap();
+        check(header.details.free_format().unwrap());
+    }
+
+    fn respond<V: FreeFormat>(
+        association: &mut Association,
+        task: NonReadTask,
+        object: &V,
+    ) -> Option<NonReadTask> {
+        futures::executor::block_on(handle_response(association, task, object))
+    }
+
+    async fn handle_response<V: FreeFormat>(
+        association: &mut Association,
+        task: NonReadTask,
+        object: &V,
+    ) -> Option<NonReadTask> {
+        let mut buffer = [0; 256];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        let mut writer = start_response(
+            ControlField::response(Sequence::default(), true, true, false),
+            ResponseFunction::Response,
+            Iin::default(),
+            &mut cursor,
+        )
+        .unwrap();
+        writer.write_free_format(object).unwrap();
+        let response = writer.to_parsed().to_response().unwrap();
+        task.handle(association, response).await
+    }
+
+    fn status(handle: u32, max_block_size: u16, status: FileStatus) -> Group70Var4<'static> {
+        Group70Var4 {
+            file_handle: handle,
+            file_size: 0,
+            max_block_size,
+            request_id: 0,
+            status,
+            text: "",
+        }
+    }
+
+    #[test]
+    fn reads_file_in_blocks() {
+        let mut association = association();
+        let (stream, tx) = FileReadStream::new(FileReadConfig::DEFAULT_MAX_QUEUED_BLOCKS);
+        let task = FileTask::new(
+            "/data/file.txt".to_string(),
+            None,
+            FileOperation::Read(ReadSink::Stream(tx), FileReadConfig::default()),
+        )
+        .wrap();
+
+        check_request(&task, FunctionCode::OpenFile, |obj| match obj {
+            FreeFormatVariation::Group70Var3(x) => {
+                assert_eq!(x.file_name, "/data/file.txt");
+                assert_eq!(x.mode, FileMode::Read);
+            }
+            _ => unreachable!(),
+        });
+        let task = respond(
+            &mut association,
+            task,
+            &status(42, 512, FileStatus::Success),
+        )
+        .unwrap();
+
+        check_request(&task, FunctionCode::Read, |obj| match obj {
+            FreeFormatVariation::Group70Var5(x) => {
+                assert_eq!(x.file_handle, 42);
+                assert_eq!(x.block_number, BlockNumber::new(0, false));
+            }
+            _ => unreachable!(),
+        });
+        let task = respond(
+            &mut association,
+            task,
+            &Group70Var5 {
+                file_handle: 42,
+                block_number: BlockNumber::new(0, false),
+                data: &[0x01, 0x02],
+            },
+        )
+        .unwrap();
+
+        check_request(&task, FunctionCode::Read, |obj| match obj {
+            FreeFormatVariation::Group70Var5(x) => {
+                assert_eq!(x.block_number, BlockNumber::new(1, false));
+            }
+            _ => unreachable!(),
+        });
+        let task = respond(
+            &mut association,
+            task,
+            &Group70Var5 {
+                file_handle: 42,
+                block_number: BlockNumber::new(1, true),
+                data: &[0x03],
+            },
+        )
+        .unwrap();
+
+        check_request(&task, FunctionCode::CloseFile, |_| {});
+        assert!(respond(&mut association, task, &status(42, 0, FileStatus::Success)).is_none());
+
+        let data = futures::executor::block_on(stream.read_to_end()).unwrap();
+        assert_eq!(data, vec![0x01, 0x02, 0x03]);
+    }
+
+    #[test]
+    fn aborts_read_when_stream_is_dropped() {
+        let mut association = association();
+        let (stream, tx) = FileReadStream::new(FileReadConfig::DEFAULT_MAX_QUEUED_BLOCKS);
+        let task = FileTask::new(
+            "file.txt".to_string(),
+            None,
+            FileOperation::Read(ReadSink::Stream(tx), FileReadConfig::default()),
+        )
+        .wrap();
+
+        let task = respond(&mut association, task, &status(7, 512, FileStatus::Success)).unwrap();
+        drop(stream);
+        let task = respond(
+            &mut association,
+            task,
+            &Group70Var5 {
+                file_handle: 7,
+                block_number: BlockNumber::new(0, false),
+                data: &[0xFF],
+            },
+        )
+        .unwrap();
+
+        check_request(&task, FunctionCode::AbortFile, |obj| match obj {
+            FreeFormatVariation::Group70Var4(x) => assert_eq!(x.file_handle, 7),
+            _ => unreachable!(),
+        });
+    }
+
+    #[tokio::test]
+    async fn waits_for_stream_to_have_room_before_reading_next_block() {
+        let mut association = association();
+        let (mut stream, tx) = FileReadStream::new(1);
+        let task = FileTask::new(
+            "file.txt".to_string(),
+            None,
+            FileOperation::Read(ReadSink::Stream(tx), FileReadConfig::default()),
+        )
+        .wrap();
+
+        let task = respond(&mut association, task, &status(3, 512, FileStatus::Success)).unwrap();
+        let task = respond(
+            &mut association,
+            task,
+            &Group70Var5 {
+                file_handle: 3,
+                block_number: BlockNumber::new(0, false),
+                data: &[0x01],
+            },
+        )
+        .unwrap();
+
+        // the stream is full, so the task can't request the next block
+        let block = Group70Var5 {
+            file_handle: 3,
+            block_number: BlockNumber::new(1, true),
+            data: &[0x02],
+        };
+        let mut reply = Box::pin(handle_response(&mut association, task, &block));
+        assert!(futures::poll!(&mut reply).is_pending());
+
+        assert_eq!(stream.next().await, Ok(Some(vec![0x01])));
+        let task = reply.await.unwrap();
+        check_request(&task, FunctionCode::CloseFile, |_| {});
+        assert!(respond(&mut association, task, &status(3, 0, FileStatus::Success)).is_none());
+
+        assert_eq!(
+            futures::StreamExt::collect::<Vec<_>>(stream).await,
+            vec![Ok(vec![0x02])]
+        );
+    }
+
+    #[test]
+    fn aborts_read_before_reporting_bad_block_number() {
+        let mut association = association();
+        let (tx, mut rx) = tokio::sync::oneshot::channel();
+        let task = FileTask::new(
+            "/data".to_string(),
+            None,
+            FileOperation::Read(
+                ReadSink::Directory(Vec::new(), Promise::OneShot(tx)),
+                FileReadConfig::default(),
+            ),
+        )
+        .wrap();
+
+        let task = respond(&mut association, task, &status(9, 512, FileStatus::Success)).unwrap();
+        let task = respond(
+            &mut association,
+            task,
+            &Group70Var5 {
+                file_handle: 9,
+                block_number: BlockNumber::new(1, false),
+                data: &[0xFF],
+            },
+        )
+        .unwrap();
+
+        check_request(&task, FunctionCode::AbortFile, |obj| match obj {
+            FreeFormatVariation::Group70Var4(x) => assert_eq!(x.file_handle, 9),
+            _ => unreachable!(),
+        });
+        assert!(rx.try_recv().is_err());
+
+        assert!(respond(&mut association, task, &status(9, 0, FileStatus::Success)).is_none());
+        assert_eq!(rx.try_recv().unwrap(), Err(FileError::BadBlockNum));
+    }
+
+    #[test]
+    fn writes_file_using_negotiated_block_size() {
+        let mut association = association();
+        let (tx, mut rx) = tokio::sync::oneshot::channel();
+        let config = FileWriteConfig {
+            max_block_size: 1024,
+            ..Default::default()
+        };
+        let task = FileTask::new(
+            "file.txt".to_string(),
+            Some(FileCredentials::new("user", "pass")),
+            FileOperation::Write(vec![1, 2, 3, 4, 5], config, Promise::OneShot(tx)),
+        )
+        .wrap();
+
+        check_request(&task, FunctionCode::AuthenticateFile, |obj| match obj {
+            FreeFormatVariation::Group70Var2(x) => {
+                assert_eq!(x.user_name, "user");
+                assert_eq!(x.password, "pass");
+            }
+            _ => unreachable!(),
+        });
+        let task = respond(
+            &mut association,
+            task,
+            &Group70Var2 {
+                auth_key: 0xCAFE,
+                user_name: "",
+                password: "",
+            },
+        )
+        .unwrap();
+
+        check_request(&task, FunctionCode::OpenFile, |obj| match obj {
+            FreeFormatVariation::Group70Var3(x) => {
+                assert_eq!(x.auth_key, 0xCAFE);
+                assert_eq!(x.mode, FileMode::Write);
+                assert_eq!(x.file_size, 5);
+            }
+            _ => unreachable!(),
+        });
+        let mut task = respond(&mut association, task, &status(1, 3, FileStatus::Success)).unwrap();
+
+        for (block, expected, last) in [(0, &[1u8, 2, 3][..], false), (1, &[4, 5][..], true)] {
+            check_request(&task, FunctionCode::Write, |obj| match obj {
+                FreeFormatVariation::Group70Var5(x) => {
+                    assert_eq!(x.block_number, BlockNumber::new(block, last));
+                    assert_eq!(x.data, expected);
+                }
+                _ => unreachable!(),
+            });
+            task = respond(
+                &mut association,
+                task,
+                &Group70Var6 {
+                    file_handle: 1,
+                    block_number: BlockNumber::new(block, last),
+                    status: FileStatus::Success,
+                    text: "",
+                },
+            )
+            .unwrap();
+        }
+
+        check_request(&task, FunctionCode::CloseFile, |_| {});
+        assert!(respond(&mut association, task, &status(1, 0, FileStatus::Success)).is_none());
+        assert_eq!(rx.try_recv().unwrap(), Ok(()));
+    }
+
+    #[test]
+    fn reads_directory_listing() {
+        let mut association = association();
+        let (tx, mut rx) = tokio::sync::oneshot::channel();
+        let task = FileTask::new(
+            "/data".to_string(),
+            None,
+            FileOperation::Read(
+                ReadSink::Directory(Vec::new(), Promise::OneShot(tx)),
+                FileReadConfig::default(),
+            ),
+        )
+        .wrap();
+
+        let entry = FileInfo {
+            file_name: "a.txt".to_string(),
+            file_type: FileType::Simple,
+            size: 12,
+            time_created: Timestamp::new(1000),
+            permissions: Permissions::default(),
+        };
+        let mut data = Vec::new();
+        entry.encode_directory_entry(&mut data);
+
+        let task = respond(&mut association, task, &status(3, 512, FileStatus::Success)).unwrap();
+        let task = respond(
+            &mut association,
+            task,
+            &Group70Var5 {
+                file_handle: 3,
+                block_number: BlockNumber::new(0, true),
+                data: &data,
+            },
+        )
+        .unwrap();
+        assert!(respond(&mut association, task, &status(3, 0, FileStatus::Success)).is_none());
+        assert_eq!(rx.try_recv().unwrap(), Ok(vec![entry]));
+    }
+
+    #[test]
+    fn reports_bad_status_on_open() {
+        let mut association = association();
+        let (tx, mut rx) = tokio::sync::oneshot::channel();
+        let task = FileTask::new(
+            "file.txt".to_string(),
+            None,
+            FileOperation::Write(Vec::new(), FileWriteConfig::default(), Promise::OneShot(tx)),
+        )
+        .wrap();
+
+        assert!(respond(
+            &mut association,
+            task,
+            &status(0, 0, FileStatus::FileNotFound)
+        )
+        .is_none());
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            Err(FileError::BadStatus(FileStatus::FileNotFound))
+        );
+    }
+}
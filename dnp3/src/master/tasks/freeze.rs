@@ -0,0 +1,154 @@
+use crate::app::format::write::HeaderWriter;
+use crate::app::parse::parser::Response;
+use crate::app::FunctionCode;
+use crate::master::error::{TaskError, WriteRequestError};
+use crate::master::handler::Promise;
+use crate::master::request::FreezeRequest;
+use crate::master::tasks::NonReadTask;
+
+use scursor::WriteError;
+
+pub(crate) struct FreezeTask {
+    request: FreezeRequest,
+    promise: Promise<Result<(), WriteRequestError>>,
+}
+
+impl FreezeTask {
+    pub(crate) fn new(
+        request: FreezeRequest,
+        promise: Promise<Result<(), WriteRequestError>>,
+    ) -> Self {
+        Self { request, promise }
+    }
+
+    pub(crate) fn wrap(self) -> NonReadTask {
+        NonReadTask::Freeze(self)
+    }
+
+    pub(crate) fn function(&self) -> FunctionCode {
+        self.request.function()
+    }
+
+    pub(crate) fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        self.request.write(writer)
+    }
+
+    pub(crate) fn on_task_error(self, err: TaskError) {
+        self.promise.complete(Err(err.into()))
+    }
+
+    pub(crate) fn on_request_sent(self) -> Option<NonReadTask> {
+        self.promise.complete(Ok(()));
+        None
+    }
+
+    pub(crate) fn handle(self, response: Response) -> Option<NonReadTask> {
+        if !response.raw_objects.is_empty() {
+            self.promise
+                .complete(Err(TaskError::UnexpectedResponseHeaders.into()));
+            return None;
+        }
+
+        if response.header.iin.has_request_error() {
+            self.promise
+                .complete(Err(WriteRequestError::IinError(response.header.iin.iin2)));
+            return None;
+        }
+
+        self.promise.complete(Ok(()));
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::format::write::start_request;
+    use crate::app::gen::all::AllObjectsVariation;
+    use crate::app::gen::count::CountVariation;
+    use crate::app::gen::ranged::RangedVariation;
+    use crate::app::parse::parser::HeaderDetails;
+    use crate::app::{ControlField, Sequence, Timestamp, Variation};
+    use crate::master::request::{FreezeOperation, ReadHeader};
+    use scursor::WriteCursor;
+
+    use super::*;
+
+    #[test]
+    fn selects_function_code_from_operation() {
+        let at_time = FreezeOperation::AtTime {
+            time: Timestamp::new(0),
+            interval_ms: 0,
+        };
+
+        let cases = [
+            (
+                FreezeRequest::new(FreezeOperation::Immediate),
+                FunctionCode::ImmediateFreeze,
+            ),
+            (
+                FreezeRequest::no_ack(FreezeOperation::Immediate),
+                FunctionCode::ImmediateFreezeNoResponse,
+            ),
+            (
+                FreezeRequest::new(FreezeOperation::Clear),
+                FunctionCode::FreezeClear,
+            ),
+            (
+                FreezeRequest::no_ack(FreezeOperation::Clear),
+                FunctionCode::FreezeClearNoResponse,
+            ),
+            (FreezeRequest::new(at_time), FunctionCode::FreezeAtTime),
+            (
+                FreezeRequest::no_ack(at_time),
+                FunctionCode::FreezeAtTimeNoResponse,
+            ),
+        ];
+
+        for (request, function) in cases {
+            assert_eq!(FreezeTask::new(request, Promise::None).function(), function);
+        }
+    }
+
+    #[test]
+    fn freeze_at_time_starts_with_g50v2() {
+        let mut request = FreezeRequest::new(FreezeOperation::AtTime {
+            time: Timestamp::new(1000),
+            interval_ms: 60_000,
+        });
+        request.add(ReadHeader::all_objects(Variation::Group20Var0));
+        request.add(ReadHeader::one_byte_range(Variation::Group20Var0, 2, 4));
+        let task = FreezeTask::new(request, Promise::None);
+
+        let mut buffer = [0; 64];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        let mut writer = start_request(
+            ControlField::request(Sequence::default()),
+            task.function(),
+            &mut cursor,
+        )
+        .unwrap();
+        task.write(&mut writer).unwrap();
+        let request = writer.to_parsed().to_request().unwrap();
+
+        assert_eq!(request.header.function, FunctionCode::FreezeAtTime);
+        let headers: Vec<HeaderDetails> =
+            request.objects.unwrap().iter().map(|x| x.details).collect();
+        assert_eq!(headers.len(), 3);
+        match headers[0] {
+            HeaderDetails::OneByteCount(1, CountVariation::Group50Var2(seq)) => {
+                let value = seq.single().unwrap();
+                assert_eq!(value.time, Timestamp::new(1000));
+                assert_eq!(value.interval, 60_000);
+            }
+            _ => unreachable!(),
+        }
+        assert!(matches!(
+            headers[1],
+            HeaderDetails::AllObjects(AllObjectsVariation::Group20Var0)
+        ));
+        assert!(matches!(
+            headers[2],
+            HeaderDetails::OneByteStartStop(2, 4, RangedVariation::Group20Var0)
+        ));
+    }
+}
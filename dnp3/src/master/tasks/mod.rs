@@ -9,20 +9,39 @@ use crate::master::extract::extract_measurements;
 use crate::master::handler::Promise;
 use crate::master::poll::Poll;
 use crate::master::request::{Classes, EventClasses};
+use crate::master::scheduler::{TaskPriority, TaskScheduler};
+use crate::master::tasks::activate_config::ActivateConfigTask;
+use crate::master::tasks::application::ApplicationTask;
+use crate::master::tasks::assign_class::AssignClassTask;
+use crate::master::tasks::auth::SessionKeyTask;
 use crate::master::tasks::auto::AutoTask;
 use crate::master::tasks::command::CommandTask;
+use crate::master::tasks::custom::CustomTask;
+use crate::master::tasks::file::FileTask;
+use crate::master::tasks::freeze::FreezeTask;
 use crate::master::tasks::read::SingleReadTask;
 use crate::master::tasks::restart::RestartTask;
 use crate::master::tasks::time::TimeSyncTask;
+use crate::master::tasks::unsolicited::UnsolicitedTask;
+use crate::master::tasks::write::WriteTask;
 use crate::master::{ReadType, TaskType};
 
 use scursor::WriteError;
 
+pub(crate) mod activate_config;
+pub(crate) mod application;
+pub(crate) mod assign_class;
+pub(crate) mod auth;
 pub(crate) mod auto;
 pub(crate) mod command;
+pub(crate) mod custom;
+pub(crate) mod file;
+pub(crate) mod freeze;
 pub(crate) mod read;
 pub(crate) mod restart;
 pub(crate) mod time;
+pub(crate) mod unsolicited;
+pub(crate) mod write;
 
 /// Queued task requiring I/O
 pub(crate) struct AssociationTask {
@@ -41,7 +60,7 @@ impl AssociationTask {
 /// There are two broad categories of tasks. Reads
 /// require handling for multi-fragmented responses.
 pub(crate) enum Task {
-    /// Reads require handling for multi-fragmented responses
+    /// Reads (and custom requests) require handling for multi-fragmented responses
     Read(ReadTask),
     /// NonRead tasks always require FIR/FIN == 1, but might require multiple read/response cycles, e.g. SBO
     NonRead(NonReadTask),
@@ -76,10 +95,20 @@ impl Task {
         Some(self)
     }
 
+    /// Priority of the task when one isn't specified explicitly
+    pub(crate) fn priority(&self, scheduler: &dyn TaskScheduler) -> TaskPriority {
+        match self {
+            Task::Read(t) => scheduler.priority(t.as_task_type()),
+            Task::NonRead(t) => scheduler.priority(t.as_task_type()),
+            // link status requests don't have a task type
+            Task::LinkStatus(_) => TaskPriority::Normal,
+        }
+    }
+
     pub(crate) fn get_id(&self) -> TaskId {
         match self {
             Task::LinkStatus(_) => TaskId::LinkStatus,
-            Task::Read(_) => TaskId::Function(FunctionCode::Read),
+            Task::Read(t) => TaskId::Function(t.function()),
             Task::NonRead(t) => TaskId::Function(t.function()),
         }
     }
@@ -99,6 +128,8 @@ pub(crate) enum ReadTask {
     EventScan(EventClasses),
     /// One-time read request
     SingleRead(SingleReadTask),
+    /// user-defined request with an arbitrary function code
+    Custom(CustomTask),
 }
 
 pub(crate) enum NonReadTask {
@@ -110,11 +141,30 @@ pub(crate) enum NonReadTask {
     TimeSync(TimeSyncTask),
     /// restart operation
     Restart(RestartTask),
+    /// file transfer operation
+    File(FileTask),
+    /// user-defined write request
+    Write(WriteTask),
+    /// assign the event class of points
+    AssignClass(AssignClassTask),
+    /// freeze counters
+    Freeze(FreezeTask),
+    /// initialize, start, or stop an application
+    Application(ApplicationTask),
+    /// activate configurations
+    ActivateConfig(ActivateConfigTask),
+    /// enable or disable unsolicited reporting at the request of the user
+    Unsolicited(UnsolicitedTask),
+    /// change the secure authentication session keys
+    SessionKey(SessionKeyTask),
 }
 
 impl RequestWriter for ReadTask {
     fn function(&self) -> FunctionCode {
-        FunctionCode::Read
+        match self {
+            ReadTask::Custom(task) => task.function(),
+            _ => FunctionCode::Read,
+        }
     }
 
     fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
@@ -123,6 +173,7 @@ impl RequestWriter for ReadTask {
             ReadTask::StartupIntegrity(classes) => classes.write(writer),
             ReadTask::EventScan(classes) => classes.write(writer),
             ReadTask::SingleRead(req) => req.format(writer),
+            ReadTask::Custom(task) => task.write(writer),
         }
     }
 }
@@ -138,6 +189,14 @@ impl RequestWriter for NonReadTask {
             NonReadTask::Command(t) => t.write(writer),
             NonReadTask::TimeSync(t) => t.write(writer),
             NonReadTask::Restart(_) => Ok(()),
+            NonReadTask::File(t) => t.write(writer),
+            NonReadTask::Write(t) => t.write(writer),
+            NonReadTask::AssignClass(t) => t.write(writer),
+            NonReadTask::Freeze(t) => t.write(writer),
+            NonReadTask::Application(t) => t.write(writer),
+            NonReadTask::ActivateConfig(t) => t.write(writer),
+            NonReadTask::Unsolicited(t) => t.write(writer),
+            NonReadTask::SessionKey(t) => t.write(writer),
         }
     }
 }
@@ -165,11 +224,18 @@ impl ReadTask {
             }
             ReadTask::SingleRead(task) => match &mut task.custom_handler {
                 Some(handler) => {
-                    extract_measurements(ReadType::SinglePoll, header, objects, handler.as_mut())
-                        .await
+                    extract_measurements(
+                        ReadType::SinglePoll,
+                        header,
+                        objects,
+                        association.extract_state_mut(),
+                        handler.as_mut(),
+                    )
+                    .await
                 }
                 None => association.handle_read_response(header, objects).await,
             },
+            ReadTask::Custom(task) => task.process_response(header, objects),
         }
     }
 
@@ -179,6 +245,7 @@ impl ReadTask {
             ReadTask::PeriodicPoll(poll) => association.complete_poll(poll.id),
             ReadTask::EventScan(_) => association.on_event_scan_complete(),
             ReadTask::SingleRead(task) => task.on_complete(),
+            ReadTask::Custom(task) => task.on_complete(),
         }
     }
 
@@ -201,6 +268,7 @@ impl ReadTask {
                 }
             }
             ReadTask::SingleRead(task) => task.on_task_error(err),
+            ReadTask::Custom(task) => task.on_task_error(err),
         }
     }
 
@@ -210,6 +278,7 @@ impl ReadTask {
             Self::StartupIntegrity(_) => TaskType::StartupIntegrity,
             Self::EventScan(_) => TaskType::AutoEventScan,
             Self::SingleRead(_) => TaskType::UserRead,
+            Self::Custom(_) => TaskType::Custom,
         }
     }
 }
@@ -225,6 +294,14 @@ impl NonReadTask {
             NonReadTask::Auto(_) => Some(self),
             NonReadTask::TimeSync(task) => task.start(association).map(|task| task.wrap()),
             NonReadTask::Restart(_) => Some(self),
+            NonReadTask::File(_) => Some(self),
+            NonReadTask::Write(_) => Some(self),
+            NonReadTask::AssignClass(_) => Some(self),
+            NonReadTask::Freeze(_) => Some(self),
+            NonReadTask::Application(_) => Some(self),
+            NonReadTask::ActivateConfig(_) => Some(self),
+            NonReadTask::Unsolicited(_) => Some(self),
+            NonReadTask::SessionKey(_) => Some(self),
         }
     }
 
@@ -234,6 +311,14 @@ impl NonReadTask {
             NonReadTask::Auto(task) => task.function(),
             NonReadTask::TimeSync(task) => task.function(),
             NonReadTask::Restart(task) => task.function(),
+            NonReadTask::File(task) => task.function(),
+            NonReadTask::Write(task) => task.function(),
+            NonReadTask::AssignClass(task) => task.function(),
+            NonReadTask::Freeze(task) => task.function(),
+            NonReadTask::Application(task) => task.function(),
+            NonReadTask::ActivateConfig(task) => task.function(),
+            NonReadTask::Unsolicited(task) => task.function(),
+            NonReadTask::SessionKey(task) => task.function(),
         }
     }
 
@@ -243,13 +328,30 @@ impl NonReadTask {
             NonReadTask::TimeSync(task) => task.on_task_error(association, err),
             NonReadTask::Auto(task) => task.on_task_error(association, err),
             NonReadTask::Restart(task) => task.on_task_error(err),
+            NonReadTask::File(task) => task.on_task_error(err),
+            NonReadTask::Write(task) => task.on_task_error(err),
+            NonReadTask::AssignClass(task) => task.on_task_error(err),
+            NonReadTask::Freeze(task) => task.on_task_error(err),
+            NonReadTask::Application(task) => task.on_task_error(err),
+            NonReadTask::ActivateConfig(task) => task.on_task_error(err),
+            NonReadTask::Unsolicited(task) => task.on_task_error(err),
+            NonReadTask::SessionKey(task) => task.on_task_error(association, err),
         }
     }
 
-    pub(crate) fn handle(
+    /// Complete a task whose request is not answered by the outstation
+    pub(crate) fn on_request_sent(self) -> Option<NonReadTask> {
+        match self {
+            NonReadTask::Freeze(task) => task.on_request_sent(),
+            // other tasks never use a function code without a response
+            _ => None,
+        }
+    }
+
+    pub(crate) async fn handle(
         self,
         association: &mut Association,
-        response: Response,
+        response: Response<'_>,
     ) -> Option<NonReadTask> {
         match self {
             NonReadTask::Command(task) => task.handle(response),
@@ -259,6 +361,14 @@ impl NonReadTask {
             },
             NonReadTask::TimeSync(task) => task.handle(association, response),
             NonReadTask::Restart(task) => task.handle(response),
+            NonReadTask::File(task) => task.handle(response).await,
+            NonReadTask::Write(task) => task.handle(response),
+            NonReadTask::AssignClass(task) => task.handle(response),
+            NonReadTask::Freeze(task) => task.handle(response),
+            NonReadTask::Application(task) => task.handle(response),
+            NonReadTask::ActivateConfig(task) => task.handle(response),
+            NonReadTask::Unsolicited(task) => task.handle(association, response),
+            NonReadTask::SessionKey(task) => task.handle(association, response),
         }
     }
 
@@ -272,6 +382,20 @@ impl NonReadTask {
             },
             Self::TimeSync(_) => TaskType::TimeSync,
             Self::Restart(_) => TaskType::Restart,
+            Self::File(_) => TaskType::FileTransfer,
+            Self::Write(_) => TaskType::Write,
+            Self::AssignClass(_) => TaskType::AssignClass,
+            Self::Freeze(_) => TaskType::Freeze,
+            Self::Application(_) => TaskType::ApplicationControl,
+            Self::ActivateConfig(_) => TaskType::ActivateConfig,
+            Self::Unsolicited(x) => {
+                if x.is_enable() {
+                    TaskType::EnableUnsolicited
+                } else {
+                    TaskType::DisableUnsolicited
+                }
+            }
+            Self::SessionKey(_) => TaskType::SessionKeyChange,
         }
     }
 }
@@ -172,7 +172,7 @@ mod tests {
         writer.write_count_of_one(Group52Var1 { time: 2 }).unwrap();
         let response = writer.to_parsed().to_response().unwrap();
 
-        assert!(task.handle(&mut association, response).is_none());
+        assert!(futures::executor::block_on(task.handle(&mut association, response)).is_none());
         assert_eq!(rx.try_recv().unwrap(), Ok(Duration::from_secs(2)));
     }
 
@@ -220,7 +220,7 @@ mod tests {
         writer.write_count_of_one(Group52Var2 { time: 2 }).unwrap();
         let response = writer.to_parsed().to_response().unwrap();
 
-        assert!(task.handle(&mut association, response).is_none());
+        assert!(futures::executor::block_on(task.handle(&mut association, response)).is_none());
         assert_eq!(rx.try_recv().unwrap(), Ok(Duration::from_millis(2)));
     }
 }
@@ -398,7 +398,7 @@ mod tests {
         .unwrap();
         let response = writer.to_parsed().to_response().unwrap();
 
-        assert!(task.handle(association, response).is_none());
+        assert!(futures::executor::block_on(task.handle(association, response)).is_none());
     }
 
     mod non_lan {
@@ -480,7 +480,7 @@ mod tests {
                     .to_response()
                     .unwrap();
 
-                task.handle(&mut association, response)
+                task.handle(&mut association, response).await
             }
             .unwrap();
             let task = check_write_request(task, &mut association, system_time);
@@ -518,7 +518,7 @@ mod tests {
 
                 let response = writer.to_parsed().to_response().unwrap();
 
-                assert!(task.handle(&mut association, response).is_none());
+                assert!(task.handle(&mut association, response).await.is_none());
             }
             assert_eq!(
                 rx.try_recv().unwrap(),
@@ -547,7 +547,7 @@ mod tests {
 
                 let response = writer.to_parsed().to_response().unwrap();
 
-                assert!(task.handle(&mut association, response).is_none());
+                assert!(task.handle(&mut association, response).await.is_none());
             }
             assert_matches!(
                 rx.try_recv().unwrap(),
@@ -576,7 +576,7 @@ mod tests {
 
                 let response = writer.to_parsed().to_response().unwrap();
 
-                assert!(task.handle(&mut association, response).is_none());
+                assert!(task.handle(&mut association, response).await.is_none());
             }
             assert_matches!(rx.try_recv().unwrap(), Err(TimeSyncError::StillNeedsTime));
         }
@@ -663,7 +663,7 @@ mod tests {
                 .unwrap();
             let response = writer.to_parsed().to_response().unwrap();
 
-            task.handle(association, response)
+            futures::executor::block_on(task.handle(association, response))
         }
 
         fn check_write_request(
@@ -737,7 +737,7 @@ mod tests {
 
                 let response = writer.to_parsed().to_response().unwrap();
 
-                assert!(task.handle(&mut association, response).is_none());
+                assert!(task.handle(&mut association, response).await.is_none());
             }
             assert_eq!(
                 rx.try_recv().unwrap(),
@@ -766,7 +766,7 @@ mod tests {
 
                 let response = writer.to_parsed().to_response().unwrap();
 
-                assert!(task.handle(&mut association, response).is_none());
+                assert!(task.handle(&mut association, response).await.is_none());
             }
             assert_eq!(
                 rx.try_recv().unwrap(),
@@ -794,7 +794,7 @@ mod tests {
 
                 let response = writer.to_parsed().to_response().unwrap();
 
-                assert!(task.handle(&mut association, response).is_none());
+                assert!(task.handle(&mut association, response).await.is_none());
             }
             assert_matches!(rx.try_recv().unwrap(), Err(TimeSyncError::StillNeedsTime));
         }
@@ -873,7 +873,7 @@ mod tests {
             .unwrap();
             let response = writer.to_parsed().to_response().unwrap();
 
-            task.handle(association, response)
+            futures::executor::block_on(task.handle(association, response))
         }
 
         fn check_write_request(
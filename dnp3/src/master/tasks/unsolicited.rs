@@ -0,0 +1,91 @@
+use crate::app::format::write::HeaderWriter;
+use crate::app::parse::parser::Response;
+use crate::app::FunctionCode;
+use crate::master::association::Association;
+use crate::master::error::{TaskError, WriteRequestError};
+use crate::master::handler::Promise;
+use crate::master::request::EventClasses;
+use crate::master::tasks::NonReadTask;
+
+use scursor::WriteError;
+
+/// Enable or disable unsolicited reporting of event classes at the request of the user
+pub(crate) struct UnsolicitedTask {
+    enable: bool,
+    classes: EventClasses,
+    promise: Promise<Result<(), WriteRequestError>>,
+}
+
+impl UnsolicitedTask {
+    pub(crate) fn enable(
+        classes: EventClasses,
+        promise: Promise<Result<(), WriteRequestError>>,
+    ) -> Self {
+        Self {
+            enable: true,
+            classes,
+            promise,
+        }
+    }
+
+    pub(crate) fn disable(
+        classes: EventClasses,
+        promise: Promise<Result<(), WriteRequestError>>,
+    ) -> Self {
+        Self {
+            enable: false,
+            classes,
+            promise,
+        }
+    }
+
+    pub(crate) fn wrap(self) -> NonReadTask {
+        NonReadTask::Unsolicited(self)
+    }
+
+    pub(crate) fn is_enable(&self) -> bool {
+        self.enable
+    }
+
+    pub(crate) fn function(&self) -> FunctionCode {
+        if self.enable {
+            FunctionCode::EnableUnsolicited
+        } else {
+            FunctionCode::DisableUnsolicited
+        }
+    }
+
+    pub(crate) fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        self.classes.write(writer)
+    }
+
+    pub(crate) fn on_task_error(self, err: TaskError) {
+        self.promise.complete(Err(err.into()))
+    }
+
+    pub(crate) fn handle(
+        self,
+        association: &mut Association,
+        response: Response,
+    ) -> Option<NonReadTask> {
+        if !response.raw_objects.is_empty() {
+            self.promise
+                .complete(Err(TaskError::UnexpectedResponseHeaders.into()));
+            return None;
+        }
+
+        if response.header.iin.has_request_error() {
+            self.promise
+                .complete(Err(WriteRequestError::IinError(response.header.iin.iin2)));
+            return None;
+        }
+
+        if self.enable {
+            association.on_unsolicited_enabled(self.classes);
+        } else {
+            association.on_unsolicited_disabled(self.classes);
+        }
+        self.promise.complete(Ok(()));
+        None
+    }
+}
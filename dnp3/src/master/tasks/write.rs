@@ -0,0 +1,266 @@
+use crate::app::format::write::HeaderWriter;
+use crate::app::parse::parser::Response;
+use crate::app::FunctionCode;
+use crate::master::error::{TaskError, WriteRequestError};
+use crate::master::handler::Promise;
+use crate::master::request::WriteRequest;
+use crate::master::tasks::NonReadTask;
+
+use scursor::WriteError;
+
+pub(crate) struct WriteTask {
+    request: WriteRequest,
+    promise: Promise<Result<(), WriteRequestError>>,
+}
+
+impl WriteTask {
+    pub(crate) fn new(
+        request: WriteRequest,
+        promise: Promise<Result<(), WriteRequestError>>,
+    ) -> Self {
+        Self { request, promise }
+    }
+
+    pub(crate) fn wrap(self) -> NonReadTask {
+        NonReadTask::Write(self)
+    }
+
+    pub(crate) fn function(&self) -> FunctionCode {
+        FunctionCode::Write
+    }
+
+    pub(crate) fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        self.request.write(writer)
+    }
+
+    pub(crate) fn on_task_error(self, err: TaskError) {
+        self.promise.complete(Err(err.into()))
+    }
+
+    pub(crate) fn handle(self, response: Response) -> Option<NonReadTask> {
+        if !response.raw_objects.is_empty() {
+            self.promise
+                .complete(Err(TaskError::UnexpectedResponseHeaders.into()));
+            return None;
+        }
+
+        if response.header.iin.has_request_error() {
+            self.promise
+                .complete(Err(WriteRequestError::IinError(response.header.iin.iin2)));
+            return None;
+        }
+
+        self.promise.complete(Ok(()));
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::format::write::{start_request, start_response};
+    use crate::app::gen::count::CountVariation;
+    use crate::app::gen::prefixed::PrefixedVariation;
+    use crate::app::gen::ranged::RangedVariation;
+    use crate::app::measurement::OctetString;
+    use crate::app::parse::parser::HeaderDetails;
+    use crate::app::{
+        ControlField, Iin, Iin1, Iin2, IntervalUnit, ResponseFunction, Sequence, TimeAndInterval,
+        Timestamp,
+    };
+    use crate::link::EndpointAddress;
+    use crate::master::association::{Association, AssociationConfig};
+    use crate::master::request::AnalogInputDeadbands;
+    use crate::master::tasks::RequestWriter;
+    use crate::master::{AssociationHandler, NullAssociationInformation, NullReadHandler};
+    use scursor::WriteCursor;
+
+    use super::*;
+
+    struct DefaultAssociationHandler;
+    impl AssociationHandler for DefaultAssociationHandler {}
+
+    fn association() -> Association {
+        Association::new(
+            EndpointAddress::try_new(1).unwrap(),
+            AssociationConfig::default(),
+            Box::new(NullReadHandler),
+            Box::new(DefaultAssociationHandler),
+            Box::new(NullAssociationInformation),
+        )
+    }
+
+    fn respond(association: &mut Association, task: NonReadTask, iin: Iin) -> Option<NonReadTask> {
+        let mut buffer = [0; 20];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        let writer = start_response(
+            ControlField::response(Sequence::default(), true, true, false),
+            ResponseFunction::Response,
+            iin,
+            &mut cursor,
+        )
+        .unwrap();
+        let response = writer.to_parsed().to_response().unwrap();
+        futures::executor::block_on(task.handle(association, response))
+    }
+
+    #[test]
+    fn writes_deadbands_with_two_byte_prefix() {
+        let mut association = association();
+        let (tx, mut rx) = tokio::sync::oneshot::channel();
+        let task = WriteTask::new(
+            AnalogInputDeadbands::f32(&[(1.5, 3), (2.0, 7)]).into(),
+            Promise::OneShot(tx),
+        )
+        .wrap();
+
+        let mut buffer = [0; 64];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        let task = task.start(&mut association).unwrap();
+        let mut writer = start_request(
+            ControlField::request(Sequence::default()),
+            task.function(),
+            &mut cursor,
+        )
+        .unwrap();
+        task.write(&mut writer).unwrap();
+        let request = writer.to_parsed().to_request().unwrap();
+
+        assert_eq!(request.header.function, FunctionCode::Write);
+        let headers = request.objects.unwrap();
+        match headers.get_only_header().unwrap().details {
+            HeaderDetails::TwoByteCountAndPrefix(_, PrefixedVariation::Group34Var3(seq)) => {
+                let items: Vec<(f32, u16)> = seq.iter().map(|x| (x.value.value, x.index)).collect();
+                assert_eq!(items, vec![(1.5, 3), (2.0, 7)]);
+            }
+            _ => unreachable!(),
+        }
+
+        assert!(respond(&mut association, task, Iin::default()).is_none());
+        assert_eq!(rx.try_recv().unwrap(), Ok(()));
+    }
+
+    #[test]
+    fn writes_headers_in_order() {
+        let mut request = WriteRequest::new();
+        request.add_octet_string(300, OctetString::new(b"hi").unwrap());
+        request.add_absolute_time(Timestamp::new(42));
+        request.add_iin_bit(7, false);
+        let task = WriteTask::new(request, Promise::None);
+
+        let mut buffer = [0; 64];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        let mut writer = start_request(
+            ControlField::request(Sequence::default()),
+            task.function(),
+            &mut cursor,
+        )
+        .unwrap();
+        task.write(&mut writer).unwrap();
+        let request = writer.to_parsed().to_request().unwrap();
+
+        let headers: Vec<HeaderDetails> =
+            request.objects.unwrap().iter().map(|x| x.details).collect();
+        assert_eq!(headers.len(), 3);
+        match &headers[0] {
+            HeaderDetails::TwoByteStartStop(300, 300, RangedVariation::Group110VarX(2, seq)) => {
+                let items: Vec<(&[u8], u16)> = seq.iter().collect();
+                assert_eq!(items, vec![(&b"hi"[..], 300)]);
+            }
+            _ => unreachable!(),
+        }
+        match &headers[1] {
+            HeaderDetails::OneByteCount(1, CountVariation::Group50Var1(seq)) => {
+                assert_eq!(seq.single().unwrap().time, Timestamp::new(42));
+            }
+            _ => unreachable!(),
+        }
+        match &headers[2] {
+            HeaderDetails::OneByteStartStop(7, 7, RangedVariation::Group80Var1(seq)) => {
+                let items: Vec<(bool, u16)> = seq.iter().collect();
+                assert_eq!(items, vec![(false, 7)]);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn writes_time_and_interval_with_two_byte_prefix() {
+        let schedule = TimeAndInterval::new(Timestamp::new(1000), 15, IntervalUnit::Minutes);
+        let mut request = WriteRequest::new();
+        request.add_time_and_interval(&[(schedule, 4)]);
+        let task = WriteTask::new(request, Promise::None);
+
+        let mut buffer = [0; 64];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        let mut writer = start_request(
+            ControlField::request(Sequence::default()),
+            task.function(),
+            &mut cursor,
+        )
+        .unwrap();
+        task.write(&mut writer).unwrap();
+        let request = writer.to_parsed().to_request().unwrap();
+
+        match request.objects.unwrap().get_only_header().unwrap().details {
+            HeaderDetails::TwoByteCountAndPrefix(1, PrefixedVariation::Group50Var4(seq)) => {
+                let items: Vec<(TimeAndInterval, u16)> =
+                    seq.iter().map(|x| (x.value.into(), x.index)).collect();
+                assert_eq!(items, vec![(schedule, 4)]);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn splits_virtual_terminal_output_into_blocks() {
+        let mut request = WriteRequest::new();
+        request.add_virtual_terminal_output(2, &[0xAA; 300]);
+        let task = WriteTask::new(request, Promise::None);
+
+        let mut buffer = [0; 512];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        let mut writer = start_request(
+            ControlField::request(Sequence::default()),
+            task.function(),
+            &mut cursor,
+        )
+        .unwrap();
+        task.write(&mut writer).unwrap();
+        let request = writer.to_parsed().to_request().unwrap();
+
+        let blocks: Vec<(u8, usize, u16)> = request
+            .objects
+            .unwrap()
+            .iter()
+            .map(|x| match x.details {
+                HeaderDetails::TwoByteCountAndPrefix(
+                    1,
+                    PrefixedVariation::Group112VarX(length, seq),
+                ) => {
+                    let (data, port) = seq.iter().next().unwrap();
+                    (length, data.len(), port)
+                }
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(blocks, vec![(255, 255, 2), (45, 45, 2)]);
+    }
+
+    #[test]
+    fn reports_iin2_errors() {
+        let mut association = association();
+        let (tx, mut rx) = tokio::sync::oneshot::channel();
+        let task = WriteTask::new(
+            AnalogInputDeadbands::u16(&[(4, 1)]).into(),
+            Promise::OneShot(tx),
+        )
+        .wrap();
+
+        let iin = Iin::new(Iin1::default(), Iin2::PARAMETER_ERROR);
+        assert!(respond(&mut association, task, iin).is_none());
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            Err(WriteRequestError::IinError(Iin2::PARAMETER_ERROR))
+        );
+    }
+}
@@ -0,0 +1,320 @@
+use crate::app::auth::*;
+use crate::app::format::write::{start_request, start_response, HeaderWriter};
+use crate::app::parse::free_format::FreeFormatVariation;
+use crate::app::parse::parser::ParsedFragment;
+use crate::app::variations::{Group120Var3, Group120Var4};
+use crate::app::{ControlField, FunctionCode, Iin, ResponseFunction, Sequence, Timestamp};
+use crate::master::association::AssociationConfig;
+use crate::master::error::{AuthenticationError, CommandError, TaskError};
+use crate::master::CommandMode;
+use crate::sa::crypto::{calc_mac, SessionKeys};
+use crate::sa::*;
+
+use scursor::WriteCursor;
+
+use super::harness::requests::*;
+use super::harness::{create_association, TestHarness};
+
+const USER: UserNumber = UserNumber::DEFAULT;
+const UPDATE_KEY: [u8; 16] = [0x11; 16];
+const MAC: MacAlgorithm = MacAlgorithm::HmacSha256Truncated16;
+
+fn auth_config(aggressive_mode: bool) -> AssociationConfig {
+    let mut auth = MasterAuthConfig::new(USER, UpdateKey::aes128(UPDATE_KEY));
+    auth.aggressive_mode = aggressive_mode;
+    let mut config = AssociationConfig::quiet();
+    config.authentication = Some(auth);
+    config
+}
+
+fn auth_request(seq: Sequence, write: impl FnOnce(&mut HeaderWriter)) -> Vec<u8> {
+    let mut buffer = [0; 256];
+    let mut cursor = WriteCursor::new(&mut buffer);
+    let mut writer = start_request(
+        ControlField::request(seq),
+        FunctionCode::AuthenticateRequest,
+        &mut cursor,
+    )
+    .unwrap();
+    write(&mut writer);
+    cursor.written().to_vec()
+}
+
+fn auth_response(seq: Sequence, write: impl FnOnce(&mut HeaderWriter)) -> Vec<u8> {
+    let mut buffer = [0; 256];
+    let mut cursor = WriteCursor::new(&mut buffer);
+    let mut writer = start_response(
+        ControlField::response(seq, true, true, false),
+        ResponseFunction::AuthenticateResponse,
+        Iin::default(),
+        &mut cursor,
+    )
+    .unwrap();
+    write(&mut writer);
+    cursor.written().to_vec()
+}
+
+fn key_status_request(seq: Sequence) -> Vec<u8> {
+    auth_request(seq, |w| {
+        w.write_count_of_one(Group120Var4 { user: USER.value() })
+            .unwrap()
+    })
+}
+
+fn key_status(seq: Sequence, status: KeyStatus, mac: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    let object = Group120Var5 {
+        key_change_seq: 1,
+        user: USER.value(),
+        key_wrap_algorithm: KeyWrapAlgorithm::Aes128.to_u8(),
+        key_status: status.to_u8(),
+        mac_algorithm: MAC.to_u8(),
+        challenge_data: &[0xAA; 4],
+        mac,
+    };
+    let response = auth_response(seq, |w| w.write_free_format(&object).unwrap());
+    (response, object.to_status_message().unwrap())
+}
+
+fn challenge(seq: Sequence) -> Vec<u8> {
+    auth_response(seq, |w| {
+        w.write_free_format(&Group120Var1 {
+            challenge_seq: 1,
+            user: 0,
+            mac_algorithm: MAC.to_u8(),
+            reason: REASON_CRITICAL,
+            challenge_data: &[0xBB; 4],
+        })
+        .unwrap()
+    })
+}
+
+fn error(seq: Sequence, error: AuthErrorCode) -> Vec<u8> {
+    auth_response(seq, |w| {
+        w.write_free_format(&Group120Var7 {
+            challenge_seq: 1,
+            user: USER.value(),
+            association_id: 0,
+            error_code: error.to_u8(),
+            time_of_error: Timestamp::new(0),
+            error_text: "",
+        })
+        .unwrap()
+    })
+}
+
+/// DIRECT_OPERATE request signed with aggressive mode objects
+fn aggressive_direct_operate(
+    seq: Sequence,
+    challenge_seq: u32,
+    keys: &SessionKeys,
+    challenge: &[u8],
+) -> Vec<u8> {
+    let inner = direct_operate_request(seq);
+    let mut buffer = [0; 64];
+    let mut cursor = WriteCursor::new(&mut buffer);
+    cursor.write_bytes(&inner[..2]).unwrap();
+    HeaderWriter::new(&mut cursor)
+        .write_count_of_one(Group120Var3 {
+            challenge_seq,
+            user: USER.value(),
+        })
+        .unwrap();
+    cursor.write_bytes(&inner[2..]).unwrap();
+    let mac = calc_mac(MAC, &keys.control, &[challenge, cursor.written()]);
+    HeaderWriter::new(&mut cursor)
+        .write_free_format(&Group120Var9 { mac: &mac })
+        .unwrap();
+    cursor.written().to_vec()
+}
+
+async fn next_write(harness: &mut TestHarness) -> Vec<u8> {
+    match harness.io.next_event().await {
+        sfio_tokio_mock_io::Event::Write(bytes) => bytes,
+        x => panic!("expected a request but got: {:?}", x),
+    }
+}
+
+/// answer the key status request and return the key change request and the keys it contains
+async fn key_change_request(
+    harness: &mut TestHarness,
+    seq: &mut Sequence,
+) -> (Vec<u8>, SessionKeys) {
+    harness.expect_write(key_status_request(*seq)).await;
+    let (response, status) = key_status(seq.increment(), KeyStatus::NotInit, &[]);
+    harness.process_response(response).await;
+
+    let request = next_write(harness).await;
+    let parsed = ParsedFragment::parse(&request)
+        .unwrap()
+        .to_request()
+        .unwrap();
+    assert_eq!(parsed.header.control.seq, *seq);
+    let object = match parsed
+        .objects
+        .unwrap()
+        .get_only_header()
+        .unwrap()
+        .details
+        .free_format()
+    {
+        Some(FreeFormatVariation::Group120Var6(x)) => x,
+        x => panic!("unexpected object: {:?}", x),
+    };
+    assert_eq!(object.key_change_seq, 1);
+    assert_eq!(object.user, USER.value());
+
+    // the keys are wrapped along with the key status message
+    let (keys, rest) =
+        SessionKeys::unwrap(&UpdateKey::aes128(UPDATE_KEY), object.wrapped_key_data).unwrap();
+    assert_eq!(&rest[..status.len()], status.as_slice());
+    (request, keys)
+}
+
+/// perform the session key change, returning the keys and the final key status
+async fn change_session_keys(
+    harness: &mut TestHarness,
+    seq: &mut Sequence,
+) -> (SessionKeys, Vec<u8>) {
+    let (request, keys) = key_change_request(harness, seq).await;
+    let mac = calc_mac(MAC, &keys.monitor, &[&request]);
+    let (response, _) = key_status(seq.increment(), KeyStatus::Ok, &mac);
+    harness.process_response(response.clone()).await;
+    (keys, response)
+}
+
+fn direct_operate(harness: &TestHarness) -> tokio::task::JoinHandle<Result<(), CommandError>> {
+    let mut association = harness.association.clone();
+    tokio::spawn(async move {
+        association
+            .operate(CommandMode::DirectOperate, latch_on())
+            .await
+    })
+}
+
+#[tokio::test]
+async fn changes_session_keys_before_other_tasks() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(auth_config(false)).await;
+    change_session_keys(&mut harness, &mut seq).await;
+
+    let task = direct_operate(&harness);
+    harness
+        .expect_write_and_respond(
+            direct_operate_request(seq),
+            direct_operate_response(seq.increment()),
+        )
+        .await;
+    assert_eq!(task.await.unwrap(), Ok(()));
+}
+
+#[tokio::test]
+async fn retries_key_change_when_key_status_mac_is_invalid() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(auth_config(false)).await;
+    key_change_request(&mut harness, &mut seq).await;
+    let (response, _) = key_status(seq.increment(), KeyStatus::Ok, &[0; 16]);
+    harness.process_response(response).await;
+
+    // the keys are not used, and the master starts over after the retry delay
+    tokio::time::pause();
+    harness.expect_write(key_status_request(seq)).await;
+}
+
+#[tokio::test]
+async fn replies_to_challenge_with_mac_over_challenge_and_request() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(auth_config(false)).await;
+    let (keys, _) = change_session_keys(&mut harness, &mut seq).await;
+
+    let task = direct_operate(&harness);
+    let request = direct_operate_request(seq);
+    let challenge = challenge(seq);
+    harness
+        .expect_write_and_respond(request.clone(), challenge.clone())
+        .await;
+    seq.increment();
+
+    let mac = calc_mac(MAC, &keys.control, &[&challenge, &request]);
+    let reply = auth_request(seq, |w| {
+        w.write_free_format(&Group120Var2 {
+            challenge_seq: 1,
+            user: USER.value(),
+            mac: &mac,
+        })
+        .unwrap()
+    });
+    harness
+        .expect_write_and_respond(reply, direct_operate_response(seq.increment()))
+        .await;
+    assert_eq!(task.await.unwrap(), Ok(()));
+}
+
+#[tokio::test]
+async fn error_after_challenge_reply_fails_task_and_changes_keys() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(auth_config(false)).await;
+    change_session_keys(&mut harness, &mut seq).await;
+
+    let task = direct_operate(&harness);
+    harness
+        .expect_write_and_respond(direct_operate_request(seq), challenge(seq))
+        .await;
+    seq.increment();
+
+    // the outstation rejects the MAC of the reply
+    next_write(&mut harness).await;
+    harness
+        .process_response(error(seq.increment(), AuthErrorCode::AuthenticationFailed))
+        .await;
+    assert_eq!(
+        task.await.unwrap(),
+        Err(CommandError::Task(TaskError::Authentication(
+            AuthenticationError::ErrorResponse(AuthErrorCode::AuthenticationFailed)
+        )))
+    );
+
+    // the session keys are no longer trusted
+    harness.expect_write(key_status_request(seq)).await;
+}
+
+#[tokio::test]
+async fn signs_critical_requests_in_aggressive_mode_after_key_status() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(auth_config(true)).await;
+    let (keys, status) = change_session_keys(&mut harness, &mut seq).await;
+
+    // the last key status is the challenge data, and each request uses the next sequence number
+    for challenge_seq in [2, 3] {
+        let task = direct_operate(&harness);
+        harness
+            .expect_write_and_respond(
+                aggressive_direct_operate(seq, challenge_seq, &keys, &status),
+                direct_operate_response(seq.increment()),
+            )
+            .await;
+        assert_eq!(task.await.unwrap(), Ok(()));
+    }
+}
+
+#[tokio::test]
+async fn error_response_to_critical_request_fails_task_and_changes_keys() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(auth_config(true)).await;
+    let (keys, status) = change_session_keys(&mut harness, &mut seq).await;
+
+    let task = direct_operate(&harness);
+    harness
+        .expect_write_and_respond(
+            aggressive_direct_operate(seq, 2, &keys, &status),
+            error(seq.increment(), AuthErrorCode::AggressiveModeNotSupported),
+        )
+        .await;
+    assert_eq!(
+        task.await.unwrap(),
+        Err(CommandError::Task(TaskError::Authentication(
+            AuthenticationError::ErrorResponse(AuthErrorCode::AggressiveModeNotSupported)
+        )))
+    );
+
+    harness.expect_write(key_status_request(seq)).await;
+}
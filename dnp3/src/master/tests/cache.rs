@@ -0,0 +1,74 @@
+use crate::app::format::write::start_response;
+use crate::app::measurement::{AnalogInput, Flags};
+use crate::app::{ControlField, Iin, ResponseFunction, Sequence};
+use crate::master::association::AssociationConfig;
+use crate::master::{CacheUpdate, MeasurementType, ReadType};
+
+use scursor::WriteCursor;
+
+use super::harness::create_association;
+use super::harness::requests::*;
+
+fn analog_response(seq: Sequence, value: i32) -> Vec<u8> {
+    let mut buffer = [0; 32];
+    let mut cursor = WriteCursor::new(&mut buffer);
+    let mut response = start_response(
+        ControlField::response(seq, true, true, false),
+        ResponseFunction::Response,
+        Iin::default(),
+        &mut cursor,
+    )
+    .unwrap();
+    // g30v1 start: 2 stop: 2 flags: ONLINE
+    response.write_raw(&[30, 1, 0x00, 2, 2, 0x01]).unwrap();
+    response.write_raw(&value.to_le_bytes()).unwrap();
+    cursor.written().to_vec()
+}
+
+#[tokio::test]
+async fn cache_is_disabled_by_default() {
+    let harness = create_association(AssociationConfig::default()).await;
+
+    assert!(harness.association.get_cache_snapshot().is_none());
+    assert!(harness.association.subscribe_to_cache().is_none());
+}
+
+#[tokio::test]
+async fn integrity_poll_populates_cache() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(AssociationConfig {
+        measurement_cache: true,
+        ..AssociationConfig::default()
+    })
+    .await;
+    let mut updates = harness.association.subscribe_to_cache().unwrap();
+
+    harness
+        .expect_write_and_respond(disable_unsol_request(seq), empty_response(seq.increment()))
+        .await;
+    harness
+        .expect_write_and_respond(
+            integrity_poll_request(seq),
+            analog_response(seq.increment(), 42),
+        )
+        .await;
+
+    assert_eq!(
+        updates.recv().await.unwrap(),
+        CacheUpdate {
+            read_type: ReadType::StartupIntegrity,
+            changes: vec![(MeasurementType::AnalogInput, 2)],
+        }
+    );
+    assert_eq!(
+        harness
+            .association
+            .read_cache(|cache| cache.analog_input.get(&2).copied())
+            .unwrap(),
+        Some(AnalogInput {
+            value: 42.0,
+            flags: Flags::ONLINE,
+            time: None
+        })
+    );
+}
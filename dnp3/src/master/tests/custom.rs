@@ -0,0 +1,218 @@
+use std::sync::{Arc, Mutex};
+
+use crate::app::variations::Variation;
+use crate::app::{FunctionCode, QualifierCode, ResponseHeader, Sequence};
+use crate::master::association::AssociationConfig;
+use crate::master::error::CustomRequestError;
+use crate::master::handler::{CustomObjectHeader, CustomResponseHandler};
+use crate::master::request::{CustomRequest, ReadHeader};
+
+use super::harness::create_association;
+use super::harness::requests::*;
+
+#[derive(Debug, PartialEq)]
+enum Received {
+    Begin(u8),
+    Header(
+        Variation,
+        QualifierCode,
+        Option<(u16, u16)>,
+        Option<u16>,
+        Vec<u8>,
+    ),
+    End(u8),
+}
+
+#[derive(Clone, Default)]
+struct RecordingHandler {
+    received: Arc<Mutex<Vec<Received>>>,
+}
+
+impl RecordingHandler {
+    fn take(&self) -> Vec<Received> {
+        std::mem::take(&mut self.received.lock().unwrap())
+    }
+}
+
+impl CustomResponseHandler for RecordingHandler {
+    fn begin_fragment(&mut self, header: ResponseHeader) {
+        self.received
+            .lock()
+            .unwrap()
+            .push(Received::Begin(header.control.seq.value()));
+    }
+
+    fn handle_header(&mut self, header: CustomObjectHeader) {
+        self.received.lock().unwrap().push(Received::Header(
+            header.variation,
+            header.qualifier,
+            header.range,
+            header.count,
+            header.raw.to_vec(),
+        ));
+    }
+
+    fn end_fragment(&mut self, header: ResponseHeader) {
+        self.received
+            .lock()
+            .unwrap()
+            .push(Received::End(header.control.seq.value()));
+    }
+}
+
+fn custom_request() -> CustomRequest {
+    let mut request = CustomRequest::new(FunctionCode::InitializeApplication);
+    request.add_header(ReadHeader::all_objects(Variation::Group60Var1));
+    // g90v1 free-format application identifier "ab"
+    request.add_raw_header(&[90, 1, 0x5B, 0x01, 0x02, 0x00, b'a', b'b']);
+    request
+}
+
+fn custom_request_bytes(seq: Sequence) -> Vec<u8> {
+    vec![
+        0xC0 | seq.value(),
+        0x10,
+        60,
+        1,
+        0x06,
+        90,
+        1,
+        0x5B,
+        0x01,
+        0x02,
+        0x00,
+        b'a',
+        b'b',
+    ]
+}
+
+#[tokio::test]
+async fn custom_request_handles_multi_fragment_response_with_confirm() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(AssociationConfig::default()).await;
+    startup_procedure(&mut harness, &mut seq).await;
+
+    let handler = RecordingHandler::default();
+    let mut association = harness.association.clone();
+    let task_handler = handler.clone();
+    let task = tokio::spawn(async move {
+        association
+            .send_custom_request(custom_request(), Box::new(task_handler))
+            .await
+    });
+
+    harness.expect_write(custom_request_bytes(seq)).await;
+
+    // FIR, CON - g30v2 [3..3] = 7, flags == ONLINE
+    let first_seq = seq.increment();
+    let first = vec![
+        0xA0 | first_seq.value(),
+        0x81,
+        0x00,
+        0x00,
+        30,
+        2,
+        0x00,
+        3,
+        3,
+        0x01,
+        0x07,
+        0x00,
+    ];
+    harness
+        .read_and_expect_write(first, vec![0xC0 | first_seq.value(), 0x00])
+        .await;
+
+    // FIN - g32v1 count and prefix [5] = 9, flags == ONLINE
+    let second_seq = seq.increment();
+    let second = vec![
+        0x40 | second_seq.value(),
+        0x81,
+        0x00,
+        0x00,
+        32,
+        1,
+        0x17,
+        1,
+        5,
+        0x01,
+        0x09,
+        0x00,
+        0x00,
+        0x00,
+    ];
+    harness.process_response(second.clone()).await;
+
+    assert_eq!(task.await.unwrap(), Ok(()));
+    assert_eq!(
+        handler.take(),
+        vec![
+            Received::Begin(first_seq.value()),
+            Received::Header(
+                Variation::Group30Var2,
+                QualifierCode::Range8,
+                Some((3, 3)),
+                None,
+                vec![30, 2, 0x00, 3, 3, 0x01, 0x07, 0x00],
+            ),
+            Received::End(first_seq.value()),
+            Received::Begin(second_seq.value()),
+            Received::Header(
+                Variation::Group32Var1,
+                QualifierCode::CountAndPrefix8,
+                None,
+                Some(1),
+                second[4..].to_vec(),
+            ),
+            Received::End(second_seq.value()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn custom_request_with_no_ack_function_completes_without_response() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(AssociationConfig::default()).await;
+    startup_procedure(&mut harness, &mut seq).await;
+
+    let mut association = harness.association.clone();
+    let task = tokio::spawn(async move {
+        let mut request = CustomRequest::new(FunctionCode::ImmediateFreezeNoResponse);
+        request.add_header(ReadHeader::all_objects(Variation::Group20Var0));
+        association
+            .send_custom_request(request, Box::new(RecordingHandler::default()))
+            .await
+    });
+
+    harness
+        .expect_write(vec![0xC0 | seq.value(), 0x08, 20, 0, 0x06])
+        .await;
+
+    assert_eq!(task.await.unwrap(), Ok(()));
+}
+
+#[tokio::test]
+async fn custom_request_rejects_response_function_codes() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(AssociationConfig::default()).await;
+    startup_procedure(&mut harness, &mut seq).await;
+
+    for function in [
+        FunctionCode::Confirm,
+        FunctionCode::Response,
+        FunctionCode::UnsolicitedResponse,
+    ] {
+        assert_eq!(
+            harness
+                .association
+                .send_custom_request(
+                    CustomRequest::new(function),
+                    Box::new(RecordingHandler::default())
+                )
+                .await,
+            Err(CustomRequestError::InvalidFunction(function))
+        );
+    }
+
+    assert_eq!(harness.io.pop_event(), None);
+}
@@ -0,0 +1,188 @@
+use crate::app::format::write::start_request;
+use crate::app::variations::{Group50Var2, Variation};
+use crate::app::{ControlField, FunctionCode, Iin, Iin1, Iin2, Sequence, Timestamp};
+use crate::master::association::AssociationConfig;
+use crate::master::error::WriteRequestError;
+use crate::master::request::{FreezeOperation, FreezeRequest, ReadHeader};
+
+use scursor::WriteCursor;
+
+use super::harness::create_association;
+use super::harness::requests::*;
+
+fn at_time() -> FreezeOperation {
+    FreezeOperation::AtTime {
+        time: Timestamp::min(),
+        interval_ms: 60_000,
+    }
+}
+
+fn freeze_all_counters(operation: FreezeOperation, no_ack: bool) -> FreezeRequest {
+    let mut request = if no_ack {
+        FreezeRequest::no_ack(operation)
+    } else {
+        FreezeRequest::new(operation)
+    };
+    request.add(ReadHeader::all_objects(Variation::Group20Var0));
+    request
+}
+
+fn freeze_at_time_request(function: FunctionCode, seq: Sequence) -> Vec<u8> {
+    let mut buffer = [0; 64];
+    let mut cursor = WriteCursor::new(&mut buffer);
+    let mut request = start_request(ControlField::request(seq), function, &mut cursor).unwrap();
+
+    request
+        .write_count_of_one(Group50Var2 {
+            time: Timestamp::min(),
+            interval: 60_000,
+        })
+        .unwrap();
+    request
+        .write_all_objects_header(Variation::Group20Var0)
+        .unwrap();
+
+    cursor.written().to_vec()
+}
+
+#[tokio::test]
+async fn freeze_at_time_completes_on_response() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(AssociationConfig::default()).await;
+    startup_procedure(&mut harness, &mut seq).await;
+
+    let mut association = harness.association.clone();
+    let task = tokio::spawn(async move {
+        association
+            .freeze(freeze_all_counters(at_time(), false))
+            .await
+    });
+
+    harness
+        .expect_write_and_respond(
+            freeze_at_time_request(FunctionCode::FreezeAtTime, seq),
+            empty_response(seq.increment()),
+        )
+        .await;
+
+    assert_eq!(task.await.unwrap(), Ok(()));
+}
+
+#[tokio::test]
+async fn freeze_at_time_no_ack_completes_without_response() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(AssociationConfig::default()).await;
+    startup_procedure(&mut harness, &mut seq).await;
+
+    let mut association = harness.association.clone();
+    let task = tokio::spawn(async move {
+        association
+            .freeze(freeze_all_counters(at_time(), true))
+            .await
+    });
+
+    harness
+        .expect_write(freeze_at_time_request(
+            FunctionCode::FreezeAtTimeNoResponse,
+            seq,
+        ))
+        .await;
+
+    assert_eq!(task.await.unwrap(), Ok(()));
+    assert_eq!(harness.io.pop_event(), None);
+}
+
+#[tokio::test]
+async fn immediate_freeze_with_range_and_count_headers() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(AssociationConfig::default()).await;
+    startup_procedure(&mut harness, &mut seq).await;
+
+    let mut request = FreezeRequest::new(FreezeOperation::Immediate);
+    request.add(ReadHeader::one_byte_range(Variation::Group20Var0, 0, 5));
+    request.add(ReadHeader::two_byte_limited_count(
+        Variation::Group20Var0,
+        3,
+    ));
+
+    let mut association = harness.association.clone();
+    let task = tokio::spawn(async move { association.freeze(request).await });
+
+    harness
+        .expect_write_and_respond(
+            vec![
+                0xC0 | seq.value(),
+                0x07,
+                20,
+                0,
+                0x00,
+                0,
+                5,
+                20,
+                0,
+                0x08,
+                3,
+                0,
+            ],
+            empty_response(seq.increment()),
+        )
+        .await;
+
+    assert_eq!(task.await.unwrap(), Ok(()));
+}
+
+#[tokio::test]
+async fn freeze_clear_fails_with_iin2_error() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(AssociationConfig::default()).await;
+    startup_procedure(&mut harness, &mut seq).await;
+
+    let mut association = harness.association.clone();
+    let task = tokio::spawn(async move {
+        association
+            .freeze(freeze_all_counters(FreezeOperation::Clear, false))
+            .await
+    });
+
+    harness
+        .expect_write_and_respond(
+            vec![0xC0 | seq.value(), 0x09, 20, 0, 0x06],
+            empty_response_custom_iin(
+                seq.increment(),
+                Iin::new(Iin1::default(), Iin2::PARAMETER_ERROR),
+            ),
+        )
+        .await;
+
+    assert_eq!(
+        task.await.unwrap(),
+        Err(WriteRequestError::IinError(Iin2::PARAMETER_ERROR))
+    );
+}
+
+#[tokio::test]
+async fn no_ack_freezes_complete_without_response() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(AssociationConfig::default()).await;
+    startup_procedure(&mut harness, &mut seq).await;
+
+    for (operation, function) in [
+        (FreezeOperation::Immediate, 0x08),
+        (FreezeOperation::Clear, 0x0A),
+    ] {
+        let mut association = harness.association.clone();
+        let task = tokio::spawn(async move {
+            association
+                .freeze(freeze_all_counters(operation, true))
+                .await
+        });
+
+        harness
+            .expect_write(vec![0xC0 | seq.increment().value(), function, 20, 0, 0x06])
+            .await;
+
+        assert_eq!(task.await.unwrap(), Ok(()));
+    }
+
+    assert_eq!(harness.io.pop_event(), None);
+}
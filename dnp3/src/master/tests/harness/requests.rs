@@ -1,8 +1,12 @@
+use crate::app::control::{Group12Var1, OpType};
 use crate::app::format::write::{start_request, start_response};
 use crate::app::variations::{Group32Var2, Variation};
 use crate::app::Sequence;
 use crate::app::{ControlField, FunctionCode, Iin, Iin1, Iin2, ResponseFunction};
-use crate::master::Classes;
+use crate::master::handler::Promise;
+use crate::master::request::{CommandBuilder, CommandHeaders, CommandSupport};
+use crate::master::tasks::command::CommandTask;
+use crate::master::{Classes, CommandMode};
 
 use scursor::WriteCursor;
 use sfio_tokio_mock_io::Event;
@@ -142,6 +146,35 @@ pub(crate) fn clear_restart_iin(seq: Sequence) -> Vec<u8> {
     cursor.written().to_vec()
 }
 
+pub(crate) fn latch_on() -> CommandHeaders {
+    CommandBuilder::single_header_u16(Group12Var1::from_op_type(OpType::LatchOn), 7)
+}
+
+pub(crate) fn direct_operate_request(seq: Sequence) -> Vec<u8> {
+    let task = CommandTask::from_mode(CommandMode::DirectOperate, latch_on(), Promise::None);
+    let mut buffer = [0; 64];
+    let mut cursor = WriteCursor::new(&mut buffer);
+    let mut request =
+        start_request(ControlField::request(seq), task.function(), &mut cursor).unwrap();
+    task.write(&mut request).unwrap();
+    cursor.written().to_vec()
+}
+
+pub(crate) fn direct_operate_response(seq: Sequence) -> Vec<u8> {
+    let task = CommandTask::from_mode(CommandMode::DirectOperate, latch_on(), Promise::None);
+    let mut buffer = [0; 64];
+    let mut cursor = WriteCursor::new(&mut buffer);
+    let mut response = start_response(
+        ControlField::response(seq, true, true, false),
+        ResponseFunction::Response,
+        Iin::default(),
+        &mut cursor,
+    )
+    .unwrap();
+    task.write(&mut response).unwrap();
+    cursor.written().to_vec()
+}
+
 pub(crate) fn empty_response(seq: Sequence) -> Vec<u8> {
     empty_response_custom_iin(seq, Iin::default())
 }
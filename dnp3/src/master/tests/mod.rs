@@ -1,4 +1,11 @@
 mod harness;
 
+mod auth;
 mod auto_tasks;
+mod cache;
+mod custom;
+mod freeze;
+mod scheduler;
 mod startup;
+mod unsolicited;
+mod write;
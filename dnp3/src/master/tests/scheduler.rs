@@ -0,0 +1,175 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::app::Sequence;
+use crate::master::association::AssociationConfig;
+use crate::master::request::Classes;
+use crate::master::{CommandMode, ReadRequest, TaskPriority, TaskScheduler, TaskType};
+
+use super::harness::requests::*;
+use super::harness::{create_association, TestHarness};
+
+async fn expect_direct_operate(harness: &mut TestHarness, seq: &mut Sequence) {
+    harness
+        .expect_write_and_respond(direct_operate_request(*seq), direct_operate_response(*seq))
+        .await;
+    seq.increment();
+}
+
+async fn expect_class0_read(harness: &mut TestHarness, seq: &mut Sequence) {
+    harness
+        .expect_write_and_respond(
+            class_scan_request(Classes::class0(), *seq),
+            empty_response(seq.increment()),
+        )
+        .await;
+}
+
+/// Poll each future once so that it queues its request, and then wait for the master to
+/// process the messages
+async fn queue(
+    harness: &mut TestHarness,
+    requests: &mut [&mut Pin<Box<dyn Future<Output = bool>>>],
+) {
+    for request in requests.iter_mut() {
+        assert!(futures::poll!(&mut **request).is_pending());
+    }
+    harness.master.get_decode_level().await.unwrap();
+}
+
+/// Start an integrity poll and leave it outstanding so that other requests queue behind it
+async fn start_integrity_poll(
+    harness: &mut TestHarness,
+    seq: Sequence,
+) -> tokio::task::JoinHandle<bool> {
+    let mut association = harness.association.clone();
+    let task = tokio::spawn(async move {
+        association
+            .read(ReadRequest::ClassScan(Classes::all()))
+            .await
+            .is_ok()
+    });
+    harness.expect_write(integrity_poll_request(seq)).await;
+    task
+}
+
+#[tokio::test]
+async fn commands_run_before_reads_queued_earlier() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(AssociationConfig::default()).await;
+    startup_procedure(&mut harness, &mut seq).await;
+
+    let poll = start_integrity_poll(&mut harness, seq).await;
+
+    let mut association = harness.association.clone();
+    let mut read: Pin<Box<dyn Future<Output = bool>>> = Box::pin(async move {
+        association
+            .read(ReadRequest::ClassScan(Classes::class0()))
+            .await
+            .is_ok()
+    });
+    let mut association = harness.association.clone();
+    let mut operate: Pin<Box<dyn Future<Output = bool>>> = Box::pin(async move {
+        association
+            .operate(CommandMode::DirectOperate, latch_on())
+            .await
+            .is_ok()
+    });
+    queue(&mut harness, &mut [&mut read, &mut operate]).await;
+
+    harness
+        .process_response(empty_response(seq.increment()))
+        .await;
+    assert!(poll.await.unwrap());
+
+    expect_direct_operate(&mut harness, &mut seq).await;
+    assert!(operate.await);
+    expect_class0_read(&mut harness, &mut seq).await;
+    assert!(read.await);
+}
+
+#[tokio::test]
+async fn explicit_priority_overrides_the_scheduler() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(AssociationConfig::default()).await;
+    startup_procedure(&mut harness, &mut seq).await;
+
+    let poll = start_integrity_poll(&mut harness, seq).await;
+
+    let mut association = harness.association.clone();
+    let mut operate: Pin<Box<dyn Future<Output = bool>>> = Box::pin(async move {
+        association
+            .operate_with_priority(CommandMode::DirectOperate, latch_on(), TaskPriority::Low)
+            .await
+            .is_ok()
+    });
+    let mut association = harness.association.clone();
+    let mut read: Pin<Box<dyn Future<Output = bool>>> = Box::pin(async move {
+        association
+            .read_with_priority(
+                ReadRequest::ClassScan(Classes::class0()),
+                TaskPriority::High,
+            )
+            .await
+            .is_ok()
+    });
+    queue(&mut harness, &mut [&mut operate, &mut read]).await;
+
+    harness
+        .process_response(empty_response(seq.increment()))
+        .await;
+    assert!(poll.await.unwrap());
+
+    expect_class0_read(&mut harness, &mut seq).await;
+    assert!(read.await);
+    expect_direct_operate(&mut harness, &mut seq).await;
+    assert!(operate.await);
+}
+
+struct FifoScheduler;
+
+impl TaskScheduler for FifoScheduler {
+    fn priority(&self, _: TaskType) -> TaskPriority {
+        TaskPriority::Normal
+    }
+}
+
+#[tokio::test]
+async fn user_requests_of_equal_priority_run_in_order() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(AssociationConfig::default()).await;
+    startup_procedure(&mut harness, &mut seq).await;
+    harness
+        .master
+        .set_scheduler(Box::new(FifoScheduler))
+        .await
+        .unwrap();
+
+    let poll = start_integrity_poll(&mut harness, seq).await;
+
+    let mut association = harness.association.clone();
+    let mut read: Pin<Box<dyn Future<Output = bool>>> = Box::pin(async move {
+        association
+            .read(ReadRequest::ClassScan(Classes::class0()))
+            .await
+            .is_ok()
+    });
+    let mut association = harness.association.clone();
+    let mut operate: Pin<Box<dyn Future<Output = bool>>> = Box::pin(async move {
+        association
+            .operate(CommandMode::DirectOperate, latch_on())
+            .await
+            .is_ok()
+    });
+    queue(&mut harness, &mut [&mut read, &mut operate]).await;
+
+    harness
+        .process_response(empty_response(seq.increment()))
+        .await;
+    assert!(poll.await.unwrap());
+
+    expect_class0_read(&mut harness, &mut seq).await;
+    assert!(read.await);
+    expect_direct_operate(&mut harness, &mut seq).await;
+    assert!(operate.await);
+}
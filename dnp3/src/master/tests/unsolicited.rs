@@ -0,0 +1,134 @@
+use crate::app::format::write::start_request;
+use crate::app::{ControlField, FunctionCode, Iin, Iin1, Iin2, Sequence};
+use crate::master::association::AssociationConfig;
+use crate::master::error::WriteRequestError;
+use crate::master::request::EventClasses;
+
+use scursor::WriteCursor;
+
+use super::harness::requests::*;
+use super::harness::{create_association, TestHarness};
+
+fn unsol_request(function: FunctionCode, classes: EventClasses, seq: Sequence) -> Vec<u8> {
+    let mut buffer = [0; 20];
+    let mut cursor = WriteCursor::new(&mut buffer);
+    let mut request = start_request(ControlField::request(seq), function, &mut cursor).unwrap();
+    classes.write(&mut request).unwrap();
+    cursor.written().to_vec()
+}
+
+async fn restart(harness: &mut TestHarness, seq: &mut Sequence) {
+    // Unsolicited NULL response with DEVICE_RESTART IIN
+    harness
+        .read_and_expect_write(unsol_null(*seq, true), unsol_confirm(*seq))
+        .await;
+    harness
+        .expect_write_and_respond(clear_restart_iin(*seq), empty_response(seq.increment()))
+        .await;
+}
+
+#[tokio::test]
+async fn disabled_classes_are_disabled_again_after_restart() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(AssociationConfig::default()).await;
+    startup_procedure(&mut harness, &mut seq).await;
+
+    let class1 = EventClasses::new(true, false, false);
+    let mut association = harness.association.clone();
+    let task = tokio::spawn(async move { association.disable_unsolicited(class1).await });
+    harness
+        .expect_write_and_respond(
+            unsol_request(FunctionCode::DisableUnsolicited, class1, seq),
+            empty_response(seq.increment()),
+        )
+        .await;
+    assert_eq!(task.await.unwrap(), Ok(()));
+
+    restart(&mut harness, &mut seq).await;
+    harness
+        .expect_write_and_respond(disable_unsol_request(seq), empty_response(seq.increment()))
+        .await;
+    harness
+        .expect_write_and_respond(integrity_poll_request(seq), empty_response(seq.increment()))
+        .await;
+    harness
+        .expect_write_and_respond(
+            unsol_request(
+                FunctionCode::EnableUnsolicited,
+                EventClasses::new(false, true, true),
+                seq,
+            ),
+            empty_response(seq.increment()),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn enabled_classes_are_enabled_again_after_restart() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(AssociationConfig {
+        enable_unsol_classes: EventClasses::none(),
+        ..AssociationConfig::default()
+    })
+    .await;
+    harness
+        .expect_write_and_respond(disable_unsol_request(seq), empty_response(seq.increment()))
+        .await;
+    harness
+        .expect_write_and_respond(integrity_poll_request(seq), empty_response(seq.increment()))
+        .await;
+
+    let class3 = EventClasses::new(false, false, true);
+    let mut association = harness.association.clone();
+    let task = tokio::spawn(async move { association.enable_unsolicited(class3).await });
+    harness
+        .expect_write_and_respond(
+            unsol_request(FunctionCode::EnableUnsolicited, class3, seq),
+            empty_response(seq.increment()),
+        )
+        .await;
+    assert_eq!(task.await.unwrap(), Ok(()));
+
+    restart(&mut harness, &mut seq).await;
+    harness
+        .expect_write_and_respond(integrity_poll_request(seq), empty_response(seq.increment()))
+        .await;
+    harness
+        .expect_write_and_respond(
+            unsol_request(FunctionCode::EnableUnsolicited, class3, seq),
+            empty_response(seq.increment()),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn rejected_request_does_not_change_classes_applied_after_restart() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(AssociationConfig::default()).await;
+    startup_procedure(&mut harness, &mut seq).await;
+
+    let class2 = EventClasses::new(false, true, false);
+    let mut association = harness.association.clone();
+    let task = tokio::spawn(async move { association.disable_unsolicited(class2).await });
+    harness
+        .expect_write_and_respond(
+            unsol_request(FunctionCode::DisableUnsolicited, class2, seq),
+            empty_response_custom_iin(
+                seq.increment(),
+                Iin::new(Iin1::default(), Iin2::NO_FUNC_CODE_SUPPORT),
+            ),
+        )
+        .await;
+    assert_eq!(
+        task.await.unwrap(),
+        Err(WriteRequestError::IinError(Iin2::NO_FUNC_CODE_SUPPORT))
+    );
+
+    restart(&mut harness, &mut seq).await;
+    harness
+        .expect_write_and_respond(integrity_poll_request(seq), empty_response(seq.increment()))
+        .await;
+    harness
+        .expect_write_and_respond(enable_unsol_request(seq), empty_response(seq.increment()))
+        .await;
+}
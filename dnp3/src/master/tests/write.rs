@@ -0,0 +1,32 @@
+use crate::app::Sequence;
+use crate::master::association::AssociationConfig;
+use crate::master::WriteRequest;
+
+use super::harness::create_association;
+use super::harness::requests::*;
+
+/// g70v5 free-format file block: handle 1, last block 0, data "ab"
+const FILE_BLOCK: &[u8] = &[
+    70, 5, 0x5B, 0x01, 0x0A, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, b'a', b'b',
+];
+
+#[tokio::test]
+async fn write_request_sends_raw_headers_after_built_in_headers() {
+    let mut seq = Sequence::default();
+    let mut harness = create_association(AssociationConfig::default()).await;
+    startup_procedure(&mut harness, &mut seq).await;
+
+    let mut request = WriteRequest::new();
+    request.add_iin_bit(7, false);
+    request.add_raw_header(FILE_BLOCK);
+
+    let mut association = harness.association.clone();
+    let task = tokio::spawn(async move { association.write(request).await });
+
+    let mut expected = vec![0xC0 | seq.value(), 0x02, 80, 1, 0x00, 7, 7, 0x00];
+    expected.extend_from_slice(FILE_BLOCK);
+    harness
+        .expect_write_and_respond(expected, empty_response(seq.increment()))
+        .await;
+    assert_eq!(task.await.unwrap(), Ok(()));
+}
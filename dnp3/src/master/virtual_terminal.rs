@@ -0,0 +1,60 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::master::{AssociationHandle, WriteRequest, WriteRequestError};
+use crate::util::virtual_terminal::VirtualTerminalReceiver;
+
+/// Byte stream for a virtual terminal port (groups 112/113) of an outstation
+///
+/// Output from the outstation (g113) is received as a [futures::Stream] of blocks, but only when
+/// events are read from the outstation, e.g. by class polls or unsolicited responses. Writes are
+/// sent as virtual terminal output (g112). The stream ends when the association is removed, the
+/// master is shut down, or the port is opened again.
+pub struct VirtualTerminal {
+    port: u16,
+    association: AssociationHandle,
+    rx: VirtualTerminalReceiver,
+}
+
+impl VirtualTerminal {
+    pub(crate) fn new(
+        port: u16,
+        association: AssociationHandle,
+        rx: VirtualTerminalReceiver,
+    ) -> Self {
+        Self {
+            port,
+            association,
+            rx,
+        }
+    }
+
+    /// index of the virtual terminal port
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Retrieve the next block of output from the outstation
+    ///
+    /// Returns `None` once the stream has ended
+    pub async fn read(&mut self) -> Option<Vec<u8>> {
+        self.rx.recv().await
+    }
+
+    /// Write to the port of the outstation using the WRITE function code
+    ///
+    /// The data is split into blocks of at most 255 bytes, but is sent in a single request
+    pub async fn write(&mut self, data: &[u8]) -> Result<(), WriteRequestError> {
+        let mut request = WriteRequest::new();
+        request.add_virtual_terminal_output(self.port, data);
+        self.association.write(request).await
+    }
+}
+
+impl futures::Stream for VirtualTerminal {
+    type Item = Vec<u8>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
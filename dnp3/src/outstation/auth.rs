@@ -0,0 +1,605 @@
+use std::collections::HashMap;
+use std::time::SystemTime;
+
+use crate::app::auth::*;
+use crate::app::format::write::HeaderWriter;
+use crate::app::gen::count::CountVariation;
+use crate::app::parse::free_format::FreeFormatVariation;
+use crate::app::parse::parser::{ObjectHeader, Request};
+use crate::app::{ControlField, FunctionCode, Sequence, Timestamp};
+use crate::sa::crypto::{calc_mac, random_bytes, verify_mac, SessionKeys};
+use crate::sa::*;
+
+use tokio::time::Instant;
+
+/// Outcome of passing a request through secure authentication
+pub(crate) enum AuthAction {
+    /// the request isn't subject to authentication and is processed normally
+    NotCritical,
+    /// an authentication response was written and must be sent
+    Respond,
+    /// the request was authenticated, process this fragment in its place
+    Authenticated(Vec<u8>),
+    /// the request is silently discarded
+    Discard,
+}
+
+struct UserSession {
+    keys: Option<SessionKeys>,
+    expiration: Instant,
+    status: KeyStatus,
+    /// sequence number and content of the last key status message sent for the user
+    last_status: Option<(u32, Vec<u8>)>,
+    /// last key change request from the user, authenticated by the key status MAC
+    last_key_change: Option<Vec<u8>>,
+}
+
+impl UserSession {
+    fn new() -> Self {
+        Self {
+            keys: None,
+            expiration: Instant::now(),
+            status: KeyStatus::NotInit,
+            last_status: None,
+            last_key_change: None,
+        }
+    }
+}
+
+/// critical request waiting for the reply to a challenge
+struct PendingChallenge {
+    challenge_seq: u32,
+    function: FunctionCode,
+    request: Vec<u8>,
+    /// the challenge fragment, recorded once it is sent
+    challenge: Option<Vec<u8>>,
+    expiration: Instant,
+}
+
+/// challenge (g120v1) or key status (g120v5) fragment authenticated by aggressive mode requests
+struct LastChallenge {
+    /// sequence number of the challenge, or of the last aggressive mode request that used it
+    seq: u32,
+    fragment: Vec<u8>,
+}
+
+/// Secure authentication state of an outstation
+pub(crate) struct OutstationAuth {
+    config: OutstationAuthConfig,
+    users: Box<dyn UserStore>,
+    info: Box<dyn AuthenticationInformation>,
+    sessions: HashMap<UserNumber, UserSession>,
+    key_change_seq: u32,
+    challenge_seq: u32,
+    pending: Option<PendingChallenge>,
+    last_challenge: Option<LastChallenge>,
+    /// sequence number of a challenge or key status that was written but not recorded yet
+    challenge_written: Option<u32>,
+    statistics: [u32; SecurityStatistic::COUNT],
+}
+
+impl OutstationAuth {
+    pub(crate) fn new(
+        config: OutstationAuthConfig,
+        users: Box<dyn UserStore>,
+        info: Box<dyn AuthenticationInformation>,
+    ) -> Self {
+        Self {
+            config,
+            users,
+            info,
+            sessions: HashMap::new(),
+            key_change_seq: 0,
+            challenge_seq: 0,
+            pending: None,
+            last_challenge: None,
+            challenge_written: None,
+            statistics: [0; SecurityStatistic::COUNT],
+        }
+    }
+
+    /// session keys don't survive the loss of communications
+    pub(crate) fn reset(&mut self) {
+        self.pending = None;
+        self.last_challenge = None;
+        self.challenge_written = None;
+        for (user, session) in self.sessions.iter_mut() {
+            if session.keys.take().is_some() {
+                session.status = KeyStatus::CommFail;
+                self.info.session_key_status(*user, KeyStatus::CommFail);
+            }
+        }
+    }
+
+    /// called for every fragment the outstation sends
+    pub(crate) fn on_message_sent(&mut self) {
+        self.increment(SecurityStatistic::TotalMessagesSent);
+    }
+
+    /// called with each authentication response after it is sent
+    pub(crate) fn on_auth_response_sent(&mut self, fragment: &[u8]) {
+        if let Some(seq) = self.challenge_written.take() {
+            if let Some(pending) = self.pending.as_mut() {
+                if pending.challenge_seq == seq && pending.challenge.is_none() {
+                    pending.challenge = Some(fragment.to_vec());
+                }
+            }
+            self.last_challenge = Some(LastChallenge {
+                seq,
+                fragment: fragment.to_vec(),
+            });
+        }
+    }
+
+    /// discard the pending challenge if its reply timed out, returning when it will otherwise
+    pub(crate) fn check_reply_timeout(&mut self) -> Option<Instant> {
+        let expiration = self.pending.as_ref()?.expiration;
+        if Instant::now() < expiration {
+            return Some(expiration);
+        }
+
+        tracing::warn!("no reply to challenge within timeout");
+        self.pending = None;
+        self.increment(SecurityStatistic::ReplyTimeouts);
+        None
+    }
+
+    pub(crate) fn process(
+        &mut self,
+        broadcast: bool,
+        request: Request,
+        writer: &mut HeaderWriter,
+    ) -> AuthAction {
+        self.increment(SecurityStatistic::TotalMessagesReceived);
+        self.check_reply_timeout();
+
+        let function = request.header.function;
+
+        if function == FunctionCode::AuthenticateRequest {
+            if broadcast {
+                tracing::warn!("ignoring broadcast authentication request");
+                return self.discard();
+            }
+            return self.handle_auth_request(request, writer);
+        }
+
+        if function == FunctionCode::AuthenticateRequestNoResponse || !is_critical(function) {
+            return AuthAction::NotCritical;
+        }
+
+        self.increment(SecurityStatistic::CriticalMessagesReceived);
+
+        // a new critical request replaces any request waiting for a reply
+        if self.pending.take().is_some() {
+            tracing::warn!("critical request was never authenticated");
+            self.increment(SecurityStatistic::ReplyTimeouts);
+        }
+
+        if let Ok(objects) = request.objects {
+            let headers: Vec<(ObjectHeader, &[u8])> = objects.iter_with_bytes().collect();
+            if let Some(aggressive) = AggressiveModeRequest::parse(request.raw_fragment, &headers) {
+                let no_response = broadcast || function.is_no_response();
+                return self.handle_aggressive_mode(function, aggressive, no_response, writer);
+            }
+        }
+
+        // the outstation can't challenge a request that it's not allowed to answer
+        if broadcast || function.is_no_response() {
+            tracing::warn!(
+                "discarding {:?} request that requires aggressive mode authentication",
+                function
+            );
+            return self.discard();
+        }
+
+        self.write_challenge(function, request.raw_fragment, writer)
+    }
+
+    fn handle_auth_request(&mut self, request: Request, writer: &mut HeaderWriter) -> AuthAction {
+        let header = match request.objects.ok().and_then(|x| x.get_only_header()) {
+            Some(x) => x,
+            None => return self.unexpected("authentication request without a single header"),
+        };
+
+        if let Some(CountVariation::Group120Var4(seq)) = header.details.count() {
+            return match seq.single() {
+                Some(x) => self.write_key_status(UserNumber::new(x.user), writer),
+                None => self.unexpected("key status request without a single object"),
+            };
+        }
+
+        match header.details.free_format() {
+            Some(FreeFormatVariation::Group120Var6(x)) => {
+                self.handle_key_change(x, request.raw_fragment, writer)
+            }
+            Some(FreeFormatVariation::Group120Var2(x)) => {
+                self.handle_reply(x, request.header.control.seq, writer)
+            }
+            _ => self.unexpected("unsupported authentication object"),
+        }
+    }
+
+    fn write_key_status(&mut self, user: UserNumber, writer: &mut HeaderWriter) -> AuthAction {
+        let update_key = match self.users.get_update_key(user) {
+            Some(x) => x,
+            None => {
+                tracing::warn!("key status requested for unknown user: {}", user);
+                return self.write_error(user, AuthErrorCode::UnknownUser, writer);
+            }
+        };
+
+        let challenge_data = match random_bytes(self.config.challenge_size()) {
+            Some(x) => x,
+            None => return self.discard(),
+        };
+
+        self.key_change_seq = self.key_change_seq.wrapping_add(1);
+        let key_change_seq = self.key_change_seq;
+        let mac_algorithm = self.config.mac_algorithm;
+        let status = self.get_key_status(user);
+        let session = self.sessions.entry(user).or_insert_with(UserSession::new);
+
+        // once the keys are valid, the MAC proves that the outstation received the key change
+        let mac = match (&session.keys, &session.last_key_change) {
+            (Some(keys), Some(request)) if status == KeyStatus::Ok => {
+                calc_mac(mac_algorithm, &keys.monitor, &[request])
+            }
+            _ => Vec::new(),
+        };
+
+        let object = Group120Var5 {
+            key_change_seq,
+            user: user.value(),
+            key_wrap_algorithm: update_key.algorithm().to_u8(),
+            key_status: status.to_u8(),
+            mac_algorithm: mac_algorithm.to_u8(),
+            challenge_data: &challenge_data,
+            mac: &mac,
+        };
+
+        session.last_status = object.to_status_message().ok().map(|x| (key_change_seq, x));
+
+        // the key status is challenge data for aggressive mode requests, just like a challenge
+        let action = Self::write_object(&object, writer);
+        if let AuthAction::Respond = action {
+            self.challenge_written = Some(key_change_seq);
+        }
+        action
+    }
+
+    fn handle_key_change(
+        &mut self,
+        object: Group120Var6,
+        fragment: &[u8],
+        writer: &mut HeaderWriter,
+    ) -> AuthAction {
+        let user = UserNumber::new(object.user);
+        let update_key = match self.users.get_update_key(user) {
+            Some(x) => x,
+            None => {
+                tracing::warn!("key change received for unknown user: {}", user);
+                return self.write_error(user, AuthErrorCode::UnknownUser, writer);
+            }
+        };
+
+        let session = self.sessions.entry(user).or_insert_with(UserSession::new);
+
+        let keys = match &session.last_status {
+            Some((seq, status)) if *seq == object.key_change_seq => {
+                unwrap_keys(&update_key, object.wrapped_key_data, status)
+            }
+            _ => {
+                tracing::warn!(
+                    "key change sequence number ({}) doesn't match the last key status",
+                    object.key_change_seq
+                );
+                None
+            }
+        };
+
+        let status = match keys {
+            Some(keys) => {
+                session.keys = Some(keys);
+                session.expiration = Instant::now() + self.config.session_key_timeout;
+                session.last_key_change = Some(fragment.to_vec());
+                session.status = KeyStatus::Ok;
+                self.increment(SecurityStatistic::SessionKeyChanges);
+                KeyStatus::Ok
+            }
+            None => {
+                tracing::warn!("session key change failed for user: {}", user);
+                session.keys = None;
+                session.last_key_change = None;
+                session.status = KeyStatus::AuthFail;
+                self.increment(SecurityStatistic::FailedSessionKeyChanges);
+                KeyStatus::AuthFail
+            }
+        };
+
+        self.info.session_key_status(user, status);
+        self.write_key_status(user, writer)
+    }
+
+    fn handle_reply(
+        &mut self,
+        reply: Group120Var2,
+        seq: Sequence,
+        writer: &mut HeaderWriter,
+    ) -> AuthAction {
+        let pending = match self.pending.take() {
+            Some(x) if x.challenge_seq == reply.challenge_seq => x,
+            Some(_) => return self.unexpected("challenge reply with unexpected sequence number"),
+            None => return self.unexpected("challenge reply without a pending challenge"),
+        };
+
+        let challenge = match &pending.challenge {
+            Some(x) => x.clone(),
+            None => return self.unexpected("challenge reply before the challenge was sent"),
+        };
+
+        let user = UserNumber::new(reply.user);
+        let data: [&[u8]; 2] = [&challenge, &pending.request];
+        if let Err(err) = self.verify(user, &data, reply.mac, pending.function) {
+            return self.write_error(user, err, writer);
+        }
+
+        // the authenticated request is answered with the sequence number of the reply
+        let mut request = pending.request;
+        let mut control = ControlField::from(request[0]);
+        control.seq = seq;
+        request[0] = control.to_u8();
+        AuthAction::Authenticated(request)
+    }
+
+    fn handle_aggressive_mode(
+        &mut self,
+        function: FunctionCode,
+        request: AggressiveModeRequest,
+        no_response: bool,
+        writer: &mut HeaderWriter,
+    ) -> AuthAction {
+        let user = UserNumber::new(request.header.user);
+
+        let result = if !self.config.allow_aggressive_mode {
+            Err(AuthErrorCode::AggressiveModeNotSupported)
+        } else {
+            match self
+                .last_challenge
+                .as_ref()
+                .map(|x| (x.seq, x.fragment.clone()))
+            {
+                Some((seq, challenge)) if request.header.challenge_seq == seq.wrapping_add(1) => {
+                    self.verify(user, &[&challenge, request.signed], request.mac, function)
+                }
+                Some(_) => {
+                    tracing::warn!(
+                        "unexpected challenge sequence number in aggressive mode request: {}",
+                        request.header.challenge_seq
+                    );
+                    Err(self.on_authentication_failure(user))
+                }
+                None => {
+                    tracing::warn!("aggressive mode request received before any challenge");
+                    Err(self.on_authentication_failure(user))
+                }
+            }
+        };
+
+        match result {
+            Ok(()) => {
+                // each request must use the next sequence number with the same challenge data
+                if let Some(last) = self.last_challenge.as_mut() {
+                    last.seq = request.header.challenge_seq;
+                }
+                AuthAction::Authenticated(request.inner)
+            }
+            Err(err) if no_response => {
+                tracing::warn!("discarding aggressive mode request: {:?}", err);
+                self.discard()
+            }
+            Err(err) => self.write_error(user, err, writer),
+        }
+    }
+
+    /// verify the MAC of a critical request and the authorization of the user
+    fn verify(
+        &mut self,
+        user: UserNumber,
+        data: &[&[u8]],
+        mac: &[u8],
+        function: FunctionCode,
+    ) -> Result<(), AuthErrorCode> {
+        let status = self.get_key_status(user);
+        let authenticated = match self.sessions.get(&user).and_then(|x| x.keys.as_ref()) {
+            Some(keys) if status == KeyStatus::Ok => {
+                verify_mac(self.config.mac_algorithm, &keys.control, data, mac)
+            }
+            _ => {
+                tracing::warn!("no valid session keys for user: {}", user);
+                false
+            }
+        };
+
+        if !authenticated {
+            return Err(self.on_authentication_failure(user));
+        }
+
+        if !self.users.is_authorized(user, function) {
+            tracing::warn!("user {} is not authorized to perform {:?}", user, function);
+            self.increment(SecurityStatistic::AuthorizationFailures);
+            self.info
+                .authentication_failed(user, AuthErrorCode::AuthorizationFailed);
+            return Err(AuthErrorCode::AuthorizationFailed);
+        }
+
+        self.increment(SecurityStatistic::SuccessfulAuthentications);
+        Ok(())
+    }
+
+    fn on_authentication_failure(&mut self, user: UserNumber) -> AuthErrorCode {
+        tracing::warn!("authentication failed for user: {}", user);
+        self.increment(SecurityStatistic::AuthenticationFailures);
+        self.info
+            .authentication_failed(user, AuthErrorCode::AuthenticationFailed);
+        AuthErrorCode::AuthenticationFailed
+    }
+
+    fn write_challenge(
+        &mut self,
+        function: FunctionCode,
+        request: &[u8],
+        writer: &mut HeaderWriter,
+    ) -> AuthAction {
+        let challenge_data = match random_bytes(self.config.challenge_size()) {
+            Some(x) => x,
+            None => return self.discard(),
+        };
+
+        self.challenge_seq = self.challenge_seq.wrapping_add(1);
+
+        let object = Group120Var1 {
+            challenge_seq: self.challenge_seq,
+            // the user isn't known until the master replies
+            user: 0,
+            mac_algorithm: self.config.mac_algorithm.to_u8(),
+            reason: REASON_CRITICAL,
+            challenge_data: &challenge_data,
+        };
+
+        let action = Self::write_object(&object, writer);
+        if let AuthAction::Respond = action {
+            self.pending = Some(PendingChallenge {
+                challenge_seq: self.challenge_seq,
+                function,
+                request: request.to_vec(),
+                challenge: None,
+                expiration: Instant::now() + self.config.reply_timeout,
+            });
+            self.challenge_written = Some(self.challenge_seq);
+        }
+        action
+    }
+
+    fn write_error(
+        &mut self,
+        user: UserNumber,
+        error: AuthErrorCode,
+        writer: &mut HeaderWriter,
+    ) -> AuthAction {
+        self.increment(SecurityStatistic::ErrorMessagesSent);
+        let object = Group120Var7 {
+            challenge_seq: self.challenge_seq,
+            user: user.value(),
+            association_id: 0,
+            error_code: error.to_u8(),
+            time_of_error: Timestamp::try_from_system_time(SystemTime::now())
+                .unwrap_or_else(Timestamp::min),
+            error_text: "",
+        };
+        Self::write_object(&object, writer)
+    }
+
+    fn write_object<V: crate::app::file::FreeFormat>(
+        object: &V,
+        writer: &mut HeaderWriter,
+    ) -> AuthAction {
+        match writer.write_free_format(object) {
+            Ok(()) => AuthAction::Respond,
+            Err(err) => {
+                tracing::error!("unable to write authentication response: {:?}", err);
+                AuthAction::Discard
+            }
+        }
+    }
+
+    /// status of the keys of a user, expiring them if necessary
+    fn get_key_status(&mut self, user: UserNumber) -> KeyStatus {
+        let session = match self.sessions.get_mut(&user) {
+            Some(x) => x,
+            None => return KeyStatus::NotInit,
+        };
+
+        if session.keys.is_some() && Instant::now() >= session.expiration {
+            tracing::info!("session keys expired for user: {}", user);
+            session.keys = None;
+            session.status = KeyStatus::NotInit;
+            self.info.session_key_status(user, KeyStatus::NotInit);
+        }
+
+        session.status
+    }
+
+    fn unexpected(&mut self, reason: &str) -> AuthAction {
+        tracing::warn!("{}", reason);
+        self.increment(SecurityStatistic::UnexpectedMessages);
+        self.discard()
+    }
+
+    fn discard(&mut self) -> AuthAction {
+        self.increment(SecurityStatistic::DiscardedMessages);
+        AuthAction::Discard
+    }
+
+    fn increment(&mut self, statistic: SecurityStatistic) {
+        let value = &mut self.statistics[statistic.index() as usize];
+        *value = value.wrapping_add(1);
+        self.info.statistic_changed(statistic, *value);
+    }
+}
+
+/// critical request authenticated with aggressive mode objects (g120v3 ... g120v9)
+struct AggressiveModeRequest<'a> {
+    header: crate::app::variations::Group120Var3,
+    /// the fragment without the g120v9 header, authenticated by the MAC
+    signed: &'a [u8],
+    mac: &'a [u8],
+    /// the request without the authentication objects
+    inner: Vec<u8>,
+}
+
+impl<'a> AggressiveModeRequest<'a> {
+    fn parse(fragment: &'a [u8], headers: &[(ObjectHeader<'a>, &'a [u8])]) -> Option<Self> {
+        let ((first, first_bytes), rest) = headers.split_first()?;
+        let ((last, last_bytes), middle) = rest.split_last()?;
+
+        let header = match first.details.count() {
+            Some(CountVariation::Group120Var3(seq)) => seq.single()?,
+            _ => return None,
+        };
+
+        let mac = match last.details.free_format() {
+            Some(FreeFormatVariation::Group120Var9(x)) => x.mac,
+            _ => return None,
+        };
+
+        let signed = &fragment[..fragment.len() - last_bytes.len()];
+        let mut inner = fragment[..crate::app::RequestHeader::LENGTH].to_vec();
+        for (_, bytes) in middle {
+            inner.extend_from_slice(bytes);
+        }
+        debug_assert_eq!(
+            inner.len() + first_bytes.len() + last_bytes.len(),
+            fragment.len()
+        );
+
+        Some(Self {
+            header,
+            signed,
+            mac,
+            inner,
+        })
+    }
+}
+
+/// unwrap session keys, verifying that they were wrapped along with the expected key status message
+fn unwrap_keys(update_key: &UpdateKey, wrapped: &[u8], status: &[u8]) -> Option<SessionKeys> {
+    let (keys, rest) = SessionKeys::unwrap(update_key, wrapped)?;
+    if rest.len() < status.len() || rest.len() - status.len() >= 8 {
+        return None;
+    }
+    let (message, padding) = rest.split_at(status.len());
+    if message != status || padding.iter().any(|x| *x != 0) {
+        return None;
+    }
+    Some(keys)
+}
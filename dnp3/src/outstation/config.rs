@@ -1,7 +1,10 @@
 use crate::app::{BufferSize, Timeout};
 use crate::decode::DecodeLevel;
 use crate::link::EndpointAddress;
-use crate::outstation::database::{ClassZeroConfig, EventBufferConfig};
+use crate::outstation::database::{
+    ClassZeroConfig, EventAnalogOutputCommandVariation, EventBinaryOutputCommandVariation,
+    EventBufferConfig, EventClass,
+};
 
 /// describes whether an optional feature is enabled or disabled
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -33,6 +36,49 @@ impl Default for Features {
     }
 }
 
+/// Controls the automatic generation of command events (g13 and g43)
+///
+/// When enabled, the outstation records a command event each time an operate request
+/// is passed to the `ControlHandler`. The event contains the commanded state or value and
+/// the `CommandStatus` returned by the handler. The time is taken from the system clock and
+/// is reported as unsynchronized.
+///
+/// Events are only buffered if `EventBufferConfig` reserves space for the type.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CommandEventConfig {
+    /// class assigned to generated events, `None` disables automatic generation
+    pub class: Option<EventClass>,
+    /// default variation of binary output command events
+    pub binary_variation: EventBinaryOutputCommandVariation,
+    /// default variation of analog output command events
+    pub analog_variation: EventAnalogOutputCommandVariation,
+}
+
+impl CommandEventConfig {
+    /// command events are not generated automatically
+    pub fn disabled() -> Self {
+        Self {
+            class: None,
+            binary_variation: EventBinaryOutputCommandVariation::Group13Var1,
+            analog_variation: EventAnalogOutputCommandVariation::Group43Var1,
+        }
+    }
+
+    /// generate command events in the specified class using the default variations
+    pub fn enabled(class: EventClass) -> Self {
+        Self {
+            class: Some(class),
+            ..Self::disabled()
+        }
+    }
+}
+
+impl Default for CommandEventConfig {
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
+
 /// Outstation configuration parameters
 #[derive(Copy, Clone, Debug)]
 pub struct OutstationConfig {
@@ -75,6 +121,8 @@ pub struct OutstationConfig {
     pub max_controls_per_request: Option<u16>,
     /// controls responses to class 0 READ requests
     pub class_zero: ClassZeroConfig,
+    /// controls the automatic generation of command events
+    pub command_events: CommandEventConfig,
 }
 
 impl Feature {
@@ -122,6 +170,7 @@ impl OutstationConfig {
             max_read_request_headers: None,
             max_controls_per_request: None,
             class_zero: ClassZeroConfig::default(),
+            command_events: CommandEventConfig::default(),
         }
     }
 }
@@ -1,10 +1,17 @@
 use crate::app::control::*;
+use crate::app::format::write::HeaderWriter;
+use crate::app::gen::count::CountVariation;
 use crate::app::gen::prefixed::PrefixedVariation;
+use crate::app::gen::ranged::RangedVariation;
+use crate::app::measurement::{AnalogOutputCommandEvent, BinaryOutputCommandEvent, Time};
+use crate::app::parse::bit::BitSequence;
 use crate::app::parse::count::CountSequence;
 use crate::app::parse::parser::{HeaderCollection, HeaderDetails, HeaderIterator, ObjectHeader};
 use crate::app::parse::prefix::Prefix;
 use crate::app::parse::traits::{FixedSizeVariation, Index};
-use crate::app::{QualifierCode, Variation};
+use crate::app::variations::Group12Var2;
+use crate::app::{QualifierCode, Timestamp, Variation};
+use crate::outstation::config::CommandEventConfig;
 use crate::outstation::control::control_type::ControlType;
 use crate::outstation::control::prefix::PrefixWriter;
 use crate::outstation::database::DatabaseHandle;
@@ -15,12 +22,14 @@ use scursor::{WriteCursor, WriteError};
 pub(crate) struct ControlTransaction<'a> {
     started: bool,
     handler: &'a mut dyn ControlHandler,
+    command_events: CommandEventConfig,
 }
 
 impl<'a> ControlTransaction<'a> {
     pub(crate) async fn execute<F, R>(
         handler: &'a mut dyn ControlHandler,
         database: &mut DatabaseHandle,
+        command_events: CommandEventConfig,
         mut func: F,
     ) -> R
     where
@@ -29,6 +38,7 @@ impl<'a> ControlTransaction<'a> {
         let mut tx = ControlTransaction {
             started: false,
             handler,
+            command_events,
         };
 
         let ret = func(&mut tx, database);
@@ -46,6 +56,62 @@ impl<'a> ControlTransaction<'a> {
             self.handler.begin_fragment();
         }
     }
+
+    fn record_binary_command(
+        &self,
+        commanded_state: bool,
+        status: CommandStatus,
+        index: u16,
+        database: &mut DatabaseHandle,
+    ) {
+        if let Some(class) = self.command_events.class {
+            let event = BinaryOutputCommandEvent {
+                commanded_state,
+                status,
+                time: now(),
+            };
+            let variation = self.command_events.binary_variation;
+            database.transaction(|db| {
+                db.record_binary_output_command_event(index, class, variation, event)
+            });
+        }
+    }
+
+    fn record_analog_command(
+        &self,
+        commanded_value: f64,
+        status: CommandStatus,
+        index: u16,
+        database: &mut DatabaseHandle,
+    ) {
+        if let Some(class) = self.command_events.class {
+            let event = AnalogOutputCommandEvent {
+                commanded_value,
+                status,
+                time: now(),
+            };
+            let variation = self.command_events.analog_variation;
+            database.transaction(|db| {
+                db.record_analog_output_command_event(index, class, variation, event)
+            });
+        }
+    }
+}
+
+/// the outstation has no notion of whether its clock is synchronized
+fn now() -> Option<Time> {
+    Timestamp::try_from_system_time(std::time::SystemTime::now()).map(Time::Unsynchronized)
+}
+
+impl ControlCode {
+    /// the state that the control will leave the output in if it succeeds
+    fn commanded_state(&self) -> bool {
+        match self.op_type {
+            OpType::LatchOn | OpType::PulseOn => true,
+            OpType::LatchOff | OpType::PulseOff => false,
+            _ => self.tcc == TripCloseCode::Close,
+        }
+    }
 }
 
 impl<'a> ControlSupport<Group12Var1> for ControlTransaction<'a> {
@@ -67,7 +133,9 @@ impl<'a> ControlSupport<Group12Var1> for ControlTransaction<'a> {
         database: &mut DatabaseHandle,
     ) -> CommandStatus {
         self.start();
-        self.handler.operate(control, index, op_type, database)
+        let status = self.handler.operate(control, index, op_type, database);
+        self.record_binary_command(control.code.commanded_state(), status, index, database);
+        status
     }
 }
 
@@ -90,7 +158,9 @@ impl<'a> ControlSupport<Group41Var1> for ControlTransaction<'a> {
         database: &mut DatabaseHandle,
     ) -> CommandStatus {
         self.start();
-        self.handler.operate(control, index, op_type, database)
+        let status = self.handler.operate(control, index, op_type, database);
+        self.record_analog_command(control.value as f64, status, index, database);
+        status
     }
 }
 
@@ -113,7 +183,9 @@ impl<'a> ControlSupport<Group41Var2> for ControlTransaction<'a> {
         database: &mut DatabaseHandle,
     ) -> CommandStatus {
         self.start();
-        self.handler.operate(control, index, op_type, database)
+        let status = self.handler.operate(control, index, op_type, database);
+        self.record_analog_command(control.value as f64, status, index, database);
+        status
     }
 }
 
@@ -136,7 +208,9 @@ impl<'a> ControlSupport<Group41Var3> for ControlTransaction<'a> {
         database: &mut DatabaseHandle,
     ) -> CommandStatus {
         self.start();
-        self.handler.operate(control, index, op_type, database)
+        let status = self.handler.operate(control, index, op_type, database);
+        self.record_analog_command(control.value as f64, status, index, database);
+        status
     }
 }
 
@@ -159,7 +233,9 @@ impl<'a> ControlSupport<Group41Var4> for ControlTransaction<'a> {
         database: &mut DatabaseHandle,
     ) -> CommandStatus {
         self.start();
-        self.handler.operate(control, index, op_type, database)
+        let status = self.handler.operate(control, index, op_type, database);
+        self.record_analog_command(control.value, status, index, database);
+        status
     }
 }
 
@@ -218,6 +294,20 @@ pub(crate) enum ControlHeader<'a> {
     TwoByteGroup41Var2(CountSequence<'a, Prefix<u16, Group41Var2>>),
     TwoByteGroup41Var3(CountSequence<'a, Prefix<u16, Group41Var3>>),
     TwoByteGroup41Var4(CountSequence<'a, Prefix<u16, Group41Var4>>),
+    OneBytePattern(Pattern<'a, u8>),
+    TwoBytePattern(Pattern<'a, u16>),
+}
+
+/// pattern control block (g12v2) and the pattern mask (g12v3) that follows it
+#[derive(Debug, PartialEq)]
+pub(crate) struct Pattern<'a, I>
+where
+    I: Index,
+{
+    control: Group12Var2,
+    start: I,
+    stop: I,
+    mask: BitSequence<'a>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -246,16 +336,11 @@ impl<'a> ControlCollection<'a> {
 
     pub(crate) fn from(headers: HeaderCollection<'a>) -> Result<Self, BadControlHeader> {
         // do one pass to ensure that all headers are control headers
-        let non_control_header: Option<BadControlHeader> = headers.iter().find_map(|x| {
-            if let Err(header) = x.to_control_header() {
-                Some(header)
-            } else {
-                None
-            }
-        });
-
-        if let Some(err) = non_control_header {
-            return Err(err);
+        let mut iter = ControlHeaderIterator {
+            inner: headers.iter(),
+        };
+        while let Some(header) = iter.next_header() {
+            header?;
         }
 
         Ok(ControlCollection { inner: headers })
@@ -347,15 +432,58 @@ pub(crate) struct ControlHeaderIterator<'a> {
     inner: HeaderIterator<'a>,
 }
 
+impl<'a> ControlHeaderIterator<'a> {
+    fn next_header(&mut self) -> Option<Result<ControlHeader<'a>, BadControlHeader>> {
+        let header = self.inner.next()?;
+
+        let control = match header.details {
+            HeaderDetails::OneByteCount(_, CountVariation::Group12Var2(seq)) => seq.single(),
+            HeaderDetails::TwoByteCount(_, CountVariation::Group12Var2(seq)) => seq.single(),
+            _ => return Some(header.to_control_header()),
+        };
+
+        let bad_pattern = BadControlHeader::new(header.variation, header.details.qualifier());
+
+        let control = match control {
+            Some(x) => x,
+            None => return Some(Err(bad_pattern)),
+        };
+
+        // a pattern control block only applies to the mask in the header that follows it
+        let result = match self.inner.next().map(|x| x.details) {
+            Some(HeaderDetails::OneByteStartStop(
+                start,
+                stop,
+                RangedVariation::Group12Var3(mask),
+            )) => Ok(ControlHeader::OneBytePattern(Pattern {
+                control,
+                start,
+                stop,
+                mask,
+            })),
+            Some(HeaderDetails::TwoByteStartStop(
+                start,
+                stop,
+                RangedVariation::Group12Var3(mask),
+            )) => Ok(ControlHeader::TwoBytePattern(Pattern {
+                control,
+                start,
+                stop,
+                mask,
+            })),
+            _ => Err(bad_pattern),
+        };
+
+        Some(result)
+    }
+}
+
 impl<'a> Iterator for ControlHeaderIterator<'a> {
     type Item = ControlHeader<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.inner.next() {
-            None => None,
-            // this should always be some b/c of pre-validation
-            Some(x) => x.to_control_header().ok(),
-        }
+        // this should always be Ok b/c of pre-validation
+        self.next_header()?.ok()
     }
 }
 
@@ -376,6 +504,8 @@ impl<'a> ControlHeader<'a> {
             Self::TwoByteGroup41Var2(seq) => respond_with_status(cursor, seq, status),
             Self::TwoByteGroup41Var3(seq) => respond_with_status(cursor, seq, status),
             Self::TwoByteGroup41Var4(seq) => respond_with_status(cursor, seq, status),
+            Self::OneBytePattern(pattern) => pattern.respond_with_status(cursor, status),
+            Self::TwoBytePattern(pattern) => pattern.respond_with_status(cursor, status),
         }
     }
 
@@ -468,6 +598,20 @@ impl<'a> ControlHeader<'a> {
                 max_controls_per_request,
                 num_controls,
             ),
+            Self::OneBytePattern(pattern) => pattern.select_with_response(
+                cursor,
+                database,
+                transaction,
+                max_controls_per_request,
+                num_controls,
+            ),
+            Self::TwoBytePattern(pattern) => pattern.select_with_response(
+                cursor,
+                database,
+                transaction,
+                max_controls_per_request,
+                num_controls,
+            ),
         }
     }
 
@@ -571,6 +715,22 @@ impl<'a> ControlHeader<'a> {
                 max_controls_per_request,
                 num_controls,
             ),
+            Self::OneBytePattern(pattern) => pattern.operate_with_response(
+                cursor,
+                database,
+                operate_type,
+                transaction,
+                max_controls_per_request,
+                num_controls,
+            ),
+            Self::TwoBytePattern(pattern) => pattern.operate_with_response(
+                cursor,
+                database,
+                operate_type,
+                transaction,
+                max_controls_per_request,
+                num_controls,
+            ),
         }
     }
 
@@ -652,10 +812,112 @@ impl<'a> ControlHeader<'a> {
                 max_controls_per_request,
                 num_controls,
             ),
+            Self::OneBytePattern(pattern) => pattern.operate_no_ack(
+                database,
+                transaction,
+                max_controls_per_request,
+                num_controls,
+            ),
+            Self::TwoBytePattern(pattern) => pattern.operate_no_ack(
+                database,
+                transaction,
+                max_controls_per_request,
+                num_controls,
+            ),
         }
     }
 }
 
+impl<I> Pattern<'_, I>
+where
+    I: Index,
+{
+    /// apply the control to every index selected by the mask, returning the first error
+    fn execute<F>(
+        &self,
+        max_controls_per_request: Option<u16>,
+        num_controls: &mut u16,
+        mut action: F,
+    ) -> CommandStatus
+    where
+        F: FnMut(Group12Var1, u16) -> CommandStatus,
+    {
+        let control: Group12Var1 = self.control.into();
+        let mut ret = CommandStatus::Success;
+        for (_, index) in self.mask.iter().filter(|(selected, _)| *selected) {
+            let status = if max_controls_per_request.map_or(true, |max| *num_controls < max) {
+                action(control, index)
+            } else {
+                CommandStatus::TooManyOps
+            };
+            ret = ret.first_error(status);
+            *num_controls += 1;
+        }
+        ret
+    }
+
+    fn respond_with_status(
+        &self,
+        cursor: &mut WriteCursor,
+        status: CommandStatus,
+    ) -> Result<(), WriteError> {
+        let mut writer = HeaderWriter::new(cursor);
+        writer.write_count_of_one(Group12Var2 {
+            status,
+            ..self.control
+        })?;
+        writer.write_pattern_mask(self.start, self.stop, self.mask.iter().map(|(bit, _)| bit))
+    }
+
+    fn select_with_response(
+        &self,
+        cursor: &mut WriteCursor,
+        database: &mut DatabaseHandle,
+        transaction: &mut ControlTransaction,
+        max_controls_per_request: Option<u16>,
+        num_controls: &mut u16,
+    ) -> Result<CommandStatus, WriteError> {
+        let status = self.execute(max_controls_per_request, num_controls, |control, index| {
+            control.select(transaction, index, database)
+        });
+        self.respond_with_status(cursor, status)?;
+        Ok(status)
+    }
+
+    fn operate_with_response(
+        &self,
+        cursor: &mut WriteCursor,
+        database: &mut DatabaseHandle,
+        operate_type: OperateType,
+        transaction: &mut ControlTransaction,
+        max_controls_per_request: Option<u16>,
+        num_controls: &mut u16,
+    ) -> Result<CommandStatus, WriteError> {
+        let status = self.execute(max_controls_per_request, num_controls, |control, index| {
+            control.operate(transaction, index, operate_type, database)
+        });
+        self.respond_with_status(cursor, status)?;
+        Ok(status)
+    }
+
+    fn operate_no_ack(
+        &self,
+        database: &mut DatabaseHandle,
+        transaction: &mut ControlTransaction,
+        max_controls_per_request: Option<u16>,
+        num_controls: &mut u16,
+    ) {
+        self.execute(max_controls_per_request, num_controls, |control, index| {
+            control.operate(
+                transaction,
+                index,
+                OperateType::DirectOperateNoAck,
+                database,
+            )
+        });
+    }
+}
+
 fn respond_with_status<I, V>(
     cursor: &mut WriteCursor,
     seq: &CountSequence<Prefix<I, V>>,
@@ -9,6 +9,15 @@ pub enum EventBinaryInputVariation {
     Group2Var3,
 }
 
+/// Enum representing all possible `BinaryOutputCommandEvent` variations
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EventBinaryOutputCommandVariation {
+    /// Binary Output Command Event - Without Time
+    Group13Var1,
+    /// Binary Output Command Event - With Time
+    Group13Var2,
+}
+
 /// Enum representing all possible `BinaryOutputStatus` event variations
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum EventBinaryOutputStatusVariation {
@@ -76,6 +85,27 @@ pub enum EventAnalogInputVariation {
     Group32Var8,
 }
 
+/// Enum representing all possible `FrozenAnalogInput` event variations
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EventFrozenAnalogInputVariation {
+    /// Frozen Analog Input Event - 32-bit With Flag
+    Group33Var1,
+    /// Frozen Analog Input Event - 16-bit With Flag
+    Group33Var2,
+    /// Frozen Analog Input Event - 32-bit With Flag and Time
+    Group33Var3,
+    /// Frozen Analog Input Event - 16-bit With Flag and Time
+    Group33Var4,
+    /// Frozen Analog Input Event - Single-precision With Flag
+    Group33Var5,
+    /// Frozen Analog Input Event - Double-precision With Flag
+    Group33Var6,
+    /// Frozen Analog Input Event - Single-precision With Flag and Time
+    Group33Var7,
+    /// Frozen Analog Input Event - Double-precision With Flag and Time
+    Group33Var8,
+}
+
 /// Enum representing all possible `AnalogOutputStatus` event variations
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum EventAnalogOutputStatusVariation {
@@ -97,10 +127,39 @@ pub enum EventAnalogOutputStatusVariation {
     Group42Var8,
 }
 
+/// Enum representing all possible `AnalogOutputCommandEvent` variations
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EventAnalogOutputCommandVariation {
+    /// Analog Output Command Event - 32-bit
+    Group43Var1,
+    /// Analog Output Command Event - 16-bit
+    Group43Var2,
+    /// Analog Output Command Event - 32-bit With Time
+    Group43Var3,
+    /// Analog Output Command Event - 16-bit With Time
+    Group43Var4,
+    /// Analog Output Command Event - Single-precision
+    Group43Var5,
+    /// Analog Output Command Event - Double-precision
+    Group43Var6,
+    /// Analog Output Command Event - Single-precision With Time
+    Group43Var7,
+    /// Analog Output Command Event - Double-precision With Time
+    Group43Var8,
+}
+
 // This is always g111vX
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub(crate) struct EventOctetStringVariation;
 
+// This is always g88v1
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct EventDataSetVariation;
+
+// This is always g113vX where X is the length of the block
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct EventVirtualTerminalVariation(pub(crate) u8);
+
 /// Enum representing all possible `BinaryInput` static variations
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum StaticBinaryInputVariation {
@@ -175,6 +234,27 @@ pub enum StaticAnalogInputVariation {
     Group30Var6,
 }
 
+/// Enum representing all possible `FrozenAnalogInput` static variations
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StaticFrozenAnalogInputVariation {
+    /// Frozen Analog Input - 32-bit With Flag
+    Group31Var1,
+    /// Frozen Analog Input - 16-bit With Flag
+    Group31Var2,
+    /// Frozen Analog Input - 32-bit With Flag and Time-of-Freeze
+    Group31Var3,
+    /// Frozen Analog Input - 16-bit With Flag and Time-of-Freeze
+    Group31Var4,
+    /// Frozen Analog Input - 32-bit Without Flag
+    Group31Var5,
+    /// Frozen Analog Input - 16-bit Without Flag
+    Group31Var6,
+    /// Frozen Analog Input - Single-precision With Flag
+    Group31Var7,
+    /// Frozen Analog Input - Double-precision With Flag
+    Group31Var8,
+}
+
 /// Enum representing all possible `AnalogOutputStatus` static variations
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum StaticAnalogOutputStatusVariation {
@@ -188,10 +268,39 @@ pub enum StaticAnalogOutputStatusVariation {
     Group40Var4,
 }
 
+/// Enum representing all possible `UnsignedInteger` static variations
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StaticUnsignedIntegerVariation {
+    /// Unsigned Integer - 8-bit
+    Group102Var1,
+}
+
+/// Enum representing all possible `Bcd` static variations
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StaticBcdVariation {
+    /// BCD Integer - Small (4 digits)
+    Group101Var1,
+    /// BCD Integer - Medium (8 digits)
+    Group101Var2,
+    /// BCD Integer - Large (16 digits)
+    Group101Var3,
+}
+
 // This is always g110vX
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub(crate) struct StaticOctetStringVariation;
 
+/// Enum representing all possible analog input deadband variations
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum AnalogInputDeadbandVariation {
+    /// Analog Input Deadband - 16-bit
+    Group34Var1,
+    /// Analog Input Deadband - 32-bit
+    Group34Var2,
+    /// Analog Input Deadband - Single-precision
+    Group34Var3,
+}
+
 /// configuration for a `BinaryInput` point
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct BinaryInputConfig {
@@ -252,6 +361,17 @@ pub struct AnalogInputConfig {
     pub deadband: f64,
 }
 
+/// configuration for a `FrozenAnalogInput` point
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FrozenAnalogInputConfig {
+    /// default static variation
+    pub s_var: StaticFrozenAnalogInputVariation,
+    /// default event variation
+    pub e_var: EventFrozenAnalogInputVariation,
+    /// deadband - value of 0 means that any change will trigger an event
+    pub deadband: f64,
+}
+
 /// configuration for an `AnalogOutputStatus` point
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct AnalogOutputStatusConfig {
@@ -267,6 +387,38 @@ pub struct AnalogOutputStatusConfig {
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct OctetStringConfig;
 
+/// configuration for an `UnsignedInteger` point
+///
+/// Unsigned integers never produce events, so the event class passed when adding the point is ignored
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UnsignedIntegerConfig {
+    /// default static variation
+    pub s_var: StaticUnsignedIntegerVariation,
+}
+
+/// configuration for a `Bcd` point
+///
+/// BCD integers never produce events, so the event class passed when adding the point is ignored
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BcdConfig {
+    /// default static variation
+    pub s_var: StaticBcdVariation,
+}
+
+impl UnsignedIntegerConfig {
+    /// construct an `UnsignedIntegerConfig` from its fields
+    pub fn new(s_var: StaticUnsignedIntegerVariation) -> Self {
+        Self { s_var }
+    }
+}
+
+impl BcdConfig {
+    /// construct a `BcdConfig` from its fields
+    pub fn new(s_var: StaticBcdVariation) -> Self {
+        Self { s_var }
+    }
+}
+
 impl BinaryInputConfig {
     /// construct a `BinaryConfig` from its fields
     pub fn new(s_var: StaticBinaryInputVariation, e_var: EventBinaryInputVariation) -> Self {
@@ -335,6 +487,21 @@ impl AnalogInputConfig {
     }
 }
 
+impl FrozenAnalogInputConfig {
+    /// construct a `FrozenAnalogInputConfig` from its fields
+    pub fn new(
+        s_var: StaticFrozenAnalogInputVariation,
+        e_var: EventFrozenAnalogInputVariation,
+        deadband: f64,
+    ) -> Self {
+        Self {
+            s_var,
+            e_var,
+            deadband,
+        }
+    }
+}
+
 impl AnalogOutputStatusConfig {
     /// construct an `AnalogOutputStatusConfig` from its fields
     pub fn new(
@@ -407,6 +574,16 @@ impl Default for AnalogInputConfig {
     }
 }
 
+impl Default for FrozenAnalogInputConfig {
+    fn default() -> Self {
+        Self::new(
+            StaticFrozenAnalogInputVariation::Group31Var1,
+            EventFrozenAnalogInputVariation::Group33Var1,
+            0.0,
+        )
+    }
+}
+
 impl Default for AnalogOutputStatusConfig {
     fn default() -> Self {
         Self::new(
@@ -416,3 +593,15 @@ impl Default for AnalogOutputStatusConfig {
         )
     }
 }
+
+impl Default for UnsignedIntegerConfig {
+    fn default() -> Self {
+        Self::new(StaticUnsignedIntegerVariation::Group102Var1)
+    }
+}
+
+impl Default for BcdConfig {
+    fn default() -> Self {
+        Self::new(StaticBcdVariation::Group101Var1)
+    }
+}
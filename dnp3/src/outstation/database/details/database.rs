@@ -1,12 +1,16 @@
-use crate::app::Iin2;
+use crate::app::{DataSet, Iin2};
 use crate::master::EventClasses;
-use crate::outstation::database::details::event::buffer::EventBuffer;
+use crate::outstation::database::config::EventDataSetVariation;
+use crate::outstation::database::details::event::buffer::{EventBuffer, InsertError, Insertable};
+use crate::outstation::database::details::range::attr::AttrMap;
+use crate::outstation::database::details::range::data_set::DataSetMap;
 use crate::outstation::database::details::range::static_db::{
-    PointConfig, StaticDatabase, Updatable,
+    IndexRange, PointConfig, StaticDatabase, Updatable,
 };
 use crate::outstation::database::read::ReadHeader;
 use crate::outstation::database::{
-    ClassZeroConfig, EventBufferConfig, ResponseInfo, UpdateOptions,
+    ClassZeroConfig, DataSetDefError, EventBufferConfig, EventClass, PointType, ResponseInfo,
+    UpdateOptions,
 };
 
 use scursor::WriteCursor;
@@ -80,6 +84,57 @@ impl Database {
         self.static_db.get::<T>(index)
     }
 
+    pub(crate) fn get_analog_deadband(&self, index: u16) -> Option<f64> {
+        self.static_db.get_analog_deadband(index)
+    }
+
+    pub(crate) fn set_analog_deadband(&mut self, index: u16, deadband: f64) -> bool {
+        self.static_db.set_analog_deadband(index, deadband)
+    }
+
+    pub(crate) fn contains_range(&self, point_type: PointType, range: Option<IndexRange>) -> bool {
+        self.static_db.contains_range(point_type, range)
+    }
+
+    pub(crate) fn set_class(
+        &mut self,
+        point_type: PointType,
+        range: Option<IndexRange>,
+        class: Option<EventClass>,
+    ) -> bool {
+        self.static_db.set_class(point_type, range, class)
+    }
+
+    pub(crate) fn attrs(&self) -> &AttrMap {
+        self.static_db.attrs()
+    }
+
+    pub(crate) fn attrs_mut(&mut self) -> &mut AttrMap {
+        self.static_db.attrs_mut()
+    }
+
+    pub(crate) fn data_sets(&self) -> &DataSetMap {
+        self.static_db.data_sets()
+    }
+
+    pub(crate) fn data_sets_mut(&mut self) -> &mut DataSetMap {
+        self.static_db.data_sets_mut()
+    }
+
+    pub(crate) fn update_data_set(
+        &mut self,
+        value: &DataSet,
+        options: UpdateOptions,
+    ) -> Result<(), DataSetDefError> {
+        if let Some(class) = self.static_db.data_sets_mut().update(value, options)? {
+            // Overflow is handled in the event buffer
+            let _ = self
+                .event_buffer
+                .insert(value.id, class, value, EventDataSetVariation);
+        }
+        Ok(())
+    }
+
     pub(crate) fn update<T>(&mut self, value: &T, index: u16, options: UpdateOptions) -> bool
     where
         T: Updatable,
@@ -95,6 +150,24 @@ impl Database {
         exists
     }
 
+    /// insert an event for a type that has no static value
+    pub(crate) fn insert_event<T>(
+        &mut self,
+        value: &T,
+        index: u16,
+        class: EventClass,
+        variation: T::EventVariation,
+    ) -> bool
+    where
+        T: Insertable,
+    {
+        // Overflow is handled in the event buffer
+        !matches!(
+            self.event_buffer.insert(index, class, value, variation),
+            Err(InsertError::TypeMaxIsZero)
+        )
+    }
+
     pub(crate) fn write_response_headers(&mut self, cursor: &mut WriteCursor) -> ResponseInfo {
         // first we write events
         let result = self.event_buffer.write_events(cursor);
@@ -3,6 +3,7 @@ use std::fmt::Debug;
 use std::ops::BitOr;
 
 use crate::app::measurement::{self};
+use crate::app::DataSet;
 use crate::master::EventClasses;
 use crate::outstation::database::config::*;
 use crate::outstation::database::read::EventReadHeader;
@@ -148,11 +149,16 @@ pub(crate) struct TypeCounter {
     num_binary: Count,
     num_double_binary: Count,
     num_binary_output_status: Count,
+    num_binary_output_command: Count,
     num_counter: Count,
     num_frozen_counter: Count,
     num_analog: Count,
+    num_frozen_analog: Count,
     num_analog_output_status: Count,
+    num_analog_output_command: Count,
     num_octet_string: Count,
+    num_data_set: Count,
+    num_virtual_terminal: Count,
 }
 
 impl TypeCounter {
@@ -161,11 +167,16 @@ impl TypeCounter {
             num_binary: Count::new(),
             num_double_binary: Count::new(),
             num_binary_output_status: Count::new(),
+            num_binary_output_command: Count::new(),
             num_counter: Count::new(),
             num_frozen_counter: Count::new(),
             num_analog: Count::new(),
+            num_frozen_analog: Count::new(),
             num_analog_output_status: Count::new(),
+            num_analog_output_command: Count::new(),
             num_octet_string: Count::new(),
+            num_data_set: Count::new(),
+            num_virtual_terminal: Count::new(),
         }
     }
 
@@ -173,11 +184,16 @@ impl TypeCounter {
         self.num_binary.zero();
         self.num_double_binary.zero();
         self.num_binary_output_status.zero();
+        self.num_binary_output_command.zero();
         self.num_counter.zero();
         self.num_frozen_counter.zero();
         self.num_analog.zero();
+        self.num_frozen_analog.zero();
         self.num_analog_output_status.zero();
+        self.num_analog_output_command.zero();
         self.num_octet_string.zero();
+        self.num_data_set.zero();
+        self.num_virtual_terminal.zero();
     }
 
     fn increment(&mut self, event: &Event) {
@@ -198,11 +214,16 @@ impl TypeCounter {
             Event::Binary(_, _) => op(&mut self.num_binary),
             Event::DoubleBitBinary(_, _) => op(&mut self.num_double_binary),
             Event::BinaryOutputStatus(_, _) => op(&mut self.num_binary_output_status),
+            Event::BinaryOutputCommand(_, _) => op(&mut self.num_binary_output_command),
             Event::Counter(_, _) => op(&mut self.num_counter),
             Event::FrozenCounter(_, _) => op(&mut self.num_frozen_counter),
             Event::Analog(_, _) => op(&mut self.num_analog),
+            Event::FrozenAnalog(_, _) => op(&mut self.num_frozen_analog),
             Event::AnalogOutputStatus(_, _) => op(&mut self.num_analog_output_status),
+            Event::AnalogOutputCommand(_, _) => op(&mut self.num_analog_output_command),
             Event::OctetString(_, _) => op(&mut self.num_octet_string),
+            Event::DataSet(_, _) => op(&mut self.num_data_set),
+            Event::VirtualTerminal(_, _) => op(&mut self.num_virtual_terminal),
         }
     }
 }
@@ -237,11 +258,16 @@ impl Counters {
             Event::Binary(_, _) => self.types.num_binary.decrement(),
             Event::DoubleBitBinary(_, _) => self.types.num_double_binary.decrement(),
             Event::BinaryOutputStatus(_, _) => self.types.num_binary_output_status.decrement(),
+            Event::BinaryOutputCommand(_, _) => self.types.num_binary_output_command.decrement(),
             Event::Counter(_, _) => self.types.num_counter.decrement(),
             Event::FrozenCounter(_, _) => self.types.num_frozen_counter.decrement(),
             Event::Analog(_, _) => self.types.num_analog.decrement(),
+            Event::FrozenAnalog(_, _) => self.types.num_frozen_analog.decrement(),
             Event::AnalogOutputStatus(_, _) => self.types.num_analog_output_status.decrement(),
+            Event::AnalogOutputCommand(_, _) => self.types.num_analog_output_command.decrement(),
             Event::OctetString(_, _) => self.types.num_octet_string.decrement(),
+            Event::DataSet(_, _) => self.types.num_data_set.decrement(),
+            Event::VirtualTerminal(_, _) => self.types.num_virtual_terminal.decrement(),
         }
     }
 }
@@ -271,6 +297,10 @@ where
     }
 }
 
+/// block of virtual terminal output (g113) recorded for a port
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct VirtualTerminalBlock(pub(crate) Box<[u8]>);
+
 #[derive(Debug, PartialEq)]
 enum Event {
     Binary(
@@ -285,6 +315,10 @@ enum Event {
         measurement::BinaryOutputStatus,
         Variation<EventBinaryOutputStatusVariation>,
     ),
+    BinaryOutputCommand(
+        measurement::BinaryOutputCommandEvent,
+        Variation<EventBinaryOutputCommandVariation>,
+    ),
     Counter(measurement::Counter, Variation<EventCounterVariation>),
     FrozenCounter(
         measurement::FrozenCounter,
@@ -294,11 +328,24 @@ enum Event {
         measurement::AnalogInput,
         Variation<EventAnalogInputVariation>,
     ),
+    FrozenAnalog(
+        measurement::FrozenAnalogInput,
+        Variation<EventFrozenAnalogInputVariation>,
+    ),
     AnalogOutputStatus(
         measurement::AnalogOutputStatus,
         Variation<EventAnalogOutputStatusVariation>,
     ),
+    AnalogOutputCommand(
+        measurement::AnalogOutputCommandEvent,
+        Variation<EventAnalogOutputCommandVariation>,
+    ),
     OctetString(Box<[u8]>, Variation<EventOctetStringVariation>),
+    DataSet(DataSet, Variation<EventDataSetVariation>),
+    VirtualTerminal(
+        VirtualTerminalBlock,
+        Variation<EventVirtualTerminalVariation>,
+    ),
 }
 
 impl Event {
@@ -307,11 +354,16 @@ impl Event {
             Event::Binary(_, v) => v.select_default(),
             Event::DoubleBitBinary(_, v) => v.select_default(),
             Event::BinaryOutputStatus(_, v) => v.select_default(),
+            Event::BinaryOutputCommand(_, v) => v.select_default(),
             Event::Counter(_, v) => v.select_default(),
             Event::FrozenCounter(_, v) => v.select_default(),
             Event::Analog(_, v) => v.select_default(),
+            Event::FrozenAnalog(_, v) => v.select_default(),
             Event::AnalogOutputStatus(_, v) => v.select_default(),
+            Event::AnalogOutputCommand(_, v) => v.select_default(),
             Event::OctetString(_, v) => v.select_default(),
+            Event::DataSet(_, v) => v.select_default(),
+            Event::VirtualTerminal(_, v) => v.select_default(),
         }
     }
 
@@ -325,11 +377,20 @@ impl Event {
             Event::Binary(evt, v) => writer.write(cursor, evt, index, v.selected.get()),
             Event::DoubleBitBinary(evt, v) => writer.write(cursor, evt, index, v.selected.get()),
             Event::BinaryOutputStatus(evt, v) => writer.write(cursor, evt, index, v.selected.get()),
+            Event::BinaryOutputCommand(evt, v) => {
+                writer.write(cursor, evt, index, v.selected.get())
+            }
             Event::Counter(evt, v) => writer.write(cursor, evt, index, v.selected.get()),
             Event::FrozenCounter(evt, v) => writer.write(cursor, evt, index, v.selected.get()),
             Event::Analog(evt, v) => writer.write(cursor, evt, index, v.selected.get()),
+            Event::FrozenAnalog(evt, v) => writer.write(cursor, evt, index, v.selected.get()),
             Event::AnalogOutputStatus(evt, v) => writer.write(cursor, evt, index, v.selected.get()),
+            Event::AnalogOutputCommand(evt, v) => {
+                writer.write(cursor, evt, index, v.selected.get())
+            }
             Event::OctetString(evt, v) => writer.write(cursor, evt, index, v.selected.get()),
+            Event::DataSet(evt, v) => writer.write(cursor, evt, index, v.selected.get()),
+            Event::VirtualTerminal(evt, v) => writer.write(cursor, evt, index, v.selected.get()),
         }
     }
 }
@@ -478,6 +539,9 @@ impl EventBuffer {
             EventReadHeader::BinaryOutputStatus(v, limit) => {
                 self.select_by_type::<measurement::BinaryOutputStatus>(v, limit)
             }
+            EventReadHeader::BinaryOutputCommand(v, limit) => {
+                self.select_by_type::<measurement::BinaryOutputCommandEvent>(v, limit)
+            }
             EventReadHeader::Counter(v, limit) => {
                 self.select_by_type::<measurement::Counter>(v, limit)
             }
@@ -487,12 +551,22 @@ impl EventBuffer {
             EventReadHeader::Analog(v, limit) => {
                 self.select_by_type::<measurement::AnalogInput>(v, limit)
             }
+            EventReadHeader::FrozenAnalog(v, limit) => {
+                self.select_by_type::<measurement::FrozenAnalogInput>(v, limit)
+            }
             EventReadHeader::AnalogOutputStatus(v, limit) => {
                 self.select_by_type::<measurement::AnalogOutputStatus>(v, limit)
             }
+            EventReadHeader::AnalogOutputCommand(v, limit) => {
+                self.select_by_type::<measurement::AnalogOutputCommandEvent>(v, limit)
+            }
             EventReadHeader::OctetString(limit) => {
                 self.select_by_type::<measurement::OctetString>(None, limit)
             }
+            EventReadHeader::DataSet(limit) => self.select_by_type::<DataSet>(None, limit),
+            EventReadHeader::VirtualTerminal(limit) => {
+                self.select_by_type::<VirtualTerminalBlock>(None, limit)
+            }
         }
     }
 
@@ -607,11 +681,16 @@ impl EventBuffer {
         self.is_full::<measurement::BinaryInput>()
             || self.is_full::<measurement::DoubleBitBinaryInput>()
             || self.is_full::<measurement::BinaryOutputStatus>()
+            || self.is_full::<measurement::BinaryOutputCommandEvent>()
             || self.is_full::<measurement::Counter>()
             || self.is_full::<measurement::FrozenCounter>()
             || self.is_full::<measurement::AnalogInput>()
+            || self.is_full::<measurement::FrozenAnalogInput>()
             || self.is_full::<measurement::AnalogOutputStatus>()
+            || self.is_full::<measurement::AnalogOutputCommandEvent>()
             || self.is_full::<measurement::OctetString>()
+            || self.is_full::<DataSet>()
+            || self.is_full::<VirtualTerminalBlock>()
     }
 
     fn is_full<T>(&self) -> bool
@@ -793,6 +872,52 @@ impl Insertable for measurement::BinaryOutputStatus {
     }
 }
 
+impl Insertable for measurement::BinaryOutputCommandEvent {
+    type EventVariation = EventBinaryOutputCommandVariation;
+
+    fn get_max(config: &EventBufferConfig) -> u16 {
+        config.max_binary_output_command
+    }
+
+    fn get_type_count(counter: &TypeCounter) -> usize {
+        counter.num_binary_output_command.get()
+    }
+
+    fn is_type(record: &EventRecord) -> bool {
+        std::matches!(record.event, Event::BinaryOutputCommand(_, _))
+    }
+
+    fn decrement_type(counter: &mut TypeCounter) {
+        counter.num_binary_output_command.decrement();
+    }
+
+    fn increment_type(counter: &mut TypeCounter) {
+        counter.num_binary_output_command.increment();
+    }
+
+    fn create_event_record(
+        &self,
+        index: u16,
+        class: EventClass,
+        default_variation: EventBinaryOutputCommandVariation,
+    ) -> EventRecord {
+        EventRecord::new(
+            index,
+            class,
+            Event::BinaryOutputCommand(*self, Variation::new(default_variation)),
+        )
+    }
+
+    fn select_variation(record: &EventRecord, variation: Self::EventVariation) -> bool {
+        if let Event::BinaryOutputCommand(_, v) = &record.event {
+            v.selected.set(variation);
+            true
+        } else {
+            false
+        }
+    }
+}
+
 impl Insertable for measurement::Counter {
     type EventVariation = EventCounterVariation;
 
@@ -931,6 +1056,52 @@ impl Insertable for measurement::AnalogInput {
     }
 }
 
+impl Insertable for measurement::FrozenAnalogInput {
+    type EventVariation = EventFrozenAnalogInputVariation;
+
+    fn get_max(config: &EventBufferConfig) -> u16 {
+        config.max_frozen_analog
+    }
+
+    fn get_type_count(counter: &TypeCounter) -> usize {
+        counter.num_frozen_analog.get()
+    }
+
+    fn is_type(record: &EventRecord) -> bool {
+        std::matches!(record.event, Event::FrozenAnalog(_, _))
+    }
+
+    fn decrement_type(counter: &mut TypeCounter) {
+        counter.num_frozen_analog.decrement();
+    }
+
+    fn increment_type(counter: &mut TypeCounter) {
+        counter.num_frozen_analog.increment();
+    }
+
+    fn create_event_record(
+        &self,
+        index: u16,
+        class: EventClass,
+        default_variation: EventFrozenAnalogInputVariation,
+    ) -> EventRecord {
+        EventRecord::new(
+            index,
+            class,
+            Event::FrozenAnalog(*self, Variation::new(default_variation)),
+        )
+    }
+
+    fn select_variation(record: &EventRecord, variation: Self::EventVariation) -> bool {
+        if let Event::FrozenAnalog(_, v) = &record.event {
+            v.selected.set(variation);
+            true
+        } else {
+            false
+        }
+    }
+}
+
 impl Insertable for measurement::AnalogOutputStatus {
     type EventVariation = EventAnalogOutputStatusVariation;
 
@@ -977,6 +1148,52 @@ impl Insertable for measurement::AnalogOutputStatus {
     }
 }
 
+impl Insertable for measurement::AnalogOutputCommandEvent {
+    type EventVariation = EventAnalogOutputCommandVariation;
+
+    fn get_max(config: &EventBufferConfig) -> u16 {
+        config.max_analog_output_command
+    }
+
+    fn get_type_count(counter: &TypeCounter) -> usize {
+        counter.num_analog_output_command.get()
+    }
+
+    fn is_type(record: &EventRecord) -> bool {
+        std::matches!(record.event, Event::AnalogOutputCommand(_, _))
+    }
+
+    fn decrement_type(counter: &mut TypeCounter) {
+        counter.num_analog_output_command.decrement();
+    }
+
+    fn increment_type(counter: &mut TypeCounter) {
+        counter.num_analog_output_command.increment();
+    }
+
+    fn create_event_record(
+        &self,
+        index: u16,
+        class: EventClass,
+        default_variation: EventAnalogOutputCommandVariation,
+    ) -> EventRecord {
+        EventRecord::new(
+            index,
+            class,
+            Event::AnalogOutputCommand(*self, Variation::new(default_variation)),
+        )
+    }
+
+    fn select_variation(record: &EventRecord, variation: Self::EventVariation) -> bool {
+        if let Event::AnalogOutputCommand(_, v) = &record.event {
+            v.selected.set(variation);
+            true
+        } else {
+            false
+        }
+    }
+}
+
 impl Insertable for measurement::OctetString {
     type EventVariation = EventOctetStringVariation;
 
@@ -1023,6 +1240,166 @@ impl Insertable for measurement::OctetString {
     }
 }
 
+// unsigned integers are static-only, so the buffer never has room for them and never creates a record
+impl Insertable for measurement::UnsignedInteger {
+    type EventVariation = ();
+
+    fn get_max(_config: &EventBufferConfig) -> u16 {
+        0
+    }
+
+    fn get_type_count(_counter: &TypeCounter) -> usize {
+        0
+    }
+
+    fn is_type(_record: &EventRecord) -> bool {
+        false
+    }
+
+    fn decrement_type(_counter: &mut TypeCounter) {}
+
+    fn increment_type(_counter: &mut TypeCounter) {}
+
+    fn create_event_record(
+        &self,
+        _index: u16,
+        _class: EventClass,
+        _default_variation: (),
+    ) -> EventRecord {
+        unreachable!("unsigned integers never produce events")
+    }
+
+    fn select_variation(_record: &EventRecord, _variation: Self::EventVariation) -> bool {
+        false
+    }
+}
+
+// BCD integers are static-only, so the buffer never has room for them and never creates a record
+impl Insertable for measurement::Bcd {
+    type EventVariation = ();
+
+    fn get_max(_config: &EventBufferConfig) -> u16 {
+        0
+    }
+
+    fn get_type_count(_counter: &TypeCounter) -> usize {
+        0
+    }
+
+    fn is_type(_record: &EventRecord) -> bool {
+        false
+    }
+
+    fn decrement_type(_counter: &mut TypeCounter) {}
+
+    fn increment_type(_counter: &mut TypeCounter) {}
+
+    fn create_event_record(
+        &self,
+        _index: u16,
+        _class: EventClass,
+        _default_variation: (),
+    ) -> EventRecord {
+        unreachable!("BCD integers never produce events")
+    }
+
+    fn select_variation(_record: &EventRecord, _variation: Self::EventVariation) -> bool {
+        false
+    }
+}
+
+impl Insertable for DataSet {
+    type EventVariation = EventDataSetVariation;
+
+    fn get_max(config: &EventBufferConfig) -> u16 {
+        config.max_data_set
+    }
+
+    fn get_type_count(counter: &TypeCounter) -> usize {
+        counter.num_data_set.get()
+    }
+
+    fn is_type(record: &EventRecord) -> bool {
+        std::matches!(record.event, Event::DataSet(_, _))
+    }
+
+    fn decrement_type(counter: &mut TypeCounter) {
+        counter.num_data_set.decrement();
+    }
+
+    fn increment_type(counter: &mut TypeCounter) {
+        counter.num_data_set.increment();
+    }
+
+    fn create_event_record(
+        &self,
+        index: u16,
+        class: EventClass,
+        default_variation: EventDataSetVariation,
+    ) -> EventRecord {
+        EventRecord::new(
+            index,
+            class,
+            Event::DataSet(self.clone(), Variation::new(default_variation)),
+        )
+    }
+
+    fn select_variation(record: &EventRecord, variation: Self::EventVariation) -> bool {
+        if let Event::DataSet(_, v) = &record.event {
+            v.selected.set(variation);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Insertable for VirtualTerminalBlock {
+    type EventVariation = EventVirtualTerminalVariation;
+
+    fn get_max(config: &EventBufferConfig) -> u16 {
+        config.max_virtual_terminal
+    }
+
+    fn get_type_count(counter: &TypeCounter) -> usize {
+        counter.num_virtual_terminal.get()
+    }
+
+    fn is_type(record: &EventRecord) -> bool {
+        std::matches!(record.event, Event::VirtualTerminal(_, _))
+    }
+
+    fn decrement_type(counter: &mut TypeCounter) {
+        counter.num_virtual_terminal.decrement();
+    }
+
+    fn increment_type(counter: &mut TypeCounter) {
+        counter.num_virtual_terminal.increment();
+    }
+
+    fn create_event_record(
+        &self,
+        index: u16,
+        class: EventClass,
+        default_variation: EventVirtualTerminalVariation,
+    ) -> EventRecord {
+        EventRecord::new(
+            index,
+            class,
+            Event::VirtualTerminal(self.clone(), Variation::new(default_variation)),
+        )
+    }
+
+    fn select_variation(record: &EventRecord, variation: Self::EventVariation) -> bool {
+        if let Event::VirtualTerminal(_, v) = &record.event {
+            v.selected.set(variation);
+            true
+        } else {
+            false
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::app::measurement::*;
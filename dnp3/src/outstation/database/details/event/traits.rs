@@ -1,6 +1,8 @@
 use crate::app::measurement::*;
 use crate::app::variations::*;
+use crate::app::DataSet;
 use crate::outstation::database::config::*;
+use crate::outstation::database::details::event::buffer::VirtualTerminalBlock;
 use crate::outstation::database::details::event::write_fn::{
     write_cto, write_fixed_size, Continue,
 };
@@ -8,7 +10,7 @@ use crate::outstation::database::details::event::writer::HeaderType;
 
 use scursor::{WriteCursor, WriteError};
 
-use super::write_fn::write_octet_string;
+use super::write_fn::{write_data_set, write_octet_string};
 
 pub(crate) trait EventVariation<T> {
     fn write(
@@ -23,6 +25,10 @@ pub(crate) trait EventVariation<T> {
     fn uses_cto(&self) -> bool {
         false
     }
+    /// free-format variations are written with one event per header
+    fn uses_free_format(&self) -> bool {
+        false
+    }
 }
 
 impl EventVariation<BinaryInput> for EventBinaryInputVariation {
@@ -91,6 +97,36 @@ impl EventVariation<BinaryOutputStatus> for EventBinaryOutputStatusVariation {
     }
 }
 
+impl EventVariation<BinaryOutputCommandEvent> for EventBinaryOutputCommandVariation {
+    fn write(
+        &self,
+        cursor: &mut WriteCursor,
+        event: &BinaryOutputCommandEvent,
+        index: u16,
+        cto: Time,
+    ) -> Result<Continue, WriteError> {
+        match self {
+            Self::Group13Var1 => {
+                write_fixed_size::<Group13Var1, BinaryOutputCommandEvent>(cursor, event, index, cto)
+            }
+            Self::Group13Var2 => {
+                write_fixed_size::<Group13Var2, BinaryOutputCommandEvent>(cursor, event, index, cto)
+            }
+        }
+    }
+
+    fn wrap(&self) -> HeaderType {
+        HeaderType::BinaryOutputCommand(*self)
+    }
+
+    fn get_group_var(&self, _event: &BinaryOutputCommandEvent) -> (u8, u8) {
+        match self {
+            Self::Group13Var1 => (13, 1),
+            Self::Group13Var2 => (13, 2),
+        }
+    }
+}
+
 impl EventVariation<DoubleBitBinaryInput> for EventDoubleBitBinaryInputVariation {
     fn write(
         &self,
@@ -259,6 +295,60 @@ impl EventVariation<AnalogInput> for EventAnalogInputVariation {
     }
 }
 
+impl EventVariation<FrozenAnalogInput> for EventFrozenAnalogInputVariation {
+    fn write(
+        &self,
+        cursor: &mut WriteCursor,
+        event: &FrozenAnalogInput,
+        index: u16,
+        cto: Time,
+    ) -> Result<Continue, WriteError> {
+        match self {
+            Self::Group33Var1 => {
+                write_fixed_size::<Group33Var1, FrozenAnalogInput>(cursor, event, index, cto)
+            }
+            Self::Group33Var2 => {
+                write_fixed_size::<Group33Var2, FrozenAnalogInput>(cursor, event, index, cto)
+            }
+            Self::Group33Var3 => {
+                write_fixed_size::<Group33Var3, FrozenAnalogInput>(cursor, event, index, cto)
+            }
+            Self::Group33Var4 => {
+                write_fixed_size::<Group33Var4, FrozenAnalogInput>(cursor, event, index, cto)
+            }
+            Self::Group33Var5 => {
+                write_fixed_size::<Group33Var5, FrozenAnalogInput>(cursor, event, index, cto)
+            }
+            Self::Group33Var6 => {
+                write_fixed_size::<Group33Var6, FrozenAnalogInput>(cursor, event, index, cto)
+            }
+            Self::Group33Var7 => {
+                write_fixed_size::<Group33Var7, FrozenAnalogInput>(cursor, event, index, cto)
+            }
+            Self::Group33Var8 => {
+                write_fixed_size::<Group33Var8, FrozenAnalogInput>(cursor, event, index, cto)
+            }
+        }
+    }
+
+    fn wrap(&self) -> HeaderType {
+        HeaderType::FrozenAnalog(*self)
+    }
+
+    fn get_group_var(&self, _event: &FrozenAnalogInput) -> (u8, u8) {
+        match self {
+            Self::Group33Var1 => (33, 1),
+            Self::Group33Var2 => (33, 2),
+            Self::Group33Var3 => (33, 3),
+            Self::Group33Var4 => (33, 4),
+            Self::Group33Var5 => (33, 5),
+            Self::Group33Var6 => (33, 6),
+            Self::Group33Var7 => (33, 7),
+            Self::Group33Var8 => (33, 8),
+        }
+    }
+}
+
 impl EventVariation<AnalogOutputStatus> for EventAnalogOutputStatusVariation {
     fn write(
         &self,
@@ -313,6 +403,60 @@ impl EventVariation<AnalogOutputStatus> for EventAnalogOutputStatusVariation {
     }
 }
 
+impl EventVariation<AnalogOutputCommandEvent> for EventAnalogOutputCommandVariation {
+    fn write(
+        &self,
+        cursor: &mut WriteCursor,
+        event: &AnalogOutputCommandEvent,
+        index: u16,
+        cto: Time,
+    ) -> Result<Continue, WriteError> {
+        match self {
+            Self::Group43Var1 => {
+                write_fixed_size::<Group43Var1, AnalogOutputCommandEvent>(cursor, event, index, cto)
+            }
+            Self::Group43Var2 => {
+                write_fixed_size::<Group43Var2, AnalogOutputCommandEvent>(cursor, event, index, cto)
+            }
+            Self::Group43Var3 => {
+                write_fixed_size::<Group43Var3, AnalogOutputCommandEvent>(cursor, event, index, cto)
+            }
+            Self::Group43Var4 => {
+                write_fixed_size::<Group43Var4, AnalogOutputCommandEvent>(cursor, event, index, cto)
+            }
+            Self::Group43Var5 => {
+                write_fixed_size::<Group43Var5, AnalogOutputCommandEvent>(cursor, event, index, cto)
+            }
+            Self::Group43Var6 => {
+                write_fixed_size::<Group43Var6, AnalogOutputCommandEvent>(cursor, event, index, cto)
+            }
+            Self::Group43Var7 => {
+                write_fixed_size::<Group43Var7, AnalogOutputCommandEvent>(cursor, event, index, cto)
+            }
+            Self::Group43Var8 => {
+                write_fixed_size::<Group43Var8, AnalogOutputCommandEvent>(cursor, event, index, cto)
+            }
+        }
+    }
+
+    fn wrap(&self) -> HeaderType {
+        HeaderType::AnalogOutputCommand(*self)
+    }
+
+    fn get_group_var(&self, _event: &AnalogOutputCommandEvent) -> (u8, u8) {
+        match self {
+            Self::Group43Var1 => (43, 1),
+            Self::Group43Var2 => (43, 2),
+            Self::Group43Var3 => (43, 3),
+            Self::Group43Var4 => (43, 4),
+            Self::Group43Var5 => (43, 5),
+            Self::Group43Var6 => (43, 6),
+            Self::Group43Var7 => (43, 7),
+            Self::Group43Var8 => (43, 8),
+        }
+    }
+}
+
 impl EventVariation<Box<[u8]>> for EventOctetStringVariation {
     fn write(
         &self,
@@ -332,3 +476,48 @@ impl EventVariation<Box<[u8]>> for EventOctetStringVariation {
         (111, event.len() as u8)
     }
 }
+
+impl EventVariation<DataSet> for EventDataSetVariation {
+    fn write(
+        &self,
+        cursor: &mut WriteCursor,
+        event: &DataSet,
+        _index: u16,
+        _cto: Time,
+    ) -> Result<Continue, WriteError> {
+        write_data_set(cursor, event)
+    }
+
+    fn wrap(&self) -> HeaderType {
+        HeaderType::DataSet(*self)
+    }
+
+    fn get_group_var(&self, _event: &DataSet) -> (u8, u8) {
+        (88, 1)
+    }
+
+    fn uses_free_format(&self) -> bool {
+        true
+    }
+}
+
+impl EventVariation<VirtualTerminalBlock> for EventVirtualTerminalVariation {
+    fn write(
+        &self,
+        cursor: &mut WriteCursor,
+        event: &VirtualTerminalBlock,
+        index: u16,
+        _cto: Time,
+    ) -> Result<Continue, WriteError> {
+        // same encoding as octet string events, index followed by the block
+        write_octet_string(cursor, &event.0, index)
+    }
+
+    fn wrap(&self) -> HeaderType {
+        HeaderType::VirtualTerminal(*self)
+    }
+
+    fn get_group_var(&self, _event: &VirtualTerminalBlock) -> (u8, u8) {
+        (113, self.0)
+    }
+}
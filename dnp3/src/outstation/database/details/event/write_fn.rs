@@ -1,6 +1,7 @@
 use crate::app::measurement::*;
 use crate::app::parse::traits::FixedSize;
 use crate::app::variations::{Group2Var3, Group4Var3};
+use crate::app::DataSet;
 
 use scursor::{WriteCursor, WriteError};
 
@@ -85,6 +86,20 @@ pub(crate) fn write_octet_string(
     Ok(Continue::Ok)
 }
 
+/// data set events are written one per free-format header, so there is no index prefix
+pub(crate) fn write_data_set(
+    cursor: &mut WriteCursor,
+    event: &DataSet,
+) -> Result<Continue, WriteError> {
+    let pos_of_size = cursor.position();
+    cursor.skip(2)?;
+    let start = cursor.position();
+    event.write(cursor)?;
+    let size = u16::try_from(cursor.position() - start).map_err(|_| WriteError::NumericOverflow)?;
+    cursor.at_pos(pos_of_size, |cur| cur.write_u16_le(size))?;
+    Ok(Continue::Ok)
+}
+
 impl ToVariationCto<Group2Var3> for BinaryInput {
     fn get_time(&self) -> Option<Time> {
         self.time
@@ -1,9 +1,11 @@
 use crate::app::measurement::*;
 use crate::app::parse::traits::{FixedSize, FixedSizeVariation};
 use crate::app::variations::{Group51Var1, Group51Var2};
+use crate::app::DataSet;
 use crate::app::QualifierCode;
 use crate::app::Timestamp;
 use crate::outstation::database::config::*;
+use crate::outstation::database::details::event::buffer::VirtualTerminalBlock;
 use crate::outstation::database::details::event::traits::EventVariation;
 use crate::outstation::database::details::event::write_fn::Continue;
 
@@ -42,11 +44,16 @@ pub(crate) enum HeaderType {
     Binary(EventBinaryInputVariation),
     DoubleBitBinary(EventDoubleBitBinaryInputVariation),
     BinaryOutputStatus(EventBinaryOutputStatusVariation),
+    BinaryOutputCommand(EventBinaryOutputCommandVariation),
     Counter(EventCounterVariation),
     FrozenCounter(EventFrozenCounterVariation),
     Analog(EventAnalogInputVariation),
+    FrozenAnalog(EventFrozenAnalogInputVariation),
     AnalogOutputStatus(EventAnalogOutputStatusVariation),
+    AnalogOutputCommand(EventAnalogOutputCommandVariation),
     OctetString(EventOctetStringVariation),
+    DataSet(EventDataSetVariation),
+    VirtualTerminal(EventVirtualTerminalVariation),
 }
 
 #[derive(Copy, Clone)]
@@ -112,7 +119,7 @@ impl EventWriter {
                     None => self.start_new_header(cursor, event, index, variation),
                     // same type of header
                     Some(current_variation) => {
-                        if current_variation != variation {
+                        if current_variation != variation || variation.uses_free_format() {
                             self.start_new_header(cursor, event, index, variation)
                         } else {
                             // if we ever wrote this number of events, let's avoid overflow
@@ -160,6 +167,19 @@ impl EventWriter {
         Ok(count_pos)
     }
 
+    fn write_free_format_header(
+        cursor: &mut WriteCursor,
+        group: u8,
+        variation: u8,
+    ) -> Result<usize, WriteError> {
+        cursor.write_u8(group)?;
+        cursor.write_u8(variation)?;
+        cursor.write_u8(QualifierCode::FreeFormat16.as_u8())?;
+        let count_pos = cursor.position();
+        cursor.write_u8(1)?;
+        Ok(count_pos)
+    }
+
     fn write_cto_header<T>(cursor: &mut WriteCursor, cto: &T) -> Result<(), WriteError>
     where
         T: FixedSize + FixedSizeVariation,
@@ -207,7 +227,11 @@ impl EventWriter {
             }
 
             let (group, var) = variation.get_group_var(event);
-            let count_pos = Self::write_event_header(cursor, group, var)?;
+            let count_pos = if variation.uses_free_format() {
+                Self::write_free_format_header(cursor, group, var)?
+            } else {
+                Self::write_event_header(cursor, group, var)?
+            };
             variation.write(cursor, event, index, time).map(|_| ())?;
             Ok(count_pos)
         };
@@ -264,6 +288,21 @@ impl Writable for BinaryOutputStatus {
     }
 }
 
+impl Writable for BinaryOutputCommandEvent {
+    type EventVariation = EventBinaryOutputCommandVariation;
+
+    fn get_header_variation(&self, header: &HeaderType) -> Option<Self::EventVariation> {
+        match header {
+            HeaderType::BinaryOutputCommand(var) => Some(*var),
+            _ => None,
+        }
+    }
+
+    fn get_time(&self) -> Option<Time> {
+        self.time
+    }
+}
+
 impl Writable for Counter {
     type EventVariation = EventCounterVariation;
 
@@ -309,6 +348,21 @@ impl Writable for AnalogInput {
     }
 }
 
+impl Writable for FrozenAnalogInput {
+    type EventVariation = EventFrozenAnalogInputVariation;
+
+    fn get_header_variation(&self, header: &HeaderType) -> Option<Self::EventVariation> {
+        match header {
+            HeaderType::FrozenAnalog(var) => Some(*var),
+            _ => None,
+        }
+    }
+
+    fn get_time(&self) -> Option<Time> {
+        self.time
+    }
+}
+
 impl Writable for AnalogOutputStatus {
     type EventVariation = EventAnalogOutputStatusVariation;
 
@@ -324,6 +378,21 @@ impl Writable for AnalogOutputStatus {
     }
 }
 
+impl Writable for AnalogOutputCommandEvent {
+    type EventVariation = EventAnalogOutputCommandVariation;
+
+    fn get_header_variation(&self, header: &HeaderType) -> Option<Self::EventVariation> {
+        match header {
+            HeaderType::AnalogOutputCommand(var) => Some(*var),
+            _ => None,
+        }
+    }
+
+    fn get_time(&self) -> Option<Time> {
+        self.time
+    }
+}
+
 impl Writable for Box<[u8]> {
     type EventVariation = EventOctetStringVariation;
 
@@ -339,6 +408,36 @@ impl Writable for Box<[u8]> {
     }
 }
 
+impl Writable for DataSet {
+    type EventVariation = EventDataSetVariation;
+
+    fn get_header_variation(&self, header: &HeaderType) -> Option<Self::EventVariation> {
+        match header {
+            HeaderType::DataSet(var) => Some(*var),
+            _ => None,
+        }
+    }
+
+    fn get_time(&self) -> Option<Time> {
+        None
+    }
+}
+
+impl Writable for VirtualTerminalBlock {
+    type EventVariation = EventVirtualTerminalVariation;
+
+    fn get_header_variation(&self, header: &HeaderType) -> Option<Self::EventVariation> {
+        match header {
+            HeaderType::VirtualTerminal(var) => Some(*var),
+            _ => None,
+        }
+    }
+
+    fn get_time(&self) -> Option<Time> {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::app::measurement::Flags;
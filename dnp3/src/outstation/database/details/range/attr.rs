@@ -0,0 +1,160 @@
+use std::collections::BTreeMap;
+
+use crate::app::format::write::HeaderWriter;
+use crate::app::variations::Variation;
+use crate::app::*;
+use crate::outstation::database::details::range::static_db::IndexRange;
+
+use scursor::{WriteCursor, WriteError};
+
+/// variation used to request the list of attributes in a set
+const LIST_VARIATION: u8 = 255;
+
+struct AttrEntry {
+    prop: AttrProp,
+    value: OwnedAttrValue,
+}
+
+/// Device attributes (g0) stored by set and variation
+///
+/// Attributes are keyed by `(set << 8) | variation` so that all of the attributes in a set,
+/// followed by the attribute list (variation 255), can be selected as a single `IndexRange`.
+#[derive(Default)]
+pub(crate) struct AttrMap {
+    inner: BTreeMap<u16, AttrEntry>,
+}
+
+fn key(set: u8, variation: u8) -> u16 {
+    ((set as u16) << 8) | variation as u16
+}
+
+fn split(key: u16) -> (u8, u8) {
+    ((key >> 8) as u8, key as u8)
+}
+
+impl AttrMap {
+    pub(crate) fn define(&mut self, prop: AttrProp, attr: OwnedAttribute) {
+        self.inner.insert(
+            key(attr.set, attr.variation),
+            AttrEntry {
+                prop,
+                value: attr.value,
+            },
+        );
+    }
+
+    pub(crate) fn remove(&mut self, set: u8, variation: u8) -> bool {
+        self.inner.remove(&key(set, variation)).is_some()
+    }
+
+    pub(crate) fn get(&self, set: u8, variation: u8) -> Option<OwnedAttribute> {
+        self.inner
+            .get(&key(set, variation))
+            .map(|entry| OwnedAttribute::new(set, variation, entry.value.clone()))
+    }
+
+    /// check that the attribute may be written by the master
+    pub(crate) fn check_write(&self, attr: &Attribute) -> Iin2 {
+        match self.inner.get(&key(attr.set, attr.variation)) {
+            None => Iin2::OBJECT_UNKNOWN,
+            Some(entry) => {
+                if !entry.prop.is_writable {
+                    tracing::warn!(
+                        "attribute set: {} variation: {} is not writable",
+                        attr.set,
+                        attr.variation
+                    );
+                    return Iin2::PARAMETER_ERROR;
+                }
+                if entry.value.data_type() != attr.value.data_type() {
+                    tracing::warn!(
+                        "attribute set: {} variation: {} has type {:?}, but {:?} was written",
+                        attr.set,
+                        attr.variation,
+                        entry.value.data_type(),
+                        attr.value.data_type()
+                    );
+                    return Iin2::PARAMETER_ERROR;
+                }
+                Iin2::default()
+            }
+        }
+    }
+
+    pub(crate) fn write_value(&mut self, attr: &Attribute) {
+        if let Some(entry) = self.inner.get_mut(&key(attr.set, attr.variation)) {
+            entry.value = attr.value.to_owned_value();
+        }
+    }
+
+    /// determine the range of keys to report for a READ of the set and optional variation
+    pub(crate) fn select(&self, set: u8, variation: Option<u8>) -> Option<IndexRange> {
+        match variation {
+            Some(LIST_VARIATION) => {
+                let list = key(set, LIST_VARIATION);
+                self.has_set(set).then(|| IndexRange::new(list, list))
+            }
+            Some(variation) => {
+                let key = key(set, variation);
+                self.inner
+                    .contains_key(&key)
+                    .then(|| IndexRange::new(key, key))
+            }
+            None => self
+                .has_set(set)
+                .then(|| IndexRange::new(key(set, 0), key(set, LIST_VARIATION))),
+        }
+    }
+
+    fn has_set(&self, set: u8) -> bool {
+        self.inner
+            .range(key(set, 0)..=key(set, LIST_VARIATION))
+            .next()
+            .is_some()
+    }
+
+    /// write the selected attributes, returning the range that remains to be written on failure
+    pub(crate) fn write(
+        &self,
+        cursor: &mut WriteCursor,
+        range: IndexRange,
+    ) -> Result<(), IndexRange> {
+        let (set, last) = split(range.stop());
+
+        for (key, entry) in self.inner.range(range) {
+            let (_, variation) = split(*key);
+            if write_attr(cursor, set, variation, &entry.value).is_err() {
+                return Err(IndexRange::new(*key, range.stop()));
+            }
+        }
+
+        if last == LIST_VARIATION {
+            let list = OwnedAttrValue::AttrList(
+                self.inner
+                    .range(key(set, 0)..key(set, LIST_VARIATION))
+                    .map(|(key, entry)| AttrItem {
+                        variation: split(*key).1,
+                        properties: entry.prop,
+                    })
+                    .collect(),
+            );
+            if write_attr(cursor, set, LIST_VARIATION, &list).is_err() {
+                return Err(IndexRange::new(range.stop(), range.stop()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn write_attr(
+    cursor: &mut WriteCursor,
+    set: u8,
+    variation: u8,
+    value: &OwnedAttrValue,
+) -> Result<(), WriteError> {
+    cursor.transaction(|cur| {
+        HeaderWriter::new(cur).write_range_only(Variation::Group0(variation), set, set)?;
+        value.write(cur)
+    })
+}
@@ -0,0 +1,206 @@
+use std::collections::BTreeMap;
+
+use crate::app::file::FreeFormat;
+use crate::app::format::write::HeaderWriter;
+use crate::app::*;
+use crate::outstation::database::details::range::static_db::IndexRange;
+use crate::outstation::database::{DataSetDefError, EventClass, EventMode, UpdateOptions};
+
+use scursor::WriteCursor;
+
+/// maximum length of the ancillary value of a descriptor element so that it fits in a single
+/// element along with the code, type, and maximum length
+const MAX_ANCILLARY_LENGTH: usize = u8::MAX as usize - 3;
+
+struct DataSetEntry {
+    descriptor: DataSetDescriptor,
+    class: Option<EventClass>,
+    value: Option<DataSet>,
+}
+
+/// Data set prototypes (g85) and data sets (g86/g87) keyed by identifier
+#[derive(Default)]
+pub(crate) struct DataSetMap {
+    prototypes: BTreeMap<u16, DataSetPrototype>,
+    data_sets: BTreeMap<u16, DataSetEntry>,
+}
+
+impl DataSetMap {
+    pub(crate) fn define_prototype(
+        &mut self,
+        prototype: DataSetPrototype,
+    ) -> Result<(), DataSetDefError> {
+        if let Some(other) = self
+            .prototypes
+            .values()
+            .find(|x| x.uuid == prototype.uuid && x.id != prototype.id)
+        {
+            return Err(DataSetDefError::DuplicateUuid(other.id));
+        }
+        check_elements(&prototype.elements)?;
+        if prototype
+            .elements
+            .iter()
+            .any(|x| x.code == DescriptorCode::Prototype)
+        {
+            return Err(DataSetDefError::NestedPrototype);
+        }
+        self.prototypes.insert(prototype.id, prototype);
+        Ok(())
+    }
+
+    pub(crate) fn define(
+        &mut self,
+        descriptor: DataSetDescriptor,
+        class: Option<EventClass>,
+    ) -> Result<(), DataSetDefError> {
+        check_elements(&descriptor.elements)?;
+        if self.value_elements(&descriptor).is_none() {
+            return Err(DataSetDefError::UnknownPrototype);
+        }
+        self.data_sets.insert(
+            descriptor.id,
+            DataSetEntry {
+                descriptor,
+                class,
+                value: None,
+            },
+        );
+        Ok(())
+    }
+
+    pub(crate) fn remove(&mut self, id: u16) -> bool {
+        self.data_sets.remove(&id).is_some()
+    }
+
+    pub(crate) fn get(&self, id: u16) -> Option<DataSet> {
+        self.data_sets.get(&id).and_then(|x| x.value.clone())
+    }
+
+    /// validate and apply an update, returning the class of the event that should be produced
+    pub(crate) fn update(
+        &mut self,
+        value: &DataSet,
+        options: UpdateOptions,
+    ) -> Result<Option<EventClass>, DataSetDefError> {
+        let entry = self
+            .data_sets
+            .get(&value.id)
+            .ok_or(DataSetDefError::UnknownDataSet(value.id))?;
+
+        // prototypes may have been redefined since the data set was defined
+        let elements = self
+            .value_elements(&entry.descriptor)
+            .ok_or(DataSetDefError::UnknownPrototype)?;
+        if elements.len() != value.values.len() {
+            return Err(DataSetDefError::CountMismatch(
+                elements.len(),
+                value.values.len(),
+            ));
+        }
+        if let Some(pos) = value
+            .values
+            .iter()
+            .zip(elements.iter())
+            .position(|(v, e)| !v.matches(e))
+        {
+            return Err(DataSetDefError::TypeMismatch(pos));
+        }
+
+        let event = match options.event_mode {
+            EventMode::Detect => entry.value.as_ref() != Some(value),
+            EventMode::Force => true,
+            EventMode::Suppress => false,
+        };
+        let class = if event { entry.class } else { None };
+
+        if options.update_static {
+            if let Some(entry) = self.data_sets.get_mut(&value.id) {
+                entry.value = Some(value.clone());
+            }
+        }
+
+        Ok(class)
+    }
+
+    fn value_elements<'a>(
+        &'a self,
+        descriptor: &'a DataSetDescriptor,
+    ) -> Option<Vec<&'a DataSetElement>> {
+        descriptor
+            .value_elements(|uuid| self.prototypes.values().find(|x| x.uuid.as_slice() == uuid))
+    }
+
+    pub(crate) fn select_prototypes(&self) -> Option<IndexRange> {
+        full_range(&self.prototypes)
+    }
+
+    pub(crate) fn select_data_sets(&self) -> Option<IndexRange> {
+        full_range(&self.data_sets)
+    }
+
+    /// write the selected prototypes, returning the range that remains to be written on failure
+    pub(crate) fn write_prototypes(
+        &self,
+        cursor: &mut WriteCursor,
+        range: IndexRange,
+    ) -> Result<(), IndexRange> {
+        for (id, prototype) in self.prototypes.range(range) {
+            write_object(cursor, prototype).map_err(|_| IndexRange::new(*id, range.stop()))?;
+        }
+        Ok(())
+    }
+
+    /// write the selected descriptors, returning the range that remains to be written on failure
+    pub(crate) fn write_descriptors(
+        &self,
+        cursor: &mut WriteCursor,
+        range: IndexRange,
+    ) -> Result<(), IndexRange> {
+        for (id, entry) in self.data_sets.range(range) {
+            write_object(cursor, &entry.descriptor)
+                .map_err(|_| IndexRange::new(*id, range.stop()))?;
+        }
+        Ok(())
+    }
+
+    /// write the selected present values, returning the range that remains to be written on failure
+    ///
+    /// data sets that have never been updated have no present value and are skipped
+    pub(crate) fn write_present_values(
+        &self,
+        cursor: &mut WriteCursor,
+        range: IndexRange,
+    ) -> Result<(), IndexRange> {
+        for (id, entry) in self.data_sets.range(range) {
+            if let Some(value) = &entry.value {
+                write_object(cursor, &PresentValue(value))
+                    .map_err(|_| IndexRange::new(*id, range.stop()))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check_elements(elements: &[DataSetElement]) -> Result<(), DataSetDefError> {
+    match elements
+        .iter()
+        .find(|x| x.ancillary.len() > MAX_ANCILLARY_LENGTH)
+    {
+        Some(x) => Err(DataSetDefError::ElementTooLong(x.ancillary.len())),
+        None => Ok(()),
+    }
+}
+
+fn full_range<T>(map: &BTreeMap<u16, T>) -> Option<IndexRange> {
+    let (first, _) = map.first_key_value()?;
+    let (last, _) = map.last_key_value()?;
+    Some(IndexRange::new(*first, *last))
+}
+
+fn write_object<T>(cursor: &mut WriteCursor, object: &T) -> Result<(), scursor::WriteError>
+where
+    T: FreeFormat,
+{
+    cursor.transaction(|cur| HeaderWriter::new(cur).write_free_format(object))
+}
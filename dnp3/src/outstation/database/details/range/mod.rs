@@ -1,3 +1,5 @@
+pub(crate) mod attr;
+pub(crate) mod data_set;
 pub(crate) mod static_db;
 pub(crate) mod traits;
 pub(crate) mod writer;
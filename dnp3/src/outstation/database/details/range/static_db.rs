@@ -6,10 +6,14 @@ use crate::app::Iin2;
 use crate::outstation::config::OutstationConfig;
 use crate::outstation::database::config::*;
 use crate::outstation::database::details::event::buffer::Insertable;
+use crate::outstation::database::details::range::attr::AttrMap;
+use crate::outstation::database::details::range::data_set::DataSetMap;
 use crate::outstation::database::details::range::traits::StaticVariation;
 use crate::outstation::database::details::range::writer::RangeWriter;
 use crate::outstation::database::read::StaticReadHeader;
-use crate::outstation::database::{ClassZeroConfig, EventClass, EventMode, UpdateOptions};
+use crate::outstation::database::{
+    ClassZeroConfig, EventClass, EventMode, PointType, UpdateOptions,
+};
 
 use crate::util::BadWrite;
 use scursor::WriteCursor;
@@ -40,6 +44,24 @@ impl IndexRange {
     pub(crate) fn new(start: u16, stop: u16) -> Self {
         Self { start, stop }
     }
+
+    pub(crate) fn stop(&self) -> u16 {
+        self.stop
+    }
+
+    /// number of indices in the range
+    pub(crate) fn len(&self) -> usize {
+        if self.stop < self.start {
+            0
+        } else {
+            (self.stop - self.start) as usize + 1
+        }
+    }
+
+    /// the index of a range containing a single value
+    pub(crate) fn single(&self) -> Option<u16> {
+        (self.start == self.stop).then_some(self.start)
+    }
 }
 
 impl RangeBounds<u16> for IndexRange {
@@ -71,8 +93,16 @@ pub(crate) enum SpecificVariation {
     Counter(Option<StaticCounterVariation>),
     FrozenCounter(Option<StaticFrozenCounterVariation>),
     Analog(Option<StaticAnalogInputVariation>),
+    FrozenAnalog(Option<StaticFrozenAnalogInputVariation>),
     AnalogOutputStatus(Option<StaticAnalogOutputStatusVariation>),
     OctetString,
+    UnsignedInteger(Option<StaticUnsignedIntegerVariation>),
+    Bcd(Option<StaticBcdVariation>),
+    AnalogInputDeadband(Option<AnalogInputDeadbandVariation>),
+    DeviceAttribute,
+    DataSetPrototype,
+    DataSetDescriptor,
+    DataSetPresentValue,
 }
 
 impl SpecificVariation {
@@ -204,6 +234,40 @@ where
         self.inner.get_mut(&index)
     }
 
+    /// true if every index in the range exists, or if the range is None
+    fn contains_range(&self, range: Option<IndexRange>) -> bool {
+        match range {
+            Some(range) => self.inner.range(range).count() == range.len(),
+            None => true,
+        }
+    }
+
+    /// set the class of the points in the range, or every point if the range is None
+    ///
+    /// returns false without changing any point if any index in the range doesn't exist
+    fn set_class(&mut self, range: Option<IndexRange>, class: Option<EventClass>) -> bool {
+        if !self.contains_range(range) {
+            return false;
+        }
+
+        let range = match range.or_else(|| self.full_range()) {
+            Some(range) => range,
+            None => return true,
+        };
+
+        for (_index, point) in self.inner.range_mut(range) {
+            point.config.class = class;
+        }
+
+        true
+    }
+
+    fn full_range(&self) -> Option<IndexRange> {
+        let (start, _) = self.inner.first_key_value()?;
+        let (stop, _) = self.inner.last_key_value()?;
+        Some(IndexRange::new(*start, *stop))
+    }
+
     fn select_range_with_variation(
         &mut self,
         range: IndexRange,
@@ -229,16 +293,10 @@ where
         &mut self,
         variation: Option<T::StaticVariation>,
     ) -> Option<VariationRange> {
-        /*
-          TODO - when this feature is stabilized we can use it here
-          TODO - #![feature(map_first_last)]
-          TODO - It'll be more efficient than finding first/last using iterators
-        */
-        let start = self.inner.iter().next().map(|(key, _)| *key)?;
-        let stop = self.inner.iter().next_back().map(|(key, _)| *key)?;
+        let range = self.full_range()?;
 
         // as far at the processing goes, we treat this just like a range scan over all the values
-        self.select_range_with_variation(IndexRange::new(start, stop), variation)
+        self.select_range_with_variation(range, variation)
     }
 }
 
@@ -252,8 +310,14 @@ pub(crate) struct StaticDatabase {
     counter: PointMap<Counter>,
     frozen_counter: PointMap<FrozenCounter>,
     analog: PointMap<AnalogInput>,
+    frozen_analog: PointMap<FrozenAnalogInput>,
     analog_output_status: PointMap<AnalogOutputStatus>,
     octet_strings: PointMap<OctetString>,
+    unsigned_integers: PointMap<UnsignedInteger>,
+    bcds: PointMap<Bcd>,
+    // device attributes are not measurements, but are read using the same selection mechanism
+    attrs: AttrMap,
+    data_sets: DataSetMap,
 }
 
 impl Default for StaticDatabase {
@@ -278,8 +342,13 @@ impl StaticDatabase {
             counter: PointMap::empty(),
             frozen_counter: PointMap::empty(),
             analog: PointMap::empty(),
+            frozen_analog: PointMap::empty(),
             analog_output_status: PointMap::empty(),
             octet_strings: PointMap::empty(),
+            unsigned_integers: PointMap::empty(),
+            bcds: PointMap::empty(),
+            attrs: AttrMap::default(),
+            data_sets: DataSetMap::default(),
         }
     }
 
@@ -292,6 +361,72 @@ impl StaticDatabase {
         self.selected.reset();
     }
 
+    pub(crate) fn attrs(&self) -> &AttrMap {
+        &self.attrs
+    }
+
+    pub(crate) fn attrs_mut(&mut self) -> &mut AttrMap {
+        &mut self.attrs
+    }
+
+    pub(crate) fn data_sets(&self) -> &DataSetMap {
+        &self.data_sets
+    }
+
+    pub(crate) fn data_sets_mut(&mut self) -> &mut DataSetMap {
+        &mut self.data_sets
+    }
+
+    pub(crate) fn get_analog_deadband(&self, index: u16) -> Option<f64> {
+        self.analog
+            .inner
+            .get(&index)
+            .map(|point| point.config.detector.deadband)
+    }
+
+    pub(crate) fn set_analog_deadband(&mut self, index: u16, deadband: f64) -> bool {
+        match self.analog.get_mut(index) {
+            Some(point) => {
+                point.config.detector = Deadband::new(deadband);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub(crate) fn contains_range(&self, point_type: PointType, range: Option<IndexRange>) -> bool {
+        match point_type {
+            PointType::BinaryInput => self.binary.contains_range(range),
+            PointType::DoubleBitBinaryInput => self.double_bit_binary.contains_range(range),
+            PointType::BinaryOutputStatus => self.binary_output_status.contains_range(range),
+            PointType::Counter => self.counter.contains_range(range),
+            PointType::FrozenCounter => self.frozen_counter.contains_range(range),
+            PointType::AnalogInput => self.analog.contains_range(range),
+            PointType::FrozenAnalogInput => self.frozen_analog.contains_range(range),
+            PointType::AnalogOutputStatus => self.analog_output_status.contains_range(range),
+            PointType::OctetString => self.octet_strings.contains_range(range),
+        }
+    }
+
+    pub(crate) fn set_class(
+        &mut self,
+        point_type: PointType,
+        range: Option<IndexRange>,
+        class: Option<EventClass>,
+    ) -> bool {
+        match point_type {
+            PointType::BinaryInput => self.binary.set_class(range, class),
+            PointType::DoubleBitBinaryInput => self.double_bit_binary.set_class(range, class),
+            PointType::BinaryOutputStatus => self.binary_output_status.set_class(range, class),
+            PointType::Counter => self.counter.set_class(range, class),
+            PointType::FrozenCounter => self.frozen_counter.set_class(range, class),
+            PointType::AnalogInput => self.analog.set_class(range, class),
+            PointType::FrozenAnalogInput => self.frozen_analog.set_class(range, class),
+            PointType::AnalogOutputStatus => self.analog_output_status.set_class(range, class),
+            PointType::OctetString => self.octet_strings.set_class(range, class),
+        }
+    }
+
     pub(crate) fn add<T>(&mut self, index: u16, config: PointConfig<T>) -> bool
     where
         T: Updatable,
@@ -405,12 +540,38 @@ impl StaticDatabase {
             SpecificVariation::Analog(var) => {
                 self.write_typed_range::<AnalogInput>(cursor, range.range, var)
             }
+            SpecificVariation::FrozenAnalog(var) => {
+                self.write_typed_range::<FrozenAnalogInput>(cursor, range.range, var)
+            }
             SpecificVariation::AnalogOutputStatus(var) => {
                 self.write_typed_range::<AnalogOutputStatus>(cursor, range.range, var)
             }
             SpecificVariation::OctetString => {
                 self.write_typed_range::<OctetString>(cursor, range.range, None)
             }
+            SpecificVariation::UnsignedInteger(var) => {
+                self.write_typed_range::<UnsignedInteger>(cursor, range.range, var)
+            }
+            SpecificVariation::Bcd(var) => self.write_typed_range::<Bcd>(cursor, range.range, var),
+            SpecificVariation::AnalogInputDeadband(var) => {
+                self.write_deadband_range(cursor, range.range, var)
+            }
+            SpecificVariation::DeviceAttribute => self
+                .attrs
+                .write(cursor, range.range)
+                .map_err(|remaining| SpecificVariation::DeviceAttribute.with(remaining)),
+            SpecificVariation::DataSetPrototype => self
+                .data_sets
+                .write_prototypes(cursor, range.range)
+                .map_err(|remaining| SpecificVariation::DataSetPrototype.with(remaining)),
+            SpecificVariation::DataSetDescriptor => self
+                .data_sets
+                .write_descriptors(cursor, range.range)
+                .map_err(|remaining| SpecificVariation::DataSetDescriptor.with(remaining)),
+            SpecificVariation::DataSetPresentValue => self
+                .data_sets
+                .write_present_values(cursor, range.range)
+                .map_err(|remaining| SpecificVariation::DataSetPresentValue.with(remaining)),
         }
     }
 
@@ -440,6 +601,31 @@ impl StaticDatabase {
         Ok(())
     }
 
+    fn write_deadband_range(
+        &self,
+        cursor: &mut WriteCursor,
+        range: IndexRange,
+        variation: Option<AnalogInputDeadbandVariation>,
+    ) -> Result<(), VariationRange> {
+        let mut writer = RangeWriter::new();
+        for (index, point) in self.analog.inner.range(range) {
+            let info = variation
+                .unwrap_or_else(|| AnalogInputDeadbandVariation::default_for(point.config.s_var))
+                .get_write_info();
+
+            if writer
+                .write(cursor, *index, &point.config.detector.deadband, info)
+                .is_err()
+            {
+                // ran out of space, tell calling code to resume at this index
+                return Err(SpecificVariation::AnalogInputDeadband(variation)
+                    .with(IndexRange::new(*index, range.stop)));
+            }
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn select(&mut self, variation: StaticReadHeader) -> Iin2 {
         match variation {
             StaticReadHeader::Class0 => self.select_class_zero(),
@@ -461,10 +647,51 @@ impl StaticDatabase {
             StaticReadHeader::Analog(variation, range) => {
                 self.select_by_type::<AnalogInput>(variation, range)
             }
+            StaticReadHeader::FrozenAnalog(variation, range) => {
+                self.select_by_type::<FrozenAnalogInput>(variation, range)
+            }
             StaticReadHeader::AnalogOutputStatus(variation, range) => {
                 self.select_by_type::<AnalogOutputStatus>(variation, range)
             }
             StaticReadHeader::OctetString(range) => self.select_by_type::<OctetString>(None, range),
+            StaticReadHeader::UnsignedInteger(variation, range) => {
+                self.select_by_type::<UnsignedInteger>(variation, range)
+            }
+            StaticReadHeader::Bcd(variation, range) => self.select_by_type::<Bcd>(variation, range),
+            StaticReadHeader::AnalogInputDeadband(variation, range) => {
+                // deadbands are part of the point configuration, so there's no value to snapshot
+                match range.or_else(|| self.analog.full_range()) {
+                    Some(range) => self.push_selection(
+                        SpecificVariation::AnalogInputDeadband(variation).with(range),
+                    ),
+                    None => Iin2::default(),
+                }
+            }
+            StaticReadHeader::DeviceAttribute(set, variation) => {
+                match self.attrs.select(set, variation) {
+                    Some(range) => {
+                        self.push_selection(SpecificVariation::DeviceAttribute.with(range))
+                    }
+                    None => Iin2::OBJECT_UNKNOWN,
+                }
+            }
+            // like device attributes, data sets are written from their current values
+            StaticReadHeader::DataSetPrototype => match self.data_sets.select_prototypes() {
+                Some(range) => self.push_selection(SpecificVariation::DataSetPrototype.with(range)),
+                None => Iin2::default(),
+            },
+            StaticReadHeader::DataSetDescriptor => match self.data_sets.select_data_sets() {
+                Some(range) => {
+                    self.push_selection(SpecificVariation::DataSetDescriptor.with(range))
+                }
+                None => Iin2::default(),
+            },
+            StaticReadHeader::DataSetPresentValue => match self.data_sets.select_data_sets() {
+                Some(range) => {
+                    self.push_selection(SpecificVariation::DataSetPresentValue.with(range))
+                }
+                None => Iin2::default(),
+            },
         }
     }
 
@@ -537,8 +764,11 @@ impl StaticDatabase {
             | self.select_class_zero_type::<Counter>()
             | self.select_class_zero_type::<FrozenCounter>()
             | self.select_class_zero_type::<AnalogInput>()
+            | self.select_class_zero_type::<FrozenAnalogInput>()
             | self.select_class_zero_type::<AnalogOutputStatus>()
             | self.select_class_zero_type::<OctetString>()
+            | self.select_class_zero_type::<UnsignedInteger>()
+            | self.select_class_zero_type::<Bcd>()
     }
 }
 
@@ -551,6 +781,8 @@ where
 }
 
 pub(crate) struct OctetStringDetector;
+// detector for static-only types that never produce events
+pub(crate) struct NoEventDetector;
 
 impl<N> Deadband<N>
 where
@@ -607,6 +839,12 @@ impl HasValue<f64> for AnalogInput {
     }
 }
 
+impl HasValue<f64> for FrozenAnalogInput {
+    fn value(&self) -> f64 {
+        self.value
+    }
+}
+
 impl HasValue<f64> for AnalogOutputStatus {
     fn value(&self) -> f64 {
         self.value
@@ -633,6 +871,15 @@ impl EventDetector<OctetString> for OctetStringDetector {
     }
 }
 
+impl<T> EventDetector<T> for NoEventDetector
+where
+    T: Updatable,
+{
+    fn is_event(&self, _new: &T, _old: &T) -> bool {
+        false
+    }
+}
+
 impl Updatable for BinaryInput {
     type StaticVariation = StaticBinaryInputVariation;
     type Detector = FlagsDetector;
@@ -759,6 +1006,27 @@ impl Updatable for AnalogInput {
     }
 }
 
+impl Updatable for FrozenAnalogInput {
+    type StaticVariation = StaticFrozenAnalogInputVariation;
+    type Detector = Deadband<f64>;
+
+    fn get_map(maps: &StaticDatabase) -> &PointMap<Self> {
+        &maps.frozen_analog
+    }
+
+    fn get_mut_map(maps: &mut StaticDatabase) -> &mut PointMap<Self> {
+        &mut maps.frozen_analog
+    }
+
+    fn wrap(range: IndexRange, variation: Option<Self::StaticVariation>) -> VariationRange {
+        SpecificVariation::FrozenAnalog(variation).with(range)
+    }
+
+    fn enabled_class_zero(config: &ClassZeroConfig) -> bool {
+        config.frozen_analog
+    }
+}
+
 impl Updatable for AnalogOutputStatus {
     type StaticVariation = StaticAnalogOutputStatusVariation;
     type Detector = Deadband<f64>;
@@ -801,6 +1069,48 @@ impl Updatable for OctetString {
     }
 }
 
+impl Updatable for UnsignedInteger {
+    type StaticVariation = StaticUnsignedIntegerVariation;
+    type Detector = NoEventDetector;
+
+    fn get_map(maps: &StaticDatabase) -> &PointMap<Self> {
+        &maps.unsigned_integers
+    }
+
+    fn get_mut_map(maps: &mut StaticDatabase) -> &mut PointMap<Self> {
+        &mut maps.unsigned_integers
+    }
+
+    fn wrap(range: IndexRange, variation: Option<Self::StaticVariation>) -> VariationRange {
+        SpecificVariation::UnsignedInteger(variation).with(range)
+    }
+
+    fn enabled_class_zero(config: &ClassZeroConfig) -> bool {
+        config.unsigned_integer
+    }
+}
+
+impl Updatable for Bcd {
+    type StaticVariation = StaticBcdVariation;
+    type Detector = NoEventDetector;
+
+    fn get_map(maps: &StaticDatabase) -> &PointMap<Self> {
+        &maps.bcds
+    }
+
+    fn get_mut_map(maps: &mut StaticDatabase) -> &mut PointMap<Self> {
+        &mut maps.bcds
+    }
+
+    fn wrap(range: IndexRange, variation: Option<Self::StaticVariation>) -> VariationRange {
+        SpecificVariation::Bcd(variation).with(range)
+    }
+
+    fn enabled_class_zero(config: &ClassZeroConfig) -> bool {
+        config.bcd
+    }
+}
+
 impl Default for BinaryInput {
     fn default() -> Self {
         Self::new(false, Flags::RESTART, Time::unsynchronized(0))
@@ -841,6 +1151,12 @@ impl Default for AnalogInput {
     }
 }
 
+impl Default for FrozenAnalogInput {
+    fn default() -> Self {
+        Self::new(0.0, Flags::RESTART, Time::unsynchronized(0))
+    }
+}
+
 impl Default for AnalogOutputStatus {
     fn default() -> Self {
         Self::new(0.0, Flags::RESTART, Time::unsynchronized(0))
@@ -178,6 +178,37 @@ impl StaticVariation<AnalogInput> for StaticAnalogInputVariation {
     }
 }
 
+impl StaticVariation<FrozenAnalogInput> for StaticFrozenAnalogInputVariation {
+    fn get_write_info(&self, _value: &FrozenAnalogInput) -> WriteInfo<FrozenAnalogInput> {
+        match self {
+            StaticFrozenAnalogInputVariation::Group31Var1 => {
+                fixed_type::<FrozenAnalogInput, Group31Var1>()
+            }
+            StaticFrozenAnalogInputVariation::Group31Var2 => {
+                fixed_type::<FrozenAnalogInput, Group31Var2>()
+            }
+            StaticFrozenAnalogInputVariation::Group31Var3 => {
+                fixed_type::<FrozenAnalogInput, Group31Var3>()
+            }
+            StaticFrozenAnalogInputVariation::Group31Var4 => {
+                fixed_type::<FrozenAnalogInput, Group31Var4>()
+            }
+            StaticFrozenAnalogInputVariation::Group31Var5 => {
+                fixed_type::<FrozenAnalogInput, Group31Var5>()
+            }
+            StaticFrozenAnalogInputVariation::Group31Var6 => {
+                fixed_type::<FrozenAnalogInput, Group31Var6>()
+            }
+            StaticFrozenAnalogInputVariation::Group31Var7 => {
+                fixed_type::<FrozenAnalogInput, Group31Var7>()
+            }
+            StaticFrozenAnalogInputVariation::Group31Var8 => {
+                fixed_type::<FrozenAnalogInput, Group31Var8>()
+            }
+        }
+    }
+}
+
 impl StaticVariation<AnalogOutputStatus> for StaticAnalogOutputStatusVariation {
     fn get_write_info(&self, _value: &AnalogOutputStatus) -> WriteInfo<AnalogOutputStatus> {
         match self {
@@ -197,6 +228,74 @@ impl StaticVariation<AnalogOutputStatus> for StaticAnalogOutputStatusVariation {
     }
 }
 
+impl StaticVariation<UnsignedInteger> for StaticUnsignedIntegerVariation {
+    fn get_write_info(&self, _value: &UnsignedInteger) -> WriteInfo<UnsignedInteger> {
+        match self {
+            StaticUnsignedIntegerVariation::Group102Var1 => {
+                fixed_type::<UnsignedInteger, Group102Var1>()
+            }
+        }
+    }
+}
+
+impl StaticVariation<Bcd> for StaticBcdVariation {
+    fn get_write_info(&self, _value: &Bcd) -> WriteInfo<Bcd> {
+        match self {
+            StaticBcdVariation::Group101Var1 => fixed_type::<Bcd, Group101Var1>(),
+            StaticBcdVariation::Group101Var2 => fixed_type::<Bcd, Group101Var2>(),
+            StaticBcdVariation::Group101Var3 => fixed_type::<Bcd, Group101Var3>(),
+        }
+    }
+}
+
+// float to integer casts saturate, so deadbands that don't fit are reported as the maximum value
+impl ToVariation<Group34Var1> for f64 {
+    fn to_variation(&self) -> Group34Var1 {
+        Group34Var1 {
+            value: *self as u16,
+        }
+    }
+}
+
+impl ToVariation<Group34Var2> for f64 {
+    fn to_variation(&self) -> Group34Var2 {
+        Group34Var2 {
+            value: *self as u32,
+        }
+    }
+}
+
+impl ToVariation<Group34Var3> for f64 {
+    fn to_variation(&self) -> Group34Var3 {
+        Group34Var3 {
+            value: *self as f32,
+        }
+    }
+}
+
+impl AnalogInputDeadbandVariation {
+    /// when no specific variation is requested, deadbands are reported
+    /// using the same representation as the static value of the point
+    pub(crate) fn default_for(s_var: StaticAnalogInputVariation) -> Self {
+        match s_var {
+            StaticAnalogInputVariation::Group30Var2 => Self::Group34Var1,
+            StaticAnalogInputVariation::Group30Var4 => Self::Group34Var1,
+            StaticAnalogInputVariation::Group30Var1 => Self::Group34Var2,
+            StaticAnalogInputVariation::Group30Var3 => Self::Group34Var2,
+            StaticAnalogInputVariation::Group30Var5 => Self::Group34Var3,
+            StaticAnalogInputVariation::Group30Var6 => Self::Group34Var3,
+        }
+    }
+
+    pub(crate) fn get_write_info(&self) -> WriteInfo<f64> {
+        match self {
+            Self::Group34Var1 => fixed_type::<f64, Group34Var1>(),
+            Self::Group34Var2 => fixed_type::<f64, Group34Var2>(),
+            Self::Group34Var3 => fixed_type::<f64, Group34Var3>(),
+        }
+    }
+}
+
 impl StaticVariation<OctetString> for StaticOctetStringVariation {
     fn get_write_info(&self, value: &OctetString) -> WriteInfo<OctetString> {
         octet_string(value)
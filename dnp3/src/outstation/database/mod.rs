@@ -1,13 +1,21 @@
 use std::sync::{Arc, Mutex};
 
 pub use config::*;
-use details::range::static_db::{Deadband, FlagsDetector, OctetStringDetector, PointConfig};
+use details::event::buffer::VirtualTerminalBlock;
+use details::range::static_db::{
+    Deadband, FlagsDetector, IndexRange, NoEventDetector, OctetStringDetector, PointConfig,
+};
 
 use crate::app::measurement::*;
 use crate::app::parse::parser::HeaderCollection;
-use crate::app::Iin2;
+use crate::app::{
+    AttrDataType, AttrProp, Attribute, DataSet, DataSetDescriptor, DataSetPrototype, Iin2,
+    OwnedAttribute,
+};
 use crate::master::EventClasses;
 use crate::outstation::database::read::ReadHeader;
+use crate::outstation::{ClassAssignment, PointRange};
+use crate::util::virtual_terminal::MAX_BLOCK_SIZE;
 
 use scursor::WriteCursor;
 
@@ -39,6 +47,29 @@ pub enum EventClass {
     Class3,
 }
 
+/// Types of static points stored in the database
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PointType {
+    /// Binary Input (g1)
+    BinaryInput,
+    /// Double-bit Binary Input (g3)
+    DoubleBitBinaryInput,
+    /// Binary Output Status (g10)
+    BinaryOutputStatus,
+    /// Counter (g20)
+    Counter,
+    /// Frozen Counter (g21)
+    FrozenCounter,
+    /// Analog Input (g30)
+    AnalogInput,
+    /// Frozen Analog Input (g31)
+    FrozenAnalogInput,
+    /// Analog Output Status (g40)
+    AnalogOutputStatus,
+    /// Octet String (g110)
+    OctetString,
+}
+
 /// Controls which types are reported during a class 0 READ
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct ClassZeroConfig {
@@ -54,11 +85,17 @@ pub struct ClassZeroConfig {
     pub frozen_counter: bool,
     /// If true, Analog Inputs are reported in Class 0 READ requests
     pub analog: bool,
+    /// If true, Frozen Analog Inputs are reported in Class 0 READ requests
+    pub frozen_analog: bool,
     /// If true, Analog Output Status points are reported in Class 0 READ requests
     pub analog_output_status: bool,
     /// If true, Octet Strings are reported in Class 0 READ requests
     /// This field defaults to `false` for conformance to the standard
     pub octet_string: bool,
+    /// If true, Unsigned Integers are reported in Class 0 READ requests
+    pub unsigned_integer: bool,
+    /// If true, BCD Integers are reported in Class 0 READ requests
+    pub bcd: bool,
 }
 
 impl ClassZeroConfig {
@@ -71,8 +108,11 @@ impl ClassZeroConfig {
         counter: bool,
         frozen_counter: bool,
         analog: bool,
+        frozen_analog: bool,
         analog_output_status: bool,
         octet_string: bool,
+        unsigned_integer: bool,
+        bcd: bool,
     ) -> Self {
         ClassZeroConfig {
             binary,
@@ -81,8 +121,11 @@ impl ClassZeroConfig {
             counter,
             frozen_counter,
             analog,
+            frozen_analog,
             analog_output_status,
             octet_string,
+            unsigned_integer,
+            bcd,
         }
     }
 }
@@ -96,8 +139,11 @@ impl Default for ClassZeroConfig {
             counter: true,
             frozen_counter: true,
             analog: true,
+            frozen_analog: true,
             analog_output_status: true,
             octet_string: false,
+            unsigned_integer: true,
+            bcd: true,
         }
     }
 }
@@ -113,22 +159,34 @@ pub struct EventBufferConfig {
     pub max_double_binary: u16,
     /// maximum number of binary output status events (g11)
     pub max_binary_output_status: u16,
+    /// maximum number of binary output command events (g13)
+    pub max_binary_output_command: u16,
     /// maximum number of counter events (g22)
     pub max_counter: u16,
     /// maximum number of frozen counter events (g23)
     pub max_frozen_counter: u16,
     /// maximum number of analog events (g32)
     pub max_analog: u16,
+    /// maximum number of frozen analog events (g33)
+    pub max_frozen_analog: u16,
     /// maximum number of analog output status events (g42)
     pub max_analog_output_status: u16,
+    /// maximum number of analog output command events (g43)
+    pub max_analog_output_command: u16,
     /// maximum number of octet string events (g111)
     pub max_octet_string: u16,
+    /// maximum number of data set snapshot events (g88)
+    pub max_data_set: u16,
+    /// maximum number of virtual terminal output events (g113)
+    pub max_virtual_terminal: u16,
 }
 
 impl EventBufferConfig {
     /// initialize with the same maximum values for all types
     pub fn all_types(max: u16) -> Self {
-        Self::new(max, max, max, max, max, max, max, max)
+        Self::new(
+            max, max, max, max, max, max, max, max, max, max, max, max, max,
+        )
     }
 
     /// initialize the configuration to support no events
@@ -142,21 +200,31 @@ impl EventBufferConfig {
         max_binary: u16,
         max_double_binary: u16,
         max_binary_output_status: u16,
+        max_binary_output_command: u16,
         max_counter: u16,
         max_frozen_counter: u16,
         max_analog: u16,
+        max_frozen_analog: u16,
         max_analog_output_status: u16,
+        max_analog_output_command: u16,
         max_octet_string: u16,
+        max_data_set: u16,
+        max_virtual_terminal: u16,
     ) -> Self {
         Self {
             max_binary,
             max_double_binary,
             max_binary_output_status,
+            max_binary_output_command,
             max_counter,
             max_frozen_counter,
             max_analog,
+            max_frozen_analog,
             max_analog_output_status,
+            max_analog_output_command,
             max_octet_string,
+            max_data_set,
+            max_virtual_terminal,
         }
     }
 
@@ -164,11 +232,16 @@ impl EventBufferConfig {
         self.max_binary as usize
             + self.max_double_binary as usize
             + self.max_binary_output_status as usize
+            + self.max_binary_output_command as usize
             + self.max_counter as usize
             + self.max_frozen_counter as usize
             + self.max_analog as usize
+            + self.max_frozen_analog as usize
             + self.max_analog_output_status as usize
+            + self.max_analog_output_command as usize
             + self.max_octet_string as usize
+            + self.max_data_set as usize
+            + self.max_virtual_terminal as usize
     }
 }
 
@@ -268,6 +341,88 @@ pub trait Get<T> {
     fn get(&self, index: u16) -> Option<T>;
 }
 
+/// Errors that occur when defining a device attribute in the database
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AttrDefError {
+    /// variations 254 and 255 are reserved for requests and the attribute list
+    ReservedVariation(u8),
+    /// the attribute list (variation 255) is generated by the outstation and may not be defined
+    AttrListNotAllowed,
+    /// the encoded value exceeds the 255 byte maximum of an attribute
+    ValueTooLong(usize),
+}
+
+impl std::fmt::Display for AttrDefError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AttrDefError::ReservedVariation(x) => {
+                write!(f, "attribute variation {} is reserved", x)
+            }
+            AttrDefError::AttrListNotAllowed => {
+                f.write_str("attribute lists are generated by the outstation")
+            }
+            AttrDefError::ValueTooLong(x) => {
+                write!(f, "attribute value length of {} exceeds 255 bytes", x)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AttrDefError {}
+
+/// Errors that occur when defining or updating a data set in the database
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DataSetDefError {
+    /// another prototype with a different identifier already uses the UUID
+    DuplicateUuid(u16),
+    /// prototypes may not reference other prototypes
+    NestedPrototype,
+    /// the descriptor references a prototype UUID that has not been defined
+    UnknownPrototype,
+    /// the ancillary value of an element exceeds the 252 byte maximum
+    ElementTooLong(usize),
+    /// no data set with this identifier has been defined
+    UnknownDataSet(u16),
+    /// the descriptor defines a different number of values (expected, actual)
+    CountMismatch(usize, usize),
+    /// the value at this position has a different type than its element or exceeds its maximum length
+    TypeMismatch(usize),
+}
+
+impl std::fmt::Display for DataSetDefError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DataSetDefError::DuplicateUuid(x) => {
+                write!(f, "UUID is already used by prototype {}", x)
+            }
+            DataSetDefError::NestedPrototype => {
+                f.write_str("prototypes may not reference other prototypes")
+            }
+            DataSetDefError::UnknownPrototype => {
+                f.write_str("descriptor references an undefined prototype")
+            }
+            DataSetDefError::ElementTooLong(x) => {
+                write!(f, "element ancillary length of {} exceeds 252 bytes", x)
+            }
+            DataSetDefError::UnknownDataSet(x) => write!(f, "data set {} is not defined", x),
+            DataSetDefError::CountMismatch(expected, actual) => write!(
+                f,
+                "data set defines {} values, but {} were provided",
+                expected, actual
+            ),
+            DataSetDefError::TypeMismatch(x) => {
+                write!(
+                    f,
+                    "data set value at position {} does not match its element",
+                    x
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for DataSetDefError {}
+
 /// Core database implementation shared between an outstation task and the user facing API.
 /// This type is always guarded by a `DatabaseHandle` which provides a transactional API.
 pub struct Database {
@@ -289,6 +444,185 @@ impl Database {
             ),
         }
     }
+
+    /// Define a device attribute (g0) that the master may READ, replacing any existing
+    /// attribute with the same set and variation
+    ///
+    /// If the properties mark the attribute as writable, the master may WRITE a value of the
+    /// same data type which is then passed to [OutstationApplication::write_device_attr].
+    ///
+    /// [OutstationApplication::write_device_attr]: crate::outstation::OutstationApplication::write_device_attr
+    pub fn define_attr(
+        &mut self,
+        prop: AttrProp,
+        attr: OwnedAttribute,
+    ) -> Result<(), AttrDefError> {
+        if attr.variation >= 254 {
+            return Err(AttrDefError::ReservedVariation(attr.variation));
+        }
+        if attr.value.data_type() == AttrDataType::AttrList {
+            return Err(AttrDefError::AttrListNotAllowed);
+        }
+        let len = attr.value.encoded_len();
+        if len > u8::MAX as usize {
+            return Err(AttrDefError::ValueTooLong(len));
+        }
+        self.inner.attrs_mut().define(prop, attr);
+        Ok(())
+    }
+
+    /// Retrieve the current value of a device attribute
+    pub fn get_attr(&self, set: u8, variation: u8) -> Option<OwnedAttribute> {
+        self.inner.attrs().get(set, variation)
+    }
+
+    /// Remove a device attribute, returning true if it existed
+    pub fn remove_attr(&mut self, set: u8, variation: u8) -> bool {
+        self.inner.attrs_mut().remove(set, variation)
+    }
+
+    /// Define a data set prototype (g85) that the master may READ, replacing any existing
+    /// prototype with the same identifier
+    ///
+    /// Descriptors reference prototypes by UUID, so a prototype must be defined before any data
+    /// set that includes it.
+    pub fn define_data_set_prototype(
+        &mut self,
+        prototype: DataSetPrototype,
+    ) -> Result<(), DataSetDefError> {
+        self.inner.data_sets_mut().define_prototype(prototype)
+    }
+
+    /// Define a data set (g86) that the master may READ, replacing any existing data set with
+    /// the same identifier along with its present value
+    ///
+    /// If a class is specified, updates to the data set produce snapshot events (g88).
+    pub fn define_data_set(
+        &mut self,
+        descriptor: DataSetDescriptor,
+        class: Option<EventClass>,
+    ) -> Result<(), DataSetDefError> {
+        self.inner.data_sets_mut().define(descriptor, class)
+    }
+
+    /// Update the present value (g87) of a data set, possibly producing a snapshot event (g88)
+    ///
+    /// The values must match the elements of the descriptor, with any prototypes expanded in place.
+    /// With the default options, an event is produced if any of the values or the time have changed.
+    pub fn update_data_set(
+        &mut self,
+        value: &DataSet,
+        options: UpdateOptions,
+    ) -> Result<(), DataSetDefError> {
+        self.inner.update_data_set(value, options)
+    }
+
+    /// Retrieve the present value of a data set, or None if it is not defined or has never been updated
+    pub fn get_data_set(&self, id: u16) -> Option<DataSet> {
+        self.inner.data_sets().get(id)
+    }
+
+    /// Remove a data set, returning true if it existed
+    pub fn remove_data_set(&mut self, id: u16) -> bool {
+        self.inner.data_sets_mut().remove(id)
+    }
+
+    /// Retrieve the deadband of an analog input, or None if the point does not exist
+    pub fn get_analog_input_deadband(&self, index: u16) -> Option<f64> {
+        self.inner.get_analog_deadband(index)
+    }
+
+    /// Change the deadband of an analog input that was configured when the point was added
+    ///
+    /// The new deadband is used for all subsequent event detection. It is also the value
+    /// reported when the master reads the deadband (g34). Returns false if the point does not exist.
+    pub fn set_analog_input_deadband(&mut self, index: u16, deadband: f64) -> bool {
+        self.inner.set_analog_deadband(index, deadband)
+    }
+
+    /// Change the event class of a point that was configured when the point was added
+    ///
+    /// Setting class to None means that the point no longer produces events. Events that were
+    /// already buffered are reported normally. Returns false if the point does not exist.
+    pub fn set_class(
+        &mut self,
+        point_type: PointType,
+        index: u16,
+        class: Option<EventClass>,
+    ) -> bool {
+        self.inner
+            .set_class(point_type, Some(IndexRange::new(index, index)), class)
+    }
+
+    /// Record a binary output command event (g13)
+    ///
+    /// Command events have no static value and are placed directly into the event buffer.
+    /// Returns false if the buffer is not configured to hold this type of event.
+    pub fn record_binary_output_command_event(
+        &mut self,
+        index: u16,
+        class: EventClass,
+        variation: EventBinaryOutputCommandVariation,
+        event: BinaryOutputCommandEvent,
+    ) -> bool {
+        self.inner.insert_event(&event, index, class, variation)
+    }
+
+    /// Record an analog output command event (g43)
+    ///
+    /// Command events have no static value and are placed directly into the event buffer.
+    /// Returns false if the buffer is not configured to hold this type of event.
+    pub fn record_analog_output_command_event(
+        &mut self,
+        index: u16,
+        class: EventClass,
+        variation: EventAnalogOutputCommandVariation,
+        event: AnalogOutputCommandEvent,
+    ) -> bool {
+        self.inner.insert_event(&event, index, class, variation)
+    }
+
+    /// Record virtual terminal output (g113) for a port
+    ///
+    /// The output is split into events of at most 255 bytes.
+    /// Returns false if the buffer is not configured to hold this type of event.
+    pub(crate) fn record_virtual_terminal_output(
+        &mut self,
+        port: u16,
+        class: EventClass,
+        data: &[u8],
+    ) -> bool {
+        data.chunks(MAX_BLOCK_SIZE).all(|block| {
+            self.inner.insert_event(
+                &VirtualTerminalBlock(block.into()),
+                port,
+                class,
+                EventVirtualTerminalVariation(block.len() as u8),
+            )
+        })
+    }
+
+    /// true if every point selected by the assignment exists
+    pub(crate) fn can_assign_class(&self, assignment: &ClassAssignment) -> bool {
+        self.inner
+            .contains_range(assignment.point_type, to_index_range(assignment.range))
+    }
+
+    pub(crate) fn assign_class(&mut self, assignment: ClassAssignment) -> bool {
+        self.inner.set_class(
+            assignment.point_type,
+            to_index_range(assignment.range),
+            assignment.class,
+        )
+    }
+
+    pub(crate) fn check_attr_write(&self, attr: &Attribute) -> Iin2 {
+        self.inner.attrs().check_write(attr)
+    }
+
+    pub(crate) fn write_attr(&mut self, attr: &Attribute) {
+        self.inner.attrs_mut().write_value(attr)
+    }
 }
 
 /// Handle type that can be used to perform transactions on an underlying database
@@ -421,6 +755,12 @@ impl Update<AnalogInput> for Database {
     }
 }
 
+impl Update<FrozenAnalogInput> for Database {
+    fn update(&mut self, index: u16, value: &FrozenAnalogInput, options: UpdateOptions) -> bool {
+        self.inner.update(value, index, options)
+    }
+}
+
 impl Update<AnalogOutputStatus> for Database {
     fn update(&mut self, index: u16, value: &AnalogOutputStatus, options: UpdateOptions) -> bool {
         self.inner.update(value, index, options)
@@ -433,6 +773,18 @@ impl Update<OctetString> for Database {
     }
 }
 
+impl Update<UnsignedInteger> for Database {
+    fn update(&mut self, index: u16, value: &UnsignedInteger, options: UpdateOptions) -> bool {
+        self.inner.update(value, index, options)
+    }
+}
+
+impl Update<Bcd> for Database {
+    fn update(&mut self, index: u16, value: &Bcd, options: UpdateOptions) -> bool {
+        self.inner.update(value, index, options)
+    }
+}
+
 impl Add<BinaryInputConfig> for Database {
     fn add(&mut self, index: u16, class: Option<EventClass>, config: BinaryInputConfig) -> bool {
         let config =
@@ -511,6 +863,23 @@ impl Add<AnalogInputConfig> for Database {
     }
 }
 
+impl Add<FrozenAnalogInputConfig> for Database {
+    fn add(
+        &mut self,
+        index: u16,
+        class: Option<EventClass>,
+        config: FrozenAnalogInputConfig,
+    ) -> bool {
+        let config = PointConfig::<FrozenAnalogInput>::new(
+            class,
+            Deadband::new(config.deadband),
+            config.s_var,
+            config.e_var,
+        );
+        self.inner.add(index, config)
+    }
+}
+
 impl Add<AnalogOutputStatusConfig> for Database {
     fn add(
         &mut self,
@@ -540,6 +909,25 @@ impl Add<OctetStringConfig> for Database {
     }
 }
 
+impl Add<UnsignedIntegerConfig> for Database {
+    fn add(
+        &mut self,
+        index: u16,
+        _class: Option<EventClass>,
+        config: UnsignedIntegerConfig,
+    ) -> bool {
+        let config = PointConfig::<UnsignedInteger>::new(None, NoEventDetector, config.s_var, ());
+        self.inner.add(index, config)
+    }
+}
+
+impl Add<BcdConfig> for Database {
+    fn add(&mut self, index: u16, _class: Option<EventClass>, config: BcdConfig) -> bool {
+        let config = PointConfig::<Bcd>::new(None, NoEventDetector, config.s_var, ());
+        self.inner.add(index, config)
+    }
+}
+
 impl Remove<BinaryInput> for Database {
     fn remove(&mut self, index: u16) -> bool {
         self.inner.remove::<BinaryInput>(index)
@@ -576,6 +964,12 @@ impl Remove<AnalogInput> for Database {
     }
 }
 
+impl Remove<FrozenAnalogInput> for Database {
+    fn remove(&mut self, index: u16) -> bool {
+        self.inner.remove::<FrozenAnalogInput>(index)
+    }
+}
+
 impl Remove<AnalogOutputStatus> for Database {
     fn remove(&mut self, index: u16) -> bool {
         self.inner.remove::<AnalogOutputStatus>(index)
@@ -588,6 +982,18 @@ impl Remove<OctetString> for Database {
     }
 }
 
+impl Remove<UnsignedInteger> for Database {
+    fn remove(&mut self, index: u16) -> bool {
+        self.inner.remove::<UnsignedInteger>(index)
+    }
+}
+
+impl Remove<Bcd> for Database {
+    fn remove(&mut self, index: u16) -> bool {
+        self.inner.remove::<Bcd>(index)
+    }
+}
+
 impl Get<BinaryInput> for Database {
     fn get(&self, index: u16) -> Option<BinaryInput> {
         self.inner.get::<BinaryInput>(index)
@@ -624,6 +1030,12 @@ impl Get<AnalogInput> for Database {
     }
 }
 
+impl Get<FrozenAnalogInput> for Database {
+    fn get(&self, index: u16) -> Option<FrozenAnalogInput> {
+        self.inner.get::<FrozenAnalogInput>(index)
+    }
+}
+
 impl Get<AnalogOutputStatus> for Database {
     fn get(&self, index: u16) -> Option<AnalogOutputStatus> {
         self.inner.get::<AnalogOutputStatus>(index)
@@ -635,3 +1047,22 @@ impl Get<OctetString> for Database {
         self.inner.get::<OctetString>(index)
     }
 }
+
+impl Get<UnsignedInteger> for Database {
+    fn get(&self, index: u16) -> Option<UnsignedInteger> {
+        self.inner.get::<UnsignedInteger>(index)
+    }
+}
+
+impl Get<Bcd> for Database {
+    fn get(&self, index: u16) -> Option<Bcd> {
+        self.inner.get::<Bcd>(index)
+    }
+}
+
+fn to_index_range(range: PointRange) -> Option<IndexRange> {
+    match range {
+        PointRange::All => None,
+        PointRange::Range(start, stop) => Some(IndexRange::new(start, stop)),
+    }
+}
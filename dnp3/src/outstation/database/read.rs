@@ -20,11 +20,21 @@ pub(crate) enum StaticReadHeader {
     Counter(Option<StaticCounterVariation>, Option<IndexRange>),
     FrozenCounter(Option<StaticFrozenCounterVariation>, Option<IndexRange>),
     Analog(Option<StaticAnalogInputVariation>, Option<IndexRange>),
+    FrozenAnalog(Option<StaticFrozenAnalogInputVariation>, Option<IndexRange>),
     AnalogOutputStatus(
         Option<StaticAnalogOutputStatusVariation>,
         Option<IndexRange>,
     ),
     OctetString(Option<IndexRange>),
+    UnsignedInteger(Option<StaticUnsignedIntegerVariation>, Option<IndexRange>),
+    Bcd(Option<StaticBcdVariation>, Option<IndexRange>),
+    AnalogInputDeadband(Option<AnalogInputDeadbandVariation>, Option<IndexRange>),
+    // device attribute set and variation, or None for all attributes in the set
+    DeviceAttribute(u8, Option<u8>),
+    // data sets are only read in their entirety
+    DataSetPrototype,
+    DataSetDescriptor,
+    DataSetPresentValue,
 }
 
 #[derive(Copy, Clone)]
@@ -37,11 +47,16 @@ pub(crate) enum EventReadHeader {
     Binary(Option<EventBinaryInputVariation>, Option<usize>),
     DoubleBitBinary(Option<EventDoubleBitBinaryInputVariation>, Option<usize>),
     BinaryOutputStatus(Option<EventBinaryOutputStatusVariation>, Option<usize>),
+    BinaryOutputCommand(Option<EventBinaryOutputCommandVariation>, Option<usize>),
     Counter(Option<EventCounterVariation>, Option<usize>),
     FrozenCounter(Option<EventFrozenCounterVariation>, Option<usize>),
     Analog(Option<EventAnalogInputVariation>, Option<usize>),
+    FrozenAnalog(Option<EventFrozenAnalogInputVariation>, Option<usize>),
     AnalogOutputStatus(Option<EventAnalogOutputStatusVariation>, Option<usize>),
+    AnalogOutputCommand(Option<EventAnalogOutputCommandVariation>, Option<usize>),
     OctetString(Option<usize>),
+    DataSet(Option<usize>),
+    VirtualTerminal(Option<usize>),
 }
 
 /// Enum representation of all header types that can be in a READ request
@@ -91,11 +106,19 @@ impl ReadHeader {
             }
             HeaderDetails::OneByteCountAndPrefix(_, _) => None,
             HeaderDetails::TwoByteCountAndPrefix(_, _) => None,
+            HeaderDetails::TwoByteFreeFormat(_, _) => None,
         }
     }
 
     fn from_all_objects(header: &AllObjectsVariation) -> Option<ReadHeader> {
         match header {
+            // group 0 - the qualifier does not specify a set, so the default set is used
+            AllObjectsVariation::Group0Var254 => {
+                Some(StaticReadHeader::DeviceAttribute(0, None).into())
+            }
+            AllObjectsVariation::Group0(var) => {
+                Some(StaticReadHeader::DeviceAttribute(0, Some(*var)).into())
+            }
             // group 1
             AllObjectsVariation::Group1Var0 => Some(StaticReadHeader::Binary(None, None).into()),
             AllObjectsVariation::Group1Var1 => Some(
@@ -194,6 +217,23 @@ impl ReadHeader {
                 )
                 .into(),
             ),
+            AllObjectsVariation::Group13Var0 => {
+                Some(EventReadHeader::BinaryOutputCommand(None, None).into())
+            }
+            AllObjectsVariation::Group13Var1 => Some(
+                EventReadHeader::BinaryOutputCommand(
+                    Some(EventBinaryOutputCommandVariation::Group13Var1),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group13Var2 => Some(
+                EventReadHeader::BinaryOutputCommand(
+                    Some(EventBinaryOutputCommandVariation::Group13Var2),
+                    None,
+                )
+                .into(),
+            ),
             // group 20
             AllObjectsVariation::Group20Var0 => Some(StaticReadHeader::Counter(None, None).into()),
             AllObjectsVariation::Group20Var1 => Some(
@@ -326,6 +366,66 @@ impl ReadHeader {
                 StaticReadHeader::Analog(Some(StaticAnalogInputVariation::Group30Var6), None)
                     .into(),
             ),
+            // group 31
+            AllObjectsVariation::Group31Var0 => {
+                Some(StaticReadHeader::FrozenAnalog(None, None).into())
+            }
+            AllObjectsVariation::Group31Var1 => Some(
+                StaticReadHeader::FrozenAnalog(
+                    Some(StaticFrozenAnalogInputVariation::Group31Var1),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group31Var2 => Some(
+                StaticReadHeader::FrozenAnalog(
+                    Some(StaticFrozenAnalogInputVariation::Group31Var2),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group31Var3 => Some(
+                StaticReadHeader::FrozenAnalog(
+                    Some(StaticFrozenAnalogInputVariation::Group31Var3),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group31Var4 => Some(
+                StaticReadHeader::FrozenAnalog(
+                    Some(StaticFrozenAnalogInputVariation::Group31Var4),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group31Var5 => Some(
+                StaticReadHeader::FrozenAnalog(
+                    Some(StaticFrozenAnalogInputVariation::Group31Var5),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group31Var6 => Some(
+                StaticReadHeader::FrozenAnalog(
+                    Some(StaticFrozenAnalogInputVariation::Group31Var6),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group31Var7 => Some(
+                StaticReadHeader::FrozenAnalog(
+                    Some(StaticFrozenAnalogInputVariation::Group31Var7),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group31Var8 => Some(
+                StaticReadHeader::FrozenAnalog(
+                    Some(StaticFrozenAnalogInputVariation::Group31Var8),
+                    None,
+                )
+                .into(),
+            ),
             // group 32
             AllObjectsVariation::Group32Var0 => Some(EventReadHeader::Analog(None, None).into()),
             AllObjectsVariation::Group32Var1 => Some(
@@ -352,6 +452,91 @@ impl ReadHeader {
             AllObjectsVariation::Group32Var8 => Some(
                 EventReadHeader::Analog(Some(EventAnalogInputVariation::Group32Var8), None).into(),
             ),
+            // group 33
+            AllObjectsVariation::Group33Var0 => {
+                Some(EventReadHeader::FrozenAnalog(None, None).into())
+            }
+            AllObjectsVariation::Group33Var1 => Some(
+                EventReadHeader::FrozenAnalog(
+                    Some(EventFrozenAnalogInputVariation::Group33Var1),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group33Var2 => Some(
+                EventReadHeader::FrozenAnalog(
+                    Some(EventFrozenAnalogInputVariation::Group33Var2),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group33Var3 => Some(
+                EventReadHeader::FrozenAnalog(
+                    Some(EventFrozenAnalogInputVariation::Group33Var3),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group33Var4 => Some(
+                EventReadHeader::FrozenAnalog(
+                    Some(EventFrozenAnalogInputVariation::Group33Var4),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group33Var5 => Some(
+                EventReadHeader::FrozenAnalog(
+                    Some(EventFrozenAnalogInputVariation::Group33Var5),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group33Var6 => Some(
+                EventReadHeader::FrozenAnalog(
+                    Some(EventFrozenAnalogInputVariation::Group33Var6),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group33Var7 => Some(
+                EventReadHeader::FrozenAnalog(
+                    Some(EventFrozenAnalogInputVariation::Group33Var7),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group33Var8 => Some(
+                EventReadHeader::FrozenAnalog(
+                    Some(EventFrozenAnalogInputVariation::Group33Var8),
+                    None,
+                )
+                .into(),
+            ),
+            // group 34
+            AllObjectsVariation::Group34Var0 => {
+                Some(StaticReadHeader::AnalogInputDeadband(None, None).into())
+            }
+            AllObjectsVariation::Group34Var1 => Some(
+                StaticReadHeader::AnalogInputDeadband(
+                    Some(AnalogInputDeadbandVariation::Group34Var1),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group34Var2 => Some(
+                StaticReadHeader::AnalogInputDeadband(
+                    Some(AnalogInputDeadbandVariation::Group34Var2),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group34Var3 => Some(
+                StaticReadHeader::AnalogInputDeadband(
+                    Some(AnalogInputDeadbandVariation::Group34Var3),
+                    None,
+                )
+                .into(),
+            ),
             // group 40
             AllObjectsVariation::Group40Var0 => {
                 Some(StaticReadHeader::AnalogOutputStatus(None, None).into())
@@ -444,6 +629,65 @@ impl ReadHeader {
                 )
                 .into(),
             ),
+            AllObjectsVariation::Group43Var0 => {
+                Some(EventReadHeader::AnalogOutputCommand(None, None).into())
+            }
+            AllObjectsVariation::Group43Var1 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var1),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group43Var2 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var2),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group43Var3 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var3),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group43Var4 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var4),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group43Var5 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var5),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group43Var6 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var6),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group43Var7 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var7),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group43Var8 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var8),
+                    None,
+                )
+                .into(),
+            ),
             // group 60
             AllObjectsVariation::Group60Var1 => Some(StaticReadHeader::Class0.into()),
             AllObjectsVariation::Group60Var2 => Some(EventReadHeader::Class1(None).into()),
@@ -451,10 +695,41 @@ impl ReadHeader {
             AllObjectsVariation::Group60Var4 => Some(EventReadHeader::Class3(None).into()),
             // group 80
             AllObjectsVariation::Group80Var1 => None,
+            // groups 85 - 88
+            AllObjectsVariation::Group85Var1 => Some(StaticReadHeader::DataSetPrototype.into()),
+            AllObjectsVariation::Group86Var1 => Some(StaticReadHeader::DataSetDescriptor.into()),
+            AllObjectsVariation::Group87Var1 => Some(StaticReadHeader::DataSetPresentValue.into()),
+            AllObjectsVariation::Group88Var1 => Some(EventReadHeader::DataSet(None).into()),
+            AllObjectsVariation::Group90Var1 => None,
+            // group 101
+            AllObjectsVariation::Group101Var0 => Some(StaticReadHeader::Bcd(None, None).into()),
+            AllObjectsVariation::Group101Var1 => {
+                Some(StaticReadHeader::Bcd(Some(StaticBcdVariation::Group101Var1), None).into())
+            }
+            AllObjectsVariation::Group101Var2 => {
+                Some(StaticReadHeader::Bcd(Some(StaticBcdVariation::Group101Var2), None).into())
+            }
+            AllObjectsVariation::Group101Var3 => {
+                Some(StaticReadHeader::Bcd(Some(StaticBcdVariation::Group101Var3), None).into())
+            }
+            // group 102
+            AllObjectsVariation::Group102Var0 => {
+                Some(StaticReadHeader::UnsignedInteger(None, None).into())
+            }
+            AllObjectsVariation::Group102Var1 => Some(
+                StaticReadHeader::UnsignedInteger(
+                    Some(StaticUnsignedIntegerVariation::Group102Var1),
+                    None,
+                )
+                .into(),
+            ),
             // group 110
             AllObjectsVariation::Group110Var0 => Some(StaticReadHeader::OctetString(None).into()),
             // group 111
             AllObjectsVariation::Group111Var0 => Some(EventReadHeader::OctetString(None).into()),
+            AllObjectsVariation::Group113Var0 => {
+                Some(EventReadHeader::VirtualTerminal(None).into())
+            }
         }
     }
 
@@ -514,6 +789,24 @@ impl ReadHeader {
                 )
                 .into(),
             ),
+            CountVariation::Group13Var0 => {
+                Some(EventReadHeader::BinaryOutputCommand(None, Some(count)).into())
+            }
+            CountVariation::Group13Var1 => Some(
+                EventReadHeader::BinaryOutputCommand(
+                    Some(EventBinaryOutputCommandVariation::Group13Var1),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group13Var2 => Some(
+                EventReadHeader::BinaryOutputCommand(
+                    Some(EventBinaryOutputCommandVariation::Group13Var2),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group20Var0 => None,
             CountVariation::Group22Var0 => Some(EventReadHeader::Counter(None, Some(count)).into()),
             CountVariation::Group22Var1 => Some(
                 EventReadHeader::Counter(Some(EventCounterVariation::Group22Var1), Some(count))
@@ -595,6 +888,65 @@ impl ReadHeader {
                 EventReadHeader::Analog(Some(EventAnalogInputVariation::Group32Var8), Some(count))
                     .into(),
             ),
+            CountVariation::Group33Var0 => {
+                Some(EventReadHeader::FrozenAnalog(None, Some(count)).into())
+            }
+            CountVariation::Group33Var1 => Some(
+                EventReadHeader::FrozenAnalog(
+                    Some(EventFrozenAnalogInputVariation::Group33Var1),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group33Var2 => Some(
+                EventReadHeader::FrozenAnalog(
+                    Some(EventFrozenAnalogInputVariation::Group33Var2),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group33Var3 => Some(
+                EventReadHeader::FrozenAnalog(
+                    Some(EventFrozenAnalogInputVariation::Group33Var3),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group33Var4 => Some(
+                EventReadHeader::FrozenAnalog(
+                    Some(EventFrozenAnalogInputVariation::Group33Var4),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group33Var5 => Some(
+                EventReadHeader::FrozenAnalog(
+                    Some(EventFrozenAnalogInputVariation::Group33Var5),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group33Var6 => Some(
+                EventReadHeader::FrozenAnalog(
+                    Some(EventFrozenAnalogInputVariation::Group33Var6),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group33Var7 => Some(
+                EventReadHeader::FrozenAnalog(
+                    Some(EventFrozenAnalogInputVariation::Group33Var7),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group33Var8 => Some(
+                EventReadHeader::FrozenAnalog(
+                    Some(EventFrozenAnalogInputVariation::Group33Var8),
+                    Some(count),
+                )
+                .into(),
+            ),
             CountVariation::Group42Var0 => {
                 Some(EventReadHeader::AnalogOutputStatus(None, Some(count)).into())
             }
@@ -654,7 +1006,68 @@ impl ReadHeader {
                 )
                 .into(),
             ),
+            CountVariation::Group43Var0 => {
+                Some(EventReadHeader::AnalogOutputCommand(None, Some(count)).into())
+            }
+            CountVariation::Group43Var1 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var1),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group43Var2 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var2),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group43Var3 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var3),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group43Var4 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var4),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group43Var5 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var5),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group43Var6 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var6),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group43Var7 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var7),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group43Var8 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var8),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group12Var2(_) => None,
             CountVariation::Group50Var1(_) => None,
+            CountVariation::Group50Var2(_) => None,
             CountVariation::Group50Var3(_) => None,
             CountVariation::Group50Var4(_) => None,
             CountVariation::Group51Var1(_) => None,
@@ -664,13 +1077,31 @@ impl ReadHeader {
             CountVariation::Group60Var2 => Some(EventReadHeader::Class1(Some(count)).into()),
             CountVariation::Group60Var3 => Some(EventReadHeader::Class2(Some(count)).into()),
             CountVariation::Group60Var4 => Some(EventReadHeader::Class3(Some(count)).into()),
+            CountVariation::Group88Var1 => Some(EventReadHeader::DataSet(Some(count)).into()),
             CountVariation::Group111Var0 => Some(EventReadHeader::OctetString(Some(count)).into()),
             CountVariation::Group111VarX(_) => None,
+            CountVariation::Group113Var0 => {
+                Some(EventReadHeader::VirtualTerminal(Some(count)).into())
+            }
+            CountVariation::Group113VarX(_) => None,
+            CountVariation::Group120Var3(_) => None,
+            CountVariation::Group120Var4(_) => None,
         }
     }
 
+    /// device attributes are requested one set at a time using start == stop == set
+    fn attr_set(range: IndexRange) -> Option<u8> {
+        range.single().and_then(|set| u8::try_from(set).ok())
+    }
+
     fn from_range(header: &RangedVariation, range: IndexRange) -> Option<ReadHeader> {
         match header {
+            // group 0
+            RangedVariation::Group0Var254 => {
+                Self::attr_set(range).map(|set| StaticReadHeader::DeviceAttribute(set, None).into())
+            }
+            RangedVariation::Group0(var, _) => Self::attr_set(range)
+                .map(|set| StaticReadHeader::DeviceAttribute(set, Some(*var)).into()),
             // group 1
             RangedVariation::Group1Var0 => Some(StaticReadHeader::Binary(None, Some(range)).into()),
             RangedVariation::Group1Var1(_) => Some(
@@ -717,6 +1148,7 @@ impl ReadHeader {
                 )
                 .into(),
             ),
+            RangedVariation::Group12Var3(_) => None,
             // group 20
             RangedVariation::Group20Var0 => {
                 Some(StaticReadHeader::Counter(None, Some(range)).into())
@@ -829,6 +1261,91 @@ impl ReadHeader {
                 )
                 .into(),
             ),
+            // group 31
+            RangedVariation::Group31Var0 => {
+                Some(StaticReadHeader::FrozenAnalog(None, Some(range)).into())
+            }
+            RangedVariation::Group31Var1(_) => Some(
+                StaticReadHeader::FrozenAnalog(
+                    Some(StaticFrozenAnalogInputVariation::Group31Var1),
+                    Some(range),
+                )
+                .into(),
+            ),
+            RangedVariation::Group31Var2(_) => Some(
+                StaticReadHeader::FrozenAnalog(
+                    Some(StaticFrozenAnalogInputVariation::Group31Var2),
+                    Some(range),
+                )
+                .into(),
+            ),
+            RangedVariation::Group31Var3(_) => Some(
+                StaticReadHeader::FrozenAnalog(
+                    Some(StaticFrozenAnalogInputVariation::Group31Var3),
+                    Some(range),
+                )
+                .into(),
+            ),
+            RangedVariation::Group31Var4(_) => Some(
+                StaticReadHeader::FrozenAnalog(
+                    Some(StaticFrozenAnalogInputVariation::Group31Var4),
+                    Some(range),
+                )
+                .into(),
+            ),
+            RangedVariation::Group31Var5(_) => Some(
+                StaticReadHeader::FrozenAnalog(
+                    Some(StaticFrozenAnalogInputVariation::Group31Var5),
+                    Some(range),
+                )
+                .into(),
+            ),
+            RangedVariation::Group31Var6(_) => Some(
+                StaticReadHeader::FrozenAnalog(
+                    Some(StaticFrozenAnalogInputVariation::Group31Var6),
+                    Some(range),
+                )
+                .into(),
+            ),
+            RangedVariation::Group31Var7(_) => Some(
+                StaticReadHeader::FrozenAnalog(
+                    Some(StaticFrozenAnalogInputVariation::Group31Var7),
+                    Some(range),
+                )
+                .into(),
+            ),
+            RangedVariation::Group31Var8(_) => Some(
+                StaticReadHeader::FrozenAnalog(
+                    Some(StaticFrozenAnalogInputVariation::Group31Var8),
+                    Some(range),
+                )
+                .into(),
+            ),
+            // group 34
+            RangedVariation::Group34Var0 => {
+                Some(StaticReadHeader::AnalogInputDeadband(None, Some(range)).into())
+            }
+            RangedVariation::Group34Var1(_) => Some(
+                StaticReadHeader::AnalogInputDeadband(
+                    Some(AnalogInputDeadbandVariation::Group34Var1),
+                    Some(range),
+                )
+                .into(),
+            ),
+            RangedVariation::Group34Var2(_) => Some(
+                StaticReadHeader::AnalogInputDeadband(
+                    Some(AnalogInputDeadbandVariation::Group34Var2),
+                    Some(range),
+                )
+                .into(),
+            ),
+            RangedVariation::Group34Var3(_) => Some(
+                StaticReadHeader::AnalogInputDeadband(
+                    Some(AnalogInputDeadbandVariation::Group34Var3),
+                    Some(range),
+                )
+                .into(),
+            ),
             // group 40
             RangedVariation::Group40Var0 => {
                 Some(StaticReadHeader::AnalogOutputStatus(None, Some(range)).into())
@@ -863,6 +1380,28 @@ impl ReadHeader {
             ),
             // group 80
             RangedVariation::Group80Var1(_) => None,
+            // group 101
+            RangedVariation::Group101Var0 => Some(StaticReadHeader::Bcd(None, Some(range)).into()),
+            RangedVariation::Group101Var1(_) => Some(
+                StaticReadHeader::Bcd(Some(StaticBcdVariation::Group101Var1), Some(range)).into(),
+            ),
+            RangedVariation::Group101Var2(_) => Some(
+                StaticReadHeader::Bcd(Some(StaticBcdVariation::Group101Var2), Some(range)).into(),
+            ),
+            RangedVariation::Group101Var3(_) => Some(
+                StaticReadHeader::Bcd(Some(StaticBcdVariation::Group101Var3), Some(range)).into(),
+            ),
+            // group 102
+            RangedVariation::Group102Var0 => {
+                Some(StaticReadHeader::UnsignedInteger(None, Some(range)).into())
+            }
+            RangedVariation::Group102Var1(_) => Some(
+                StaticReadHeader::UnsignedInteger(
+                    Some(StaticUnsignedIntegerVariation::Group102Var1),
+                    Some(range),
+                )
+                .into(),
+            ),
             // group 110
             RangedVariation::Group110Var0 => {
                 Some(StaticReadHeader::OctetString(Some(range)).into())
@@ -0,0 +1,104 @@
+use crate::app::{FileInfo, FileMode, FileStatus, Permissions, Timestamp};
+
+/// Parameters of an OPEN_FILE request (g70v3)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OpenFileRequest<'a> {
+    /// name of the file to open
+    pub file_name: &'a str,
+    /// mode in which the file is opened
+    pub mode: FileMode,
+    /// authentication key previously obtained by the master, or zero
+    pub auth_key: u32,
+    /// size of the file the master intends to write, zero when reading
+    pub file_size: u32,
+    /// permissions to assign to the file if it is created
+    pub permissions: Permissions,
+    /// maximum block size requested by the master
+    pub max_block_size: u16,
+    /// time of creation to assign to the file if it is created
+    pub time_of_creation: Timestamp,
+}
+
+/// Information returned by the [FileHandler] when a file is successfully opened
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OpenedFile {
+    /// handle used by the master to refer to the file in subsequent requests
+    pub handle: u32,
+    /// size of the file in bytes
+    pub file_size: u32,
+    /// maximum block size the outstation will use to read or accept when writing
+    ///
+    /// The outstation reduces this value if the blocks would not fit in its transmit buffer
+    pub max_block_size: u16,
+}
+
+/// Block of file data returned by the [FileHandler] when the master reads a file
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileBlock {
+    /// content of the block
+    pub data: Vec<u8>,
+    /// true if this is the last block of the file
+    pub last: bool,
+}
+
+/// File system operations performed on behalf of the master using the file-control objects (group 70)
+///
+/// Every method has a default implementation that rejects the operation. Block numbers are
+/// passed through from the master, so the handler is responsible for rejecting out of
+/// sequence blocks with [FileStatus::BlockSeq].
+pub trait FileHandler: Send + Sync + 'static {
+    /// Handle an AUTHENTICATE_FILE request
+    ///
+    /// Return a non-zero authentication key if the credentials are valid, or None to deny access
+    fn authenticate(&mut self, _user_name: &str, _password: &str) -> Option<u32> {
+        None
+    }
+
+    /// Handle an OPEN_FILE request
+    fn open_file(&mut self, _request: OpenFileRequest) -> Result<OpenedFile, FileStatus> {
+        Err(FileStatus::PermissionDenied)
+    }
+
+    /// Handle a CLOSE_FILE request
+    fn close_file(&mut self, _handle: u32) -> Result<(), FileStatus> {
+        Err(FileStatus::InvalidHandle)
+    }
+
+    /// Handle a DELETE_FILE request
+    fn delete_file(&mut self, _file_name: &str, _auth_key: u32) -> Result<(), FileStatus> {
+        Err(FileStatus::PermissionDenied)
+    }
+
+    /// Handle a GET_FILE_INFO request
+    fn get_file_info(&mut self, _file_name: &str) -> Result<FileInfo, FileStatus> {
+        Err(FileStatus::FileNotFound)
+    }
+
+    /// Handle an ABORT_FILE request
+    fn abort_file(&mut self, _handle: u32) -> Result<(), FileStatus> {
+        Err(FileStatus::CannotAbort)
+    }
+
+    /// Read a block of a file opened in [FileMode::Read]
+    ///
+    /// `max_size` is the largest block that fits in the response
+    fn read_file_block(
+        &mut self,
+        _handle: u32,
+        _block: u32,
+        _max_size: usize,
+    ) -> Result<FileBlock, FileStatus> {
+        Err(FileStatus::InvalidHandle)
+    }
+
+    /// Write a block of a file opened in [FileMode::Write] or [FileMode::Append]
+    fn write_file_block(
+        &mut self,
+        _handle: u32,
+        _block: u32,
+        _data: &[u8],
+        _last: bool,
+    ) -> Result<(), FileStatus> {
+        Err(FileStatus::InvalidHandle)
+    }
+}
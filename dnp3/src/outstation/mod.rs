@@ -1,11 +1,15 @@
 pub use config::*;
+pub use file::*;
 pub use traits::*;
+pub use virtual_terminal::*;
 
 use crate::app::Shutdown;
 use crate::decode::DecodeLevel;
-use crate::outstation::database::{Database, DatabaseHandle};
+use crate::outstation::database::{Database, DatabaseHandle, EventClass};
 use crate::outstation::task::{ConfigurationChange, OutstationMessage};
+use crate::sa::{AuthenticationInformation, OutstationAuthConfig, UserStore};
 use crate::util::channel::Sender;
+use crate::util::virtual_terminal::virtual_terminal_channel;
 
 /// configuration types
 
@@ -14,16 +18,21 @@ pub mod database;
 
 /// wraps an outstation task so that it can switch communication sessions
 pub(crate) mod adapter;
+/// secure authentication of critical requests
+pub(crate) mod auth;
 mod config;
 /// functionality for processing control requests
 pub(crate) mod control;
 /// handling of deferred read requests
 pub(crate) mod deferred;
+mod file;
 /// outstation session
 pub(crate) mod session;
 /// async outstation task
 pub(crate) mod task;
 mod traits;
+/// virtual terminal (groups 112/113) byte streams
+mod virtual_terminal;
 
 #[cfg(test)]
 mod tests;
@@ -57,6 +66,53 @@ impl OutstationHandle {
         Ok(())
     }
 
+    /// Set the handler used to process file transfer requests
+    ///
+    /// Until a handler is set, the outstation responds to file-control requests with IIN2.0 NO_FUNC_CODE_SUPPORT
+    pub async fn set_file_handler(
+        &mut self,
+        handler: Box<dyn FileHandler>,
+    ) -> Result<(), Shutdown> {
+        self.sender
+            .send(ConfigurationChange::SetFileHandler(handler).into())
+            .await?;
+        Ok(())
+    }
+
+    /// Enable secure authentication (SAv5) of critical requests
+    ///
+    /// Once enabled, critical requests are challenged or must use aggressive mode, and masters
+    /// must establish session keys with the update keys provided by the `users` store
+    pub async fn set_authentication(
+        &mut self,
+        config: OutstationAuthConfig,
+        users: Box<dyn UserStore>,
+        info: Box<dyn AuthenticationInformation>,
+    ) -> Result<(), Shutdown> {
+        self.sender
+            .send(ConfigurationChange::SetAuthentication(config, users, info).into())
+            .await?;
+        Ok(())
+    }
+
+    /// Open a byte stream for the virtual terminal port (groups 112/113) with the specified index
+    ///
+    /// Output written to the stream is reported to the master as events in the specified class.
+    /// Opening a port that is already open ends the previous stream. Up to 32 blocks written by
+    /// the master are buffered, after which further blocks are rejected with IIN2.2 PARAMETER_ERROR
+    /// until the stream is read.
+    pub async fn open_virtual_terminal(
+        &mut self,
+        port: u16,
+        class: EventClass,
+    ) -> Result<VirtualTerminal, Shutdown> {
+        let (tx, rx) = virtual_terminal_channel();
+        self.sender
+            .send(ConfigurationChange::OpenVirtualTerminal(port, tx).into())
+            .await?;
+        Ok(VirtualTerminal::new(port, class, self.database.clone(), rx))
+    }
+
     pub(crate) async fn shutdown(&mut self) -> Result<(), Shutdown> {
         self.sender.send(OutstationMessage::Shutdown).await?;
         Ok(())
@@ -4,13 +4,20 @@ use tracing::Instrument;
 use xxhash_rust::xxh64::xxh64;
 
 use crate::app::control::CommandStatus;
+use crate::app::file::*;
 use crate::app::format::write::HeaderWriter;
 use crate::app::gen::all::AllObjectsVariation;
 use crate::app::gen::count::CountVariation;
+use crate::app::gen::prefixed::PrefixedVariation;
 use crate::app::gen::ranged::RangedVariation;
+use crate::app::measurement::OctetString;
+use crate::app::parse::bit::BitSequence;
 use crate::app::parse::count::CountSequence;
-use crate::app::parse::parser::{HeaderCollection, HeaderDetails, Request};
-use crate::app::variations::{Group50Var3, Group52Var1, Group52Var2};
+use crate::app::parse::free_format::FreeFormatVariation;
+use crate::app::parse::parser::{HeaderCollection, HeaderDetails, ParsedFragment, Request};
+use crate::app::parse::prefix::Prefix;
+use crate::app::parse::traits::Index;
+use crate::app::variations::{Group50Var2, Group50Var3, Group50Var4, Group52Var1, Group52Var2};
 use crate::app::*;
 use crate::app::{ControlField, Iin, Iin1, Iin2, ResponseFunction, ResponseHeader};
 use crate::decode::DecodeLevel;
@@ -18,13 +25,18 @@ use crate::link::error::LinkError;
 use crate::link::header::BroadcastConfirmMode;
 use crate::link::EndpointAddress;
 use crate::master::EventClasses;
-use crate::outstation::config::{Feature, OutstationConfig};
+use crate::outstation::auth::{AuthAction, OutstationAuth};
+use crate::outstation::config::{CommandEventConfig, Feature, OutstationConfig};
 use crate::outstation::control::collection::{ControlCollection, ControlTransaction};
 use crate::outstation::control::select::SelectState;
-use crate::outstation::database::{DatabaseHandle, ResponseInfo};
+use crate::outstation::database::{
+    DatabaseHandle, EventClass, Get, PointType, ResponseInfo, Update,
+};
 use crate::outstation::deferred::DeferredRead;
+use crate::outstation::file::*;
 use crate::outstation::task::{ConfigurationChange, OutstationMessage};
 use crate::outstation::traits::*;
+use crate::sa::{AuthenticationInformation, OutstationAuthConfig, UserStore};
 use crate::transport::{
     FragmentInfo, RequestGuard, TransportReader, TransportRequest, TransportRequestError,
     TransportWriter,
@@ -32,6 +44,7 @@ use crate::transport::{
 use crate::util::buffer::Buffer;
 use crate::util::channel::Receiver;
 use crate::util::phys::PhysLayer;
+use crate::util::virtual_terminal::VirtualTerminalPorts;
 
 use scursor::WriteError;
 
@@ -155,6 +168,7 @@ pub(crate) struct SessionConfig {
     unsolicited_retry_delay: std::time::Duration,
     keep_alive_timeout: Option<std::time::Duration>,
     max_controls_per_request: Option<u16>,
+    command_events: CommandEventConfig,
 }
 
 pub(crate) struct SessionParameters {
@@ -176,6 +190,7 @@ impl From<OutstationConfig> for SessionConfig {
             unsolicited_retry_delay: config.unsolicited_retry_delay,
             keep_alive_timeout: config.keep_alive_timeout,
             max_controls_per_request: config.max_controls_per_request,
+            command_events: config.command_events,
         }
     }
 }
@@ -199,6 +214,13 @@ enum UnsolicitedState {
     Ready(Option<tokio::time::Instant>),
 }
 
+/// freeze requested using FREEZE_AT_TIME
+struct FreezeSchedule {
+    next: tokio::time::Instant,
+    interval: Option<std::time::Duration>,
+    indices: Vec<FreezeIndices>,
+}
+
 /// state that mutates while the session runs
 struct SessionState {
     restart_iin_asserted: bool,
@@ -210,6 +232,7 @@ struct SessionState {
     deferred_read: DeferredRead,
     last_recorded_time: Option<tokio::time::Instant>,
     last_broadcast_type: Option<BroadcastConfirmMode>,
+    freeze_schedule: Option<FreezeSchedule>,
 }
 
 impl SessionState {
@@ -224,6 +247,7 @@ impl SessionState {
             deferred_read: DeferredRead::new(max_read_headers),
             last_recorded_time: None,
             last_broadcast_type: None,
+            freeze_schedule: None,
         }
     }
 
@@ -244,6 +268,9 @@ pub(crate) struct OutstationSession {
     application: Box<dyn OutstationApplication>,
     info: Box<dyn OutstationInformation>,
     control_handler: Box<dyn ControlHandler>,
+    file_handler: Option<Box<dyn FileHandler>>,
+    virtual_terminals: VirtualTerminalPorts,
+    auth: Option<OutstationAuth>,
     next_link_status: Option<tokio::time::Instant>,
 }
 
@@ -326,6 +353,9 @@ impl OutstationSession {
             application,
             info: information,
             control_handler,
+            file_handler: None,
+            virtual_terminals: VirtualTerminalPorts::default(),
+            auth: None,
             next_link_status,
         }
     }
@@ -353,6 +383,9 @@ impl OutstationSession {
 
     pub(crate) fn reset(&mut self) {
         self.state.reset();
+        if let Some(auth) = self.auth.as_mut() {
+            auth.reset();
+        }
     }
 
     async fn write_unsolicited(
@@ -380,6 +413,10 @@ impl OutstationSession {
 
         let len = std::cmp::max(cursor.written().len(), response.size);
 
+        if let Some(auth) = self.auth.as_mut() {
+            auth.on_message_sent();
+        }
+
         writer
             .write(
                 io,
@@ -422,6 +459,10 @@ impl OutstationSession {
 
         let len = std::cmp::max(cursor.written().len(), response.size);
 
+        if let Some(auth) = self.auth.as_mut() {
+            auth.on_message_sent();
+        }
+
         writer
             .write(
                 io,
@@ -453,13 +494,19 @@ impl OutstationSession {
         // check to see if we should perform a link status check
         self.check_link_status(io, writer).await?;
 
-        let deadline = match deadline {
-            Some(deadline) => match self.next_link_status {
-                Some(link_deadline) => Some(tokio::time::Instant::min(deadline, link_deadline)),
-                None => Some(deadline),
-            },
-            None => self.next_link_status,
-        };
+        // check to see if a scheduled freeze has expired
+        let freeze_deadline = self.check_freeze_schedule(database);
+
+        // check to see if the reply to an authentication challenge timed out
+        let reply_deadline = self.auth.as_mut().and_then(|x| x.check_reply_timeout());
+
+        let deadline = min_deadline(
+            min_deadline(
+                min_deadline(deadline, self.next_link_status),
+                freeze_deadline,
+            ),
+            reply_deadline,
+        );
 
         // wait for an event
         tokio::select! {
@@ -563,6 +610,41 @@ impl OutstationSession {
         Ok(())
     }
 
+    fn check_freeze_schedule(
+        &mut self,
+        database: &mut DatabaseHandle,
+    ) -> Option<tokio::time::Instant> {
+        let schedule = self.state.freeze_schedule.as_mut()?;
+
+        let now = tokio::time::Instant::now();
+        if schedule.next > now {
+            return Some(schedule.next);
+        }
+
+        for indices in schedule.indices.iter() {
+            if let Err(err) =
+                self.application
+                    .freeze_counter(*indices, FreezeType::FreezeAtTime, database)
+            {
+                tracing::warn!("scheduled freeze of {:?} failed: {:?}", indices, err);
+            }
+        }
+
+        match schedule.interval {
+            Some(interval) => {
+                // skip any intervals that were missed
+                while schedule.next <= now {
+                    schedule.next += interval;
+                }
+                Some(schedule.next)
+            }
+            None => {
+                self.state.freeze_schedule = None;
+                None
+            }
+        }
+    }
+
     async fn perform_null_unsolicited(
         &mut self,
         io: &mut PhysLayer,
@@ -882,9 +964,34 @@ impl OutstationSession {
                 tracing::info!("decode level changed to: {:?}", level);
                 self.config.decode_level = level;
             }
+            ConfigurationChange::SetFileHandler(handler) => {
+                tracing::info!("file handler changed");
+                self.set_file_handler(handler);
+            }
+            ConfigurationChange::SetAuthentication(config, users, info) => {
+                tracing::info!("secure authentication enabled");
+                self.set_authentication(config, users, info);
+            }
+            ConfigurationChange::OpenVirtualTerminal(port, sender) => {
+                tracing::info!("virtual terminal port {} opened", port);
+                self.virtual_terminals.open(port, sender);
+            }
         }
     }
 
+    pub(crate) fn set_file_handler(&mut self, handler: Box<dyn FileHandler>) {
+        self.file_handler = Some(handler);
+    }
+
+    pub(crate) fn set_authentication(
+        &mut self,
+        config: OutstationAuthConfig,
+        users: Box<dyn UserStore>,
+        info: Box<dyn AuthenticationInformation>,
+    ) {
+        self.auth = Some(OutstationAuth::new(config, users, info));
+    }
+
     async fn handle_deferred_read(
         &mut self,
         io: &mut PhysLayer,
@@ -940,6 +1047,14 @@ impl OutstationSession {
                             .write_solicited(io, writer, *response, database)
                             .await?;
 
+                        if response.header.function == ResponseFunction::AuthenticateResponse {
+                            if let Some(auth) = self.auth.as_mut() {
+                                if let Some(fragment) = self.sol_tx_buffer.get(response.size) {
+                                    auth.on_auth_response_sent(fragment);
+                                }
+                            }
+                        }
+
                         // check if an extra confirmation was added due to broadcast
                         if response.header.control.con && result.series.is_none() {
                             result.series =
@@ -985,6 +1100,69 @@ impl OutstationSession {
 
         let seq = request.header.control.seq;
 
+        let authenticated = match self.authenticate(info, request) {
+            (AuthAction::NotCritical, _) => None,
+            (AuthAction::Respond, response) => {
+                let hash = xxh64(request.raw_fragment, 0);
+                return Some(LastValidRequest::new(seq, hash, response, None));
+            }
+            (AuthAction::Authenticated(fragment), _) => Some(fragment),
+            (AuthAction::Discard, _) => return None,
+        };
+
+        match authenticated {
+            None => self.process_request(info, request, database).await,
+            Some(fragment) => {
+                // the authenticated request was validated when it was first received
+                let request = ParsedFragment::parse(&fragment).ok()?.to_request().ok()?;
+                self.process_request(info, request, database).await
+            }
+        }
+    }
+
+    /// pass a request through secure authentication, writing any authentication response
+    fn authenticate(
+        &mut self,
+        info: FragmentInfo,
+        request: Request,
+    ) -> (AuthAction, Option<Response>) {
+        let auth = match self.auth.as_mut() {
+            Some(x) => x,
+            None => return (AuthAction::NotCritical, None),
+        };
+
+        let seq = request.header.control.seq;
+        let mut cursor = self.sol_tx_buffer.write_cursor();
+        let _ = cursor.skip(ResponseHeader::LENGTH);
+        let action = auth.process(
+            info.broadcast.is_some(),
+            request,
+            &mut HeaderWriter::new(&mut cursor),
+        );
+
+        let response = match action {
+            AuthAction::Respond => {
+                let header = ResponseHeader::new(
+                    ControlField::single_response(seq),
+                    ResponseFunction::AuthenticateResponse,
+                    Iin::default(),
+                );
+                Some(Response::new(header, cursor.written().len()))
+            }
+            _ => None,
+        };
+
+        (action, response)
+    }
+
+    async fn process_request(
+        &mut self,
+        info: FragmentInfo,
+        request: Request<'_>,
+        database: &mut DatabaseHandle,
+    ) -> Option<LastValidRequest> {
+        let seq = request.header.control.seq;
+
         match self.classify(info, request) {
             FragmentType::MalformedRequest(hash, err) => {
                 let response = Response::empty_solicited(seq, Iin::default() | Iin2::from(err));
@@ -1105,7 +1283,7 @@ impl OutstationSession {
         object_headers: HeaderCollection<'_>,
     ) -> Option<Response> {
         let mut result = match function {
-            FunctionCode::Write => Some(self.handle_write(seq, object_headers)),
+            FunctionCode::Write => Some(self.handle_write(database, seq, object_headers)),
             // these function don't process objects
             FunctionCode::DelayMeasure => Some(self.handle_delay_measure(seq)),
             FunctionCode::RecordCurrentTime => Some(self.handle_record_current_time(seq)),
@@ -1180,12 +1358,37 @@ impl OutstationSession {
                 FreezeType::FreezeAndClear,
                 false,
             ),
+            FunctionCode::FreezeAtTime => self.handle_freeze_at_time(seq, object_headers, true),
+            FunctionCode::FreezeAtTimeNoResponse => {
+                self.handle_freeze_at_time(seq, object_headers, false)
+            }
             FunctionCode::EnableUnsolicited => {
                 Some(self.handle_enable_or_disable_unsolicited(true, seq, object_headers))
             }
+            FunctionCode::AssignClass => {
+                let iin2 = self.handle_assign_class(database, object_headers);
+                Some(Response::empty_solicited(seq, Iin::default() | iin2))
+            }
             FunctionCode::DisableUnsolicited => {
                 Some(self.handle_enable_or_disable_unsolicited(false, seq, object_headers))
             }
+            FunctionCode::ActivateConfig => Some(self.handle_activate_config(seq, object_headers)),
+            FunctionCode::InitializeApplication
+            | FunctionCode::StartApplication
+            | FunctionCode::StopApplication => {
+                let iin2 = self.handle_application_control(function, object_headers);
+                Some(Response::empty_solicited(seq, Iin::default() | iin2))
+            }
+            // only READ requests for file transport objects are classified as non-read
+            FunctionCode::Read
+            | FunctionCode::OpenFile
+            | FunctionCode::CloseFile
+            | FunctionCode::DeleteFile
+            | FunctionCode::GetFileInfo
+            | FunctionCode::AuthenticateFile
+            | FunctionCode::AbortFile => {
+                Some(self.handle_file_request(function, seq, object_headers))
+            }
 
             _ => {
                 tracing::warn!("unsupported function code: {:?}", function);
@@ -1216,7 +1419,21 @@ impl OutstationSession {
         }
     }
 
-    fn handle_write(&mut self, seq: Sequence, object_headers: HeaderCollection) -> Response {
+    fn handle_write(
+        &mut self,
+        database: &mut DatabaseHandle,
+        seq: Sequence,
+        object_headers: HeaderCollection,
+    ) -> Response {
+        if Self::is_file_transport(object_headers) {
+            return self.handle_file_request(FunctionCode::Write, seq, object_headers);
+        }
+
+        // virtual terminal output may span multiple headers
+        if let Some(iin2) = self.handle_write_virtual_terminal(object_headers) {
+            return Response::empty_solicited(seq, Iin::default() | iin2);
+        }
+
         let iin2 = if let Some(header) = object_headers.get_only_header() {
             match header.details {
                 HeaderDetails::OneByteStartStop(_, _, RangedVariation::Group80Var1(seq)) => {
@@ -1257,14 +1474,54 @@ impl OutstationSession {
                 HeaderDetails::OneByteCount(_, CountVariation::Group50Var3(seq)) => {
                     self.handle_g50v3(seq)
                 }
-                _ => {
-                    tracing::warn!(
-                        "WRITE not supported with qualifier: {} and variation: {}",
-                        header.details.qualifier(),
-                        header.variation
-                    );
-                    Iin2::NO_FUNC_CODE_SUPPORT
+                HeaderDetails::OneByteCountAndPrefix(_, PrefixedVariation::Group50Var4(seq)) => {
+                    self.handle_write_time_and_interval(seq)
+                }
+                HeaderDetails::TwoByteCountAndPrefix(_, PrefixedVariation::Group50Var4(seq)) => {
+                    self.handle_write_time_and_interval(seq)
+                }
+                HeaderDetails::OneByteStartStop(_, _, RangedVariation::Group110VarX(_, seq)) => {
+                    self.handle_write_octet_strings(database, &mut seq.iter())
                 }
+                HeaderDetails::TwoByteStartStop(_, _, RangedVariation::Group110VarX(_, seq)) => {
+                    self.handle_write_octet_strings(database, &mut seq.iter())
+                }
+                HeaderDetails::OneByteCountAndPrefix(
+                    _,
+                    PrefixedVariation::Group110VarX(_, seq),
+                ) => self.handle_write_octet_strings(
+                    database,
+                    &mut seq.iter().map(|(value, index)| (value, u16::from(index))),
+                ),
+                HeaderDetails::TwoByteCountAndPrefix(
+                    _,
+                    PrefixedVariation::Group110VarX(_, seq),
+                ) => self.handle_write_octet_strings(database, &mut seq.iter()),
+                HeaderDetails::OneByteStartStop(_, _, RangedVariation::Group10Var1(seq)) => {
+                    self.handle_write_binary_outputs(database, seq)
+                }
+                HeaderDetails::TwoByteStartStop(_, _, RangedVariation::Group10Var1(seq)) => {
+                    self.handle_write_binary_outputs(database, seq)
+                }
+                HeaderDetails::OneByteStartStop(_, _, RangedVariation::Group0(_, Some(attr))) => {
+                    self.handle_write_attr(database, attr)
+                }
+                HeaderDetails::TwoByteStartStop(_, _, RangedVariation::Group0(_, Some(attr))) => {
+                    self.handle_write_attr(database, attr)
+                }
+                ref details => match with_deadbands(details, |iter| {
+                    self.handle_write_deadbands(database, iter)
+                }) {
+                    Some(iin2) => iin2,
+                    None => {
+                        tracing::warn!(
+                            "WRITE not supported with qualifier: {} and variation: {}",
+                            header.details.qualifier(),
+                            header.variation
+                        );
+                        Iin2::NO_FUNC_CODE_SUPPORT
+                    }
+                },
             }
         } else {
             tracing::warn!("empty WRITE request");
@@ -1274,6 +1531,508 @@ impl OutstationSession {
         Response::empty_solicited(seq, Iin::default() | iin2)
     }
 
+    /// returns None if the request contains anything other than virtual terminal output (g112)
+    fn handle_write_virtual_terminal(&mut self, object_headers: HeaderCollection) -> Option<Iin2> {
+        let mut blocks = Vec::new();
+        for header in object_headers.iter() {
+            match &header.details {
+                HeaderDetails::OneByteCountAndPrefix(
+                    _,
+                    PrefixedVariation::Group112VarX(_, seq),
+                ) => blocks.extend(seq.iter().map(|(data, port)| (data, u16::from(port)))),
+                HeaderDetails::TwoByteCountAndPrefix(
+                    _,
+                    PrefixedVariation::Group112VarX(_, seq),
+                ) => blocks.extend(seq.iter()),
+                _ => return None,
+            }
+        }
+
+        if blocks.is_empty() {
+            return None;
+        }
+
+        let mut iin2 = Iin2::default();
+        for (data, port) in blocks {
+            if !self.virtual_terminals.deliver(port, data) {
+                iin2 |= Iin2::PARAMETER_ERROR;
+            }
+        }
+        Some(iin2)
+    }
+
+    fn handle_write_binary_outputs(
+        &mut self,
+        database: &mut DatabaseHandle,
+        seq: BitSequence,
+    ) -> Iin2 {
+        match self
+            .application
+            .write_binary_outputs(&mut seq.iter(), database)
+        {
+            Ok(()) => Iin2::default(),
+            Err(err) => err.into(),
+        }
+    }
+
+    fn handle_write_time_and_interval<I: Index>(
+        &mut self,
+        seq: CountSequence<Prefix<I, Group50Var4>>,
+    ) -> Iin2 {
+        let mut values = seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()));
+        match self.application.write_time_and_interval(&mut values) {
+            Ok(()) => Iin2::default(),
+            Err(err) => err.into(),
+        }
+    }
+
+    fn handle_write_octet_strings(
+        &mut self,
+        database: &mut DatabaseHandle,
+        iter: &mut dyn Iterator<Item = (&[u8], u16)>,
+    ) -> Iin2 {
+        let mut iin2 = Iin2::default();
+        for (value, index) in iter {
+            let value = match OctetString::new(value) {
+                Ok(x) => x,
+                Err(err) => {
+                    tracing::warn!("ignoring octet string for index {}: {:?}", index, err);
+                    iin2 |= Iin2::PARAMETER_ERROR;
+                    continue;
+                }
+            };
+
+            let exists = database.transaction(|db| Get::<OctetString>::get(db, index).is_some());
+            if !exists {
+                tracing::warn!("ignoring write of non-existent octet string {}", index);
+                iin2 |= Iin2::PARAMETER_ERROR;
+                continue;
+            }
+
+            match self.application.write_octet_string(index, &value) {
+                Ok(options) => {
+                    database.transaction(|db| db.update(index, &value, options));
+                }
+                Err(err) => iin2 |= err.into(),
+            }
+        }
+        iin2
+    }
+
+    fn handle_write_attr(&mut self, database: &mut DatabaseHandle, attr: Attribute) -> Iin2 {
+        let iin2 = database.transaction(|db| db.check_attr_write(&attr));
+        if iin2 != Iin2::default() {
+            return iin2;
+        }
+
+        if !self.application.write_device_attr(attr) {
+            tracing::warn!(
+                "application rejected write of attribute set: {} variation: {}",
+                attr.set,
+                attr.variation
+            );
+            return Iin2::PARAMETER_ERROR;
+        }
+
+        database.transaction(|db| db.write_attr(&attr));
+        Iin2::default()
+    }
+
+    fn handle_write_deadbands(
+        &mut self,
+        database: &mut DatabaseHandle,
+        iter: &mut dyn Iterator<Item = (f64, u16)>,
+    ) -> Iin2 {
+        let mut iin2 = Iin2::default();
+        for (deadband, index) in iter {
+            if !deadband.is_finite() || deadband < 0.0 {
+                tracing::warn!(
+                    "ignoring invalid deadband {} for analog input {}",
+                    deadband,
+                    index
+                );
+                iin2 |= Iin2::PARAMETER_ERROR;
+                continue;
+            }
+
+            if database.transaction(|db| db.set_analog_input_deadband(index, deadband)) {
+                self.application
+                    .analog_input_deadband_written(index, deadband);
+            } else {
+                tracing::warn!("ignoring deadband for non-existent analog input {}", index);
+                iin2 |= Iin2::PARAMETER_ERROR;
+            }
+        }
+        iin2
+    }
+
+    fn handle_assign_class(
+        &mut self,
+        database: &mut DatabaseHandle,
+        object_headers: HeaderCollection,
+    ) -> Iin2 {
+        let mut iin2 = Iin2::default();
+        // each class header (g60) applies to the point headers that follow it
+        let mut class: Option<Option<EventClass>> = None;
+        let mut assignments = Vec::new();
+
+        for header in object_headers.iter() {
+            if let Some(x) = get_assigned_class(&header.details) {
+                class = Some(x);
+                continue;
+            }
+
+            let (point_type, range) = match get_class_assignment_points(&header.details) {
+                Some(x) => x,
+                None => {
+                    tracing::warn!(
+                        "unsupported header in ASSIGN_CLASS request: {} with qualifier: {}",
+                        header.variation,
+                        header.details.qualifier()
+                    );
+                    iin2 |= Iin2::OBJECT_UNKNOWN;
+                    continue;
+                }
+            };
+
+            let class = match class {
+                Some(x) => x,
+                None => {
+                    tracing::warn!(
+                        "ASSIGN_CLASS header {} not preceded by a class header",
+                        header.variation
+                    );
+                    iin2 |= Iin2::PARAMETER_ERROR;
+                    continue;
+                }
+            };
+
+            let assignment = ClassAssignment {
+                point_type,
+                range,
+                class,
+            };
+
+            if !database.transaction(|db| db.can_assign_class(&assignment)) {
+                tracing::warn!(
+                    "ASSIGN_CLASS header {} references points that do not exist",
+                    header.variation
+                );
+                iin2 |= Iin2::PARAMETER_ERROR;
+                continue;
+            }
+
+            assignments.push(assignment);
+        }
+
+        // every header is validated before any of them is applied so that a rejected request
+        // doesn't change the class of any point
+        if iin2 != Iin2::default() {
+            return iin2;
+        }
+
+        database.transaction(|db| {
+            for assignment in assignments.iter() {
+                db.assign_class(*assignment);
+            }
+        });
+
+        for assignment in assignments {
+            self.application.class_assigned(assignment);
+        }
+
+        iin2
+    }
+
+    fn handle_application_control(
+        &mut self,
+        function: FunctionCode,
+        object_headers: HeaderCollection,
+    ) -> Iin2 {
+        if object_headers.is_empty() {
+            tracing::warn!(
+                "{:?} request does not contain any application identifiers",
+                function
+            );
+            return Iin2::PARAMETER_ERROR;
+        }
+
+        let mut iin2 = Iin2::default();
+
+        for header in object_headers.iter() {
+            let application = match header.details {
+                HeaderDetails::AllObjects(AllObjectsVariation::Group90Var1) => {
+                    ApplicationIdentifier::All
+                }
+                HeaderDetails::TwoByteFreeFormat(_, FreeFormatVariation::Group90Var1(x)) => {
+                    ApplicationIdentifier::Name(x.name.to_string())
+                }
+                _ => {
+                    tracing::warn!(
+                        "unsupported header in {:?} request: {} with qualifier: {}",
+                        function,
+                        header.variation,
+                        header.details.qualifier()
+                    );
+                    iin2 |= Iin2::OBJECT_UNKNOWN;
+                    continue;
+                }
+            };
+
+            let result = match function {
+                FunctionCode::InitializeApplication => {
+                    self.application.initialize_application(application)
+                }
+                FunctionCode::StartApplication => self.application.start_application(application),
+                _ => self.application.stop_application(application),
+            };
+
+            if let Err(err) = result {
+                iin2 |= err.into();
+            }
+        }
+
+        iin2
+    }
+
+    fn handle_activate_config(
+        &mut self,
+        seq: Sequence,
+        object_headers: HeaderCollection,
+    ) -> Response {
+        let mut configurations = Vec::new();
+
+        for header in object_headers.iter() {
+            match header.details {
+                HeaderDetails::TwoByteFreeFormat(_, FreeFormatVariation::Group70Var8(x)) => {
+                    configurations.push(ConfigurationReference::File(
+                        x.file_specification.to_string(),
+                    ));
+                }
+                HeaderDetails::OneByteStartStop(_, _, RangedVariation::Group110VarX(_, items))
+                | HeaderDetails::TwoByteStartStop(_, _, RangedVariation::Group110VarX(_, items)) => {
+                    for (value, index) in items.iter() {
+                        match OctetString::new(value) {
+                            Ok(value) => configurations
+                                .push(ConfigurationReference::OctetString(index, Box::new(value))),
+                            Err(err) => {
+                                tracing::warn!(
+                                    "bad octet string in ACTIVATE_CONFIG request: {:?}",
+                                    err
+                                );
+                                return Response::empty_solicited(
+                                    seq,
+                                    Iin::default() | Iin2::PARAMETER_ERROR,
+                                );
+                            }
+                        }
+                    }
+                }
+                _ => {
+                    tracing::warn!(
+                        "unsupported header in ACTIVATE_CONFIG request: {} with qualifier: {}",
+                        header.variation,
+                        header.details.qualifier()
+                    );
+                    return Response::empty_solicited(seq, Iin::default() | Iin2::OBJECT_UNKNOWN);
+                }
+            }
+        }
+
+        if configurations.is_empty() {
+            tracing::warn!("ACTIVATE_CONFIG request does not reference any configurations");
+            return Response::empty_solicited(seq, Iin::default() | Iin2::PARAMETER_ERROR);
+        }
+
+        match self.application.activate_config(&configurations) {
+            Ok(status) => self.write_free_format_response(seq, &status),
+            Err(err) => Response::empty_solicited(seq, Iin::default() | Iin2::from(err)),
+        }
+    }
+
+    fn is_file_transport(object_headers: HeaderCollection) -> bool {
+        matches!(
+            object_headers
+                .get_only_header()
+                .and_then(|header| header.details.free_format()),
+            Some(FreeFormatVariation::Group70Var5(_))
+        )
+    }
+
+    /// largest file block that fits in a g70v5 object in a single response
+    fn max_file_block_size(&self) -> u16 {
+        // g/v, qualifier, count, size, handle, and block number
+        const OVERHEAD: usize = 2 + 1 + 1 + 2 + 4 + 4;
+        let available = self
+            .sol_tx_buffer
+            .len()
+            .saturating_sub(ResponseHeader::LENGTH + OVERHEAD);
+        u16::try_from(available).unwrap_or(u16::MAX)
+    }
+
+    fn handle_file_request(
+        &mut self,
+        function: FunctionCode,
+        seq: Sequence,
+        object_headers: HeaderCollection,
+    ) -> Response {
+        let max_block_size = self.max_file_block_size();
+
+        let handler = match self.file_handler.as_mut() {
+            Some(x) => x,
+            None => {
+                tracing::warn!("no file handler configured for {:?}", function);
+                return Response::empty_solicited(seq, Iin::default() | Iin2::NO_FUNC_CODE_SUPPORT);
+            }
+        };
+
+        let object = match object_headers
+            .get_only_header()
+            .and_then(|header| header.details.free_format())
+        {
+            Some(x) => x,
+            None => {
+                tracing::warn!("{:?} requires a single file-control object", function);
+                return Response::empty_solicited(seq, Iin::default() | Iin2::PARAMETER_ERROR);
+            }
+        };
+
+        match (function, object) {
+            (FunctionCode::AuthenticateFile, FreeFormatVariation::Group70Var2(x)) => {
+                let auth_key = handler.authenticate(x.user_name, x.password).unwrap_or(0);
+                self.write_free_format_response(
+                    seq,
+                    &Group70Var2 {
+                        auth_key,
+                        user_name: "",
+                        password: "",
+                    },
+                )
+            }
+            (FunctionCode::OpenFile, FreeFormatVariation::Group70Var3(x)) => {
+                let result = handler.open_file(OpenFileRequest {
+                    file_name: x.file_name,
+                    mode: x.mode,
+                    auth_key: x.auth_key,
+                    file_size: x.file_size,
+                    permissions: x.permissions,
+                    max_block_size: x.max_block_size,
+                    time_of_creation: x.time_of_creation,
+                });
+                let status = match result {
+                    Ok(file) => Group70Var4 {
+                        file_handle: file.handle,
+                        file_size: file.file_size,
+                        max_block_size: file.max_block_size.min(max_block_size),
+                        request_id: x.request_id,
+                        status: FileStatus::Success,
+                        text: "",
+                    },
+                    Err(status) => file_status(0, x.request_id, status),
+                };
+                self.write_free_format_response(seq, &status)
+            }
+            (FunctionCode::CloseFile, FreeFormatVariation::Group70Var4(x)) => {
+                let status = to_file_status(handler.close_file(x.file_handle));
+                self.write_free_format_response(
+                    seq,
+                    &file_status(x.file_handle, x.request_id, status),
+                )
+            }
+            (FunctionCode::AbortFile, FreeFormatVariation::Group70Var4(x)) => {
+                let status = to_file_status(handler.abort_file(x.file_handle));
+                self.write_free_format_response(
+                    seq,
+                    &file_status(x.file_handle, x.request_id, status),
+                )
+            }
+            (FunctionCode::DeleteFile, FreeFormatVariation::Group70Var3(x)) => {
+                let status = to_file_status(handler.delete_file(x.file_name, x.auth_key));
+                self.write_free_format_response(seq, &file_status(0, x.request_id, status))
+            }
+            (FunctionCode::GetFileInfo, FreeFormatVariation::Group70Var7(x)) => {
+                match handler.get_file_info(x.file_name) {
+                    Ok(info) => {
+                        self.write_free_format_response(seq, &info.to_descriptor(x.request_id))
+                    }
+                    Err(status) => {
+                        self.write_free_format_response(seq, &file_status(0, x.request_id, status))
+                    }
+                }
+            }
+            (FunctionCode::Read, FreeFormatVariation::Group70Var5(x)) => {
+                match handler.read_file_block(
+                    x.file_handle,
+                    x.block_number.value,
+                    max_block_size as usize,
+                ) {
+                    Ok(block) => self.write_free_format_response(
+                        seq,
+                        &Group70Var5 {
+                            file_handle: x.file_handle,
+                            block_number: BlockNumber::new(x.block_number.value, block.last),
+                            data: &block.data,
+                        },
+                    ),
+                    Err(status) => self.write_free_format_response(
+                        seq,
+                        &Group70Var6 {
+                            file_handle: x.file_handle,
+                            block_number: x.block_number,
+                            status,
+                            text: "",
+                        },
+                    ),
+                }
+            }
+            (FunctionCode::Write, FreeFormatVariation::Group70Var5(x)) => {
+                let status = to_file_status(handler.write_file_block(
+                    x.file_handle,
+                    x.block_number.value,
+                    x.data,
+                    x.block_number.last,
+                ));
+                self.write_free_format_response(
+                    seq,
+                    &Group70Var6 {
+                        file_handle: x.file_handle,
+                        block_number: x.block_number,
+                        status,
+                        text: "",
+                    },
+                )
+            }
+            _ => {
+                tracing::warn!(
+                    "{:?} not supported with file-control object: {:?}",
+                    function,
+                    object
+                );
+                Response::empty_solicited(seq, Iin::default() | Iin2::PARAMETER_ERROR)
+            }
+        }
+    }
+
+    fn write_free_format_response<V>(&mut self, seq: Sequence, object: &V) -> Response
+    where
+        V: FreeFormat,
+    {
+        let mut cursor = self.sol_tx_buffer.write_cursor();
+        let _ = cursor.skip(ResponseHeader::LENGTH);
+        let mut writer = HeaderWriter::new(&mut cursor);
+        if let Err(err) = writer.write_free_format(object) {
+            tracing::warn!("unable to write free-format response: {:?}", err);
+            return Response::empty_solicited(seq, Iin::default() | Iin2::PARAMETER_ERROR);
+        }
+
+        let header = ResponseHeader::new(
+            ControlField::response(seq, true, true, false),
+            ResponseFunction::Response,
+            Iin::default(),
+        );
+        Response::new(header, cursor.written().len())
+    }
+
     fn handle_g50v3(&mut self, seq: CountSequence<Group50Var3>) -> Iin2 {
         let value = if let Some(value) = seq.single() {
             value
@@ -1381,9 +2140,11 @@ impl OutstationSession {
             let _ = cursor.skip(ResponseHeader::LENGTH);
 
             let max_controls_per_request = self.config.max_controls_per_request;
+            let command_events = self.config.command_events;
             let result = ControlTransaction::execute(
                 self.control_handler.borrow_mut(),
                 database,
+                command_events,
                 |tx, db| {
                     controls.operate_with_response(
                         &mut cursor,
@@ -1462,9 +2223,13 @@ impl OutstationSession {
         controls: ControlCollection<'_>,
     ) {
         let max_controls_per_request = self.config.max_controls_per_request;
-        ControlTransaction::execute(self.control_handler.borrow_mut(), database, |tx, db| {
-            controls.operate_no_ack(tx, db, max_controls_per_request)
-        })
+        let command_events = self.config.command_events;
+        ControlTransaction::execute(
+            self.control_handler.borrow_mut(),
+            database,
+            command_events,
+            |tx, db| controls.operate_no_ack(tx, db, max_controls_per_request),
+        )
         .await;
     }
 
@@ -1529,9 +2294,11 @@ impl OutstationSession {
             let _ = cursor.skip(ResponseHeader::LENGTH);
 
             let max_controls_per_request = self.config.max_controls_per_request;
+            let command_events = self.config.command_events;
             let result: Result<CommandStatus, WriteError> = ControlTransaction::execute(
                 self.control_handler.borrow_mut(),
                 database,
+                command_events,
                 |tx, db| {
                     controls.select_with_response(&mut cursor, tx, db, max_controls_per_request)
                 },
@@ -1593,9 +2360,11 @@ impl OutstationSession {
                         }
                         Ok(()) => {
                             let max_controls_per_request = self.config.max_controls_per_request;
+                            let command_events = self.config.command_events;
                             ControlTransaction::execute(
                                 self.control_handler.borrow_mut(),
                                 database,
+                                command_events,
                                 |tx, db| {
                                     controls
                                         .operate_with_response(
@@ -1648,30 +2417,14 @@ impl OutstationSession {
         let mut iin = Iin::default();
 
         for header in object_headers.iter() {
-            match header.details {
-                HeaderDetails::AllObjects(AllObjectsVariation::Group20Var0) => {
+            match get_freeze_indices(&header.details) {
+                Some(indices) => {
                     iin |= self
                         .application
-                        .freeze_counter(FreezeIndices::All, freeze_type, database)
+                        .freeze_counter(indices, freeze_type, database)
                         .map_or_else(|err| err.into(), |_| Iin2::default());
                 }
-                HeaderDetails::OneByteStartStop(start, stop, RangedVariation::Group20Var0) => {
-                    iin |= self
-                        .application
-                        .freeze_counter(
-                            FreezeIndices::Range(start as u16, stop as u16),
-                            freeze_type,
-                            database,
-                        )
-                        .map_or_else(|err| err.into(), |_| Iin2::default());
-                }
-                HeaderDetails::TwoByteStartStop(start, stop, RangedVariation::Group20Var0) => {
-                    iin |= self
-                        .application
-                        .freeze_counter(FreezeIndices::Range(start, stop), freeze_type, database)
-                        .map_or_else(|err| err.into(), |_| Iin2::default());
-                }
-                _ => {
+                None => {
                     iin |= Iin2::NO_FUNC_CODE_SUPPORT;
                 }
             }
@@ -1684,6 +2437,82 @@ impl OutstationSession {
         }
     }
 
+    fn handle_freeze_at_time(
+        &mut self,
+        seq: Sequence,
+        object_headers: HeaderCollection,
+        respond: bool,
+    ) -> Option<Response> {
+        let mut iin = Iin::default();
+        iin |= self.schedule_freeze(object_headers);
+
+        if respond {
+            Some(Response::empty_solicited(seq, iin))
+        } else {
+            None
+        }
+    }
+
+    fn schedule_freeze(&mut self, object_headers: HeaderCollection) -> Iin2 {
+        let mut headers = object_headers.iter();
+
+        let value = match headers.next().map(|x| x.details) {
+            Some(HeaderDetails::OneByteCount(_, CountVariation::Group50Var2(seq))) => {
+                match seq.single() {
+                    Some(value) => value,
+                    None => {
+                        tracing::warn!("FREEZE_AT_TIME request lacks a single g50v2");
+                        return Iin2::PARAMETER_ERROR;
+                    }
+                }
+            }
+            _ => {
+                tracing::warn!("FREEZE_AT_TIME request must begin with a g50v2 header");
+                return Iin2::PARAMETER_ERROR;
+            }
+        };
+
+        let mut indices = Vec::new();
+        for header in headers {
+            match get_freeze_indices(&header.details) {
+                Some(x) => indices.push(x),
+                None => {
+                    tracing::warn!(
+                        "FREEZE_AT_TIME not supported with qualifier: {} and variation: {}",
+                        header.details.qualifier(),
+                        header.variation
+                    );
+                    return Iin2::NO_FUNC_CODE_SUPPORT;
+                }
+            }
+        }
+
+        if indices.is_empty() {
+            tracing::warn!("FREEZE_AT_TIME request does not select any counters");
+            return Iin2::PARAMETER_ERROR;
+        }
+
+        let delay = match get_first_freeze_delay(value, self.application.get_current_time()) {
+            Some(delay) => delay,
+            None => {
+                tracing::warn!("unable to schedule freeze with {}", value);
+                return Iin2::PARAMETER_ERROR;
+            }
+        };
+
+        // a new schedule replaces any existing schedule
+        self.state.freeze_schedule = Some(FreezeSchedule {
+            next: tokio::time::Instant::now() + delay,
+            interval: match value.interval {
+                0 => None,
+                x => Some(std::time::Duration::from_millis(x as u64)),
+            },
+            indices,
+        });
+
+        Iin2::default()
+    }
+
     fn get_response_iin(&mut self, database: &DatabaseHandle) -> Iin {
         let mut iin = Iin::default();
 
@@ -1768,7 +2597,7 @@ impl OutstationSession {
 
         match request.header.function {
             FunctionCode::Write => {
-                self.handle_write(seq, objects);
+                self.handle_write(database, seq, objects);
                 BroadcastAction::Processed
             }
             FunctionCode::DirectOperateNoResponse => {
@@ -1790,6 +2619,10 @@ impl OutstationSession {
                 self.handle_freeze(database, seq, objects, FreezeType::FreezeAndClear, false);
                 BroadcastAction::Processed
             }
+            FunctionCode::FreezeAtTimeNoResponse => {
+                self.handle_freeze_at_time(seq, objects, false);
+                BroadcastAction::Processed
+            }
             FunctionCode::RecordCurrentTime => {
                 self.handle_record_current_time(seq);
                 BroadcastAction::Processed
@@ -1802,6 +2635,10 @@ impl OutstationSession {
                 self.handle_enable_or_disable_unsolicited(true, seq, objects);
                 BroadcastAction::Processed
             }
+            FunctionCode::AssignClass => {
+                self.handle_assign_class(database, objects);
+                BroadcastAction::Processed
+            }
             _ => {
                 tracing::warn!(
                     "unsupported broadcast function: {:?}",
@@ -1980,10 +2817,14 @@ impl OutstationSession {
             Err(err) => return FragmentType::MalformedRequest(this_hash, err),
         };
 
+        // reading a block of a file changes the state of the transfer, so it's not a regular READ
+        let is_read = request.header.function == FunctionCode::Read
+            && !Self::is_file_transport(object_headers);
+
         // detect duplicate requests
         if let Some(last) = self.state.last_valid_request {
             if last.seq == request.header.control.seq && last.request_hash == this_hash {
-                return if request.header.function == FunctionCode::Read {
+                return if is_read {
                     FragmentType::RepeatRead(this_hash, last.response, object_headers)
                 } else {
                     FragmentType::RepeatNonRead(this_hash, last.response)
@@ -1991,7 +2832,7 @@ impl OutstationSession {
             }
         }
 
-        if request.header.function == FunctionCode::Read {
+        if is_read {
             FragmentType::NewRead(this_hash, object_headers)
         } else {
             FragmentType::NewNonRead(this_hash, object_headers)
@@ -2006,6 +2847,197 @@ impl OutstationSession {
     }
 }
 
+fn file_status(file_handle: u32, request_id: u16, status: FileStatus) -> Group70Var4<'static> {
+    Group70Var4 {
+        file_handle,
+        file_size: 0,
+        max_block_size: 0,
+        request_id,
+        status,
+        text: "",
+    }
+}
+
+fn to_file_status(result: Result<(), FileStatus>) -> FileStatus {
+    match result {
+        Ok(()) => FileStatus::Success,
+        Err(status) => status,
+    }
+}
+
+/// the class assigned by a class header (g60) in an ASSIGN_CLASS request where Class 0
+/// means that the points no longer produce events
+fn get_assigned_class(details: &HeaderDetails) -> Option<Option<EventClass>> {
+    match details {
+        HeaderDetails::AllObjects(AllObjectsVariation::Group60Var1) => Some(None),
+        HeaderDetails::AllObjects(AllObjectsVariation::Group60Var2) => {
+            Some(Some(EventClass::Class1))
+        }
+        HeaderDetails::AllObjects(AllObjectsVariation::Group60Var3) => {
+            Some(Some(EventClass::Class2))
+        }
+        HeaderDetails::AllObjects(AllObjectsVariation::Group60Var4) => {
+            Some(Some(EventClass::Class3))
+        }
+        _ => None,
+    }
+}
+
+/// the static points selected by a header in an ASSIGN_CLASS request
+fn get_class_assignment_points(details: &HeaderDetails) -> Option<(PointType, PointRange)> {
+    fn from_ranged(variation: &RangedVariation) -> Option<PointType> {
+        match variation {
+            RangedVariation::Group1Var0 => Some(PointType::BinaryInput),
+            RangedVariation::Group3Var0 => Some(PointType::DoubleBitBinaryInput),
+            RangedVariation::Group10Var0 => Some(PointType::BinaryOutputStatus),
+            RangedVariation::Group20Var0 => Some(PointType::Counter),
+            RangedVariation::Group21Var0 => Some(PointType::FrozenCounter),
+            RangedVariation::Group30Var0 => Some(PointType::AnalogInput),
+            RangedVariation::Group31Var0 => Some(PointType::FrozenAnalogInput),
+            RangedVariation::Group40Var0 => Some(PointType::AnalogOutputStatus),
+            RangedVariation::Group110Var0 => Some(PointType::OctetString),
+            _ => None,
+        }
+    }
+
+    match details {
+        HeaderDetails::AllObjects(variation) => {
+            let point_type = match variation {
+                AllObjectsVariation::Group1Var0 => PointType::BinaryInput,
+                AllObjectsVariation::Group3Var0 => PointType::DoubleBitBinaryInput,
+                AllObjectsVariation::Group10Var0 => PointType::BinaryOutputStatus,
+                AllObjectsVariation::Group20Var0 => PointType::Counter,
+                AllObjectsVariation::Group21Var0 => PointType::FrozenCounter,
+                AllObjectsVariation::Group30Var0 => PointType::AnalogInput,
+                AllObjectsVariation::Group31Var0 => PointType::FrozenAnalogInput,
+                AllObjectsVariation::Group40Var0 => PointType::AnalogOutputStatus,
+                AllObjectsVariation::Group110Var0 => PointType::OctetString,
+                _ => return None,
+            };
+            Some((point_type, PointRange::All))
+        }
+        HeaderDetails::OneByteStartStop(start, stop, variation) => Some((
+            from_ranged(variation)?,
+            PointRange::Range(*start as u16, *stop as u16),
+        )),
+        HeaderDetails::TwoByteStartStop(start, stop, variation) => {
+            Some((from_ranged(variation)?, PointRange::Range(*start, *stop)))
+        }
+        _ => None,
+    }
+}
+
+fn min_deadline(
+    first: Option<tokio::time::Instant>,
+    second: Option<tokio::time::Instant>,
+) -> Option<tokio::time::Instant> {
+    match (first, second) {
+        (Some(x), Some(y)) => Some(tokio::time::Instant::min(x, y)),
+        (x, None) => x,
+        (None, y) => y,
+    }
+}
+
+fn get_freeze_indices(details: &HeaderDetails) -> Option<FreezeIndices> {
+    match details {
+        HeaderDetails::AllObjects(AllObjectsVariation::Group20Var0) => Some(FreezeIndices::All),
+        HeaderDetails::OneByteStartStop(start, stop, RangedVariation::Group20Var0) => {
+            Some(FreezeIndices::Range(*start as u16, *stop as u16))
+        }
+        HeaderDetails::TwoByteStartStop(start, stop, RangedVariation::Group20Var0) => {
+            Some(FreezeIndices::Range(*start, *stop))
+        }
+        // a limited count selects the first 'count' counters
+        HeaderDetails::OneByteCount(count, CountVariation::Group20Var0) => count
+            .checked_sub(1)
+            .map(|stop| FreezeIndices::Range(0, stop as u16)),
+        HeaderDetails::TwoByteCount(count, CountVariation::Group20Var0) => count
+            .checked_sub(1)
+            .map(|stop| FreezeIndices::Range(0, stop)),
+        _ => None,
+    }
+}
+
+/// Calculate the delay until the first freeze described by a g50v2 object
+///
+/// * time == 0 && interval == 0: freeze immediately
+/// * time == 0 && interval > 0: freeze periodically, starting one interval from now
+/// * time > 0 && interval == 0: freeze once at the specified time, which may not have passed
+/// * time > 0 && interval > 0: freeze periodically, aligned to the specified time
+fn get_first_freeze_delay(
+    value: Group50Var2,
+    now: Option<Timestamp>,
+) -> Option<std::time::Duration> {
+    let time = value.time.raw_value();
+    let interval = value.interval as u64;
+
+    let delay_ms = if time == 0 {
+        interval
+    } else {
+        let now = now?.raw_value();
+        if time >= now {
+            time - now
+        } else if interval == 0 {
+            return None;
+        } else {
+            match (now - time) % interval {
+                0 => 0,
+                elapsed => interval - elapsed,
+            }
+        }
+    };
+
+    Some(std::time::Duration::from_millis(delay_ms))
+}
+
+/// invoke the function with the (deadband, index) pairs of a g34 header widened to f64,
+/// or return None if the header does not contain analog input deadbands
+fn with_deadbands<R>(
+    details: &HeaderDetails,
+    func: impl FnOnce(&mut dyn Iterator<Item = (f64, u16)>) -> R,
+) -> Option<R> {
+    let result = match details {
+        HeaderDetails::OneByteStartStop(_, _, RangedVariation::Group34Var1(seq)) => {
+            func(&mut seq.iter().map(|(v, i)| (v.value.into(), i)))
+        }
+        HeaderDetails::OneByteStartStop(_, _, RangedVariation::Group34Var2(seq)) => {
+            func(&mut seq.iter().map(|(v, i)| (v.value.into(), i)))
+        }
+        HeaderDetails::OneByteStartStop(_, _, RangedVariation::Group34Var3(seq)) => {
+            func(&mut seq.iter().map(|(v, i)| (v.value.into(), i)))
+        }
+        HeaderDetails::TwoByteStartStop(_, _, RangedVariation::Group34Var1(seq)) => {
+            func(&mut seq.iter().map(|(v, i)| (v.value.into(), i)))
+        }
+        HeaderDetails::TwoByteStartStop(_, _, RangedVariation::Group34Var2(seq)) => {
+            func(&mut seq.iter().map(|(v, i)| (v.value.into(), i)))
+        }
+        HeaderDetails::TwoByteStartStop(_, _, RangedVariation::Group34Var3(seq)) => {
+            func(&mut seq.iter().map(|(v, i)| (v.value.into(), i)))
+        }
+        HeaderDetails::OneByteCountAndPrefix(_, PrefixedVariation::Group34Var1(seq)) => {
+            func(&mut seq.iter().map(|x| (x.value.value.into(), x.index as u16)))
+        }
+        HeaderDetails::OneByteCountAndPrefix(_, PrefixedVariation::Group34Var2(seq)) => {
+            func(&mut seq.iter().map(|x| (x.value.value.into(), x.index as u16)))
+        }
+        HeaderDetails::OneByteCountAndPrefix(_, PrefixedVariation::Group34Var3(seq)) => {
+            func(&mut seq.iter().map(|x| (x.value.value.into(), x.index as u16)))
+        }
+        HeaderDetails::TwoByteCountAndPrefix(_, PrefixedVariation::Group34Var1(seq)) => {
+            func(&mut seq.iter().map(|x| (x.value.value.into(), x.index)))
+        }
+        HeaderDetails::TwoByteCountAndPrefix(_, PrefixedVariation::Group34Var2(seq)) => {
+            func(&mut seq.iter().map(|x| (x.value.value.into(), x.index)))
+        }
+        HeaderDetails::TwoByteCountAndPrefix(_, PrefixedVariation::Group34Var3(seq)) => {
+            func(&mut seq.iter().map(|x| (x.value.value.into(), x.index)))
+        }
+        _ => return None,
+    };
+    Some(result)
+}
+
 impl From<ObjectParseError> for Iin2 {
     fn from(err: ObjectParseError) -> Self {
         // TODO - review these
@@ -2017,6 +3049,10 @@ impl From<ObjectParseError> for Iin2 {
             ObjectParseError::UnsupportedQualifierCode(_) => Iin2::PARAMETER_ERROR,
             ObjectParseError::UnknownQualifier(_) => Iin2::PARAMETER_ERROR,
             ObjectParseError::ZeroLengthOctetData => Iin2::PARAMETER_ERROR,
+            ObjectParseError::UnsupportedFreeFormatCount(_) => Iin2::PARAMETER_ERROR,
+            ObjectParseError::BadEncoding => Iin2::PARAMETER_ERROR,
+            ObjectParseError::BadAttribute(_) => Iin2::PARAMETER_ERROR,
+            ObjectParseError::BadDataSet(_) => Iin2::PARAMETER_ERROR,
         }
     }
 }
@@ -3,14 +3,25 @@ use crate::decode::DecodeLevel;
 use crate::link::LinkErrorMode;
 use crate::outstation::config::*;
 use crate::outstation::database::DatabaseHandle;
+use crate::outstation::file::FileHandler;
 use crate::outstation::session::{OutstationSession, RunError};
 use crate::outstation::traits::{ControlHandler, OutstationApplication, OutstationInformation};
 use crate::outstation::OutstationHandle;
+use crate::sa::{AuthenticationInformation, OutstationAuthConfig, UserStore};
 use crate::transport::{TransportReader, TransportWriter};
 use crate::util::phys::PhysLayer;
+use crate::util::virtual_terminal::VirtualTerminalSender;
 
+#[allow(clippy::enum_variant_names)]
 pub(crate) enum ConfigurationChange {
     SetDecodeLevel(DecodeLevel),
+    SetFileHandler(Box<dyn FileHandler>),
+    SetAuthentication(
+        OutstationAuthConfig,
+        Box<dyn UserStore>,
+        Box<dyn AuthenticationInformation>,
+    ),
+    OpenVirtualTerminal(u16, VirtualTerminalSender),
 }
 
 impl From<ConfigurationChange> for OutstationMessage {
@@ -94,6 +105,21 @@ impl OutstationTask {
         self.writer.reset();
     }
 
+    #[cfg(test)]
+    pub(crate) fn set_file_handler(&mut self, handler: Box<dyn FileHandler>) {
+        self.session.set_file_handler(handler);
+    }
+
+    #[cfg(test)]
+    pub(crate) fn set_authentication(
+        &mut self,
+        config: OutstationAuthConfig,
+        users: Box<dyn UserStore>,
+        info: Box<dyn AuthenticationInformation>,
+    ) {
+        self.session.set_authentication(config, users, info);
+    }
+
     #[cfg(test)]
     pub(crate) fn get_reader(&mut self) -> &mut TransportReader {
         &mut self.reader
@@ -0,0 +1,73 @@
+use crate::app::measurement::OctetString;
+use crate::app::ConfigurationReference;
+use crate::outstation::tests::harness::*;
+use crate::outstation::traits::RequestError;
+
+const EMPTY_RESPONSE_NO_FUNC_CODE: &[u8] = &[0xC0, 0x81, 0x80, 0x01];
+const EMPTY_RESPONSE_OBJECT_UNKNOWN: &[u8] = &[0xC0, 0x81, 0x80, 0x02];
+const EMPTY_RESPONSE_PARAM_ERROR: &[u8] = &[0xC0, 0x81, 0x80, 0x04];
+
+#[tokio::test]
+async fn activates_file_and_octet_string_configurations() {
+    let mut harness = new_harness(get_default_config());
+
+    // g70v8 free-format "a.cfg", g110v2 range [4, 4] "ok"
+    harness
+        .test_request_response(
+            &[
+                0xC0, 0x1F, 70, 8, 0x5B, 0x01, 0x05, 0x00, b'a', b'.', b'c', b'f', b'g', 110, 2,
+                0x00, 4, 4, b'o', b'k',
+            ],
+            &[
+                0xC0, 0x81, 0x80, 0x00, 91, 1, 0x5B, 0x01, 0x09, 0x00, 0xE8, 0x03, 0x00, 0x00,
+                0x02, 0x01, 0x00, 0x01, 0x00,
+            ],
+        )
+        .await;
+
+    harness.check_events(&[Event::ActivateConfig(vec![
+        ConfigurationReference::File("a.cfg".to_string()),
+        ConfigurationReference::OctetString(4, Box::new(OctetString::new(b"ok").unwrap())),
+    ])]);
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn sets_iin2_when_application_rejects_request() {
+    let mut harness = new_harness(get_default_config());
+
+    harness.application_data.lock().unwrap().application_error = Some(RequestError::NotSupported);
+    harness
+        .test_request_response(
+            &[0xC0, 0x1F, 70, 8, 0x5B, 0x01, 0x01, 0x00, b'a'],
+            EMPTY_RESPONSE_NO_FUNC_CODE,
+        )
+        .await;
+
+    harness.check_events(&[Event::ActivateConfig(vec![ConfigurationReference::File(
+        "a".to_string(),
+    )])]);
+}
+
+#[tokio::test]
+async fn rejects_request_without_configurations() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(&[0xC0, 0x1F], EMPTY_RESPONSE_PARAM_ERROR)
+        .await;
+
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn rejects_unsupported_object_headers() {
+    let mut harness = new_harness(get_default_config());
+
+    // ACTIVATE_CONFIG g90v1 all objects
+    harness
+        .test_request_response(&[0xC0, 0x1F, 90, 1, 0x06], EMPTY_RESPONSE_OBJECT_UNKNOWN)
+        .await;
+
+    harness.check_no_events();
+}
@@ -0,0 +1,104 @@
+use crate::app::ApplicationIdentifier;
+use crate::outstation::tests::harness::*;
+use crate::outstation::traits::RequestError;
+
+const EMPTY_RESPONSE: &[u8] = &[0xC0, 0x81, 0x80, 0x00];
+const EMPTY_RESPONSE_NO_FUNC_CODE: &[u8] = &[0xC0, 0x81, 0x80, 0x01];
+const EMPTY_RESPONSE_OBJECT_UNKNOWN: &[u8] = &[0xC0, 0x81, 0x80, 0x02];
+const EMPTY_RESPONSE_PARAM_ERROR: &[u8] = &[0xC0, 0x81, 0x80, 0x04];
+
+#[tokio::test]
+async fn starts_all_applications() {
+    let mut harness = new_harness(get_default_config());
+
+    // START_APPLICATION g90v1 all objects
+    harness
+        .test_request_response(&[0xC0, 0x11, 90, 1, 0x06], EMPTY_RESPONSE)
+        .await;
+
+    harness.check_events(&[Event::StartApplication(ApplicationIdentifier::All)]);
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn stops_named_application() {
+    let mut harness = new_harness(get_default_config());
+
+    // STOP_APPLICATION g90v1 free-format "plc"
+    harness
+        .test_request_response(
+            &[0xC0, 0x12, 90, 1, 0x5B, 0x01, 0x03, 0x00, b'p', b'l', b'c'],
+            EMPTY_RESPONSE,
+        )
+        .await;
+
+    harness.check_events(&[Event::StopApplication(ApplicationIdentifier::Name(
+        "plc".to_string(),
+    ))]);
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn calls_application_once_per_identifier() {
+    let mut harness = new_harness(get_default_config());
+
+    // INITIALIZE_APPLICATION g90v1 "a", g90v1 "b"
+    harness
+        .test_request_response(
+            &[
+                0xC0, 0x10, 90, 1, 0x5B, 0x01, 0x01, 0x00, b'a', 90, 1, 0x5B, 0x01, 0x01, 0x00,
+                b'b',
+            ],
+            EMPTY_RESPONSE,
+        )
+        .await;
+
+    harness.check_events(&[
+        Event::InitializeApplication(ApplicationIdentifier::Name("a".to_string())),
+        Event::InitializeApplication(ApplicationIdentifier::Name("b".to_string())),
+    ]);
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn sets_iin2_when_application_rejects_request() {
+    let mut harness = new_harness(get_default_config());
+
+    harness.application_data.lock().unwrap().application_error = Some(RequestError::NotSupported);
+    harness
+        .test_request_response(&[0xC0, 0x11, 90, 1, 0x06], EMPTY_RESPONSE_NO_FUNC_CODE)
+        .await;
+
+    harness.application_data.lock().unwrap().application_error = Some(RequestError::ParameterError);
+    harness
+        .test_request_response(&[0xC1, 0x12, 90, 1, 0x06], &[0xC1, 0x81, 0x80, 0x04])
+        .await;
+
+    harness.check_events(&[
+        Event::StartApplication(ApplicationIdentifier::All),
+        Event::StopApplication(ApplicationIdentifier::All),
+    ]);
+}
+
+#[tokio::test]
+async fn rejects_request_without_application_identifier() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(&[0xC0, 0x11], EMPTY_RESPONSE_PARAM_ERROR)
+        .await;
+
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn rejects_unsupported_object_headers() {
+    let mut harness = new_harness(get_default_config());
+
+    // START_APPLICATION g1v0 all objects
+    harness
+        .test_request_response(&[0xC0, 0x11, 1, 0, 0x06], EMPTY_RESPONSE_OBJECT_UNKNOWN)
+        .await;
+
+    harness.check_no_events();
+}
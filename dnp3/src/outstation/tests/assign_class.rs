@@ -0,0 +1,180 @@
+use crate::app::measurement::*;
+use crate::app::*;
+use crate::outstation::database::*;
+use crate::outstation::tests::harness::*;
+use crate::outstation::{ClassAssignment, PointRange};
+
+const EMPTY_RESPONSE: &[u8] = &[0xC0, 0x81, 0x80, 0x00];
+const EMPTY_RESPONSE_PARAM_ERROR: &[u8] = &[0xC0, 0x81, 0x80, 0x04];
+const EMPTY_RESPONSE_OBJECT_UNKNOWN: &[u8] = &[0xC0, 0x81, 0x80, 0x02];
+const READ_CLASS_1: &[u8] = &[0xC1, 0x01, 60, 2, 0x06];
+const EMPTY_RESPONSE_SEQ1: &[u8] = &[0xC1, 0x81, 0x80, 0x00];
+
+fn update_binary(harness: &mut OutstationHarness, index: u16) {
+    harness.handle.database.transaction(|db| {
+        db.update(
+            index,
+            &BinaryInput::new(true, Flags::ONLINE, Time::Synchronized(Timestamp::new(0))),
+            UpdateOptions::detect_event(),
+        )
+    });
+}
+
+#[tokio::test]
+async fn assigns_class_to_all_points_of_a_type() {
+    let mut harness = new_harness(get_default_config());
+    harness.handle.database.transaction(|db| {
+        db.add(0, None, BinaryInputConfig::default());
+    });
+
+    // g60v2 (class 1), g1v0 all objects
+    harness
+        .test_request_response(&[0xC0, 0x16, 60, 2, 0x06, 1, 0, 0x06], EMPTY_RESPONSE)
+        .await;
+
+    harness.check_events(&[Event::ClassAssigned(ClassAssignment {
+        point_type: PointType::BinaryInput,
+        range: PointRange::All,
+        class: Some(EventClass::Class1),
+    })]);
+
+    // the point now produces class 1 events
+    update_binary(&mut harness, 0);
+    harness
+        .test_request_response(
+            READ_CLASS_1,
+            &[0xE1, 0x81, 0x80, 0x00, 2, 1, 0x28, 1, 0, 0, 0, 0x81],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn class_zero_disables_events_for_a_range() {
+    let mut harness = new_harness(get_default_config());
+    harness.handle.database.transaction(|db| {
+        db.add(0, Some(EventClass::Class1), BinaryInputConfig::default());
+        db.add(1, Some(EventClass::Class1), BinaryInputConfig::default());
+    });
+
+    // g60v1 (class 0), g1v0 range [1, 1]
+    harness
+        .test_request_response(&[0xC0, 0x16, 60, 1, 0x06, 1, 0, 0x00, 1, 1], EMPTY_RESPONSE)
+        .await;
+
+    harness.check_events(&[Event::ClassAssigned(ClassAssignment {
+        point_type: PointType::BinaryInput,
+        range: PointRange::Range(1, 1),
+        class: None,
+    })]);
+
+    update_binary(&mut harness, 1);
+    harness
+        .test_request_response(READ_CLASS_1, EMPTY_RESPONSE_SEQ1)
+        .await;
+}
+
+#[tokio::test]
+async fn each_class_header_applies_to_the_headers_that_follow() {
+    let mut harness = new_harness(get_default_config());
+
+    // g60v3 (class 2), g30v0 all objects, g20v0 all objects, g60v4 (class 3), g40v0 all objects
+    harness
+        .test_request_response(
+            &[
+                0xC0, 0x16, 60, 3, 0x06, 30, 0, 0x06, 20, 0, 0x06, 60, 4, 0x06, 40, 0, 0x06,
+            ],
+            EMPTY_RESPONSE,
+        )
+        .await;
+
+    harness.check_events(&[
+        Event::ClassAssigned(ClassAssignment {
+            point_type: PointType::AnalogInput,
+            range: PointRange::All,
+            class: Some(EventClass::Class2),
+        }),
+        Event::ClassAssigned(ClassAssignment {
+            point_type: PointType::Counter,
+            range: PointRange::All,
+            class: Some(EventClass::Class2),
+        }),
+        Event::ClassAssigned(ClassAssignment {
+            point_type: PointType::AnalogOutputStatus,
+            range: PointRange::All,
+            class: Some(EventClass::Class3),
+        }),
+    ]);
+}
+
+#[tokio::test]
+async fn point_header_without_class_header_is_rejected() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(&[0xC0, 0x16, 1, 0, 0x06], EMPTY_RESPONSE_PARAM_ERROR)
+        .await;
+
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn range_with_missing_points_is_reported_as_parameter_error() {
+    let mut harness = new_harness(get_default_config());
+    harness.handle.database.transaction(|db| {
+        db.add(0, None, BinaryInputConfig::default());
+        db.add(1, None, BinaryInputConfig::default());
+    });
+
+    // g60v2 (class 1), g1v0 range [0, 0], g1v0 range [1, 2]
+    harness
+        .test_request_response(
+            &[0xC0, 0x16, 60, 2, 0x06, 1, 0, 0x00, 0, 0, 1, 0, 0x00, 1, 2],
+            EMPTY_RESPONSE_PARAM_ERROR,
+        )
+        .await;
+
+    // nothing is applied, not even the valid header
+    harness.check_no_events();
+    update_binary(&mut harness, 0);
+    update_binary(&mut harness, 1);
+    harness
+        .test_request_response(READ_CLASS_1, EMPTY_RESPONSE_SEQ1)
+        .await;
+}
+
+#[tokio::test]
+async fn event_types_are_rejected_as_unknown_objects() {
+    let mut harness = new_harness(get_default_config());
+
+    // g60v2 (class 1), g2v0 all objects
+    harness
+        .test_request_response(
+            &[0xC0, 0x16, 60, 2, 0x06, 2, 0, 0x06],
+            EMPTY_RESPONSE_OBJECT_UNKNOWN,
+        )
+        .await;
+
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn database_can_change_class_at_runtime() {
+    let mut harness = new_harness(get_default_config());
+    let (exists, missing) = harness.handle.database.transaction(|db| {
+        db.add(0, None, BinaryInputConfig::default());
+        (
+            db.set_class(PointType::BinaryInput, 0, Some(EventClass::Class1)),
+            db.set_class(PointType::BinaryInput, 1, Some(EventClass::Class1)),
+        )
+    });
+    assert!(exists);
+    assert!(!missing);
+
+    update_binary(&mut harness, 0);
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 60, 2, 0x06],
+            &[0xE0, 0x81, 0x80, 0x00, 2, 1, 0x28, 1, 0, 0, 0, 0x81],
+        )
+        .await;
+}
@@ -0,0 +1,251 @@
+use crate::app::*;
+use crate::outstation::database::AttrDefError;
+use crate::outstation::tests::harness::*;
+
+const EMPTY_RESPONSE: &[u8] = &[0xC0, 0x81, 0x80, 0x00];
+const EMPTY_RESPONSE_OBJECT_UNKNOWN: &[u8] = &[0xC0, 0x81, 0x80, 0x02];
+const EMPTY_RESPONSE_PARAM_ERROR: &[u8] = &[0xC0, 0x81, 0x80, 0x04];
+
+const MANUFACTURER_NAME: u8 = 252;
+const MAX_TX_FRAGMENT_SIZE: u8 = 240;
+
+fn define_attributes(harness: &mut OutstationHarness) {
+    harness.handle.database.transaction(|db| {
+        db.define_attr(
+            AttrProp::read_only(),
+            OwnedAttribute::new(
+                0,
+                MANUFACTURER_NAME,
+                OwnedAttrValue::VisibleString("ACME".to_string()),
+            ),
+        )
+        .unwrap();
+        db.define_attr(
+            AttrProp::writable(),
+            OwnedAttribute::new(0, MAX_TX_FRAGMENT_SIZE, OwnedAttrValue::UnsignedInt(2048)),
+        )
+        .unwrap();
+    });
+}
+
+fn get_max_tx_fragment_size(harness: &mut OutstationHarness) -> Option<OwnedAttrValue> {
+    harness
+        .handle
+        .database
+        .transaction(|db| db.get_attr(0, MAX_TX_FRAGMENT_SIZE))
+        .map(|x| x.value)
+}
+
+#[tokio::test]
+async fn can_read_specific_attribute() {
+    let mut harness = new_harness(get_default_config());
+    define_attributes(&mut harness);
+
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 0x00, MANUFACTURER_NAME, 0x00, 0x00, 0x00],
+            &[
+                0xC0,
+                0x81,
+                0x80,
+                0x00,
+                0x00,
+                MANUFACTURER_NAME,
+                0x00,
+                0x00,
+                0x00,
+                0x01,
+                0x04,
+                b'A',
+                b'C',
+                b'M',
+                b'E',
+            ],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn can_read_all_attributes_and_list() {
+    let mut harness = new_harness(get_default_config());
+    define_attributes(&mut harness);
+
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 0x00, 0xFE, 0x06],
+            &[
+                0xC0,
+                0x81,
+                0x80,
+                0x00,
+                // max tx fragment size
+                0x00,
+                MAX_TX_FRAGMENT_SIZE,
+                0x00,
+                0x00,
+                0x00,
+                0x02,
+                0x04,
+                0x00,
+                0x08,
+                0x00,
+                0x00,
+                // manufacturer name
+                0x00,
+                MANUFACTURER_NAME,
+                0x00,
+                0x00,
+                0x00,
+                0x01,
+                0x04,
+                b'A',
+                b'C',
+                b'M',
+                b'E',
+                // attribute list
+                0x00,
+                0xFF,
+                0x00,
+                0x00,
+                0x00,
+                0xFE,
+                0x04,
+                MAX_TX_FRAGMENT_SIZE,
+                0x01,
+                MANUFACTURER_NAME,
+                0x00,
+            ],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn reading_undefined_attribute_returns_object_unknown() {
+    let mut harness = new_harness(get_default_config());
+    define_attributes(&mut harness);
+
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 0x00, 0xF9, 0x00, 0x00, 0x00],
+            EMPTY_RESPONSE_OBJECT_UNKNOWN,
+        )
+        .await;
+
+    // no attributes are defined in set 1
+    harness
+        .test_request_response(
+            &[0xC1, 0x01, 0x00, 0xFE, 0x00, 0x01, 0x01],
+            &[0xC1, 0x81, 0x80, 0x02],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn can_write_writable_attribute() {
+    let mut harness = new_harness(get_default_config());
+    define_attributes(&mut harness);
+
+    harness
+        .test_request_response(
+            &[
+                0xC0,
+                0x02,
+                0x00,
+                MAX_TX_FRAGMENT_SIZE,
+                0x00,
+                0x00,
+                0x00,
+                0x02,
+                0x04,
+                0x00,
+                0x04,
+                0x00,
+                0x00,
+            ],
+            EMPTY_RESPONSE,
+        )
+        .await;
+
+    harness.check_events(&[Event::WriteDeviceAttr(0, MAX_TX_FRAGMENT_SIZE)]);
+    assert_eq!(
+        get_max_tx_fragment_size(&mut harness),
+        Some(OwnedAttrValue::UnsignedInt(1024))
+    );
+}
+
+#[tokio::test]
+async fn rejects_write_of_read_only_attribute() {
+    let mut harness = new_harness(get_default_config());
+    define_attributes(&mut harness);
+
+    harness
+        .test_request_response(
+            &[
+                0xC0,
+                0x02,
+                0x00,
+                MANUFACTURER_NAME,
+                0x00,
+                0x00,
+                0x00,
+                0x01,
+                0x01,
+                b'X',
+            ],
+            EMPTY_RESPONSE_PARAM_ERROR,
+        )
+        .await;
+
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn rejects_write_with_wrong_data_type() {
+    let mut harness = new_harness(get_default_config());
+    define_attributes(&mut harness);
+
+    harness
+        .test_request_response(
+            &[
+                0xC0,
+                0x02,
+                0x00,
+                MAX_TX_FRAGMENT_SIZE,
+                0x00,
+                0x00,
+                0x00,
+                0x01,
+                0x01,
+                b'X',
+            ],
+            EMPTY_RESPONSE_PARAM_ERROR,
+        )
+        .await;
+
+    harness.check_no_events();
+    assert_eq!(
+        get_max_tx_fragment_size(&mut harness),
+        Some(OwnedAttrValue::UnsignedInt(2048))
+    );
+}
+
+#[tokio::test]
+async fn cannot_define_reserved_attributes() {
+    let harness = new_harness(get_default_config());
+    harness.handle.database.transaction(|db| {
+        assert_eq!(
+            db.define_attr(
+                AttrProp::read_only(),
+                OwnedAttribute::new(0, 255, OwnedAttrValue::AttrList(Vec::new())),
+            ),
+            Err(AttrDefError::ReservedVariation(255))
+        );
+        assert_eq!(
+            db.define_attr(
+                AttrProp::read_only(),
+                OwnedAttribute::new(0, 252, OwnedAttrValue::OctetString(vec![0; 256])),
+            ),
+            Err(AttrDefError::ValueTooLong(256))
+        );
+    });
+}
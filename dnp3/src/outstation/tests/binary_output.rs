@@ -0,0 +1,105 @@
+use crate::app::measurement::*;
+use crate::app::Timestamp;
+use crate::outstation::database::*;
+use crate::outstation::tests::harness::*;
+use crate::outstation::traits::RequestError;
+
+const EMPTY_RESPONSE: &[u8] = &[0xC0, 0x81, 0x80, 0x00];
+
+fn add_outputs(harness: &mut OutstationHarness, flags: Flags) {
+    harness.handle.database.transaction(|db| {
+        for (index, value) in [true, false, true].into_iter().enumerate() {
+            db.add(
+                index as u16,
+                None,
+                BinaryOutputStatusConfig::new(
+                    StaticBinaryOutputStatusVariation::Group10Var1,
+                    EventBinaryOutputStatusVariation::Group11Var1,
+                ),
+            );
+            db.update(
+                index as u16,
+                &BinaryOutputStatus::new(value, flags, Time::Synchronized(Timestamp::new(0))),
+                UpdateOptions::default(),
+            );
+        }
+    });
+}
+
+#[tokio::test]
+async fn serves_online_outputs_in_packed_format() {
+    let mut harness = new_harness(get_default_config());
+    add_outputs(&mut harness, Flags::ONLINE);
+
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 10, 0, 0x06],
+            &[0xC0, 0x81, 0x80, 0x00, 10, 1, 0x01, 0, 0, 2, 0, 0b101],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn promotes_packed_format_to_flags_when_not_online() {
+    let mut harness = new_harness(get_default_config());
+    add_outputs(&mut harness, Flags::COMM_LOST);
+
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 10, 0, 0x06],
+            &[
+                0xC0, 0x81, 0x80, 0x00, 10, 2, 0x01, 0, 0, 2, 0, 0x84, 0x04, 0x84,
+            ],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn delivers_packed_write_to_application() {
+    let mut harness = new_harness(get_default_config());
+
+    // WRITE g10v1 with 1-byte start/stop [0, 2]
+    harness
+        .test_request_response(&[0xC0, 0x02, 10, 1, 0x00, 0, 2, 0b101], EMPTY_RESPONSE)
+        .await;
+    // WRITE g10v1 with 2-byte start/stop [300, 301]
+    harness
+        .test_request_response(
+            &[0xC1, 0x02, 10, 1, 0x01, 0x2C, 0x01, 0x2D, 0x01, 0b10],
+            &[0xC1, 0x81, 0x80, 0x00],
+        )
+        .await;
+
+    harness.check_events(&[
+        Event::WriteBinaryOutputs(vec![(true, 0), (false, 1), (true, 2)]),
+        Event::WriteBinaryOutputs(vec![(false, 300), (true, 301)]),
+    ]);
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn sets_iin2_when_application_rejects_packed_write() {
+    let mut harness = new_harness(get_default_config());
+
+    harness.application_data.lock().unwrap().application_error = Some(RequestError::NotSupported);
+    harness
+        .test_request_response(
+            &[0xC0, 0x02, 10, 1, 0x00, 0, 0, 0x01],
+            &[0xC0, 0x81, 0x80, 0x01],
+        )
+        .await;
+
+    harness.application_data.lock().unwrap().application_error = Some(RequestError::ParameterError);
+    harness
+        .test_request_response(
+            &[0xC1, 0x02, 10, 1, 0x00, 0, 0, 0x01],
+            &[0xC1, 0x81, 0x80, 0x04],
+        )
+        .await;
+
+    harness.check_events(&[
+        Event::WriteBinaryOutputs(vec![(true, 0)]),
+        Event::WriteBinaryOutputs(vec![(true, 0)]),
+    ]);
+    harness.check_no_events();
+}
@@ -0,0 +1,156 @@
+use crate::app::control::CommandStatus;
+use crate::app::measurement::*;
+use crate::app::*;
+use crate::outstation::config::CommandEventConfig;
+use crate::outstation::database::*;
+use crate::outstation::tests::harness::*;
+
+// direct operate, seq == 0, g41v2 - count == 1, index == 7, value = 513, status == SUCCESS
+const DIRECT_OPERATE_G41V2: &[u8] = &[0xC0, 0x05, 41, 2, 0x17, 0x01, 0x07, 0x01, 0x02, 0x00];
+// direct operate, seq == 0, g12v1 - count == 1, index == 3, LATCH_ON, count == 1, on/off == 1000
+const DIRECT_OPERATE_G12V1: &[u8] = &[
+    0xC0, 0x05, 12, 1, 0x17, 0x01, 0x03, 0x03, 0x01, 0xE8, 0x03, 0x00, 0x00, 0xE8, 0x03, 0x00,
+    0x00, 0x00,
+];
+const READ_CLASS_1: &[u8] = &[0xC1, 0x01, 60, 2, 0x06];
+const EMPTY_RESPONSE_SEQ1: &[u8] = &[0xC1, 0x81, 0x80, 0x00];
+
+fn enabled_config() -> crate::outstation::OutstationConfig {
+    let mut config = get_default_config();
+    config.command_events = CommandEventConfig::enabled(EventClass::Class1);
+    config
+}
+
+fn with_response_header(request: &[u8], iin1: u8) -> Vec<u8> {
+    let mut response = vec![0xC0, 0x81, iin1, 0x00];
+    response.extend_from_slice(&request[2..]);
+    response
+}
+
+#[tokio::test]
+async fn command_events_are_disabled_by_default() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(
+            DIRECT_OPERATE_G41V2,
+            &with_response_header(DIRECT_OPERATE_G41V2, 0x80),
+        )
+        .await;
+
+    harness
+        .test_request_response(READ_CLASS_1, EMPTY_RESPONSE_SEQ1)
+        .await;
+}
+
+#[tokio::test]
+async fn analog_operate_produces_analog_output_command_event() {
+    let mut config = enabled_config();
+    config.command_events.analog_variation = EventAnalogOutputCommandVariation::Group43Var2;
+    let mut harness = new_harness(config);
+
+    harness
+        .test_request_response(
+            DIRECT_OPERATE_G41V2,
+            &with_response_header(DIRECT_OPERATE_G41V2, 0x82),
+        )
+        .await;
+
+    // g43v2 [7] = 513, status == SUCCESS
+    harness
+        .test_request_response(
+            READ_CLASS_1,
+            &[
+                0xE1, 0x81, 0x80, 0x00, 43, 2, 0x28, 0x01, 0x00, 0x07, 0x00, 0x00, 0x01, 0x02,
+            ],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn binary_operate_produces_binary_output_command_event() {
+    let mut harness = new_harness(enabled_config());
+
+    harness
+        .test_request_response(
+            DIRECT_OPERATE_G12V1,
+            &with_response_header(DIRECT_OPERATE_G12V1, 0x82),
+        )
+        .await;
+
+    // g13v1 [3] = commanded state ON, status == SUCCESS
+    harness
+        .test_request_response(
+            READ_CLASS_1,
+            &[
+                0xE1, 0x81, 0x80, 0x00, 13, 1, 0x28, 0x01, 0x00, 0x03, 0x00, 0x80,
+            ],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn select_does_not_produce_command_events() {
+    let mut harness = new_harness(enabled_config());
+
+    // select, seq == 0, g41v2 - count == 1, index == 7, value = 513, status == SUCCESS
+    let select: &[u8] = &[0xC0, 0x03, 41, 2, 0x17, 0x01, 0x07, 0x01, 0x02, 0x00];
+    harness
+        .test_request_response(select, &with_response_header(select, 0x80))
+        .await;
+
+    harness
+        .test_request_response(READ_CLASS_1, EMPTY_RESPONSE_SEQ1)
+        .await;
+}
+
+#[tokio::test]
+async fn can_record_command_events_from_the_database() {
+    let mut harness = new_harness(get_default_config());
+
+    let recorded = harness.handle.database.transaction(|db| {
+        db.record_binary_output_command_event(
+            5,
+            EventClass::Class1,
+            EventBinaryOutputCommandVariation::Group13Var2,
+            BinaryOutputCommandEvent::new(
+                false,
+                CommandStatus::HardwareError,
+                Time::Synchronized(Timestamp::new(0)),
+            ),
+        )
+    });
+    assert!(recorded);
+
+    // g13v2 [5] = commanded state OFF, status == HARDWARE_ERROR, t = 0
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 13, 0, 0x06],
+            &[
+                0xE0, 0x81, 0x80, 0x00, 13, 2, 0x28, 0x01, 0x00, 0x05, 0x00, 0x06, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn command_events_are_not_recorded_without_buffer_space() {
+    let mut config = enabled_config();
+    config.event_buffer_config.max_analog_output_command = 0;
+    let harness = new_harness(config);
+
+    let recorded = harness.handle.database.transaction(|db| {
+        db.record_analog_output_command_event(
+            0,
+            EventClass::Class1,
+            EventAnalogOutputCommandVariation::Group43Var1,
+            AnalogOutputCommandEvent::new(
+                1.0,
+                CommandStatus::Success,
+                Time::Synchronized(Timestamp::new(0)),
+            ),
+        )
+    });
+    assert!(!recorded);
+}
@@ -1,5 +1,6 @@
 use tokio::time::Duration;
 
+use crate::app::control::{Group12Var1, OpType};
 use crate::app::variations::Group41Var2;
 use crate::app::FunctionCode;
 use crate::link::header::BroadcastConfirmMode;
@@ -41,6 +42,27 @@ const RESPONSE_SEQ1_G41V2_INDEX8_NO_SELECT: &[u8] = &[
 const RESPONSE_SEQ1_G41V2_SELECT_TIMEOUT: &[u8] = &[
     0xC1, 0x81, 0x80, 0x00, 41, 2, 0x17, 0x1, 0x07, 0x01, 0x02, 0x01,
 ];
+// direct operate, seq == 0, g12v2 - count == 1, LATCH_ON, count == 1, on/off == 1000, status == SUCCESS
+// followed by g12v3 - start == 2, stop == 5, mask == 0b1101 (indices 2, 4, and 5)
+const DIRECT_OPERATE_SEQ0_PATTERN: &[u8] = &[
+    0xC0, 0x05, 12, 2, 0x07, 0x01, 0x03, 0x01, 0xE8, 0x03, 0x00, 0x00, 0xE8, 0x03, 0x00, 0x00,
+    0x00, 12, 3, 0x00, 0x02, 0x05, 0b1101,
+];
+// response, seq == 0, restart IIN + echo of the pattern control block and mask
+const RESPONSE_SEQ0_PATTERN_SUCCESS: &[u8] = &[
+    0xC0, 0x81, 0x80, 0x00, 12, 2, 0x07, 0x01, 0x03, 0x01, 0xE8, 0x03, 0x00, 0x00, 0xE8, 0x03,
+    0x00, 0x00, 0x00, 12, 3, 0x00, 0x02, 0x05, 0b1101,
+];
+// direct operate, seq == 0, g12v2 without the g12v3 mask that must follow it
+const DIRECT_OPERATE_SEQ0_PATTERN_WITHOUT_MASK: &[u8] = &[
+    0xC0, 0x05, 12, 2, 0x07, 0x01, 0x03, 0x01, 0xE8, 0x03, 0x00, 0x00, 0xE8, 0x03, 0x00, 0x00, 0x00,
+];
+// response, seq == 0, restart IIN + PARAMETER_ERROR
+const RESPONSE_SEQ0_PARAMETER_ERROR: &[u8] = &[0xC0, 0x81, 0x80, 0x04];
+
+fn pattern_control() -> Group12Var1 {
+    Group12Var1::from_op_type(OpType::LatchOn)
+}
 
 #[tokio::test]
 async fn performs_direct_operate() {
@@ -255,3 +277,43 @@ async fn accept_two_identical_selects_before_operate() {
         Event::EndControls,
     ]);
 }
+
+#[tokio::test]
+async fn expands_pattern_mask_into_individual_controls() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(DIRECT_OPERATE_SEQ0_PATTERN, RESPONSE_SEQ0_PATTERN_SUCCESS)
+        .await;
+
+    harness.check_events(&[
+        Event::BeginControls,
+        Event::Operate(
+            Control::G12V1(pattern_control(), 2),
+            OperateType::DirectOperate,
+        ),
+        Event::Operate(
+            Control::G12V1(pattern_control(), 4),
+            OperateType::DirectOperate,
+        ),
+        Event::Operate(
+            Control::G12V1(pattern_control(), 5),
+            OperateType::DirectOperate,
+        ),
+        Event::EndControls,
+    ]);
+}
+
+#[tokio::test]
+async fn rejects_pattern_control_block_without_mask() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(
+            DIRECT_OPERATE_SEQ0_PATTERN_WITHOUT_MASK,
+            RESPONSE_SEQ0_PARAMETER_ERROR,
+        )
+        .await;
+
+    harness.check_no_events();
+}
@@ -0,0 +1,227 @@
+use crate::app::*;
+use crate::outstation::database::*;
+use crate::outstation::tests::harness::*;
+
+const UUID: [u8; 16] = [0x11; 16];
+const DATA_SET_ID: u16 = 1;
+
+const EMPTY_RESPONSE: &[u8] = &[0xC0, 0x81, 0x80, 0x00];
+
+fn prototype() -> DataSetPrototype {
+    DataSetPrototype::new(
+        0,
+        UUID,
+        vec![DataSetElement::data_element(
+            "kwh",
+            DataSetType::UnsignedInt,
+            4,
+        )],
+    )
+}
+
+fn descriptor() -> DataSetDescriptor {
+    DataSetDescriptor::new(
+        DATA_SET_ID,
+        vec![
+            DataSetElement::name("meter"),
+            DataSetElement::data_element("label", DataSetType::VisibleString, 4),
+            DataSetElement::prototype(UUID),
+        ],
+    )
+}
+
+fn data_set(label: &str) -> DataSet {
+    DataSet::new(
+        DATA_SET_ID,
+        Timestamp::new(1),
+        vec![
+            DataSetValue::VisibleString(label.to_string()),
+            DataSetValue::UnsignedInt(7),
+        ],
+    )
+}
+
+fn define_data_set(harness: &mut OutstationHarness) {
+    harness.handle.database.transaction(|db| {
+        db.define_data_set_prototype(prototype()).unwrap();
+        db.define_data_set(descriptor(), Some(EventClass::Class1))
+            .unwrap();
+    });
+}
+
+fn response(control: u8, iin1: u8, objects: &[&[u8]]) -> Vec<u8> {
+    let mut response = vec![control, 0x81, iin1, 0x00];
+    for object in objects {
+        response.extend_from_slice(object);
+    }
+    response
+}
+
+/// free-format header for a single object
+fn header(group: u8, object: &[u8]) -> Vec<u8> {
+    let mut header = vec![group, 0x01, 0x5B, 0x01, object.len() as u8, 0x00];
+    header.extend_from_slice(object);
+    header
+}
+
+fn prototype_object() -> Vec<u8> {
+    let mut object = vec![0x02, 0x00, 0x00, 0x10];
+    object.extend_from_slice(&UUID);
+    object.extend_from_slice(&[0x06, 0x05, 0x02, 0x04, b'k', b'w', b'h']);
+    header(85, &object)
+}
+
+fn descriptor_object() -> Vec<u8> {
+    let mut object = vec![
+        0x02, 0x01, 0x00, // id
+        0x08, 0x04, 0x00, 0x00, b'm', b'e', b't', b'e', b'r', // name
+        0x08, 0x05, 0x01, 0x04, b'l', b'a', b'b', b'e', b'l', // data element
+        0x13, 0x06, 0x00, 0x00, // prototype
+    ];
+    object.extend_from_slice(&UUID);
+    header(86, &object)
+}
+
+fn data_set_object(group: u8) -> Vec<u8> {
+    header(
+        group,
+        &[
+            0x02, 0x01, 0x00, // id
+            0x06, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, // time
+            0x02, b'a', b'b', // label
+            0x04, 0x07, 0x00, 0x00, 0x00, // kwh
+        ],
+    )
+}
+
+#[tokio::test]
+async fn can_read_prototypes_and_descriptors() {
+    let mut harness = new_harness(get_default_config());
+    define_data_set(&mut harness);
+
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 85, 1, 0x06, 86, 1, 0x06],
+            &response(0xC0, 0x80, &[&prototype_object(), &descriptor_object()]),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn present_value_is_only_reported_after_update() {
+    let mut harness = new_harness(get_default_config());
+    define_data_set(&mut harness);
+
+    harness
+        .test_request_response(&[0xC0, 0x01, 87, 1, 0x06], EMPTY_RESPONSE)
+        .await;
+
+    harness.handle.database.transaction(|db| {
+        db.update_data_set(&data_set("ab"), UpdateOptions::no_event())
+            .unwrap()
+    });
+
+    harness
+        .test_request_response(
+            &[0xC1, 0x01, 87, 1, 0x06],
+            &response(0xC1, 0x80, &[&data_set_object(87)]),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn class_zero_does_not_include_data_sets() {
+    let mut harness = new_harness(get_default_config());
+    define_data_set(&mut harness);
+    harness.handle.database.transaction(|db| {
+        db.update_data_set(&data_set("ab"), UpdateOptions::no_event())
+            .unwrap()
+    });
+
+    harness
+        .test_request_response(&[0xC0, 0x01, 60, 1, 0x06], EMPTY_RESPONSE)
+        .await;
+}
+
+#[tokio::test]
+async fn changed_data_set_produces_snapshot_event() {
+    let mut harness = new_harness(get_default_config());
+    define_data_set(&mut harness);
+
+    harness.handle.database.transaction(|db| {
+        db.update_data_set(&data_set("ab"), UpdateOptions::detect_event())
+            .unwrap();
+        // no change, no event
+        db.update_data_set(&data_set("ab"), UpdateOptions::detect_event())
+            .unwrap();
+    });
+
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 60, 2, 0x06],
+            &response(0xE0, 0x80, &[&data_set_object(88)]),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn each_snapshot_event_has_its_own_header() {
+    let mut harness = new_harness(get_default_config());
+    define_data_set(&mut harness);
+
+    harness.handle.database.transaction(|db| {
+        db.update_data_set(&data_set("ab"), UpdateOptions::detect_event())
+            .unwrap();
+        db.update_data_set(&data_set("ab"), UpdateOptions::new(true, EventMode::Force))
+            .unwrap();
+    });
+
+    let event = data_set_object(88);
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 88, 1, 0x06],
+            &response(0xE0, 0x80, &[&event, &event]),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn rejects_values_that_do_not_match_descriptor() {
+    let mut harness = new_harness(get_default_config());
+    define_data_set(&mut harness);
+
+    harness.handle.database.transaction(|db| {
+        // label exceeds the maximum length of 4
+        assert_eq!(
+            db.update_data_set(&data_set("abcde"), UpdateOptions::detect_event()),
+            Err(DataSetDefError::TypeMismatch(0))
+        );
+        assert_eq!(
+            db.update_data_set(
+                &DataSet::new(DATA_SET_ID, Timestamp::new(1), Vec::new()),
+                UpdateOptions::detect_event()
+            ),
+            Err(DataSetDefError::CountMismatch(2, 0))
+        );
+        assert_eq!(
+            db.update_data_set(
+                &DataSet::new(7, Timestamp::new(1), Vec::new()),
+                UpdateOptions::detect_event()
+            ),
+            Err(DataSetDefError::UnknownDataSet(7))
+        );
+        assert_eq!(db.get_data_set(DATA_SET_ID), None);
+    });
+}
+
+#[tokio::test]
+async fn rejects_descriptor_with_unknown_prototype() {
+    let harness = new_harness(get_default_config());
+
+    harness.handle.database.transaction(|db| {
+        assert_eq!(
+            db.define_data_set(descriptor(), None),
+            Err(DataSetDefError::UnknownPrototype)
+        );
+    });
+}
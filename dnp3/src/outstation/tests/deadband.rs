@@ -0,0 +1,182 @@
+use crate::app::measurement::*;
+use crate::app::*;
+use crate::outstation::database::*;
+use crate::outstation::tests::harness::*;
+
+const EMPTY_RESPONSE: &[u8] = &[0xC0, 0x81, 0x80, 0x00];
+const EMPTY_RESPONSE_WITH_CLASS_1_EVENTS: &[u8] = &[0xC0, 0x81, 0x82, 0x00];
+const EMPTY_RESPONSE_PARAM_ERROR: &[u8] = &[0xC0, 0x81, 0x80, 0x04];
+
+fn create_analog_inputs(harness: &mut OutstationHarness) {
+    harness.handle.database.transaction(|db| {
+        // 32-bit static variation with the default deadband of zero
+        db.add(0, Some(EventClass::Class1), AnalogInputConfig::default());
+        // single-precision static variation with a configured deadband
+        db.add(
+            1,
+            Some(EventClass::Class1),
+            AnalogInputConfig::new(
+                StaticAnalogInputVariation::Group30Var5,
+                EventAnalogInputVariation::Group32Var5,
+                2.5,
+            ),
+        );
+    });
+}
+
+fn get_deadband(harness: &mut OutstationHarness, index: u16) -> Option<f64> {
+    harness
+        .handle
+        .database
+        .transaction(|db| db.get_analog_input_deadband(index))
+}
+
+#[tokio::test]
+async fn default_variation_follows_static_variation() {
+    let mut harness = new_harness(get_default_config());
+    create_analog_inputs(&mut harness);
+
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 34, 0, 0x06],
+            &[
+                0xC0, 0x81, 0x80, 0x00, // header
+                34, 2, 0x01, 0, 0, 0, 0, 0x00, 0x00, 0x00, 0x00, // g34v2 [0] = 0
+                34, 3, 0x01, 1, 0, 1, 0, 0x00, 0x00, 0x20, 0x40, // g34v3 [1] = 2.5
+            ],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn can_read_specific_variation_by_range() {
+    let mut harness = new_harness(get_default_config());
+    create_analog_inputs(&mut harness);
+
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 34, 1, 0x00, 0, 1],
+            &[
+                0xC0, 0x81, 0x80, 0x00, 34, 1, 0x01, 0, 0, 1, 0, 0x00, 0x00, 0x02, 0x00,
+            ],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn write_updates_deadband_and_notifies_application() {
+    let mut harness = new_harness(get_default_config());
+    create_analog_inputs(&mut harness);
+
+    // g34v3 [0] = 1.5 using a two byte count and prefix
+    harness
+        .test_request_response(
+            &[
+                0xC0, 0x02, 34, 3, 0x28, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x3F,
+            ],
+            EMPTY_RESPONSE,
+        )
+        .await;
+
+    harness.check_events(&[Event::AnalogInputDeadbandWritten(0, 1.5)]);
+    assert_eq!(get_deadband(&mut harness, 0), Some(1.5));
+    assert_eq!(get_deadband(&mut harness, 1), Some(2.5));
+}
+
+#[tokio::test]
+async fn write_by_range_updates_each_point() {
+    let mut harness = new_harness(get_default_config());
+    create_analog_inputs(&mut harness);
+
+    // g34v1 [0..1] = {3, 4}
+    harness
+        .test_request_response(
+            &[0xC0, 0x02, 34, 1, 0x00, 0, 1, 0x03, 0x00, 0x04, 0x00],
+            EMPTY_RESPONSE,
+        )
+        .await;
+
+    harness.check_events(&[
+        Event::AnalogInputDeadbandWritten(0, 3.0),
+        Event::AnalogInputDeadbandWritten(1, 4.0),
+    ]);
+}
+
+#[tokio::test]
+async fn write_to_unknown_point_is_rejected() {
+    let mut harness = new_harness(get_default_config());
+    create_analog_inputs(&mut harness);
+
+    // g34v2 [5] = 10
+    harness
+        .test_request_response(
+            &[
+                0xC0, 0x02, 34, 2, 0x28, 0x01, 0x00, 0x05, 0x00, 0x0A, 0x00, 0x00, 0x00,
+            ],
+            EMPTY_RESPONSE_PARAM_ERROR,
+        )
+        .await;
+
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn write_of_negative_deadband_is_rejected() {
+    let mut harness = new_harness(get_default_config());
+    create_analog_inputs(&mut harness);
+
+    // g34v3 [1] = -1.0
+    harness
+        .test_request_response(
+            &[
+                0xC0, 0x02, 34, 3, 0x28, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x80, 0xBF,
+            ],
+            EMPTY_RESPONSE_PARAM_ERROR,
+        )
+        .await;
+
+    harness.check_no_events();
+    assert_eq!(get_deadband(&mut harness, 1), Some(2.5));
+}
+
+#[tokio::test]
+async fn written_deadband_is_used_for_event_detection() {
+    let mut harness = new_harness(get_default_config());
+    create_analog_inputs(&mut harness);
+    harness.handle.database.transaction(|db| {
+        db.update(
+            0,
+            &AnalogInput::new(0.0, Flags::ONLINE, Time::Synchronized(Timestamp::new(0))),
+            UpdateOptions::default(),
+        )
+    });
+
+    // g34v2 [0] = 10
+    harness
+        .test_request_response(
+            &[
+                0xC0, 0x02, 34, 2, 0x28, 0x01, 0x00, 0x00, 0x00, 0x0A, 0x00, 0x00, 0x00,
+            ],
+            EMPTY_RESPONSE_WITH_CLASS_1_EVENTS,
+        )
+        .await;
+    harness.check_events(&[Event::AnalogInputDeadbandWritten(0, 10.0)]);
+
+    // only the initial update produces an event, the change within the new deadband does not
+    harness.handle.database.transaction(|db| {
+        db.update(
+            0,
+            &AnalogInput::new(5.0, Flags::ONLINE, Time::Synchronized(Timestamp::new(0))),
+            UpdateOptions::default(),
+        )
+    });
+
+    harness
+        .test_request_response(
+            &[0xC1, 0x01, 60, 2, 0x06],
+            &[
+                0xE1, 0x81, 0x80, 0x00, 32, 1, 0x28, 1, 0, 0, 0, 0x01, 0, 0, 0, 0,
+            ],
+        )
+        .await;
+}
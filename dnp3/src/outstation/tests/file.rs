@@ -0,0 +1,137 @@
+use crate::app::FileMode;
+use crate::outstation::tests::harness::*;
+
+const OPEN_FILE: &[u8] = &[
+    0xC0, 0x19, 0x46, 0x03, 0x5B, 0x01, 0x22, 0x00, 0x1A, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x04,
+    0x07, 0x00, 0x66, 0x69, 0x6C, 0x65, 0x2E, 0x74, 0x78, 0x74,
+];
+const OPEN_FILE_RESPONSE: &[u8] = &[
+    0xC0, 0x81, 0x80, 0x00, 0x46, 0x04, 0x5B, 0x01, 0x0D, 0x00, 0x01, 0x00, 0x00, 0x00, 0x03, 0x00,
+    0x00, 0x00, 0x00, 0x04, 0x07, 0x00, 0x00,
+];
+const OPEN_MISSING_FILE: &[u8] = &[
+    0xC0, 0x19, 0x46, 0x03, 0x5B, 0x01, 0x25, 0x00, 0x1A, 0x00, 0x0B, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x04,
+    0x07, 0x00, 0x6D, 0x69, 0x73, 0x73, 0x69, 0x6E, 0x67, 0x2E, 0x74, 0x78, 0x74,
+];
+const OPEN_MISSING_FILE_RESPONSE: &[u8] = &[
+    0xC0, 0x81, 0x80, 0x00, 0x46, 0x04, 0x5B, 0x01, 0x0D, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x07, 0x00, 0x03,
+];
+const READ_BLOCK: &[u8] = &[
+    0xC1, 0x01, 0x46, 0x05, 0x5B, 0x01, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+const READ_BLOCK_RESPONSE: &[u8] = &[
+    0xC1, 0x81, 0x80, 0x00, 0x46, 0x05, 0x5B, 0x01, 0x0B, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x80, 0x01, 0x02, 0x03,
+];
+const CLOSE_FILE: &[u8] = &[
+    0xC2, 0x1A, 0x46, 0x04, 0x5B, 0x01, 0x0D, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x07, 0x00, 0x00,
+];
+const CLOSE_FILE_RESPONSE: &[u8] = &[
+    0xC2, 0x81, 0x80, 0x00, 0x46, 0x04, 0x5B, 0x01, 0x0D, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00,
+];
+const AUTHENTICATE: &[u8] = &[
+    0xC0, 0x1D, 0x46, 0x02, 0x5B, 0x01, 0x14, 0x00, 0x0C, 0x00, 0x04, 0x00, 0x10, 0x00, 0x04, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x75, 0x73, 0x65, 0x72, 0x70, 0x61, 0x73, 0x73,
+];
+const AUTHENTICATE_RESPONSE: &[u8] = &[
+    0xC0, 0x81, 0x80, 0x00, 0x46, 0x02, 0x5B, 0x01, 0x0C, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x0C, 0x00,
+    0x00, 0x00, 0xAA, 0x00, 0x00, 0x00,
+];
+const AUTHENTICATE_BAD: &[u8] = &[
+    0xC1, 0x1D, 0x46, 0x02, 0x5B, 0x01, 0x15, 0x00, 0x0C, 0x00, 0x04, 0x00, 0x10, 0x00, 0x05, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x75, 0x73, 0x65, 0x72, 0x77, 0x72, 0x6F, 0x6E, 0x67,
+];
+const AUTHENTICATE_BAD_RESPONSE: &[u8] = &[
+    0xC1, 0x81, 0x80, 0x00, 0x46, 0x02, 0x5B, 0x01, 0x0C, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x0C, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+const WRITE_BLOCK: &[u8] = &[
+    0xC0, 0x02, 0x46, 0x05, 0x5B, 0x01, 0x0A, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80,
+    0x04, 0x05,
+];
+const WRITE_BLOCK_RESPONSE: &[u8] = &[
+    0xC0, 0x81, 0x80, 0x00, 0x46, 0x06, 0x5B, 0x01, 0x09, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x80, 0x00,
+];
+const GET_FILE_INFO: &[u8] = &[
+    0xC0, 0x1C, 0x46, 0x07, 0x5B, 0x01, 0x1C, 0x00, 0x14, 0x00, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x66, 0x69, 0x6C, 0x65,
+    0x2E, 0x74, 0x78, 0x74,
+];
+const GET_FILE_INFO_RESPONSE: &[u8] = &[
+    0xC0, 0x81, 0x80, 0x00, 0x46, 0x07, 0x5B, 0x01, 0x1C, 0x00, 0x14, 0x00, 0x08, 0x00, 0x01, 0x00,
+    0x03, 0x00, 0x00, 0x00, 0x34, 0x12, 0x00, 0x00, 0x00, 0x00, 0xA4, 0x01, 0x03, 0x00, 0x66, 0x69,
+    0x6C, 0x65, 0x2E, 0x74, 0x78, 0x74,
+];
+const NO_FUNCTION_SUPPORT_RESPONSE: &[u8] = &[0xC0, 0x81, 0x80, 0x01];
+
+#[tokio::test]
+async fn file_requests_are_not_supported_without_handler() {
+    let mut harness = new_harness(get_default_config());
+    harness
+        .test_request_response(OPEN_FILE, NO_FUNCTION_SUPPORT_RESPONSE)
+        .await;
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn can_open_read_and_close_file() {
+    let mut harness = new_harness_with_file_handler(get_default_config());
+    harness
+        .test_request_response(OPEN_FILE, OPEN_FILE_RESPONSE)
+        .await;
+    harness.check_events(&[Event::OpenFile(FileMode::Read)]);
+    harness
+        .test_request_response(READ_BLOCK, READ_BLOCK_RESPONSE)
+        .await;
+    harness.check_events(&[Event::ReadFileBlock(FILE_HANDLE, 0)]);
+    harness
+        .test_request_response(CLOSE_FILE, CLOSE_FILE_RESPONSE)
+        .await;
+    harness.check_events(&[Event::CloseFile(FILE_HANDLE)]);
+}
+
+#[tokio::test]
+async fn returns_status_when_file_cannot_be_opened() {
+    let mut harness = new_harness_with_file_handler(get_default_config());
+    harness
+        .test_request_response(OPEN_MISSING_FILE, OPEN_MISSING_FILE_RESPONSE)
+        .await;
+    harness.check_events(&[Event::OpenFile(FileMode::Read)]);
+}
+
+#[tokio::test]
+async fn returns_authentication_key_only_for_valid_credentials() {
+    let mut harness = new_harness_with_file_handler(get_default_config());
+    harness
+        .test_request_response(AUTHENTICATE, AUTHENTICATE_RESPONSE)
+        .await;
+    harness.check_events(&[Event::AuthenticateFile(true)]);
+    harness
+        .test_request_response(AUTHENTICATE_BAD, AUTHENTICATE_BAD_RESPONSE)
+        .await;
+    harness.check_events(&[Event::AuthenticateFile(false)]);
+}
+
+#[tokio::test]
+async fn passes_written_blocks_to_handler() {
+    let mut harness = new_harness_with_file_handler(get_default_config());
+    harness
+        .test_request_response(WRITE_BLOCK, WRITE_BLOCK_RESPONSE)
+        .await;
+    harness.check_events(&[Event::WriteFileBlock(FILE_HANDLE, 0, 2, true)]);
+}
+
+#[tokio::test]
+async fn responds_to_get_file_info_with_descriptor() {
+    let mut harness = new_harness_with_file_handler(get_default_config());
+    harness
+        .test_request_response(GET_FILE_INFO, GET_FILE_INFO_RESPONSE)
+        .await;
+    harness.check_no_events();
+}
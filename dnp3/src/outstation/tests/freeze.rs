@@ -1,9 +1,13 @@
+use std::time::Duration;
+
+use crate::app::Timestamp;
 use crate::outstation::tests::harness::*;
 use crate::outstation::{FreezeIndices, FreezeType};
 
 const EMPTY_RESPONSE: &[u8] = &[0xC0, 0x81, 0x80, 0x00];
 const EMPTY_RESPONSE_PARAM_ERROR: &[u8] = &[0xC0, 0x81, 0x80, 0x04];
 const EMPTY_RESPONSE_NO_FUNC_SUPPORTED: &[u8] = &[0xC0, 0x81, 0x80, 0x01];
+const ALL_COUNTERS: &[u8] = &[20, 0, 0x06];
 
 #[tokio::test]
 async fn immediate_freeze_all_counters() {
@@ -113,6 +117,37 @@ async fn freeze_and_clear_no_response_range_of_counters() {
     )]);
 }
 
+#[tokio::test]
+async fn immediate_freeze_limited_count_of_counters() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(&[0xC0, 0x07, 20, 0, 0x07, 4], EMPTY_RESPONSE)
+        .await;
+    harness
+        .test_request_response(&[0xC0, 0x07, 20, 0, 0x08, 0x00, 0x01], EMPTY_RESPONSE)
+        .await;
+
+    harness.check_events(&[
+        Event::Freeze(FreezeIndices::Range(0, 3), FreezeType::ImmediateFreeze),
+        Event::Freeze(FreezeIndices::Range(0, 255), FreezeType::ImmediateFreeze),
+    ]);
+}
+
+#[tokio::test]
+async fn freeze_count_of_zero_is_not_supported() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(
+            &[0xC0, 0x07, 20, 0, 0x07, 0],
+            EMPTY_RESPONSE_NO_FUNC_SUPPORTED,
+        )
+        .await;
+
+    harness.check_no_events();
+}
+
 #[tokio::test]
 async fn freeze_invalid_object() {
     let mut harness = new_harness(get_default_config());
@@ -129,3 +164,183 @@ async fn freeze_invalid_object() {
         FreezeType::ImmediateFreeze,
     )]);
 }
+
+fn freeze_at_time_request(function: u8, time: u64, interval: u32, counters: &[u8]) -> Vec<u8> {
+    let mut request = vec![0xC0, function, 50, 2, 0x07, 0x01];
+    request.extend_from_slice(&time.to_le_bytes()[0..6]);
+    request.extend_from_slice(&interval.to_le_bytes());
+    request.extend_from_slice(counters);
+    request
+}
+
+// tokio timers have millisecond granularity and may expire up to 1 ms late
+fn assert_elapsed(start: tokio::time::Instant, expected_ms: u64) {
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_millis(expected_ms));
+    assert!(elapsed <= Duration::from_millis(expected_ms + 1));
+}
+
+fn set_current_time(harness: &mut OutstationHarness, time: u64) {
+    harness.application_data.lock().unwrap().current_time = Some(Timestamp::new(time));
+}
+
+#[tokio::test]
+async fn freeze_at_time_with_zero_time_and_interval_freezes_once_immediately() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(
+            &freeze_at_time_request(0x0B, 0, 0, ALL_COUNTERS),
+            EMPTY_RESPONSE,
+        )
+        .await;
+
+    harness
+        .wait_for_events(&[Event::Freeze(FreezeIndices::All, FreezeType::FreezeAtTime)])
+        .await;
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn freeze_at_time_with_zero_time_freezes_periodically() {
+    let mut harness = new_harness(get_default_config());
+    tokio::time::pause();
+
+    let start = tokio::time::Instant::now();
+    harness
+        .test_request_response(
+            &freeze_at_time_request(0x0B, 0, 1000, &[20, 0, 0x00, 0, 10]),
+            EMPTY_RESPONSE,
+        )
+        .await;
+    harness.check_no_events();
+
+    for interval in 1..=3 {
+        harness
+            .wait_for_events(&[Event::Freeze(
+                FreezeIndices::Range(0, 10),
+                FreezeType::FreezeAtTime,
+            )])
+            .await;
+        assert_elapsed(start, 1000 * interval);
+    }
+}
+
+#[tokio::test]
+async fn freeze_at_time_freezes_once_at_future_time() {
+    let mut harness = new_harness(get_default_config());
+    set_current_time(&mut harness, 1000);
+    tokio::time::pause();
+
+    let start = tokio::time::Instant::now();
+    harness
+        .test_request_response(
+            &freeze_at_time_request(0x0B, 5000, 0, ALL_COUNTERS),
+            EMPTY_RESPONSE,
+        )
+        .await;
+
+    harness
+        .wait_for_events(&[Event::Freeze(FreezeIndices::All, FreezeType::FreezeAtTime)])
+        .await;
+    assert_elapsed(start, 4000);
+
+    // the schedule was only for a single freeze
+    tokio::time::sleep(Duration::from_secs(60)).await;
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn freeze_at_past_time_is_aligned_to_the_interval() {
+    let mut harness = new_harness(get_default_config());
+    set_current_time(&mut harness, 10_500);
+    tokio::time::pause();
+
+    let start = tokio::time::Instant::now();
+    harness
+        .test_request_response(
+            &freeze_at_time_request(0x0B, 1000, 1000, ALL_COUNTERS),
+            EMPTY_RESPONSE,
+        )
+        .await;
+
+    harness
+        .wait_for_events(&[Event::Freeze(FreezeIndices::All, FreezeType::FreezeAtTime)])
+        .await;
+    assert_elapsed(start, 500);
+}
+
+#[tokio::test]
+async fn freeze_at_time_no_response_schedules_freeze() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .send_and_process(&freeze_at_time_request(0x0C, 0, 0, ALL_COUNTERS))
+        .await;
+
+    harness
+        .wait_for_events(&[Event::Freeze(FreezeIndices::All, FreezeType::FreezeAtTime)])
+        .await;
+}
+
+#[tokio::test]
+async fn freeze_at_past_time_without_interval_is_rejected() {
+    let mut harness = new_harness(get_default_config());
+    set_current_time(&mut harness, 10_000);
+
+    harness
+        .test_request_response(
+            &freeze_at_time_request(0x0B, 5000, 0, ALL_COUNTERS),
+            EMPTY_RESPONSE_PARAM_ERROR,
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn freeze_at_time_is_rejected_if_current_time_is_unknown() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(
+            &freeze_at_time_request(0x0B, 5000, 1000, ALL_COUNTERS),
+            EMPTY_RESPONSE_PARAM_ERROR,
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn freeze_at_time_requires_g50v2() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(&[0xC0, 0x0B, 20, 0, 0x06], EMPTY_RESPONSE_PARAM_ERROR)
+        .await;
+}
+
+#[tokio::test]
+async fn freeze_at_time_requires_counters() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(
+            &freeze_at_time_request(0x0B, 0, 0, &[]),
+            EMPTY_RESPONSE_PARAM_ERROR,
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn freeze_at_time_rejects_invalid_object() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(
+            &freeze_at_time_request(0x0B, 0, 0, &[22, 0, 0x06]),
+            EMPTY_RESPONSE_NO_FUNC_SUPPORTED,
+        )
+        .await;
+
+    tokio::time::pause();
+    tokio::time::sleep(Duration::from_secs(60)).await;
+    harness.check_no_events();
+}
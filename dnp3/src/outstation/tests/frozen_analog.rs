@@ -0,0 +1,131 @@
+use crate::app::measurement::*;
+use crate::app::*;
+use crate::outstation::database::*;
+use crate::outstation::tests::harness::*;
+
+const READ_CLASS_0: &[u8] = &[0xC0, 0x01, 60, 1, 0x06];
+const EMPTY_RESPONSE: &[u8] = &[0xC0, 0x81, 0x80, 0x00];
+
+fn demand_value() -> FrozenAnalogInput {
+    FrozenAnalogInput::new(42.0, Flags::ONLINE, Time::Synchronized(Timestamp::new(0)))
+}
+
+fn create_frozen_analog(harness: &mut OutstationHarness, config: FrozenAnalogInputConfig) {
+    harness.handle.database.transaction(|db| {
+        db.add(0, Some(EventClass::Class1), config);
+    });
+}
+
+#[tokio::test]
+async fn class_zero_includes_frozen_analog_inputs() {
+    let mut harness = new_harness(get_default_config());
+    create_frozen_analog(&mut harness, FrozenAnalogInputConfig::default());
+
+    harness
+        .test_request_response(
+            READ_CLASS_0,
+            &[
+                0xC0, 0x81, 0x80, 0x00, // header
+                31, 1, 0x01, 0, 0, 0, 0, 0x02, 0, 0, 0, 0, // g31v1 [0] = 0, RESTART
+            ],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn class_zero_can_exclude_frozen_analog_inputs() {
+    let mut config = get_default_config();
+    config.class_zero.frozen_analog = false;
+    let mut harness = new_harness(config);
+    create_frozen_analog(&mut harness, FrozenAnalogInputConfig::default());
+
+    harness
+        .test_request_response(READ_CLASS_0, EMPTY_RESPONSE)
+        .await;
+}
+
+#[tokio::test]
+async fn can_read_specific_static_variation_by_range() {
+    let mut harness = new_harness(get_default_config());
+    create_frozen_analog(&mut harness, FrozenAnalogInputConfig::default());
+    harness.handle.database.transaction(|db| {
+        db.update(0, &demand_value(), UpdateOptions::no_event());
+    });
+
+    // g31v7 - single-precision with flag
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 31, 7, 0x00, 0, 0],
+            &[
+                0xC0, 0x81, 0x80, 0x00, 31, 7, 0x01, 0, 0, 0, 0, 0x01, 0x00, 0x00, 0x28, 0x42,
+            ],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn update_produces_event_in_configured_variation() {
+    let mut harness = new_harness(get_default_config());
+    create_frozen_analog(
+        &mut harness,
+        FrozenAnalogInputConfig::new(
+            StaticFrozenAnalogInputVariation::Group31Var1,
+            EventFrozenAnalogInputVariation::Group33Var2,
+            0.0,
+        ),
+    );
+    harness.handle.database.transaction(|db| {
+        db.update(0, &demand_value(), UpdateOptions::detect_event());
+    });
+
+    // g33v2 - 16-bit with flag
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 60, 2, 0x06],
+            &[
+                0xE0, 0x81, 0x80, 0x00, 33, 2, 0x28, 1, 0, 0, 0, 0x01, 0x2A, 0x00,
+            ],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn can_read_events_in_specific_variation() {
+    let mut harness = new_harness(get_default_config());
+    create_frozen_analog(&mut harness, FrozenAnalogInputConfig::default());
+    harness.handle.database.transaction(|db| {
+        db.update(0, &demand_value(), UpdateOptions::detect_event());
+    });
+
+    // g33v5 - single-precision without time
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 33, 5, 0x06],
+            &[
+                0xE0, 0x81, 0x80, 0x00, 33, 5, 0x28, 1, 0, 0, 0, 0x01, 0x00, 0x00, 0x28, 0x42,
+            ],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn get_and_remove_frozen_analog_input() {
+    let mut harness = new_harness(get_default_config());
+    create_frozen_analog(&mut harness, FrozenAnalogInputConfig::default());
+
+    let value = harness.handle.database.transaction(|db| {
+        db.update(0, &demand_value(), UpdateOptions::no_event());
+        Get::<FrozenAnalogInput>::get(db, 0)
+    });
+    assert_eq!(value, Some(demand_value()));
+
+    let removed = harness
+        .handle
+        .database
+        .transaction(|db| Remove::<FrozenAnalogInput>::remove(db, 0));
+    assert!(removed);
+
+    harness
+        .test_request_response(READ_CLASS_0, EMPTY_RESPONSE)
+        .await;
+}
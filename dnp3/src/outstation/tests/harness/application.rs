@@ -1,10 +1,14 @@
 use std::sync::{Arc, Mutex};
 
-use crate::app::Timestamp;
-use crate::outstation::database::DatabaseHandle;
+use crate::app::measurement::OctetString;
+use crate::app::{
+    ActivateConfigResponse, ActivationStatus, ApplicationIdentifier, Attribute,
+    ConfigurationReference, TimeAndInterval, Timestamp,
+};
+use crate::outstation::database::{DatabaseHandle, UpdateOptions};
 use crate::outstation::tests::harness::{Event, EventSender};
 use crate::outstation::traits::{OutstationApplication, RequestError, RestartDelay};
-use crate::outstation::{FreezeIndices, FreezeType};
+use crate::outstation::{ClassAssignment, FreezeIndices, FreezeType};
 
 pub(crate) struct MockOutstationApplication {
     events: EventSender,
@@ -14,6 +18,9 @@ pub(crate) struct MockOutstationApplication {
 pub(crate) struct ApplicationData {
     pub(crate) processing_delay: u16,
     pub(crate) restart_delay: Option<RestartDelay>,
+    pub(crate) current_time: Option<Timestamp>,
+    pub(crate) application_error: Option<RequestError>,
+    pub(crate) octet_string_update: UpdateOptions,
 }
 
 impl ApplicationData {
@@ -21,6 +28,9 @@ impl ApplicationData {
         Self {
             processing_delay: 0,
             restart_delay: None,
+            current_time: None,
+            application_error: None,
+            octet_string_update: UpdateOptions::default(),
         }
     }
 }
@@ -32,6 +42,13 @@ impl MockOutstationApplication {
         let data = Arc::new(Mutex::new(ApplicationData::new()));
         (data.clone(), Box::new(Self { events, data }))
     }
+
+    fn application_result(&self) -> Result<(), RequestError> {
+        match self.data.lock().unwrap().application_error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
 }
 
 impl OutstationApplication for MockOutstationApplication {
@@ -40,6 +57,36 @@ impl OutstationApplication for MockOutstationApplication {
         Ok(())
     }
 
+    fn write_binary_outputs(
+        &mut self,
+        values: &mut dyn Iterator<Item = (bool, u16)>,
+        _database: &mut DatabaseHandle,
+    ) -> Result<(), RequestError> {
+        self.events
+            .send(Event::WriteBinaryOutputs(values.collect()));
+        self.application_result()
+    }
+
+    fn write_time_and_interval(
+        &mut self,
+        values: &mut dyn Iterator<Item = (TimeAndInterval, u16)>,
+    ) -> Result<(), RequestError> {
+        self.events
+            .send(Event::WriteTimeAndInterval(values.collect()));
+        self.application_result()
+    }
+
+    fn write_octet_string(
+        &mut self,
+        index: u16,
+        value: &OctetString,
+    ) -> Result<UpdateOptions, RequestError> {
+        self.events
+            .send(Event::WriteOctetString(index, value.value().to_vec()));
+        self.application_result()?;
+        Ok(self.data.lock().unwrap().octet_string_update)
+    }
+
     fn get_processing_delay_ms(&self) -> u16 {
         self.data.lock().unwrap().processing_delay
     }
@@ -56,6 +103,10 @@ impl OutstationApplication for MockOutstationApplication {
         delay
     }
 
+    fn get_current_time(&self) -> Option<Timestamp> {
+        self.data.lock().unwrap().current_time
+    }
+
     fn freeze_counter(
         &mut self,
         indices: FreezeIndices,
@@ -65,4 +116,53 @@ impl OutstationApplication for MockOutstationApplication {
         self.events.send(Event::Freeze(indices, freeze_type));
         Ok(())
     }
+
+    fn write_device_attr(&mut self, attr: Attribute) -> bool {
+        self.events
+            .send(Event::WriteDeviceAttr(attr.set, attr.variation));
+        true
+    }
+
+    fn analog_input_deadband_written(&mut self, index: u16, deadband: f64) {
+        self.events
+            .send(Event::AnalogInputDeadbandWritten(index, deadband));
+    }
+
+    fn class_assigned(&mut self, assignment: ClassAssignment) {
+        self.events.send(Event::ClassAssigned(assignment));
+    }
+
+    fn initialize_application(
+        &mut self,
+        application: ApplicationIdentifier,
+    ) -> Result<(), RequestError> {
+        self.events.send(Event::InitializeApplication(application));
+        self.application_result()
+    }
+
+    fn start_application(
+        &mut self,
+        application: ApplicationIdentifier,
+    ) -> Result<(), RequestError> {
+        self.events.send(Event::StartApplication(application));
+        self.application_result()
+    }
+
+    fn stop_application(&mut self, application: ApplicationIdentifier) -> Result<(), RequestError> {
+        self.events.send(Event::StopApplication(application));
+        self.application_result()
+    }
+
+    fn activate_config(
+        &mut self,
+        configurations: &[ConfigurationReference],
+    ) -> Result<ActivateConfigResponse, RequestError> {
+        self.events
+            .send(Event::ActivateConfig(configurations.to_vec()));
+        self.application_result()?;
+        Ok(ActivateConfigResponse::new(
+            1000,
+            vec![ActivationStatus::success(); configurations.len()],
+        ))
+    }
 }
@@ -0,0 +1,50 @@
+use crate::outstation::tests::harness::{Event, EventSender};
+use crate::sa::*;
+
+pub(crate) const USER: UserNumber = UserNumber::DEFAULT;
+pub(crate) const UPDATE_KEY: [u8; 16] = [0x11; 16];
+
+pub(crate) struct MockUserStore;
+
+impl MockUserStore {
+    pub(crate) fn new() -> Box<dyn UserStore> {
+        Box::new(Self)
+    }
+}
+
+impl UserStore for MockUserStore {
+    fn get_update_key(&self, user: UserNumber) -> Option<UpdateKey> {
+        if user == USER {
+            Some(UpdateKey::aes128(UPDATE_KEY))
+        } else {
+            None
+        }
+    }
+}
+
+pub(crate) struct MockAuthenticationInformation {
+    events: EventSender,
+}
+
+impl MockAuthenticationInformation {
+    pub(crate) fn new(events: EventSender) -> Box<dyn AuthenticationInformation> {
+        Box::new(Self { events })
+    }
+}
+
+impl AuthenticationInformation for MockAuthenticationInformation {
+    fn statistic_changed(&mut self, statistic: SecurityStatistic, value: u32) {
+        // the other statistics change with every message
+        if statistic == SecurityStatistic::ReplyTimeouts {
+            self.events.send(Event::ReplyTimeouts(value));
+        }
+    }
+
+    fn session_key_status(&mut self, user: UserNumber, status: KeyStatus) {
+        self.events.send(Event::SessionKeyStatus(user, status));
+    }
+
+    fn authentication_failed(&mut self, user: UserNumber, error: AuthErrorCode) {
+        self.events.send(Event::AuthenticationFailed(user, error));
+    }
+}
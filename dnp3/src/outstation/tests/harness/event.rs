@@ -1,8 +1,12 @@
 use crate::app::variations::{Group12Var1, Group41Var1, Group41Var2, Group41Var3, Group41Var4};
 use crate::outstation::traits::{BroadcastAction, OperateType, RestartDelay};
-use crate::outstation::{FreezeIndices, FreezeType};
+use crate::outstation::{ClassAssignment, FreezeIndices, FreezeType};
+use crate::sa::{AuthErrorCode, KeyStatus, UserNumber};
 
-use crate::app::{FunctionCode, Timestamp};
+use crate::app::{
+    ApplicationIdentifier, ConfigurationReference, FileMode, FunctionCode, TimeAndInterval,
+    Timestamp,
+};
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub(crate) enum Control {
@@ -13,7 +17,7 @@ pub(crate) enum Control {
     G41V4(Group41Var4, u16),
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub(crate) enum Event {
     BeginControls,
     Select(Control),
@@ -34,6 +38,24 @@ pub(crate) enum Event {
     WarmRestart(Option<RestartDelay>),
     ClearRestartIIN,
     WriteAbsoluteTime(Timestamp),
+    WriteBinaryOutputs(Vec<(bool, u16)>),
+    WriteTimeAndInterval(Vec<(TimeAndInterval, u16)>),
+    WriteOctetString(u16, Vec<u8>),
+    AuthenticateFile(bool),
+    OpenFile(FileMode),
+    CloseFile(u32),
+    ReadFileBlock(u32, u32),
+    WriteFileBlock(u32, u32, usize, bool),
+    WriteDeviceAttr(u8, u8),
+    AnalogInputDeadbandWritten(u16, f64),
+    ClassAssigned(ClassAssignment),
+    InitializeApplication(ApplicationIdentifier),
+    StartApplication(ApplicationIdentifier),
+    StopApplication(ApplicationIdentifier),
+    ActivateConfig(Vec<ConfigurationReference>),
+    SessionKeyStatus(UserNumber, KeyStatus),
+    AuthenticationFailed(UserNumber, AuthErrorCode),
+    ReplyTimeouts(u32),
 }
 
 #[derive(Clone)]
@@ -0,0 +1,102 @@
+use crate::app::{FileInfo, FileStatus, FileType, PermissionSet, Permissions, Timestamp};
+use crate::outstation::tests::harness::{Event, EventSender};
+use crate::outstation::{FileBlock, FileHandler, OpenFileRequest, OpenedFile};
+
+pub(crate) const FILE_NAME: &str = "file.txt";
+pub(crate) const FILE_HANDLE: u32 = 1;
+pub(crate) const FILE_DATA: &[u8] = &[0x01, 0x02, 0x03];
+pub(crate) const AUTH_KEY: u32 = 0xAA;
+
+pub(crate) struct MockFileHandler {
+    events: EventSender,
+}
+
+impl MockFileHandler {
+    pub(crate) fn new(events: EventSender) -> Box<dyn FileHandler> {
+        Box::new(Self { events })
+    }
+}
+
+impl FileHandler for MockFileHandler {
+    fn authenticate(&mut self, user_name: &str, password: &str) -> Option<u32> {
+        let success = user_name == "user" && password == "pass";
+        self.events.send(Event::AuthenticateFile(success));
+        if success {
+            Some(AUTH_KEY)
+        } else {
+            None
+        }
+    }
+
+    fn open_file(&mut self, request: OpenFileRequest) -> Result<OpenedFile, FileStatus> {
+        self.events.send(Event::OpenFile(request.mode));
+        if request.file_name != FILE_NAME {
+            return Err(FileStatus::FileNotFound);
+        }
+        Ok(OpenedFile {
+            handle: FILE_HANDLE,
+            file_size: FILE_DATA.len() as u32,
+            max_block_size: request.max_block_size,
+        })
+    }
+
+    fn close_file(&mut self, handle: u32) -> Result<(), FileStatus> {
+        self.events.send(Event::CloseFile(handle));
+        Ok(())
+    }
+
+    fn get_file_info(&mut self, file_name: &str) -> Result<FileInfo, FileStatus> {
+        if file_name != FILE_NAME {
+            return Err(FileStatus::FileNotFound);
+        }
+        Ok(FileInfo {
+            file_name: file_name.to_string(),
+            file_type: FileType::Simple,
+            size: FILE_DATA.len() as u32,
+            time_created: Timestamp::new(0x1234),
+            permissions: Permissions {
+                world: PermissionSet {
+                    read: true,
+                    ..Default::default()
+                },
+                group: PermissionSet {
+                    read: true,
+                    ..Default::default()
+                },
+                owner: PermissionSet {
+                    read: true,
+                    write: true,
+                    execute: false,
+                },
+            },
+        })
+    }
+
+    fn read_file_block(
+        &mut self,
+        handle: u32,
+        block: u32,
+        _max_size: usize,
+    ) -> Result<FileBlock, FileStatus> {
+        self.events.send(Event::ReadFileBlock(handle, block));
+        if block != 0 {
+            return Err(FileStatus::BlockSeq);
+        }
+        Ok(FileBlock {
+            data: FILE_DATA.to_vec(),
+            last: true,
+        })
+    }
+
+    fn write_file_block(
+        &mut self,
+        handle: u32,
+        block: u32,
+        data: &[u8],
+        last: bool,
+    ) -> Result<(), FileStatus> {
+        self.events
+            .send(Event::WriteFileBlock(handle, block, data.len(), last));
+        Ok(())
+    }
+}
@@ -5,14 +5,16 @@ use crate::decode::AppDecodeLevel;
 use crate::link::header::{BroadcastConfirmMode, FrameInfo, FrameType};
 use crate::link::{EndpointAddress, LinkErrorMode};
 use crate::outstation::config::{Feature, OutstationConfig};
-use crate::outstation::database::EventBufferConfig;
+use crate::outstation::database::{EventBufferConfig, EventClass};
 use crate::outstation::session::RunError;
 use crate::outstation::task::OutstationTask;
 use crate::outstation::tests::harness::{
-    event_handlers, ApplicationData, Event, EventReceiver, MockControlHandler,
-    MockOutstationApplication, MockOutstationInformation,
+    event_handlers, ApplicationData, Event, EventReceiver, MockAuthenticationInformation,
+    MockControlHandler, MockFileHandler, MockOutstationApplication, MockOutstationInformation,
+    MockUserStore,
 };
-use crate::outstation::OutstationHandle;
+use crate::outstation::{OutstationHandle, VirtualTerminal};
+use crate::sa::OutstationAuthConfig;
 use crate::util::phys::PhysLayer;
 
 pub(crate) fn get_default_config() -> OutstationConfig {
@@ -47,6 +49,21 @@ impl OutstationHarness {
         self.expect_response(response).await;
     }
 
+    /// open a virtual terminal and let the outstation task process the request before continuing
+    pub(crate) async fn open_virtual_terminal(
+        &mut self,
+        port: u16,
+        class: EventClass,
+    ) -> VirtualTerminal {
+        let terminal = self
+            .handle
+            .open_virtual_terminal(port, class)
+            .await
+            .unwrap();
+        tokio::task::yield_now().await;
+        terminal
+    }
+
     pub(crate) async fn expect_response(&mut self, response: &[u8]) {
         assert_eq!(
             self.io.next_event().await,
@@ -54,6 +71,14 @@ impl OutstationHarness {
         );
     }
 
+    /// the next response, for responses that contain random challenge data
+    pub(crate) async fn next_response(&mut self) -> Vec<u8> {
+        match self.io.next_event().await {
+            sfio_tokio_mock_io::Event::Write(bytes) => bytes,
+            x => panic!("expected a response but got: {:?}", x),
+        }
+    }
+
     pub(crate) async fn send_and_process(&mut self, request: &[u8]) {
         self.io.read(request);
         assert_eq!(self.io.next_event().await, sfio_tokio_mock_io::Event::Read);
@@ -89,23 +114,36 @@ impl OutstationHarness {
 }
 
 pub(crate) fn new_harness(config: OutstationConfig) -> OutstationHarness {
-    new_harness_impl(config, None)
+    new_harness_impl(config, None, false, None)
 }
 
 pub(crate) fn new_harness_with_custom_event_buffers(config: OutstationConfig) -> OutstationHarness {
-    new_harness_impl(config, None)
+    new_harness_impl(config, None, false, None)
 }
 
 pub(crate) fn new_harness_for_broadcast(
     config: OutstationConfig,
     broadcast: BroadcastConfirmMode,
 ) -> OutstationHarness {
-    new_harness_impl(config, Some(broadcast))
+    new_harness_impl(config, Some(broadcast), false, None)
+}
+
+pub(crate) fn new_harness_with_file_handler(config: OutstationConfig) -> OutstationHarness {
+    new_harness_impl(config, None, true, None)
+}
+
+pub(crate) fn new_harness_with_authentication(
+    config: OutstationConfig,
+    auth: OutstationAuthConfig,
+) -> OutstationHarness {
+    new_harness_impl(config, None, false, Some(auth))
 }
 
 fn new_harness_impl(
     config: OutstationConfig,
     broadcast: Option<BroadcastConfirmMode>,
+    file_handler: bool,
+    auth: Option<OutstationAuthConfig>,
 ) -> OutstationHarness {
     let (sender, receiver) = event_handlers();
 
@@ -121,6 +159,18 @@ fn new_harness_impl(
 
     let mut task = Box::new(task);
 
+    if file_handler {
+        task.set_file_handler(MockFileHandler::new(sender.clone()));
+    }
+
+    if let Some(auth) = auth {
+        task.set_authentication(
+            auth,
+            MockUserStore::new(),
+            MockAuthenticationInformation::new(sender.clone()),
+        );
+    }
+
     task.get_reader()
         .get_inner()
         .set_rx_frame_info(FrameInfo::new(
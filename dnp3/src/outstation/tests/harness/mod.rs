@@ -1,11 +1,15 @@
 pub(crate) use application::*;
+pub(crate) use auth::*;
 pub(crate) use control::*;
 pub(crate) use event::*;
+pub(crate) use file::*;
 pub(crate) use harness::*;
 pub(crate) use info::*;
 
 mod application;
+mod auth;
 mod control;
 mod event;
+mod file;
 mod harness;
 mod info;
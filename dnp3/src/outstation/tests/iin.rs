@@ -56,8 +56,8 @@ async fn object_unknown() {
 
     harness
         .test_request_response(
-            &[0xC0, 0x01, 0x00, 0x00], // Read g0v0
-            &[0xC0, 0x81, 0x80, 0x02], // IIN2.1 OBJECT_UNKNOWN set
+            &[0xC0, 0x01, 0x00, 0x00, 0x06], // Read g0v0 (undefined attribute)
+            &[0xC0, 0x81, 0x80, 0x02],       // IIN2.1 OBJECT_UNKNOWN set
         )
         .await;
 }
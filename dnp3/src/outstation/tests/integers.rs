@@ -0,0 +1,135 @@
+use crate::app::measurement::*;
+use crate::outstation::database::*;
+use crate::outstation::tests::harness::*;
+
+const READ_CLASS_0: &[u8] = &[0xC0, 0x01, 60, 1, 0x06];
+const READ_CLASS_1: &[u8] = &[0xC0, 0x01, 60, 2, 0x06];
+const EMPTY_RESPONSE: &[u8] = &[0xC0, 0x81, 0x80, 0x00];
+
+fn create_points(harness: &mut OutstationHarness, bcd: BcdConfig) {
+    harness.handle.database.transaction(|db| {
+        db.add(0, None, UnsignedIntegerConfig::default());
+        db.add(0, None, bcd);
+    });
+}
+
+fn set_values(harness: &mut OutstationHarness, unsigned: u8, bcd: u64) {
+    harness.handle.database.transaction(|db| {
+        db.update(0, &UnsignedInteger::new(unsigned), UpdateOptions::default());
+        db.update(0, &Bcd::new(bcd), UpdateOptions::default());
+    });
+}
+
+#[tokio::test]
+async fn class_zero_includes_unsigned_integers_and_bcd() {
+    let mut harness = new_harness(get_default_config());
+    create_points(&mut harness, BcdConfig::default());
+    set_values(&mut harness, 200, 1234);
+
+    harness
+        .test_request_response(
+            READ_CLASS_0,
+            &[
+                0xC0, 0x81, 0x80, 0x00, // header
+                102, 1, 0x01, 0, 0, 0, 0, 0xC8, // g102v1 [0] = 200
+                101, 1, 0x01, 0, 0, 0, 0, 0x34, 0x12, // g101v1 [0] = 1234
+            ],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn class_zero_can_exclude_unsigned_integers_and_bcd() {
+    let mut config = get_default_config();
+    config.class_zero.unsigned_integer = false;
+    config.class_zero.bcd = false;
+    let mut harness = new_harness(config);
+    create_points(&mut harness, BcdConfig::default());
+
+    harness
+        .test_request_response(READ_CLASS_0, EMPTY_RESPONSE)
+        .await;
+}
+
+#[tokio::test]
+async fn can_read_specific_bcd_variation_by_range() {
+    let mut harness = new_harness(get_default_config());
+    create_points(&mut harness, BcdConfig::default());
+    set_values(&mut harness, 0, 1234);
+
+    // g101v3 - large (16 digits)
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 101, 3, 0x00, 0, 0],
+            &[
+                0xC0, 0x81, 0x80, 0x00, 101, 3, 0x01, 0, 0, 0, 0, 0x34, 0x12, 0, 0, 0, 0, 0, 0,
+            ],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn bcd_values_saturate_in_smaller_variations() {
+    let mut harness = new_harness(get_default_config());
+    create_points(
+        &mut harness,
+        BcdConfig::new(StaticBcdVariation::Group101Var2),
+    );
+    set_values(&mut harness, 0, 123_456_789);
+
+    // g101v0 uses the configured medium (8 digits) variation
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 101, 0, 0x06],
+            &[
+                0xC0, 0x81, 0x80, 0x00, 101, 2, 0x01, 0, 0, 0, 0, 0x99, 0x99, 0x99, 0x99,
+            ],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn updates_never_produce_events() {
+    let mut harness = new_harness(get_default_config());
+    harness.handle.database.transaction(|db| {
+        db.add(
+            0,
+            Some(EventClass::Class1),
+            UnsignedIntegerConfig::default(),
+        );
+        db.add(0, Some(EventClass::Class1), BcdConfig::default());
+        let force = UpdateOptions::new(true, EventMode::Force);
+        db.update(0, &UnsignedInteger::new(7), force);
+        db.update(0, &Bcd::new(7), force);
+    });
+
+    harness
+        .test_request_response(READ_CLASS_1, EMPTY_RESPONSE)
+        .await;
+}
+
+#[tokio::test]
+async fn get_and_remove_unsigned_integers_and_bcd() {
+    let mut harness = new_harness(get_default_config());
+    create_points(&mut harness, BcdConfig::default());
+    set_values(&mut harness, 42, 9876);
+
+    let values = harness
+        .handle
+        .database
+        .transaction(|db| (Get::<UnsignedInteger>::get(db, 0), Get::<Bcd>::get(db, 0)));
+    assert_eq!(
+        values,
+        (Some(UnsignedInteger::new(42)), Some(Bcd::new(9876)))
+    );
+
+    let removed = harness
+        .handle
+        .database
+        .transaction(|db| Remove::<UnsignedInteger>::remove(db, 0) && Remove::<Bcd>::remove(db, 0));
+    assert!(removed);
+
+    harness
+        .test_request_response(READ_CLASS_0, EMPTY_RESPONSE)
+        .await;
+}
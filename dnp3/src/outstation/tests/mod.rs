@@ -1,19 +1,47 @@
 pub(crate) mod harness;
 
+/// configuration activation with ACTIVATE_CONFIG
+mod activate_config;
+/// INITIALIZE_APPLICATION, START_APPLICATION, and STOP_APPLICATION
+mod application;
+/// assigning event classes with ASSIGN_CLASS
+mod assign_class;
+/// device attributes
+mod attr;
+/// binary output status packed format (g10v1) reads and writes
+mod binary_output;
+/// binary and analog output command events
+mod command_events;
 /// control functionality
 mod controls;
+/// data sets (groups 85 - 88)
+mod data_set;
+/// analog input deadbands
+mod deadband;
+/// file transfer
+mod file;
 /// freeze counters tests
 mod freeze;
+/// frozen analog inputs
+mod frozen_analog;
 /// various IIN bit tests
 mod iin;
+/// unsigned integers (g102) and BCD integers (g101)
+mod integers;
+/// octet string (g110) writes
+mod octet_string;
 /// state machine for READ requests
 mod read_states;
 /// clear restart IIN + cold/warm restart
 mod restart;
+/// secure authentication
+mod sa;
 /// time synchronization
 mod time;
 /// unsolicited responses
 mod unsolicited;
+/// virtual terminals (groups 112/113)
+mod virtual_terminal;
 
 /// test data for use in multiple tests
 mod data {
@@ -0,0 +1,149 @@
+use crate::app::measurement::*;
+use crate::outstation::database::*;
+use crate::outstation::tests::harness::*;
+use crate::outstation::traits::RequestError;
+
+const EMPTY_RESPONSE_SEQ0: &[u8] = &[0xC0, 0x81, 0x80, 0x00];
+const EMPTY_RESPONSE_SEQ1: &[u8] = &[0xC1, 0x81, 0x80, 0x00];
+
+fn add_octet_strings(harness: &mut OutstationHarness, class: Option<EventClass>) {
+    harness.handle.database.transaction(|db| {
+        for index in [1, 300] {
+            db.add(index, class, OctetStringConfig);
+            db.update(
+                index,
+                &OctetString::new(b"init").unwrap(),
+                UpdateOptions::no_event(),
+            );
+        }
+    });
+}
+
+fn get_value(harness: &mut OutstationHarness, index: u16) -> Vec<u8> {
+    harness
+        .handle
+        .database
+        .transaction(|db| Get::<OctetString>::get(db, index).unwrap().value().to_vec())
+}
+
+#[tokio::test]
+async fn updates_database_for_range_write() {
+    let mut harness = new_harness(get_default_config());
+    add_octet_strings(&mut harness, None);
+
+    // WRITE g110v2 with 1-byte start/stop [1, 1]
+    harness
+        .test_request_response(
+            &[0xC0, 0x02, 110, 2, 0x00, 1, 1, b'h', b'i'],
+            EMPTY_RESPONSE_SEQ0,
+        )
+        .await;
+
+    harness.check_events(&[Event::WriteOctetString(1, b"hi".to_vec())]);
+    harness.check_no_events();
+    assert_eq!(get_value(&mut harness, 1), b"hi");
+}
+
+#[tokio::test]
+async fn updates_database_for_prefixed_writes() {
+    let mut harness = new_harness(get_default_config());
+    add_octet_strings(&mut harness, None);
+
+    // WRITE g110v1 with 1-byte count and prefix
+    harness
+        .test_request_response(&[0xC0, 0x02, 110, 1, 0x17, 1, 1, b'a'], EMPTY_RESPONSE_SEQ0)
+        .await;
+    // WRITE g110v3 with 2-byte count and prefix
+    harness
+        .test_request_response(
+            &[0xC1, 0x02, 110, 3, 0x28, 1, 0, 0x2C, 0x01, b'x', b'y', b'z'],
+            EMPTY_RESPONSE_SEQ1,
+        )
+        .await;
+
+    harness.check_events(&[
+        Event::WriteOctetString(1, b"a".to_vec()),
+        Event::WriteOctetString(300, b"xyz".to_vec()),
+    ]);
+    harness.check_no_events();
+    assert_eq!(get_value(&mut harness, 1), b"a");
+    assert_eq!(get_value(&mut harness, 300), b"xyz");
+}
+
+#[tokio::test]
+async fn produces_event_when_application_requests_it() {
+    let mut harness = new_harness(get_default_config());
+    add_octet_strings(&mut harness, Some(EventClass::Class1));
+
+    // IIN1.1 CLASS_1_EVENTS is set once the event is recorded
+    harness
+        .test_request_response(
+            &[0xC0, 0x02, 110, 2, 0x00, 1, 1, b'h', b'i'],
+            &[0xC0, 0x81, 0x82, 0x00],
+        )
+        .await;
+
+    harness
+        .test_request_response(
+            &[0xC1, 0x01, 60, 2, 0x06],
+            &[
+                0xE1, 0x81, 0x80, 0x00, 111, 2, 0x28, 0x01, 0x00, 0x01, 0x00, b'h', b'i',
+            ],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn suppresses_event_when_application_requests_it() {
+    let mut harness = new_harness(get_default_config());
+    add_octet_strings(&mut harness, Some(EventClass::Class1));
+    harness.application_data.lock().unwrap().octet_string_update = UpdateOptions::no_event();
+
+    harness
+        .test_request_response(
+            &[0xC0, 0x02, 110, 2, 0x00, 1, 1, b'h', b'i'],
+            EMPTY_RESPONSE_SEQ0,
+        )
+        .await;
+
+    harness
+        .test_request_response(&[0xC1, 0x01, 60, 2, 0x06], EMPTY_RESPONSE_SEQ1)
+        .await;
+    assert_eq!(get_value(&mut harness, 1), b"hi");
+}
+
+#[tokio::test]
+async fn rejects_write_to_non_existent_point() {
+    let mut harness = new_harness(get_default_config());
+    add_octet_strings(&mut harness, None);
+
+    harness
+        .test_request_response(
+            &[0xC0, 0x02, 110, 1, 0x17, 2, 1, b'a', 2, b'b'],
+            &[0xC0, 0x81, 0x80, 0x04],
+        )
+        .await;
+
+    // the value for the existing point is still applied
+    harness.check_events(&[Event::WriteOctetString(1, b"a".to_vec())]);
+    harness.check_no_events();
+    assert_eq!(get_value(&mut harness, 1), b"a");
+}
+
+#[tokio::test]
+async fn leaves_database_unchanged_when_application_rejects_value() {
+    let mut harness = new_harness(get_default_config());
+    add_octet_strings(&mut harness, None);
+
+    harness.application_data.lock().unwrap().application_error = Some(RequestError::ParameterError);
+    harness
+        .test_request_response(
+            &[0xC0, 0x02, 110, 1, 0x17, 1, 1, b'a'],
+            &[0xC0, 0x81, 0x80, 0x04],
+        )
+        .await;
+
+    harness.check_events(&[Event::WriteOctetString(1, b"a".to_vec())]);
+    harness.check_no_events();
+    assert_eq!(get_value(&mut harness, 1), b"init");
+}
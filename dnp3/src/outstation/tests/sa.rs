@@ -0,0 +1,368 @@
+use crate::app::auth::*;
+use crate::app::format::write::{start_request, HeaderWriter};
+use crate::app::parse::free_format::FreeFormatVariation;
+use crate::app::parse::parser::ParsedFragment;
+use crate::app::variations::{Group120Var3, Group120Var4};
+use crate::app::{ControlField, FunctionCode, ResponseFunction, Sequence};
+use crate::outstation::tests::harness::*;
+use crate::sa::crypto::{calc_mac, SessionKeys};
+use crate::sa::*;
+
+use scursor::WriteCursor;
+
+const MAC: MacAlgorithm = MacAlgorithm::HmacSha256Truncated16;
+const CLEAR_RESTART_IIN: &[u8] = &[0x50, 0x01, 0x00, 0x07, 0x07, 0x00];
+
+fn request(seq: u8, function: FunctionCode, write: impl FnOnce(&mut HeaderWriter)) -> Vec<u8> {
+    let mut buffer = [0; 2048];
+    let mut cursor = WriteCursor::new(&mut buffer);
+    let mut writer = start_request(
+        ControlField::request(Sequence::new(seq)),
+        function,
+        &mut cursor,
+    )
+    .unwrap();
+    write(&mut writer);
+    cursor.written().to_vec()
+}
+
+fn clear_restart_iin(seq: u8) -> Vec<u8> {
+    request(seq, FunctionCode::Write, |w| {
+        w.write_raw(CLEAR_RESTART_IIN).unwrap()
+    })
+}
+
+fn auth_object(response: &[u8]) -> FreeFormatVariation<'_> {
+    let response = ParsedFragment::parse(response)
+        .unwrap()
+        .to_response()
+        .unwrap();
+    assert_eq!(
+        response.header.function,
+        ResponseFunction::AuthenticateResponse
+    );
+    response
+        .objects
+        .unwrap()
+        .get_only_header()
+        .unwrap()
+        .details
+        .free_format()
+        .unwrap()
+}
+
+/// returns the key change sequence number, key status, status message, and MAC
+fn key_status(response: &[u8]) -> (u32, KeyStatus, Vec<u8>, Vec<u8>) {
+    match auth_object(response) {
+        FreeFormatVariation::Group120Var5(x) => (
+            x.key_change_seq,
+            KeyStatus::new(x.key_status),
+            x.to_status_message().unwrap(),
+            x.mac.to_vec(),
+        ),
+        x => panic!("unexpected object: {:?}", x),
+    }
+}
+
+fn challenge_seq(response: &[u8]) -> u32 {
+    match auth_object(response) {
+        FreeFormatVariation::Group120Var1(x) => {
+            assert_eq!(x.user, 0);
+            assert_eq!(x.mac_algorithm, MAC.to_u8());
+            assert_eq!(x.reason, REASON_CRITICAL);
+            assert_eq!(x.challenge_data.len(), 4);
+            x.challenge_seq
+        }
+        x => panic!("unexpected object: {:?}", x),
+    }
+}
+
+fn error_code(response: &[u8]) -> AuthErrorCode {
+    match auth_object(response) {
+        FreeFormatVariation::Group120Var7(x) => AuthErrorCode::new(x.error_code),
+        x => panic!("unexpected object: {:?}", x),
+    }
+}
+
+fn reply(seq: u8, challenge_seq: u32, mac: &[u8]) -> Vec<u8> {
+    request(seq, FunctionCode::AuthenticateRequest, |w| {
+        w.write_free_format(&Group120Var2 {
+            challenge_seq,
+            user: USER.value(),
+            mac,
+        })
+        .unwrap()
+    })
+}
+
+/// WRITE to clear the restart IIN, authenticated with aggressive mode objects
+fn aggressive_write(seq: u8, challenge_seq: u32, keys: &SessionKeys, challenge: &[u8]) -> Vec<u8> {
+    let mut fragment = request(seq, FunctionCode::Write, |w| {
+        w.write_count_of_one(Group120Var3 {
+            challenge_seq,
+            user: USER.value(),
+        })
+        .unwrap();
+        w.write_raw(CLEAR_RESTART_IIN).unwrap();
+    });
+    let mac = calc_mac(MAC, &keys.control, &[challenge, &fragment]);
+    let mut buffer = [0; 64];
+    let mut cursor = WriteCursor::new(&mut buffer);
+    HeaderWriter::new(&mut cursor)
+        .write_free_format(&Group120Var9 { mac: &mac })
+        .unwrap();
+    fragment.extend_from_slice(cursor.written());
+    fragment
+}
+
+fn new_auth_harness() -> OutstationHarness {
+    new_harness_with_authentication(get_default_config(), OutstationAuthConfig::default())
+}
+
+/// perform a session key change with sequence numbers 0 and 1, returning the keys and the last key status
+async fn change_session_keys(harness: &mut OutstationHarness) -> (SessionKeys, Vec<u8>) {
+    let status_request = request(0, FunctionCode::AuthenticateRequest, |w| {
+        w.write_count_of_one(Group120Var4 { user: USER.value() })
+            .unwrap()
+    });
+    harness.send_and_process(&status_request).await;
+    let response = harness.next_response().await;
+    assert_eq!(&response[..2], &[0xC0, 0x83]);
+    let (key_change_seq, status, message, mac) = key_status(&response);
+    assert_eq!(status, KeyStatus::NotInit);
+    assert!(mac.is_empty());
+
+    let keys = SessionKeys {
+        control: vec![0x22; 16],
+        monitor: vec![0x33; 16],
+    };
+    let wrapped_key_data = keys.wrap(&UpdateKey::aes128(UPDATE_KEY), &message).unwrap();
+    let key_change = request(1, FunctionCode::AuthenticateRequest, |w| {
+        w.write_free_format(&Group120Var6 {
+            key_change_seq,
+            user: USER.value(),
+            wrapped_key_data: &wrapped_key_data,
+        })
+        .unwrap()
+    });
+    harness.send_and_process(&key_change).await;
+    let response = harness.next_response().await;
+    assert_eq!(&response[..2], &[0xC1, 0x83]);
+    let (_, status, _, mac) = key_status(&response);
+    assert_eq!(status, KeyStatus::Ok);
+    assert_eq!(mac, calc_mac(MAC, &keys.monitor, &[&key_change]));
+    harness.check_events(&[Event::SessionKeyStatus(USER, KeyStatus::Ok)]);
+
+    (keys, response)
+}
+
+/// challenge a WRITE with sequence number 2 and return the challenge fragment
+async fn challenge_write(harness: &mut OutstationHarness) -> (Vec<u8>, Vec<u8>) {
+    let write = clear_restart_iin(2);
+    harness.send_and_process(&write).await;
+    let challenge = harness.next_response().await;
+    assert_eq!(&challenge[..2], &[0xC2, 0x83]);
+    (write, challenge)
+}
+
+#[tokio::test]
+async fn does_not_challenge_non_critical_requests() {
+    let mut harness = new_auth_harness();
+    harness
+        .test_request_response(&[0xC0, 0x01, 60, 1, 0x06], &[0xC0, 0x81, 0x80, 0x00])
+        .await;
+}
+
+#[tokio::test]
+async fn changes_session_keys() {
+    let mut harness = new_auth_harness();
+    change_session_keys(&mut harness).await;
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn rejects_session_keys_wrapped_with_wrong_update_key() {
+    let mut harness = new_auth_harness();
+    harness
+        .send_and_process(&request(0, FunctionCode::AuthenticateRequest, |w| {
+            w.write_count_of_one(Group120Var4 { user: USER.value() })
+                .unwrap()
+        }))
+        .await;
+    let (key_change_seq, _, message, _) = key_status(&harness.next_response().await);
+
+    let keys = SessionKeys {
+        control: vec![0x22; 16],
+        monitor: vec![0x33; 16],
+    };
+    let wrapped_key_data = keys.wrap(&UpdateKey::aes128([0x99; 16]), &message).unwrap();
+    harness
+        .send_and_process(&request(1, FunctionCode::AuthenticateRequest, |w| {
+            w.write_free_format(&Group120Var6 {
+                key_change_seq,
+                user: USER.value(),
+                wrapped_key_data: &wrapped_key_data,
+            })
+            .unwrap()
+        }))
+        .await;
+    let (_, status, _, mac) = key_status(&harness.next_response().await);
+    assert_eq!(status, KeyStatus::AuthFail);
+    assert!(mac.is_empty());
+    harness.check_events(&[Event::SessionKeyStatus(USER, KeyStatus::AuthFail)]);
+}
+
+#[tokio::test]
+async fn processes_critical_request_after_valid_challenge_reply() {
+    let mut harness = new_auth_harness();
+    let (keys, _) = change_session_keys(&mut harness).await;
+    let (write, challenge) = challenge_write(&mut harness).await;
+    harness.check_no_events();
+
+    let mac = calc_mac(MAC, &keys.control, &[&challenge, &write]);
+    harness
+        .test_request_response(
+            &reply(3, challenge_seq(&challenge), &mac),
+            &[0xC3, 0x81, 0x00, 0x00],
+        )
+        .await;
+    harness.check_events(&[Event::ClearRestartIIN]);
+}
+
+#[tokio::test]
+async fn rejects_challenge_reply_with_invalid_mac() {
+    let mut harness = new_auth_harness();
+    change_session_keys(&mut harness).await;
+    let (_, challenge) = challenge_write(&mut harness).await;
+
+    harness
+        .send_and_process(&reply(3, challenge_seq(&challenge), &[0; 16]))
+        .await;
+    let response = harness.next_response().await;
+    assert_eq!(&response[..2], &[0xC3, 0x83]);
+    assert_eq!(error_code(&response), AuthErrorCode::AuthenticationFailed);
+    harness.check_events(&[Event::AuthenticationFailed(
+        USER,
+        AuthErrorCode::AuthenticationFailed,
+    )]);
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn rejects_critical_requests_without_session_keys() {
+    let mut harness = new_auth_harness();
+    let (_, challenge) = challenge_write(&mut harness).await;
+
+    harness
+        .send_and_process(&reply(3, challenge_seq(&challenge), &[0; 16]))
+        .await;
+    let response = harness.next_response().await;
+    assert_eq!(error_code(&response), AuthErrorCode::AuthenticationFailed);
+    harness.check_events(&[Event::AuthenticationFailed(
+        USER,
+        AuthErrorCode::AuthenticationFailed,
+    )]);
+}
+
+#[tokio::test]
+async fn processes_aggressive_mode_request() {
+    let mut harness = new_auth_harness();
+    let (keys, _) = change_session_keys(&mut harness).await;
+    let (write, challenge) = challenge_write(&mut harness).await;
+    let challenge_seq = challenge_seq(&challenge);
+    let mac = calc_mac(MAC, &keys.control, &[&challenge, &write]);
+    harness
+        .test_request_response(&reply(3, challenge_seq, &mac), &[0xC3, 0x81, 0x00, 0x00])
+        .await;
+    harness.check_events(&[Event::ClearRestartIIN]);
+
+    let aggressive = aggressive_write(4, challenge_seq + 1, &keys, &challenge);
+    harness
+        .test_request_response(&aggressive, &[0xC4, 0x81, 0x00, 0x00])
+        .await;
+    harness.check_events(&[Event::ClearRestartIIN]);
+
+    // each aggressive mode request must increment the challenge sequence number
+    let replayed = aggressive_write(5, challenge_seq + 1, &keys, &challenge);
+    harness.send_and_process(&replayed).await;
+    let response = harness.next_response().await;
+    assert_eq!(&response[..2], &[0xC5, 0x83]);
+    assert_eq!(error_code(&response), AuthErrorCode::AuthenticationFailed);
+    harness.check_events(&[Event::AuthenticationFailed(
+        USER,
+        AuthErrorCode::AuthenticationFailed,
+    )]);
+}
+
+#[tokio::test]
+async fn rejects_aggressive_mode_when_not_allowed() {
+    let config = OutstationAuthConfig {
+        allow_aggressive_mode: false,
+        ..Default::default()
+    };
+    let mut harness = new_harness_with_authentication(get_default_config(), config);
+    let (keys, _) = change_session_keys(&mut harness).await;
+    let (write, challenge) = challenge_write(&mut harness).await;
+    let challenge_seq = challenge_seq(&challenge);
+    let mac = calc_mac(MAC, &keys.control, &[&challenge, &write]);
+    harness
+        .test_request_response(&reply(3, challenge_seq, &mac), &[0xC3, 0x81, 0x00, 0x00])
+        .await;
+    harness.check_events(&[Event::ClearRestartIIN]);
+
+    harness
+        .send_and_process(&aggressive_write(4, challenge_seq + 1, &keys, &challenge))
+        .await;
+    let response = harness.next_response().await;
+    assert_eq!(
+        error_code(&response),
+        AuthErrorCode::AggressiveModeNotSupported
+    );
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn processes_aggressive_mode_request_after_key_status() {
+    let mut harness = new_auth_harness();
+    let (keys, status) = change_session_keys(&mut harness).await;
+    let (key_change_seq, _, _, _) = key_status(&status);
+
+    // the last key status is challenge data just like a challenge
+    harness
+        .test_request_response(
+            &aggressive_write(2, key_change_seq + 1, &keys, &status),
+            &[0xC2, 0x81, 0x00, 0x00],
+        )
+        .await;
+    harness.check_events(&[Event::ClearRestartIIN]);
+
+    harness
+        .test_request_response(
+            &aggressive_write(3, key_change_seq + 2, &keys, &status),
+            &[0xC3, 0x81, 0x00, 0x00],
+        )
+        .await;
+    harness.check_events(&[Event::ClearRestartIIN]);
+}
+
+#[tokio::test]
+async fn discards_challenged_request_when_reply_times_out() {
+    let mut harness = new_auth_harness();
+    let (keys, _) = change_session_keys(&mut harness).await;
+    let (write, challenge) = challenge_write(&mut harness).await;
+
+    tokio::time::pause();
+    let start = tokio::time::Instant::now();
+    harness.wait_for_events(&[Event::ReplyTimeouts(1)]).await;
+    assert!(start.elapsed() >= OutstationAuthConfig::default().reply_timeout);
+
+    // the late reply is discarded without a response
+    let mac = calc_mac(MAC, &keys.control, &[&challenge, &write]);
+    harness
+        .send_and_process(&reply(3, challenge_seq(&challenge), &mac))
+        .await;
+    harness
+        .test_request_response(&[0xC4, 0x01, 60, 1, 0x06], &[0xC4, 0x81, 0x80, 0x00])
+        .await;
+    harness.check_no_events();
+}
@@ -1,4 +1,5 @@
-use crate::app::Timestamp;
+use crate::app::{IntervalUnit, TimeAndInterval, Timestamp};
+use crate::outstation::traits::RequestError;
 
 use super::harness::*;
 
@@ -58,3 +59,67 @@ async fn lan_procedure() {
 
     harness.check_events(&[Event::WriteAbsoluteTime(Timestamp::new(0 + 0xCAFE))]);
 }
+
+#[tokio::test]
+async fn delivers_indexed_time_and_interval_to_application() {
+    let mut harness = new_harness(get_default_config());
+
+    // WRITE g50v4 with 1-byte count and prefix
+    harness
+        .test_request_response(
+            &[
+                0xC0, 0x02, 50, 4, 0x17, 1, 3, 0xE8, 0x03, 0, 0, 0, 0, 15, 0, 0, 0, 3,
+            ],
+            EMPTY_RESPONSE_SEQ0,
+        )
+        .await;
+    // WRITE g50v4 with 2-byte count and prefix
+    harness
+        .test_request_response(
+            &[
+                0xC1, 0x02, 50, 4, 0x28, 2, 0, 0x2C, 0x01, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 5, 0x2D,
+                0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x80,
+            ],
+            EMPTY_RESPONSE_SEQ1,
+        )
+        .await;
+
+    harness.check_events(&[
+        Event::WriteTimeAndInterval(vec![(
+            TimeAndInterval::new(Timestamp::new(1000), 15, IntervalUnit::Minutes),
+            3,
+        )]),
+        Event::WriteTimeAndInterval(vec![
+            (
+                TimeAndInterval::new(Timestamp::new(0), 1, IntervalUnit::Days),
+                300,
+            ),
+            (
+                TimeAndInterval::new(Timestamp::new(0), 0, IntervalUnit::Unknown(0x80)),
+                301,
+            ),
+        ]),
+    ]);
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn sets_iin2_when_application_rejects_time_and_interval() {
+    let mut harness = new_harness(get_default_config());
+
+    harness.application_data.lock().unwrap().application_error = Some(RequestError::ParameterError);
+    harness
+        .test_request_response(
+            &[
+                0xC0, 0x02, 50, 4, 0x17, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            ],
+            &[0xC0, 0x81, 0x80, 0x04],
+        )
+        .await;
+
+    harness.check_events(&[Event::WriteTimeAndInterval(vec![(
+        TimeAndInterval::new(Timestamp::new(0), 0, IntervalUnit::None),
+        0,
+    )])]);
+    harness.check_no_events();
+}
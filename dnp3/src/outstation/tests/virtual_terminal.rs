@@ -0,0 +1,123 @@
+use futures::{FutureExt, StreamExt};
+
+use crate::outstation::database::EventClass;
+use crate::outstation::tests::harness::*;
+use crate::util::virtual_terminal::MAX_BUFFERED_BLOCKS;
+
+const EMPTY_RESPONSE: &[u8] = &[0xC0, 0x81, 0x80, 0x00];
+const PARAMETER_ERROR: &[u8] = &[0xC0, 0x81, 0x80, 0x04];
+
+#[tokio::test]
+async fn master_output_is_delivered_to_open_port() {
+    let mut harness = new_harness(get_default_config());
+    let mut terminal = harness.open_virtual_terminal(2, EventClass::Class1).await;
+
+    // 2-byte count and prefix
+    harness
+        .test_request_response(
+            &[
+                0xC0, 0x02, 112, 0x03, 0x28, 0x01, 0x00, 0x02, 0x00, b'a', b'b', b'c',
+            ],
+            EMPTY_RESPONSE,
+        )
+        .await;
+
+    // 1-byte count and prefix
+    harness
+        .test_request_response(
+            &[0xC1, 0x02, 112, 0x01, 0x17, 0x01, 0x02, b'd'],
+            &[0xC1, 0x81, 0x80, 0x00],
+        )
+        .await;
+
+    assert_eq!(terminal.read().await.unwrap(), b"abc".to_vec());
+    assert_eq!(terminal.read().await.unwrap(), b"d".to_vec());
+}
+
+#[tokio::test]
+async fn master_output_may_span_multiple_headers() {
+    let mut harness = new_harness(get_default_config());
+    let mut terminal = harness.open_virtual_terminal(0, EventClass::Class1).await;
+
+    harness
+        .test_request_response(
+            &[
+                0xC0, 0x02, 112, 0x02, 0x28, 0x01, 0x00, 0x00, 0x00, b'a', b'b', 112, 0x01, 0x28,
+                0x01, 0x00, 0x00, 0x00, b'c',
+            ],
+            EMPTY_RESPONSE,
+        )
+        .await;
+
+    assert_eq!(terminal.read().await.unwrap(), b"ab".to_vec());
+    assert_eq!(terminal.read().await.unwrap(), b"c".to_vec());
+}
+
+#[tokio::test]
+async fn rejects_output_for_closed_port() {
+    let mut harness = new_harness(get_default_config());
+    let _terminal = harness.open_virtual_terminal(0, EventClass::Class1).await;
+
+    harness
+        .test_request_response(
+            &[0xC0, 0x02, 112, 0x01, 0x28, 0x01, 0x00, 0x01, 0x00, b'a'],
+            PARAMETER_ERROR,
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn rejects_output_when_stream_is_full() {
+    let mut harness = new_harness(get_default_config());
+    let mut terminal = harness.open_virtual_terminal(1, EventClass::Class1).await;
+
+    // one more single byte block than the stream can buffer
+    let mut request = vec![0xC0, 0x02];
+    for i in 0..=MAX_BUFFERED_BLOCKS {
+        request.extend_from_slice(&[112, 0x01, 0x17, 0x01, 0x01, i as u8]);
+    }
+    harness
+        .test_request_response(&request, PARAMETER_ERROR)
+        .await;
+
+    for i in 0..MAX_BUFFERED_BLOCKS {
+        assert_eq!(terminal.next().await.unwrap(), vec![i as u8]);
+    }
+    assert!(terminal.next().now_or_never().is_none());
+}
+
+#[tokio::test]
+async fn outstation_output_is_reported_as_events() {
+    let mut harness = new_harness(get_default_config());
+    let terminal = harness.open_virtual_terminal(2, EventClass::Class1).await;
+
+    assert!(terminal.write(b"hi"));
+
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 60, 2, 0x06],
+            &[
+                0xE0, 0x81, 0x80, 0x00, 113, 0x02, 0x28, 0x01, 0x00, 0x02, 0x00, b'h', b'i',
+            ],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn long_outstation_output_is_split_into_blocks() {
+    let mut harness = new_harness(get_default_config());
+    let terminal = harness.open_virtual_terminal(1, EventClass::Class2).await;
+
+    let output = [0x55; 300];
+    assert!(terminal.write(&output));
+
+    let mut expected = vec![0xE0, 0x81, 0x80, 0x00];
+    expected.extend_from_slice(&[113, 0xFF, 0x28, 0x01, 0x00, 0x01, 0x00]);
+    expected.extend_from_slice(&output[..255]);
+    expected.extend_from_slice(&[113, 0x2D, 0x28, 0x01, 0x00, 0x01, 0x00]);
+    expected.extend_from_slice(&output[255..]);
+
+    harness
+        .test_request_response(&[0xC0, 0x01, 113, 0x00, 0x06], &expected)
+        .await;
+}
@@ -1,11 +1,15 @@
+use crate::app::measurement::OctetString;
 use crate::app::parse::count::CountSequence;
 use crate::app::parse::prefix::Prefix;
 use crate::app::parse::traits::{FixedSizeVariation, Index};
 use crate::app::RequestHeader;
 use crate::app::Sequence;
-use crate::app::{control::*, Timestamp};
+use crate::app::{
+    control::*, ActivateConfigResponse, ApplicationIdentifier, Attribute, ConfigurationReference,
+    TimeAndInterval, Timestamp,
+};
 use crate::app::{FunctionCode, MaybeAsync};
-use crate::outstation::database::DatabaseHandle;
+use crate::outstation::database::{DatabaseHandle, EventClass, PointType, UpdateOptions};
 
 /// Application-controlled IIN bits
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
@@ -30,11 +34,18 @@ pub enum RestartDelay {
 }
 
 /// Enum describing the result of an operation
+///
+/// Errors returned by [OutstationApplication] methods are reported to the master in the IIN
+/// bits of the response.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum RequestError {
     /// outstation supports this operation, but the parameter(s) are nonsensical.
+    ///
+    /// Sets IIN2.2 PARAMETER_ERROR in the response.
     ParameterError,
     /// outstation does not support this operation
+    ///
+    /// Sets IIN2.0 NO_FUNC_CODE_SUPPORT in the response.
     NotSupported,
 }
 
@@ -70,6 +81,38 @@ pub trait OutstationApplication: Sync + Send + 'static {
         Err(RequestError::NotSupported)
     }
 
+    /// Handle a WRITE of binary output values using the packed format (g10v1)
+    ///
+    /// The values are provided as (value, index) pairs in the order they appear in the request.
+    fn write_binary_outputs(
+        &mut self,
+        _values: &mut dyn Iterator<Item = (bool, u16)>,
+        _database: &mut DatabaseHandle,
+    ) -> Result<(), RequestError> {
+        Err(RequestError::NotSupported)
+    }
+
+    /// Handle a WRITE of indexed absolute time and long interval objects (g50v4)
+    ///
+    /// These are typically used to configure the freeze schedule of individual counters.
+    fn write_time_and_interval(
+        &mut self,
+        _values: &mut dyn Iterator<Item = (TimeAndInterval, u16)>,
+    ) -> Result<(), RequestError> {
+        Err(RequestError::NotSupported)
+    }
+
+    /// Handle a WRITE of an octet string (g110) to a point that exists in the database
+    ///
+    /// Return the options used to update the point in the database, or an error to reject it.
+    fn write_octet_string(
+        &mut self,
+        _index: u16,
+        _value: &OctetString,
+    ) -> Result<UpdateOptions, RequestError> {
+        Err(RequestError::NotSupported)
+    }
+
     /// Returns the application-controlled IIN bits
     fn get_application_iin(&self) -> ApplicationIin {
         ApplicationIin::default()
@@ -101,7 +144,19 @@ pub trait OutstationApplication: Sync + Send + 'static {
         None
     }
 
+    /// Returns the current time of the outstation
+    ///
+    /// This is used to schedule the freezes requested by FREEZE_AT_TIME relative to the
+    /// absolute time in the g50v2 object. The default implementation uses the system clock.
+    /// Returning None rejects requests with a non-zero time with IIN2.2 PARAMETER_ERROR.
+    fn get_current_time(&self) -> Option<Timestamp> {
+        Timestamp::try_from_system_time(std::time::SystemTime::now())
+    }
+
     /// Perform a counter freeze operation
+    ///
+    /// Freezes of type [`FreezeType::FreezeAtTime`] are performed by the outstation when
+    /// the schedule expires. Errors returned for these freezes are logged.
     fn freeze_counter(
         &mut self,
         _indices: FreezeIndices,
@@ -110,6 +165,68 @@ pub trait OutstationApplication: Sync + Send + 'static {
     ) -> Result<(), RequestError> {
         Err(RequestError::NotSupported)
     }
+
+    /// Handle a WRITE of a device attribute (g0) defined as writable in the database
+    ///
+    /// The outstation has already verified that the attribute exists, is writable, and that
+    /// the data type matches the defined value. Return true to accept the value and update
+    /// the database, or false to reject it with IIN2.2 PARAMETER_ERROR.
+    fn write_device_attr(&mut self, _attr: Attribute) -> bool {
+        true
+    }
+
+    /// Notification that the master wrote the deadband (g34) of an analog input
+    ///
+    /// The new deadband has already been applied to the point in the database and is used
+    /// for all subsequent event detection.
+    fn analog_input_deadband_written(&mut self, _index: u16, _deadband: f64) {}
+
+    /// Notification that the master assigned the event class of points using ASSIGN_CLASS (g60)
+    ///
+    /// The new class has already been applied to the points in the database and is used for all
+    /// subsequent events. Requests that reference points that do not exist are rejected with
+    /// IIN2.2 PARAMETER_ERROR without changing the class of any point or calling this method.
+    fn class_assigned(&mut self, _assignment: ClassAssignment) {}
+
+    /// Prepare an application to be started using INITIALIZE_APPLICATION (g90v1)
+    ///
+    /// Called once for each application identifier in the request.
+    fn initialize_application(
+        &mut self,
+        _application: ApplicationIdentifier,
+    ) -> Result<(), RequestError> {
+        Err(RequestError::NotSupported)
+    }
+
+    /// Start running an application using START_APPLICATION (g90v1)
+    ///
+    /// Called once for each application identifier in the request.
+    fn start_application(
+        &mut self,
+        _application: ApplicationIdentifier,
+    ) -> Result<(), RequestError> {
+        Err(RequestError::NotSupported)
+    }
+
+    /// Stop running an application using STOP_APPLICATION (g90v1)
+    ///
+    /// Called once for each application identifier in the request.
+    fn stop_application(
+        &mut self,
+        _application: ApplicationIdentifier,
+    ) -> Result<(), RequestError> {
+        Err(RequestError::NotSupported)
+    }
+
+    /// Activate the configurations referenced in an ACTIVATE_CONFIG request
+    ///
+    /// The returned g91v1 object should contain a status for each configuration.
+    fn activate_config(
+        &mut self,
+        _configurations: &[ConfigurationReference],
+    ) -> Result<ActivateConfigResponse, RequestError> {
+        Err(RequestError::NotSupported)
+    }
 }
 
 /// enumeration describing how the outstation processed a broadcast request
@@ -218,6 +335,26 @@ pub enum FreezeIndices {
     Range(u16, u16),
 }
 
+/// Range of points selected by an ASSIGN_CLASS request
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PointRange {
+    /// All points of the type
+    All,
+    /// Range of points (the range is inclusive)
+    Range(u16, u16),
+}
+
+/// Event class assignment performed by the master using the ASSIGN_CLASS function code
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ClassAssignment {
+    /// Type of the points
+    pub point_type: PointType,
+    /// Points of the type that were assigned
+    pub range: PointRange,
+    /// New event class of the points, None if they no longer produce events
+    pub class: Option<EventClass>,
+}
+
 /// Freeze operation type
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum FreezeType {
@@ -226,6 +363,9 @@ pub enum FreezeType {
     /// Copy the current value of a counter to the associated point and
     /// clear the current value to 0
     FreezeAndClear,
+    /// Copy the current value of a counter to the associated point at the time
+    /// and interval scheduled by a FREEZE_AT_TIME request (g50v2)
+    FreezeAtTime,
 }
 
 /// callbacks for handling controls
@@ -0,0 +1,62 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::outstation::database::{DatabaseHandle, EventClass};
+use crate::util::virtual_terminal::VirtualTerminalReceiver;
+
+/// Byte stream for a virtual terminal port (groups 112/113) of the outstation
+///
+/// Blocks written by the master (g112) are received as a [futures::Stream]. Output written using
+/// [VirtualTerminal::write] is reported to the master as events (g113). The stream ends when the
+/// outstation is shut down or the port is opened again.
+pub struct VirtualTerminal {
+    port: u16,
+    class: EventClass,
+    database: DatabaseHandle,
+    rx: VirtualTerminalReceiver,
+}
+
+impl VirtualTerminal {
+    pub(crate) fn new(
+        port: u16,
+        class: EventClass,
+        database: DatabaseHandle,
+        rx: VirtualTerminalReceiver,
+    ) -> Self {
+        Self {
+            port,
+            class,
+            database,
+            rx,
+        }
+    }
+
+    /// index of the virtual terminal port
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Retrieve the next block written by the master to the port
+    ///
+    /// Returns `None` once the stream has ended
+    pub async fn read(&mut self) -> Option<Vec<u8>> {
+        self.rx.recv().await
+    }
+
+    /// Write output to the master using virtual terminal events (g113)
+    ///
+    /// The output is split into events of at most 255 bytes. Returns false if the
+    /// event buffer is not configured to hold virtual terminal events.
+    pub fn write(&self, data: &[u8]) -> bool {
+        self.database
+            .transaction(|db| db.record_virtual_terminal_output(self.port, self.class, data))
+    }
+}
+
+impl futures::Stream for VirtualTerminal {
+    type Item = Vec<u8>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
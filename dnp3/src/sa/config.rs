@@ -0,0 +1,63 @@
+use std::time::Duration;
+
+use crate::sa::{MacAlgorithm, UpdateKey, UserNumber};
+
+/// Secure authentication configuration of a master association
+#[derive(Copy, Clone, Debug)]
+pub struct MasterAuthConfig {
+    /// user on whose behalf the master sends requests
+    pub user: UserNumber,
+    /// pre-shared update key of the user
+    pub update_key: UpdateKey,
+    /// if true, critical requests carry their own MAC (aggressive mode) once a challenge has been received
+    pub aggressive_mode: bool,
+    /// interval after which the master changes the session keys (default == 15 minutes)
+    pub session_key_change_interval: Duration,
+}
+
+impl MasterAuthConfig {
+    /// Create a configuration for a user with default values for the other fields
+    pub fn new(user: UserNumber, update_key: UpdateKey) -> Self {
+        Self {
+            user,
+            update_key,
+            aggressive_mode: false,
+            session_key_change_interval: Duration::from_secs(15 * 60),
+        }
+    }
+}
+
+/// Secure authentication configuration of an outstation
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OutstationAuthConfig {
+    /// algorithm used to authenticate challenge replies (default == HMAC-SHA-256 truncated to 16 octets)
+    pub mac_algorithm: MacAlgorithm,
+    /// number of random bytes in each challenge, values less than 4 are increased to 4 (default == 4)
+    pub challenge_size: usize,
+    /// time after which session keys expire unless changed (default == 30 minutes)
+    pub session_key_timeout: Duration,
+    /// if true, the outstation accepts critical requests authenticated in aggressive mode (default == true)
+    pub allow_aggressive_mode: bool,
+    /// time the outstation waits for the reply to a challenge before discarding the challenged request (default == 2 seconds)
+    pub reply_timeout: Duration,
+}
+
+impl OutstationAuthConfig {
+    pub(crate) const MIN_CHALLENGE_SIZE: usize = 4;
+
+    pub(crate) fn challenge_size(&self) -> usize {
+        self.challenge_size.max(Self::MIN_CHALLENGE_SIZE)
+    }
+}
+
+impl Default for OutstationAuthConfig {
+    fn default() -> Self {
+        Self {
+            mac_algorithm: MacAlgorithm::HmacSha256Truncated16,
+            challenge_size: Self::MIN_CHALLENGE_SIZE,
+            session_key_timeout: Duration::from_secs(30 * 60),
+            allow_aggressive_mode: true,
+            reply_timeout: Duration::from_secs(2),
+        }
+    }
+}
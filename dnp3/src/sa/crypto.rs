@@ -0,0 +1,279 @@
+use aes::{Aes128, Aes256, Block, BlockDecrypt, BlockEncrypt, NewBlockCipher};
+use ring::hmac;
+use ring::rand::{SecureRandom, SystemRandom};
+
+use crate::sa::{KeyWrapAlgorithm, MacAlgorithm, UpdateKey};
+
+/// initial value defined in RFC 3394
+const KEY_WRAP_IV: [u8; 8] = [0xA6; 8];
+
+/// calculate the HMAC-SHA-256 of the concatenation of `parts`, truncated for the algorithm
+pub(crate) fn calc_mac(algorithm: MacAlgorithm, key: &[u8], parts: &[&[u8]]) -> Vec<u8> {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, key);
+    let mut context = hmac::Context::with_key(&key);
+    for part in parts {
+        context.update(part);
+    }
+    context.sign().as_ref()[..algorithm.length()].to_vec()
+}
+
+/// verify a received MAC in constant time
+pub(crate) fn verify_mac(
+    algorithm: MacAlgorithm,
+    key: &[u8],
+    parts: &[&[u8]],
+    received: &[u8],
+) -> bool {
+    let expected = calc_mac(algorithm, key, parts);
+    ring::constant_time::verify_slices_are_equal(&expected, received).is_ok()
+}
+
+/// generate cryptographically secure random bytes
+pub(crate) fn random_bytes(length: usize) -> Option<Vec<u8>> {
+    let mut bytes = vec![0; length];
+    SystemRandom::new().fill(&mut bytes).ok()?;
+    Some(bytes)
+}
+
+enum Cipher {
+    Aes128(Box<Aes128>),
+    Aes256(Box<Aes256>),
+}
+
+impl Cipher {
+    fn new(key: &[u8]) -> Option<Self> {
+        match key.len() {
+            16 => Some(Self::Aes128(Box::new(Aes128::new_from_slice(key).ok()?))),
+            32 => Some(Self::Aes256(Box::new(Aes256::new_from_slice(key).ok()?))),
+            _ => None,
+        }
+    }
+
+    fn encrypt(&self, block: &mut Block) {
+        match self {
+            Self::Aes128(x) => x.encrypt_block(block),
+            Self::Aes256(x) => x.encrypt_block(block),
+        }
+    }
+
+    fn decrypt(&self, block: &mut Block) {
+        match self {
+            Self::Aes128(x) => x.decrypt_block(block),
+            Self::Aes256(x) => x.decrypt_block(block),
+        }
+    }
+}
+
+fn xor_counter(value: &mut [u8], t: u64) {
+    for (x, y) in value.iter_mut().zip(t.to_be_bytes()) {
+        *x ^= y;
+    }
+}
+
+/// AES key wrap as defined in RFC 3394
+///
+/// The plaintext must be a multiple of 8 bytes and at least 16 bytes long
+pub(crate) fn key_wrap(kek: &[u8], plaintext: &[u8]) -> Option<Vec<u8>> {
+    if !plaintext.len().is_multiple_of(8) || plaintext.len() < 16 {
+        return None;
+    }
+
+    let cipher = Cipher::new(kek)?;
+    let n = plaintext.len() / 8;
+    let mut a = KEY_WRAP_IV;
+    let mut r: Vec<[u8; 8]> = plaintext
+        .chunks_exact(8)
+        .map(|x| {
+            let mut block = [0; 8];
+            block.copy_from_slice(x);
+            block
+        })
+        .collect();
+
+    for j in 0..6 {
+        for (i, item) in r.iter_mut().enumerate() {
+            let mut block = Block::default();
+            block[..8].copy_from_slice(&a);
+            block[8..].copy_from_slice(item);
+            cipher.encrypt(&mut block);
+            a.copy_from_slice(&block[..8]);
+            xor_counter(&mut a, (n * j + i + 1) as u64);
+            item.copy_from_slice(&block[8..]);
+        }
+    }
+
+    let mut output = a.to_vec();
+    for x in r {
+        output.extend_from_slice(&x);
+    }
+    Some(output)
+}
+
+/// AES key unwrap as defined in RFC 3394, `None` if the integrity check fails
+pub(crate) fn key_unwrap(kek: &[u8], ciphertext: &[u8]) -> Option<Vec<u8>> {
+    if !ciphertext.len().is_multiple_of(8) || ciphertext.len() < 24 {
+        return None;
+    }
+
+    let cipher = Cipher::new(kek)?;
+    let n = ciphertext.len() / 8 - 1;
+    let mut a = [0; 8];
+    a.copy_from_slice(&ciphertext[..8]);
+    let mut r: Vec<[u8; 8]> = ciphertext[8..]
+        .chunks_exact(8)
+        .map(|x| {
+            let mut block = [0; 8];
+            block.copy_from_slice(x);
+            block
+        })
+        .collect();
+
+    for j in (0..6).rev() {
+        for (i, item) in r.iter_mut().enumerate().rev() {
+            xor_counter(&mut a, (n * j + i + 1) as u64);
+            let mut block = Block::default();
+            block[..8].copy_from_slice(&a);
+            block[8..].copy_from_slice(item);
+            cipher.decrypt(&mut block);
+            a.copy_from_slice(&block[..8]);
+            item.copy_from_slice(&block[8..]);
+        }
+    }
+
+    if ring::constant_time::verify_slices_are_equal(&a, &KEY_WRAP_IV).is_err() {
+        return None;
+    }
+
+    Some(r.concat())
+}
+
+/// Control and monitoring direction session keys of a user
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) struct SessionKeys {
+    /// authenticates messages from the master to the outstation
+    pub(crate) control: Vec<u8>,
+    /// authenticates messages from the outstation to the master
+    pub(crate) monitor: Vec<u8>,
+}
+
+impl SessionKeys {
+    /// generate random keys of the same length as the update key
+    pub(crate) fn generate(algorithm: KeyWrapAlgorithm) -> Option<Self> {
+        Some(Self {
+            control: random_bytes(algorithm.key_length())?,
+            monitor: random_bytes(algorithm.key_length())?,
+        })
+    }
+
+    /// wrap the keys along with the key status message received from the outstation
+    pub(crate) fn wrap(&self, update_key: &UpdateKey, status_message: &[u8]) -> Option<Vec<u8>> {
+        let length = u16::try_from(self.control.len()).ok()?;
+        let mut data = length.to_le_bytes().to_vec();
+        data.extend_from_slice(&self.control);
+        data.extend_from_slice(&self.monitor);
+        data.extend_from_slice(status_message);
+        // pad to a multiple of the key wrap block size
+        while !data.len().is_multiple_of(8) {
+            data.push(0);
+        }
+        key_wrap(update_key.as_bytes(), &data)
+    }
+
+    /// unwrap keys wrapped by the master, returning them with the rest of the data
+    ///
+    /// The rest of the data is the key status message followed by padding
+    pub(crate) fn unwrap(update_key: &UpdateKey, wrapped: &[u8]) -> Option<(Self, Vec<u8>)> {
+        let data = key_unwrap(update_key.as_bytes(), wrapped)?;
+        let (length, rest) = data.split_at(2);
+        let length = u16::from_le_bytes([length[0], length[1]]) as usize;
+        if !(length == 16 || length == 32) || rest.len() < 2 * length {
+            return None;
+        }
+        let (control, rest) = rest.split_at(length);
+        let (monitor, rest) = rest.split_at(length);
+        let keys = Self {
+            control: control.to_vec(),
+            monitor: monitor.to_vec(),
+        };
+        Some((keys, rest.to_vec()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn hex(value: &str) -> Vec<u8> {
+        (0..value.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&value[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn wraps_128_bit_key_with_128_bit_kek() {
+        // RFC 3394 section 4.1
+        let kek = hex("000102030405060708090A0B0C0D0E0F");
+        let key = hex("00112233445566778899AABBCCDDEEFF");
+        let wrapped = key_wrap(&kek, &key).unwrap();
+        assert_eq!(
+            wrapped,
+            hex("1FA68B0A8112B447AEF34BD8FB5A7B829D3E862371D2CFE5")
+        );
+        assert_eq!(key_unwrap(&kek, &wrapped).unwrap(), key);
+    }
+
+    #[test]
+    fn wraps_256_bit_key_with_256_bit_kek() {
+        // RFC 3394 section 4.6
+        let kek = hex("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F");
+        let key = hex("00112233445566778899AABBCCDDEEFF000102030405060708090A0B0C0D0E0F");
+        let wrapped = key_wrap(&kek, &key).unwrap();
+        assert_eq!(
+            wrapped,
+            hex("28C9F404C4B810F4CBCCB35CFB87F8263F5786E2D80ED326CBC7F0E71A99F43BFB988B9B7A02DD21")
+        );
+        assert_eq!(key_unwrap(&kek, &wrapped).unwrap(), key);
+    }
+
+    #[test]
+    fn unwrap_detects_modified_ciphertext() {
+        let kek = hex("000102030405060708090A0B0C0D0E0F");
+        let mut wrapped = hex("1FA68B0A8112B447AEF34BD8FB5A7B829D3E862371D2CFE5");
+        wrapped[10] ^= 0x01;
+        assert!(key_unwrap(&kek, &wrapped).is_none());
+    }
+
+    #[test]
+    fn calculates_truncated_hmac_sha256() {
+        // RFC 4231 test case 2
+        let expected = hex("5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843");
+        let mac = calc_mac(
+            MacAlgorithm::HmacSha256Truncated16,
+            b"Jefe",
+            &[b"what do ya want ", b"for nothing?"],
+        );
+        assert_eq!(mac, expected[..16].to_vec());
+        assert!(verify_mac(
+            MacAlgorithm::HmacSha256Truncated8,
+            b"Jefe",
+            &[b"what do ya want for nothing?"],
+            &expected[..8]
+        ));
+    }
+
+    #[test]
+    fn session_keys_round_trip_through_key_wrap() {
+        let update_key = UpdateKey::aes128([0x55; 16]);
+        let keys = SessionKeys::generate(update_key.algorithm()).unwrap();
+        let status = [1, 2, 3, 4, 5];
+        let wrapped = keys.wrap(&update_key, &status).unwrap();
+        let (unwrapped, rest) = SessionKeys::unwrap(&update_key, &wrapped).unwrap();
+        assert!(unwrapped == keys);
+        assert_eq!(&rest[..5], &status);
+        assert!(rest[5..].iter().all(|x| *x == 0));
+
+        let other_key = UpdateKey::aes128([0x66; 16]);
+        assert!(SessionKeys::unwrap(&other_key, &wrapped).is_none());
+    }
+}
@@ -0,0 +1,9 @@
+pub use config::*;
+pub use traits::*;
+pub use types::*;
+
+mod config;
+/// HMAC, key wrap, and random number primitives
+pub(crate) mod crypto;
+mod traits;
+mod types;
@@ -0,0 +1,28 @@
+use crate::app::FunctionCode;
+use crate::sa::{AuthErrorCode, KeyStatus, SecurityStatistic, UpdateKey, UserNumber};
+
+/// Database of users known to an outstation
+pub trait UserStore: Sync + Send + 'static {
+    /// Retrieve the update key of a user, `None` if the user is unknown
+    fn get_update_key(&self, user: UserNumber) -> Option<UpdateKey>;
+
+    /// Check if a user is allowed to perform a critical function
+    ///
+    /// The default implementation authorizes every function
+    fn is_authorized(&self, _user: UserNumber, _function: FunctionCode) -> bool {
+        true
+    }
+}
+
+/// Informational callbacks about secure authentication
+///
+/// `statistic_changed` is the only way the security statistics counted by the outstation are
+/// reported
+pub trait AuthenticationInformation: Sync + Send + 'static {
+    /// a security statistic changed to a new value
+    fn statistic_changed(&mut self, _statistic: SecurityStatistic, _value: u32) {}
+    /// the status of the session keys of a user changed
+    fn session_key_status(&mut self, _user: UserNumber, _status: KeyStatus) {}
+    /// a request from a user failed authentication or authorization
+    fn authentication_failed(&mut self, _user: UserNumber, _error: AuthErrorCode) {}
+}
@@ -0,0 +1,343 @@
+use crate::app::FunctionCode;
+
+/// User number identifying the set of keys used to authenticate a request
+///
+/// User number 1 is the default user defined by the standard
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UserNumber(u16);
+
+impl UserNumber {
+    /// The default user defined by the standard
+    pub const DEFAULT: UserNumber = UserNumber(1);
+
+    /// Create a user number from its raw value
+    pub const fn new(value: u16) -> Self {
+        Self(value)
+    }
+
+    /// Raw value of the user number
+    pub const fn value(self) -> u16 {
+        self.0
+    }
+}
+
+impl Default for UserNumber {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl std::fmt::Display for UserNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Algorithm used to calculate the MAC of challenge replies and key status messages
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MacAlgorithm {
+    /// HMAC-SHA-256 truncated to 8 octets, intended for serial links
+    HmacSha256Truncated8,
+    /// HMAC-SHA-256 truncated to 16 octets, intended for networked links
+    HmacSha256Truncated16,
+}
+
+impl MacAlgorithm {
+    pub(crate) fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            3 => Some(Self::HmacSha256Truncated8),
+            4 => Some(Self::HmacSha256Truncated16),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn to_u8(self) -> u8 {
+        match self {
+            Self::HmacSha256Truncated8 => 3,
+            Self::HmacSha256Truncated16 => 4,
+        }
+    }
+
+    /// length of the MAC value in bytes
+    pub(crate) fn length(self) -> usize {
+        match self {
+            Self::HmacSha256Truncated8 => 8,
+            Self::HmacSha256Truncated16 => 16,
+        }
+    }
+}
+
+/// Algorithm used to wrap session keys with the update key
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KeyWrapAlgorithm {
+    /// AES-128 key wrap (RFC 3394)
+    Aes128,
+    /// AES-256 key wrap (RFC 3394)
+    Aes256,
+}
+
+impl KeyWrapAlgorithm {
+    pub(crate) fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(Self::Aes128),
+            2 => Some(Self::Aes256),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn to_u8(self) -> u8 {
+        match self {
+            Self::Aes128 => 1,
+            Self::Aes256 => 2,
+        }
+    }
+
+    /// length of the update key and of each session key in bytes
+    pub(crate) fn key_length(self) -> usize {
+        match self {
+            Self::Aes128 => 16,
+            Self::Aes256 => 32,
+        }
+    }
+}
+
+/// Status of the session keys of a user, as reported in g120v5
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KeyStatus {
+    /// Session keys are valid
+    Ok,
+    /// Session keys have not been initialized, or have expired
+    NotInit,
+    /// A communication failure invalidated the session keys
+    CommFail,
+    /// An authentication failure invalidated the session keys
+    AuthFail,
+    /// Value not defined by the standard
+    Unknown(u8),
+}
+
+impl KeyStatus {
+    pub(crate) fn new(value: u8) -> Self {
+        match value {
+            1 => Self::Ok,
+            2 => Self::NotInit,
+            3 => Self::CommFail,
+            4 => Self::AuthFail,
+            _ => Self::Unknown(value),
+        }
+    }
+
+    pub(crate) fn to_u8(self) -> u8 {
+        match self {
+            Self::Ok => 1,
+            Self::NotInit => 2,
+            Self::CommFail => 3,
+            Self::AuthFail => 4,
+            Self::Unknown(x) => x,
+        }
+    }
+}
+
+/// Error code reported in an authentication error object (g120v7)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AuthErrorCode {
+    /// The MAC of a challenge reply or aggressive mode request was invalid
+    AuthenticationFailed,
+    /// Aggressive mode is not permitted
+    AggressiveModeNotSupported,
+    /// The requested MAC algorithm is not supported
+    MacNotSupported,
+    /// The requested key wrap algorithm is not supported
+    KeyWrapNotSupported,
+    /// The user is not authorized to perform the requested function
+    AuthorizationFailed,
+    /// The update key change method is not permitted
+    UpdateKeyMethodNotPermitted,
+    /// A digital signature was invalid
+    InvalidSignature,
+    /// Certification data was invalid
+    InvalidCertification,
+    /// The user is not known to the outstation
+    UnknownUser,
+    /// Too many session key status requests were received
+    MaxSessionKeyStatusRequestsExceeded,
+    /// Value not defined by the standard
+    Unknown(u8),
+}
+
+impl AuthErrorCode {
+    pub(crate) fn new(value: u8) -> Self {
+        match value {
+            1 => Self::AuthenticationFailed,
+            4 => Self::AggressiveModeNotSupported,
+            5 => Self::MacNotSupported,
+            6 => Self::KeyWrapNotSupported,
+            7 => Self::AuthorizationFailed,
+            8 => Self::UpdateKeyMethodNotPermitted,
+            9 => Self::InvalidSignature,
+            10 => Self::InvalidCertification,
+            11 => Self::UnknownUser,
+            12 => Self::MaxSessionKeyStatusRequestsExceeded,
+            _ => Self::Unknown(value),
+        }
+    }
+
+    pub(crate) fn to_u8(self) -> u8 {
+        match self {
+            Self::AuthenticationFailed => 1,
+            Self::AggressiveModeNotSupported => 4,
+            Self::MacNotSupported => 5,
+            Self::KeyWrapNotSupported => 6,
+            Self::AuthorizationFailed => 7,
+            Self::UpdateKeyMethodNotPermitted => 8,
+            Self::InvalidSignature => 9,
+            Self::InvalidCertification => 10,
+            Self::UnknownUser => 11,
+            Self::MaxSessionKeyStatusRequestsExceeded => 12,
+            Self::Unknown(x) => x,
+        }
+    }
+}
+
+/// Pre-shared key used to wrap the session keys of a user
+///
+/// The key is never written to the log
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct UpdateKey {
+    bytes: [u8; 32],
+    algorithm: KeyWrapAlgorithm,
+}
+
+impl UpdateKey {
+    /// Create a 128-bit update key wrapped with AES-128
+    pub fn aes128(key: [u8; 16]) -> Self {
+        let mut bytes = [0; 32];
+        bytes[..16].copy_from_slice(&key);
+        Self {
+            bytes,
+            algorithm: KeyWrapAlgorithm::Aes128,
+        }
+    }
+
+    /// Create a 256-bit update key wrapped with AES-256
+    pub fn aes256(key: [u8; 32]) -> Self {
+        Self {
+            bytes: key,
+            algorithm: KeyWrapAlgorithm::Aes256,
+        }
+    }
+
+    /// Key wrap algorithm implied by the length of the key
+    pub fn algorithm(&self) -> KeyWrapAlgorithm {
+        self.algorithm
+    }
+
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.algorithm.key_length()]
+    }
+}
+
+impl std::fmt::Debug for UpdateKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "UpdateKey({:?}, <redacted>)", self.algorithm)
+    }
+}
+
+/// Security statistics defined by the standard
+///
+/// The outstation only delivers these to the application through
+/// [AuthenticationInformation::statistic_changed](crate::sa::AuthenticationInformation::statistic_changed).
+/// They are not reported to the master as g121/g122 objects.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SecurityStatistic {
+    /// Messages that were not expected in the current state
+    UnexpectedMessages,
+    /// Requests rejected by the user store because the user was not authorized
+    AuthorizationFailures,
+    /// Challenge replies or aggressive mode requests with an invalid MAC
+    AuthenticationFailures,
+    /// Challenges for which no reply was received
+    ReplyTimeouts,
+    /// Session key changes caused by an authentication failure
+    RekeysDueToAuthenticationFailure,
+    /// Total number of messages sent
+    TotalMessagesSent,
+    /// Total number of messages received
+    TotalMessagesReceived,
+    /// Critical messages sent
+    CriticalMessagesSent,
+    /// Critical messages received
+    CriticalMessagesReceived,
+    /// Messages that were discarded without processing
+    DiscardedMessages,
+    /// Error objects (g120v7) sent
+    ErrorMessagesSent,
+    /// Error objects (g120v7) received
+    ErrorMessagesReceived,
+    /// Critical requests that were successfully authenticated
+    SuccessfulAuthentications,
+    /// Successful session key changes
+    SessionKeyChanges,
+    /// Failed session key changes
+    FailedSessionKeyChanges,
+    /// Successful update key changes
+    UpdateKeyChanges,
+    /// Failed update key changes
+    FailedUpdateKeyChanges,
+    /// Session key changes caused by a restart
+    RekeysDueToRestarts,
+}
+
+impl SecurityStatistic {
+    pub(crate) const COUNT: usize = 18;
+
+    /// Index of the statistic in the list defined by the standard
+    pub fn index(self) -> u16 {
+        match self {
+            Self::UnexpectedMessages => 0,
+            Self::AuthorizationFailures => 1,
+            Self::AuthenticationFailures => 2,
+            Self::ReplyTimeouts => 3,
+            Self::RekeysDueToAuthenticationFailure => 4,
+            Self::TotalMessagesSent => 5,
+            Self::TotalMessagesReceived => 6,
+            Self::CriticalMessagesSent => 7,
+            Self::CriticalMessagesReceived => 8,
+            Self::DiscardedMessages => 9,
+            Self::ErrorMessagesSent => 10,
+            Self::ErrorMessagesReceived => 11,
+            Self::SuccessfulAuthentications => 12,
+            Self::SessionKeyChanges => 13,
+            Self::FailedSessionKeyChanges => 14,
+            Self::UpdateKeyChanges => 15,
+            Self::FailedUpdateKeyChanges => 16,
+            Self::RekeysDueToRestarts => 17,
+        }
+    }
+}
+
+/// Function codes that require authentication unless the outstation is told otherwise
+pub(crate) fn is_critical(function: FunctionCode) -> bool {
+    matches!(
+        function,
+        FunctionCode::Write
+            | FunctionCode::Select
+            | FunctionCode::Operate
+            | FunctionCode::DirectOperate
+            | FunctionCode::DirectOperateNoResponse
+            | FunctionCode::ColdRestart
+            | FunctionCode::WarmRestart
+            | FunctionCode::InitializeData
+            | FunctionCode::InitializeApplication
+            | FunctionCode::StartApplication
+            | FunctionCode::StopApplication
+            | FunctionCode::EnableUnsolicited
+            | FunctionCode::DisableUnsolicited
+            | FunctionCode::AssignClass
+            | FunctionCode::RecordCurrentTime
+            | FunctionCode::OpenFile
+            | FunctionCode::CloseFile
+            | FunctionCode::DeleteFile
+            | FunctionCode::AuthenticateFile
+    )
+}
@@ -24,7 +24,6 @@ impl Buffer {
         self.inner.get_mut(0..length)
     }
 
-    #[cfg(test)]
     pub(crate) fn len(&self) -> usize {
         self.inner.len()
     }
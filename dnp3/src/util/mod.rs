@@ -7,6 +7,7 @@ pub(crate) mod decode;
 pub(crate) mod future;
 pub(crate) mod phys;
 pub(crate) mod slice_ext;
+pub(crate) mod virtual_terminal;
 
 pub(crate) struct Smallest<T>
 where
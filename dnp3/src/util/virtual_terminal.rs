@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+
+use tokio::sync::mpsc::error::TrySendError;
+
+pub(crate) type VirtualTerminalSender = tokio::sync::mpsc::Sender<Vec<u8>>;
+pub(crate) type VirtualTerminalReceiver = tokio::sync::mpsc::Receiver<Vec<u8>>;
+
+/// maximum number of blocks buffered for a stream before new blocks are discarded
+pub(crate) const MAX_BUFFERED_BLOCKS: usize = 32;
+
+pub(crate) fn virtual_terminal_channel() -> (VirtualTerminalSender, VirtualTerminalReceiver) {
+    tokio::sync::mpsc::channel(MAX_BUFFERED_BLOCKS)
+}
+
+/// the variation of g112/g113 is the length of the block, so it can't exceed 255 bytes
+pub(crate) const MAX_BLOCK_SIZE: usize = u8::MAX as usize;
+
+/// Streams of virtual terminal data opened by the user, keyed by port index
+#[derive(Default)]
+pub(crate) struct VirtualTerminalPorts {
+    ports: BTreeMap<u16, VirtualTerminalSender>,
+}
+
+impl VirtualTerminalPorts {
+    /// replaces any stream previously opened on the same port
+    pub(crate) fn open(&mut self, port: u16, sender: VirtualTerminalSender) {
+        self.ports.insert(port, sender);
+    }
+
+    /// deliver a block to the port, returning false if it was discarded
+    pub(crate) fn deliver(&mut self, port: u16, data: &[u8]) -> bool {
+        let sender = match self.ports.get(&port) {
+            Some(x) => x,
+            None => {
+                tracing::warn!("no stream is open for virtual terminal port {}", port);
+                return false;
+            }
+        };
+
+        match sender.try_send(data.to_vec()) {
+            Ok(()) => true,
+            Err(TrySendError::Full(_)) => {
+                tracing::warn!(
+                    "discarding block for virtual terminal port {} b/c the stream is full",
+                    port
+                );
+                false
+            }
+            Err(TrySendError::Closed(_)) => {
+                tracing::warn!("stream for virtual terminal port {} was dropped", port);
+                self.ports.remove(&port);
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn discards_blocks_when_the_stream_is_full_or_dropped() {
+        let mut ports = VirtualTerminalPorts::default();
+        let (tx, mut rx) = virtual_terminal_channel();
+        ports.open(1, tx);
+
+        for _ in 0..MAX_BUFFERED_BLOCKS {
+            assert!(ports.deliver(1, b"a"));
+        }
+        assert!(!ports.deliver(1, b"b"));
+
+        // reading a block makes room for another
+        assert_eq!(rx.try_recv().unwrap(), b"a".to_vec());
+        assert!(ports.deliver(1, b"c"));
+
+        drop(rx);
+        assert!(!ports.deliver(1, b"d"));
+        assert!(!ports.deliver(2, b"e"));
+    }
+}
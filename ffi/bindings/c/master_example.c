@@ -128,6 +128,44 @@ void handle_analog_input(dnp3_header_info_t info, dnp3_analog_input_iterator_t *
     }
 }
 
+void handle_frozen_analog_input(dnp3_header_info_t info, dnp3_frozen_analog_input_iterator_t *it, void *arg)
+{
+    printf("Frozen Analogs:\n");
+    printf("Qualifier: %s \n", dnp3_qualifier_code_to_string(info.qualifier));
+    printf("Variation: %s \n", dnp3_variation_to_string(info.variation));
+
+    dnp3_frozen_analog_input_t *value = NULL;
+    while (value = dnp3_frozen_analog_input_iterator_next(it)) {
+        printf("Frozen AI %u: Value=%f Flags=0x%02X Time=%" PRIu64 "\n", value->index, value->value, value->flags.value, value->time.value);
+    }
+}
+
+void handle_binary_output_command_event(dnp3_header_info_t info, dnp3_binary_output_command_event_iterator_t *it, void *arg)
+{
+    printf("Binary Output Command Events:\n");
+    printf("Qualifier: %s \n", dnp3_qualifier_code_to_string(info.qualifier));
+    printf("Variation: %s \n", dnp3_variation_to_string(info.variation));
+
+    dnp3_binary_output_command_event_t *value = NULL;
+    while (value = dnp3_binary_output_command_event_iterator_next(it)) {
+        printf("BOCE %u: State=%s Status=%s Time=%" PRIu64 "\n", value->index, value->commanded_state ? "true" : "false",
+               dnp3_command_status_to_string(value->status), value->time.value);
+    }
+}
+
+void handle_analog_output_command_event(dnp3_header_info_t info, dnp3_analog_output_command_event_iterator_t *it, void *arg)
+{
+    printf("Analog Output Command Events:\n");
+    printf("Qualifier: %s \n", dnp3_qualifier_code_to_string(info.qualifier));
+    printf("Variation: %s \n", dnp3_variation_to_string(info.variation));
+
+    dnp3_analog_output_command_event_t *value = NULL;
+    while (value = dnp3_analog_output_command_event_iterator_next(it)) {
+        printf("AOCE %u: Value=%f Status=%s Time=%" PRIu64 "\n", value->index, value->commanded_value,
+               dnp3_command_status_to_string(value->status), value->time.value);
+    }
+}
+
 void handle_analog_output_status(dnp3_header_info_t info, dnp3_analog_output_status_iterator_t *it, void *arg)
 {
     printf("Analog Output Statuses:\n");
@@ -171,7 +209,10 @@ dnp3_read_handler_t get_read_handler()
         .handle_counter = &handle_counter,
         .handle_frozen_counter = &handle_frozen_counter,
         .handle_analog_input = &handle_analog_input,
+        .handle_frozen_analog_input = &handle_frozen_analog_input,
         .handle_analog_output_status = &handle_analog_output_status,
+        .handle_binary_output_command_event = &handle_binary_output_command_event,
+        .handle_analog_output_command_event = &handle_analog_output_command_event,
         .handle_octet_string = &handle_octet_strings,
         .on_destroy = NULL,
         .ctx = NULL,
@@ -86,12 +86,30 @@ class ReadHandler : public dnp3::ReadHandler {
             std::cout << "AnalogInput(" << value.index << "): value: " << value.value << " flags: " << value.flags << " time: " << value.time.value << std::endl;
         }
     }
+    void handle_frozen_analog_input(const dnp3::HeaderInfo& info, dnp3::FrozenAnalogInputIterator& it) override {
+        while (it.next()) {
+            const auto value = it.get();
+            std::cout << "FrozenAnalogInput(" << value.index << "): value: " << value.value << " flags: " << value.flags << " time: " << value.time.value << std::endl;
+        }
+    }
     void handle_analog_output_status(const dnp3::HeaderInfo& info, dnp3::AnalogOutputStatusIterator& it) override {
         while (it.next()) {
             const auto value = it.get();
             std::cout << "AnalogOutputStatus(" << value.index << "): value: " << value.value << " flags: " << value.flags << " time: " << value.time.value << std::endl;
         }
     }
+    void handle_binary_output_command_event(const dnp3::HeaderInfo& info, dnp3::BinaryOutputCommandEventIterator& it) override {
+        while (it.next()) {
+            const auto value = it.get();
+            std::cout << "BinaryOutputCommandEvent(" << value.index << "): state: " << value.commanded_state << " status: " << dnp3::to_string(value.status) << " time: " << value.time.value << std::endl;
+        }
+    }
+    void handle_analog_output_command_event(const dnp3::HeaderInfo& info, dnp3::AnalogOutputCommandEventIterator& it) override {
+        while (it.next()) {
+            const auto value = it.get();
+            std::cout << "AnalogOutputCommandEvent(" << value.index << "): value: " << value.commanded_value << " status: " << dnp3::to_string(value.status) << " time: " << value.time.value << std::endl;
+        }
+    }
     void handle_octet_string(const dnp3::HeaderInfo& info, dnp3::OctetStringIterator& it) override {
         while (it.next()) {
             auto value = it.get();
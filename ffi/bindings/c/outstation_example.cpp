@@ -183,7 +183,7 @@ public:
 // ANCHOR: event_buffer_config
 dnp3::EventBufferConfig get_event_buffer_config()
 {
-    return EventBufferConfig(10, 10, 10, 10, 10, 10, 10, 10);
+    return EventBufferConfig(10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 0, 0);
 }
 // ANCHOR_END: event_buffer_config
 
@@ -117,6 +117,18 @@ class MainClass
             }
         }
 
+        public void HandleFrozenAnalogInput(HeaderInfo info, ICollection<FrozenAnalogInput> values)
+        {
+            Console.WriteLine("Frozen Analog Inputs:");
+            Console.WriteLine("Qualifier: " + info.Qualifier);
+            Console.WriteLine("Variation: " + info.Variation);
+
+            foreach (var val in values)
+            {
+                Console.WriteLine($"Frozen AI {val.Index}: Value={val.Value} Flags={val.Flags.Value} Time={val.Time.Value} ({val.Time.Quality})");
+            }
+        }
+
         public void HandleAnalogOutputStatus(HeaderInfo info, ICollection<AnalogOutputStatus> values)
         {
             Console.WriteLine("Analog Output Statuses:");
@@ -129,6 +141,30 @@ class MainClass
             }
         }
 
+        public void HandleBinaryOutputCommandEvent(HeaderInfo info, ICollection<BinaryOutputCommandEvent> values)
+        {
+            Console.WriteLine("Binary Output Command Events:");
+            Console.WriteLine("Qualifier: " + info.Qualifier);
+            Console.WriteLine("Variation: " + info.Variation);
+
+            foreach (var val in values)
+            {
+                Console.WriteLine($"BOCE {val.Index}: State={val.CommandedState} Status={val.Status} Time={val.Time.Value} ({val.Time.Quality})");
+            }
+        }
+
+        public void HandleAnalogOutputCommandEvent(HeaderInfo info, ICollection<AnalogOutputCommandEvent> values)
+        {
+            Console.WriteLine("Analog Output Command Events:");
+            Console.WriteLine("Qualifier: " + info.Qualifier);
+            Console.WriteLine("Variation: " + info.Variation);
+
+            foreach (var val in values)
+            {
+                Console.WriteLine($"AOCE {val.Index}: Value={val.CommandedValue} Status={val.Status} Time={val.Time.Value} ({val.Time.Quality})");
+            }
+        }
+
         public void HandleOctetString(HeaderInfo info, ICollection<OctetString> values)
         {
             Console.WriteLine("Octet Strings:");
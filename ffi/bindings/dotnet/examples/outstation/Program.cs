@@ -328,11 +328,16 @@ class ExampleOutstation
             10, // binary
             10, // double-bit binary
             10, // binary output status
+            10, // binary output command
             5,  // counter
             5,  // frozen counter
             5,  // analog
+            5,  // frozen analog
             5,  // analog output status
-            3   // octet string
+            5,  // analog output command
+            3,  // octet string
+            0,  // data set
+            0   // virtual terminal
         );
     }
     // ANCHOR_END: event_buffer_config
@@ -189,6 +189,29 @@ class TestReadHandler implements ReadHandler {
           });
     }
 
+    @Override
+    public void handleFrozenAnalogInput(HeaderInfo info, List<FrozenAnalogInput> it) {
+      System.out.println("Frozen Analog Inputs:");
+      System.out.println("Qualifier: " + info.qualifier);
+      System.out.println("Variation: " + info.variation);
+
+      it.forEach(
+          val -> {
+            System.out.println(
+                "Frozen AI "
+                    + val.index
+                    + ": Value="
+                    + val.value
+                    + " Flags="
+                    + val.flags.value
+                    + " Time="
+                    + val.time.value
+                    + " ("
+                    + val.time.quality
+                    + ")");
+          });
+    }
+
     @Override
     public void handleAnalogOutputStatus(HeaderInfo info, List<AnalogOutputStatus> it) {
       System.out.println("Analog Output Statuses:");
@@ -212,6 +235,52 @@ class TestReadHandler implements ReadHandler {
           });
     }
 
+    @Override
+    public void handleBinaryOutputCommandEvent(HeaderInfo info, List<BinaryOutputCommandEvent> it) {
+      System.out.println("Binary Output Command Events:");
+      System.out.println("Qualifier: " + info.qualifier);
+      System.out.println("Variation: " + info.variation);
+
+      it.forEach(
+          val -> {
+            System.out.println(
+                "BOCE "
+                    + val.index
+                    + ": State="
+                    + val.commandedState
+                    + " Status="
+                    + val.status
+                    + " Time="
+                    + val.time.value
+                    + " ("
+                    + val.time.quality
+                    + ")");
+          });
+    }
+
+    @Override
+    public void handleAnalogOutputCommandEvent(HeaderInfo info, List<AnalogOutputCommandEvent> it) {
+      System.out.println("Analog Output Command Events:");
+      System.out.println("Qualifier: " + info.qualifier);
+      System.out.println("Variation: " + info.variation);
+
+      it.forEach(
+          val -> {
+            System.out.println(
+                "AOCE "
+                    + val.index
+                    + ": Value="
+                    + val.commandedValue
+                    + " Status="
+                    + val.status
+                    + " Time="
+                    + val.time.value
+                    + " ("
+                    + val.time.quality
+                    + ")");
+          });
+    }
+
     @Override
     public void handleOctetString(HeaderInfo info, List<OctetString> it) {
       System.out.println("Octet Strings:");
@@ -206,11 +206,16 @@ public class OutstationExample {
         ushort(10), // binary
         ushort(10), // double-bit binary
         ushort(10), // binary output status
+        ushort(10), // binary output command
         ushort(5), // counter
         ushort(5), // frozen counter
         ushort(5), // analog
+        ushort(5), // frozen analog
         ushort(5), // analog output status
-        ushort(3) // octet string
+        ushort(5), // analog output command
+        ushort(3), // octet string
+        ushort(0), // data set
+        ushort(0) // virtual terminal
         );
   }
   // ANCHOR_END: event_buffer_config
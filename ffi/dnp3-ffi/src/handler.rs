@@ -103,6 +103,16 @@ impl ReadHandler for ffi::ReadHandler {
         ffi::ReadHandler::handle_analog_input(self, info, &mut iterator);
     }
 
+    fn handle_frozen_analog_input(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (FrozenAnalogInput, u16)>,
+    ) {
+        let info = info.into();
+        let mut iterator = FrozenAnalogInputIterator::new(iter);
+        ffi::ReadHandler::handle_frozen_analog_input(self, info, &mut iterator);
+    }
+
     fn handle_analog_output_status(
         &mut self,
         info: HeaderInfo,
@@ -113,6 +123,26 @@ impl ReadHandler for ffi::ReadHandler {
         ffi::ReadHandler::handle_analog_output_status(self, info, &mut iterator);
     }
 
+    fn handle_binary_output_command_event(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (BinaryOutputCommandEvent, u16)>,
+    ) {
+        let info = info.into();
+        let mut iterator = BinaryOutputCommandEventIterator::new(iter);
+        ffi::ReadHandler::handle_binary_output_command_event(self, info, &mut iterator);
+    }
+
+    fn handle_analog_output_command_event(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (AnalogOutputCommandEvent, u16)>,
+    ) {
+        let info = info.into();
+        let mut iterator = AnalogOutputCommandEventIterator::new(iter);
+        ffi::ReadHandler::handle_analog_output_command_event(self, info, &mut iterator);
+    }
+
     fn handle_octet_string<'a>(
         &mut self,
         info: HeaderInfo,
@@ -178,6 +208,9 @@ impl From<ResponseHeader> for ffi::ResponseHeader {
             func: match header.function {
                 ResponseFunction::Response => ffi::ResponseFunction::Response,
                 ResponseFunction::UnsolicitedResponse => ffi::ResponseFunction::UnsolicitedResponse,
+                ResponseFunction::AuthenticateResponse => {
+                    ffi::ResponseFunction::AuthenticateResponse
+                }
             },
             iin: ffi::Iin {
                 iin1: header.iin.iin1.into(),
@@ -222,6 +255,14 @@ impl From<TaskType> for ffi::TaskType {
             TaskType::DisableUnsolicited => ffi::TaskType::DisableUnsolicited,
             TaskType::TimeSync => ffi::TaskType::TimeSync,
             TaskType::Restart => ffi::TaskType::Restart,
+            TaskType::FileTransfer => ffi::TaskType::FileTransfer,
+            TaskType::Write => ffi::TaskType::Write,
+            TaskType::AssignClass => ffi::TaskType::AssignClass,
+            TaskType::Freeze => ffi::TaskType::Freeze,
+            TaskType::ApplicationControl => ffi::TaskType::ApplicationControl,
+            TaskType::ActivateConfig => ffi::TaskType::ActivateConfig,
+            TaskType::Custom => ffi::TaskType::Custom,
+            TaskType::SessionKeyChange => ffi::TaskType::SessionKeyChange,
         }
     }
 }
@@ -293,12 +334,30 @@ implement_iterator!(
     AnalogInput,
     ffi::AnalogInput
 );
+implement_iterator!(
+    FrozenAnalogInputIterator,
+    frozen_analog_input_iterator_next,
+    FrozenAnalogInput,
+    ffi::FrozenAnalogInput
+);
 implement_iterator!(
     AnalogOutputStatusIterator,
     analog_output_status_iterator_next,
     AnalogOutputStatus,
     ffi::AnalogOutputStatus
 );
+implement_iterator!(
+    BinaryOutputCommandEventIterator,
+    binary_output_command_event_iterator_next,
+    BinaryOutputCommandEvent,
+    ffi::BinaryOutputCommandEvent
+);
+implement_iterator!(
+    AnalogOutputCommandEventIterator,
+    analog_output_command_event_iterator_next,
+    AnalogOutputCommandEvent,
+    ffi::AnalogOutputCommandEvent
+);
 
 impl ffi::BinaryInput {
     pub(crate) fn new(idx: u16, value: BinaryInput) -> Self {
@@ -372,6 +431,17 @@ impl ffi::AnalogInput {
     }
 }
 
+impl ffi::FrozenAnalogInput {
+    pub(crate) fn new(idx: u16, value: FrozenAnalogInput) -> Self {
+        Self {
+            index: idx,
+            value: value.value,
+            flags: value.flags.into(),
+            time: value.time.into(),
+        }
+    }
+}
+
 impl ffi::AnalogOutputStatus {
     pub(crate) fn new(idx: u16, value: AnalogOutputStatus) -> Self {
         Self {
@@ -383,6 +453,30 @@ impl ffi::AnalogOutputStatus {
     }
 }
 
+impl ffi::BinaryOutputCommandEvent {
+    pub(crate) fn new(idx: u16, value: BinaryOutputCommandEvent) -> Self {
+        ffi::BinaryOutputCommandEventFields {
+            index: idx,
+            commanded_state: value.commanded_state,
+            status: value.status.into(),
+            time: value.time.into(),
+        }
+        .into()
+    }
+}
+
+impl ffi::AnalogOutputCommandEvent {
+    pub(crate) fn new(idx: u16, value: AnalogOutputCommandEvent) -> Self {
+        ffi::AnalogOutputCommandEventFields {
+            index: idx,
+            commanded_value: value.commanded_value,
+            status: value.status.into(),
+            time: value.time.into(),
+        }
+        .into()
+    }
+}
+
 pub struct OctetStringIterator<'a> {
     inner: &'a mut dyn Iterator<Item = (&'a [u8], u16)>,
     next: Option<ffi::OctetString<'a>>,
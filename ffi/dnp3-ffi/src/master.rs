@@ -229,6 +229,8 @@ pub unsafe fn master_channel_add_association(
             config.event_scan_on_events_available(),
         ),
         max_queued_user_requests: config.max_queued_user_requests as usize,
+        authentication: None,
+        measurement_cache: false,
     };
 
     channel.runtime.block_on(channel.handle.add_association(
@@ -778,6 +780,7 @@ macro_rules! define_task_from_impl {
                     TaskError::NoConnection => ffi::$name::NoConnection,
                     TaskError::Shutdown => ffi::$name::Shutdown,
                     TaskError::Disabled => ffi::$name::NoConnection,
+                    TaskError::Authentication(_) => ffi::$name::AuthenticationFailure,
                 }
             }
         }
@@ -164,6 +164,7 @@ impl From<FreezeType> for ffi::FreezeType {
         match from {
             FreezeType::ImmediateFreeze => ffi::FreezeType::ImmediateFreeze,
             FreezeType::FreezeAndClear => ffi::FreezeType::FreezeAndClear,
+            FreezeType::FreezeAtTime => ffi::FreezeType::FreezeAtTime,
         }
     }
 }
@@ -391,8 +392,14 @@ impl From<FunctionCode> for ffi::FunctionCode {
             FunctionCode::GetFileInfo => ffi::FunctionCode::GetFileInfo,
             FunctionCode::AuthenticateFile => ffi::FunctionCode::AuthenticateFile,
             FunctionCode::AbortFile => ffi::FunctionCode::AbortFile,
+            FunctionCode::ActivateConfig => ffi::FunctionCode::ActivateConfig,
+            FunctionCode::AuthenticateRequest => ffi::FunctionCode::AuthenticateRequest,
+            FunctionCode::AuthenticateRequestNoResponse => {
+                ffi::FunctionCode::AuthenticateRequestNoResponse
+            }
             FunctionCode::Response => ffi::FunctionCode::Response,
             FunctionCode::UnsolicitedResponse => ffi::FunctionCode::UnsolicitedResponse,
+            FunctionCode::AuthenticateResponse => ffi::FunctionCode::AuthenticateResponse,
         }
     }
 }
@@ -127,6 +127,17 @@ implement_database_point_operations!(
     ffi::AnalogInputConfig,
 );
 
+implement_database_point_operations!(
+    database_add_frozen_analog_input,
+    database_remove_frozen_analog_input,
+    database_update_frozen_analog_input,
+    database_get_frozen_analog_input,
+    FrozenAnalogInput,
+    FrozenAnalogInputConfig,
+    ffi::FrozenAnalogInput,
+    ffi::FrozenAnalogInputConfig,
+);
+
 implement_database_point_operations!(
     database_add_analog_output_status,
     database_remove_analog_output_status,
@@ -506,6 +517,76 @@ impl From<ffi::AnalogInput> for AnalogInput {
     }
 }
 
+impl From<ffi::FrozenAnalogInputConfig> for FrozenAnalogInputConfig {
+    fn from(from: ffi::FrozenAnalogInputConfig) -> Self {
+        Self {
+            s_var: match from.static_variation() {
+                ffi::StaticFrozenAnalogInputVariation::Group31Var1 => {
+                    StaticFrozenAnalogInputVariation::Group31Var1
+                }
+                ffi::StaticFrozenAnalogInputVariation::Group31Var2 => {
+                    StaticFrozenAnalogInputVariation::Group31Var2
+                }
+                ffi::StaticFrozenAnalogInputVariation::Group31Var3 => {
+                    StaticFrozenAnalogInputVariation::Group31Var3
+                }
+                ffi::StaticFrozenAnalogInputVariation::Group31Var4 => {
+                    StaticFrozenAnalogInputVariation::Group31Var4
+                }
+                ffi::StaticFrozenAnalogInputVariation::Group31Var5 => {
+                    StaticFrozenAnalogInputVariation::Group31Var5
+                }
+                ffi::StaticFrozenAnalogInputVariation::Group31Var6 => {
+                    StaticFrozenAnalogInputVariation::Group31Var6
+                }
+                ffi::StaticFrozenAnalogInputVariation::Group31Var7 => {
+                    StaticFrozenAnalogInputVariation::Group31Var7
+                }
+                ffi::StaticFrozenAnalogInputVariation::Group31Var8 => {
+                    StaticFrozenAnalogInputVariation::Group31Var8
+                }
+            },
+            e_var: match from.event_variation() {
+                ffi::EventFrozenAnalogInputVariation::Group33Var1 => {
+                    EventFrozenAnalogInputVariation::Group33Var1
+                }
+                ffi::EventFrozenAnalogInputVariation::Group33Var2 => {
+                    EventFrozenAnalogInputVariation::Group33Var2
+                }
+                ffi::EventFrozenAnalogInputVariation::Group33Var3 => {
+                    EventFrozenAnalogInputVariation::Group33Var3
+                }
+                ffi::EventFrozenAnalogInputVariation::Group33Var4 => {
+                    EventFrozenAnalogInputVariation::Group33Var4
+                }
+                ffi::EventFrozenAnalogInputVariation::Group33Var5 => {
+                    EventFrozenAnalogInputVariation::Group33Var5
+                }
+                ffi::EventFrozenAnalogInputVariation::Group33Var6 => {
+                    EventFrozenAnalogInputVariation::Group33Var6
+                }
+                ffi::EventFrozenAnalogInputVariation::Group33Var7 => {
+                    EventFrozenAnalogInputVariation::Group33Var7
+                }
+                ffi::EventFrozenAnalogInputVariation::Group33Var8 => {
+                    EventFrozenAnalogInputVariation::Group33Var8
+                }
+            },
+            deadband: from.deadband(),
+        }
+    }
+}
+
+impl From<ffi::FrozenAnalogInput> for FrozenAnalogInput {
+    fn from(from: ffi::FrozenAnalogInput) -> Self {
+        Self {
+            value: from.value(),
+            flags: from.flags().into(),
+            time: from.time().into(),
+        }
+    }
+}
+
 impl From<ffi::AnalogOutputStatusConfig> for AnalogOutputStatusConfig {
     fn from(from: ffi::AnalogOutputStatusConfig) -> Self {
         Self {
@@ -5,8 +5,13 @@ use std::time::Duration;
 pub use database::*;
 use dnp3::app::{BufferSize, BufferSizeError, Listener, MaybeAsync, Timeout};
 use dnp3::link::{EndpointAddress, LinkErrorMode};
-use dnp3::outstation::database::{ClassZeroConfig, EventBufferConfig};
-use dnp3::outstation::{ConnectionState, Feature, Features, OutstationConfig, OutstationHandle};
+use dnp3::outstation::database::{
+    ClassZeroConfig, EventAnalogOutputCommandVariation, EventBinaryOutputCommandVariation,
+    EventBufferConfig,
+};
+use dnp3::outstation::{
+    CommandEventConfig, ConnectionState, Feature, Features, OutstationConfig, OutstationHandle,
+};
 use dnp3::tcp::{FilterError, ServerHandle};
 pub use struct_constructors::*;
 
@@ -324,6 +329,7 @@ fn convert_outstation_config(
         unsolicited_retry_delay: config.unsolicited_retry_delay(),
         keep_alive_timeout,
         class_zero: config.class_zero.into(),
+        command_events: config.command_events.into(),
         max_read_request_headers: Some(config.max_read_request_headers),
         max_controls_per_request: Some(config.max_controls_per_request),
     })
@@ -362,6 +368,48 @@ impl From<&ffi::OutstationFeatures> for Features {
     }
 }
 
+impl From<ffi::CommandEventConfig> for CommandEventConfig {
+    fn from(from: ffi::CommandEventConfig) -> Self {
+        CommandEventConfig {
+            class: from.event_class().into(),
+            binary_variation: match from.binary_variation() {
+                ffi::EventBinaryOutputCommandVariation::Group13Var1 => {
+                    EventBinaryOutputCommandVariation::Group13Var1
+                }
+                ffi::EventBinaryOutputCommandVariation::Group13Var2 => {
+                    EventBinaryOutputCommandVariation::Group13Var2
+                }
+            },
+            analog_variation: match from.analog_variation() {
+                ffi::EventAnalogOutputCommandVariation::Group43Var1 => {
+                    EventAnalogOutputCommandVariation::Group43Var1
+                }
+                ffi::EventAnalogOutputCommandVariation::Group43Var2 => {
+                    EventAnalogOutputCommandVariation::Group43Var2
+                }
+                ffi::EventAnalogOutputCommandVariation::Group43Var3 => {
+                    EventAnalogOutputCommandVariation::Group43Var3
+                }
+                ffi::EventAnalogOutputCommandVariation::Group43Var4 => {
+                    EventAnalogOutputCommandVariation::Group43Var4
+                }
+                ffi::EventAnalogOutputCommandVariation::Group43Var5 => {
+                    EventAnalogOutputCommandVariation::Group43Var5
+                }
+                ffi::EventAnalogOutputCommandVariation::Group43Var6 => {
+                    EventAnalogOutputCommandVariation::Group43Var6
+                }
+                ffi::EventAnalogOutputCommandVariation::Group43Var7 => {
+                    EventAnalogOutputCommandVariation::Group43Var7
+                }
+                ffi::EventAnalogOutputCommandVariation::Group43Var8 => {
+                    EventAnalogOutputCommandVariation::Group43Var8
+                }
+            },
+        }
+    }
+}
+
 impl From<ffi::ClassZeroConfig> for ClassZeroConfig {
     fn from(from: ffi::ClassZeroConfig) -> Self {
         ClassZeroConfig {
@@ -371,8 +419,11 @@ impl From<ffi::ClassZeroConfig> for ClassZeroConfig {
             counter: from.counter(),
             frozen_counter: from.frozen_counter(),
             analog: from.analog(),
+            frozen_analog: from.frozen_analog(),
             analog_output_status: from.analog_output_status(),
             octet_string: from.octet_string(),
+            unsigned_integer: from.unsigned_integer(),
+            bcd: from.bcd(),
         }
     }
 }
@@ -383,11 +434,16 @@ impl From<&ffi::EventBufferConfig> for EventBufferConfig {
             max_binary: from.max_binary(),
             max_double_binary: from.max_double_bit_binary(),
             max_binary_output_status: from.max_binary_output_status(),
+            max_binary_output_command: from.max_binary_output_command(),
             max_counter: from.max_counter(),
             max_frozen_counter: from.max_frozen_counter(),
             max_analog: from.max_analog(),
+            max_frozen_analog: from.max_frozen_analog(),
             max_analog_output_status: from.max_analog_output_status(),
+            max_analog_output_command: from.max_analog_output_command(),
             max_octet_string: from.max_octet_string(),
+            max_data_set: from.max_data_set(),
+            max_virtual_terminal: from.max_virtual_terminal(),
         }
     }
 }
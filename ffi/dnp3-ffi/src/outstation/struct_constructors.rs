@@ -101,11 +101,16 @@ impl From<EventBufferConfig> for ffi::EventBufferConfig {
             max_binary: from.max_binary,
             max_double_bit_binary: from.max_double_binary,
             max_binary_output_status: from.max_binary_output_status,
+            max_binary_output_command: from.max_binary_output_command,
             max_counter: from.max_counter,
             max_frozen_counter: from.max_frozen_counter,
             max_analog: from.max_analog,
+            max_frozen_analog: from.max_frozen_analog,
             max_analog_output_status: from.max_analog_output_status,
+            max_analog_output_command: from.max_analog_output_command,
             max_octet_string: from.max_octet_string,
+            max_data_set: from.max_data_set,
+            max_virtual_terminal: from.max_virtual_terminal,
         }
         .into()
     }
@@ -163,6 +163,8 @@ pub unsafe fn request_add_two_byte_limited_count_header(
 impl From<ffi::Variation> for Variation {
     fn from(from: ffi::Variation) -> Variation {
         match from {
+            ffi::Variation::Group0Var254 => Variation::Group0Var254,
+            ffi::Variation::Group0 => Variation::Group0(0),
             ffi::Variation::Group1Var0 => Variation::Group1Var0,
             ffi::Variation::Group1Var1 => Variation::Group1Var1,
             ffi::Variation::Group1Var2 => Variation::Group1Var2,
@@ -185,8 +187,11 @@ impl From<ffi::Variation> for Variation {
             ffi::Variation::Group11Var2 => Variation::Group11Var2,
             ffi::Variation::Group12Var0 => Variation::Group12Var0,
             ffi::Variation::Group12Var1 => Variation::Group12Var1,
-            //ffi::Variation::Group13Var1 => Variation::Group13Var1 - TODO
-            //ffi::Variation::Group13Var2 => Variation::Group13Var2 - TODO
+            ffi::Variation::Group12Var2 => Variation::Group12Var2,
+            ffi::Variation::Group12Var3 => Variation::Group12Var3,
+            ffi::Variation::Group13Var0 => Variation::Group13Var0,
+            ffi::Variation::Group13Var1 => Variation::Group13Var1,
+            ffi::Variation::Group13Var2 => Variation::Group13Var2,
             ffi::Variation::Group20Var0 => Variation::Group20Var0,
             ffi::Variation::Group20Var1 => Variation::Group20Var1,
             ffi::Variation::Group20Var2 => Variation::Group20Var2,
@@ -216,6 +221,15 @@ impl From<ffi::Variation> for Variation {
             ffi::Variation::Group30Var4 => Variation::Group30Var4,
             ffi::Variation::Group30Var5 => Variation::Group30Var5,
             ffi::Variation::Group30Var6 => Variation::Group30Var6,
+            ffi::Variation::Group31Var0 => Variation::Group31Var0,
+            ffi::Variation::Group31Var1 => Variation::Group31Var1,
+            ffi::Variation::Group31Var2 => Variation::Group31Var2,
+            ffi::Variation::Group31Var3 => Variation::Group31Var3,
+            ffi::Variation::Group31Var4 => Variation::Group31Var4,
+            ffi::Variation::Group31Var5 => Variation::Group31Var5,
+            ffi::Variation::Group31Var6 => Variation::Group31Var6,
+            ffi::Variation::Group31Var7 => Variation::Group31Var7,
+            ffi::Variation::Group31Var8 => Variation::Group31Var8,
             ffi::Variation::Group32Var0 => Variation::Group32Var0,
             ffi::Variation::Group32Var1 => Variation::Group32Var1,
             ffi::Variation::Group32Var2 => Variation::Group32Var2,
@@ -225,6 +239,19 @@ impl From<ffi::Variation> for Variation {
             ffi::Variation::Group32Var6 => Variation::Group32Var6,
             ffi::Variation::Group32Var7 => Variation::Group32Var7,
             ffi::Variation::Group32Var8 => Variation::Group32Var8,
+            ffi::Variation::Group33Var0 => Variation::Group33Var0,
+            ffi::Variation::Group33Var1 => Variation::Group33Var1,
+            ffi::Variation::Group33Var2 => Variation::Group33Var2,
+            ffi::Variation::Group33Var3 => Variation::Group33Var3,
+            ffi::Variation::Group33Var4 => Variation::Group33Var4,
+            ffi::Variation::Group33Var5 => Variation::Group33Var5,
+            ffi::Variation::Group33Var6 => Variation::Group33Var6,
+            ffi::Variation::Group33Var7 => Variation::Group33Var7,
+            ffi::Variation::Group33Var8 => Variation::Group33Var8,
+            ffi::Variation::Group34Var0 => Variation::Group34Var0,
+            ffi::Variation::Group34Var1 => Variation::Group34Var1,
+            ffi::Variation::Group34Var2 => Variation::Group34Var2,
+            ffi::Variation::Group34Var3 => Variation::Group34Var3,
             ffi::Variation::Group40Var0 => Variation::Group40Var0,
             ffi::Variation::Group40Var1 => Variation::Group40Var1,
             ffi::Variation::Group40Var2 => Variation::Group40Var2,
@@ -244,7 +271,7 @@ impl From<ffi::Variation> for Variation {
             ffi::Variation::Group42Var6 => Variation::Group42Var6,
             ffi::Variation::Group42Var7 => Variation::Group42Var7,
             ffi::Variation::Group42Var8 => Variation::Group42Var8,
-            /* TODO
+            ffi::Variation::Group43Var0 => Variation::Group43Var0,
             ffi::Variation::Group43Var1 => Variation::Group43Var1,
             ffi::Variation::Group43Var2 => Variation::Group43Var2,
             ffi::Variation::Group43Var3 => Variation::Group43Var3,
@@ -253,8 +280,8 @@ impl From<ffi::Variation> for Variation {
             ffi::Variation::Group43Var6 => Variation::Group43Var6,
             ffi::Variation::Group43Var7 => Variation::Group43Var7,
             ffi::Variation::Group43Var8 => Variation::Group43Var8,
-             */
             ffi::Variation::Group50Var1 => Variation::Group50Var1,
+            ffi::Variation::Group50Var2 => Variation::Group50Var2,
             ffi::Variation::Group50Var3 => Variation::Group50Var3,
             ffi::Variation::Group50Var4 => Variation::Group50Var4,
             ffi::Variation::Group51Var1 => Variation::Group51Var1,
@@ -265,9 +292,39 @@ impl From<ffi::Variation> for Variation {
             ffi::Variation::Group60Var2 => Variation::Group60Var2,
             ffi::Variation::Group60Var3 => Variation::Group60Var3,
             ffi::Variation::Group60Var4 => Variation::Group60Var4,
+            ffi::Variation::Group70Var1 => Variation::Group70Var1,
+            ffi::Variation::Group70Var2 => Variation::Group70Var2,
+            ffi::Variation::Group70Var3 => Variation::Group70Var3,
+            ffi::Variation::Group70Var4 => Variation::Group70Var4,
+            ffi::Variation::Group70Var5 => Variation::Group70Var5,
+            ffi::Variation::Group70Var6 => Variation::Group70Var6,
+            ffi::Variation::Group70Var7 => Variation::Group70Var7,
+            ffi::Variation::Group70Var8 => Variation::Group70Var8,
             ffi::Variation::Group80Var1 => Variation::Group80Var1,
+            ffi::Variation::Group85Var1 => Variation::Group85Var1,
+            ffi::Variation::Group86Var1 => Variation::Group86Var1,
+            ffi::Variation::Group87Var1 => Variation::Group87Var1,
+            ffi::Variation::Group88Var1 => Variation::Group88Var1,
+            ffi::Variation::Group90Var1 => Variation::Group90Var1,
+            ffi::Variation::Group91Var1 => Variation::Group91Var1,
+            ffi::Variation::Group101Var0 => Variation::Group101Var0,
+            ffi::Variation::Group101Var1 => Variation::Group101Var1,
+            ffi::Variation::Group101Var2 => Variation::Group101Var2,
+            ffi::Variation::Group101Var3 => Variation::Group101Var3,
+            ffi::Variation::Group102Var0 => Variation::Group102Var0,
+            ffi::Variation::Group102Var1 => Variation::Group102Var1,
             ffi::Variation::Group110 => Variation::Group110(0),
             ffi::Variation::Group111 => Variation::Group111(0),
+            ffi::Variation::Group112 => Variation::Group112(0),
+            ffi::Variation::Group113 => Variation::Group113(0),
+            ffi::Variation::Group120Var1 => Variation::Group120Var1,
+            ffi::Variation::Group120Var2 => Variation::Group120Var2,
+            ffi::Variation::Group120Var3 => Variation::Group120Var3,
+            ffi::Variation::Group120Var4 => Variation::Group120Var4,
+            ffi::Variation::Group120Var5 => Variation::Group120Var5,
+            ffi::Variation::Group120Var6 => Variation::Group120Var6,
+            ffi::Variation::Group120Var7 => Variation::Group120Var7,
+            ffi::Variation::Group120Var9 => Variation::Group120Var9,
             /*
             ffi::Variation::Group112 => Variation::Group112(0),
             ffi::Variation::Group113 => Variation::Group113(0),
@@ -279,6 +336,8 @@ impl From<ffi::Variation> for Variation {
 impl From<Variation> for ffi::Variation {
     fn from(from: Variation) -> ffi::Variation {
         match from {
+            Variation::Group0Var254 => ffi::Variation::Group0Var254,
+            Variation::Group0(_) => ffi::Variation::Group0,
             Variation::Group1Var0 => ffi::Variation::Group1Var0,
             Variation::Group1Var1 => ffi::Variation::Group1Var1,
             Variation::Group1Var2 => ffi::Variation::Group1Var2,
@@ -301,10 +360,11 @@ impl From<Variation> for ffi::Variation {
             Variation::Group11Var2 => ffi::Variation::Group11Var2,
             Variation::Group12Var0 => ffi::Variation::Group12Var0,
             Variation::Group12Var1 => ffi::Variation::Group12Var1,
-            /*
+            Variation::Group12Var2 => ffi::Variation::Group12Var2,
+            Variation::Group12Var3 => ffi::Variation::Group12Var3,
+            Variation::Group13Var0 => ffi::Variation::Group13Var0,
             Variation::Group13Var1 => ffi::Variation::Group13Var1,
             Variation::Group13Var2 => ffi::Variation::Group13Var2,
-             */
             Variation::Group20Var0 => ffi::Variation::Group20Var0,
             Variation::Group20Var1 => ffi::Variation::Group20Var1,
             Variation::Group20Var2 => ffi::Variation::Group20Var2,
@@ -334,6 +394,15 @@ impl From<Variation> for ffi::Variation {
             Variation::Group30Var4 => ffi::Variation::Group30Var4,
             Variation::Group30Var5 => ffi::Variation::Group30Var5,
             Variation::Group30Var6 => ffi::Variation::Group30Var6,
+            Variation::Group31Var0 => ffi::Variation::Group31Var0,
+            Variation::Group31Var1 => ffi::Variation::Group31Var1,
+            Variation::Group31Var2 => ffi::Variation::Group31Var2,
+            Variation::Group31Var3 => ffi::Variation::Group31Var3,
+            Variation::Group31Var4 => ffi::Variation::Group31Var4,
+            Variation::Group31Var5 => ffi::Variation::Group31Var5,
+            Variation::Group31Var6 => ffi::Variation::Group31Var6,
+            Variation::Group31Var7 => ffi::Variation::Group31Var7,
+            Variation::Group31Var8 => ffi::Variation::Group31Var8,
             Variation::Group32Var0 => ffi::Variation::Group32Var0,
             Variation::Group32Var1 => ffi::Variation::Group32Var1,
             Variation::Group32Var2 => ffi::Variation::Group32Var2,
@@ -343,6 +412,19 @@ impl From<Variation> for ffi::Variation {
             Variation::Group32Var6 => ffi::Variation::Group32Var6,
             Variation::Group32Var7 => ffi::Variation::Group32Var7,
             Variation::Group32Var8 => ffi::Variation::Group32Var8,
+            Variation::Group33Var0 => ffi::Variation::Group33Var0,
+            Variation::Group33Var1 => ffi::Variation::Group33Var1,
+            Variation::Group33Var2 => ffi::Variation::Group33Var2,
+            Variation::Group33Var3 => ffi::Variation::Group33Var3,
+            Variation::Group33Var4 => ffi::Variation::Group33Var4,
+            Variation::Group33Var5 => ffi::Variation::Group33Var5,
+            Variation::Group33Var6 => ffi::Variation::Group33Var6,
+            Variation::Group33Var7 => ffi::Variation::Group33Var7,
+            Variation::Group33Var8 => ffi::Variation::Group33Var8,
+            Variation::Group34Var0 => ffi::Variation::Group34Var0,
+            Variation::Group34Var1 => ffi::Variation::Group34Var1,
+            Variation::Group34Var2 => ffi::Variation::Group34Var2,
+            Variation::Group34Var3 => ffi::Variation::Group34Var3,
             Variation::Group40Var0 => ffi::Variation::Group40Var0,
             Variation::Group40Var1 => ffi::Variation::Group40Var1,
             Variation::Group40Var2 => ffi::Variation::Group40Var2,
@@ -362,7 +444,7 @@ impl From<Variation> for ffi::Variation {
             Variation::Group42Var6 => ffi::Variation::Group42Var6,
             Variation::Group42Var7 => ffi::Variation::Group42Var7,
             Variation::Group42Var8 => ffi::Variation::Group42Var8,
-            /* TODO
+            Variation::Group43Var0 => ffi::Variation::Group43Var0,
             Variation::Group43Var1 => ffi::Variation::Group43Var1,
             Variation::Group43Var2 => ffi::Variation::Group43Var2,
             Variation::Group43Var3 => ffi::Variation::Group43Var3,
@@ -371,8 +453,8 @@ impl From<Variation> for ffi::Variation {
             Variation::Group43Var6 => ffi::Variation::Group43Var6,
             Variation::Group43Var7 => ffi::Variation::Group43Var7,
             Variation::Group43Var8 => ffi::Variation::Group43Var8,
-             */
             Variation::Group50Var1 => ffi::Variation::Group50Var1,
+            Variation::Group50Var2 => ffi::Variation::Group50Var2,
             Variation::Group50Var3 => ffi::Variation::Group50Var3,
             Variation::Group50Var4 => ffi::Variation::Group50Var4,
             Variation::Group51Var1 => ffi::Variation::Group51Var1,
@@ -383,9 +465,39 @@ impl From<Variation> for ffi::Variation {
             Variation::Group60Var2 => ffi::Variation::Group60Var2,
             Variation::Group60Var3 => ffi::Variation::Group60Var3,
             Variation::Group60Var4 => ffi::Variation::Group60Var4,
+            Variation::Group70Var1 => ffi::Variation::Group70Var1,
+            Variation::Group70Var2 => ffi::Variation::Group70Var2,
+            Variation::Group70Var3 => ffi::Variation::Group70Var3,
+            Variation::Group70Var4 => ffi::Variation::Group70Var4,
+            Variation::Group70Var5 => ffi::Variation::Group70Var5,
+            Variation::Group70Var6 => ffi::Variation::Group70Var6,
+            Variation::Group70Var7 => ffi::Variation::Group70Var7,
+            Variation::Group70Var8 => ffi::Variation::Group70Var8,
             Variation::Group80Var1 => ffi::Variation::Group80Var1,
+            Variation::Group85Var1 => ffi::Variation::Group85Var1,
+            Variation::Group86Var1 => ffi::Variation::Group86Var1,
+            Variation::Group87Var1 => ffi::Variation::Group87Var1,
+            Variation::Group88Var1 => ffi::Variation::Group88Var1,
+            Variation::Group90Var1 => ffi::Variation::Group90Var1,
+            Variation::Group91Var1 => ffi::Variation::Group91Var1,
+            Variation::Group101Var0 => ffi::Variation::Group101Var0,
+            Variation::Group101Var1 => ffi::Variation::Group101Var1,
+            Variation::Group101Var2 => ffi::Variation::Group101Var2,
+            Variation::Group101Var3 => ffi::Variation::Group101Var3,
+            Variation::Group102Var0 => ffi::Variation::Group102Var0,
+            Variation::Group102Var1 => ffi::Variation::Group102Var1,
             Variation::Group110(_) => ffi::Variation::Group110,
             Variation::Group111(_) => ffi::Variation::Group111,
+            Variation::Group112(_) => ffi::Variation::Group112,
+            Variation::Group113(_) => ffi::Variation::Group113,
+            Variation::Group120Var1 => ffi::Variation::Group120Var1,
+            Variation::Group120Var2 => ffi::Variation::Group120Var2,
+            Variation::Group120Var3 => ffi::Variation::Group120Var3,
+            Variation::Group120Var4 => ffi::Variation::Group120Var4,
+            Variation::Group120Var5 => ffi::Variation::Group120Var5,
+            Variation::Group120Var6 => ffi::Variation::Group120Var6,
+            Variation::Group120Var7 => ffi::Variation::Group120Var7,
+            Variation::Group120Var9 => ffi::Variation::Group120Var9,
             /*
             Variation::Group112(_) => ffi::Variation::Group112,
             Variation::Group113(_) => ffi::Variation::Group113,
@@ -377,6 +377,87 @@ pub fn define_analog_config(lib: &mut LibraryBuilder) -> BackTraced<FunctionArgS
     Ok(config)
 }
 
+fn define_frozen_analog_config(lib: &mut LibraryBuilder) -> BackTraced<FunctionArgStructHandle> {
+    let frozen_analog_static_variation = lib
+        .define_enum("static_frozen_analog_input_variation")?
+        .push(gv(31, 1), "Frozen analog input - 32-bit with flag")?
+        .push(gv(31, 2), "Frozen analog input - 16-bit with flag")?
+        .push(
+            gv(31, 3),
+            "Frozen analog input - 32-bit with flag and time-of-freeze",
+        )?
+        .push(
+            gv(31, 4),
+            "Frozen analog input - 16-bit with flag and time-of-freeze",
+        )?
+        .push(gv(31, 5), "Frozen analog input - 32-bit without flag")?
+        .push(gv(31, 6), "Frozen analog input - 16-bit without flag")?
+        .push(
+            gv(31, 7),
+            "Frozen analog input - single-precision, floating-point with flag",
+        )?
+        .push(
+            gv(31, 8),
+            "Frozen analog input - double-precision, floating-point with flag",
+        )?
+        .doc("Static frozen analog variation")?
+        .build()?;
+
+    let frozen_analog_event_variation = lib
+        .define_enum("event_frozen_analog_input_variation")?
+        .push(gv(33, 1), "Frozen analog input event - 32-bit without time")?
+        .push(gv(33, 2), "Frozen analog input event - 16-bit without time")?
+        .push(gv(33, 3), "Frozen analog input event - 32-bit with time")?
+        .push(gv(33, 4), "Frozen analog input event - 16-bit with time")?
+        .push(
+            gv(33, 5),
+            "Frozen analog input event - single-precision, floating-point without time",
+        )?
+        .push(
+            gv(33, 6),
+            "Frozen analog input event - double-precision, floating-point without time",
+        )?
+        .push(
+            gv(33, 7),
+            "Frozen analog input event - single-precision, floating-point with time",
+        )?
+        .push(
+            gv(33, 8),
+            "Frozen analog input event - double-precision, floating-point with time",
+        )?
+        .doc("Event frozen analog variation")?
+        .build()?;
+
+    let static_variation = Name::create("static_variation")?;
+    let event_variation = Name::create("event_variation")?;
+    let deadband = Name::create("deadband")?;
+
+    let config = lib.declare_function_argument_struct("frozen_analog_input_config")?;
+    let config = lib
+        .define_function_argument_struct(config)?
+        .add(
+            &static_variation,
+            frozen_analog_static_variation,
+            "Default static variation",
+        )?
+        .add(
+            &event_variation,
+            frozen_analog_event_variation,
+            "Default event variation",
+        )?
+        .add(&deadband, Primitive::Double, "Deadband value")?
+        .doc("Frozen analog configuration")?
+        .end_fields()?
+        .begin_initializer("init", InitializerType::Normal, "Initialize to defaults")?
+        .default_variant(&static_variation, gv(31, 1))?
+        .default_variant(&event_variation, gv(33, 1))?
+        .default(&deadband, NumberValue::Double(0.0))?
+        .end_initializer()?
+        .build()?;
+
+    Ok(config)
+}
+
 fn define_analog_output_status_config(
     lib: &mut LibraryBuilder,
 ) -> BackTraced<FunctionArgStructHandle> {
@@ -453,13 +534,23 @@ fn define_analog_output_status_config(
 pub(crate) struct DatabaseTypes {
     pub(crate) database_transaction: SynchronousInterface,
     pub(crate) database_handle: ClassHandle,
+    pub(crate) event_class: EnumHandle,
 }
 
 pub(crate) fn define(
     lib: &mut LibraryBuilder,
     shared_def: &SharedDefinitions,
 ) -> BackTraced<DatabaseTypes> {
-    let database = define_database(lib, shared_def)?;
+    let event_class = lib
+        .define_enum("event_class")?
+        .push("none", "Does not generate events")?
+        .push("class1", "Class 1 event")?
+        .push("class2", "Class 2 event")?
+        .push("class3", "Class 3 event")?
+        .doc("Event class")?
+        .build()?;
+
+    let database = define_database(lib, shared_def, &event_class)?;
 
     let database_transaction = lib
         .define_interface("database_transaction", "Database transaction interface")?
@@ -490,24 +581,17 @@ pub(crate) fn define(
     Ok(DatabaseTypes {
         database_transaction,
         database_handle,
+        event_class,
     })
 }
 
 pub(crate) fn define_database(
     lib: &mut LibraryBuilder,
     shared_def: &SharedDefinitions,
+    event_class: &EnumHandle,
 ) -> BackTraced<ClassHandle> {
     let database = lib.declare_class("database")?;
 
-    let event_class = lib
-        .define_enum("event_class")?
-        .push("none", "Does not generate events")?
-        .push("class1", "Class 1 event")?
-        .push("class2", "Class 2 event")?
-        .push("class3", "Class 3 event")?
-        .doc("Event class")?
-        .build()?;
-
     let update_options = define_update_options(lib)?;
 
     // Binary Input
@@ -807,6 +891,57 @@ pub(crate) fn define_database(
         .doc("Get a AnalogInput point")?
         .build()?;
 
+    // Frozen Analog
+    let frozen_analog_config = define_frozen_analog_config(lib)?;
+
+    let add_frozen_analog = lib
+        .define_method("add_frozen_analog_input", database.clone())?
+        .param("index", Primitive::U16, "Index of the point")?
+        .param("point_class", event_class.clone(), "Event class")?
+        .param("config", frozen_analog_config, "Configuration")?
+        .returns(
+            Primitive::Bool,
+            "True if the point was successfully added, false otherwise",
+        )?
+        .doc("Add a new FrozenAnalogInput point")?
+        .build()?;
+
+    let remove_frozen_analog = lib
+        .define_method("remove_frozen_analog_input", database.clone())?
+        .param("index", Primitive::U16, "Index of the point")?
+        .returns(
+            Primitive::Bool,
+            "True if the point was successfully removed, false otherwise",
+        )?
+        .doc("Remove a FrozenAnalogInput point")?
+        .build()?;
+
+    let update_frozen_analog = lib
+        .define_method("update_frozen_analog_input", database.clone())?
+        .param(
+            "value",
+            shared_def.frozen_analog_point.clone(),
+            "New value of the point",
+        )?
+        .param("options", update_options.clone(), "Update options")?
+        .returns(
+            Primitive::Bool,
+            "True if the point was successfully updated, false otherwise",
+        )?
+        .doc("Update a FrozenAnalogInput point")?
+        .build()?;
+
+    let get_frozen_analog = lib
+        .define_method("get_frozen_analog_input", database.clone())?
+        .param("index", Primitive::U16, "Index of the point to get")?
+        .returns(
+            shared_def.frozen_analog_point.clone(),
+            "Frozen analog point",
+        )?
+        .fails_with(shared_def.error_type.clone())?
+        .doc("Get a FrozenAnalogInput point")?
+        .build()?;
+
     // Analog Output Status
     let analog_output_status_config = define_analog_output_status_config(lib)?;
     let add_analog_output_status = lib
@@ -863,7 +998,7 @@ pub(crate) fn define_database(
     let add_octet_string = lib
         .define_method("add_octet_string", database.clone())?
         .param("index", Primitive::U16, "Index of the point")?
-        .param("point_class", event_class, "Event class")?
+        .param("point_class", event_class.clone(), "Event class")?
         .returns(
             Primitive::Bool,
             "True if the point was successfully added, false otherwise",
@@ -927,6 +1062,11 @@ pub(crate) fn define_database(
         .method(remove_analog)?
         .method(update_analog)?
         .method(get_analog)?
+        // frozen-analog methods
+        .method(add_frozen_analog)?
+        .method(remove_frozen_analog)?
+        .method(update_frozen_analog)?
+        .method(get_frozen_analog)?
         // analog output status methods
         .method(add_analog_output_status)?
         .method(remove_analog_output_status)?
@@ -9,6 +9,7 @@ pub fn define(
         .define_enum("response_function")?
         .push("response", "Solicited response")?
         .push("unsolicited_response", "Unsolicited response")?
+        .push("authenticate_response", "Secure authentication response")?
         .doc("Type of response")?
         .build()?;
 
@@ -181,6 +182,19 @@ pub fn define(
             "Iterator of point values in the response. This iterator is valid only within this call. Do not copy it."
         )?
 
+        .end_callback()?
+        .begin_callback("handle_frozen_analog_input", "Handle frozen analog input data")?
+        .param(
+            "info",
+           header_info.clone(),
+            "Group/variation and qualifier information",
+        )?
+        .param(
+            "it",
+           shared_def.frozen_analog_it.clone(),
+            "Iterator of point values in the response. This iterator is valid only within this call. Do not copy it."
+        )?
+
         .end_callback()?
         .begin_callback(
             "handle_analog_output_status",
@@ -197,6 +211,38 @@ pub fn define(
             "Iterator of point values in the response. This iterator is valid only within this call. Do not copy it."
         )?
 
+        .end_callback()?
+        .begin_callback(
+            "handle_binary_output_command_event",
+            "Handle binary output command events (g13)",
+        )?
+        .param(
+            "info",
+           header_info.clone(),
+            "Group/variation and qualifier information",
+        )?
+        .param(
+            "it",
+           shared_def.binary_output_command_event_it.clone(),
+            "Iterator of point values in the response. This iterator is valid only within this call. Do not copy it."
+        )?
+
+        .end_callback()?
+        .begin_callback(
+            "handle_analog_output_command_event",
+            "Handle analog output command events (g43)",
+        )?
+        .param(
+            "info",
+           header_info.clone(),
+            "Group/variation and qualifier information",
+        )?
+        .param(
+            "it",
+           shared_def.analog_output_command_event_it.clone(),
+            "Iterator of point values in the response. This iterator is valid only within this call. Do not copy it."
+        )?
+
         .end_callback()?
         .begin_callback("handle_octet_string", "Handle octet string data")?
         .param(
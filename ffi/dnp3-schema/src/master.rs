@@ -772,6 +772,23 @@ fn define_association_information(
         .push("disable_unsolicited", "Disable unsolicited startup request")?
         .push("time_sync", "Time synchronisation task")?
         .push("restart", "Cold or warm restart task")?
+        .push("file_transfer", "File transfer task")?
+        .push("write", "User-defined write request")?
+        .push("assign_class", "Assign class request")?
+        .push("freeze", "Freeze request")?
+        .push(
+            "application_control",
+            "Initialize, start, or stop application request",
+        )?
+        .push("activate_config", "Activate configuration request")?
+        .push(
+            "custom",
+            "User-defined request with an arbitrary function code",
+        )?
+        .push(
+            "session_key_change",
+            "Secure authentication session key change",
+        )?
         .doc("Task type used in {interface:association_information}")?
         .build()?;
 
@@ -941,6 +958,10 @@ const TASK_ERRORS: &[(&str, &str)] = &[
     ("no_connection", "no connection"),
     ("shutdown", "master was shutdown"),
     ("association_removed", "association was removed mid-task"),
+    (
+        "authentication_failure",
+        "secure authentication of the request failed",
+    ),
 ];
 
 trait TaskErrors: Sized {
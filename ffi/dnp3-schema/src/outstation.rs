@@ -1,4 +1,5 @@
 use crate::database::DatabaseTypes;
+use crate::gv;
 use crate::shared::SharedDefinitions;
 use oo_bindgen::model::*;
 use std::time::Duration;
@@ -17,11 +18,12 @@ impl OutstationTypes {
         let DatabaseTypes {
             database_transaction,
             database_handle,
+            event_class,
         } = crate::database::define(lib, shared_def)?;
 
         Ok(Self {
             database_transaction,
-            outstation_config: define_outstation_config(lib, shared_def)?,
+            outstation_config: define_outstation_config(lib, shared_def, &event_class)?,
             outstation_application: define_outstation_application(lib, &database_handle)?,
             outstation_information: define_outstation_information(lib, shared_def)?,
             control_handler: define_control_handler(lib, &database_handle, shared_def)?,
@@ -267,8 +269,11 @@ fn define_class_zero_config(lib: &mut LibraryBuilder) -> BackTraced<FunctionArgS
     let counter = Name::create("counter")?;
     let frozen_counter = Name::create("frozen_counter")?;
     let analog = Name::create("analog")?;
+    let frozen_analog = Name::create("frozen_analog")?;
     let analog_output_status = Name::create("analog_output_status")?;
     let octet_strings = Name::create("octet_string")?;
+    let unsigned_integer = Name::create("unsigned_integer")?;
+    let bcd = Name::create("bcd")?;
 
     let class_zero_config = lib.declare_function_argument_struct("class_zero_config")?;
     let class_zero_config = lib
@@ -303,6 +308,11 @@ fn define_class_zero_config(lib: &mut LibraryBuilder) -> BackTraced<FunctionArgS
             Primitive::Bool,
             "Include Analog Inputs in Class 0 reads",
         )?
+        .add(
+            &frozen_analog,
+            Primitive::Bool,
+            "Include Frozen Analog Inputs in Class 0 reads",
+        )?
         .add(
             &analog_output_status,
             Primitive::Bool,
@@ -314,6 +324,16 @@ fn define_class_zero_config(lib: &mut LibraryBuilder) -> BackTraced<FunctionArgS
             doc("Include Binary Inputs in Class 0 reads")
                 .warning("For conformance, this should be false."),
         )?
+        .add(
+            &unsigned_integer,
+            Primitive::Bool,
+            "Include Unsigned Integers (g102) in Class 0 reads",
+        )?
+        .add(
+            &bcd,
+            Primitive::Bool,
+            "Include BCD Integers (g101) in Class 0 reads",
+        )?
         .doc("Controls which types are reported during a Class 0 read.")?
         .end_fields()?
         .begin_initializer(
@@ -327,8 +347,11 @@ fn define_class_zero_config(lib: &mut LibraryBuilder) -> BackTraced<FunctionArgS
         .default(&counter, true)?
         .default(&frozen_counter, true)?
         .default(&analog, true)?
+        .default(&frozen_analog, true)?
         .default(&analog_output_status, true)?
         .default(&octet_strings, false)?
+        .default(&unsigned_integer, true)?
+        .default(&bcd, true)?
         .end_initializer()?
         .build()?;
 
@@ -374,12 +397,86 @@ fn define_outstation_features(lib: &mut LibraryBuilder) -> BackTraced<FunctionAr
     Ok(features)
 }
 
+fn define_command_event_config(
+    lib: &mut LibraryBuilder,
+    event_class: &EnumHandle,
+) -> BackTraced<FunctionArgStructHandle> {
+    let binary_variation_enum = lib
+        .define_enum("event_binary_output_command_variation")?
+        .push(gv(13, 1), "Binary output command event - without time")?
+        .push(gv(13, 2), "Binary output command event - with time")?
+        .doc("Event binary output command variation")?
+        .build()?;
+
+    let analog_variation_enum = lib
+        .define_enum("event_analog_output_command_variation")?
+        .push(gv(43, 1), "Analog output command event - 32-bit")?
+        .push(gv(43, 2), "Analog output command event - 16-bit")?
+        .push(gv(43, 3), "Analog output command event - 32-bit with time")?
+        .push(gv(43, 4), "Analog output command event - 16-bit with time")?
+        .push(gv(43, 5), "Analog output command event - single-precision")?
+        .push(gv(43, 6), "Analog output command event - double-precision")?
+        .push(
+            gv(43, 7),
+            "Analog output command event - single-precision with time",
+        )?
+        .push(
+            gv(43, 8),
+            "Analog output command event - double-precision with time",
+        )?
+        .doc("Event analog output command variation")?
+        .build()?;
+
+    let class = Name::create("event_class")?;
+    let binary_variation = Name::create("binary_variation")?;
+    let analog_variation = Name::create("analog_variation")?;
+
+    let command_event_config = lib.declare_function_argument_struct("command_event_config")?;
+    let command_event_config = lib
+        .define_function_argument_struct(command_event_config)?
+        .add(
+            &class,
+            event_class.clone(),
+            "Class assigned to generated events, `none` disables automatic generation",
+        )?
+        .add(
+            &binary_variation,
+            binary_variation_enum,
+            "Default variation of binary output command events (g13)",
+        )?
+        .add(
+            &analog_variation,
+            analog_variation_enum,
+            "Default variation of analog output command events (g43)",
+        )?
+        .doc(
+            doc("Controls the automatic generation of command events (g13 and g43)")
+                .details("When enabled, the outstation records a command event each time an operate request is passed to the control handler. The time is taken from the system clock and is reported as unsynchronized.")
+                .details("Events are only buffered if the event buffer configuration reserves space for the type."),
+        )?
+        .end_fields()?
+        .begin_initializer(
+            "init",
+            InitializerType::Normal,
+            "Initialize to default values where command events are not generated",
+        )?
+        .default_variant(&class, "none")?
+        .default_variant(&binary_variation, gv(13, 1))?
+        .default_variant(&analog_variation, gv(43, 1))?
+        .end_initializer()?
+        .build()?;
+
+    Ok(command_event_config)
+}
+
 fn define_outstation_config(
     lib: &mut LibraryBuilder,
     shared: &SharedDefinitions,
+    event_class: &EnumHandle,
 ) -> BackTraced<FunctionArgStructHandle> {
     let event_buffer_config = define_event_buffer_config(lib)?;
     let class_zero_config = define_class_zero_config(lib)?;
+    let command_event_config = define_command_event_config(lib, event_class)?;
     let outstation_features = define_outstation_features(lib)?;
 
     let solicited_buffer_size = Name::create("solicited_buffer_size")?;
@@ -395,6 +492,7 @@ fn define_outstation_config(
     let max_read_request_headers = Name::create("max_read_request_headers")?;
     let max_controls_per_request = Name::create("max_controls_per_request")?;
     let class_zero = Name::create("class_zero")?;
+    let command_events = Name::create("command_events")?;
 
     let outstation_config = lib.declare_function_argument_struct("outstation_config")?;
     let outstation_config = lib
@@ -457,6 +555,7 @@ fn define_outstation_config(
         .add(&max_read_request_headers, Primitive::U16, doc("Maximum number of headers that will be processed in a READ request.").details("Internally, this controls the size of a pre-allocated buffer used to process requests. A minimum value of `DEFAULT_READ_REQUEST_HEADERS` is always enforced. Requesting more than this number will result in the PARAMETER_ERROR IIN bit being set in the response."))?
         .add(&max_controls_per_request, Primitive::U16, doc("Maximum number of controls in a single request."))?
         .add(&class_zero, class_zero_config, "Controls responses to Class 0 reads")?
+        .add(&command_events, command_event_config, "Controls the automatic generation of command events")?
         .end_fields()?
         .begin_initializer("init", InitializerType::Normal, "Initialize to defaults")?
         .default(&solicited_buffer_size, NumberValue::U16(2048))?
@@ -472,6 +571,7 @@ fn define_outstation_config(
         .default(&max_read_request_headers, NumberValue::U16(64))?
         .default(&max_controls_per_request, NumberValue::U16(u16::MAX))?
         .default_struct(&class_zero)?
+        .default_struct(&command_events)?
         .end_initializer()?
         .build()?;
 
@@ -482,11 +582,16 @@ fn define_event_buffer_config(lib: &mut LibraryBuilder) -> BackTraced<FunctionAr
     let max_binary = Name::create("max_binary")?;
     let max_double_bit_binary = Name::create("max_double_bit_binary")?;
     let max_binary_output_status = Name::create("max_binary_output_status")?;
+    let max_binary_output_command = Name::create("max_binary_output_command")?;
     let max_counter = Name::create("max_counter")?;
     let max_frozen_counter = Name::create("max_frozen_counter")?;
     let max_analog = Name::create("max_analog")?;
+    let max_frozen_analog = Name::create("max_frozen_analog")?;
     let max_analog_output_status = Name::create("max_analog_output_status")?;
+    let max_analog_output_command = Name::create("max_analog_output_command")?;
     let max_octet_string = Name::create("max_octet_string")?;
+    let max_data_set = Name::create("max_data_set")?;
+    let max_virtual_terminal = Name::create("max_virtual_terminal")?;
 
     let event_buffer_config = lib.declare_function_argument_struct("event_buffer_config")?;
     let event_buffer_config = lib
@@ -506,6 +611,11 @@ fn define_event_buffer_config(lib: &mut LibraryBuilder) -> BackTraced<FunctionAr
             Primitive::U16,
             "Maximum number of Binary Output Status events (g11)",
         )?
+        .add(
+            &max_binary_output_command,
+            Primitive::U16,
+            "Maximum number of Binary Output Command events (g13)",
+        )?
         .add(
             &max_counter,
             Primitive::U16,
@@ -521,16 +631,36 @@ fn define_event_buffer_config(lib: &mut LibraryBuilder) -> BackTraced<FunctionAr
             Primitive::U16,
             "Maximum number of Analog Input events (g32)",
         )?
+        .add(
+            &max_frozen_analog,
+            Primitive::U16,
+            "Maximum number of Frozen Analog Input events (g33)",
+        )?
         .add(
             &max_analog_output_status,
             Primitive::U16,
             "Maximum number of Analog Output Status events (g42)",
         )?
+        .add(
+            &max_analog_output_command,
+            Primitive::U16,
+            "Maximum number of Analog Output Command events (g43)",
+        )?
         .add(
             &max_octet_string,
             Primitive::U16,
             doc("Maximum number of Octet String events (g111)"),
         )?
+        .add(
+            &max_data_set,
+            Primitive::U16,
+            doc("Maximum number of Data Set snapshot events (g88)"),
+        )?
+        .add(
+            &max_virtual_terminal,
+            Primitive::U16,
+            doc("Maximum number of Virtual Terminal output events (g113)"),
+        )?
         .doc(
             doc("Maximum number of events for each type")
                 .details("A value of zero means that events will not be buffered for that type."),
@@ -545,11 +675,16 @@ fn define_event_buffer_config(lib: &mut LibraryBuilder) -> BackTraced<FunctionAr
         .default(&max_binary, NumberValue::U16(0))?
         .default(&max_double_bit_binary, NumberValue::U16(0))?
         .default(&max_binary_output_status, NumberValue::U16(0))?
+        .default(&max_binary_output_command, NumberValue::U16(0))?
         .default(&max_counter, NumberValue::U16(0))?
         .default(&max_frozen_counter, NumberValue::U16(0))?
         .default(&max_analog, NumberValue::U16(0))?
+        .default(&max_frozen_analog, NumberValue::U16(0))?
         .default(&max_analog_output_status, NumberValue::U16(0))?
+        .default(&max_analog_output_command, NumberValue::U16(0))?
         .default(&max_octet_string, NumberValue::U16(0))?
+        .default(&max_data_set, NumberValue::U16(0))?
+        .default(&max_virtual_terminal, NumberValue::U16(0))?
         .end_initializer()?
         .build()?;
 
@@ -659,6 +794,7 @@ fn define_outstation_application(
     let freeze_type = lib.define_enum("freeze_type")?
         .push("immediate_freeze", "Copy the current value of a counter to the associated point")?
         .push("freeze_and_clear", "Copy the current value of a counter to the associated point and clear the current value to 0.")?
+        .push("freeze_at_time", "Copy the current value of a counter to the associated point at the time and interval scheduled by a FREEZE_AT_TIME request")?
         .doc("Freeze operation type")?
         .build()?;
 
@@ -804,7 +940,7 @@ fn define_control_handler(
     database_handle: &ClassHandle,
     shared_def: &SharedDefinitions,
 ) -> BackTraced<AsynchronousInterface> {
-    let command_status = define_command_status(lib)?;
+    let command_status = shared_def.command_status.clone();
 
     let operate_type = lib
         .define_enum("operate_type")?
@@ -1075,32 +1211,3 @@ fn define_tls_server_config(
 
     Ok(tls_server_config)
 }
-
-fn define_command_status(lib: &mut LibraryBuilder) -> BackTraced<EnumHandle> {
-    let command_status = lib.define_enum("command_status")?
-    .push("success", "command was accepted, initiated, or queued (value == 0)")?
-    .push("timeout", "command timed out before completing (value == 1)")?
-    .push("no_select", "command requires being selected before operate, configuration issue (value == 2)")?
-    .push("format_error", "bad control code or timing values (value == 3)")?
-    .push("not_supported", "command is not implemented (value == 4)")?
-    .push("already_active", "command is all ready in progress or its all ready in that mode (value == 5)")?
-    .push("hardware_error", "something is stopping the command, often a local/remote interlock (value == 6)")?
-    .push("local", "the function governed by the control is in local only control (value == 7)")?
-    .push("too_many_ops", "the command has been done too often and has been throttled (value == 8)")?
-    .push("not_authorized", "the command was rejected because the device denied it or an RTU intercepted it (value == 9)")?
-    .push("automation_inhibit", "command not accepted because it was prevented or inhibited by a local automation process, such as interlocking logic or synchrocheck (value == 10)")?
-    .push("processing_limited", "command not accepted because the device cannot process any more activities than are presently in progress (value == 11)")?
-    .push("out_of_range", "command not accepted because the value is outside the acceptable range permitted for this point (value == 12)")?
-    .push("downstream_local", "command not accepted because the outstation is forwarding the request to another downstream device which reported LOCAL (value == 13)")?
-    .push("already_complete", "command not accepted because the outstation has already completed the requested operation (value == 14)")?
-    .push("blocked", "command not accepted because the requested function is specifically blocked at the outstation (value == 15)")?
-    .push("canceled", "command not accepted because the operation was cancelled (value == 16)")?
-    .push("blocked_other_master", "command not accepted because another master is communicating with the outstation and has exclusive rights to operate this control point (value == 17)")?
-    .push("downstream_fail", "command not accepted because the outstation is forwarding the request to another downstream device which cannot be reached or is otherwise incapable of performing the request (value == 18)")?
-    .push("non_participating", "(deprecated) indicates the outstation shall not issue or perform the control operation (value == 126)")?
-    .push("unknown", "captures any value not defined in the enumeration")?
-    .doc("Enumeration received from an outstation in response to command request")?
-    .build()?;
-
-    Ok(command_status)
-}
@@ -26,8 +26,13 @@ pub struct SharedDefinitions {
     pub frozen_counter_it: AbstractIteratorHandle,
     pub analog_point: UniversalStructHandle,
     pub analog_it: AbstractIteratorHandle,
+    pub frozen_analog_point: UniversalStructHandle,
+    pub frozen_analog_it: AbstractIteratorHandle,
     pub analog_output_status_point: UniversalStructHandle,
     pub analog_output_status_it: AbstractIteratorHandle,
+    pub command_status: EnumHandle,
+    pub binary_output_command_event_it: AbstractIteratorHandle,
+    pub analog_output_command_event_it: AbstractIteratorHandle,
     pub octet_string: FunctionReturnStructHandle,
     pub octet_string_it: AbstractIteratorHandle,
     pub min_tls_version: EnumHandle,
@@ -244,6 +249,13 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<SharedDefinitions> {
         &flags_struct,
         &timestamp_struct,
     )?;
+    let (frozen_analog_point, frozen_analog_it) = build_iterator(
+        "frozen_analog_input",
+        Primitive::Double,
+        lib,
+        &flags_struct,
+        &timestamp_struct,
+    )?;
     let (analog_output_status_point, analog_output_status_it) = build_iterator(
         "analog_output_status",
         Primitive::Double,
@@ -252,6 +264,26 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<SharedDefinitions> {
         &timestamp_struct,
     )?;
 
+    let command_status = define_command_status(lib)?;
+    let binary_output_command_event_it = build_command_event_iterator(
+        "binary_output_command_event",
+        "commanded_state",
+        Primitive::Bool,
+        "Commanded state of the binary output",
+        lib,
+        &command_status,
+        &timestamp_struct,
+    )?;
+    let analog_output_command_event_it = build_command_event_iterator(
+        "analog_output_command_event",
+        "commanded_value",
+        Primitive::Double,
+        "Commanded value of the analog output",
+        lib,
+        &command_status,
+        &timestamp_struct,
+    )?;
+
     let (octet_string, octet_string_it) = build_octet_string(lib)?;
 
     Ok(SharedDefinitions {
@@ -280,8 +312,13 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<SharedDefinitions> {
         frozen_counter_it,
         analog_point,
         analog_it,
+        frozen_analog_point,
+        frozen_analog_it,
         analog_output_status_point,
         analog_output_status_it,
+        command_status,
+        binary_output_command_event_it,
+        analog_output_command_event_it,
         octet_string,
         octet_string_it,
     })
@@ -572,8 +609,12 @@ fn define_function_code(lib: &mut LibraryBuilder) -> BackTraced<EnumHandle> {
         .push("get_file_info", "Outstation shall retrieve information about a file (value == 28)")?
         .push("authenticate_file", "Outstation shall return a file authentication key (value == 29)")?
         .push("abort_file", "Outstation shall abort a file transfer operation (value == 30)")?
+        .push("activate_config", "Outstation shall activate the configurations specified by the objects in the request (value == 31)")?
+        .push("authenticate_request", "Master or outstation sends a secure authentication request (value == 32)")?
+        .push("authenticate_request_no_response", "Same as AuthenticateRequest but the outstation shall not send a response (value == 33)")?
         .push("response", "Master shall interpret this fragment as an Application Layer response to an ApplicationLayer request (value == 129)")?
         .push("unsolicited_response", "Master shall interpret this fragment as an unsolicited response that was not prompted by an explicit request (value == 130)")?
+        .push("authenticate_response", "Outstation sends this to the master as a secure authentication response (value == 131)")?
         .doc("Application layer function code")?
         .build()?;
 
@@ -604,6 +645,36 @@ fn build_iterator<T: Into<UniversalStructField>>(
     Ok((value_struct, value_iterator))
 }
 
+fn build_command_event_iterator<T: Into<UniversalStructField>>(
+    name: &str,
+    value_name: &str,
+    value_type: T,
+    value_doc: &str,
+    lib: &mut LibraryBuilder,
+    command_status: &EnumHandle,
+    timestamp_struct: &UniversalStructHandle,
+) -> Result<AbstractIteratorHandle, BindingError> {
+    let value_struct_decl = lib.declare_universal_struct(name)?;
+    let value_struct = lib
+        .define_universal_struct(value_struct_decl)?
+        .add("index", Primitive::U16, "Point index")?
+        .add(value_name, value_type, value_doc)?
+        .add(
+            "status",
+            command_status.clone(),
+            "Status of the command as reported by the outstation",
+        )?
+        .add("time", timestamp_struct.clone(), "Event timestamp")?
+        .doc(format!("{} value", name))?
+        .end_fields()?
+        .add_full_initializer("init")?
+        .build()?;
+
+    let value_iterator = lib.define_iterator(format!("{}_iterator", name), value_struct)?;
+
+    Ok(value_iterator)
+}
+
 fn build_octet_string(
     lib: &mut LibraryBuilder,
 ) -> Result<(FunctionReturnStructHandle, AbstractIteratorHandle), BindingError> {
@@ -623,3 +694,32 @@ fn build_octet_string(
 
     Ok((octet_string_struct, octet_string_iterator))
 }
+
+fn define_command_status(lib: &mut LibraryBuilder) -> BackTraced<EnumHandle> {
+    let command_status = lib.define_enum("command_status")?
+    .push("success", "command was accepted, initiated, or queued (value == 0)")?
+    .push("timeout", "command timed out before completing (value == 1)")?
+    .push("no_select", "command requires being selected before operate, configuration issue (value == 2)")?
+    .push("format_error", "bad control code or timing values (value == 3)")?
+    .push("not_supported", "command is not implemented (value == 4)")?
+    .push("already_active", "command is all ready in progress or its all ready in that mode (value == 5)")?
+    .push("hardware_error", "something is stopping the command, often a local/remote interlock (value == 6)")?
+    .push("local", "the function governed by the control is in local only control (value == 7)")?
+    .push("too_many_ops", "the command has been done too often and has been throttled (value == 8)")?
+    .push("not_authorized", "the command was rejected because the device denied it or an RTU intercepted it (value == 9)")?
+    .push("automation_inhibit", "command not accepted because it was prevented or inhibited by a local automation process, such as interlocking logic or synchrocheck (value == 10)")?
+    .push("processing_limited", "command not accepted because the device cannot process any more activities than are presently in progress (value == 11)")?
+    .push("out_of_range", "command not accepted because the value is outside the acceptable range permitted for this point (value == 12)")?
+    .push("downstream_local", "command not accepted because the outstation is forwarding the request to another downstream device which reported LOCAL (value == 13)")?
+    .push("already_complete", "command not accepted because the outstation has already completed the requested operation (value == 14)")?
+    .push("blocked", "command not accepted because the requested function is specifically blocked at the outstation (value == 15)")?
+    .push("canceled", "command not accepted because the operation was cancelled (value == 16)")?
+    .push("blocked_other_master", "command not accepted because another master is communicating with the outstation and has exclusive rights to operate this control point (value == 17)")?
+    .push("downstream_fail", "command not accepted because the outstation is forwarding the request to another downstream device which cannot be reached or is otherwise incapable of performing the request (value == 18)")?
+    .push("non_participating", "(deprecated) indicates the outstation shall not issue or perform the control operation (value == 126)")?
+    .push("unknown", "captures any value not defined in the enumeration")?
+    .doc("Enumeration received from an outstation in response to command request")?
+    .build()?;
+
+    Ok(command_status)
+}
@@ -4,6 +4,11 @@ use oo_bindgen::model::{BackTraced, EnumHandle, LibraryBuilder};
 pub(crate) fn define(lib: &mut LibraryBuilder) -> BackTraced<EnumHandle> {
     let variation = lib
         .define_enum("variation")?
+        .push(
+            gv(0, 254),
+            "Device Attributes - Non-specific all attributes request",
+        )?
+        .push("group0", "Device Attributes - Specific attribute")?
         .push(gv(1, 0), "Binary Input - Default variation")?
         .push(gv(1, 1), "Binary Input - Packed format")?
         .push(gv(1, 2), "Binary Input - With flags")?
@@ -38,10 +43,11 @@ pub(crate) fn define(lib: &mut LibraryBuilder) -> BackTraced<EnumHandle> {
             "Binary Output Command - Control Relay Output Block",
         )?
         .push(gv(12, 1), "Binary Output Command - Pattern Control Block")?
-        /* TODO
+        .push(gv(12, 2), "Binary Output Command - Pattern Control Block")?
+        .push(gv(12, 3), "Binary Output Command - Pattern Mask")?
+        .push(gv(13, 0), "Binary Output Command Event - Default variation")?
         .push(gv(13, 1), "Binary Output Command Event - Without time")?
         .push(gv(13, 2), "Binary Output Command Event - With time")?
-         */
         .push(gv(20, 0), "Counter - Default variation")?
         .push(gv(20, 1), "Counter - 32-bit with flags")?
         .push(gv(20, 2), "Counter - 16-bit with flags")?
@@ -83,6 +89,27 @@ pub(crate) fn define(lib: &mut LibraryBuilder) -> BackTraced<EnumHandle> {
             gv(30, 6),
             "Analog Input - Double-precision floating point with flags",
         )?
+        .push(gv(31, 0), "Frozen Analog Input - Default variation")?
+        .push(gv(31, 1), "Frozen Analog Input - 32-bit with flag")?
+        .push(gv(31, 2), "Frozen Analog Input - 16-bit with flag")?
+        .push(
+            gv(31, 3),
+            "Frozen Analog Input - 32-bit with flag and time-of-freeze",
+        )?
+        .push(
+            gv(31, 4),
+            "Frozen Analog Input - 16-bit with flag and time-of-freeze",
+        )?
+        .push(gv(31, 5), "Frozen Analog Input - 32-bit without flag")?
+        .push(gv(31, 6), "Frozen Analog Input - 16-bit without flag")?
+        .push(
+            gv(31, 7),
+            "Frozen Analog Input - Single-precision floating point with flag",
+        )?
+        .push(
+            gv(31, 8),
+            "Frozen Analog Input - Double-precision floating point with flag",
+        )?
         .push(gv(32, 0), "Analog Input Event - Default variation")?
         .push(gv(32, 1), "Analog Input Event - 32-bit without time")?
         .push(gv(32, 2), "Analog Input Event - 16-bit without time")?
@@ -104,6 +131,34 @@ pub(crate) fn define(lib: &mut LibraryBuilder) -> BackTraced<EnumHandle> {
             gv(32, 8),
             "Analog Input Event - Double-precision floating point with time",
         )?
+        .push(gv(33, 0), "Frozen Analog Input Event - Default variation")?
+        .push(gv(33, 1), "Frozen Analog Input Event - 32-bit without time")?
+        .push(gv(33, 2), "Frozen Analog Input Event - 16-bit without time")?
+        .push(gv(33, 3), "Frozen Analog Input Event - 32-bit with time")?
+        .push(gv(33, 4), "Frozen Analog Input Event - 16-bit with time")?
+        .push(
+            gv(33, 5),
+            "Frozen Analog Input Event - Single-precision floating point without time",
+        )?
+        .push(
+            gv(33, 6),
+            "Frozen Analog Input Event - Double-precision floating point without time",
+        )?
+        .push(
+            gv(33, 7),
+            "Frozen Analog Input Event - Single-precision floating point with time",
+        )?
+        .push(
+            gv(33, 8),
+            "Frozen Analog Input Event - Double-precision floating point with time",
+        )?
+        .push(gv(34, 0), "Analog Input Deadband - Default variation")?
+        .push(gv(34, 1), "Analog Input Deadband - 16-bit")?
+        .push(gv(34, 2), "Analog Input Deadband - 32-bit")?
+        .push(
+            gv(34, 3),
+            "Analog Input Deadband - Single-precision floating point",
+        )?
         .push(gv(40, 0), "Analog Output Status - Default variation")?
         .push(gv(40, 1), "Analog Output Status - 32-bit with flags")?
         .push(gv(40, 2), "Analog Output Status - 16-bit with flags")?
@@ -141,7 +196,7 @@ pub(crate) fn define(lib: &mut LibraryBuilder) -> BackTraced<EnumHandle> {
             gv(42, 8),
             "Analog Output Event - Double-preicions floating point with time",
         )?
-        /*
+        .push(gv(43, 0), "Analog Output Command Event - Default variation")?
         .push(
             gv(43, 1),
             "Analog Output Command Event - 32-bit without time",
@@ -150,14 +205,8 @@ pub(crate) fn define(lib: &mut LibraryBuilder) -> BackTraced<EnumHandle> {
             gv(43, 2),
             "Analog Output Command Event - 16-bit without time",
         )?
-        .push(
-            gv(43, 3),
-            "Analog Output Command Event - 32-bit with time",
-        )?
-        .push(
-            gv(43, 4),
-            "Analog Output Command Event - 16-bit with time",
-        )?
+        .push(gv(43, 3), "Analog Output Command Event - 32-bit with time")?
+        .push(gv(43, 4), "Analog Output Command Event - 16-bit with time")?
         .push(
             gv(43, 5),
             "Analog Output Command Event - Single-precision floating point without time",
@@ -174,8 +223,8 @@ pub(crate) fn define(lib: &mut LibraryBuilder) -> BackTraced<EnumHandle> {
             gv(43, 8),
             "Analog Output Command Event - Double-precision floating point with time",
         )?
-         */
         .push(gv(50, 1), "Time and Date - Absolute time")?
+        .push(gv(50, 2), "Time and Date - Absolute time and interval")?
         .push(
             gv(50, 3),
             "Time and Date - Absolute time at last recorded time",
@@ -195,9 +244,42 @@ pub(crate) fn define(lib: &mut LibraryBuilder) -> BackTraced<EnumHandle> {
         .push(gv(60, 2), "Class objects - Class 1 data")?
         .push(gv(60, 3), "Class objects - Class 2 data")?
         .push(gv(60, 4), "Class objects - Class 3 data")?
+        .push(gv(70, 1), "File-control - File identifier")?
+        .push(gv(70, 2), "File-control - Authentication")?
+        .push(gv(70, 3), "File-control - File command")?
+        .push(gv(70, 4), "File-control - File command status")?
+        .push(gv(70, 5), "File-control - File transport")?
+        .push(gv(70, 6), "File-control - File transport status")?
+        .push(gv(70, 7), "File-control - File descriptor")?
+        .push(gv(70, 8), "File-control - File specification string")?
         .push(gv(80, 1), "Internal Indications - Packed format")?
+        .push(gv(85, 1), "Data Set Prototype - With UUID")?
+        .push(gv(86, 1), "Data Set Descriptor - Data Set Contents")?
+        .push(gv(87, 1), "Data Set - Present Value")?
+        .push(gv(88, 1), "Data Set Event - Snapshot")?
+        .push(gv(90, 1), "Application Identifier - Identifier")?
+        .push(
+            gv(91, 1),
+            "Status of Requested Operation - Activate Configuration",
+        )?
+        .push(gv(101, 0), "BCD Integer - Any Variation")?
+        .push(gv(101, 1), "BCD Integer - Small (4 digits)")?
+        .push(gv(101, 2), "BCD Integer - Medium (8 digits)")?
+        .push(gv(101, 3), "BCD Integer - Large (16 digits)")?
+        .push(gv(102, 0), "Unsigned Integer - Any Variation")?
+        .push(gv(102, 1), "Unsigned Integer - 8-bit")?
         .push("group110", "Octet String")?
         .push("group111", "Octet String Event")?
+        .push("group112", "Virtual Terminal Output Block")?
+        .push("group113", "Virtual Terminal Event Data")?
+        .push(gv(120, 1), "Authentication - Challenge")?
+        .push(gv(120, 2), "Authentication - Reply")?
+        .push(gv(120, 3), "Authentication - Aggressive mode request")?
+        .push(gv(120, 4), "Authentication - Session key status request")?
+        .push(gv(120, 5), "Authentication - Session key status")?
+        .push(gv(120, 6), "Authentication - Session key change")?
+        .push(gv(120, 7), "Authentication - Error")?
+        .push(gv(120, 9), "Authentication - HMAC")?
         /*
         .push("group112", "Virtual Terminal Output Block")?
         .push("group113", "Virtual Terminal Event Data")?